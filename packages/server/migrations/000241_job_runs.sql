@@ -0,0 +1,44 @@
+-- In-process job scheduler: run history for recurring maintenance jobs.
+--
+-- The server binary registers named jobs (kernel/jobs/builtin.rs) on cron
+-- schedules. Every run — scheduled or triggered by an admin — writes one row
+-- here so the admin CMS can show "last run / rows affected / error" per job.
+--
+--   trigger        — 'schedule' (fired by the scheduler loop) or 'manual'
+--                    (POST /Jobs/run_now).
+--   scheduled_for  — the cron fire time this run belongs to. Set only for
+--                    cluster-wide scheduled runs. The partial unique index
+--                    below makes the insert the dedup point: if two replicas
+--                    wake for the same fire time (clock skew, or one replica
+--                    finishing before the other acquires the advisory lock),
+--                    only the first INSERT wins and the second skips.
+--   status         — 'running' → 'succeeded' | 'failed'. A row stuck in
+--                    'running' means the process died mid-job.
+--   rows_affected  — whatever the job reports (rows expired, keys pruned…).
+--
+-- Mutual exclusion between replicas is a session-level Postgres advisory
+-- lock keyed on hashtext(job_name); this table is the audit trail, not the
+-- lock.
+
+CREATE TABLE job_runs (
+    id             UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    job_name       TEXT NOT NULL,
+    trigger        TEXT NOT NULL,
+    scheduled_for  TIMESTAMPTZ,
+    status         TEXT NOT NULL DEFAULT 'running',
+    rows_affected  BIGINT,
+    error          TEXT,
+    started_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    finished_at    TIMESTAMPTZ,
+    CONSTRAINT job_runs_trigger_check
+        CHECK (trigger IN ('schedule', 'manual')),
+    CONSTRAINT job_runs_status_check
+        CHECK (status IN ('running', 'succeeded', 'failed'))
+);
+
+CREATE UNIQUE INDEX idx_job_runs_scheduled_once
+    ON job_runs(job_name, scheduled_for)
+    WHERE scheduled_for IS NOT NULL;
+
+CREATE INDEX idx_job_runs_name_started
+    ON job_runs(job_name, started_at DESC);
@@ -0,0 +1,60 @@
+-- Durable work queue for side effects that shouldn't block a request.
+--
+-- Root Signal ingest used to run revision reflow inline (and swallow the
+-- error), and never fetched `source_image_url` at all. Both now enqueue a
+-- row here and the worker pool in the server process (kernel/work_queue)
+-- drains it.
+--
+--   kind           — handler name: 'ingest_post_media' | 'reflow_editions'.
+--   payload        — handler input (e.g. {"post_id": "..."}).
+--   dedup_key      — idempotent job key. At most one *pending* item per
+--                    (kind, dedup_key): a second enqueue while the first is
+--                    still waiting is a no-op. Once the item is picked up a
+--                    new enqueue is accepted, so work that arrives mid-run
+--                    isn't lost.
+--   status         — 'pending' → 'running' → 'succeeded'
+--                                         ↘ 'pending' (retry, run_after bumped)
+--                                         ↘ 'dead'    (attempts exhausted or
+--                                                      permanent failure)
+--   run_after      — earliest time a worker may claim the item. Retries push
+--                    it out with exponential backoff.
+--   locked_at      — when a worker claimed it. 'running' rows whose lease has
+--                    expired (worker crashed) are put back to 'pending' by the
+--                    requeue_stale_work_items scheduled job.
+--
+-- Workers claim with `FOR UPDATE SKIP LOCKED` so any number of workers and
+-- replicas can drain concurrently without double-processing.
+
+CREATE TABLE work_items (
+    id            UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    kind          TEXT NOT NULL,
+    payload       JSONB NOT NULL DEFAULT '{}'::jsonb,
+    dedup_key     TEXT,
+    status        TEXT NOT NULL DEFAULT 'pending',
+    attempts      INT NOT NULL DEFAULT 0,
+    max_attempts  INT NOT NULL DEFAULT 5,
+    run_after     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    locked_at     TIMESTAMPTZ,
+    last_error    TEXT,
+    created_at    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    finished_at   TIMESTAMPTZ,
+    CONSTRAINT work_items_status_check
+        CHECK (status IN ('pending', 'running', 'succeeded', 'dead')),
+    CONSTRAINT work_items_attempts_check
+        CHECK (attempts >= 0 AND max_attempts > 0)
+);
+
+-- Claim path: oldest due pending item first.
+CREATE INDEX idx_work_items_pending_due
+    ON work_items(run_after)
+    WHERE status = 'pending';
+
+-- Idempotent enqueue.
+CREATE UNIQUE INDEX idx_work_items_pending_dedup
+    ON work_items(kind, dedup_key)
+    WHERE status = 'pending' AND dedup_key IS NOT NULL;
+
+-- Admin inspection by status.
+CREATE INDEX idx_work_items_status_updated
+    ON work_items(status, updated_at DESC);
@@ -0,0 +1,46 @@
+-- Audit trail for PII the scrubber found and redacted.
+--
+-- The PII detector (kernel/pii.rs) now runs on Root Signal ingest bodies,
+-- editor saves via /Post/{id}/update_content, and public Note content. Each
+-- match it redacts is recorded here against the entity it came from so
+-- editors can see *what kind* of PII was removed and where.
+--
+--   entity_type         — 'post' | 'note'.
+--   field               — column the match was in: body_raw, body_heavy,
+--                         body_medium, body_light, body_ast, content.
+--   pii_type            — email | phone | ssn | credit_card | ip_address.
+--   masked_value        — the match passed through the partial-mask
+--                         redactor (j***@example.com). The raw value is
+--                         never stored: this table must not become the
+--                         PII store it exists to audit.
+--   start/end_offset    — byte offsets of the match in the *original* text
+--                         of that field (for body_ast, within the text node).
+--   detection_context   — 'personal_message' | 'public_content'.
+--   redaction_strategy  — 'full_removal' | 'partial_mask' | 'token_replacement'.
+--   source              — which write path ran the scrub: 'ingest' |
+--                         'editor' | 'note'.
+--
+-- Posts with findings are routed to in_review; the Signal Inbox derives a
+-- `pii_detected` review flag from the presence of rows here.
+
+CREATE TABLE pii_findings (
+    id                  UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    entity_type         TEXT NOT NULL,
+    entity_id           UUID NOT NULL,
+    field               TEXT NOT NULL,
+    pii_type            TEXT NOT NULL,
+    masked_value        TEXT NOT NULL,
+    start_offset        INT NOT NULL,
+    end_offset          INT NOT NULL,
+    detection_context   TEXT NOT NULL,
+    redaction_strategy  TEXT NOT NULL,
+    source              TEXT NOT NULL,
+    created_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT pii_findings_entity_type_check
+        CHECK (entity_type IN ('post', 'note')),
+    CONSTRAINT pii_findings_source_check
+        CHECK (source IN ('ingest', 'editor', 'note'))
+);
+
+CREATE INDEX idx_pii_findings_entity
+    ON pii_findings(entity_type, entity_id, created_at DESC);
@@ -0,0 +1,81 @@
+-- Newsroom roles and county-scoped capability grants.
+--
+-- Until now authorization was binary: identifiers.is_admin (set from
+-- ADMIN_IDENTIFIERS) put a member in the JWT as an admin and every admin
+-- route accepted them. County desk editors, copy editors and the managing
+-- editor need narrower access, so:
+--
+--   roles              — a named bundle of capabilities ('county_desk_editor').
+--   role_capabilities  — role → capability string, matching
+--                        common::auth::AdminCapability::as_str()
+--                        ('editions:publish', 'posts:edit', ...).
+--   member_roles       — a member holds a role, either everywhere
+--                        (county_id NULL) or for one county per row. A desk
+--                        editor covering Hennepin and Anoka has two rows.
+--
+-- Grants are resolved at login and carried in the JWT (Claims.grants), so
+-- a role change takes effect on the member's next login.
+--
+-- Members with identifiers.is_admin remain full admins and bypass grants.
+
+CREATE TABLE roles (
+    id          UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    slug        TEXT NOT NULL UNIQUE,
+    name        TEXT NOT NULL,
+    description TEXT,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE role_capabilities (
+    role_id     UUID NOT NULL REFERENCES roles(id) ON DELETE CASCADE,
+    capability  TEXT NOT NULL,
+    PRIMARY KEY (role_id, capability)
+);
+
+CREATE TABLE member_roles (
+    id          UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    member_id   UUID NOT NULL REFERENCES members(id) ON DELETE CASCADE,
+    role_id     UUID NOT NULL REFERENCES roles(id) ON DELETE CASCADE,
+    county_id   UUID REFERENCES counties(id) ON DELETE CASCADE,
+    granted_by  UUID REFERENCES members(id) ON DELETE SET NULL,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- One row per (member, role, county); NULL county counts as a value.
+CREATE UNIQUE INDEX idx_member_roles_unique
+    ON member_roles (member_id, role_id, COALESCE(county_id, '00000000-0000-0000-0000-000000000000'::uuid));
+CREATE INDEX idx_member_roles_member ON member_roles (member_id);
+
+-- Seed the three newsroom roles.
+INSERT INTO roles (slug, name, description) VALUES
+    ('managing_editor', 'Managing editor', 'Runs every county''s editions and posts.'),
+    ('county_desk_editor', 'County desk editor', 'Builds, reviews and publishes editions for their counties.'),
+    ('copy_editor', 'Copy editor', 'Edits post copy; cannot review or publish.');
+
+INSERT INTO role_capabilities (role_id, capability)
+SELECT r.id, c.capability
+FROM roles r
+CROSS JOIN (VALUES
+    ('editions:view'), ('editions:edit'), ('editions:review'), ('editions:publish'),
+    ('counties:manage'),
+    ('posts:view'), ('posts:edit'), ('posts:review'), ('posts:delete')
+) AS c(capability)
+WHERE r.slug = 'managing_editor';
+
+INSERT INTO role_capabilities (role_id, capability)
+SELECT r.id, c.capability
+FROM roles r
+CROSS JOIN (VALUES
+    ('editions:view'), ('editions:edit'), ('editions:review'), ('editions:publish'),
+    ('posts:view'), ('posts:edit'), ('posts:review')
+) AS c(capability)
+WHERE r.slug = 'county_desk_editor';
+
+INSERT INTO role_capabilities (role_id, capability)
+SELECT r.id, c.capability
+FROM roles r
+CROSS JOIN (VALUES
+    ('editions:view'), ('posts:view'), ('posts:edit')
+) AS c(capability)
+WHERE r.slug = 'copy_editor';
@@ -0,0 +1,53 @@
+-- Revocable sessions: short-lived access tokens, rotating refresh tokens,
+-- and a jti revocation list.
+--
+-- Access tokens used to live 24 hours with no way to withdraw them, so
+-- /Auth/logout was cosmetic and a departing volunteer editor kept access
+-- until their token expired. Now:
+--
+--   auth_sessions   — one row per login. Holds the SHA-256 of the current
+--                     refresh token (the plaintext is returned once and never
+--                     stored) and the jti of the access token most recently
+--                     minted for it. Each /Auth/refresh rotates the refresh
+--                     token; the previous hash is kept so a replayed (stolen)
+--                     refresh token revokes the whole session.
+--   revoked_tokens  — access-token jtis that must be rejected before their
+--                     exp. Checked by the auth extractors through an
+--                     in-memory cache. Rows past expires_at are useless and
+--                     are pruned by the prune_auth_sessions job.
+--
+-- identifier_hint is the masked phone number / email (never the raw value),
+-- carried into refreshed tokens for logging.
+
+CREATE TABLE auth_sessions (
+    id                          UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    member_id                   UUID NOT NULL REFERENCES members(id) ON DELETE CASCADE,
+    identifier_hint             TEXT NOT NULL,
+    refresh_token_hash          TEXT NOT NULL UNIQUE,
+    previous_refresh_token_hash TEXT,
+    access_jti                  TEXT NOT NULL,
+    access_expires_at           TIMESTAMPTZ NOT NULL,
+    expires_at                  TIMESTAMPTZ NOT NULL,
+    created_at                  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_refreshed_at           TIMESTAMPTZ,
+    revoked_at                  TIMESTAMPTZ,
+    revoked_reason              TEXT
+);
+
+CREATE INDEX idx_auth_sessions_member_active
+    ON auth_sessions (member_id)
+    WHERE revoked_at IS NULL;
+
+CREATE INDEX idx_auth_sessions_previous_refresh
+    ON auth_sessions (previous_refresh_token_hash)
+    WHERE previous_refresh_token_hash IS NOT NULL;
+
+CREATE TABLE revoked_tokens (
+    jti        TEXT PRIMARY KEY,
+    member_id  UUID NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL,
+    reason     TEXT NOT NULL,
+    revoked_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_revoked_tokens_expires ON revoked_tokens (expires_at);
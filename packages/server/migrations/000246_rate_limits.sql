@@ -0,0 +1,48 @@
+-- Rate limiting and lockouts for the OTP endpoints.
+--
+-- /Auth/send_otp costs real money per SMS and /Auth/verify_otp guards a
+-- six-digit code, and neither had any limit. The policy lives in code
+-- (kernel::rate_limit, domains/auth/activities/otp_limits.rs); these tables
+-- only hold its state so every replica shares one budget:
+--
+--   rate_limit_counters  — fixed-window hit counters. key is e.g.
+--                          'otp_send:ip:203.0.113.7' or 'otp_send:global';
+--                          identifier keys carry a SHA-256 hash, never the
+--                          phone number or email. Rows are pruned once
+--                          expires_at (window end) has passed.
+--   rate_limit_lockouts  — consecutive verification failures per key.
+--                          strikes counts lockouts served; each doubles the
+--                          next one, up to a cap.
+--   auth_security_events — exhausted budgets and lockouts, for admins
+--                          (/Auth/security_events). Retained 90 days.
+
+CREATE TABLE rate_limit_counters (
+    key          TEXT NOT NULL,
+    window_start TIMESTAMPTZ NOT NULL,
+    count        INTEGER NOT NULL DEFAULT 0,
+    expires_at   TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (key, window_start)
+);
+
+CREATE INDEX idx_rate_limit_counters_expires ON rate_limit_counters (expires_at);
+
+CREATE TABLE rate_limit_lockouts (
+    key             TEXT PRIMARY KEY,
+    failures        INTEGER NOT NULL DEFAULT 0,
+    strikes         INTEGER NOT NULL DEFAULT 0,
+    locked_until    TIMESTAMPTZ,
+    last_failure_at TIMESTAMPTZ,
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE auth_security_events (
+    id          UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    event_type  TEXT NOT NULL,
+    subject     TEXT NOT NULL,
+    ip_address  TEXT,
+    detail      JSONB NOT NULL DEFAULT '{}'::jsonb,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_auth_security_events_created ON auth_security_events (created_at DESC);
+CREATE INDEX idx_auth_security_events_type ON auth_security_events (event_type, created_at DESC);
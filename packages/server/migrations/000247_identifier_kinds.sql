@@ -0,0 +1,12 @@
+-- Record whether an identifier is a phone number or an email.
+--
+-- Identifiers are now normalized before hashing (E.164 for phones,
+-- lowercased for emails) and the hash is kind-specific: phones hash exactly
+-- as before, emails hash as sha256('email:' || email). Email rows created
+-- by the old code hashed the address as typed; they are labelled 'phone'
+-- here because the hash can't tell us otherwise, and are rewritten to the
+-- new hash and kind = 'email' the next time their owner logs in.
+
+ALTER TABLE identifiers
+    ADD COLUMN kind TEXT NOT NULL DEFAULT 'phone'
+        CHECK (kind IN ('phone', 'email'));
@@ -0,0 +1,22 @@
+-- Layout traces: why each candidate post did or didn't make an edition.
+--
+-- Editors ask "why didn't post X make this week's Aitkin edition?" and the
+-- layout engine had no answer: load_county_posts filters on status,
+-- geography and the date window in one query, and place_posts drops posts
+-- that don't fit without a record. generate_edition now stores a structured
+-- trace per edition (one row, replaced on every regeneration):
+--
+--   trace.posts[]  — each candidate post with every eligibility clause
+--                    (status / geography / window) evaluated separately,
+--                    then its placement: the row and templates it landed in,
+--                    or the reason it was left out.
+--   trace.layout   — the strategy used and the layout's score breakdown.
+--
+-- Served by /Editions/layout_trace. The JSON shape is LayoutTrace in
+-- domains/editions/data/types.rs.
+
+CREATE TABLE edition_layout_traces (
+    edition_id   UUID PRIMARY KEY REFERENCES editions(id) ON DELETE CASCADE,
+    trace        JSONB NOT NULL,
+    generated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,44 @@
+-- Edition snapshots: the paper of record for every published edition.
+--
+-- The public broadsheet is assembled from live joins (rows, slots, posts,
+-- field groups, widgets, sections), so editing a post after publication
+-- silently changed what a past edition looked like. publish_edition now
+-- freezes the fully rendered broadsheet (the PublicBroadsheetResult JSON,
+-- including every body tier) into this table.
+--
+--   version       — 1 on first publish; a re-publish after an unpublish
+--                   adds the next version only if the content changed.
+--   content_hash  — SHA-256 (hex) of the snapshot JSON with object keys
+--                   sorted, so identical content always hashes the same.
+--
+-- Rows are never updated; a trigger rejects UPDATEs. Deleting the edition
+-- still cascades.
+--
+-- Served by /Public/back_issues and /Public/back_issue.
+
+CREATE TABLE edition_snapshots (
+    id            UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    edition_id    UUID NOT NULL REFERENCES editions(id) ON DELETE CASCADE,
+    county_id     UUID NOT NULL REFERENCES counties(id),
+    period_start  DATE NOT NULL,
+    period_end    DATE NOT NULL,
+    version       INT NOT NULL,
+    content_hash  TEXT NOT NULL,
+    snapshot      JSONB NOT NULL,
+    published_at  TIMESTAMPTZ NOT NULL,
+    created_at    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (edition_id, version)
+);
+
+CREATE INDEX idx_edition_snapshots_county_period
+    ON edition_snapshots (county_id, period_start DESC);
+
+CREATE OR REPLACE FUNCTION edition_snapshots_reject_update() RETURNS trigger AS $$
+BEGIN
+    RAISE EXCEPTION 'edition_snapshots rows are immutable';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trigger_edition_snapshots_immutable
+    BEFORE UPDATE ON edition_snapshots
+    FOR EACH ROW EXECUTE FUNCTION edition_snapshots_reject_update();
@@ -0,0 +1,24 @@
+-- Scheduled edition publishing.
+--
+-- Editors approve an edition and set publish_at ("Monday 6:00 America/
+-- Chicago", stored as UTC). The publish_scheduled_editions job publishes
+-- every approved edition whose publish_at has passed, re-checking the
+-- populated-slots gate and refusing seed-contaminated editions (there is
+-- no editor on hand to confirm the override the admin app offers).
+--
+--   publish_at     — cleared once the edition is published, and when the
+--                    scheduled attempt fails, so a failure isn't retried
+--                    every minute.
+--   publish_error  — why the last scheduled attempt failed; cleared when
+--                    the edition is rescheduled or published.
+--
+-- Per-post embargoes need no schema: an active post whose published_at
+-- is in the future is withheld from the public post endpoints until then.
+
+ALTER TABLE editions
+    ADD COLUMN publish_at TIMESTAMPTZ,
+    ADD COLUMN publish_error TEXT;
+
+CREATE INDEX idx_editions_publish_at
+    ON editions (publish_at)
+    WHERE publish_at IS NOT NULL;
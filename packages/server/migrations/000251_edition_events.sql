@@ -0,0 +1,43 @@
+-- Edition history: every mutation of an edition recorded as an operation.
+--
+-- Layout edits (move_slot, change_slot_template, remove_post, reorder_rows,
+-- assign_row_to_section, section CRUD, generate_edition, ...) overwrote
+-- state with no record, and regenerating wiped hand-placed work. Each edit
+-- now appends an edition_events row:
+--
+--   op / params    — the handler name and its request, for the history view.
+--   before / after — the edition's full layout (sections, rows, slots as
+--                    table rows) either side of the edit. NULL for
+--                    lifecycle events (review, approve, publish, ...),
+--                    which are recorded but can't be undone.
+--   status         — applied | undone | discarded. Undo restores the
+--                    `before` of the oldest of the last N applied layout
+--                    events; redo restores the `after` of the Nth undone
+--                    one. A new edit discards whatever could still be
+--                    redone.
+--
+-- Layout states are small (an edition is ~20 rows, ~60 slots), so a full
+-- copy per event keeps undo exact without per-operation inverse logic.
+--
+-- edition_slots.pinned marks slots an editor wants kept when the edition
+-- is regenerated with keep_pinned.
+
+CREATE TABLE edition_events (
+    id           UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    seq          BIGSERIAL NOT NULL,
+    edition_id   UUID NOT NULL REFERENCES editions(id) ON DELETE CASCADE,
+    actor_id     UUID REFERENCES members(id) ON DELETE SET NULL,
+    op           TEXT NOT NULL,
+    params       JSONB NOT NULL DEFAULT '{}'::jsonb,
+    before       JSONB,
+    after        JSONB,
+    status       TEXT NOT NULL DEFAULT 'applied'
+                 CHECK (status IN ('applied', 'undone', 'discarded')),
+    created_at   TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    reverted_at  TIMESTAMPTZ
+);
+
+CREATE INDEX idx_edition_events_edition_seq ON edition_events (edition_id, seq DESC);
+
+ALTER TABLE edition_slots
+    ADD COLUMN pinned BOOLEAN NOT NULL DEFAULT false;
@@ -0,0 +1,19 @@
+-- Editor pins on edition rows and sections.
+--
+-- edition_slots.pinned (000251) fixed a single slot to its post and
+-- template. Editors also need to pin a whole row (its template and every
+-- slot in it) or a whole section (its title and all of its rows). The
+-- layout engine now treats pins as constraints: pinned posts and widgets
+-- are taken out of the pool, the rest of the edition is laid out around
+-- them, and each pin goes back at its old position. Pins that can't be
+-- honoured (the post expired or was archived, the template was removed,
+-- ...) are dropped and listed in the layout report.
+--
+-- Regeneration honours pins by default; generate_edition takes
+-- ignore_pins to start from scratch.
+
+ALTER TABLE edition_rows
+    ADD COLUMN pinned BOOLEAN NOT NULL DEFAULT false;
+
+ALTER TABLE edition_sections
+    ADD COLUMN pinned BOOLEAN NOT NULL DEFAULT false;
@@ -0,0 +1,11 @@
+-- iCalendar SEQUENCE for schedules.
+--
+-- Calendar clients that subscribe to the event feeds (/Post/{id}/calendar.ics
+-- and /County/{id}/calendar.ics) key each VEVENT on its UID — the schedule
+-- id — and only accept an update to an event they already hold when its
+-- SEQUENCE is higher than the copy they have. Schedule::update bumps this
+-- on every edit; new schedules start at 0.
+
+ALTER TABLE schedules ADD COLUMN sequence INTEGER NOT NULL DEFAULT 0;
+
+COMMENT ON COLUMN schedules.sequence IS 'RFC 5545 SEQUENCE, bumped on every update so subscribed calendars pick up changes';
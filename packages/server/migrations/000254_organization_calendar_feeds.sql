@@ -0,0 +1,38 @@
+-- iCalendar feeds as a second ingest source.
+--
+-- Partner organisations already publish Google/Outlook calendars. Staff
+-- register a feed URL against the organisation; the importer fetches it
+-- through the media-ingest SSRF guard and fetcher (HTTPS only, 5s, 5 MiB),
+-- and turns each VEVENT into an `event` post with its schedule (RRULE and
+-- EXDATE kept). Imports run every six hours on the work queue, or on
+-- demand from the organisation page.
+--
+-- Dedup is by VEVENT UID: the post's content_hash is a hash of
+-- (organisation, UID), so a re-import finds the post it made last time.
+-- The primary post_sources row carries a fingerprint of the event's
+-- content; when that changes the importer files a revision
+-- (revision_of_post_id) instead of editing the live post.
+--
+-- Imported posts land in_review with submission_type 'ical_import', which
+-- the Signal inbox shows as its own review flag.
+
+CREATE TABLE organization_calendar_feeds (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    url TEXT NOT NULL,
+    -- The `sources` row imported posts cite (source_type 'ical').
+    source_id UUID REFERENCES sources(id) ON DELETE SET NULL,
+    active BOOLEAN NOT NULL DEFAULT true,
+    last_fetched_at TIMESTAMPTZ,
+    last_error TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (organization_id, url)
+);
+
+CREATE INDEX idx_organization_calendar_feeds_org
+    ON organization_calendar_feeds(organization_id);
+
+ALTER TABLE posts DROP CONSTRAINT IF EXISTS posts_submission_type_check;
+ALTER TABLE posts ADD CONSTRAINT posts_submission_type_check
+  CHECK (submission_type = ANY (ARRAY['ingested', 'admin', 'org_submitted', 'reader_submitted', 'revision', 'ical_import']));
@@ -0,0 +1,82 @@
+-- Persisted review flags for the Signal Inbox.
+--
+-- Ingest computed its soft-fail flags (low_confidence, possible_duplicate,
+-- deck_missing_on_heavy, unknown_topic, source_stale,
+-- individual_no_consent, pii_detected), used them to pick
+-- status = 'in_review', and threw them away. The inbox re-derived what it
+-- could from columns at query time and could not show the rest. Each flag
+-- is now a row:
+--
+--   flag         — the reason code, as listed above, plus 'ical_import'
+--                  for posts the calendar importer creates.
+--   details      — whatever ingest knew when it raised the flag
+--                  (extraction_confidence, duplicate_of_id, finding count, ...).
+--   blocking     — an open blocking flag keeps the post in review. When an
+--                  editor resolves the last one the post goes active.
+--   resolved_at / resolved_by
+--                — set when an editor clears the flag, or when the post is
+--                  approved outright.
+--
+-- At most one open row per (post, flag).
+--
+-- Backfill: posts already in review get the flags that can still be
+-- derived from current columns, the same ones the inbox derived before.
+-- unknown_topic, source_stale and individual_no_consent were never stored
+-- and can't be recovered, so every such post (bar calendar imports, whose
+-- reason is known) also gets a blocking 'legacy_review' flag. Resolving a
+-- derived flag alone never activates a post held back for a reason no one
+-- can see any more — an editor has to clear the legacy flag too.
+
+CREATE TABLE post_review_flags (
+    id           UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    post_id      UUID NOT NULL REFERENCES posts(id) ON DELETE CASCADE,
+    flag         TEXT NOT NULL,
+    details      JSONB NOT NULL DEFAULT '{}'::jsonb,
+    blocking     BOOLEAN NOT NULL DEFAULT true,
+    created_at   TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    resolved_at  TIMESTAMPTZ,
+    resolved_by  UUID REFERENCES members(id) ON DELETE SET NULL
+);
+
+CREATE UNIQUE INDEX idx_post_review_flags_open
+    ON post_review_flags (post_id, flag)
+    WHERE resolved_at IS NULL;
+
+CREATE INDEX idx_post_review_flags_open_by_flag
+    ON post_review_flags (flag, created_at DESC)
+    WHERE resolved_at IS NULL;
+
+INSERT INTO post_review_flags (post_id, flag, details)
+SELECT id, 'low_confidence', jsonb_build_object('extraction_confidence', extraction_confidence)
+FROM posts
+WHERE status = 'in_review' AND deleted_at IS NULL AND extraction_confidence < 60;
+
+INSERT INTO post_review_flags (post_id, flag, details)
+SELECT id, 'possible_duplicate', jsonb_build_object('duplicate_of_id', duplicate_of_id)
+FROM posts
+WHERE status = 'in_review' AND deleted_at IS NULL AND duplicate_of_id IS NOT NULL;
+
+INSERT INTO post_review_flags (post_id, flag)
+SELECT p.id, 'deck_missing_on_heavy'
+FROM posts p
+LEFT JOIN post_meta pm ON pm.post_id = p.id
+WHERE p.status = 'in_review' AND p.deleted_at IS NULL AND p.weight = 'heavy'
+  AND COALESCE(TRIM(pm.deck), '') = '';
+
+INSERT INTO post_review_flags (post_id, flag, details)
+SELECT p.id, 'pii_detected', jsonb_build_object('finding_count', COUNT(f.id))
+FROM posts p
+JOIN pii_findings f ON f.entity_type = 'post' AND f.entity_id = p.id
+WHERE p.status = 'in_review' AND p.deleted_at IS NULL
+GROUP BY p.id;
+
+INSERT INTO post_review_flags (post_id, flag)
+SELECT id, 'ical_import'
+FROM posts
+WHERE status = 'in_review' AND deleted_at IS NULL AND submission_type = 'ical_import';
+
+INSERT INTO post_review_flags (post_id, flag, details)
+SELECT id, 'legacy_review', jsonb_build_object('reason', 'in review before flags were recorded')
+FROM posts
+WHERE status = 'in_review' AND deleted_at IS NULL
+  AND submission_type IS DISTINCT FROM 'ical_import';
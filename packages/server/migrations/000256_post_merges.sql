@@ -0,0 +1,41 @@
+-- Post merges: folding a duplicate post into the one that survives.
+--
+-- Ingest records `duplicate_of_id` on a post it thinks repeats another,
+-- but nothing combined the two. /Post/{id}/merge_into now moves the
+-- duplicate's citations, tags, contacts, schedules, notes and media
+-- references onto the survivor, takes whichever field groups the editor
+-- chose from the duplicate, re-points edition slots in unpublished
+-- editions, and archives the duplicate with `duplicate_of_id` pointing at
+-- the survivor (the redirect).
+--
+-- One row per merge:
+--
+--   merged_content_hash — the duplicate's `posts.content_hash` at merge
+--                         time. Content-hash dedup excludes posts with
+--                         `duplicate_of_id` set, so without this a
+--                         re-ingest of the duplicate's content would create
+--                         a third post; with it, dedup resolves to the
+--                         survivor.
+--   field_groups        — groups taken from the duplicate instead of the
+--                         survivor.
+--   summary             — what moved (counts per table, editions touched).
+--
+-- When a survivor is itself merged later, earlier rows are re-pointed at
+-- the new survivor so lookups stay one hop.
+
+CREATE TABLE post_merges (
+    id                   UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    survivor_post_id     UUID NOT NULL REFERENCES posts(id) ON DELETE CASCADE,
+    merged_post_id       UUID NOT NULL UNIQUE REFERENCES posts(id) ON DELETE CASCADE,
+    merged_content_hash  TEXT,
+    field_groups         TEXT[] NOT NULL DEFAULT '{}',
+    summary              JSONB NOT NULL DEFAULT '{}'::jsonb,
+    merged_by            UUID REFERENCES members(id) ON DELETE SET NULL,
+    created_at           TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT post_merges_distinct_posts CHECK (survivor_post_id <> merged_post_id)
+);
+
+CREATE INDEX idx_post_merges_survivor ON post_merges (survivor_post_id);
+CREATE INDEX idx_post_merges_content_hash
+    ON post_merges (merged_content_hash)
+    WHERE merged_content_hash IS NOT NULL;
@@ -0,0 +1,36 @@
+-- Near-duplicate detection: MinHash signatures over post text.
+--
+-- Content-hash dedup (§1.5) only catches exact repeats. Root Signal often
+-- sends the same story reworded, or the same event from two sources, and
+-- those sailed through as separate posts. Ingest now computes a MinHash
+-- signature over word 3-gram shingles of the title and body and stores it
+-- here (see posts/activities/near_duplicate.rs).
+--
+--   post_similarity_signatures — 128 minimum hashes per post. Two posts'
+--       Jaccard similarity is estimated as the fraction of positions where
+--       their signatures agree.
+--   post_similarity_bands      — the signature cut into 32 bands of 4,
+--       each band hashed to a bucket. Posts sharing any bucket are
+--       candidates; only candidates are compared in full. With 32×4 bands
+--       a pair at similarity 0.6 shares a bucket ~99% of the time, a pair
+--       at 0.2 about 5%.
+--
+-- A candidate within 14 days and sharing a county that scores at or above
+-- the threshold raises a `possible_duplicate` review flag on the new post
+-- with the matched post ids and scores.
+
+CREATE TABLE post_similarity_signatures (
+    post_id        UUID PRIMARY KEY REFERENCES posts(id) ON DELETE CASCADE,
+    signature      BIGINT[] NOT NULL,
+    shingle_count  INT NOT NULL,
+    created_at     TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE post_similarity_bands (
+    post_id  UUID NOT NULL REFERENCES posts(id) ON DELETE CASCADE,
+    band     SMALLINT NOT NULL,
+    bucket   BIGINT NOT NULL,
+    PRIMARY KEY (post_id, band)
+);
+
+CREATE INDEX idx_post_similarity_bands_bucket ON post_similarity_bands (band, bucket);
@@ -0,0 +1,63 @@
+-- County boundaries and a resolved county on each post.
+--
+-- Edition eligibility mapped posts to counties only through
+-- `locations.postal_code → zip_counties`. ZIPs that straddle a county line
+-- put a post in every county the ZIP touches, and posts with coordinates
+-- but no ZIP fell through to the "no location = ambient" rule and showed
+-- up everywhere.
+--
+--   county_boundaries — one GeoJSON Polygon/MultiPolygon per county
+--       (e.g. the MnGeo county boundaries, loaded through
+--       /Editions/load_county_boundaries). The bounding box columns let
+--       resolution fetch only the few counties whose box holds a point;
+--       the point-in-polygon test itself runs in Rust
+--       (editions/activities/county_boundaries.rs).
+--   posts.county_id    — the county a post resolves to: its coordinates
+--       (or its primary location's) inside a boundary, else its ZIP's
+--       primary county. `county_resolved_via` records which.
+--
+-- Eligibility queries read posts.county_id directly and only fall back to
+-- the zip_counties join for posts not resolved yet. The backfill below
+-- seeds the ZIP resolution so nothing changes until boundaries are loaded
+-- and coordinates take over.
+
+CREATE TABLE county_boundaries (
+    county_id   UUID PRIMARY KEY REFERENCES counties(id) ON DELETE CASCADE,
+    geometry    JSONB NOT NULL,
+    min_lat     DOUBLE PRECISION NOT NULL,
+    max_lat     DOUBLE PRECISION NOT NULL,
+    min_lng     DOUBLE PRECISION NOT NULL,
+    max_lng     DOUBLE PRECISION NOT NULL,
+    source      TEXT,
+    loaded_at   TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_county_boundaries_bbox
+    ON county_boundaries (min_lat, max_lat, min_lng, max_lng);
+
+ALTER TABLE posts
+    ADD COLUMN county_id UUID REFERENCES counties(id) ON DELETE SET NULL,
+    ADD COLUMN county_resolved_via TEXT
+        CHECK (county_resolved_via IN ('coordinates', 'zip')),
+    ADD COLUMN county_resolved_at TIMESTAMPTZ;
+
+CREATE INDEX idx_posts_county_id ON posts (county_id) WHERE county_id IS NOT NULL;
+
+-- ZIP resolution for existing posts: primary location's ZIP first, then
+-- the legacy posts.zip_code, taking the ZIP's primary county.
+UPDATE posts p
+SET county_id = r.county_id,
+    county_resolved_via = 'zip',
+    county_resolved_at = NOW()
+FROM (
+    SELECT DISTINCT ON (p2.id) p2.id, zc.county_id
+    FROM posts p2
+    LEFT JOIN locationables la
+        ON la.locatable_id = p2.id
+        AND la.locatable_type = 'post'
+        AND la.is_primary = true
+    LEFT JOIN locations loc ON loc.id = la.location_id
+    JOIN zip_counties zc ON zc.zip_code = COALESCE(loc.postal_code, p2.zip_code)
+    ORDER BY p2.id, zc.is_primary DESC, zc.county_id
+) r
+WHERE p.id = r.id;
@@ -0,0 +1,19 @@
+-- Geocode confidence on locations.
+--
+-- Ingested posts often carry only a free-text `location` ("Brainerd Public
+-- Library, 416 S 5th St") with no coordinates, so proximity search never
+-- finds them. The offline geocoder (kernel/geocoding) resolves that text
+-- against zip_codes and a bundled Minnesota gazetteer; the location rows it
+-- creates record how it got there.
+--
+--   geocode_precision  — landmark | postal_code | area | city | region
+--   geocode_confidence — 0.0–1.0, the geocoder's own score
+--
+-- NULL on both means the coordinates came from the source (or there are
+-- none), not from the geocoder.
+
+ALTER TABLE locations
+    ADD COLUMN geocode_precision TEXT
+        CHECK (geocode_precision IN ('landmark', 'postal_code', 'area', 'city', 'region')),
+    ADD COLUMN geocode_confidence DOUBLE PRECISION
+        CHECK (geocode_confidence BETWEEN 0 AND 1);
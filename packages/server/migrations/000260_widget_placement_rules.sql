@@ -0,0 +1,49 @@
+-- Widget placement rules, moved out of `layout_engine::place_widgets`.
+--
+-- Where widget rows land in a generated broadsheet was a hard-coded Rust
+-- table ("after row 2: section_sep", "after row 4: number ×3", …), so
+-- moving a pull quote needed a deploy. Each row here is one rule:
+--
+--   after_row        — insert after this post row (0-based)
+--   widget_type      — widgets.widget_type to draw from (round-robin)
+--   count            — widgets in the row: 1 standalone, 2 pair, 3 trio
+--   min_rows         — only if the broadsheet has at least this many post rows
+--   rows_after       — only if at least this many post rows follow
+--                      (section separators need something below them)
+--   min_gap          — post rows since the previous widget row; 1 keeps two
+--                      widget rows from sharing a position
+--   at_section_start — only if a topic section starts right below
+--
+-- county_id NULL is the global rule set. A county with any rules of its own
+-- uses only those (disabled ones included, so a county can switch all
+-- widgets off by disabling its rules). Rules are evaluated in sort_order.
+--
+-- The seed reproduces the hard-coded table exactly.
+
+CREATE TABLE widget_placement_rules (
+    id                UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    county_id         UUID REFERENCES counties(id) ON DELETE CASCADE,
+    sort_order        INT NOT NULL DEFAULT 0,
+    after_row         INT NOT NULL CHECK (after_row >= 0),
+    widget_type       TEXT NOT NULL,
+    count             INT NOT NULL DEFAULT 1 CHECK (count BETWEEN 1 AND 3),
+    min_rows          INT NOT NULL DEFAULT 0 CHECK (min_rows >= 0),
+    rows_after        INT NOT NULL DEFAULT 0 CHECK (rows_after >= 0),
+    min_gap           INT NOT NULL DEFAULT 1 CHECK (min_gap >= 0),
+    at_section_start  BOOLEAN NOT NULL DEFAULT false,
+    enabled           BOOLEAN NOT NULL DEFAULT true,
+    created_at        TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at        TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_widget_placement_rules_county
+    ON widget_placement_rules (county_id, sort_order);
+
+INSERT INTO widget_placement_rules (sort_order, after_row, widget_type, count, rows_after) VALUES
+    (0, 2, 'section_sep',  1, 1),
+    (1, 4, 'number',       3, 0),  -- stat-card trio
+    (2, 5, 'section_sep',  1, 1),
+    (3, 6, 'photo',        1, 0),
+    (4, 7, 'pull_quote',   1, 0),
+    (5, 8, 'number',       2, 0),  -- number-block pair
+    (6, 9, 'resource_bar', 1, 0);
@@ -0,0 +1,29 @@
+-- Which calendar feed an imported event's citation came from.
+--
+-- An organisation can register several feeds that all cite the same
+-- `sources` row, so (source_type 'ical', source_id, platform_id = UID)
+-- doesn't say which feed to check an event against. With the feed on the
+-- citation, an import can archive the events that have disappeared from
+-- that feed.
+--
+-- Existing citations are backfilled from the feed id recorded on the
+-- post's `ical_import` review flag.
+
+ALTER TABLE post_sources
+    ADD COLUMN calendar_feed_id UUID REFERENCES organization_calendar_feeds(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_post_sources_calendar_feed
+    ON post_sources(calendar_feed_id, platform_id)
+    WHERE calendar_feed_id IS NOT NULL;
+
+UPDATE post_sources ps
+SET calendar_feed_id = (f.details->>'feed_id')::uuid
+FROM post_review_flags f
+WHERE f.post_id = ps.post_id
+  AND f.flag = 'ical_import'
+  AND ps.source_type = 'ical'
+  AND ps.platform_id IS NOT NULL
+  AND EXISTS (
+      SELECT 1 FROM organization_calendar_feeds cf
+      WHERE cf.id = (f.details->>'feed_id')::uuid
+  );
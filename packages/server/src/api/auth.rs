@@ -1,9 +1,14 @@
 use axum::extract::FromRef;
 use axum::http::request::Parts;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
 
+use crate::common::auth::{
+    granted_county_ids, grants_allow, AdminCapability, CapabilityGrant, CountyScope,
+};
 use crate::common::{ApiKeyId, MemberId};
-use crate::domains::auth::JwtService;
 use crate::domains::posts::models::ApiKey;
+use crate::kernel::rate_limit::TrustedProxies;
 
 use super::error::ApiError;
 use super::state::AppState;
@@ -15,6 +20,68 @@ pub struct AuthUser {
     pub member_id: MemberId,
     pub phone_number: String,
     pub is_admin: bool,
+    /// Role grants from the token. Full admins don't need any.
+    pub grants: Vec<CapabilityGrant>,
+    /// The presented token's id, for revoking it at logout.
+    pub jti: String,
+    /// Session the token belongs to (absent on pre-session tokens).
+    pub session_id: Option<Uuid>,
+    pub token_expires_at: DateTime<Utc>,
+}
+
+impl AuthUser {
+    /// Admin or holder of at least one role grant — may use the newsroom
+    /// admin routes, subject to per-handler capability checks.
+    pub fn is_staff(&self) -> bool {
+        self.is_admin || !self.grants.is_empty()
+    }
+
+    /// Whether the user holds `capability` within `scope`. Full admins
+    /// hold every capability everywhere.
+    pub fn can(&self, capability: AdminCapability, scope: CountyScope<'_>) -> bool {
+        self.is_admin || grants_allow(&self.grants, capability, scope)
+    }
+
+    /// `can`, as a 403 for handlers.
+    pub fn require(
+        &self,
+        capability: AdminCapability,
+        scope: CountyScope<'_>,
+    ) -> Result<(), ApiError> {
+        if self.can(capability, scope) {
+            return Ok(());
+        }
+        let target = match scope {
+            CountyScope::Any => "",
+            CountyScope::County(_) => " for this county",
+            CountyScope::Counties([]) => " statewide",
+            CountyScope::Counties(_) => " for every county this touches",
+        };
+        Err(ApiError::Forbidden(format!(
+            "Missing capability {}{}",
+            capability.as_str(),
+            target
+        )))
+    }
+
+    /// Counties a listing is limited to. A `requested` county must be
+    /// covered by a grant of `capability`; otherwise the caller's granted
+    /// counties apply. `None` means no filter — admins and unscoped grants.
+    pub fn county_filter(
+        &self,
+        capability: AdminCapability,
+        requested: Option<Uuid>,
+    ) -> Result<Option<Vec<Uuid>>, ApiError> {
+        if let Some(county_id) = requested {
+            self.require(capability, CountyScope::County(county_id))?;
+            return Ok(Some(vec![county_id]));
+        }
+        self.require(capability, CountyScope::Any)?;
+        if self.is_admin {
+            return Ok(None);
+        }
+        Ok(granted_county_ids(&self.grants, capability))
+    }
 }
 
 /// Extract JWT from `X-User-Token` or `Authorization` header.
@@ -42,16 +109,33 @@ fn extract_bearer_token(parts: &Parts) -> Option<String> {
         .map(|t| t.to_string())
 }
 
-fn validate_token(token: &str, jwt_service: &JwtService) -> Result<AuthUser, ApiError> {
-    let claims = jwt_service
+/// Verify the JWT and check its `jti` and session against the revocation
+/// list.
+async fn validate_token(token: &str, state: &AppState) -> Result<AuthUser, ApiError> {
+    let claims = state
+        .deps
+        .jwt_service
         .verify_token(token)
         .map_err(|e| ApiError::Unauthorized(format!("Invalid token: {}", e)))?;
 
+    if state
+        .deps
+        .token_revocations
+        .is_revoked(&claims.jti, claims.sid, &state.deps.db_pool)
+        .await?
+    {
+        return Err(ApiError::Unauthorized("Token has been revoked".into()));
+    }
+
     Ok(AuthUser {
         user_id: claims.member_id.to_string(),
         member_id: MemberId::from_uuid(claims.member_id),
         phone_number: claims.phone_number,
         is_admin: claims.is_admin,
+        grants: claims.grants,
+        jti: claims.jti,
+        session_id: claims.sid,
+        token_expires_at: DateTime::from_timestamp(claims.exp, 0).unwrap_or_else(Utc::now),
     })
 }
 
@@ -59,7 +143,8 @@ fn validate_token(token: &str, jwt_service: &JwtService) -> Result<AuthUser, Api
 // Axum extractors
 // ---------------------------------------------------------------------------
 
-/// Requires a valid JWT. Rejects with 401 if missing or invalid.
+/// Requires a valid, unrevoked JWT. Rejects with 401 if missing, invalid or
+/// revoked.
 pub struct AuthenticatedUser(pub AuthUser);
 
 impl<S> axum::extract::FromRequestParts<S> for AuthenticatedUser
@@ -77,7 +162,7 @@ where
             let app_state = AppState::from_ref(state);
             let token = extract_token(parts)
                 .ok_or_else(|| ApiError::Unauthorized("Missing authentication header".into()))?;
-            let user = validate_token(&token, &app_state.deps.jwt_service)?;
+            let user = validate_token(&token, &app_state).await?;
             Ok(AuthenticatedUser(user))
         }
     }
@@ -101,7 +186,7 @@ where
             let app_state = AppState::from_ref(state);
             let token = extract_token(parts)
                 .ok_or_else(|| ApiError::Unauthorized("Missing authentication header".into()))?;
-            let user = validate_token(&token, &app_state.deps.jwt_service)?;
+            let user = validate_token(&token, &app_state).await?;
             if !user.is_admin {
                 return Err(ApiError::Forbidden("Admin access required".into()));
             }
@@ -110,6 +195,35 @@ where
     }
 }
 
+/// Requires a valid JWT from an admin or a member holding role grants.
+/// Handlers must still check the specific capability with
+/// [`AuthUser::require`]. Rejects with 401/403.
+pub struct StaffUser(pub AuthUser);
+
+impl<S> axum::extract::FromRequestParts<S> for StaffUser
+where
+    AppState: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    fn from_request_parts(
+        parts: &mut Parts,
+        state: &S,
+    ) -> impl std::future::Future<Output = Result<Self, Self::Rejection>> + Send {
+        async move {
+            let app_state = AppState::from_ref(state);
+            let token = extract_token(parts)
+                .ok_or_else(|| ApiError::Unauthorized("Missing authentication header".into()))?;
+            let user = validate_token(&token, &app_state).await?;
+            if !user.is_staff() {
+                return Err(ApiError::Forbidden("Staff access required".into()));
+            }
+            Ok(StaffUser(user))
+        }
+    }
+}
+
 /// Optionally extracts auth. Never rejects — returns `None` on missing/invalid token.
 pub struct OptionalUser(pub Option<AuthUser>);
 
@@ -126,13 +240,50 @@ where
     ) -> impl std::future::Future<Output = Result<Self, Self::Rejection>> + Send {
         async move {
             let app_state = AppState::from_ref(state);
-            let user = extract_token(parts)
-                .and_then(|token| validate_token(&token, &app_state.deps.jwt_service).ok());
+            let user = match extract_token(parts) {
+                Some(token) => validate_token(&token, &app_state).await.ok(),
+                None => None,
+            };
             Ok(OptionalUser(user))
         }
     }
 }
 
+/// Best-effort client IP for rate limiting. Never rejects.
+///
+/// The socket peer address, unless that peer is one of the configured
+/// `TRUSTED_PROXIES`; then the client named by `X-Forwarded-For` (or
+/// `X-Real-IP`). See [`TrustedProxies::client_ip`].
+pub struct ClientIp(pub Option<String>);
+
+impl<S> axum::extract::FromRequestParts<S> for ClientIp
+where
+    AppState: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = std::convert::Infallible;
+
+    fn from_request_parts(
+        parts: &mut Parts,
+        state: &S,
+    ) -> impl std::future::Future<Output = Result<Self, Self::Rejection>> + Send {
+        let app_state = AppState::from_ref(state);
+        let ip = client_ip(parts, &app_state.deps.trusted_proxies);
+        async move { Ok(ClientIp(ip)) }
+    }
+}
+
+fn client_ip(parts: &Parts, trusted_proxies: &TrustedProxies) -> Option<String> {
+    let header = |name: &str| parts.headers.get(name).and_then(|v| v.to_str().ok());
+    let peer = parts
+        .extensions
+        .get::<axum::extract::ConnectInfo<std::net::SocketAddr>>()
+        .map(|info| info.0.ip());
+    trusted_proxies
+        .client_ip(peer, header("x-forwarded-for"), header("x-real-ip"))
+        .map(|ip| ip.to_string())
+}
+
 // ---------------------------------------------------------------------------
 // Service-client (Root Signal) extractor — spec §14.1.
 // ---------------------------------------------------------------------------
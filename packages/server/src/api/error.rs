@@ -1,4 +1,4 @@
-use axum::http::StatusCode;
+use axum::http::{header, StatusCode};
 use axum::response::{IntoResponse, Response};
 use axum::Json;
 use serde::Serialize;
@@ -145,6 +145,11 @@ pub enum ApiError {
     Conflict(String),
     /// 422 — one or more field-level validation failures.
     Validation(Vec<FieldError>),
+    /// 429 — rate limited; `retry_after_secs` is sent as `Retry-After`.
+    TooManyRequests {
+        message: String,
+        retry_after_secs: u64,
+    },
     Internal(anyhow::Error),
 }
 
@@ -184,6 +189,19 @@ impl IntoResponse for ApiError {
                 })),
             )
                 .into_response(),
+            ApiError::TooManyRequests {
+                message,
+                retry_after_secs,
+            } => (
+                StatusCode::TOO_MANY_REQUESTS,
+                [(header::RETRY_AFTER, retry_after_secs.to_string())],
+                Json(serde_json::json!({
+                    "message": message,
+                    "code": ErrorCode::RateLimited,
+                    "retry_after_secs": retry_after_secs,
+                })),
+            )
+                .into_response(),
             ApiError::Internal(err) => {
                 tracing::error!(error = %err, "Internal server error");
                 (
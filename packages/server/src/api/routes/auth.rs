@@ -3,30 +3,55 @@ use axum::routing::post;
 use axum::{Json, Router};
 use serde::{Deserialize, Serialize};
 
-use crate::api::error::ApiResult;
+use crate::api::auth::{AdminUser, AuthenticatedUser, ClientIp};
+use crate::api::error::{ApiError, ApiResult};
 use crate::api::state::AppState;
 use crate::domains::auth::activities;
-use crate::domains::auth::types::{OtpSent, OtpVerified};
+use crate::domains::auth::activities::otp_limits;
+use crate::domains::auth::models::{
+    AuthSecurityEvent, InvalidIdentifierError, NormalizedIdentifier,
+};
+use crate::domains::auth::types::{OtpSent, OtpVerified, SessionRefreshed};
 
 // --- Request types ---
 
+/// `phone_number` takes a phone number or an email; `identifier` is
+/// accepted as an alias.
 #[derive(Debug, Deserialize)]
 pub struct SendOtpRequest {
+    #[serde(alias = "identifier")]
     pub phone_number: String,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct VerifyOtpRequest {
+    #[serde(alias = "identifier")]
     pub phone_number: String,
     pub code: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct RefreshRequest {
+    pub refresh_token: String,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct LogoutRequest {
     #[allow(dead_code)]
     pub session_token: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct SecurityEventsRequest {
+    pub event_type: Option<String>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ClearLockoutRequest {
+    pub identifier: String,
+}
+
 // --- Response types ---
 
 #[derive(Debug, Serialize)]
@@ -34,35 +59,124 @@ pub struct LogoutResult {
     pub success: bool,
 }
 
+#[derive(Debug, Serialize)]
+pub struct SecurityEventList {
+    pub events: Vec<AuthSecurityEvent>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ClearLockoutResult {
+    pub success: bool,
+}
+
+/// Map OTP errors: rate-limit refusals to 429 with `Retry-After`, malformed
+/// identifiers to 400.
+fn otp_error(e: anyhow::Error) -> ApiError {
+    if e.is::<InvalidIdentifierError>() {
+        return ApiError::BadRequest(e.to_string());
+    }
+    match e.downcast::<activities::RateLimitedError>() {
+        Ok(limited) => {
+            tracing::warn!(scope = %limited.scope, "Auth request rate limited");
+            ApiError::TooManyRequests {
+                message: limited.to_string(),
+                retry_after_secs: limited.retry_after_secs,
+            }
+        }
+        Err(e) => e.into(),
+    }
+}
+
 // --- Handlers ---
 
 async fn send_otp(
     State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
     Json(req): Json<SendOtpRequest>,
 ) -> ApiResult<Json<OtpSent>> {
     tracing::info!(phone_number = %req.phone_number, "Auth/send_otp");
-    let result = activities::send_otp(req.phone_number, &state.deps).await?;
+    let result = activities::send_otp(req.phone_number, client_ip, &state.deps)
+        .await
+        .map_err(otp_error)?;
     Ok(Json(result))
 }
 
 async fn verify_otp(
     State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
     Json(req): Json<VerifyOtpRequest>,
 ) -> ApiResult<Json<OtpVerified>> {
     tracing::info!(phone_number = %req.phone_number, "Auth/verify_otp");
-    let result = activities::verify_otp(req.phone_number, req.code, &state.deps).await?;
+    let result = activities::verify_otp(req.phone_number, req.code, client_ip, &state.deps)
+        .await
+        .map_err(otp_error)?;
     Ok(Json(result))
 }
 
-async fn logout(Json(_req): Json<LogoutRequest>) -> ApiResult<Json<LogoutResult>> {
+async fn refresh(
+    State(state): State<AppState>,
+    Json(req): Json<RefreshRequest>,
+) -> ApiResult<Json<SessionRefreshed>> {
+    match activities::refresh_session(&req.refresh_token, &state.deps).await {
+        Ok(result) => Ok(Json(result)),
+        Err(e) if e.is::<activities::InvalidRefreshTokenError>() => {
+            Err(ApiError::Unauthorized(e.to_string()))
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Revokes the presented access token and its session's refresh token.
+async fn logout(
+    State(state): State<AppState>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Json(_req): Json<LogoutRequest>,
+) -> ApiResult<Json<LogoutResult>> {
+    activities::logout(
+        user.member_id.into_uuid(),
+        &user.jti,
+        user.token_expires_at,
+        user.session_id,
+        &state.deps,
+    )
+    .await?;
     Ok(Json(LogoutResult { success: true }))
 }
 
+/// Recent exhausted budgets and lockouts on the OTP endpoints.
+async fn security_events(
+    State(state): State<AppState>,
+    _user: AdminUser,
+    Json(req): Json<SecurityEventsRequest>,
+) -> ApiResult<Json<SecurityEventList>> {
+    let limit = req.limit.unwrap_or(100).clamp(1, 500);
+    let events =
+        AuthSecurityEvent::list_recent(req.event_type.as_deref(), limit, &state.deps.db_pool)
+            .await?;
+    Ok(Json(SecurityEventList { events }))
+}
+
+/// Lift a verification lockout, e.g. for an editor who mistyped their code.
+async fn clear_lockout(
+    State(state): State<AppState>,
+    AdminUser(user): AdminUser,
+    Json(req): Json<ClearLockoutRequest>,
+) -> ApiResult<Json<ClearLockoutResult>> {
+    let identifier = NormalizedIdentifier::parse(&req.identifier)
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    otp_limits::clear_identifier_lockout(&identifier, state.deps.rate_limiter.as_ref()).await?;
+    tracing::info!(admin = %user.member_id, "Cleared OTP lockout");
+    Ok(Json(ClearLockoutResult { success: true }))
+}
+
 // --- Router ---
 
 pub fn router() -> Router<AppState> {
     Router::new()
         .route("/Auth/send_otp", post(send_otp))
         .route("/Auth/verify_otp", post(verify_otp))
+        .route("/Auth/refresh", post(refresh))
         .route("/Auth/logout", post(logout))
+        .route("/Auth/security_events", post(security_events))
+        .route("/Auth/clear_lockout", post(clear_lockout))
 }
@@ -1,4 +1,4 @@
-use axum::extract::State;
+use axum::extract::{DefaultBodyLimit, State};
 use axum::routing::post;
 use axum::{Json, Router};
 use chrono::NaiveDate;
@@ -7,26 +7,46 @@ use uuid::Uuid;
 
 use std::collections::HashMap;
 
-use crate::api::auth::AdminUser;
+use crate::api::auth::{AuthUser, StaffUser};
 use crate::api::error::{ApiError, ApiResult};
-use crate::api::routes::posts::{load_tags_and_notes, PublicTagResult, UrgentNoteInfo};
 use crate::api::state::AppState;
+use crate::common::auth::{AdminCapability, CountyScope};
 use crate::domains::editions::activities;
+use crate::domains::editions::activities::broadsheet::build_public_broadsheet;
+use crate::domains::editions::activities::county_boundaries::{
+    self, BoundaryLoadSummary, CountyBoundaryError,
+};
+use crate::domains::editions::activities::edition_history::{self, LayoutEdit};
+use crate::domains::editions::activities::template_authoring::{
+    self, TemplateAuthoringError, TemplatePreview,
+};
+use crate::domains::editions::activities::widget_placement::{
+    self, WidgetRuleError, WidgetRulePreview,
+};
+use crate::domains::editions::data::broadsheet::{
+    edition_to_result, section_to_result, CountyResult, EditionResult, EditionSectionResult,
+    PublicBroadsheetResult,
+};
+use crate::domains::editions::data::types::{
+    LayoutOptions, LayoutReport, LayoutTrace, PostTemplateDraft, RowTemplateDraft,
+    WidgetRuleDraft,
+};
 use crate::domains::editions::models::county::County;
 use crate::domains::editions::models::edition::{Edition, EditionFilters};
+use crate::domains::editions::models::edition_event::EditionEvent;
+use crate::domains::editions::models::edition_layout_trace::EditionLayoutTrace;
 use crate::domains::editions::models::edition_row::EditionRow;
 use crate::domains::editions::models::edition_section::EditionSection;
+use crate::domains::editions::models::edition_snapshot::EditionSnapshot;
 use crate::domains::editions::models::edition_slot::EditionSlot;
 use crate::domains::widgets::Widget;
 use crate::domains::editions::models::post_template_config::PostTemplateConfig;
-use crate::domains::editions::models::row_template_config::RowTemplateConfig;
-use crate::domains::editions::models::row_template_slot::RowTemplateSlot;
-use crate::domains::contacts::models::contact::Contact;
-use crate::domains::posts::models::post::Post;
-use crate::domains::posts::models::{
-    PostDatetimeRecord, PostItem, PostLinkRecord, PostMediaRecord, PostMetaRecord,
-    PostPersonRecord, PostScheduleEntry, PostSourceAttr, PostStatusRecord,
+use crate::domains::editions::models::row_template_config::{
+    RowTemplateConfig, RowTemplateWithSlots,
 };
+use crate::domains::editions::models::row_template_slot::RowTemplateSlot;
+use crate::domains::editions::models::widget_placement_rule::WidgetPlacementRule;
+use crate::kernel::work_queue::WorkRequest;
 
 // =============================================================================
 // Request types
@@ -60,6 +80,68 @@ pub struct CurrentEditionRequest {
     pub county_id: Uuid,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PinSlotRequest {
+    pub slot_id: Uuid,
+    pub pinned: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PinRowRequest {
+    pub row_id: Uuid,
+    pub pinned: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PinSectionRequest {
+    pub section_id: Uuid,
+    pub pinned: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EditionHistoryRequest {
+    pub id: Uuid,
+    pub limit: Option<i32>,
+    pub offset: Option<i32>,
+    /// Include each event's before/after layout.
+    #[serde(default)]
+    pub include_layouts: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UndoRedoRequest {
+    pub id: Uuid,
+    /// How many edits to undo or redo (default 1).
+    pub steps: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SchedulePublishRequest {
+    pub id: Uuid,
+    /// RFC 3339, or a local `YYYY-MM-DDTHH:MM` read as America/Chicago.
+    /// Omit (or null) to clear the schedule.
+    pub publish_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BackIssuesRequest {
+    pub county_id: Uuid,
+    /// Earliest period_start to include (YYYY-MM-DD).
+    pub from: Option<String>,
+    /// Latest period_start to include (YYYY-MM-DD).
+    pub to: Option<String>,
+    pub limit: Option<i32>,
+    pub offset: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BackIssueRequest {
+    pub county_id: Uuid,
+    pub period_start: String,
+    /// Defaults to the latest snapshot of the edition.
+    pub version: Option<i32>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct CreateEditionRequest {
     pub county_id: Uuid,
@@ -68,9 +150,23 @@ pub struct CreateEditionRequest {
     pub title: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct GenerateEditionRequest {
     pub id: Uuid,
+    /// Layout strategy and search knobs; omitted fields use the defaults
+    /// (greedy placement).
+    #[serde(default)]
+    pub layout: LayoutOptions,
+    /// Lay out from scratch, dropping editor pins.
+    #[serde(default)]
+    pub ignore_pins: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LayoutTraceRequest {
+    pub id: Uuid,
+    /// Only trace this post ("why didn't X make it?").
+    pub post_id: Option<Uuid>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -89,31 +185,31 @@ pub struct BatchGenerateRequest {
     pub period_end: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct UpdateEditionRowRequest {
     pub row_id: Uuid,
     pub row_template_slug: Option<String>,
     pub sort_order: Option<i32>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct ReorderRowsRequest {
     pub edition_id: Uuid,
     pub row_ids: Vec<Uuid>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct RemovePostFromEditionRequest {
     pub slot_id: Uuid,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct ChangeSlotTemplateRequest {
     pub slot_id: Uuid,
     pub post_template: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct MoveSlotRequest {
     pub slot_id: Uuid,
     pub target_row_id: Uuid,
@@ -124,7 +220,7 @@ pub struct MoveSlotRequest {
     pub sort_order: Option<i32>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct AddPostToEditionRequest {
     pub edition_row_id: Uuid,
     pub post_id: Uuid,
@@ -132,14 +228,14 @@ pub struct AddPostToEditionRequest {
     pub slot_index: i32,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct AddEditionRowRequest {
     pub edition_id: Uuid,
     pub row_template_slug: String,
     pub sort_order: i32,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct DeleteEditionRowRequest {
     pub row_id: Uuid,
 }
@@ -175,7 +271,7 @@ pub struct EditionKanbanStatsRequest {
     pub period_end: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct AddWidgetToEditionRequest {
     pub edition_row_id: Uuid,
     pub widget_id: Uuid,
@@ -183,7 +279,7 @@ pub struct AddWidgetToEditionRequest {
 }
 
 // Section CRUD requests
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct AddSectionRequest {
     pub edition_id: Uuid,
     pub title: String,
@@ -192,7 +288,7 @@ pub struct AddSectionRequest {
     pub sort_order: i32,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct UpdateSectionRequest {
     pub id: Uuid,
     pub title: Option<String>,
@@ -200,18 +296,18 @@ pub struct UpdateSectionRequest {
     pub topic_slug: Option<Option<String>>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct ReorderSectionsRequest {
     pub edition_id: Uuid,
     pub section_ids: Vec<Uuid>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct DeleteSectionRequest {
     pub id: Uuid,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct AssignRowToSectionRequest {
     pub row_id: Uuid,
     pub section_id: Option<Uuid>,
@@ -222,41 +318,90 @@ pub struct AssignRowToSectionRequest {
 // =============================================================================
 
 #[derive(Debug, Serialize)]
-pub struct CountyResult {
-    pub id: Uuid,
-    pub fips_code: String,
-    pub name: String,
-    pub state: String,
-    pub target_content_weight: i32,
-    /// Synthetic row (e.g. Statewide) rather than a real MN county.
-    /// Frontends use this to group/flag pseudo counties in pickers
-    /// and to exclude them from "N of 87 counties" roll-ups.
-    pub is_pseudo: bool,
+pub struct CountyListResult {
+    pub counties: Vec<CountyResult>,
 }
 
 #[derive(Debug, Serialize)]
-pub struct CountyListResult {
-    pub counties: Vec<CountyResult>,
+pub struct GenerateEditionResult {
+    #[serde(flatten)]
+    pub edition: EditionResult,
+    pub layout: Option<LayoutReport>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LayoutTraceResult {
+    pub edition_id: Uuid,
+    pub generated_at: String,
+    /// With `post_id`, `trace.posts` holds at most that post. Empty means it
+    /// wasn't a candidate: outside the county's geography, or inactive and
+    /// unchanged for more than two weeks.
+    pub trace: LayoutTrace,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EditionListResult {
+    pub editions: Vec<EditionResult>,
+    pub total_count: i64,
 }
 
 #[derive(Debug, Serialize)]
-pub struct EditionResult {
+pub struct EditionEventResult {
     pub id: Uuid,
-    pub county_id: Uuid,
-    pub title: Option<String>,
-    pub period_start: String,
-    pub period_end: String,
+    pub op: String,
+    pub actor_id: Option<Uuid>,
+    pub params: serde_json::Value,
+    /// applied | undone | discarded
     pub status: String,
-    pub published_at: Option<String>,
+    /// Layout edits can be undone; lifecycle events can't.
+    pub undoable: bool,
     pub created_at: String,
+    pub reverted_at: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub row_count: Option<i64>,
+    pub before: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Serialize)]
-pub struct EditionListResult {
-    pub editions: Vec<EditionResult>,
-    pub total_count: i64,
+pub struct EditionHistoryResult {
+    pub events: Vec<EditionEventResult>,
+    /// Layout edits available to undo / redo right now.
+    pub undo_depth: usize,
+    pub redo_depth: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UndoRedoResult {
+    pub events: Vec<EditionEventResult>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BackIssueSummaryResult {
+    pub edition_id: Uuid,
+    pub title: Option<String>,
+    pub period_start: String,
+    pub period_end: String,
+    pub version: i32,
+    pub content_hash: String,
+    pub published_at: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BackIssueListResult {
+    pub back_issues: Vec<BackIssueSummaryResult>,
+}
+
+/// A back issue as frozen at publish time. `broadsheet` has the same shape
+/// as the `/Public/current_broadsheet` response.
+#[derive(Debug, Serialize)]
+pub struct BackIssueResult {
+    pub edition_id: Uuid,
+    pub version: i32,
+    pub content_hash: String,
+    pub published_at: String,
+    pub frozen_at: String,
+    pub broadsheet: serde_json::Value,
 }
 
 #[derive(Debug, Serialize)]
@@ -278,6 +423,7 @@ pub struct EditionRowResult {
     pub sort_order: i32,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub section_id: Option<Uuid>,
+    pub pinned: bool,
     pub slots: Vec<EditionSlotResult>,
 }
 
@@ -287,6 +433,7 @@ pub struct EditionSlotResult {
     pub kind: String,
     pub slot_index: i32,
     pub sort_order: i32,
+    pub pinned: bool,
     // Post fields (present when kind='post')
     #[serde(skip_serializing_if = "Option::is_none")]
     pub post_id: Option<Uuid>,
@@ -326,6 +473,7 @@ pub struct RowTemplateResult {
     pub display_name: String,
     pub description: Option<String>,
     pub layout_variant: String,
+    pub sort_order: i32,
     pub slots: Vec<RowTemplateSlotResult>,
 }
 
@@ -334,6 +482,8 @@ pub struct RowTemplateSlotResult {
     pub slot_index: i32,
     pub weight: String,
     pub count: i32,
+    pub count_min: i32,
+    pub count_max: i32,
     pub accepts: Option<Vec<String>>,
     pub post_template_slug: Option<String>,
 }
@@ -353,6 +503,10 @@ pub struct PostTemplateResult {
     pub body_max: i32,
     pub title_max: i32,
     pub weight: String,
+    pub description: Option<String>,
+    pub sort_order: i32,
+    pub height_units: i32,
+    pub height_override: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Serialize)]
@@ -398,211 +552,10 @@ pub struct EditionKanbanStatsResult {
     pub published: i32,
 }
 
-// =============================================================================
-// Public broadsheet result types (unauthenticated, full post data)
-// =============================================================================
-
-#[derive(Debug, Serialize)]
-pub struct PublicBroadsheetResult {
-    pub edition: EditionResult,
-    pub county: CountyResult,
-    pub rows: Vec<PublicBroadsheetRowResult>,
-    pub sections: Vec<EditionSectionResult>,
-}
-
-#[derive(Debug, Serialize)]
-pub struct PublicBroadsheetRowResult {
-    pub row_template_slug: String,
-    pub layout_variant: String,
-    pub sort_order: i32,
-    pub section_id: Option<Uuid>,
-    pub slots: Vec<PublicBroadsheetSlotResult>,
-}
-
-#[derive(Debug, Serialize)]
-pub struct PublicBroadsheetSlotResult {
-    pub kind: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub post_template: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub widget_template: Option<String>,
-    pub slot_index: i32,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub post: Option<PublicBroadsheetPostResult>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub widget: Option<PublicBroadsheetWidgetResult>,
-}
-
-#[derive(Debug, Serialize)]
-pub struct PublicBroadsheetWidgetResult {
-    pub id: Uuid,
-    pub widget_type: String,
-    pub authoring_mode: String,
-    pub data: serde_json::Value,
-}
-
-#[derive(Debug, Serialize)]
-pub struct PublicBroadsheetPostResult {
-    pub id: Uuid,
-    pub title: String,
-    pub body_raw: String,
-    pub post_type: String,
-    pub weight: String,
-    pub is_urgent: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub pencil_mark: Option<String>,
-    pub location: Option<String>,
-    pub organization_name: Option<String>,
-    pub published_at: Option<String>,
-    pub tags: Vec<PublicTagResult>,
-    pub contacts: Vec<BroadsheetContactResult>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
-    pub urgent_notes: Vec<UrgentNoteInfo>,
-    // Weight-specific body text from Root Signal
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub body_heavy: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub body_medium: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub body_light: Option<String>,
-    // Field groups
-    #[serde(skip_serializing_if = "Vec::is_empty")]
-    pub media: Vec<BroadsheetMediaResult>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
-    pub items: Vec<BroadsheetItemResult>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub person: Option<BroadsheetPersonResult>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub link: Option<BroadsheetLinkResult>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub source_attribution: Option<BroadsheetSourceAttributionResult>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub meta: Option<BroadsheetMetaResult>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub datetime: Option<BroadsheetDatetimeResult>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub post_status: Option<BroadsheetStatusResult>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
-    pub schedule: Vec<BroadsheetScheduleEntryResult>,
-}
-
-#[derive(Debug, Clone, Serialize)]
-pub struct BroadsheetContactResult {
-    pub contact_type: String,
-    pub contact_value: String,
-    pub contact_label: Option<String>,
-}
-
-// =============================================================================
-// Field group result types
-// =============================================================================
-
-#[derive(Debug, Serialize)]
-pub struct BroadsheetMediaResult {
-    pub image_url: Option<String>,
-    pub caption: Option<String>,
-    pub credit: Option<String>,
-}
-
-#[derive(Debug, Serialize)]
-pub struct BroadsheetItemResult {
-    pub name: String,
-    pub detail: Option<String>,
-}
-
-#[derive(Debug, Serialize)]
-pub struct BroadsheetPersonResult {
-    pub name: Option<String>,
-    pub role: Option<String>,
-    pub bio: Option<String>,
-    pub photo_url: Option<String>,
-    pub quote: Option<String>,
-}
-
-#[derive(Debug, Serialize)]
-pub struct BroadsheetLinkResult {
-    pub label: Option<String>,
-    pub url: Option<String>,
-    pub deadline: Option<String>,
-}
-
-#[derive(Debug, Serialize)]
-pub struct BroadsheetSourceAttributionResult {
-    pub source_name: Option<String>,
-    pub attribution: Option<String>,
-}
-
-#[derive(Debug, Serialize)]
-pub struct BroadsheetMetaResult {
-    pub kicker: Option<String>,
-    pub byline: Option<String>,
-    pub timestamp: Option<String>,
-    pub updated: Option<String>,
-    pub deck: Option<String>,
-}
-
-#[derive(Debug, Serialize)]
-pub struct BroadsheetDatetimeResult {
-    pub start: Option<String>,
-    pub end: Option<String>,
-    pub cost: Option<String>,
-    pub recurring: bool,
-}
-
-#[derive(Debug, Serialize)]
-pub struct BroadsheetStatusResult {
-    pub state: Option<String>,
-    pub verified: Option<String>,
-}
-
-#[derive(Debug, Serialize)]
-pub struct BroadsheetScheduleEntryResult {
-    pub day: String,
-    pub opens: String,
-    pub closes: String,
-}
-
-#[derive(Debug, Serialize)]
-pub struct EditionSectionResult {
-    pub id: Uuid,
-    pub edition_id: Uuid,
-    pub title: String,
-    pub subtitle: Option<String>,
-    pub topic_slug: Option<String>,
-    pub sort_order: i32,
-    pub created_at: String,
-}
-
-fn section_to_result(s: &EditionSection) -> EditionSectionResult {
-    EditionSectionResult {
-        id: s.id,
-        edition_id: s.edition_id,
-        title: s.title.clone(),
-        subtitle: s.subtitle.clone(),
-        topic_slug: s.topic_slug.clone(),
-        sort_order: s.sort_order,
-        created_at: s.created_at.to_rfc3339(),
-    }
-}
-
 // =============================================================================
 // Helpers
 // =============================================================================
 
-fn edition_to_result(e: &Edition) -> EditionResult {
-    EditionResult {
-        id: e.id,
-        county_id: e.county_id,
-        title: e.title.clone(),
-        period_start: e.period_start.to_string(),
-        period_end: e.period_end.to_string(),
-        status: e.status.clone(),
-        published_at: e.published_at.map(|t| t.to_rfc3339()),
-        created_at: e.created_at.to_rfc3339(),
-        row_count: None,
-    }
-}
-
 async fn load_edition_detail(
     edition: &Edition,
     pool: &sqlx::PgPool,
@@ -621,13 +574,7 @@ async fn load_edition_detail(
         let template_slot_results: Vec<RowTemplateSlotResult> = all_template_slots
             .iter()
             .filter(|s| s.row_template_config_id == row.row_template_config_id)
-            .map(|s| RowTemplateSlotResult {
-                slot_index: s.slot_index,
-                weight: s.weight.clone(),
-                count: s.count,
-                accepts: s.accepts.clone(),
-                post_template_slug: s.post_template_slug.clone(),
-            })
+            .map(row_template_slot_result)
             .collect();
 
         let slots = EditionSlot::find_by_row_with_content(row.id, pool).await?;
@@ -644,6 +591,7 @@ async fn load_edition_detail(
             row_template_slots: template_slot_results,
             sort_order: row.sort_order,
             section_id: row.section_id,
+            pinned: row.pinned,
             slots: slots
                 .iter()
                 .map(|s| EditionSlotResult {
@@ -651,6 +599,7 @@ async fn load_edition_detail(
                     kind: s.kind.clone(),
                     slot_index: s.slot_index,
                     sort_order: s.sort_order,
+                    pinned: s.pinned,
                     post_id: s.post_id,
                     post_template: s.post_template.clone(),
                     post_title: s.post_title.clone(),
@@ -707,16 +656,11 @@ async fn build_row_result(
         row_template_description: template.and_then(|t| t.description),
         row_template_slots: template_slots
             .iter()
-            .map(|s| RowTemplateSlotResult {
-                slot_index: s.slot_index,
-                weight: s.weight.clone(),
-                count: s.count,
-                accepts: s.accepts.clone(),
-                post_template_slug: s.post_template_slug.clone(),
-            })
+            .map(row_template_slot_result)
             .collect(),
         sort_order: row.sort_order,
         section_id: row.section_id,
+        pinned: row.pinned,
         slots: slots
             .iter()
             .map(|s| EditionSlotResult {
@@ -724,6 +668,7 @@ async fn build_row_result(
                 kind: s.kind.clone(),
                 slot_index: s.slot_index,
                 sort_order: s.sort_order,
+                pinned: s.pinned,
                 post_id: s.post_id,
                 post_template: s.post_template.clone(),
                 post_title: s.post_title.clone(),
@@ -755,6 +700,7 @@ async fn slot_with_content_data(
             kind: s.kind,
             slot_index: s.slot_index,
             sort_order: s.sort_order,
+            pinned: s.pinned,
             post_id: s.post_id,
             post_template: s.post_template,
             post_title: s.post_title,
@@ -773,6 +719,7 @@ async fn slot_with_content_data(
             kind: slot.kind.clone(),
             slot_index: slot.slot_index,
             sort_order: slot.sort_order,
+            pinned: slot.pinned,
             post_id: slot.post_id,
             post_template: slot.post_template.clone(),
             post_title: None,
@@ -798,6 +745,95 @@ fn parse_date(s: &str, field: &str) -> ApiResult<NaiveDate> {
 // Handlers
 // =============================================================================
 
+/// What a layout handler acts on; resolved to the owning edition's county
+/// for capability checks.
+#[derive(Debug, Clone, Copy)]
+enum EditionTarget {
+    Edition(Uuid),
+    Row(Uuid),
+    Slot(Uuid),
+    Section(Uuid),
+}
+
+/// Require `capability` for the county that owns `target`. Full admins skip
+/// the lookup; a target that doesn't exist is a 404.
+async fn require_edition_capability(
+    user: &AuthUser,
+    capability: AdminCapability,
+    target: EditionTarget,
+    state: &AppState,
+) -> ApiResult<()> {
+    if user.is_admin {
+        return Ok(());
+    }
+    let pool = &state.deps.db_pool;
+    let county_id = match target {
+        EditionTarget::Edition(id) => Edition::find_county_id(id, pool).await?,
+        EditionTarget::Row(id) => Edition::find_county_id_by_row(id, pool).await?,
+        EditionTarget::Slot(id) => Edition::find_county_id_by_slot(id, pool).await?,
+        EditionTarget::Section(id) => Edition::find_county_id_by_section(id, pool).await?,
+    }
+    .ok_or_else(|| ApiError::NotFound("Edition not found".into()))?;
+    user.require(capability, CountyScope::County(county_id))
+}
+
+/// Start recording a layout edit on the edition that owns `target`.
+async fn begin_edit(target: EditionTarget, state: &AppState) -> ApiResult<LayoutEdit> {
+    let pool = &state.deps.db_pool;
+    let edition_id = match target {
+        EditionTarget::Edition(id) => Some(id),
+        EditionTarget::Row(id) => EditionRow::find_by_id(id, pool).await?.map(|r| r.edition_id),
+        EditionTarget::Slot(id) => Edition::find_id_by_slot(id, pool).await?,
+        EditionTarget::Section(id) => {
+            EditionSection::find_by_id(id, pool).await?.map(|s| s.edition_id)
+        }
+    }
+    .ok_or_else(|| ApiError::NotFound("Edition not found".into()))?;
+    Ok(LayoutEdit::begin(edition_id, &state.deps).await?)
+}
+
+/// Request payload stored on an edition event.
+fn event_params<T: Serialize>(req: &T) -> serde_json::Value {
+    serde_json::to_value(req).unwrap_or_default()
+}
+
+fn actor(user: &AuthUser) -> Option<Uuid> {
+    Some(user.member_id.into_uuid())
+}
+
+/// Record a status change made by `user` in the edition's history.
+async fn record_status_change(
+    edition: &Edition,
+    user: &AuthUser,
+    op: &str,
+    state: &AppState,
+) -> ApiResult<()> {
+    activities::record_lifecycle(
+        edition.id,
+        actor(user),
+        op,
+        serde_json::json!({ "status": edition.status }),
+        &state.deps,
+    )
+    .await?;
+    Ok(())
+}
+
+fn event_to_result(e: EditionEvent, include_layouts: bool) -> EditionEventResult {
+    EditionEventResult {
+        id: e.id,
+        op: e.op,
+        actor_id: e.actor_id,
+        params: e.params,
+        status: e.status,
+        undoable: e.before.is_some(),
+        created_at: e.created_at.to_rfc3339(),
+        reverted_at: e.reverted_at.map(|t| t.to_rfc3339()),
+        before: e.before.filter(|_| include_layouts),
+        after: e.after.filter(|_| include_layouts),
+    }
+}
+
 async fn list_counties(
     State(state): State<AppState>,
     // Public: the county list drives the public-site county picker.
@@ -825,9 +861,10 @@ async fn list_counties(
 
 async fn get_county(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(req): Json<GetCountyRequest>,
 ) -> ApiResult<Json<CountyResult>> {
+    user.require(AdminCapability::ViewEditions, CountyScope::County(req.id))?;
     let county = County::find_by_id(req.id, &state.deps.db_pool)
         .await?
         .ok_or_else(|| ApiError::NotFound(format!("County not found: {}", req.id)))?;
@@ -848,35 +885,107 @@ pub struct UpdateCountyTargetWeightRequest {
     pub target_content_weight: i32,
 }
 
-async fn update_county_target_weight(
-    State(state): State<AppState>,
-    _user: AdminUser,
-    Json(req): Json<UpdateCountyTargetWeightRequest>,
-) -> ApiResult<Json<CountyResult>> {
-    if req.target_content_weight <= 0 {
-        return Err(ApiError::BadRequest(
-            "target_content_weight must be positive".to_string(),
-        ));
-    }
-    let county =
-        County::update_target_content_weight(req.id, req.target_content_weight, &state.deps.db_pool)
-            .await?;
+/// Body of `/Editions/load_county_boundaries`: a GeoJSON FeatureCollection
+/// with one Polygon/MultiPolygon feature per county, keyed by FIPS.
+#[derive(Debug, Deserialize)]
+pub struct LoadCountyBoundariesRequest {
+    pub geojson: serde_json::Value,
+    /// Where the boundaries came from (e.g. "MnGeo county boundaries 2024").
+    pub source: Option<String>,
+}
 
-    Ok(Json(CountyResult {
-        id: county.id,
-        fips_code: county.fips_code,
-        name: county.name,
-        state: county.state,
-        target_content_weight: county.target_content_weight,
-        is_pseudo: county.is_pseudo,
+#[derive(Debug, Serialize)]
+pub struct LoadCountyBoundariesResult {
+    #[serde(flatten)]
+    pub boundaries: BoundaryLoadSummary,
+    /// Whether a re-resolution of every post's county was queued (false
+    /// when one is already pending).
+    pub posts_queued: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ResolvePostCountiesResult {
+    /// False when a re-resolution is already pending.
+    pub queued: bool,
+}
+
+/// Boundary files run to several MB; the default 2MB JSON limit is too small.
+const COUNTY_BOUNDARIES_BODY_LIMIT: usize = 32 * 1024 * 1024;
+
+async fn load_county_boundaries(
+    State(state): State<AppState>,
+    StaffUser(user): StaffUser,
+    Json(req): Json<LoadCountyBoundariesRequest>,
+) -> ApiResult<Json<LoadCountyBoundariesResult>> {
+    user.require(AdminCapability::ManageCounties, CountyScope::STATEWIDE)?;
+    let pool = &state.deps.db_pool;
+    let boundaries =
+        county_boundaries::load_boundaries(&req.geojson, req.source.as_deref(), pool)
+            .await
+            .map_err(|e| match e {
+                CountyBoundaryError::Invalid(msg) => ApiError::BadRequest(msg),
+                CountyBoundaryError::Db(e) => ApiError::Internal(e),
+            })?;
+    // Every post's county may have moved with the new boundaries; the
+    // work queue re-resolves them in the background.
+    let posts_queued = WorkRequest::ResolvePostCounties
+        .enqueue(pool)
+        .await?
+        .is_some();
+
+    Ok(Json(LoadCountyBoundariesResult {
+        boundaries,
+        posts_queued,
+    }))
+}
+
+/// Queue a re-resolution of every post's county against the stored
+/// boundaries (ZIP fallback). For posts whose location changed outside
+/// ingest and admin edits.
+async fn resolve_post_counties(
+    State(state): State<AppState>,
+    StaffUser(user): StaffUser,
+    Json(_req): Json<EmptyRequest>,
+) -> ApiResult<Json<ResolvePostCountiesResult>> {
+    user.require(AdminCapability::ManageCounties, CountyScope::STATEWIDE)?;
+    let queued = WorkRequest::ResolvePostCounties
+        .enqueue(&state.deps.db_pool)
+        .await?
+        .is_some();
+    Ok(Json(ResolvePostCountiesResult { queued }))
+}
+
+async fn update_county_target_weight(
+    State(state): State<AppState>,
+    StaffUser(user): StaffUser,
+    Json(req): Json<UpdateCountyTargetWeightRequest>,
+) -> ApiResult<Json<CountyResult>> {
+    user.require(AdminCapability::ManageCounties, CountyScope::County(req.id))?;
+    if req.target_content_weight <= 0 {
+        return Err(ApiError::BadRequest(
+            "target_content_weight must be positive".to_string(),
+        ));
+    }
+    let county =
+        County::update_target_content_weight(req.id, req.target_content_weight, &state.deps.db_pool)
+            .await?;
+
+    Ok(Json(CountyResult {
+        id: county.id,
+        fips_code: county.fips_code,
+        name: county.name,
+        state: county.state,
+        target_content_weight: county.target_content_weight,
+        is_pseudo: county.is_pseudo,
     }))
 }
 
 async fn list_editions(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(req): Json<ListEditionsRequest>,
 ) -> ApiResult<Json<EditionListResult>> {
+    let county_ids = user.county_filter(AdminCapability::ViewEditions, req.county_id)?;
     let period_start = req
         .period_start
         .as_deref()
@@ -890,6 +999,7 @@ async fn list_editions(
 
     let filters = EditionFilters {
         county_id: req.county_id,
+        county_ids,
         status: req.status,
         period_start,
         period_end,
@@ -907,10 +1017,12 @@ async fn list_editions(
 
 async fn latest_editions(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(_req): Json<EmptyRequest>,
 ) -> ApiResult<Json<EditionListResult>> {
-    let editions = Edition::latest_per_county(&state.deps.db_pool).await?;
+    let county_ids = user.county_filter(AdminCapability::ViewEditions, None)?;
+    let editions =
+        Edition::latest_per_county(county_ids.as_deref(), &state.deps.db_pool).await?;
     let total_count = editions.len() as i64;
 
     // Batch-load row counts for all editions (single query)
@@ -935,9 +1047,16 @@ async fn latest_editions(
 
 async fn get_edition(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(req): Json<GetEditionRequest>,
 ) -> ApiResult<Json<EditionDetailResult>> {
+    require_edition_capability(
+        &user,
+        AdminCapability::ViewEditions,
+        EditionTarget::Edition(req.id),
+        &state,
+    )
+    .await?;
     let edition = Edition::find_by_id(req.id, &state.deps.db_pool)
         .await?
         .ok_or_else(|| ApiError::NotFound(format!("Edition not found: {}", req.id)))?;
@@ -948,9 +1067,10 @@ async fn get_edition(
 
 async fn current_edition(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(req): Json<CurrentEditionRequest>,
 ) -> ApiResult<Json<EditionDetailResult>> {
+    user.require(AdminCapability::ViewEditions, CountyScope::County(req.county_id))?;
     let edition = Edition::find_published(req.county_id, &state.deps.db_pool)
         .await?
         .ok_or_else(|| {
@@ -966,9 +1086,10 @@ async fn current_edition(
 
 async fn create_edition(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(req): Json<CreateEditionRequest>,
 ) -> ApiResult<Json<EditionResult>> {
+    user.require(AdminCapability::EditEditions, CountyScope::County(req.county_id))?;
     let period_start = parse_date(&req.period_start, "period_start")?;
     let period_end = parse_date(&req.period_end, "period_end")?;
 
@@ -981,41 +1102,144 @@ async fn create_edition(
     )
     .await?;
 
+    record_status_change(&edition, &user, "create_edition", &state).await?;
     Ok(Json(edition_to_result(&edition)))
 }
 
 async fn generate_edition(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(req): Json<GenerateEditionRequest>,
-) -> ApiResult<Json<EditionResult>> {
-    let edition = activities::generate_edition(req.id, &state.deps).await?;
-    Ok(Json(edition_to_result(&edition)))
+) -> ApiResult<Json<GenerateEditionResult>> {
+    require_edition_capability(
+        &user,
+        AdminCapability::EditEditions,
+        EditionTarget::Edition(req.id),
+        &state,
+    )
+    .await?;
+    let mut edit = begin_edit(EditionTarget::Edition(req.id), &state).await?;
+    let params = event_params(&req);
+    let (edition, layout) = activities::generate_edition_with_options(
+        req.id,
+        &req.layout,
+        !req.ignore_pins,
+        edit.conn(),
+        &state.deps,
+    )
+    .await?;
+    edit.commit(actor(&user), "generate_edition", params)
+        .await?;
+    Ok(Json(GenerateEditionResult {
+        edition: edition_to_result(&edition),
+        layout,
+    }))
+}
+
+async fn layout_trace(
+    State(state): State<AppState>,
+    StaffUser(user): StaffUser,
+    Json(req): Json<LayoutTraceRequest>,
+) -> ApiResult<Json<LayoutTraceResult>> {
+    require_edition_capability(
+        &user,
+        AdminCapability::ViewEditions,
+        EditionTarget::Edition(req.id),
+        &state,
+    )
+    .await?;
+    let stored = EditionLayoutTrace::find_by_edition(req.id, &state.deps.db_pool)
+        .await?
+        .ok_or_else(|| {
+            ApiError::NotFound(format!(
+                "No layout trace for edition {}; regenerate it to record one",
+                req.id
+            ))
+        })?;
+
+    let mut trace = stored.parsed()?;
+    if let Some(post_id) = req.post_id {
+        trace.posts.retain(|p| p.post_id == post_id);
+    }
+
+    Ok(Json(LayoutTraceResult {
+        edition_id: stored.edition_id,
+        generated_at: stored.generated_at.to_rfc3339(),
+        trace,
+    }))
 }
 
 async fn publish_edition(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(req): Json<PublishEditionRequest>,
 ) -> ApiResult<Json<EditionResult>> {
+    require_edition_capability(
+        &user,
+        AdminCapability::PublishEditions,
+        EditionTarget::Edition(req.id),
+        &state,
+    )
+    .await?;
     let edition = activities::publish_edition(req.id, &state.deps).await?;
+    record_status_change(&edition, &user, "publish_edition", &state).await?;
+    Ok(Json(edition_to_result(&edition)))
+}
+
+async fn schedule_publish(
+    State(state): State<AppState>,
+    StaffUser(user): StaffUser,
+    Json(req): Json<SchedulePublishRequest>,
+) -> ApiResult<Json<EditionResult>> {
+    require_edition_capability(
+        &user,
+        AdminCapability::PublishEditions,
+        EditionTarget::Edition(req.id),
+        &state,
+    )
+    .await?;
+    let publish_at = req
+        .publish_at
+        .as_deref()
+        .map(activities::parse_publish_at)
+        .transpose()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    let edition = activities::schedule_edition_publish(req.id, publish_at, &state.deps).await?;
+    activities::record_lifecycle(
+        edition.id,
+        actor(&user),
+        "schedule_publish",
+        serde_json::json!({ "publish_at": edition.publish_at }),
+        &state.deps,
+    )
+    .await?;
     Ok(Json(edition_to_result(&edition)))
 }
 
 async fn archive_edition(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(req): Json<ArchiveEditionRequest>,
 ) -> ApiResult<Json<EditionResult>> {
+    require_edition_capability(
+        &user,
+        AdminCapability::PublishEditions,
+        EditionTarget::Edition(req.id),
+        &state,
+    )
+    .await?;
     let edition = activities::archive_edition(req.id, &state.deps).await?;
+    record_status_change(&edition, &user, "archive_edition", &state).await?;
     Ok(Json(edition_to_result(&edition)))
 }
 
 async fn batch_generate(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(req): Json<BatchGenerateRequest>,
 ) -> ApiResult<Json<BatchGenerateEditionsResult>> {
+    // Generates every county's edition for the period.
+    user.require(AdminCapability::EditEditions, CountyScope::STATEWIDE)?;
     let period_start = parse_date(&req.period_start, "period_start")?;
     let period_end = parse_date(&req.period_end, "period_end")?;
 
@@ -1033,70 +1257,366 @@ async fn batch_generate(
 
 async fn row_templates(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(_req): Json<EmptyRequest>,
 ) -> ApiResult<Json<RowTemplateListResult>> {
-    let pool = &state.deps.db_pool;
-    let configs = RowTemplateConfig::find_all(pool).await?;
-    let all_slots = RowTemplateSlot::find_all(pool).await?;
+    user.require(AdminCapability::ViewEditions, CountyScope::Any)?;
+    let templates = RowTemplateConfig::find_all_with_slots(&state.deps.db_pool).await?;
 
-    let templates = configs
-        .into_iter()
-        .map(|c| {
-            let slots: Vec<RowTemplateSlotResult> = all_slots
-                .iter()
-                .filter(|s| s.row_template_config_id == c.id)
-                .map(|s| RowTemplateSlotResult {
-                    slot_index: s.slot_index,
-                    weight: s.weight.clone(),
-                    count: s.count,
-                    accepts: s.accepts.clone(),
-                    post_template_slug: s.post_template_slug.clone(),
-                })
-                .collect();
-            RowTemplateResult {
-                id: c.id,
-                slug: c.slug,
-                display_name: c.display_name,
-                description: c.description,
-                layout_variant: c.layout_variant,
-                slots,
-            }
-        })
-        .collect();
+    Ok(Json(RowTemplateListResult {
+        templates: templates.into_iter().map(row_template_result).collect(),
+    }))
+}
 
-    Ok(Json(RowTemplateListResult { templates }))
+fn row_template_result(t: RowTemplateWithSlots) -> RowTemplateResult {
+    RowTemplateResult {
+        id: t.config.id,
+        slug: t.config.slug,
+        display_name: t.config.display_name,
+        description: t.config.description,
+        layout_variant: t.config.layout_variant,
+        sort_order: t.config.sort_order,
+        slots: t
+            .slots
+            .iter()
+            .map(row_template_slot_result)
+            .collect(),
+    }
+}
+
+fn row_template_slot_result(s: &RowTemplateSlot) -> RowTemplateSlotResult {
+    RowTemplateSlotResult {
+        slot_index: s.slot_index,
+        weight: s.weight.clone(),
+        count: s.count,
+        count_min: s.count_min,
+        count_max: s.count_max,
+        accepts: s.accepts.clone(),
+        post_template_slug: s.post_template_slug.clone(),
+    }
 }
 
 async fn post_templates(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(_req): Json<EmptyRequest>,
 ) -> ApiResult<Json<PostTemplateListResult>> {
+    user.require(AdminCapability::ViewEditions, CountyScope::Any)?;
     let configs = PostTemplateConfig::find_all(&state.deps.db_pool).await?;
 
     Ok(Json(PostTemplateListResult {
-        templates: configs
-            .into_iter()
-            .map(|c| PostTemplateResult {
-                id: c.id,
-                slug: c.slug,
-                display_name: c.display_name,
-                compatible_types: c.compatible_types,
-                body_target: c.body_target,
-                body_max: c.body_max,
-                title_max: c.title_max,
-                weight: c.weight,
-            })
-            .collect(),
+        templates: configs.into_iter().map(post_template_result).collect(),
+    }))
+}
+
+fn post_template_result(c: PostTemplateConfig) -> PostTemplateResult {
+    PostTemplateResult {
+        id: c.id,
+        slug: c.slug,
+        display_name: c.display_name,
+        compatible_types: c.compatible_types,
+        body_target: c.body_target,
+        body_max: c.body_max,
+        title_max: c.title_max,
+        weight: c.weight,
+        description: c.description,
+        sort_order: c.sort_order,
+        height_units: c.height_units,
+        height_override: c.height_override,
+    }
+}
+
+// =============================================================================
+// Template authoring (statewide editors)
+// =============================================================================
+
+/// Create (`id` absent) or replace a row template. With `dry_run`, nothing
+/// is saved: the proposal is validated and `county_id`'s pool is laid out
+/// with it instead.
+#[derive(Debug, Deserialize)]
+pub struct SaveRowTemplateRequest {
+    pub id: Option<Uuid>,
+    pub template: RowTemplateDraft,
+    #[serde(default)]
+    pub dry_run: bool,
+    pub county_id: Option<Uuid>,
+    /// Edition period to sample posts for (YYYY-MM-DD). Defaults to today.
+    pub period_start: Option<String>,
+}
+
+/// Create (`id` absent) or replace a post template; `dry_run` as for
+/// `SaveRowTemplateRequest`.
+#[derive(Debug, Deserialize)]
+pub struct SavePostTemplateRequest {
+    pub id: Option<Uuid>,
+    pub template: PostTemplateDraft,
+    #[serde(default)]
+    pub dry_run: bool,
+    pub county_id: Option<Uuid>,
+    pub period_start: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DeleteTemplateRequest {
+    pub id: Uuid,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SaveRowTemplateResult {
+    /// The stored template; None on a dry run.
+    pub template: Option<RowTemplateResult>,
+    pub preview: Option<TemplatePreview>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SavePostTemplateResult {
+    pub template: Option<PostTemplateResult>,
+    pub preview: Option<TemplatePreview>,
+}
+
+impl From<TemplateAuthoringError> for ApiError {
+    fn from(e: TemplateAuthoringError) -> Self {
+        match e {
+            TemplateAuthoringError::Invalid(msg) => ApiError::BadRequest(msg),
+            TemplateAuthoringError::InUse(msg) => ApiError::Conflict(msg),
+            TemplateAuthoringError::NotFound(msg) => ApiError::NotFound(msg),
+            TemplateAuthoringError::Db(e) => ApiError::Internal(e),
+        }
+    }
+}
+
+/// County and period for a dry run.
+fn dry_run_target(
+    county_id: Option<Uuid>,
+    period_start: Option<&str>,
+) -> ApiResult<(Uuid, NaiveDate)> {
+    let county_id = county_id
+        .ok_or_else(|| ApiError::BadRequest("county_id is required for a dry run".into()))?;
+    let period_start = match period_start {
+        Some(s) => parse_date(s, "period_start")?,
+        None => chrono::Utc::now().date_naive(),
+    };
+    Ok((county_id, period_start))
+}
+
+async fn save_row_template(
+    State(state): State<AppState>,
+    StaffUser(user): StaffUser,
+    Json(req): Json<SaveRowTemplateRequest>,
+) -> ApiResult<Json<SaveRowTemplateResult>> {
+    user.require(AdminCapability::EditEditions, CountyScope::STATEWIDE)?;
+    let pool = &state.deps.db_pool;
+
+    if req.dry_run {
+        let (county_id, period_start) =
+            dry_run_target(req.county_id, req.period_start.as_deref())?;
+        let proposed = template_authoring::check_row_template(req.id, &req.template, pool).await?;
+        let preview =
+            template_authoring::preview(&proposed, county_id, period_start, &state.deps).await?;
+        return Ok(Json(SaveRowTemplateResult {
+            template: None,
+            preview: Some(preview),
+        }));
+    }
+
+    let saved = template_authoring::save_row_template(req.id, &req.template, pool).await?;
+    Ok(Json(SaveRowTemplateResult {
+        template: Some(row_template_result(saved)),
+        preview: None,
+    }))
+}
+
+async fn save_post_template(
+    State(state): State<AppState>,
+    StaffUser(user): StaffUser,
+    Json(req): Json<SavePostTemplateRequest>,
+) -> ApiResult<Json<SavePostTemplateResult>> {
+    user.require(AdminCapability::EditEditions, CountyScope::STATEWIDE)?;
+    let pool = &state.deps.db_pool;
+
+    if req.dry_run {
+        let (county_id, period_start) =
+            dry_run_target(req.county_id, req.period_start.as_deref())?;
+        let proposed =
+            template_authoring::check_post_template(req.id, &req.template, pool).await?;
+        let preview =
+            template_authoring::preview(&proposed, county_id, period_start, &state.deps).await?;
+        return Ok(Json(SavePostTemplateResult {
+            template: None,
+            preview: Some(preview),
+        }));
+    }
+
+    let saved = template_authoring::save_post_template(req.id, &req.template, pool).await?;
+    Ok(Json(SavePostTemplateResult {
+        template: Some(post_template_result(saved)),
+        preview: None,
+    }))
+}
+
+async fn delete_row_template(
+    State(state): State<AppState>,
+    StaffUser(user): StaffUser,
+    Json(req): Json<DeleteTemplateRequest>,
+) -> ApiResult<Json<serde_json::Value>> {
+    user.require(AdminCapability::EditEditions, CountyScope::STATEWIDE)?;
+    template_authoring::delete_row_template(req.id, &state.deps.db_pool).await?;
+    Ok(Json(serde_json::json!({ "deleted": true })))
+}
+
+async fn delete_post_template(
+    State(state): State<AppState>,
+    StaffUser(user): StaffUser,
+    Json(req): Json<DeleteTemplateRequest>,
+) -> ApiResult<Json<serde_json::Value>> {
+    user.require(AdminCapability::EditEditions, CountyScope::STATEWIDE)?;
+    template_authoring::delete_post_template(req.id, &state.deps.db_pool).await?;
+    Ok(Json(serde_json::json!({ "deleted": true })))
+}
+
+// =============================================================================
+// Widget placement rules
+// =============================================================================
+
+/// `county_id` absent means the global rule set.
+#[derive(Debug, Deserialize)]
+pub struct WidgetRulesRequest {
+    pub county_id: Option<Uuid>,
+}
+
+/// Replace a scope's rules. With `dry_run`, nothing is saved: the rules lay
+/// out `preview_county_id`'s pool instead (defaults to `county_id`).
+#[derive(Debug, Deserialize)]
+pub struct SaveWidgetRulesRequest {
+    pub county_id: Option<Uuid>,
+    pub rules: Vec<WidgetRuleDraft>,
+    #[serde(default)]
+    pub dry_run: bool,
+    pub preview_county_id: Option<Uuid>,
+    /// Edition period to sample posts for (YYYY-MM-DD). Defaults to today.
+    pub period_start: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WidgetRuleResult {
+    pub id: Uuid,
+    pub sort_order: i32,
+    pub after_row: i32,
+    pub widget_type: String,
+    pub count: i32,
+    pub min_rows: i32,
+    pub rows_after: i32,
+    pub min_gap: i32,
+    pub at_section_start: bool,
+    pub enabled: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WidgetRulesResult {
+    pub county_id: Option<Uuid>,
+    /// True when a county has no rules of its own and `rules` are the
+    /// global ones it falls back to.
+    pub inherited: bool,
+    pub rules: Vec<WidgetRuleResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preview: Option<WidgetRulePreview>,
+}
+
+impl From<WidgetRuleError> for ApiError {
+    fn from(e: WidgetRuleError) -> Self {
+        match e {
+            WidgetRuleError::Invalid(msg) => ApiError::BadRequest(msg),
+            WidgetRuleError::NotFound(msg) => ApiError::NotFound(msg),
+            WidgetRuleError::Db(e) => ApiError::Internal(e),
+        }
+    }
+}
+
+fn widget_rule_result(r: WidgetPlacementRule) -> WidgetRuleResult {
+    WidgetRuleResult {
+        id: r.id,
+        sort_order: r.sort_order,
+        after_row: r.after_row,
+        widget_type: r.widget_type,
+        count: r.count,
+        min_rows: r.min_rows,
+        rows_after: r.rows_after,
+        min_gap: r.min_gap,
+        at_section_start: r.at_section_start,
+        enabled: r.enabled,
+    }
+}
+
+fn widget_rules_scope(county_id: Option<Uuid>) -> CountyScope<'static> {
+    county_id.map_or(CountyScope::STATEWIDE, CountyScope::County)
+}
+
+async fn widget_rules(
+    State(state): State<AppState>,
+    StaffUser(user): StaffUser,
+    Json(req): Json<WidgetRulesRequest>,
+) -> ApiResult<Json<WidgetRulesResult>> {
+    user.require(AdminCapability::ViewEditions, widget_rules_scope(req.county_id))?;
+    let pool = &state.deps.db_pool;
+
+    let mut rules = WidgetPlacementRule::find_by_scope(req.county_id, pool).await?;
+    let inherited = req.county_id.is_some() && rules.is_empty();
+    if inherited {
+        rules = WidgetPlacementRule::find_by_scope(None, pool).await?;
+    }
+
+    Ok(Json(WidgetRulesResult {
+        county_id: req.county_id,
+        inherited,
+        rules: rules.into_iter().map(widget_rule_result).collect(),
+        preview: None,
+    }))
+}
+
+async fn save_widget_rules(
+    State(state): State<AppState>,
+    StaffUser(user): StaffUser,
+    Json(req): Json<SaveWidgetRulesRequest>,
+) -> ApiResult<Json<WidgetRulesResult>> {
+    user.require(AdminCapability::EditEditions, widget_rules_scope(req.county_id))?;
+
+    if req.dry_run {
+        let (county_id, period_start) = dry_run_target(
+            req.preview_county_id.or(req.county_id),
+            req.period_start.as_deref(),
+        )?;
+        user.require(AdminCapability::ViewEditions, CountyScope::County(county_id))?;
+        let preview =
+            widget_placement::preview(&req.rules, county_id, period_start, &state.deps).await?;
+        return Ok(Json(WidgetRulesResult {
+            county_id: req.county_id,
+            inherited: false,
+            rules: Vec::new(),
+            preview: Some(preview),
+        }));
+    }
+
+    let saved = widget_placement::save_rules(req.county_id, &req.rules, &state.deps.db_pool).await?;
+    Ok(Json(WidgetRulesResult {
+        county_id: req.county_id,
+        inherited: false,
+        rules: saved.into_iter().map(widget_rule_result).collect(),
+        preview: None,
     }))
 }
 
 async fn update_edition_row(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(req): Json<UpdateEditionRowRequest>,
 ) -> ApiResult<Json<EditionRowResult>> {
+    require_edition_capability(
+        &user,
+        AdminCapability::EditEditions,
+        EditionTarget::Row(req.row_id),
+        &state,
+    )
+    .await?;
     let pool = &state.deps.db_pool;
 
     // Resolve template slug to ID if provided
@@ -1112,19 +1632,33 @@ async fn update_edition_row(
         None => None,
     };
 
-    let row = EditionRow::update(req.row_id, template_id, req.sort_order, pool).await?;
+    let mut edit = begin_edit(EditionTarget::Row(req.row_id), &state).await?;
+    let params = event_params(&req);
+    let row = EditionRow::update(req.row_id, template_id, req.sort_order, edit.conn()).await?;
+    edit.commit(actor(&user), "update_edition_row", params)
+        .await?;
     let result = build_row_result(&row, pool).await?;
     Ok(Json(result))
 }
 
 async fn reorder_rows(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(req): Json<ReorderRowsRequest>,
 ) -> ApiResult<Json<ReorderRowsResult>> {
+    require_edition_capability(
+        &user,
+        AdminCapability::EditEditions,
+        EditionTarget::Edition(req.edition_id),
+        &state,
+    )
+    .await?;
     let pool = &state.deps.db_pool;
 
-    let rows = EditionRow::reorder(req.edition_id, &req.row_ids, pool).await?;
+    let mut edit = begin_edit(EditionTarget::Edition(req.edition_id), &state).await?;
+    let params = event_params(&req);
+    let rows = EditionRow::reorder(req.edition_id, &req.row_ids, edit.conn()).await?;
+    edit.commit(actor(&user), "reorder_rows", params).await?;
 
     // Load all templates + slots upfront (avoids N+1)
     let all_templates = RowTemplateConfig::find_all(pool).await?;
@@ -1138,13 +1672,7 @@ async fn reorder_rows(
         let template_slot_results: Vec<RowTemplateSlotResult> = all_template_slots
             .iter()
             .filter(|s| s.row_template_config_id == row.row_template_config_id)
-            .map(|s| RowTemplateSlotResult {
-                slot_index: s.slot_index,
-                weight: s.weight.clone(),
-                count: s.count,
-                accepts: s.accepts.clone(),
-                post_template_slug: s.post_template_slug.clone(),
-            })
+            .map(row_template_slot_result)
             .collect();
 
         let slots = EditionSlot::find_by_row_with_content(row.id, pool).await?;
@@ -1161,6 +1689,7 @@ async fn reorder_rows(
             row_template_slots: template_slot_results,
             sort_order: row.sort_order,
             section_id: row.section_id,
+            pinned: row.pinned,
             slots: slots
                 .iter()
                 .map(|s| EditionSlotResult {
@@ -1168,6 +1697,7 @@ async fn reorder_rows(
                     kind: s.kind.clone(),
                     slot_index: s.slot_index,
                     sort_order: s.sort_order,
+                    pinned: s.pinned,
                     post_id: s.post_id,
                     post_template: s.post_template.clone(),
                     post_title: s.post_title.clone(),
@@ -1190,21 +1720,41 @@ async fn reorder_rows(
 
 async fn remove_post(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(req): Json<RemovePostFromEditionRequest>,
 ) -> ApiResult<Json<bool>> {
-    EditionSlot::delete(req.slot_id, &state.deps.db_pool).await?;
+    require_edition_capability(
+        &user,
+        AdminCapability::EditEditions,
+        EditionTarget::Slot(req.slot_id),
+        &state,
+    )
+    .await?;
+    let mut edit = begin_edit(EditionTarget::Slot(req.slot_id), &state).await?;
+    let params = event_params(&req);
+    EditionSlot::delete(req.slot_id, edit.conn()).await?;
+    edit.commit(actor(&user), "remove_post", params).await?;
     Ok(Json(true))
 }
 
 async fn change_slot_template(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(req): Json<ChangeSlotTemplateRequest>,
 ) -> ApiResult<Json<EditionSlotResult>> {
+    require_edition_capability(
+        &user,
+        AdminCapability::EditEditions,
+        EditionTarget::Slot(req.slot_id),
+        &state,
+    )
+    .await?;
     let pool = &state.deps.db_pool;
-    let slot =
-        EditionSlot::change_template(req.slot_id, &req.post_template, pool).await?;
+    let mut edit = begin_edit(EditionTarget::Slot(req.slot_id), &state).await?;
+    let params = event_params(&req);
+    let slot = EditionSlot::change_template(req.slot_id, &req.post_template, edit.conn()).await?;
+    edit.commit(actor(&user), "change_slot_template", params)
+        .await?;
 
     let result = slot_with_content_data(&slot, pool).await?;
     Ok(Json(result))
@@ -1212,13 +1762,35 @@ async fn change_slot_template(
 
 async fn move_slot(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(req): Json<MoveSlotRequest>,
 ) -> ApiResult<Json<EditionSlotResult>> {
+    require_edition_capability(
+        &user,
+        AdminCapability::EditEditions,
+        EditionTarget::Slot(req.slot_id),
+        &state,
+    )
+    .await?;
+    require_edition_capability(
+        &user,
+        AdminCapability::EditEditions,
+        EditionTarget::Row(req.target_row_id),
+        &state,
+    )
+    .await?;
     let pool = &state.deps.db_pool;
-    let slot =
-        EditionSlot::move_to(req.slot_id, req.target_row_id, req.slot_index, req.sort_order, pool)
-            .await?;
+    let mut edit = begin_edit(EditionTarget::Slot(req.slot_id), &state).await?;
+    let params = event_params(&req);
+    let slot = EditionSlot::move_to(
+        req.slot_id,
+        req.target_row_id,
+        req.slot_index,
+        req.sort_order,
+        edit.conn(),
+    )
+    .await?;
+    edit.commit(actor(&user), "move_slot", params).await?;
 
     let result = slot_with_content_data(&slot, pool).await?;
     Ok(Json(result))
@@ -1226,18 +1798,29 @@ async fn move_slot(
 
 async fn add_post_to_edition(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(req): Json<AddPostToEditionRequest>,
 ) -> ApiResult<Json<EditionSlotResult>> {
+    require_edition_capability(
+        &user,
+        AdminCapability::EditEditions,
+        EditionTarget::Row(req.edition_row_id),
+        &state,
+    )
+    .await?;
     let pool = &state.deps.db_pool;
+    let mut edit = begin_edit(EditionTarget::Row(req.edition_row_id), &state).await?;
+    let params = event_params(&req);
     let slot = EditionSlot::create(
         req.edition_row_id,
         req.post_id,
         &req.post_template,
         req.slot_index,
-        pool,
+        edit.conn(),
     )
     .await?;
+    edit.commit(actor(&user), "add_post_to_edition", params)
+        .await?;
 
     let result = slot_with_content_data(&slot, pool).await?;
     Ok(Json(result))
@@ -1245,9 +1828,16 @@ async fn add_post_to_edition(
 
 async fn add_edition_row(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(req): Json<AddEditionRowRequest>,
 ) -> ApiResult<Json<EditionRowResult>> {
+    require_edition_capability(
+        &user,
+        AdminCapability::EditEditions,
+        EditionTarget::Edition(req.edition_id),
+        &state,
+    )
+    .await?;
     let pool = &state.deps.db_pool;
 
     // Resolve template slug to ID
@@ -1260,8 +1850,10 @@ async fn add_edition_row(
             ))
         })?;
 
-    let row =
-        EditionRow::create(req.edition_id, template.id, req.sort_order, pool).await?;
+    let mut edit = begin_edit(EditionTarget::Edition(req.edition_id), &state).await?;
+    let params = event_params(&req);
+    let row = EditionRow::create(req.edition_id, template.id, req.sort_order, edit.conn()).await?;
+    edit.commit(actor(&user), "add_edition_row", params).await?;
 
     let template_slots =
         RowTemplateSlot::find_by_template(template.id, pool).await?;
@@ -1275,78 +1867,129 @@ async fn add_edition_row(
         row_template_description: template.description,
         row_template_slots: template_slots
             .iter()
-            .map(|s| RowTemplateSlotResult {
-                slot_index: s.slot_index,
-                weight: s.weight.clone(),
-                count: s.count,
-                accepts: s.accepts.clone(),
-                post_template_slug: s.post_template_slug.clone(),
-            })
+            .map(row_template_slot_result)
             .collect(),
         sort_order: row.sort_order,
         section_id: row.section_id,
+        pinned: row.pinned,
         slots: vec![],
     }))
 }
 
 async fn delete_edition_row(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(req): Json<DeleteEditionRowRequest>,
 ) -> ApiResult<Json<bool>> {
-    EditionRow::delete(req.row_id, &state.deps.db_pool).await?;
+    require_edition_capability(
+        &user,
+        AdminCapability::EditEditions,
+        EditionTarget::Row(req.row_id),
+        &state,
+    )
+    .await?;
+    let mut edit = begin_edit(EditionTarget::Row(req.row_id), &state).await?;
+    let params = event_params(&req);
+    EditionRow::delete(req.row_id, edit.conn()).await?;
+    edit.commit(actor(&user), "delete_edition_row", params)
+        .await?;
     Ok(Json(true))
 }
 
 async fn review_edition(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(req): Json<ReviewEditionRequest>,
 ) -> ApiResult<Json<EditionResult>> {
+    require_edition_capability(
+        &user,
+        AdminCapability::ReviewEditions,
+        EditionTarget::Edition(req.id),
+        &state,
+    )
+    .await?;
     let edition = activities::review_edition(req.id, &state.deps).await?;
+    record_status_change(&edition, &user, "review_edition", &state).await?;
     Ok(Json(edition_to_result(&edition)))
 }
 
 async fn approve_edition(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(req): Json<ApproveEditionRequest>,
 ) -> ApiResult<Json<EditionResult>> {
+    require_edition_capability(
+        &user,
+        AdminCapability::ReviewEditions,
+        EditionTarget::Edition(req.id),
+        &state,
+    )
+    .await?;
     let edition = activities::approve_edition(req.id, &state.deps).await?;
+    record_status_change(&edition, &user, "approve_edition", &state).await?;
     Ok(Json(edition_to_result(&edition)))
 }
 
 async fn batch_approve_editions(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(req): Json<BatchApproveEditionsRequest>,
 ) -> ApiResult<Json<BatchEditionsResult>> {
-    let result = activities::batch_approve_editions(&req.ids, &state.deps).await?;
-    Ok(Json(batch_to_result(result)))
+    let (allowed, denied) =
+        partition_by_capability(&user, AdminCapability::ReviewEditions, &req.ids, &state).await?;
+    let result = activities::batch_approve_editions(&allowed, &state.deps).await?;
+    for id in &allowed {
+        if result.errors.iter().any(|e| e.edition_id == *id) {
+            continue;
+        }
+        let params = serde_json::json!({ "status": "approved", "batch": true });
+        activities::record_lifecycle(*id, actor(&user), "approve_edition", params, &state.deps).await?;
+    }
+    Ok(Json(batch_to_result(result, denied)))
 }
 
 async fn batch_publish_editions(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(req): Json<BatchPublishEditionsRequest>,
 ) -> ApiResult<Json<BatchEditionsResult>> {
-    let result = activities::batch_publish_editions(&req.ids, &state.deps).await?;
-    Ok(Json(batch_to_result(result)))
+    let (allowed, denied) =
+        partition_by_capability(&user, AdminCapability::PublishEditions, &req.ids, &state).await?;
+    let result = activities::batch_publish_editions(&allowed, &state.deps).await?;
+    for id in &allowed {
+        if result.errors.iter().any(|e| e.edition_id == *id) {
+            continue;
+        }
+        let params = serde_json::json!({ "status": "published", "batch": true });
+        activities::record_lifecycle(*id, actor(&user), "publish_edition", params, &state.deps).await?;
+    }
+    Ok(Json(batch_to_result(result, denied)))
 }
 
 async fn unpublish_edition(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(req): Json<UnpublishEditionRequest>,
 ) -> ApiResult<Json<EditionResult>> {
+    require_edition_capability(
+        &user,
+        AdminCapability::PublishEditions,
+        EditionTarget::Edition(req.id),
+        &state,
+    )
+    .await?;
     let edition = activities::unpublish_edition(req.id, &state.deps).await?;
+    record_status_change(&edition, &user, "unpublish_edition", &state).await?;
     Ok(Json(edition_to_result(&edition)))
 }
 
-fn batch_to_result(r: activities::BatchLifecycleResult) -> BatchEditionsResult {
+fn batch_to_result(
+    r: activities::BatchLifecycleResult,
+    denied: Vec<BatchEditionErrorResult>,
+) -> BatchEditionsResult {
     BatchEditionsResult {
         succeeded: r.succeeded,
-        failed: r.failed,
+        failed: r.failed + denied.len() as i32,
         errors: r
             .errors
             .into_iter()
@@ -1354,20 +1997,58 @@ fn batch_to_result(r: activities::BatchLifecycleResult) -> BatchEditionsResult {
                 edition_id: e.edition_id,
                 message: e.message,
             })
+            .chain(denied)
             .collect(),
     }
 }
 
+/// Split a batch into the editions `user` holds `capability` for and an
+/// error entry for each one they don't, so a desk editor's batch still
+/// processes their own counties.
+async fn partition_by_capability(
+    user: &AuthUser,
+    capability: AdminCapability,
+    ids: &[Uuid],
+    state: &AppState,
+) -> ApiResult<(Vec<Uuid>, Vec<BatchEditionErrorResult>)> {
+    if user.is_admin {
+        return Ok((ids.to_vec(), Vec::new()));
+    }
+    let mut allowed = Vec::new();
+    let mut denied = Vec::new();
+    for &id in ids {
+        let check =
+            require_edition_capability(user, capability, EditionTarget::Edition(id), state).await;
+        match check {
+            Ok(()) => allowed.push(id),
+            Err(ApiError::Forbidden(message)) | Err(ApiError::NotFound(message)) => {
+                denied.push(BatchEditionErrorResult {
+                    edition_id: id,
+                    message,
+                })
+            }
+            Err(e) => return Err(e),
+        }
+    }
+    Ok((allowed, denied))
+}
+
 async fn edition_kanban_stats(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(req): Json<EditionKanbanStatsRequest>,
 ) -> ApiResult<Json<EditionKanbanStatsResult>> {
+    let county_ids = user.county_filter(AdminCapability::ViewEditions, None)?;
     let period_start = parse_date(&req.period_start, "period_start")?;
     let period_end = parse_date(&req.period_end, "period_end")?;
 
-    let counts =
-        Edition::count_by_status(period_start, period_end, &state.deps.db_pool).await?;
+    let counts = Edition::count_by_status(
+        period_start,
+        period_end,
+        county_ids.as_deref(),
+        &state.deps.db_pool,
+    )
+    .await?;
 
     let mut result = EditionKanbanStatsResult {
         draft: 0,
@@ -1391,9 +2072,16 @@ async fn edition_kanban_stats(
 
 async fn add_widget_to_edition(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(req): Json<AddWidgetToEditionRequest>,
 ) -> ApiResult<Json<EditionSlotResult>> {
+    require_edition_capability(
+        &user,
+        AdminCapability::EditEditions,
+        EditionTarget::Row(req.edition_row_id),
+        &state,
+    )
+    .await?;
     let pool = &state.deps.db_pool;
 
     // Verify widget exists
@@ -1401,14 +2089,18 @@ async fn add_widget_to_edition(
         .await?
         .ok_or_else(|| ApiError::NotFound(format!("Widget not found: {}", req.widget_id)))?;
 
+    let mut edit = begin_edit(EditionTarget::Row(req.edition_row_id), &state).await?;
+    let params = event_params(&req);
     let slot = EditionSlot::create_widget_slot(
         req.edition_row_id,
         req.widget_id,
         None, // widget_template set separately via admin UI (not on initial create)
         req.slot_index,
-        pool,
+        edit.conn(),
     )
     .await?;
+    edit.commit(actor(&user), "add_widget_to_edition", params)
+        .await?;
 
     let result = slot_with_content_data(&slot, pool).await?;
     Ok(Json(result))
@@ -1440,7 +2132,7 @@ async fn public_current_broadsheet(
             ApiError::NotFound(format!("County not found: {}", edition.county_id))
         })?;
 
-    let broadsheet_result = build_public_broadsheet(&edition, &county, &state).await?;
+    let broadsheet_result = build_public_broadsheet(&edition, &county, pool).await?;
     Ok(Json(broadsheet_result))
 }
 
@@ -1455,9 +2147,16 @@ pub struct PreviewBroadsheetRequest {
 
 async fn preview_broadsheet(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(req): Json<PreviewBroadsheetRequest>,
 ) -> ApiResult<Json<PublicBroadsheetResult>> {
+    require_edition_capability(
+        &user,
+        AdminCapability::ViewEditions,
+        EditionTarget::Edition(req.edition_id),
+        &state,
+    )
+    .await?;
     let pool = &state.deps.db_pool;
 
     let edition = Edition::find_by_id(req.edition_id, pool)
@@ -1472,289 +2171,78 @@ async fn preview_broadsheet(
             ApiError::NotFound(format!("County not found: {}", edition.county_id))
         })?;
 
-    let broadsheet_result = build_public_broadsheet(&edition, &county, &state).await?;
+    let broadsheet_result = build_public_broadsheet(&edition, &county, pool).await?;
     Ok(Json(broadsheet_result))
 }
 
-/// Shared logic for building a public broadsheet response (used by both public and preview).
-async fn build_public_broadsheet(
-    edition: &Edition,
-    county: &County,
-    state: &AppState,
-) -> ApiResult<PublicBroadsheetResult> {
-    let pool = &state.deps.db_pool;
-    let rows = EditionRow::find_by_edition(edition.id, pool).await?;
-    let all_templates = RowTemplateConfig::find_all(pool).await?;
-
-    // Collect all post IDs across all rows for batch loading
-    let mut all_slots_by_row: Vec<Vec<EditionSlot>> = Vec::new();
-    let mut all_post_ids: Vec<Uuid> = Vec::new();
-    let mut all_widget_ids: Vec<Uuid> = Vec::new();
+// =============================================================================
+// Back issues (public, served from publish-time snapshots)
+// =============================================================================
 
-    for row in &rows {
-        let slots = EditionSlot::find_by_row(row.id, pool).await?;
-        for slot in &slots {
-            if let Some(post_id) = slot.post_id {
-                all_post_ids.push(post_id);
-            }
-            if let Some(widget_id) = slot.widget_id {
-                all_widget_ids.push(widget_id);
-            }
-        }
-        all_slots_by_row.push(slots);
-    }
+async fn public_back_issues(
+    State(state): State<AppState>,
+    // No AdminUser — public endpoint
+    Json(req): Json<BackIssuesRequest>,
+) -> ApiResult<Json<BackIssueListResult>> {
+    let from = req.from.as_deref().map(|s| parse_date(s, "from")).transpose()?;
+    let to = req.to.as_deref().map(|s| parse_date(s, "to")).transpose()?;
+    let limit = req.limit.unwrap_or(52).clamp(1, 200) as i64;
+    let offset = req.offset.unwrap_or(0).max(0) as i64;
+
+    let issues = EditionSnapshot::list_back_issues(
+        req.county_id,
+        from,
+        to,
+        limit,
+        offset,
+        &state.deps.db_pool,
+    )
+    .await?;
 
-    // Batch load full post data, tags, urgent notes, and org info
-    let posts_by_id: HashMap<Uuid, Post> = if !all_post_ids.is_empty() {
-        Post::find_by_ids(&all_post_ids, pool)
-            .await?
+    Ok(Json(BackIssueListResult {
+        back_issues: issues
             .into_iter()
-            .map(|p| (p.id.into_uuid(), p))
-            .collect()
-    } else {
-        HashMap::new()
-    };
-
-    // Batch load widgets
-    let widgets_by_id: HashMap<Uuid, Widget> = if !all_widget_ids.is_empty() {
-        let mut map = HashMap::new();
-        for wid in &all_widget_ids {
-            if let Some(w) = Widget::find_by_id(*wid, pool).await? {
-                map.insert(w.id, w);
-            }
-        }
-        map
-    } else {
-        HashMap::new()
-    };
-
-    let (mut tags_by_post, mut urgent_notes_by_post) =
-        load_tags_and_notes(&all_post_ids, &state.deps).await?;
-
-    let mut org_info = Post::find_org_info_for_posts(&all_post_ids, pool).await?;
-
-    // Batch load contacts for all posts
-    let all_contacts = Contact::find_by_post_ids(&all_post_ids, pool).await?;
-    let mut contacts_by_post: HashMap<Uuid, Vec<BroadsheetContactResult>> = HashMap::new();
-    for c in all_contacts {
-        contacts_by_post
-            .entry(c.contactable_id)
-            .or_default()
-            .push(BroadsheetContactResult {
-                contact_type: c.contact_type,
-                contact_value: c.contact_value,
-                contact_label: c.contact_label,
-            });
-    }
-
-    // Batch load field groups for all posts
-    let all_media = PostMediaRecord::find_by_post_ids(&all_post_ids, pool).await?;
-    let mut media_by_post: HashMap<Uuid, Vec<BroadsheetMediaResult>> = HashMap::new();
-    for m in all_media {
-        media_by_post
-            .entry(m.post_id)
-            .or_default()
-            .push(BroadsheetMediaResult {
-                image_url: m.image_url,
-                caption: m.caption,
-                credit: m.credit,
-            });
-    }
-
-    let all_items = PostItem::find_by_post_ids(&all_post_ids, pool).await?;
-    let mut items_by_post: HashMap<Uuid, Vec<BroadsheetItemResult>> = HashMap::new();
-    for item in all_items {
-        items_by_post
-            .entry(item.post_id)
-            .or_default()
-            .push(BroadsheetItemResult {
-                name: item.name,
-                detail: item.detail,
-            });
-    }
-
-    let all_schedule = PostScheduleEntry::find_by_post_ids(&all_post_ids, pool).await?;
-    let mut schedule_by_post: HashMap<Uuid, Vec<BroadsheetScheduleEntryResult>> = HashMap::new();
-    for entry in all_schedule {
-        schedule_by_post
-            .entry(entry.post_id)
-            .or_default()
-            .push(BroadsheetScheduleEntryResult {
-                day: entry.day,
-                opens: entry.opens,
-                closes: entry.closes,
-            });
-    }
-
-    // 1:1 field groups
-    let all_persons = PostPersonRecord::find_by_post_ids(&all_post_ids, pool).await?;
-    let mut persons_by_post: HashMap<Uuid, BroadsheetPersonResult> = all_persons
-        .into_iter()
-        .map(|p| (p.post_id, BroadsheetPersonResult {
-            name: p.name,
-            role: p.role,
-            bio: p.bio,
-            photo_url: p.photo_url,
-            quote: p.quote,
-        }))
-        .collect();
-
-    let all_links = PostLinkRecord::find_by_post_ids(&all_post_ids, pool).await?;
-    let mut links_by_post: HashMap<Uuid, BroadsheetLinkResult> = all_links
-        .into_iter()
-        .map(|l| (l.post_id, BroadsheetLinkResult {
-            label: l.label,
-            url: l.url,
-            deadline: l.deadline.map(|d| d.to_string()),
-        }))
-        .collect();
-
-    let all_source_attrs = PostSourceAttr::find_by_post_ids(&all_post_ids, pool).await?;
-    let mut source_attrs_by_post: HashMap<Uuid, BroadsheetSourceAttributionResult> = all_source_attrs
-        .into_iter()
-        .map(|s| (s.post_id, BroadsheetSourceAttributionResult {
-            source_name: s.source_name,
-            attribution: s.attribution,
-        }))
-        .collect();
-
-    let all_metas = PostMetaRecord::find_by_post_ids(&all_post_ids, pool).await?;
-    let mut metas_by_post: HashMap<Uuid, BroadsheetMetaResult> = all_metas
-        .into_iter()
-        .map(|m| (m.post_id, BroadsheetMetaResult {
-            kicker: m.kicker,
-            byline: m.byline,
-            timestamp: m.timestamp.map(|t| t.to_rfc3339()),
-            updated: m.updated,
-            deck: m.deck,
-        }))
-        .collect();
-
-    let all_datetimes = PostDatetimeRecord::find_by_post_ids(&all_post_ids, pool).await?;
-    let mut datetimes_by_post: HashMap<Uuid, BroadsheetDatetimeResult> = all_datetimes
-        .into_iter()
-        .map(|d| (d.post_id, BroadsheetDatetimeResult {
-            start: d.start_at.map(|t| t.to_rfc3339()),
-            end: d.end_at.map(|t| t.to_rfc3339()),
-            cost: d.cost,
-            recurring: d.recurring,
-        }))
-        .collect();
-
-    let all_statuses = PostStatusRecord::find_by_post_ids(&all_post_ids, pool).await?;
-    let mut statuses_by_post: HashMap<Uuid, BroadsheetStatusResult> = all_statuses
-        .into_iter()
-        .map(|s| (s.post_id, BroadsheetStatusResult {
-            state: s.state,
-            verified: s.verified,
-        }))
-        .collect();
-
-    // Assemble rows
-    let mut row_results = Vec::new();
-    for (row, slots) in rows.iter().zip(all_slots_by_row.iter()) {
-        let template = all_templates
-            .iter()
-            .find(|t| t.id == row.row_template_config_id);
-
-        let slot_results: Vec<PublicBroadsheetSlotResult> = slots
-            .iter()
-            .filter_map(|slot| {
-                match slot.kind.as_str() {
-                    "post" => {
-                        let post_id = slot.post_id?;
-                        let post = posts_by_id.get(&post_id)?;
-                        let id = post.id.into_uuid();
-                        let org_name = org_info.remove(&id).map(|(_, name)| name);
-
-                        Some(PublicBroadsheetSlotResult {
-                            kind: "post".to_string(),
-                            post_template: slot.post_template.clone(),
-                            widget_template: None,
-                            slot_index: slot.slot_index,
-                            post: Some(PublicBroadsheetPostResult {
-                                id,
-                                title: post.title.clone(),
-                                body_raw: post.body_raw.clone(),
-                                post_type: post.post_type.clone(),
-                                weight: post.weight.clone(),
-                                is_urgent: post.is_urgent,
-                                pencil_mark: post.pencil_mark.clone(),
-                                location: post.location.clone(),
-                                organization_name: org_name,
-                                published_at: post.published_at.map(|dt| dt.to_rfc3339()),
-                                tags: tags_by_post.remove(&id).unwrap_or_default(),
-                                contacts: contacts_by_post.remove(&id).unwrap_or_default(),
-                                urgent_notes: urgent_notes_by_post.remove(&id).unwrap_or_default(),
-                                body_heavy: post.body_heavy.clone(),
-                                body_medium: post.body_medium.clone(),
-                                body_light: post.body_light.clone(),
-                                // Field groups
-                                media: media_by_post.remove(&id).unwrap_or_default(),
-                                items: items_by_post.remove(&id).unwrap_or_default(),
-                                person: persons_by_post.remove(&id),
-                                link: links_by_post.remove(&id),
-                                source_attribution: source_attrs_by_post.remove(&id),
-                                meta: metas_by_post.remove(&id),
-                                datetime: datetimes_by_post.remove(&id),
-                                post_status: statuses_by_post.remove(&id),
-                                schedule: schedule_by_post.remove(&id).unwrap_or_default(),
-                            }),
-                            widget: None,
-                        })
-                    }
-                    "widget" => {
-                        let widget_id = slot.widget_id?;
-                        let widget = widgets_by_id.get(&widget_id)?;
-
-                        Some(PublicBroadsheetSlotResult {
-                            kind: "widget".to_string(),
-                            post_template: None,
-                            widget_template: slot.widget_template.clone(),
-                            slot_index: slot.slot_index,
-                            post: None,
-                            widget: Some(PublicBroadsheetWidgetResult {
-                                id: widget.id,
-                                widget_type: widget.widget_type.clone(),
-                                authoring_mode: widget.authoring_mode.clone(),
-                                data: widget.data.clone(),
-                            }),
-                        })
-                    }
-                    _ => None,
-                }
+            .map(|i| BackIssueSummaryResult {
+                edition_id: i.edition_id,
+                title: i.title,
+                period_start: i.period_start.to_string(),
+                period_end: i.period_end.to_string(),
+                version: i.version,
+                content_hash: i.content_hash,
+                published_at: i.published_at.to_rfc3339(),
             })
-            .collect();
-
-        row_results.push(PublicBroadsheetRowResult {
-            row_template_slug: template.map(|t| t.slug.clone()).unwrap_or_default(),
-            layout_variant: template.map(|t| t.layout_variant.clone()).unwrap_or_else(|| "full".to_string()),
-            sort_order: row.sort_order,
-            section_id: row.section_id,
-            slots: slot_results,
-        });
-    }
-
-    // Load sections
-    let sections = EditionSection::find_by_edition(edition.id, pool).await?;
-    let section_results: Vec<EditionSectionResult> = sections
-        .iter()
-        .map(|s| section_to_result(s))
-        .collect();
+            .collect(),
+    }))
+}
 
-    Ok(PublicBroadsheetResult {
-        edition: edition_to_result(edition),
-        county: CountyResult {
-            id: county.id,
-            fips_code: county.fips_code.clone(),
-            name: county.name.clone(),
-            state: county.state.clone(),
-            target_content_weight: county.target_content_weight,
-            is_pseudo: county.is_pseudo,
-        },
-        rows: row_results,
-        sections: section_results,
-    })
+async fn public_back_issue(
+    State(state): State<AppState>,
+    // No AdminUser — public endpoint
+    Json(req): Json<BackIssueRequest>,
+) -> ApiResult<Json<BackIssueResult>> {
+    let period_start = parse_date(&req.period_start, "period_start")?;
+    let snapshot = EditionSnapshot::find_back_issue(
+        req.county_id,
+        period_start,
+        req.version,
+        &state.deps.db_pool,
+    )
+    .await?
+    .ok_or_else(|| {
+        ApiError::NotFound(format!(
+            "No back issue for county {} and period {}",
+            req.county_id, period_start
+        ))
+    })?;
+
+    Ok(Json(BackIssueResult {
+        edition_id: snapshot.edition_id,
+        version: snapshot.version,
+        content_hash: snapshot.content_hash,
+        published_at: snapshot.published_at.to_rfc3339(),
+        frozen_at: snapshot.created_at.to_rfc3339(),
+        broadsheet: snapshot.snapshot,
+    }))
 }
 
 // =============================================================================
@@ -1763,69 +2251,262 @@ async fn build_public_broadsheet(
 
 async fn add_section(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(req): Json<AddSectionRequest>,
 ) -> ApiResult<Json<EditionSectionResult>> {
-    let pool = &state.deps.db_pool;
+    require_edition_capability(
+        &user,
+        AdminCapability::EditEditions,
+        EditionTarget::Edition(req.edition_id),
+        &state,
+    )
+    .await?;
+    let mut edit = begin_edit(EditionTarget::Edition(req.edition_id), &state).await?;
+    let params = event_params(&req);
     let section = EditionSection::create(
         req.edition_id,
         &req.title,
         req.subtitle.as_deref(),
         req.topic_slug.as_deref(),
         req.sort_order,
-        pool,
+        edit.conn(),
     )
     .await?;
+    edit.commit(actor(&user), "add_section", params).await?;
     Ok(Json(section_to_result(&section)))
 }
 
 async fn update_section(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(req): Json<UpdateSectionRequest>,
 ) -> ApiResult<Json<EditionSectionResult>> {
-    let pool = &state.deps.db_pool;
+    require_edition_capability(
+        &user,
+        AdminCapability::EditEditions,
+        EditionTarget::Section(req.id),
+        &state,
+    )
+    .await?;
+    let mut edit = begin_edit(EditionTarget::Section(req.id), &state).await?;
+    let params = event_params(&req);
     let section = EditionSection::update(
         req.id,
         req.title.as_deref(),
         req.subtitle.as_ref().map(|s| s.as_deref()),
         req.topic_slug.as_ref().map(|s| s.as_deref()),
-        pool,
+        edit.conn(),
     )
     .await?;
+    edit.commit(actor(&user), "update_section", params).await?;
     Ok(Json(section_to_result(&section)))
 }
 
 async fn reorder_sections(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(req): Json<ReorderSectionsRequest>,
 ) -> ApiResult<Json<Vec<EditionSectionResult>>> {
-    let pool = &state.deps.db_pool;
-    let sections = EditionSection::reorder(req.edition_id, &req.section_ids, pool).await?;
+    require_edition_capability(
+        &user,
+        AdminCapability::EditEditions,
+        EditionTarget::Edition(req.edition_id),
+        &state,
+    )
+    .await?;
+    let mut edit = begin_edit(EditionTarget::Edition(req.edition_id), &state).await?;
+    let params = event_params(&req);
+    let sections = EditionSection::reorder(req.edition_id, &req.section_ids, edit.conn()).await?;
+    edit.commit(actor(&user), "reorder_sections", params)
+        .await?;
     Ok(Json(sections.iter().map(section_to_result).collect()))
 }
 
 async fn delete_section(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(req): Json<DeleteSectionRequest>,
 ) -> ApiResult<Json<serde_json::Value>> {
-    let pool = &state.deps.db_pool;
-    EditionSection::delete(req.id, pool).await?;
+    require_edition_capability(
+        &user,
+        AdminCapability::EditEditions,
+        EditionTarget::Section(req.id),
+        &state,
+    )
+    .await?;
+    let mut edit = begin_edit(EditionTarget::Section(req.id), &state).await?;
+    let params = event_params(&req);
+    EditionSection::delete(req.id, edit.conn()).await?;
+    edit.commit(actor(&user), "delete_section", params).await?;
     Ok(Json(serde_json::json!({ "deleted": true })))
 }
 
 async fn assign_row_to_section(
     State(state): State<AppState>,
-    _user: AdminUser,
+    StaffUser(user): StaffUser,
     Json(req): Json<AssignRowToSectionRequest>,
 ) -> ApiResult<Json<serde_json::Value>> {
-    let pool = &state.deps.db_pool;
-    EditionRow::assign_to_section(req.row_id, req.section_id, pool).await?;
+    require_edition_capability(
+        &user,
+        AdminCapability::EditEditions,
+        EditionTarget::Row(req.row_id),
+        &state,
+    )
+    .await?;
+    if let Some(section_id) = req.section_id {
+        require_edition_capability(
+            &user,
+            AdminCapability::EditEditions,
+            EditionTarget::Section(section_id),
+            &state,
+        )
+        .await?;
+    }
+    let mut edit = begin_edit(EditionTarget::Row(req.row_id), &state).await?;
+    let params = event_params(&req);
+    EditionRow::assign_to_section(req.row_id, req.section_id, edit.conn()).await?;
+    edit.commit(actor(&user), "assign_row_to_section", params)
+        .await?;
     Ok(Json(serde_json::json!({ "success": true })))
 }
 
+// =============================================================================
+// Edition history, undo/redo and pinning
+// =============================================================================
+
+async fn pin_slot(
+    State(state): State<AppState>,
+    StaffUser(user): StaffUser,
+    Json(req): Json<PinSlotRequest>,
+) -> ApiResult<Json<EditionSlotResult>> {
+    require_edition_capability(
+        &user,
+        AdminCapability::EditEditions,
+        EditionTarget::Slot(req.slot_id),
+        &state,
+    )
+    .await?;
+    let pool = &state.deps.db_pool;
+    let mut edit = begin_edit(EditionTarget::Slot(req.slot_id), &state).await?;
+    let slot = EditionSlot::set_pinned(req.slot_id, req.pinned, edit.conn()).await?;
+    edit.commit(actor(&user), "pin_slot", event_params(&req))
+        .await?;
+
+    let result = slot_with_content_data(&slot, pool).await?;
+    Ok(Json(result))
+}
+
+async fn pin_row(
+    State(state): State<AppState>,
+    StaffUser(user): StaffUser,
+    Json(req): Json<PinRowRequest>,
+) -> ApiResult<Json<EditionRowResult>> {
+    require_edition_capability(
+        &user,
+        AdminCapability::EditEditions,
+        EditionTarget::Row(req.row_id),
+        &state,
+    )
+    .await?;
+    let pool = &state.deps.db_pool;
+    let mut edit = begin_edit(EditionTarget::Row(req.row_id), &state).await?;
+    let row = EditionRow::set_pinned(req.row_id, req.pinned, edit.conn()).await?;
+    edit.commit(actor(&user), "pin_row", event_params(&req))
+        .await?;
+
+    Ok(Json(build_row_result(&row, pool).await?))
+}
+
+async fn pin_section(
+    State(state): State<AppState>,
+    StaffUser(user): StaffUser,
+    Json(req): Json<PinSectionRequest>,
+) -> ApiResult<Json<EditionSectionResult>> {
+    require_edition_capability(
+        &user,
+        AdminCapability::EditEditions,
+        EditionTarget::Section(req.section_id),
+        &state,
+    )
+    .await?;
+    let mut edit = begin_edit(EditionTarget::Section(req.section_id), &state).await?;
+    let section = EditionSection::set_pinned(req.section_id, req.pinned, edit.conn()).await?;
+    edit.commit(actor(&user), "pin_section", event_params(&req))
+        .await?;
+
+    Ok(Json(section_to_result(&section)))
+}
+
+async fn edition_history(
+    State(state): State<AppState>,
+    StaffUser(user): StaffUser,
+    Json(req): Json<EditionHistoryRequest>,
+) -> ApiResult<Json<EditionHistoryResult>> {
+    require_edition_capability(
+        &user,
+        AdminCapability::ViewEditions,
+        EditionTarget::Edition(req.id),
+        &state,
+    )
+    .await?;
+    let pool = &state.deps.db_pool;
+    let limit = req.limit.unwrap_or(50).clamp(1, 500) as i64;
+    let offset = req.offset.unwrap_or(0).max(0) as i64;
+
+    let events = EditionEvent::find_by_edition(req.id, limit, offset, pool).await?;
+    let stack = EditionEvent::layout_stack(req.id, pool).await?;
+    let depth = |plan: Option<edition_history::HistoryPlan>| {
+        plan.map(|p| p.event_ids.len()).unwrap_or(0)
+    };
+
+    Ok(Json(EditionHistoryResult {
+        events: events
+            .into_iter()
+            .map(|e| event_to_result(e, req.include_layouts))
+            .collect(),
+        undo_depth: depth(edition_history::plan_undo(&stack, usize::MAX)),
+        redo_depth: depth(edition_history::plan_redo(&stack, usize::MAX)),
+    }))
+}
+
+async fn undo_edits(
+    State(state): State<AppState>,
+    StaffUser(user): StaffUser,
+    Json(req): Json<UndoRedoRequest>,
+) -> ApiResult<Json<UndoRedoResult>> {
+    require_edition_capability(
+        &user,
+        AdminCapability::EditEditions,
+        EditionTarget::Edition(req.id),
+        &state,
+    )
+    .await?;
+    let steps = req.steps.unwrap_or(1).max(1) as usize;
+    let events = edition_history::undo_edits(req.id, steps, &state.deps).await?;
+    Ok(Json(UndoRedoResult {
+        events: events.into_iter().map(|e| event_to_result(e, false)).collect(),
+    }))
+}
+
+async fn redo_edits(
+    State(state): State<AppState>,
+    StaffUser(user): StaffUser,
+    Json(req): Json<UndoRedoRequest>,
+) -> ApiResult<Json<UndoRedoResult>> {
+    require_edition_capability(
+        &user,
+        AdminCapability::EditEditions,
+        EditionTarget::Edition(req.id),
+        &state,
+    )
+    .await?;
+    let steps = req.steps.unwrap_or(1).max(1) as usize;
+    let events = edition_history::redo_edits(req.id, steps, &state.deps).await?;
+    Ok(Json(UndoRedoResult {
+        events: events.into_iter().map(|e| event_to_result(e, false)).collect(),
+    }))
+}
+
 // =============================================================================
 // Router
 // =============================================================================
@@ -1834,22 +2515,37 @@ pub fn router() -> Router<AppState> {
     Router::new()
         // Public (no auth)
         .route("/Public/current_broadsheet", post(public_current_broadsheet))
+        .route("/Public/back_issues", post(public_back_issues))
+        .route("/Public/back_issue", post(public_back_issue))
         // Admin
         .route("/Editions/list_counties", post(list_counties))
         .route("/Editions/get_county", post(get_county))
         .route("/Editions/update_county_target_weight", post(update_county_target_weight))
+        .route(
+            "/Editions/load_county_boundaries",
+            post(load_county_boundaries).layer(DefaultBodyLimit::max(COUNTY_BOUNDARIES_BODY_LIMIT)),
+        )
+        .route("/Editions/resolve_post_counties", post(resolve_post_counties))
         .route("/Editions/list_editions", post(list_editions))
         .route("/Editions/latest_editions", post(latest_editions))
         .route("/Editions/get_edition", post(get_edition))
         .route("/Editions/current_edition", post(current_edition))
         .route("/Editions/create_edition", post(create_edition))
         .route("/Editions/generate_edition", post(generate_edition))
+        .route("/Editions/layout_trace", post(layout_trace))
         .route("/Editions/publish_edition", post(publish_edition))
+        .route("/Editions/schedule_publish", post(schedule_publish))
         .route("/Editions/unpublish_edition", post(unpublish_edition))
         .route("/Editions/archive_edition", post(archive_edition))
         .route("/Editions/batch_generate", post(batch_generate))
         .route("/Editions/row_templates", post(row_templates))
         .route("/Editions/post_templates", post(post_templates))
+        .route("/Editions/save_row_template", post(save_row_template))
+        .route("/Editions/save_post_template", post(save_post_template))
+        .route("/Editions/delete_row_template", post(delete_row_template))
+        .route("/Editions/delete_post_template", post(delete_post_template))
+        .route("/Editions/widget_rules", post(widget_rules))
+        .route("/Editions/save_widget_rules", post(save_widget_rules))
         .route("/Editions/update_edition_row", post(update_edition_row))
         .route("/Editions/reorder_rows", post(reorder_rows))
         .route("/Editions/remove_post", post(remove_post))
@@ -1881,4 +2577,11 @@ pub fn router() -> Router<AppState> {
         .route("/Editions/reorder_sections", post(reorder_sections))
         .route("/Editions/delete_section", post(delete_section))
         .route("/Editions/assign_row_to_section", post(assign_row_to_section))
+        // History
+        .route("/Editions/history", post(edition_history))
+        .route("/Editions/undo", post(undo_edits))
+        .route("/Editions/redo", post(redo_edits))
+        .route("/Editions/pin_slot", post(pin_slot))
+        .route("/Editions/pin_row", post(pin_row))
+        .route("/Editions/pin_section", post(pin_section))
 }
@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use axum::extract::State;
+use axum::routing::post;
+use axum::{Json, Router};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::api::auth::AdminUser;
+use crate::api::error::{ApiError, ApiResult};
+use crate::api::state::AppState;
+use crate::kernel::jobs::{builtin_registry, run_job, JobOutcome, JobRun, JobTrigger};
+
+// =============================================================================
+// Request types
+// =============================================================================
+
+#[derive(Debug, Deserialize)]
+pub struct ListJobsRequest {}
+
+#[derive(Debug, Deserialize)]
+pub struct JobRunsRequest {
+    pub job_name: String,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RunJobNowRequest {
+    pub job_name: String,
+}
+
+// =============================================================================
+// Response types
+// =============================================================================
+
+#[derive(Debug, Serialize)]
+pub struct JobRunResult {
+    pub id: Uuid,
+    pub job_name: String,
+    pub trigger: String,
+    pub scheduled_for: Option<String>,
+    pub status: String,
+    pub rows_affected: Option<i64>,
+    pub error: Option<String>,
+    pub started_at: String,
+    pub finished_at: Option<String>,
+}
+
+impl From<JobRun> for JobRunResult {
+    fn from(r: JobRun) -> Self {
+        Self {
+            id: r.id,
+            job_name: r.job_name,
+            trigger: r.trigger,
+            scheduled_for: r.scheduled_for.map(|t| t.to_rfc3339()),
+            status: r.status,
+            rows_affected: r.rows_affected,
+            error: r.error,
+            started_at: r.started_at.to_rfc3339(),
+            finished_at: r.finished_at.map(|t| t.to_rfc3339()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct JobResult {
+    pub name: String,
+    pub description: String,
+    pub schedule: String,
+    pub scope: String,
+    pub next_run_at: Option<String>,
+    pub last_run: Option<JobRunResult>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct JobListResult {
+    pub jobs: Vec<JobResult>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct JobRunListResult {
+    pub runs: Vec<JobRunResult>,
+}
+
+// =============================================================================
+// Handlers
+// =============================================================================
+
+async fn list(
+    State(state): State<AppState>,
+    _user: AdminUser,
+    Json(_req): Json<ListJobsRequest>,
+) -> ApiResult<Json<JobListResult>> {
+    let registry = builtin_registry()?;
+    let mut latest: HashMap<String, JobRun> = JobRun::find_latest_per_job(&state.deps.db_pool)
+        .await?
+        .into_iter()
+        .map(|r| (r.job_name.clone(), r))
+        .collect();
+
+    let now = Utc::now();
+    let jobs = registry
+        .jobs()
+        .iter()
+        .map(|job| JobResult {
+            name: job.name.to_string(),
+            description: job.description.to_string(),
+            schedule: job.schedule_expr.to_string(),
+            scope: job.scope.as_str().to_string(),
+            next_run_at: job.next_run_after(now).map(|t| t.to_rfc3339()),
+            last_run: latest.remove(job.name).map(JobRunResult::from),
+        })
+        .collect();
+
+    Ok(Json(JobListResult { jobs }))
+}
+
+async fn runs(
+    State(state): State<AppState>,
+    _user: AdminUser,
+    Json(req): Json<JobRunsRequest>,
+) -> ApiResult<Json<JobRunListResult>> {
+    let limit = req.limit.unwrap_or(20).clamp(1, 100);
+    let runs = JobRun::find_recent(&req.job_name, limit, &state.deps.db_pool).await?;
+
+    Ok(Json(JobRunListResult {
+        runs: runs.into_iter().map(JobRunResult::from).collect(),
+    }))
+}
+
+async fn run_now(
+    State(state): State<AppState>,
+    user: AdminUser,
+    Json(req): Json<RunJobNowRequest>,
+) -> ApiResult<Json<JobRunResult>> {
+    let registry = builtin_registry()?;
+    let job = registry
+        .get(&req.job_name)
+        .ok_or_else(|| ApiError::NotFound(format!("Unknown job '{}'", req.job_name)))?;
+
+    tracing::info!(job = job.name, member_id = %user.0.member_id, "Manual job run requested");
+
+    match run_job(&job, JobTrigger::Manual, None, &state.deps).await? {
+        JobOutcome::Ran(run) => Ok(Json(run.into())),
+        JobOutcome::Locked | JobOutcome::AlreadyRan => Err(ApiError::Conflict(format!(
+            "Job '{}' is already running",
+            job.name
+        ))),
+    }
+}
+
+// =============================================================================
+// Router
+// =============================================================================
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/Jobs/list", post(list))
+        .route("/Jobs/runs", post(runs))
+        .route("/Jobs/run_now", post(run_now))
+}
@@ -7,6 +7,7 @@ use uuid::Uuid;
 use crate::api::auth::{AdminUser, OptionalUser};
 use crate::api::error::{ApiError, ApiResult};
 use crate::api::state::AppState;
+use crate::domains::auth::activities as auth_activities;
 use crate::domains::member::activities;
 use crate::domains::member::models::member::Member;
 
@@ -19,6 +20,12 @@ pub struct UpdateStatusRequest {
     pub active: bool,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct RevokeSessionsRequest {
+    /// Recorded on each revoked session, e.g. "left the newsroom".
+    pub reason: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct RegisterMemberRequest {
     pub expo_push_token: String,
@@ -52,6 +59,11 @@ impl From<Member> for MemberResult {
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct RevokeSessionsResult {
+    pub sessions_revoked: u64,
+}
+
 #[derive(Debug, Serialize)]
 pub struct RegisterMemberResult {
     pub member_id: Uuid,
@@ -88,6 +100,28 @@ async fn update_status(
     Ok(Json(MemberResult::from(member)))
 }
 
+/// Sign a member out everywhere: revokes all their sessions and the access
+/// tokens minted for them.
+async fn revoke_sessions(
+    State(state): State<AppState>,
+    user: AdminUser,
+    Path(member_id): Path<Uuid>,
+    Json(req): Json<RevokeSessionsRequest>,
+) -> ApiResult<Json<RevokeSessionsResult>> {
+    let reason = req.reason.as_deref().unwrap_or("revoked_by_admin");
+    let sessions_revoked =
+        auth_activities::revoke_member_sessions(member_id, reason, &state.deps).await?;
+
+    tracing::info!(
+        member_id = %member_id,
+        revoked_by = %user.0.member_id,
+        sessions_revoked,
+        "Member sessions revoked by admin"
+    );
+
+    Ok(Json(RevokeSessionsResult { sessions_revoked }))
+}
+
 // =============================================================================
 // RegisterMemberWorkflow handler
 // =============================================================================
@@ -127,6 +161,7 @@ pub fn router() -> Router<AppState> {
     Router::new()
         .route("/Member/{id}/get", post(get))
         .route("/Member/{id}/update_status", post(update_status))
+        .route("/Member/{id}/revoke_sessions", post(revoke_sessions))
         .route(
             "/RegisterMemberWorkflow/{key}/run",
             post(register_member),
@@ -1,13 +1,16 @@
 pub mod auth;
 pub mod editions;
+pub mod jobs;
 pub mod media;
 pub mod member_object;
 pub mod members;
 pub mod notes;
 pub mod organizations;
 pub mod posts;
+pub mod roles;
 pub mod tags;
 pub mod widgets;
+pub mod work_queue;
 
 use axum::Router;
 
@@ -17,12 +20,15 @@ pub fn router() -> Router<AppState> {
     Router::new()
         .merge(auth::router())
         .merge(editions::router())
+        .merge(jobs::router())
         .merge(media::router())
         .merge(member_object::router())
         .merge(members::router())
         .merge(notes::router())
         .merge(organizations::router())
         .merge(posts::router())
+        .merge(roles::router())
         .merge(tags::router())
         .merge(widgets::router())
+        .merge(work_queue::router())
 }
@@ -9,6 +9,9 @@ use crate::api::error::{ApiError, ApiResult};
 use crate::api::state::AppState;
 use crate::common::NoteId;
 use crate::domains::notes::models::{Note, Noteable};
+use crate::domains::pii::activities::scrub;
+use crate::domains::pii::models::NewPiiFinding;
+use crate::domains::pii::ScrubPolicy;
 
 // =============================================================================
 // Request types
@@ -180,19 +183,34 @@ async fn create(
     Json(req): Json<CreateNoteRequest>,
 ) -> ApiResult<Json<NoteResult>> {
     let pool = &state.deps.db_pool;
+    let is_public = req.is_public.unwrap_or(false);
+    let org_ids: Vec<Uuid> = match (req.noteable_type.as_deref(), req.noteable_id) {
+        (Some("organization"), Some(org_id)) => vec![org_id],
+        _ => Vec::new(),
+    };
+    let (content, pii_findings) =
+        scrub_note_content(&req.content, is_public, &org_ids, &state).await?;
 
     let note = Note::create(
-        &req.content,
+        &content,
         req.severity.as_deref().unwrap_or("info"),
         req.source_url.as_deref(),
         req.source_id,
         req.source_type.as_deref(),
-        req.is_public.unwrap_or(false),
+        is_public,
         "admin",
         req.cta_text.as_deref(),
         pool,
     )
     .await?;
+    scrub::record_findings(
+        "note",
+        note.id.into_uuid(),
+        ScrubPolicy::PUBLIC_NOTE,
+        &pii_findings,
+        pool,
+    )
+    .await?;
 
     // If entity linking info provided, link immediately
     if let (Some(noteable_type), Some(noteable_id)) = (&req.noteable_type, &req.noteable_id) {
@@ -246,19 +264,62 @@ async fn update(
     _user: AdminUser,
     Json(req): Json<UpdateNoteRequest>,
 ) -> ApiResult<Json<NoteResult>> {
+    let org_ids: Vec<Uuid> = if req.is_public {
+        Noteable::find_linked_orgs_for_notes(&[NoteId::from(req.id)], &state.deps.db_pool)
+            .await?
+            .into_iter()
+            .map(|lo| lo.org_id)
+            .collect()
+    } else {
+        Vec::new()
+    };
+    let (content, pii_findings) =
+        scrub_note_content(&req.content, req.is_public, &org_ids, &state).await?;
     let note = Note::update(
         NoteId::from(req.id),
-        &req.content,
+        &content,
         &req.severity,
         req.is_public,
         req.cta_text.as_deref(),
         &state.deps.db_pool,
     )
     .await?;
+    scrub::record_findings(
+        "note",
+        note.id.into_uuid(),
+        ScrubPolicy::PUBLIC_NOTE,
+        &pii_findings,
+        &state.deps.db_pool,
+    )
+    .await?;
 
     Ok(Json(NoteResult::from(note)))
 }
 
+/// Public notes are scrubbed before they are saved; internal (non-public)
+/// notes are admin-only and stored as written. Addresses of the
+/// organisations the note is attached to are allowed.
+async fn scrub_note_content(
+    content: &str,
+    is_public: bool,
+    org_ids: &[Uuid],
+    state: &AppState,
+) -> ApiResult<(String, Vec<NewPiiFinding>)> {
+    if !is_public {
+        return Ok((content.to_string(), Vec::new()));
+    }
+    let allow_list = scrub::organization_allow_list(org_ids, &state.deps.db_pool).await?;
+    let scrubbed = scrub::scrub_field(
+        "content",
+        content,
+        ScrubPolicy::PUBLIC_NOTE,
+        &allow_list,
+        &state.deps,
+    )
+    .await?;
+    Ok((scrubbed.clean_text, scrubbed.findings))
+}
+
 async fn delete(
     State(state): State<AppState>,
     _user: AdminUser,
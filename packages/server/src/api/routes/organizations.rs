@@ -9,12 +9,18 @@ use uuid::Uuid;
 use crate::api::auth::AdminUser;
 use crate::api::error::{ApiError, ApiResult};
 use crate::api::state::AppState;
-use crate::common::{OrganizationId, OrganizationLinkId};
+use crate::common::{OrganizationCalendarFeedId, OrganizationId, OrganizationLinkId};
+use crate::domains::media::ingest::ssrf;
 use crate::domains::notes::models::Note;
 use crate::domains::organization::models::organization_checklist::{
     CHECKLIST_KEYS, CHECKLIST_LABELS,
 };
-use crate::domains::organization::models::{Organization, OrganizationChecklistItem, OrganizationLink};
+use crate::domains::organization::models::{
+    Organization, OrganizationCalendarFeed, OrganizationChecklistItem, OrganizationLink,
+};
+use crate::domains::posts::activities::calendar_import::{
+    self, CalendarImportError, CalendarImportSummary,
+};
 use crate::domains::posts::models::Post;
 use crate::common::TagId;
 use crate::domains::tag::models::TagKindConfig;
@@ -845,6 +851,144 @@ async fn reorder_org_links(
     }))
 }
 
+// =============================================================================
+// Calendar feeds — iCalendar URLs imported as `event` posts.
+// See migration 254 and `posts::activities::calendar_import`.
+// =============================================================================
+
+#[derive(Debug, Serialize)]
+pub struct CalendarFeedResult {
+    pub id: String,
+    pub organization_id: String,
+    pub url: String,
+    pub active: bool,
+    pub last_fetched_at: Option<String>,
+    pub last_error: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CalendarFeedsResult {
+    pub feeds: Vec<CalendarFeedResult>,
+}
+
+fn calendar_feed_to_result(feed: &OrganizationCalendarFeed) -> CalendarFeedResult {
+    CalendarFeedResult {
+        id: feed.id.to_string(),
+        organization_id: feed.organization_id.to_string(),
+        url: feed.url.clone(),
+        active: feed.active,
+        last_fetched_at: feed.last_fetched_at.map(|t| t.to_rfc3339()),
+        last_error: feed.last_error.clone(),
+        created_at: feed.created_at.to_rfc3339(),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListCalendarFeedsRequest {
+    pub organization_id: Uuid,
+}
+
+async fn list_calendar_feeds(
+    State(state): State<AppState>,
+    _user: AdminUser,
+    Json(req): Json<ListCalendarFeedsRequest>,
+) -> ApiResult<Json<CalendarFeedsResult>> {
+    let org_id = OrganizationId::from(req.organization_id);
+    let feeds = OrganizationCalendarFeed::find_by_organization(org_id, &state.deps.db_pool).await?;
+    Ok(Json(CalendarFeedsResult {
+        feeds: feeds.iter().map(calendar_feed_to_result).collect(),
+    }))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AddCalendarFeedRequest {
+    pub organization_id: Uuid,
+    pub url: String,
+}
+
+/// Register a feed. The URL must pass the same SSRF guard the importer
+/// fetches through, so a bad URL is refused here rather than failing
+/// every six hours.
+async fn add_calendar_feed(
+    State(state): State<AppState>,
+    _user: AdminUser,
+    Json(req): Json<AddCalendarFeedRequest>,
+) -> ApiResult<Json<CalendarFeedResult>> {
+    let pool = &state.deps.db_pool;
+    let org_id = OrganizationId::from(req.organization_id);
+    let url = ssrf::validate_url(req.url.trim())
+        .map_err(|e| ApiError::BadRequest(format!("url: {}", e)))?;
+
+    let existing = OrganizationCalendarFeed::find_by_organization(org_id, pool).await?;
+    if existing.iter().any(|f| f.url == url.as_str()) {
+        return Err(ApiError::Conflict(
+            "This calendar feed is already registered".into(),
+        ));
+    }
+
+    let feed = OrganizationCalendarFeed::create(org_id, url.as_str(), pool).await?;
+    info!(org_id = %org_id, feed_id = %feed.id, "Added calendar feed");
+    Ok(Json(calendar_feed_to_result(&feed)))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SetCalendarFeedActiveRequest {
+    pub feed_id: Uuid,
+    pub active: bool,
+}
+
+async fn set_calendar_feed_active(
+    State(state): State<AppState>,
+    _user: AdminUser,
+    Json(req): Json<SetCalendarFeedActiveRequest>,
+) -> ApiResult<Json<CalendarFeedResult>> {
+    let feed = OrganizationCalendarFeed::set_active(
+        OrganizationCalendarFeedId::from(req.feed_id),
+        req.active,
+        &state.deps.db_pool,
+    )
+    .await?;
+    Ok(Json(calendar_feed_to_result(&feed)))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CalendarFeedRequest {
+    pub feed_id: Uuid,
+}
+
+async fn delete_calendar_feed(
+    State(state): State<AppState>,
+    _user: AdminUser,
+    Json(req): Json<CalendarFeedRequest>,
+) -> ApiResult<Json<serde_json::Value>> {
+    OrganizationCalendarFeed::delete(
+        OrganizationCalendarFeedId::from(req.feed_id),
+        &state.deps.db_pool,
+    )
+    .await?;
+    info!(feed_id = %req.feed_id, "Deleted calendar feed");
+    Ok(Json(serde_json::json!({ "ok": true })))
+}
+
+/// Import a feed now, inline, and report what changed. Imported posts
+/// land in the Signal inbox for review.
+async fn import_calendar_feed(
+    State(state): State<AppState>,
+    _user: AdminUser,
+    Json(req): Json<CalendarFeedRequest>,
+) -> ApiResult<Json<CalendarImportSummary>> {
+    let feed_id = OrganizationCalendarFeedId::from(req.feed_id);
+    calendar_import::import_feed(feed_id, &state.deps)
+        .await
+        .map(Json)
+        .map_err(|e| match e {
+            CalendarImportError::FeedNotFound => ApiError::NotFound(e.to_string()),
+            CalendarImportError::Db(err) => ApiError::Internal(err),
+            other => ApiError::BadRequest(other.to_string()),
+        })
+}
+
 // =============================================================================
 // Router
 // =============================================================================
@@ -885,4 +1029,10 @@ pub fn router() -> Router<AppState> {
         .route("/Organizations/upsert_link", post(upsert_org_link))
         .route("/Organizations/delete_link", post(delete_org_link))
         .route("/Organizations/reorder_links", post(reorder_org_links))
+        // Calendar feeds (iCalendar import — see migration 254)
+        .route("/Organizations/list_calendar_feeds", post(list_calendar_feeds))
+        .route("/Organizations/add_calendar_feed", post(add_calendar_feed))
+        .route("/Organizations/set_calendar_feed_active", post(set_calendar_feed_active))
+        .route("/Organizations/delete_calendar_feed", post(delete_calendar_feed))
+        .route("/Organizations/import_calendar_feed", post(import_calendar_feed))
 }
@@ -5,33 +5,42 @@
 
 use axum::body::Bytes;
 use axum::extract::{Path, State};
-use axum::http::HeaderMap;
-use axum::routing::post;
+use axum::http::{header, HeaderMap};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
 use axum::{Json, Router};
 use rust_decimal::prelude::ToPrimitive;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use uuid::Uuid;
 
-use crate::api::auth::{AdminUser, OptionalUser, ServiceClientAuth};
+use crate::api::auth::{AuthUser, OptionalUser, ServiceClientAuth, StaffUser};
 use crate::api::error::{ApiError, ApiResult};
 use crate::api::state::AppState;
+use crate::common::auth::{AdminCapability, CountyScope};
 use crate::common::{PaginationArgs, PostId, ScheduleId};
 use crate::domains::contacts::Contact;
+use crate::domains::editions::models::county::County;
 use crate::domains::editions::Edition;
 use crate::domains::locations::models::ZipCode;
 use crate::domains::notes::models::note::Note;
+use crate::domains::pii::models::PiiFinding;
 use crate::domains::posts::activities;
+use crate::domains::posts::activities::map_points;
+use crate::domains::posts::activities::near_duplicate::{self, SimilarPair};
+use crate::domains::posts::activities::post_merge::{
+    self, FieldGroup, PostMergeError, PostMergeSummary,
+};
 use crate::domains::posts::activities::schedule::ScheduleParams;
-use crate::domains::posts::activities::tags::TagInput;
-use crate::domains::posts::data::types::SubmitPostInput;
+use crate::domains::posts::activities::tags::{load_tags_and_notes, TagInput};
+use crate::domains::posts::data::types::{PublicTagResult, SubmitPostInput, UrgentNoteInfo};
 use crate::domains::posts::models::post::PostFilters;
 use crate::domains::posts::models::post_report::{PostReportRecord, PostReportWithDetails};
 use crate::domains::posts::models::Post;
 use crate::domains::posts::models::{
     PostMediaRecord, PostMetaRecord, PostPersonRecord, PostLinkRecord,
     PostSource, PostSourceAttr, PostSourceEnriched, PostDatetimeRecord,
-    PostStatusRecord,
+    PostReviewFlag, PostStatusRecord,
 };
 use crate::common::PostSourceId;
 use crate::domains::schedules::models::Schedule;
@@ -118,6 +127,21 @@ pub struct PublicListRequest {
     pub radius_miles: Option<f64>,
 }
 
+/// Viewport for `/Posts/map_points`. Missing bounds default to Minnesota,
+/// missing zoom to the map page's initial zoom (7).
+#[derive(Debug, Clone, Deserialize)]
+pub struct MapPointsRequest {
+    pub min_lat: Option<f64>,
+    pub max_lat: Option<f64>,
+    pub min_lng: Option<f64>,
+    pub max_lng: Option<f64>,
+    pub zoom: Option<u8>,
+    pub post_type: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub county_id: Option<Uuid>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct ListPostsByOrganizationRequest {
     pub organization_id: Uuid,
@@ -136,6 +160,17 @@ pub struct BackfillLocationsRequest {
     pub batch_size: Option<i32>,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct NearDuplicateClustersRequest {
+    /// Defaults to the ingest flagging threshold.
+    pub min_similarity: Option<f64>,
+    /// Only posts published in the last `days` days (default 30, max 90).
+    pub days: Option<i64>,
+    /// Only posts resolved to this county. Defaults to the caller's
+    /// granted counties.
+    pub county_id: Option<Uuid>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct SchedulesForEntityRequest {
     pub schedulable_type: String,
@@ -199,6 +234,20 @@ pub struct ResolveReportRequest {
     pub action_taken: String,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct MergeIntoRequest {
+    /// The post that survives; `{id}` is the duplicate folded into it.
+    pub survivor_post_id: Uuid,
+    /// Field groups to take from the duplicate. The survivor keeps the rest.
+    #[serde(default)]
+    pub keep_from_duplicate: Vec<FieldGroup>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ResolveReviewFlagRequest {
+    pub flag_id: Uuid,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct DismissReportRequest {
     pub report_id: Uuid,
@@ -332,12 +381,6 @@ pub struct PostScheduleResult {
     pub duration_minutes: Option<i32>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct UrgentNoteInfo {
-    pub content: String,
-    pub cta_text: Option<String>,
-}
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PostResult {
     pub id: Uuid,
@@ -550,14 +593,6 @@ pub struct PublicPostResult {
     pub organization_name: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct PublicTagResult {
-    pub kind: String,
-    pub value: String,
-    pub display_name: Option<String>,
-    pub color: Option<String>,
-}
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PublicListResult {
     pub posts: Vec<PublicPostResult>,
@@ -625,50 +660,46 @@ pub struct PostReportListResult {
     pub reports: Vec<ReportResult>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct OptionalPostResult {
-    pub post: Option<PostResult>,
+#[derive(Debug, Serialize)]
+pub struct PiiFindingListResult {
+    pub findings: Vec<PiiFinding>,
 }
 
-// =============================================================================
-// Helper: batch-load public tags and urgent notes for a set of post IDs
-// =============================================================================
+#[derive(Debug, Clone, Serialize)]
+pub struct ClusterPostResult {
+    pub id: Uuid,
+    pub title: String,
+    pub status: String,
+    pub published_at: Option<String>,
+}
 
-pub(crate) async fn load_tags_and_notes(
-    post_ids: &[Uuid],
-    deps: &ServerDeps,
-) -> ApiResult<(
-    HashMap<Uuid, Vec<PublicTagResult>>,
-    HashMap<Uuid, Vec<UrgentNoteInfo>>,
-)> {
-    let tag_rows = Tag::find_public_for_post_ids(post_ids, &deps.db_pool).await?;
+#[derive(Debug, Clone, Serialize)]
+pub struct NearDuplicateClusterResult {
+    pub posts: Vec<ClusterPostResult>,
+    pub pairs: Vec<SimilarPair>,
+}
 
-    let urgent_rows = Note::find_urgent_note_content_for_posts(post_ids, &deps.db_pool)
-        .await
-        .unwrap_or_default();
+#[derive(Debug, Clone, Serialize)]
+pub struct NearDuplicateClustersResult {
+    pub clusters: Vec<NearDuplicateClusterResult>,
+}
 
-    let mut tags_by_post: HashMap<Uuid, Vec<PublicTagResult>> = HashMap::new();
-    for row in tag_rows {
-        tags_by_post
-            .entry(row.taggable_id)
-            .or_default()
-            .push(PublicTagResult {
-                kind: row.tag.kind,
-                value: row.tag.value,
-                display_name: row.tag.display_name,
-                color: row.tag.color,
-            });
-    }
+#[derive(Debug, Clone, Serialize)]
+pub struct ReviewFlagListResult {
+    pub flags: Vec<PostReviewFlag>,
+}
 
-    let mut urgent_notes_by_post: HashMap<Uuid, Vec<UrgentNoteInfo>> = HashMap::new();
-    for (post_id, content, cta_text) in urgent_rows {
-        urgent_notes_by_post
-            .entry(post_id)
-            .or_default()
-            .push(UrgentNoteInfo { content, cta_text });
-    }
+#[derive(Debug, Clone, Serialize)]
+pub struct ResolveReviewFlagResult {
+    pub flag: PostReviewFlag,
+    /// The post's status afterwards — `active` when this cleared its last
+    /// blocking flag.
+    pub post_status: String,
+}
 
-    Ok((tags_by_post, urgent_notes_by_post))
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OptionalPostResult {
+    pub post: Option<PostResult>,
 }
 
 // =============================================================================
@@ -791,9 +822,10 @@ async fn build_post_result(
 
 async fn list(
     State(state): State<AppState>,
-    _user: AdminUser,
+    user: StaffUser,
     Json(req): Json<ListPostsRequest>,
 ) -> ApiResult<Json<PostListResult>> {
+    let county_ids = user.0.county_filter(AdminCapability::ViewPosts, None)?;
     let deps = &state.deps;
 
     let filters = PostFilters {
@@ -806,6 +838,7 @@ async fn list(
         exclude_submission_type: req.exclude_submission_type.as_deref(),
         county_id: req.county_id,
         statewide_only: req.statewide_only.unwrap_or(false),
+        county_ids: county_ids.as_deref(),
     };
 
     if let Some(ref zip_code) = req.zip_code {
@@ -967,43 +1000,68 @@ async fn list(
 }
 
 // =============================================================================
-// Signal Inbox list — status=in_review with derived review_flags
+// Signal Inbox list — status=in_review with persisted review flags
 // =============================================================================
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct ListInReviewRequest {
     pub limit: Option<i64>,
     pub offset: Option<i64>,
+    /// Only posts with this flag open (e.g. `pii_detected`).
+    pub flag: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
 pub struct InReviewPostResult {
     #[serde(flatten)]
     pub post: PostResult,
+    /// Open flag codes, for badges.
     pub review_flags: Vec<String>,
+    /// The same flags with ids and details, for resolving one at a time.
+    pub review_flag_records: Vec<PostReviewFlag>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReviewFlagCount {
+    pub flag: String,
+    pub count: i64,
 }
 
 #[derive(Debug, Clone, Serialize)]
 pub struct InReviewListResult {
     pub posts: Vec<InReviewPostResult>,
     pub total_count: i64,
+    /// Open flags per reason across the whole inbox, ignoring `flag`.
+    pub flag_counts: Vec<ReviewFlagCount>,
 }
 
-/// Signal Inbox backend — returns posts with `status = 'in_review'` and a
-/// per-post `review_flags` array derived at query time from current columns
-/// (see `Post::find_in_review_with_flags`). Tags, org info, and field groups
+/// Signal Inbox backend — returns posts with `status = 'in_review'` and
+/// their open `post_review_flags` (see `Post::find_in_review_with_flags`),
+/// plus per-reason counts for grouping. Tags, org info, and field groups
 /// the admin UI renders are hydrated alongside.
 async fn list_in_review(
     State(state): State<AppState>,
-    _user: AdminUser,
+    user: StaffUser,
     Json(req): Json<ListInReviewRequest>,
 ) -> ApiResult<Json<InReviewListResult>> {
+    let county_ids = user.0.county_filter(AdminCapability::ViewPosts, None)?;
     let deps = &state.deps;
     let limit = req.limit.unwrap_or(100).clamp(1, 500);
     let offset = req.offset.unwrap_or(0).max(0);
 
-    let (rows, total_count) =
-        Post::find_in_review_with_flags(limit, offset, &deps.db_pool).await?;
+    let (rows, total_count) = Post::find_in_review_with_flags(
+        req.flag.as_deref(),
+        county_ids.as_deref(),
+        limit,
+        offset,
+        &deps.db_pool,
+    )
+    .await?;
+    let flag_counts = PostReviewFlag::count_open_by_flag(county_ids.as_deref(), &deps.db_pool)
+        .await?
+        .into_iter()
+        .map(|(flag, count)| ReviewFlagCount { flag, count })
+        .collect();
 
     let post_ids: Vec<Uuid> = rows.iter().map(|(p, _)| p.id.into_uuid()).collect();
 
@@ -1036,12 +1094,17 @@ async fn list_in_review(
             }
             InReviewPostResult {
                 post: pr,
-                review_flags: flags,
+                review_flags: flags.iter().map(|f| f.flag.clone()).collect(),
+                review_flag_records: flags,
             }
         })
         .collect();
 
-    Ok(Json(InReviewListResult { posts, total_count }))
+    Ok(Json(InReviewListResult {
+        posts,
+        total_count,
+        flag_counts,
+    }))
 }
 
 /// List posts eligible for a given edition, mirroring the layout engine's
@@ -1049,13 +1112,14 @@ async fn list_in_review(
 /// with optional slotted/not_slotted filtering relative to the edition.
 async fn list_for_edition(
     State(state): State<AppState>,
-    _user: AdminUser,
+    user: StaffUser,
     Json(req): Json<ListPostsForEditionRequest>,
 ) -> ApiResult<Json<PostListResult>> {
     let deps = &state.deps;
     let edition = Edition::find_by_id(req.edition_id, &deps.db_pool)
         .await?
         .ok_or_else(|| ApiError::NotFound(format!("Edition not found: {}", req.edition_id)))?;
+    user.0.require(AdminCapability::ViewPosts, CountyScope::County(edition.county_id))?;
 
     let slotted_filter = req.slotted_filter.as_deref().unwrap_or("all");
     let limit = req.limit.unwrap_or(200);
@@ -1211,17 +1275,21 @@ async fn submit(
 
 async fn list_pending_revisions(
     State(state): State<AppState>,
-    _user: AdminUser,
+    user: StaffUser,
     Json(req): Json<PendingRevisionsRequest>,
 ) -> ApiResult<Json<PendingRevisionsResult>> {
+    let county_ids = user.0.county_filter(AdminCapability::ViewPosts, None)?;
     let source_filter = match (req.source_type.as_deref(), req.source_id) {
         (Some(st), Some(sid)) => Some((st, sid)),
         _ => None,
     };
 
-    let revisions =
-        activities::revision_actions::get_pending_revisions(source_filter, &state.deps.db_pool)
-            .await?;
+    let revisions = activities::revision_actions::get_pending_revisions(
+        source_filter,
+        county_ids.as_deref(),
+        &state.deps.db_pool,
+    )
+    .await?;
 
     Ok(Json(PendingRevisionsResult {
         posts: revisions.into_iter().map(PostResult::from).collect(),
@@ -1230,17 +1298,22 @@ async fn list_pending_revisions(
 
 async fn list_reports(
     State(state): State<AppState>,
-    _user: AdminUser,
+    user: StaffUser,
     Json(req): Json<ListReportsRequest>,
 ) -> ApiResult<Json<ReportListResult>> {
+    let county_ids = user.0.county_filter(AdminCapability::ViewPosts, None)?;
     let limit = req.limit.unwrap_or(50) as i64;
     let offset = req.offset.unwrap_or(0) as i64;
 
     let reports: Vec<PostReportWithDetails> = match req.status.as_deref() {
         Some("pending") | None => {
-            PostReportRecord::query_pending(limit, offset, &state.deps.db_pool).await
+            PostReportRecord::query_pending(limit, offset, county_ids.as_deref(), &state.deps.db_pool)
+                .await
+        }
+        _ => {
+            PostReportRecord::query_all(limit, offset, county_ids.as_deref(), &state.deps.db_pool)
+                .await
         }
-        _ => PostReportRecord::query_all(limit, offset, &state.deps.db_pool).await,
     }
     .map_err(|e| ApiError::Internal(e.into()))?;
 
@@ -1316,9 +1389,10 @@ async fn schedules_for_entity(
 
 async fn backfill_locations(
     State(state): State<AppState>,
-    _user: AdminUser,
+    user: StaffUser,
     Json(req): Json<BackfillLocationsRequest>,
 ) -> ApiResult<Json<BackfillLocationsResult>> {
+    user.0.require(AdminCapability::EditPosts, CountyScope::STATEWIDE)?;
     let batch_size = req.batch_size.unwrap_or(100).min(500) as i64;
 
     let r = activities::backfill::backfill_post_locations(batch_size, &state.deps).await?;
@@ -1330,15 +1404,68 @@ async fn backfill_locations(
     }))
 }
 
+/// Clusters of near-duplicate active posts (see
+/// `activities::near_duplicate`) published in the last `days` days, in the
+/// caller's counties. Posts without a signature yet are indexed by the
+/// `index_post_similarity` job.
+async fn near_duplicate_clusters(
+    State(state): State<AppState>,
+    user: StaffUser,
+    Json(req): Json<NearDuplicateClustersRequest>,
+) -> ApiResult<Json<NearDuplicateClustersResult>> {
+    let county_ids = user.0.county_filter(AdminCapability::ViewPosts, req.county_id)?;
+    let pool = &state.deps.db_pool;
+    let min_similarity = req
+        .min_similarity
+        .unwrap_or(near_duplicate::SIMILARITY_THRESHOLD)
+        .clamp(0.0, 1.0);
+    let since = chrono::Utc::now() - chrono::Duration::days(req.days.unwrap_or(30).clamp(1, 90));
+
+    let clusters =
+        near_duplicate::find_clusters(min_similarity, since, county_ids.as_deref(), pool).await?;
+
+    let ids: Vec<Uuid> = clusters.iter().flat_map(|c| c.post_ids.clone()).collect();
+    let posts: HashMap<Uuid, Post> = Post::find_by_ids(&ids, pool)
+        .await?
+        .into_iter()
+        .map(|p| (p.id.into_uuid(), p))
+        .collect();
+
+    let clusters = clusters
+        .into_iter()
+        .map(|c| NearDuplicateClusterResult {
+            posts: c
+                .post_ids
+                .iter()
+                .filter_map(|id| posts.get(id))
+                .map(|p| ClusterPostResult {
+                    id: p.id.into_uuid(),
+                    title: p.title.clone(),
+                    status: p.status.clone(),
+                    published_at: p.published_at.map(|t| t.to_rfc3339()),
+                })
+                .collect(),
+            pairs: c.pairs,
+        })
+        .collect();
+
+    Ok(Json(NearDuplicateClustersResult { clusters }))
+}
+
 async fn list_by_organization(
     State(state): State<AppState>,
-    _user: AdminUser,
+    user: StaffUser,
     Json(req): Json<ListPostsByOrganizationRequest>,
 ) -> ApiResult<Json<PostListResult>> {
+    let county_ids = user.0.county_filter(AdminCapability::ViewPosts, None)?;
     let deps = &state.deps;
 
-    let posts =
-        Post::find_all_by_organization_id(req.organization_id, &deps.db_pool).await?;
+    let posts = Post::find_all_by_organization_id(
+        req.organization_id,
+        county_ids.as_deref(),
+        &deps.db_pool,
+    )
+    .await?;
 
     let post_ids: Vec<Uuid> = posts.iter().map(|p| p.id.into_uuid()).collect();
     let tag_rows = Tag::find_for_post_ids(&post_ids, &deps.db_pool).await?;
@@ -1443,7 +1570,7 @@ async fn public_list(
             let post_ids: Vec<Uuid> =
                 nearby_posts.iter().map(|p| p.id.into_uuid()).collect();
             let (mut tags_by_post, mut urgent_notes_by_post) =
-                load_tags_and_notes(&post_ids, deps).await?;
+                load_tags_and_notes(&post_ids, &deps.db_pool).await?;
             let mut org_info =
                 Post::find_org_info_for_posts(&post_ids, &deps.db_pool).await?;
 
@@ -1486,7 +1613,7 @@ async fn public_list(
 
             let post_ids: Vec<Uuid> = posts.iter().map(|p| p.id.into_uuid()).collect();
             let (mut tags_by_post, mut urgent_notes_by_post) =
-                load_tags_and_notes(&post_ids, deps).await?;
+                load_tags_and_notes(&post_ids, &deps.db_pool).await?;
             let mut org_info =
                 Post::find_org_info_for_posts(&post_ids, &deps.db_pool).await?;
 
@@ -1526,6 +1653,29 @@ async fn public_list(
     }))
 }
 
+/// Public map data as a GeoJSON FeatureCollection: post points when zoomed
+/// in, per-cell cluster counts when zoomed out (see
+/// `activities::map_points`).
+async fn map_points(
+    State(state): State<AppState>,
+    Json(req): Json<MapPointsRequest>,
+) -> ApiResult<Json<map_points::FeatureCollection>> {
+    let query = map_points::MapQuery {
+        min_lat: req.min_lat.unwrap_or(43.49),
+        max_lat: req.max_lat.unwrap_or(49.39),
+        min_lng: req.min_lng.unwrap_or(-97.24),
+        max_lng: req.max_lng.unwrap_or(-89.48),
+        zoom: req.zoom.unwrap_or(7),
+        post_type: req.post_type,
+        tags: req.tags,
+        county_id: req.county_id,
+    };
+    query.validate().map_err(ApiError::BadRequest)?;
+
+    let features = map_points::load_map(&query, &state.deps.db_pool).await?;
+    Ok(Json(features))
+}
+
 async fn public_filters(
     State(state): State<AppState>,
     Json(_req): Json<PublicFiltersRequest>,
@@ -1558,10 +1708,13 @@ async fn expire_stale_posts(
 
 async fn stats(
     State(state): State<AppState>,
-    _user: AdminUser,
+    user: StaffUser,
     Json(req): Json<PostStatsRequest>,
 ) -> ApiResult<Json<PostStatsResult>> {
-    let rows = Post::stats_by_status(req.status.as_deref(), &state.deps.db_pool).await?;
+    let county_ids = user.0.county_filter(AdminCapability::ViewPosts, None)?;
+    let rows =
+        Post::stats_by_status(req.status.as_deref(), county_ids.as_deref(), &state.deps.db_pool)
+            .await?;
 
     let mut total: i64 = 0;
     let mut stories: i64 = 0;
@@ -1698,9 +1851,11 @@ async fn create_post(
 /// the ServiceClient-authenticated ingest endpoint.
 async fn admin_create_post(
     State(state): State<AppState>,
-    user: AdminUser,
+    user: StaffUser,
     Json(req): Json<AdminCreatePostRequest>,
 ) -> ApiResult<Json<PostResult>> {
+    // A new post has no county yet, so creating one needs an unscoped grant.
+    user.0.require(AdminCapability::EditPosts, CountyScope::STATEWIDE)?;
     let post = activities::admin_create_post(
         req.title,
         req.body_raw,
@@ -1721,6 +1876,34 @@ async fn admin_create_post(
 // Handlers — Post virtual object (keyed, singular path: /Post/{id}/...)
 // =============================================================================
 
+/// Require `capability` for every county the post belongs to. Full admins
+/// skip the lookup; posts with no county (or tagged statewide) need an
+/// unscoped grant.
+async fn require_post_capability(
+    user: &AuthUser,
+    capability: AdminCapability,
+    post_id: Uuid,
+    state: &AppState,
+) -> ApiResult<()> {
+    if user.is_admin {
+        return Ok(());
+    }
+    let county_ids =
+        Post::find_county_ids(PostId::from_uuid(post_id), &state.deps.db_pool).await?;
+    user.require(capability, CountyScope::Counties(&county_ids))
+}
+
+/// Reject a child record (report, contact, schedule) addressed through a
+/// post it doesn't belong to, so the post's capability check can't be
+/// borrowed for another county's record. Full admins are not restricted.
+fn ensure_belongs_to_post(user: &AuthUser, belongs: bool, what: &str) -> ApiResult<()> {
+    if user.is_admin || belongs {
+        Ok(())
+    } else {
+        Err(ApiError::NotFound(format!("{what} not found for this post")))
+    }
+}
+
 async fn get_post(
     State(state): State<AppState>,
     Path(post_id): Path<Uuid>,
@@ -1729,12 +1912,16 @@ async fn get_post(
 ) -> ApiResult<Json<PostResult>> {
     let is_admin = user.0.as_ref().map(|u| u.is_admin).unwrap_or(false);
 
-    // Non-admins can only see active, non-deleted posts
+    // Non-admins can only see active, non-deleted, non-embargoed posts
     let post = Post::find_by_id(PostId::from_uuid(post_id), &state.deps.db_pool)
         .await?
         .ok_or_else(|| ApiError::NotFound("Post not found".into()))?;
 
-    if !is_admin && (post.status != "active" || post.deleted_at.is_some()) {
+    if !is_admin
+        && (post.status != "active"
+            || post.deleted_at.is_some()
+            || post.is_embargoed(chrono::Utc::now()))
+    {
         return Err(ApiError::NotFound("Post not found".into()));
     }
 
@@ -1744,6 +1931,48 @@ async fn get_post(
         .map(Json)
 }
 
+/// iCalendar for one event post. Public and unauthenticated so calendar
+/// apps can fetch it; only active, non-embargoed posts are served.
+async fn post_calendar(
+    State(state): State<AppState>,
+    Path(post_id): Path<Uuid>,
+) -> ApiResult<Response> {
+    let post = Post::find_by_id(PostId::from_uuid(post_id), &state.deps.db_pool)
+        .await?
+        .filter(|p| {
+            p.status == "active" && p.deleted_at.is_none() && !p.is_embargoed(chrono::Utc::now())
+        })
+        .ok_or_else(|| ApiError::NotFound("Post not found".into()))?;
+
+    let ics = activities::calendar_export::post_calendar(&post, &state.deps).await?;
+    Ok(calendar_response(ics, &format!("post-{}.ics", post_id)))
+}
+
+/// Subscribable iCalendar feed of a county's event posts.
+async fn county_calendar(
+    State(state): State<AppState>,
+    Path(county_id): Path<Uuid>,
+) -> ApiResult<Response> {
+    let county = County::find_by_id(county_id, &state.deps.db_pool)
+        .await?
+        .ok_or_else(|| ApiError::NotFound("County not found".into()))?;
+
+    let ics = activities::calendar_export::county_calendar(&county, &state.deps).await?;
+    Ok(calendar_response(ics, &format!("county-{}.ics", county_id)))
+}
+
+fn calendar_response(ics: String, filename: &str) -> Response {
+    (
+        [
+            (header::CONTENT_TYPE, "text/calendar; charset=utf-8".to_string()),
+            (header::CONTENT_DISPOSITION, format!("inline; filename=\"{}\"", filename)),
+            (header::CACHE_CONTROL, "public, max-age=900".to_string()),
+        ],
+        ics,
+    )
+        .into_response()
+}
+
 /// Preview endpoint — admin-only view of a post at any status. Mirrors
 /// the `/Editions/preview_broadsheet` pattern: returning 401 here is the
 /// signal the web-app uses to show its "Admin Access Required" banner
@@ -1755,8 +1984,9 @@ async fn get_post(
 async fn preview_post(
     State(state): State<AppState>,
     Path(post_id): Path<Uuid>,
-    _user: AdminUser,
+    user: StaffUser,
 ) -> ApiResult<Json<PostResult>> {
+    require_post_capability(&user.0, AdminCapability::ViewPosts, post_id, &state).await?;
     // Still 404 if the post genuinely doesn't exist.
     let _post = Post::find_by_id(PostId::from_uuid(post_id), &state.deps.db_pool)
         .await?
@@ -1786,7 +2016,7 @@ async fn get_related_posts(
 
     let post_ids: Vec<Uuid> = related.iter().map(|p| p.id.into_uuid()).collect();
     let (mut tags_by_post, _urgent_notes_by_post) =
-        load_tags_and_notes(&post_ids, &state.deps).await?;
+        load_tags_and_notes(&post_ids, &state.deps.db_pool).await?;
 
     let results = related
         .into_iter()
@@ -1817,13 +2047,15 @@ async fn get_related_posts(
 async fn approve(
     State(state): State<AppState>,
     Path(post_id): Path<Uuid>,
-    user: AdminUser,
+    user: StaffUser,
     Json(_req): Json<ApprovePostRequest>,
 ) -> ApiResult<Json<PostResult>> {
+    require_post_capability(&user.0, AdminCapability::ReviewPosts, post_id, &state).await?;
     activities::approve_post(
         post_id,
         user.0.member_id.into_uuid(),
         user.0.is_admin,
+        &user.0.grants,
         &state.deps,
     )
     .await?;
@@ -1838,9 +2070,10 @@ async fn approve(
 async fn edit_and_approve(
     State(state): State<AppState>,
     Path(post_id): Path<Uuid>,
-    user: AdminUser,
+    user: StaffUser,
     Json(req): Json<EditApproveRequest>,
 ) -> ApiResult<Json<PostResult>> {
+    require_post_capability(&user.0, AdminCapability::ReviewPosts, post_id, &state).await?;
     use crate::domains::posts::data::types::EditPostInput;
 
     let edit_input = EditPostInput {
@@ -1855,6 +2088,7 @@ async fn edit_and_approve(
         edit_input,
         user.0.member_id.into_uuid(),
         user.0.is_admin,
+        &user.0.grants,
         &state.deps,
     )
     .await?;
@@ -1869,14 +2103,16 @@ async fn edit_and_approve(
 async fn reject(
     State(state): State<AppState>,
     Path(post_id): Path<Uuid>,
-    user: AdminUser,
+    user: StaffUser,
     Json(req): Json<RejectPostRequest>,
 ) -> ApiResult<Json<PostResult>> {
+    require_post_capability(&user.0, AdminCapability::ReviewPosts, post_id, &state).await?;
     activities::reject_post(
         post_id,
         req.reason,
         user.0.member_id.into_uuid(),
         user.0.is_admin,
+        &user.0.grants,
         &state.deps,
     )
     .await?;
@@ -1891,13 +2127,15 @@ async fn reject(
 async fn delete(
     State(state): State<AppState>,
     Path(post_id): Path<Uuid>,
-    user: AdminUser,
+    user: StaffUser,
     Json(_req): Json<EmptyRequest>,
 ) -> ApiResult<Json<()>> {
+    require_post_capability(&user.0, AdminCapability::DeletePosts, post_id, &state).await?;
     activities::delete_post(
         post_id,
         user.0.member_id.into_uuid(),
         user.0.is_admin,
+        &user.0.grants,
         &state.deps,
     )
     .await?;
@@ -1908,13 +2146,15 @@ async fn delete(
 async fn archive(
     State(state): State<AppState>,
     Path(post_id): Path<Uuid>,
-    user: AdminUser,
+    user: StaffUser,
     Json(_req): Json<EmptyRequest>,
 ) -> ApiResult<Json<PostResult>> {
+    require_post_capability(&user.0, AdminCapability::DeletePosts, post_id, &state).await?;
     activities::archive_post(
         post_id,
         user.0.member_id.into_uuid(),
         user.0.is_admin,
+        &user.0.grants,
         &state.deps,
     )
     .await?;
@@ -1929,9 +2169,10 @@ async fn archive(
 async fn reactivate(
     State(state): State<AppState>,
     Path(post_id): Path<Uuid>,
-    _user: AdminUser,
+    user: StaffUser,
     Json(_req): Json<EmptyRequest>,
 ) -> ApiResult<Json<PostResult>> {
+    require_post_capability(&user.0, AdminCapability::ReviewPosts, post_id, &state).await?;
     Post::update_status(PostId::from_uuid(post_id), "active", &state.deps.db_pool)
         .await?;
 
@@ -1945,13 +2186,15 @@ async fn reactivate(
 async fn expire(
     State(state): State<AppState>,
     Path(post_id): Path<Uuid>,
-    user: AdminUser,
+    user: StaffUser,
     Json(_req): Json<EmptyRequest>,
 ) -> ApiResult<Json<PostResult>> {
+    require_post_capability(&user.0, AdminCapability::ReviewPosts, post_id, &state).await?;
     activities::expire_post(
         post_id,
         user.0.member_id.into_uuid(),
         user.0.is_admin,
+        &user.0.grants,
         &state.deps,
     )
     .await?;
@@ -1984,14 +2227,23 @@ async fn report(
 
 async fn resolve_report(
     State(state): State<AppState>,
-    Path(_post_id): Path<Uuid>,
-    user: AdminUser,
+    Path(post_id): Path<Uuid>,
+    user: StaffUser,
     Json(req): Json<ResolveReportRequest>,
 ) -> ApiResult<Json<()>> {
+    require_post_capability(&user.0, AdminCapability::ReviewPosts, post_id, &state).await?;
+    let reports =
+        PostReportRecord::query_for_post(PostId::from_uuid(post_id), &state.deps.db_pool).await?;
+    ensure_belongs_to_post(
+        &user.0,
+        reports.iter().any(|r| r.id.into_uuid() == req.report_id),
+        "Report",
+    )?;
     activities::resolve_report(
         req.report_id,
         user.0.member_id.into_uuid(),
         user.0.is_admin,
+        &user.0.grants,
         req.resolution_notes,
         req.action_taken,
         &state.deps,
@@ -2003,14 +2255,23 @@ async fn resolve_report(
 
 async fn dismiss_report(
     State(state): State<AppState>,
-    Path(_post_id): Path<Uuid>,
-    user: AdminUser,
+    Path(post_id): Path<Uuid>,
+    user: StaffUser,
     Json(req): Json<DismissReportRequest>,
 ) -> ApiResult<Json<()>> {
+    require_post_capability(&user.0, AdminCapability::ReviewPosts, post_id, &state).await?;
+    let reports =
+        PostReportRecord::query_for_post(PostId::from_uuid(post_id), &state.deps.db_pool).await?;
+    ensure_belongs_to_post(
+        &user.0,
+        reports.iter().any(|r| r.id.into_uuid() == req.report_id),
+        "Report",
+    )?;
     activities::dismiss_report(
         req.report_id,
         user.0.member_id.into_uuid(),
         user.0.is_admin,
+        &user.0.grants,
         req.resolution_notes,
         &state.deps,
     )
@@ -2022,9 +2283,10 @@ async fn dismiss_report(
 async fn update_tags(
     State(state): State<AppState>,
     Path(post_id): Path<Uuid>,
-    _user: AdminUser,
+    user: StaffUser,
     Json(req): Json<UpdateTagsRequest>,
 ) -> ApiResult<Json<PostResult>> {
+    require_post_capability(&user.0, AdminCapability::EditPosts, post_id, &state).await?;
     let tags: Vec<TagInput> = req
         .tags
         .into_iter()
@@ -2046,9 +2308,10 @@ async fn update_tags(
 async fn add_tag(
     State(state): State<AppState>,
     Path(post_id): Path<Uuid>,
-    _user: AdminUser,
+    user: StaffUser,
     Json(req): Json<AddTagRequest>,
 ) -> ApiResult<Json<()>> {
+    require_post_capability(&user.0, AdminCapability::EditPosts, post_id, &state).await?;
     activities::tags::add_post_tag(
         post_id,
         req.tag_kind,
@@ -2065,9 +2328,10 @@ async fn add_tag(
 async fn remove_tag(
     State(state): State<AppState>,
     Path(post_id): Path<Uuid>,
-    _user: AdminUser,
+    user: StaffUser,
     Json(req): Json<RemoveTagRequest>,
 ) -> ApiResult<Json<()>> {
+    require_post_capability(&user.0, AdminCapability::EditPosts, post_id, &state).await?;
     activities::tags::remove_post_tag(post_id, req.tag_id, &state.deps.db_pool).await?;
 
     Ok(Json(()))
@@ -2076,9 +2340,10 @@ async fn remove_tag(
 async fn add_contact(
     State(state): State<AppState>,
     Path(post_id): Path<Uuid>,
-    _user: AdminUser,
+    user: StaffUser,
     Json(req): Json<AddContactRequest>,
 ) -> ApiResult<Json<()>> {
+    require_post_capability(&user.0, AdminCapability::EditPosts, post_id, &state).await?;
     activities::contacts::add_post_contact(
         post_id,
         &req.contact_type,
@@ -2093,10 +2358,17 @@ async fn add_contact(
 
 async fn remove_contact(
     State(state): State<AppState>,
-    Path(_post_id): Path<Uuid>,
-    _user: AdminUser,
+    Path(post_id): Path<Uuid>,
+    user: StaffUser,
     Json(req): Json<RemoveContactRequest>,
 ) -> ApiResult<Json<()>> {
+    require_post_capability(&user.0, AdminCapability::EditPosts, post_id, &state).await?;
+    let contacts = Contact::find_by_post(PostId::from_uuid(post_id), &state.deps.db_pool).await?;
+    ensure_belongs_to_post(
+        &user.0,
+        contacts.iter().any(|c| c.id == req.contact_id),
+        "Contact",
+    )?;
     activities::contacts::remove_post_contact(req.contact_id, &state.deps.db_pool).await?;
 
     Ok(Json(()))
@@ -2105,9 +2377,10 @@ async fn remove_contact(
 async fn add_schedule(
     State(state): State<AppState>,
     Path(post_id): Path<Uuid>,
-    _user: AdminUser,
+    user: StaffUser,
     Json(req): Json<AddScheduleRequest>,
 ) -> ApiResult<Json<ScheduleResult>> {
+    require_post_capability(&user.0, AdminCapability::EditPosts, post_id, &state).await?;
     let params = ScheduleParams {
         dtstart: req.dtstart,
         dtend: req.dtend,
@@ -2138,11 +2411,18 @@ async fn add_schedule(
 
 async fn update_schedule(
     State(state): State<AppState>,
-    Path(_post_id): Path<Uuid>,
-    _user: AdminUser,
+    Path(post_id): Path<Uuid>,
+    user: StaffUser,
     Json(req): Json<UpdateScheduleRequest>,
 ) -> ApiResult<Json<ScheduleResult>> {
+    require_post_capability(&user.0, AdminCapability::EditPosts, post_id, &state).await?;
     let schedule_id = ScheduleId::from_uuid(req.schedule_id);
+    let existing = Schedule::find_by_id(schedule_id, &state.deps.db_pool).await?;
+    ensure_belongs_to_post(
+        &user.0,
+        existing.schedulable_type == "post" && existing.schedulable_id == post_id,
+        "Schedule",
+    )?;
     let params = ScheduleParams {
         dtstart: req.dtstart,
         dtend: req.dtend,
@@ -2170,11 +2450,18 @@ async fn update_schedule(
 
 async fn delete_schedule(
     State(state): State<AppState>,
-    Path(_post_id): Path<Uuid>,
-    _user: AdminUser,
+    Path(post_id): Path<Uuid>,
+    user: StaffUser,
     Json(req): Json<DeleteScheduleRequest>,
 ) -> ApiResult<Json<()>> {
+    require_post_capability(&user.0, AdminCapability::EditPosts, post_id, &state).await?;
     let schedule_id = ScheduleId::from_uuid(req.schedule_id);
+    let existing = Schedule::find_by_id(schedule_id, &state.deps.db_pool).await?;
+    ensure_belongs_to_post(
+        &user.0,
+        existing.schedulable_type == "post" && existing.schedulable_id == post_id,
+        "Schedule",
+    )?;
     activities::schedule::delete_schedule(schedule_id, &state.deps).await?;
     Ok(Json(()))
 }
@@ -2200,9 +2487,10 @@ async fn track_click(
 async fn approve_revision(
     State(state): State<AppState>,
     Path(post_id): Path<Uuid>,
-    _user: AdminUser,
+    user: StaffUser,
     Json(_req): Json<EmptyRequest>,
 ) -> ApiResult<Json<PostResult>> {
+    require_post_capability(&user.0, AdminCapability::ReviewPosts, post_id, &state).await?;
     activities::revision_actions::approve_revision(
         PostId::from_uuid(post_id),
         &state.deps.db_pool,
@@ -2219,9 +2507,10 @@ async fn approve_revision(
 async fn reject_revision(
     State(state): State<AppState>,
     Path(post_id): Path<Uuid>,
-    _user: AdminUser,
+    user: StaffUser,
     Json(_req): Json<EmptyRequest>,
 ) -> ApiResult<Json<()>> {
+    require_post_capability(&user.0, AdminCapability::ReviewPosts, post_id, &state).await?;
     activities::revision_actions::reject_revision(
         PostId::from_uuid(post_id),
         &state.deps.db_pool,
@@ -2244,9 +2533,10 @@ async fn regenerate(
 async fn update_content(
     State(state): State<AppState>,
     Path(post_id): Path<Uuid>,
-    user: AdminUser,
+    user: StaffUser,
     Json(req): Json<UpdatePostContentRequest>,
 ) -> ApiResult<Json<PostResult>> {
+    require_post_capability(&user.0, AdminCapability::EditPosts, post_id, &state).await?;
     activities::admin_update_post(
         post_id,
         req.title,
@@ -2274,9 +2564,10 @@ async fn update_content(
 async fn get_reports(
     State(state): State<AppState>,
     Path(post_id): Path<Uuid>,
-    _user: AdminUser,
+    user: StaffUser,
     Json(_req): Json<EmptyRequest>,
 ) -> ApiResult<Json<PostReportListResult>> {
+    require_post_capability(&user.0, AdminCapability::ViewPosts, post_id, &state).await?;
     let reports =
         PostReportRecord::query_for_post(PostId::from_uuid(post_id), &state.deps.db_pool).await?;
 
@@ -2294,12 +2585,94 @@ async fn get_reports(
     }))
 }
 
+async fn get_pii_findings(
+    State(state): State<AppState>,
+    Path(post_id): Path<Uuid>,
+    user: StaffUser,
+    Json(_req): Json<EmptyRequest>,
+) -> ApiResult<Json<PiiFindingListResult>> {
+    require_post_capability(&user.0, AdminCapability::ViewPosts, post_id, &state).await?;
+    let findings = PiiFinding::find_for_entity("post", post_id, &state.deps.db_pool).await?;
+    Ok(Json(PiiFindingListResult { findings }))
+}
+
+/// Fold the duplicate `{id}` into `survivor_post_id` (see
+/// `activities::post_merge`). Needs review rights on both posts.
+async fn merge_into(
+    State(state): State<AppState>,
+    Path(post_id): Path<Uuid>,
+    user: StaffUser,
+    Json(req): Json<MergeIntoRequest>,
+) -> ApiResult<Json<PostMergeSummary>> {
+    require_post_capability(&user.0, AdminCapability::ReviewPosts, post_id, &state).await?;
+    require_post_capability(&user.0, AdminCapability::ReviewPosts, req.survivor_post_id, &state)
+        .await?;
+    post_merge::merge_into(
+        post_id,
+        req.survivor_post_id,
+        &req.keep_from_duplicate,
+        user.0.member_id.into_uuid(),
+        &state.deps,
+    )
+    .await
+    .map(Json)
+    .map_err(|e| match e {
+        PostMergeError::NotFound(_) => ApiError::NotFound(e.to_string()),
+        PostMergeError::SamePost => ApiError::BadRequest(e.to_string()),
+        PostMergeError::AlreadyMerged(_) => ApiError::Conflict(e.to_string()),
+        PostMergeError::Db(err) => ApiError::Internal(err),
+    })
+}
+
+async fn get_review_flags(
+    State(state): State<AppState>,
+    Path(post_id): Path<Uuid>,
+    user: StaffUser,
+    Json(_req): Json<EmptyRequest>,
+) -> ApiResult<Json<ReviewFlagListResult>> {
+    require_post_capability(&user.0, AdminCapability::ViewPosts, post_id, &state).await?;
+    let flags =
+        PostReviewFlag::find_for_post(PostId::from_uuid(post_id), &state.deps.db_pool).await?;
+    Ok(Json(ReviewFlagListResult { flags }))
+}
+
+/// Resolve one review flag. Clearing the last blocking flag on an
+/// `in_review` post activates it.
+async fn resolve_review_flag(
+    State(state): State<AppState>,
+    Path(post_id): Path<Uuid>,
+    user: StaffUser,
+    Json(req): Json<ResolveReviewFlagRequest>,
+) -> ApiResult<Json<ResolveReviewFlagResult>> {
+    require_post_capability(&user.0, AdminCapability::ReviewPosts, post_id, &state).await?;
+    let flag = activities::resolve_review_flag(
+        post_id,
+        req.flag_id,
+        user.0.member_id.into_uuid(),
+        user.0.is_admin,
+        &user.0.grants,
+        &state.deps,
+    )
+    .await?
+    .ok_or_else(|| ApiError::NotFound("Open review flag not found on this post".into()))?;
+
+    let post = Post::find_by_id(PostId::from_uuid(post_id), &state.deps.db_pool)
+        .await?
+        .ok_or_else(|| ApiError::NotFound("Post not found after resolve".into()))?;
+
+    Ok(Json(ResolveReviewFlagResult {
+        flag,
+        post_status: post.status,
+    }))
+}
+
 async fn get_revision(
     State(state): State<AppState>,
     Path(post_id): Path<Uuid>,
-    _user: AdminUser,
+    user: StaffUser,
     Json(_req): Json<EmptyRequest>,
 ) -> ApiResult<Json<OptionalPostResult>> {
+    require_post_capability(&user.0, AdminCapability::ViewPosts, post_id, &state).await?;
     let revision = activities::revision_actions::get_revision_for_post(
         PostId::from_uuid(post_id),
         &state.deps.db_pool,
@@ -2354,8 +2727,9 @@ pub struct PostEditionSlottingsResult {
 async fn get_edition_slottings(
     State(state): State<AppState>,
     Path(post_id): Path<Uuid>,
-    _user: AdminUser,
+    user: StaffUser,
 ) -> ApiResult<Json<PostEditionSlottingsResult>> {
+    require_post_capability(&user.0, AdminCapability::ViewPosts, post_id, &state).await?;
     let slottings = Post::find_edition_slottings(
         PostId::from_uuid(post_id),
         &state.deps.db_pool,
@@ -2379,8 +2753,9 @@ pub struct PostSourcesResult {
 async fn get_post_sources(
     State(state): State<AppState>,
     Path(post_id): Path<Uuid>,
-    _user: AdminUser,
+    user: StaffUser,
 ) -> ApiResult<Json<PostSourcesResult>> {
+    require_post_capability(&user.0, AdminCapability::ViewPosts, post_id, &state).await?;
     let sources = PostSource::find_enriched_by_post(
         PostId::from_uuid(post_id),
         &state.deps.db_pool,
@@ -2402,9 +2777,10 @@ pub struct SetPrimarySourceRequest {
 async fn set_primary_source(
     State(state): State<AppState>,
     Path(post_id): Path<Uuid>,
-    _user: AdminUser,
+    user: StaffUser,
     Json(req): Json<SetPrimarySourceRequest>,
 ) -> ApiResult<Json<FieldGroupResult>> {
+    require_post_capability(&user.0, AdminCapability::EditPosts, post_id, &state).await?;
     let pool = &state.deps.db_pool;
     let post_id_typed = PostId::from_uuid(post_id);
     let post_source_id = PostSourceId::from_uuid(req.post_source_id);
@@ -2492,9 +2868,10 @@ pub struct FieldGroupResult {
 async fn upsert_post_media(
     State(state): State<AppState>,
     Path(post_id): Path<Uuid>,
-    _user: AdminUser,
+    user: StaffUser,
     Json(req): Json<UpsertPostMediaRequest>,
 ) -> ApiResult<Json<FieldGroupResult>> {
+    require_post_capability(&user.0, AdminCapability::EditPosts, post_id, &state).await?;
     let pool = &state.deps.db_pool;
     PostMediaRecord::upsert_primary(
         post_id,
@@ -2519,9 +2896,10 @@ pub struct UpsertPostMetaRequest {
 async fn upsert_post_meta(
     State(state): State<AppState>,
     Path(post_id): Path<Uuid>,
-    _user: AdminUser,
+    user: StaffUser,
     Json(req): Json<UpsertPostMetaRequest>,
 ) -> ApiResult<Json<FieldGroupResult>> {
+    require_post_capability(&user.0, AdminCapability::EditPosts, post_id, &state).await?;
     let pool = &state.deps.db_pool;
     PostMetaRecord::upsert(
         post_id,
@@ -2549,9 +2927,10 @@ pub struct UpsertPostPersonRequest {
 async fn upsert_post_person(
     State(state): State<AppState>,
     Path(post_id): Path<Uuid>,
-    _user: AdminUser,
+    user: StaffUser,
     Json(req): Json<UpsertPostPersonRequest>,
 ) -> ApiResult<Json<FieldGroupResult>> {
+    require_post_capability(&user.0, AdminCapability::EditPosts, post_id, &state).await?;
     let pool = &state.deps.db_pool;
     PostPersonRecord::upsert(
         post_id,
@@ -2577,9 +2956,10 @@ pub struct UpsertPostLinkRequest {
 async fn upsert_post_link(
     State(state): State<AppState>,
     Path(post_id): Path<Uuid>,
-    _user: AdminUser,
+    user: StaffUser,
     Json(req): Json<UpsertPostLinkRequest>,
 ) -> ApiResult<Json<FieldGroupResult>> {
+    require_post_capability(&user.0, AdminCapability::EditPosts, post_id, &state).await?;
     let pool = &state.deps.db_pool;
     let deadline = req
         .deadline
@@ -2605,9 +2985,10 @@ pub struct UpsertPostSourceAttrRequest {
 async fn upsert_post_source_attr(
     State(state): State<AppState>,
     Path(post_id): Path<Uuid>,
-    _user: AdminUser,
+    user: StaffUser,
     Json(req): Json<UpsertPostSourceAttrRequest>,
 ) -> ApiResult<Json<FieldGroupResult>> {
+    require_post_capability(&user.0, AdminCapability::EditPosts, post_id, &state).await?;
     let pool = &state.deps.db_pool;
     PostSourceAttr::upsert(
         post_id,
@@ -2630,9 +3011,10 @@ pub struct UpsertPostDatetimeRequest {
 async fn upsert_post_datetime(
     State(state): State<AppState>,
     Path(post_id): Path<Uuid>,
-    _user: AdminUser,
+    user: StaffUser,
     Json(req): Json<UpsertPostDatetimeRequest>,
 ) -> ApiResult<Json<FieldGroupResult>> {
+    require_post_capability(&user.0, AdminCapability::EditPosts, post_id, &state).await?;
     let pool = &state.deps.db_pool;
     let start_at = req
         .start_at
@@ -2665,9 +3047,10 @@ pub struct UpsertPostStatusRequest {
 async fn upsert_post_status(
     State(state): State<AppState>,
     Path(post_id): Path<Uuid>,
-    _user: AdminUser,
+    user: StaffUser,
     Json(req): Json<UpsertPostStatusRequest>,
 ) -> ApiResult<Json<FieldGroupResult>> {
+    require_post_capability(&user.0, AdminCapability::EditPosts, post_id, &state).await?;
     let pool = &state.deps.db_pool;
     PostStatusRecord::upsert(
         post_id,
@@ -2687,9 +3070,10 @@ pub struct UpsertPostItemsRequest {
 async fn upsert_post_items(
     State(state): State<AppState>,
     Path(post_id): Path<Uuid>,
-    _user: AdminUser,
+    user: StaffUser,
     Json(req): Json<UpsertPostItemsRequest>,
 ) -> ApiResult<Json<FieldGroupResult>> {
+    require_post_capability(&user.0, AdminCapability::EditPosts, post_id, &state).await?;
     let pool = &state.deps.db_pool;
     crate::domains::posts::models::PostItem::replace_all(post_id, &req.items, pool).await?;
     Ok(Json(FieldGroupResult { success: true }))
@@ -2712,15 +3096,19 @@ pub fn router() -> Router<AppState> {
         .route("/Posts/upcoming_events", post(upcoming_events))
         .route("/Posts/schedules_for_entity", post(schedules_for_entity))
         .route("/Posts/backfill_locations", post(backfill_locations))
+        .route("/Posts/near_duplicate_clusters", post(near_duplicate_clusters))
         .route("/Posts/list_by_organization", post(list_by_organization))
         .route("/Posts/public_list", post(public_list))
         .route("/Posts/public_filters", post(public_filters))
+        .route("/Posts/map_points", post(map_points))
         .route("/Posts/expire_stale_posts", post(expire_stale_posts))
         .route("/Posts/stats", post(stats))
         .route("/Posts/create_post", post(create_post))
         .route("/Posts/admin_create", post(admin_create_post))
         // --- Post object (keyed, singular) ---
         .route("/Post/{id}/get", post(get_post))
+        .route("/Post/{id}/calendar.ics", get(post_calendar))
+        .route("/County/{id}/calendar.ics", get(county_calendar))
         .route("/Post/{id}/preview", post(preview_post))
         .route("/Post/{id}/approve", post(approve))
         .route("/Post/{id}/edit_and_approve", post(edit_and_approve))
@@ -2747,6 +3135,10 @@ pub fn router() -> Router<AppState> {
         .route("/Post/{id}/regenerate", post(regenerate))
         .route("/Post/{id}/update_content", post(update_content))
         .route("/Post/{id}/get_reports", post(get_reports))
+        .route("/Post/{id}/pii_findings", post(get_pii_findings))
+        .route("/Post/{id}/review_flags", post(get_review_flags))
+        .route("/Post/{id}/resolve_review_flag", post(resolve_review_flag))
+        .route("/Post/{id}/merge_into", post(merge_into))
         .route("/Post/{id}/get_revision", post(get_revision))
         // Field groups
         .route("/Post/{id}/related", post(get_related_posts))
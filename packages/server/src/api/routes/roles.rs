@@ -0,0 +1,241 @@
+use axum::extract::State;
+use axum::routing::post;
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::api::auth::AdminUser;
+use crate::api::error::{ApiError, ApiResult};
+use crate::api::state::AppState;
+use crate::common::auth::AdminCapability;
+use crate::domains::auth::models::{MemberRole, Role};
+use crate::domains::editions::models::county::County;
+
+// =============================================================================
+// Request types
+// =============================================================================
+
+#[derive(Debug, Deserialize)]
+pub struct ListRolesRequest {}
+
+#[derive(Debug, Deserialize)]
+pub struct UpsertRoleRequest {
+    pub slug: String,
+    pub name: String,
+    pub description: Option<String>,
+    /// Capability strings, e.g. `editions:publish`.
+    pub capabilities: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RoleIdRequest {
+    pub id: Uuid,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AssignRoleRequest {
+    pub member_id: Uuid,
+    pub role_id: Uuid,
+    /// Counties the role applies to. Omitted or empty grants it everywhere.
+    #[serde(default)]
+    pub county_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UnassignRoleRequest {
+    pub member_role_id: Uuid,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MemberRolesRequest {
+    pub member_id: Uuid,
+}
+
+// =============================================================================
+// Response types
+// =============================================================================
+
+#[derive(Debug, Serialize)]
+pub struct RoleResult {
+    pub id: Uuid,
+    pub slug: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub capabilities: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RoleListResult {
+    pub roles: Vec<RoleResult>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MemberRoleResult {
+    pub id: Uuid,
+    pub member_id: Uuid,
+    pub role_id: Uuid,
+    pub county_id: Option<Uuid>,
+    pub granted_by: Option<Uuid>,
+    pub created_at: String,
+}
+
+impl From<MemberRole> for MemberRoleResult {
+    fn from(r: MemberRole) -> Self {
+        Self {
+            id: r.id,
+            member_id: r.member_id,
+            role_id: r.role_id,
+            county_id: r.county_id,
+            granted_by: r.granted_by,
+            created_at: r.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct MemberRoleListResult {
+    pub member_roles: Vec<MemberRoleResult>,
+}
+
+// =============================================================================
+// Handlers
+// =============================================================================
+
+async fn role_result(role: Role, state: &AppState) -> ApiResult<RoleResult> {
+    let capabilities = Role::capabilities(role.id, &state.deps.db_pool).await?;
+    Ok(RoleResult {
+        id: role.id,
+        slug: role.slug,
+        name: role.name,
+        description: role.description,
+        capabilities,
+    })
+}
+
+async fn list(
+    State(state): State<AppState>,
+    _user: AdminUser,
+    Json(_req): Json<ListRolesRequest>,
+) -> ApiResult<Json<RoleListResult>> {
+    let mut roles = Vec::new();
+    for role in Role::find_all(&state.deps.db_pool).await? {
+        roles.push(role_result(role, &state).await?);
+    }
+    Ok(Json(RoleListResult { roles }))
+}
+
+async fn upsert(
+    State(state): State<AppState>,
+    _user: AdminUser,
+    Json(req): Json<UpsertRoleRequest>,
+) -> ApiResult<Json<RoleResult>> {
+    let slug = req.slug.trim();
+    if slug.is_empty() || req.name.trim().is_empty() {
+        return Err(ApiError::BadRequest("slug and name are required".into()));
+    }
+    let unknown: Vec<&str> = req
+        .capabilities
+        .iter()
+        .map(String::as_str)
+        .filter(|c| AdminCapability::parse(c).is_none())
+        .collect();
+    if !unknown.is_empty() {
+        return Err(ApiError::BadRequest(format!(
+            "Unknown capabilities: {}",
+            unknown.join(", ")
+        )));
+    }
+
+    let pool = &state.deps.db_pool;
+    let role = Role::upsert(slug, req.name.trim(), req.description.as_deref(), pool).await?;
+    Role::set_capabilities(role.id, &req.capabilities, pool).await?;
+
+    Ok(Json(role_result(role, &state).await?))
+}
+
+async fn delete(
+    State(state): State<AppState>,
+    _user: AdminUser,
+    Json(req): Json<RoleIdRequest>,
+) -> ApiResult<Json<()>> {
+    Role::find_by_id(req.id, &state.deps.db_pool)
+        .await?
+        .ok_or_else(|| ApiError::NotFound("Role not found".into()))?;
+    Role::delete(req.id, &state.deps.db_pool).await?;
+    Ok(Json(()))
+}
+
+async fn assign(
+    State(state): State<AppState>,
+    user: AdminUser,
+    Json(req): Json<AssignRoleRequest>,
+) -> ApiResult<Json<MemberRoleListResult>> {
+    let pool = &state.deps.db_pool;
+    Role::find_by_id(req.role_id, pool)
+        .await?
+        .ok_or_else(|| ApiError::NotFound("Role not found".into()))?;
+    for county_id in &req.county_ids {
+        County::find_by_id(*county_id, pool)
+            .await?
+            .ok_or_else(|| ApiError::NotFound(format!("County not found: {}", county_id)))?;
+    }
+
+    let granted_by = Some(user.0.member_id.into_uuid());
+    let counties: Vec<Option<Uuid>> = if req.county_ids.is_empty() {
+        vec![None]
+    } else {
+        req.county_ids.iter().copied().map(Some).collect()
+    };
+
+    let mut member_roles = Vec::new();
+    for county_id in counties {
+        let assigned =
+            MemberRole::assign(req.member_id, req.role_id, county_id, granted_by, pool).await?;
+        member_roles.push(assigned.into());
+    }
+
+    tracing::info!(
+        member_id = %req.member_id,
+        role_id = %req.role_id,
+        granted_by = %user.0.member_id,
+        "Role assigned; takes effect at the member's next login"
+    );
+
+    Ok(Json(MemberRoleListResult { member_roles }))
+}
+
+async fn unassign(
+    State(state): State<AppState>,
+    _user: AdminUser,
+    Json(req): Json<UnassignRoleRequest>,
+) -> ApiResult<Json<()>> {
+    if !MemberRole::delete(req.member_role_id, &state.deps.db_pool).await? {
+        return Err(ApiError::NotFound("Role assignment not found".into()));
+    }
+    Ok(Json(()))
+}
+
+async fn member_roles(
+    State(state): State<AppState>,
+    _user: AdminUser,
+    Json(req): Json<MemberRolesRequest>,
+) -> ApiResult<Json<MemberRoleListResult>> {
+    let rows = MemberRole::find_for_member(req.member_id, &state.deps.db_pool).await?;
+    Ok(Json(MemberRoleListResult {
+        member_roles: rows.into_iter().map(MemberRoleResult::from).collect(),
+    }))
+}
+
+// =============================================================================
+// Router
+// =============================================================================
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/Roles/list", post(list))
+        .route("/Roles/upsert", post(upsert))
+        .route("/Roles/delete", post(delete))
+        .route("/Roles/assign", post(assign))
+        .route("/Roles/unassign", post(unassign))
+        .route("/Roles/member_roles", post(member_roles))
+}
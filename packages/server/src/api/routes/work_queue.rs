@@ -0,0 +1,189 @@
+use axum::extract::State;
+use axum::routing::post;
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::api::auth::AdminUser;
+use crate::api::error::{ApiError, ApiResult};
+use crate::api::state::AppState;
+use crate::kernel::work_queue::{WorkItem, WorkItemCount};
+
+// =============================================================================
+// Request types
+// =============================================================================
+
+#[derive(Debug, Deserialize)]
+pub struct ListWorkItemsRequest {
+    pub status: Option<String>,
+    pub kind: Option<String>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WorkQueueStatsRequest {}
+
+#[derive(Debug, Deserialize)]
+pub struct WorkItemIdRequest {
+    pub id: Uuid,
+}
+
+// =============================================================================
+// Response types
+// =============================================================================
+
+#[derive(Debug, Serialize)]
+pub struct WorkItemResult {
+    pub id: Uuid,
+    pub kind: String,
+    pub payload: serde_json::Value,
+    pub dedup_key: Option<String>,
+    pub status: String,
+    pub attempts: i32,
+    pub max_attempts: i32,
+    pub run_after: String,
+    pub locked_at: Option<String>,
+    pub last_error: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+    pub finished_at: Option<String>,
+}
+
+impl From<WorkItem> for WorkItemResult {
+    fn from(w: WorkItem) -> Self {
+        Self {
+            id: w.id,
+            kind: w.kind,
+            payload: w.payload,
+            dedup_key: w.dedup_key,
+            status: w.status,
+            attempts: w.attempts,
+            max_attempts: w.max_attempts,
+            run_after: w.run_after.to_rfc3339(),
+            locked_at: w.locked_at.map(|t| t.to_rfc3339()),
+            last_error: w.last_error,
+            created_at: w.created_at.to_rfc3339(),
+            updated_at: w.updated_at.to_rfc3339(),
+            finished_at: w.finished_at.map(|t| t.to_rfc3339()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct WorkItemListResult {
+    pub items: Vec<WorkItemResult>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WorkItemCountResult {
+    pub kind: String,
+    pub status: String,
+    pub count: i64,
+}
+
+impl From<WorkItemCount> for WorkItemCountResult {
+    fn from(c: WorkItemCount) -> Self {
+        Self {
+            kind: c.kind,
+            status: c.status,
+            count: c.count,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct WorkQueueStatsResult {
+    pub counts: Vec<WorkItemCountResult>,
+}
+
+// =============================================================================
+// Handlers
+// =============================================================================
+
+const VALID_STATUSES: &[&str] = &["pending", "running", "succeeded", "dead"];
+
+async fn list(
+    State(state): State<AppState>,
+    _user: AdminUser,
+    Json(req): Json<ListWorkItemsRequest>,
+) -> ApiResult<Json<WorkItemListResult>> {
+    if let Some(status) = req.status.as_deref() {
+        if !VALID_STATUSES.contains(&status) {
+            return Err(ApiError::BadRequest(format!(
+                "status must be one of: {}",
+                VALID_STATUSES.join(", ")
+            )));
+        }
+    }
+    let limit = req.limit.unwrap_or(50).clamp(1, 200);
+    let items = WorkItem::list(
+        req.status.as_deref(),
+        req.kind.as_deref(),
+        limit,
+        &state.deps.db_pool,
+    )
+    .await?;
+
+    Ok(Json(WorkItemListResult {
+        items: items.into_iter().map(WorkItemResult::from).collect(),
+    }))
+}
+
+async fn get(
+    State(state): State<AppState>,
+    _user: AdminUser,
+    Json(req): Json<WorkItemIdRequest>,
+) -> ApiResult<Json<WorkItemResult>> {
+    let item = WorkItem::find_by_id(req.id, &state.deps.db_pool)
+        .await?
+        .ok_or_else(|| ApiError::NotFound("Work item not found".into()))?;
+    Ok(Json(item.into()))
+}
+
+async fn stats(
+    State(state): State<AppState>,
+    _user: AdminUser,
+    Json(_req): Json<WorkQueueStatsRequest>,
+) -> ApiResult<Json<WorkQueueStatsResult>> {
+    let counts = WorkItem::count_by_kind_and_status(&state.deps.db_pool).await?;
+    Ok(Json(WorkQueueStatsResult {
+        counts: counts.into_iter().map(WorkItemCountResult::from).collect(),
+    }))
+}
+
+async fn retry(
+    State(state): State<AppState>,
+    user: AdminUser,
+    Json(req): Json<WorkItemIdRequest>,
+) -> ApiResult<Json<WorkItemResult>> {
+    let pool = &state.deps.db_pool;
+    let item = WorkItem::find_by_id(req.id, pool)
+        .await?
+        .ok_or_else(|| ApiError::NotFound("Work item not found".into()))?;
+    if item.status != "dead" {
+        return Err(ApiError::Conflict(format!(
+            "Only dead work items can be retried (status is '{}')",
+            item.status
+        )));
+    }
+
+    let retried = WorkItem::retry_dead(req.id, pool)
+        .await?
+        .ok_or_else(|| ApiError::Conflict("An identical work item is already pending".into()))?;
+
+    tracing::info!(item_id = %retried.id, kind = %retried.kind, member_id = %user.0.member_id, "Work item retried by admin");
+
+    Ok(Json(retried.into()))
+}
+
+// =============================================================================
+// Router
+// =============================================================================
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/WorkQueue/list", post(list))
+        .route("/WorkQueue/get", post(get))
+        .route("/WorkQueue/stats", post(stats))
+        .route("/WorkQueue/retry", post(retry))
+}
@@ -3,9 +3,13 @@
 use std::sync::Arc;
 
 use anyhow::{Context, Result};
+use server_core::domains::auth::models::NormalizedIdentifier;
 use server_core::domains::auth::JwtService;
+use server_core::kernel::jobs::{builtin_registry, JobScheduler};
+use server_core::kernel::work_queue::WorkerPool;
 use server_core::kernel::ServerDeps;
-use server_core::kernel::{TwilioAdapter, StreamHub};
+use server_core::kernel::rate_limit::TrustedProxies;
+use server_core::kernel::{create_geocoder, PostgresRateLimiter, TwilioAdapter, StreamHub};
 use server_core::kernel::sse::SseState;
 use sqlx::postgres::PgPoolOptions;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
@@ -52,16 +56,39 @@ async fn main() -> Result<()> {
         .unwrap_or_else(|_| "false".to_string())
         .parse::<bool>()
         .unwrap_or(false);
+    // Phone numbers and/or emails; stored normalized (E.164, lowercase)
     let admin_identifiers = std::env::var("ADMIN_IDENTIFIERS")
         .unwrap_or_default()
         .split(',')
-        .map(|s| s.trim().to_string())
+        .map(|s| s.trim())
         .filter(|s| !s.is_empty())
+        .filter_map(|s| match NormalizedIdentifier::parse(s) {
+            Ok(identifier) => Some(identifier.value),
+            Err(_) => {
+                tracing::warn!("Ignoring invalid ADMIN_IDENTIFIERS entry");
+                None
+            }
+        })
         .collect::<Vec<_>>();
+    // Reverse proxies allowed to set X-Forwarded-For (addresses or CIDRs)
+    let trusted_proxies =
+        TrustedProxies::parse(&std::env::var("TRUSTED_PROXIES").unwrap_or_default());
     let pii_scrubbing_enabled = std::env::var("PII_SCRUBBING_ENABLED")
         .unwrap_or_else(|_| "false".to_string())
         .parse::<bool>()
         .unwrap_or(false);
+    let geocoding_enabled = std::env::var("GEOCODING_ENABLED")
+        .unwrap_or_else(|_| "true".to_string())
+        .parse::<bool>()
+        .unwrap_or(true);
+    let job_scheduler_enabled = std::env::var("JOB_SCHEDULER_ENABLED")
+        .unwrap_or_else(|_| "true".to_string())
+        .parse::<bool>()
+        .unwrap_or(true);
+    let work_queue_workers = std::env::var("WORK_QUEUE_WORKERS")
+        .unwrap_or_else(|_| "2".to_string())
+        .parse::<usize>()
+        .unwrap_or(2);
     // Create Twilio service
     let twilio_options = TwilioOptions {
         account_sid: twilio_account_sid,
@@ -112,12 +139,29 @@ async fn main() -> Result<()> {
         pool.clone(),
         Arc::new(TwilioAdapter::new(twilio)),
         pii_detector,
+        Arc::new(PostgresRateLimiter::new(pool.clone())),
+        create_geocoder(geocoding_enabled, pool.clone()),
         storage,
         jwt_service.clone(),
         stream_hub.clone(),
         test_identifier_enabled,
         admin_identifiers,
-    ));
+    )
+    .with_trusted_proxies(trusted_proxies));
+
+    // Start recurring maintenance jobs
+    if job_scheduler_enabled {
+        JobScheduler::new(builtin_registry()?, server_deps.clone()).start();
+    } else {
+        tracing::info!("JOB_SCHEDULER_ENABLED=false — scheduled jobs disabled");
+    }
+
+    // Drain the work queue (media fetch, edition reflow)
+    if work_queue_workers > 0 {
+        WorkerPool::new(server_deps.clone(), work_queue_workers).start();
+    } else {
+        tracing::info!("WORK_QUEUE_WORKERS=0 — work queue not drained by this process");
+    }
 
     // Get port from environment or use default
     let port = std::env::var("SERVER_PORT")
@@ -144,7 +188,14 @@ async fn main() -> Result<()> {
         .await
         .context("Failed to bind listener")?;
 
-    axum::serve(listener, app).await.context("Server error")?;
+    // Connect info gives the OTP rate limits the peer address; forwarding
+    // headers are honoured only when that peer is in TRUSTED_PROXIES.
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<std::net::SocketAddr>(),
+    )
+    .await
+    .context("Server error")?;
 
     Ok(())
 }
@@ -1,4 +1,4 @@
-use super::{AdminCapability, AuthError};
+use super::{grants_allow, AdminCapability, AuthError, CapabilityGrant, CountyScope};
 use crate::common::entity_ids::MemberId;
 use anyhow::Result;
 
@@ -14,6 +14,7 @@ use anyhow::Result;
 pub struct Actor {
     actor_id: MemberId,
     is_admin: bool,
+    grants: Vec<CapabilityGrant>,
 }
 
 impl Actor {
@@ -23,7 +24,19 @@ impl Actor {
     /// * `actor_id` - The member ID of the actor
     /// * `is_admin` - Admin flag from JWT/session (already validated during authentication)
     pub fn new(actor_id: MemberId, is_admin: bool) -> Self {
-        Self { actor_id, is_admin }
+        Self {
+            actor_id,
+            is_admin,
+            grants: Vec::new(),
+        }
+    }
+
+    /// Attach the actor's role grants (from the JWT). A non-admin actor
+    /// passes a check if any grant covers the capability; county scoping is
+    /// enforced by the route handler, which knows the resource's county.
+    pub fn with_grants(mut self, grants: &[CapabilityGrant]) -> Self {
+        self.grants = grants.to_vec();
+        self
     }
 
     /// Specify what capability the actor needs
@@ -31,6 +44,7 @@ impl Actor {
         CapabilityBuilder {
             actor_id: self.actor_id,
             is_admin: self.is_admin,
+            grants: self.grants,
             capability,
         }
     }
@@ -40,6 +54,7 @@ impl Actor {
 pub struct CapabilityBuilder {
     actor_id: MemberId,
     is_admin: bool,
+    grants: Vec<CapabilityGrant>,
     capability: AdminCapability,
 }
 
@@ -49,7 +64,14 @@ impl CapabilityBuilder {
     where
         D: HasAuthContext,
     {
-        check_admin_permission(self.actor_id, self.is_admin, self.capability, deps).await
+        check_admin_permission(
+            self.actor_id,
+            self.is_admin,
+            &self.grants,
+            self.capability,
+            deps,
+        )
+        .await
     }
 }
 
@@ -67,10 +89,13 @@ pub trait HasAuthContext: Send + Sync {
 /// 1. JWT tokens are cryptographically signed and verified
 /// 2. The flag was set during authentication by checking against admin_identifiers
 /// 3. Tokens expire after 24 hours, limiting the window for stale permissions
+///
+/// Non-admins pass only if one of their role grants covers the capability.
 async fn check_admin_permission<D>(
     _actor_id: MemberId,
     is_admin: bool,
-    _capability: AdminCapability,
+    grants: &[CapabilityGrant],
+    capability: AdminCapability,
     _deps: &D,
 ) -> Result<(), AuthError>
 where
@@ -80,7 +105,7 @@ where
     // The is_admin flag comes from the JWT and was validated during OTP verification
     // by checking the phone number against the admin_identifiers list.
 
-    if !is_admin {
+    if !is_admin && !grants_allow(grants, capability, CountyScope::Any) {
         return Err(AuthError::AdminRequired);
     }
 
@@ -134,4 +159,29 @@ mod tests {
 
         assert!(matches!(result, Err(AuthError::AdminRequired)));
     }
+
+    #[tokio::test]
+    async fn test_grant_holder_allowed_for_granted_capability_only() {
+        let deps = TestDeps {
+            admin_identifiers: vec![],
+        };
+        let grants = vec![CapabilityGrant {
+            capability: AdminCapability::ReviewPosts,
+            county_ids: Some(vec![uuid::Uuid::new_v4()]),
+        }];
+
+        let allowed = Actor::new(MemberId::new(), false)
+            .with_grants(&grants)
+            .can(AdminCapability::ReviewPosts)
+            .check(&deps)
+            .await;
+        assert!(allowed.is_ok());
+
+        let denied = Actor::new(MemberId::new(), false)
+            .with_grants(&grants)
+            .can(AdminCapability::DeletePosts)
+            .check(&deps)
+            .await;
+        assert!(matches!(denied, Err(AuthError::AdminRequired)));
+    }
 }
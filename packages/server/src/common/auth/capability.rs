@@ -1,25 +1,138 @@
+use serde::{Deserialize, Serialize};
+
 /// Capabilities in the MN Digital Aid platform
 ///
-/// This is a simplified model focused on admin operations since the platform
-/// is primarily admin-managed with automated matching for volunteers.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The original admin capabilities cover platform-wide operations. The
+/// newsroom capabilities (`editions:*`, `posts:*`, `counties:manage`) are
+/// granted through roles and may be scoped to specific counties — see
+/// [`CapabilityGrant`](super::CapabilityGrant).
+///
+/// Each capability has a stable string form (`editions:publish`) used in the
+/// `role_capabilities` table and in JWT claims.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum AdminCapability {
     /// Approve or reject needs
+    #[serde(rename = "needs:manage")]
     ManageNeeds,
 
     /// Create and manage posts
+    #[serde(rename = "posts:manage")]
     ManagePosts,
 
     /// Trigger scraping operations
+    #[serde(rename = "scraping:trigger")]
     TriggerScraping,
 
     /// Manage member status
+    #[serde(rename = "members:manage")]
     ManageMembers,
 
     /// Manage listings (create, update, delete, verify)
+    #[serde(rename = "listings:manage")]
     ManageListings,
 
     /// Full admin access to all operations
+    #[serde(rename = "admin:full")]
     FullAdmin,
+
+    /// Read editions, their rows, slots and previews
+    #[serde(rename = "editions:view")]
+    ViewEditions,
+
+    /// Create, generate and lay out editions (rows, slots, sections, widgets)
+    #[serde(rename = "editions:edit")]
+    EditEditions,
+
+    /// Move editions through review and approval
+    #[serde(rename = "editions:review")]
+    ReviewEditions,
+
+    /// Publish, unpublish and archive editions
+    #[serde(rename = "editions:publish")]
+    PublishEditions,
+
+    /// Change county settings (e.g. target content weight)
+    #[serde(rename = "counties:manage")]
+    ManageCounties,
+
+    /// Read posts in any status, with their sources, reports and findings
+    #[serde(rename = "posts:view")]
+    ViewPosts,
+
+    /// Create posts and edit their content, tags, schedules and field groups
+    #[serde(rename = "posts:edit")]
+    EditPosts,
+
+    /// Approve, reject, expire or reactivate posts, revisions and reports
+    #[serde(rename = "posts:review")]
+    ReviewPosts,
+
+    /// Delete or archive posts
+    #[serde(rename = "posts:delete")]
+    DeletePosts,
+}
+
+impl AdminCapability {
+    /// Every capability, in declaration order.
+    pub const ALL: [AdminCapability; 15] = [
+        AdminCapability::ManageNeeds,
+        AdminCapability::ManagePosts,
+        AdminCapability::TriggerScraping,
+        AdminCapability::ManageMembers,
+        AdminCapability::ManageListings,
+        AdminCapability::FullAdmin,
+        AdminCapability::ViewEditions,
+        AdminCapability::EditEditions,
+        AdminCapability::ReviewEditions,
+        AdminCapability::PublishEditions,
+        AdminCapability::ManageCounties,
+        AdminCapability::ViewPosts,
+        AdminCapability::EditPosts,
+        AdminCapability::ReviewPosts,
+        AdminCapability::DeletePosts,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AdminCapability::ManageNeeds => "needs:manage",
+            AdminCapability::ManagePosts => "posts:manage",
+            AdminCapability::TriggerScraping => "scraping:trigger",
+            AdminCapability::ManageMembers => "members:manage",
+            AdminCapability::ManageListings => "listings:manage",
+            AdminCapability::FullAdmin => "admin:full",
+            AdminCapability::ViewEditions => "editions:view",
+            AdminCapability::EditEditions => "editions:edit",
+            AdminCapability::ReviewEditions => "editions:review",
+            AdminCapability::PublishEditions => "editions:publish",
+            AdminCapability::ManageCounties => "counties:manage",
+            AdminCapability::ViewPosts => "posts:view",
+            AdminCapability::EditPosts => "posts:edit",
+            AdminCapability::ReviewPosts => "posts:review",
+            AdminCapability::DeletePosts => "posts:delete",
+        }
+    }
+
+    /// Parse the string form (`editions:publish`). Returns `None` for
+    /// unknown capabilities.
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.as_str() == s)
+    }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn string_form_round_trips() {
+        for capability in AdminCapability::ALL {
+            assert_eq!(
+                AdminCapability::parse(capability.as_str()),
+                Some(capability)
+            );
+            let json = serde_json::to_value(capability).unwrap();
+            assert_eq!(json, capability.as_str());
+        }
+        assert_eq!(AdminCapability::parse("editions:delete"), None);
+    }
+}
@@ -0,0 +1,212 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::AdminCapability;
+
+/// A capability held by a member through one of their roles, optionally
+/// limited to a set of counties.
+///
+/// Grants are resolved from `member_roles` → `role_capabilities` at login
+/// and carried in the JWT, so route handlers can authorize without a
+/// database round trip.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CapabilityGrant {
+    pub capability: AdminCapability,
+    /// `None` means every county.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub county_ids: Option<Vec<Uuid>>,
+}
+
+impl CapabilityGrant {
+    fn covers(&self, capability: AdminCapability) -> bool {
+        self.capability == capability || self.capability == AdminCapability::FullAdmin
+    }
+
+    fn covers_county(&self, county_id: Uuid) -> bool {
+        match &self.county_ids {
+            None => true,
+            Some(ids) => ids.contains(&county_id),
+        }
+    }
+}
+
+/// Which counties an action touches.
+#[derive(Debug, Clone, Copy)]
+pub enum CountyScope<'a> {
+    /// Not tied to a county (listings, templates) — any grant of the
+    /// capability is enough, whatever its scope.
+    Any,
+    /// A single county's resource, e.g. one edition.
+    County(Uuid),
+    /// A resource spanning several counties, e.g. a post tagged for two.
+    /// Every county must be covered; an empty slice means statewide and
+    /// needs an unscoped grant.
+    Counties(&'a [Uuid]),
+}
+
+impl CountyScope<'_> {
+    /// Statewide resources (and ones with no county) need an unscoped grant.
+    pub const STATEWIDE: CountyScope<'static> = CountyScope::Counties(&[]);
+}
+
+/// Whether `grants` allow `capability` within `scope`.
+pub fn grants_allow(
+    grants: &[CapabilityGrant],
+    capability: AdminCapability,
+    scope: CountyScope<'_>,
+) -> bool {
+    let mut matching = grants.iter().filter(|g| g.covers(capability)).peekable();
+    match scope {
+        CountyScope::Any => matching.peek().is_some(),
+        CountyScope::County(county_id) => matching.any(|g| g.covers_county(county_id)),
+        CountyScope::Counties(county_ids) => {
+            let matching: Vec<&CapabilityGrant> = matching.collect();
+            if matching.iter().any(|g| g.county_ids.is_none()) {
+                return true;
+            }
+            !county_ids.is_empty()
+                && county_ids
+                    .iter()
+                    .all(|id| matching.iter().any(|g| g.covers_county(*id)))
+        }
+    }
+}
+
+/// Counties `grants` allow `capability` in, for filtering listings.
+/// `None` when a grant of it is unscoped (every county); an empty list
+/// when none is held.
+pub fn granted_county_ids(
+    grants: &[CapabilityGrant],
+    capability: AdminCapability,
+) -> Option<Vec<Uuid>> {
+    let mut county_ids = Vec::new();
+    for grant in grants.iter().filter(|g| g.covers(capability)) {
+        match &grant.county_ids {
+            None => return None,
+            Some(ids) => county_ids.extend(ids.iter().copied()),
+        }
+    }
+    county_ids.sort();
+    county_ids.dedup();
+    Some(county_ids)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grant(capability: AdminCapability, county_ids: Option<Vec<Uuid>>) -> CapabilityGrant {
+        CapabilityGrant {
+            capability,
+            county_ids,
+        }
+    }
+
+    #[test]
+    fn county_scoped_grant_only_covers_its_counties() {
+        let hennepin = Uuid::new_v4();
+        let ramsey = Uuid::new_v4();
+        let grants = vec![grant(
+            AdminCapability::PublishEditions,
+            Some(vec![hennepin]),
+        )];
+
+        assert!(grants_allow(
+            &grants,
+            AdminCapability::PublishEditions,
+            CountyScope::County(hennepin)
+        ));
+        assert!(!grants_allow(
+            &grants,
+            AdminCapability::PublishEditions,
+            CountyScope::County(ramsey)
+        ));
+        assert!(!grants_allow(
+            &grants,
+            AdminCapability::EditEditions,
+            CountyScope::County(hennepin)
+        ));
+        assert!(grants_allow(
+            &grants,
+            AdminCapability::PublishEditions,
+            CountyScope::Any
+        ));
+    }
+
+    #[test]
+    fn multi_county_resources_need_every_county_covered() {
+        let hennepin = Uuid::new_v4();
+        let ramsey = Uuid::new_v4();
+        let grants = vec![
+            grant(AdminCapability::EditPosts, Some(vec![hennepin])),
+            grant(AdminCapability::EditPosts, Some(vec![ramsey])),
+        ];
+
+        assert!(grants_allow(
+            &grants,
+            AdminCapability::EditPosts,
+            CountyScope::Counties(&[hennepin, ramsey])
+        ));
+        assert!(!grants_allow(
+            &grants,
+            AdminCapability::EditPosts,
+            CountyScope::Counties(&[hennepin, Uuid::new_v4()])
+        ));
+        assert!(!grants_allow(
+            &grants,
+            AdminCapability::EditPosts,
+            CountyScope::STATEWIDE
+        ));
+    }
+
+    #[test]
+    fn unscoped_and_full_admin_grants_cover_everything() {
+        let county = Uuid::new_v4();
+        let unscoped = vec![grant(AdminCapability::EditPosts, None)];
+        assert!(grants_allow(
+            &unscoped,
+            AdminCapability::EditPosts,
+            CountyScope::STATEWIDE
+        ));
+        assert!(grants_allow(
+            &unscoped,
+            AdminCapability::EditPosts,
+            CountyScope::County(county)
+        ));
+
+        let full = vec![grant(AdminCapability::FullAdmin, Some(vec![county]))];
+        assert!(grants_allow(
+            &full,
+            AdminCapability::PublishEditions,
+            CountyScope::County(county)
+        ));
+        assert!(!grants_allow(
+            &full,
+            AdminCapability::PublishEditions,
+            CountyScope::STATEWIDE
+        ));
+    }
+
+    #[test]
+    fn granted_counties_union_scoped_grants() {
+        let hennepin = Uuid::new_v4();
+        let ramsey = Uuid::new_v4();
+        let grants = vec![
+            grant(AdminCapability::ViewPosts, Some(vec![ramsey])),
+            grant(AdminCapability::ViewPosts, Some(vec![hennepin, ramsey])),
+            grant(AdminCapability::EditPosts, None),
+        ];
+        let mut expected = vec![hennepin, ramsey];
+        expected.sort();
+
+        assert_eq!(
+            granted_county_ids(&grants, AdminCapability::ViewPosts),
+            Some(expected)
+        );
+        assert_eq!(granted_county_ids(&grants, AdminCapability::EditPosts), None);
+        assert_eq!(
+            granted_county_ids(&grants, AdminCapability::PublishEditions),
+            Some(vec![])
+        );
+    }
+}
@@ -19,8 +19,14 @@ mod capability;
 ///
 /// This pattern keeps authorization logic in the effect layer where it belongs,
 /// not in the API handler layer.
+///
+/// Newsroom staff who aren't full admins hold role-based `CapabilityGrant`s,
+/// optionally scoped to counties. Route handlers for editions and posts check
+/// them with `grants_allow` against the county the resource belongs to.
 mod errors;
+mod grants;
 
 pub use builder::{Actor, CapabilityBuilder, HasAuthContext};
 pub use capability::AdminCapability;
 pub use errors::AuthError;
+pub use grants::{granted_county_ids, grants_allow, CapabilityGrant, CountyScope};
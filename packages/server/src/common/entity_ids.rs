@@ -95,6 +95,9 @@ pub struct Note;
 /// Marker type for Noteable entities (polymorphic note associations).
 pub struct Noteable;
 
+/// Marker type for PostReviewFlag entities (Signal Inbox review reasons).
+pub struct PostReviewFlag;
+
 /// Marker type for PostSource entities (unified post source tracking).
 pub struct PostSource;
 
@@ -104,6 +107,9 @@ pub struct Locatable;
 /// Marker type for OrganizationLink entities.
 pub struct OrganizationLink;
 
+/// Marker type for OrganizationCalendarFeed entities (iCalendar import).
+pub struct OrganizationCalendarFeed;
+
 /// Marker type for ApiKey entities (service-client Bearer tokens).
 pub struct ApiKey;
 
@@ -187,6 +193,9 @@ pub type NoteId = Id<Note>;
 /// Typed ID for Noteable entities.
 pub type NoteableId = Id<Noteable>;
 
+/// Typed ID for PostReviewFlag entities.
+pub type PostReviewFlagId = Id<PostReviewFlag>;
+
 /// Typed ID for PostSource entities.
 pub type PostSourceId = Id<PostSource>;
 
@@ -196,6 +205,9 @@ pub type LocatableId = Id<Locatable>;
 /// Typed ID for OrganizationLink entities.
 pub type OrganizationLinkId = Id<OrganizationLink>;
 
+/// Typed ID for OrganizationCalendarFeed entities.
+pub type OrganizationCalendarFeedId = Id<OrganizationCalendarFeed>;
+
 /// Typed ID for ApiKey entities.
 pub type ApiKeyId = Id<ApiKey>;
 
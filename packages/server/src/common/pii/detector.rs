@@ -9,6 +9,26 @@ pub enum PiiType {
     Ssn,
     CreditCard,
     IpAddress,
+    StreetAddress,
+    DriversLicense,
+    DateOfBirth,
+    PersonName,
+}
+
+impl PiiType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PiiType::Email => "email",
+            PiiType::Phone => "phone",
+            PiiType::Ssn => "ssn",
+            PiiType::CreditCard => "credit_card",
+            PiiType::IpAddress => "ip_address",
+            PiiType::StreetAddress => "street_address",
+            PiiType::DriversLicense => "drivers_license",
+            PiiType::DateOfBirth => "date_of_birth",
+            PiiType::PersonName => "person_name",
+        }
+    }
 }
 
 /// A detected piece of PII with its location
@@ -56,6 +76,66 @@ impl PiiFindings {
             .filter(|m| &m.pii_type == pii_type)
             .collect()
     }
+
+    /// Drop matches that overlap a longer match (e.g. a phone-shaped run of
+    /// digits inside a driver's licence number), leaving them in text order.
+    /// The redactor rewrites by byte range, so overlapping ranges must not
+    /// both survive.
+    fn remove_overlaps(&mut self) {
+        self.matches
+            .sort_by_key(|m| (m.start, std::cmp::Reverse(m.end - m.start)));
+
+        let mut kept: Vec<PiiMatch> = Vec::with_capacity(self.matches.len());
+        for m in self.matches.drain(..) {
+            match kept.last_mut() {
+                // Starts inside the last kept match: the longer one wins
+                Some(last) if m.start < last.end => {
+                    if m.end - m.start > last.end - last.start {
+                        *last = m;
+                    }
+                }
+                _ => kept.push(m),
+            }
+        }
+        self.matches = kept;
+    }
+}
+
+/// Values that must not be treated as PII even though they match a
+/// detector — currently the street addresses of an organisation's own
+/// `Location`s, so "visit us at 1200 Lake St" isn't redacted from that
+/// organisation's posts.
+#[derive(Debug, Default, Clone)]
+pub struct PiiAllowList {
+    addresses: Vec<String>,
+}
+
+impl PiiAllowList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allow a street address (typically `locations.address_line_1`).
+    /// Comparison ignores case, punctuation, suffix/direction spelling
+    /// ("Avenue" vs "Ave.") and any unit designator.
+    pub fn add_address(&mut self, address: &str) {
+        let normalized = normalize_street_address(address);
+        if !normalized.is_empty() && !self.addresses.contains(&normalized) {
+            self.addresses.push(normalized);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.addresses.is_empty()
+    }
+
+    pub fn allows_address(&self, address: &str) -> bool {
+        if self.addresses.is_empty() {
+            return false;
+        }
+        let normalized = normalize_street_address(address);
+        self.addresses.iter().any(|a| a == &normalized)
+    }
 }
 
 lazy_static! {
@@ -88,8 +168,70 @@ lazy_static! {
     static ref IPV6_REGEX: Regex = Regex::new(
         r"\b(?:[0-9a-fA-F]{1,4}:){7}[0-9a-fA-F]{1,4}\b"
     ).unwrap();
+
+    // US street address - house number, optional direction, 1-4 capitalised
+    // (or ordinal) street-name words, a street suffix, optional trailing
+    // direction and optional unit ("1234 N Lake St NE, Apt 5")
+    static ref STREET_ADDRESS_REGEX: Regex = Regex::new(
+        r"\b\d{1,6}[A-Z]?\s+(?:(?:North|South|East|West|NE|NW|SE|SW|N|S|E|W)\.?\s+)?(?:(?:[A-Z][a-z]+|\d{1,3}(?:st|nd|rd|th))\s+){1,4}(?i:Street|St|Avenue|Ave|Road|Rd|Boulevard|Blvd|Drive|Dr|Lane|Ln|Court|Ct|Place|Pl|Way|Terrace|Ter|Parkway|Pkwy|Circle|Cir|Highway|Hwy|Trail|Trl)\b\.?(?:\s+(?:NE|NW|SE|SW|N|S|E|W)\b\.?)?(?:,?\s+(?:(?i:Apartment|Apt|Unit|Suite|Ste)\.?\s*|\#\s*)[A-Za-z0-9-]+)?"
+    ).unwrap();
+
+    // Minnesota driver's licence / state ID - one letter and 12 digits,
+    // optionally grouped in threes (A123-456-789-012)
+    static ref MN_DRIVERS_LICENSE_REGEX: Regex = Regex::new(
+        r"\b[A-Z][-\s]?\d{3}[-\s]?\d{3}[-\s]?\d{3}[-\s]?\d{3}\b"
+    ).unwrap();
+
+    // Date of birth - only a date introduced by a birth cue word; the date
+    // itself is capture group 1
+    static ref DATE_OF_BIRTH_REGEX: Regex = Regex::new(
+        r"(?i)\b(?:dob|d\.o\.b\.?|date\s+of\s+birth|birth\s*date|birthday|born(?:\s+on)?)\s*(?:[:-]|\bis\b)?\s*(\d{1,2}[/-]\d{1,2}[/-](?:\d{4}|\d{2})\b|\d{4}-\d{2}-\d{2}\b|(?:Jan(?:uary)?|Feb(?:ruary)?|Mar(?:ch)?|Apr(?:il)?|May|June?|July?|Aug(?:ust)?|Sept?(?:ember)?|Oct(?:ober)?|Nov(?:ember)?|Dec(?:ember)?)\.?\s+\d{1,2}(?:st|nd|rd|th)?,?\s+\d{4}\b)"
+    ).unwrap();
+
+    // Person name after a cue ("my neighbor John Smith", "my name is Ana",
+    // "a man named Lee"). The cue is case-insensitive; the name must be
+    // capitalised, on one line, and is the `name` group; `self_intro` marks
+    // "my name is".
+    static ref PERSON_NAME_REGEX: Regex = Regex::new(
+        r"(?:(?i:\bmy\s+(?:next[-\s]door\s+)?(?:neighbou?r|friend|son|daughter|mother|mom|father|dad|husband|wife|partner|boyfriend|girlfriend|grandmother|grandma|grandfather|grandpa|sister|brother|aunt|uncle|cousin|niece|nephew|roommate|landlord|coworker|co-worker|caseworker)|(?P<self_intro>\bmy\s+name\s+is)|\b(?:man|woman|person|guy|lady|girl|boy|child|kid|resident|tenant)\s+named))[,:]?\s+(?P<name>[A-Z][a-z]+(?:['-][A-Z][a-z]+)?(?:[ \t]+[A-Z]\.)?(?:[ \t]+[A-Z][a-z]+(?:['-][A-Z][a-z]+)?)?)"
+    ).unwrap();
 }
 
+/// Capitalised words that follow a name cue without being a name
+/// ("my neighbor The ...", "my friend And I"). A name stops at the first
+/// one ("my friend John And I" → "John").
+const NAME_STOPWORDS: &[&str] = &[
+    "The", "A", "An", "And", "But", "Or", "So", "Who", "Is", "Was", "Has", "Had", "Will", "Would",
+    "Said", "Says", "Told", "Lives", "In", "At", "On", "From", "With", "For", "Of", "To", "I",
+];
+
+/// Words just before a street address that mark it as a venue or an
+/// organisation's premises rather than someone's home.
+const VENUE_CUES: &[&str] = &[
+    "visit us",
+    "join us",
+    "located at",
+    "location:",
+    "address:",
+    "held at",
+    "hosted at",
+    "takes place at",
+    "drop-off",
+    "drop off",
+    "our office",
+    "offices",
+    "headquarters",
+    "center",
+    "centre",
+    "church",
+    "library",
+    "school",
+    "clinic",
+    "shelter",
+    "pantry",
+    "food shelf",
+];
+
 /// Context for PII detection - determines what should be considered PII
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DetectionContext {
@@ -99,6 +241,15 @@ pub enum DetectionContext {
     PublicContent,
 }
 
+impl DetectionContext {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DetectionContext::PersonalMessage => "personal_message",
+            DetectionContext::PublicContent => "public_content",
+        }
+    }
+}
+
 /// Detect structured PII in text using regex patterns
 ///
 /// Note: This detects ALL occurrences of structured PII patterns.
@@ -111,8 +262,19 @@ pub fn detect_structured_pii(text: &str) -> PiiFindings {
 /// Detect PII with context awareness
 ///
 /// - `PersonalMessage`: Scrub all PII (emails, phones, SSNs, etc.)
-/// - `PublicContent`: Preserve likely organizational contact info
+/// - `PublicContent`: Preserve likely organizational contact info, venue
+///   addresses and staff introducing themselves ("my name is ...")
 pub fn detect_pii_contextual(text: &str, context: DetectionContext) -> PiiFindings {
+    detect_pii_with_allow_list(text, context, &PiiAllowList::default())
+}
+
+/// Detect PII with context awareness, skipping anything on `allow_list`
+/// (e.g. the organisation's own street address)
+pub fn detect_pii_with_allow_list(
+    text: &str,
+    context: DetectionContext,
+    allow_list: &PiiAllowList,
+) -> PiiFindings {
     let mut findings = PiiFindings::new();
 
     // Detect emails
@@ -187,9 +349,143 @@ pub fn detect_pii_contextual(text: &str, context: DetectionContext) -> PiiFindin
         );
     }
 
+    // Detect street addresses (organisation's own addresses are allowed)
+    for mat in STREET_ADDRESS_REGEX.find_iter(text) {
+        let address = mat.as_str();
+        if allow_list.allows_address(address) {
+            continue;
+        }
+
+        // For public content, skip venue and office addresses
+        if context == DetectionContext::PublicContent && is_likely_venue_address(mat.start(), text)
+        {
+            continue;
+        }
+
+        findings.add(
+            PiiType::StreetAddress,
+            address.to_string(),
+            mat.start(),
+            mat.end(),
+        );
+    }
+
+    // Detect Minnesota driver's licence numbers
+    for mat in MN_DRIVERS_LICENSE_REGEX.find_iter(text) {
+        findings.add(
+            PiiType::DriversLicense,
+            mat.as_str().to_string(),
+            mat.start(),
+            mat.end(),
+        );
+    }
+
+    // Detect dates of birth (the date only, not the cue)
+    for caps in DATE_OF_BIRTH_REGEX.captures_iter(text) {
+        if let Some(date) = caps.get(1) {
+            findings.add(
+                PiiType::DateOfBirth,
+                date.as_str().to_string(),
+                date.start(),
+                date.end(),
+            );
+        }
+    }
+
+    // Detect person names following a cue word (the name only)
+    for caps in PERSON_NAME_REGEX.captures_iter(text) {
+        // For public content, someone introducing themselves is staff
+        if context == DetectionContext::PublicContent && caps.name("self_intro").is_some() {
+            continue;
+        }
+        let Some(name) = caps.name("name") else {
+            continue;
+        };
+        let Some(trimmed) = trim_name(name.as_str()) else {
+            continue;
+        };
+
+        findings.add(
+            PiiType::PersonName,
+            trimmed.to_string(),
+            name.start(),
+            name.start() + trimmed.len(),
+        );
+    }
+
+    findings.remove_overlaps();
     findings
 }
 
+/// Cut a cue-matched name at its first stopword ("John And" → "John").
+/// None when the name starts with one.
+fn trim_name(name: &str) -> Option<&str> {
+    let mut end = 0;
+    for word in name.split_whitespace() {
+        if NAME_STOPWORDS.contains(&word) {
+            break;
+        }
+        end = name[end..].find(word).map_or(end, |i| end + i) + word.len();
+    }
+    (end > 0).then(|| &name[..end])
+}
+
+/// Check if the clause leading up to an address names a venue or an
+/// organisation's premises ("Visit us at ...", "Sabathani Community
+/// Center, ...")
+fn is_likely_venue_address(address_start: usize, context_text: &str) -> bool {
+    let before = &context_text[..address_start];
+    let clause_start = before.rfind(['.', '!', '?', '\n']).map_or(0, |i| i + 1);
+    let clause = before[clause_start..].to_lowercase();
+
+    VENUE_CUES.iter().any(|cue| clause.contains(cue))
+}
+
+/// Reduce a street address to a comparable form: lowercase words, USPS
+/// suffix and direction abbreviations, unit designator dropped
+/// ("1200 Lake Street East, Suite 4" → "1200 lake st e").
+fn normalize_street_address(address: &str) -> String {
+    // "#4" units have no keyword to stop at, so cut them off up front.
+    let street = address.split('#').next().unwrap_or_default();
+    let lowered = street.to_lowercase();
+    let mut words: Vec<&str> = Vec::new();
+
+    for word in lowered
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+    {
+        if matches!(word, "apartment" | "apt" | "unit" | "suite" | "ste") {
+            break;
+        }
+        words.push(match word {
+            "street" => "st",
+            "avenue" => "ave",
+            "road" => "rd",
+            "boulevard" => "blvd",
+            "drive" => "dr",
+            "lane" => "ln",
+            "court" => "ct",
+            "place" => "pl",
+            "terrace" => "ter",
+            "parkway" => "pkwy",
+            "circle" => "cir",
+            "highway" => "hwy",
+            "trail" => "trl",
+            "north" => "n",
+            "south" => "s",
+            "east" => "e",
+            "west" => "w",
+            "northeast" => "ne",
+            "northwest" => "nw",
+            "southeast" => "se",
+            "southwest" => "sw",
+            other => other,
+        });
+    }
+
+    words.join(" ")
+}
+
 /// Luhn algorithm for credit card validation
 fn is_valid_luhn(card_number: &str) -> bool {
     let digits: Vec<u32> = card_number.chars().filter_map(|c| c.to_digit(10)).collect();
@@ -435,4 +731,142 @@ mod tests {
         assert_eq!(findings.by_type(&PiiType::Phone).len(), 1);
         assert_eq!(findings.by_type(&PiiType::IpAddress).len(), 1);
     }
+
+    #[test]
+    fn test_detect_street_addresses() {
+        let text = "I live at 1234 N Lake Street, Apt 5 and work at 500 W 7th St.";
+        let findings = detect_structured_pii(text);
+
+        let addresses = findings.by_type(&PiiType::StreetAddress);
+        assert_eq!(addresses.len(), 2);
+        assert_eq!(addresses[0].value, "1234 N Lake Street, Apt 5");
+        assert_eq!(addresses[1].value, "500 W 7th St.");
+    }
+
+    #[test]
+    fn test_street_address_needs_suffix() {
+        let text = "We served 1200 Families last year";
+        let findings = detect_structured_pii(text);
+
+        assert!(findings.by_type(&PiiType::StreetAddress).is_empty());
+    }
+
+    #[test]
+    fn test_allow_listed_address_is_kept() {
+        let mut allow_list = PiiAllowList::new();
+        allow_list.add_address("1200 Lake Street East, Suite 200");
+
+        let text = "Visit us at 1200 Lake St E. My place is 77 Pine Ave #3.";
+        let findings =
+            detect_pii_with_allow_list(text, DetectionContext::PublicContent, &allow_list);
+
+        let addresses = findings.by_type(&PiiType::StreetAddress);
+        assert_eq!(addresses.len(), 1);
+        assert_eq!(addresses[0].value, "77 Pine Ave #3");
+    }
+
+    #[test]
+    fn test_detect_mn_drivers_license() {
+        let text = "License A123456789012 or B-123-456-789-012";
+        let findings = detect_structured_pii(text);
+
+        let licenses = findings.by_type(&PiiType::DriversLicense);
+        assert_eq!(licenses.len(), 2);
+        // The digits inside the licence aren't also reported as a phone
+        assert!(findings.by_type(&PiiType::Phone).is_empty());
+    }
+
+    #[test]
+    fn test_detect_date_of_birth() {
+        let text = "DOB: 03/14/1985. Born on March 14, 1985. The event is 03/14/2025.";
+        let findings = detect_structured_pii(text);
+
+        let dates = findings.by_type(&PiiType::DateOfBirth);
+        assert_eq!(dates.len(), 2);
+        assert_eq!(dates[0].value, "03/14/1985");
+        assert_eq!(dates[1].value, "March 14, 1985");
+    }
+
+    #[test]
+    fn test_detect_cue_word_names() {
+        let text = "My neighbor John Smith needs help. My friend said hi. A man named Lee came by.";
+        let findings = detect_structured_pii(text);
+
+        let names = findings.by_type(&PiiType::PersonName);
+        assert_eq!(names.len(), 2);
+        assert_eq!(names[0].value, "John Smith");
+        assert_eq!(names[1].value, "Lee");
+    }
+
+    #[test]
+    fn test_name_stops_at_a_stopword() {
+        let text = "My friend John And I went. My neighbor Ana\nThe end.";
+        let findings = detect_structured_pii(text);
+
+        let names = findings.by_type(&PiiType::PersonName);
+        assert_eq!(names.len(), 2);
+        assert_eq!(names[0].value, "John");
+        assert_eq!(&text[names[0].start..names[0].end], "John");
+        assert_eq!(names[1].value, "Ana");
+    }
+
+    #[test]
+    fn test_public_content_keeps_self_introductions() {
+        let text = "My name is Maria Lopez and I run the pantry. My neighbor John Smith helps.";
+        let findings = detect_pii_contextual(text, DetectionContext::PublicContent);
+
+        let names = findings.by_type(&PiiType::PersonName);
+        assert_eq!(names.len(), 1);
+        assert_eq!(names[0].value, "John Smith");
+
+        let findings = detect_structured_pii(text);
+        assert_eq!(findings.by_type(&PiiType::PersonName).len(), 2);
+    }
+
+    #[test]
+    fn test_public_content_keeps_venue_addresses() {
+        let text = "Sabathani Community Center, 310 E 38th St. My place is 77 Pine Ave #3.";
+        let findings = detect_pii_contextual(text, DetectionContext::PublicContent);
+
+        let addresses = findings.by_type(&PiiType::StreetAddress);
+        assert_eq!(addresses.len(), 1);
+        assert_eq!(addresses[0].value, "77 Pine Ave #3");
+
+        let findings = detect_structured_pii(text);
+        assert_eq!(findings.by_type(&PiiType::StreetAddress).len(), 2);
+    }
+
+    #[test]
+    fn test_overlaps_keep_the_longer_match_in_text_order() {
+        let mut findings = PiiFindings::new();
+        findings.add(PiiType::Phone, "456-789-0123".into(), 6, 18);
+        findings.add(PiiType::Email, "a@b.co".into(), 30, 36);
+        findings.add(PiiType::DriversLicense, "A123-456-789-012".into(), 0, 16);
+        findings.add(PiiType::Ssn, "123-45-6789".into(), 20, 31);
+        findings.remove_overlaps();
+
+        let kept: Vec<PiiType> = findings
+            .matches
+            .iter()
+            .map(|m| m.pii_type.clone())
+            .collect();
+        assert_eq!(kept, vec![PiiType::DriversLicense, PiiType::Ssn]);
+    }
+
+    #[test]
+    fn test_names_without_cue_are_ignored() {
+        let text = "John Smith from Hennepin County spoke at the meeting.";
+        let findings = detect_structured_pii(text);
+
+        assert!(findings.by_type(&PiiType::PersonName).is_empty());
+    }
+
+    #[test]
+    fn test_normalize_street_address() {
+        assert_eq!(
+            normalize_street_address("1200 Lake Street East, Suite 4"),
+            "1200 lake st e"
+        );
+        assert_eq!(normalize_street_address("1200 Lake St. E #4"), "1200 lake st e");
+    }
 }
@@ -1,7 +1,9 @@
 /// PII (Personally Identifiable Information) detection and redaction
 ///
-/// Regex-based detection of structured PII (emails, phones, SSNs, credit cards, IPs)
-/// with context-aware filtering (preserves public organizational contact info).
+/// Regex-based detection of structured PII (emails, phones, SSNs, credit cards, IPs,
+/// street addresses, MN driver's licences, dates of birth, cue-word person names)
+/// with context-aware filtering (preserves public organizational contact info and
+/// allow-listed organization addresses).
 ///
 /// # Redaction Strategies
 ///
@@ -28,6 +30,7 @@ pub mod redactor;
 
 // Re-export main types and functions
 pub use detector::{
-    detect_pii_contextual, detect_structured_pii, DetectionContext, PiiFindings, PiiMatch, PiiType,
+    detect_pii_contextual, detect_pii_with_allow_list, detect_structured_pii, DetectionContext,
+    PiiAllowList, PiiFindings, PiiMatch, PiiType,
 };
-pub use redactor::{redact_pii, RedactionStrategy};
+pub use redactor::{mask_value, redact_pii, RedactionStrategy};
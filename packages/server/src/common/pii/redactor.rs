@@ -13,6 +13,16 @@ pub enum RedactionStrategy {
     TokenReplacement,
 }
 
+impl RedactionStrategy {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RedactionStrategy::FullRemoval => "full_removal",
+            RedactionStrategy::PartialMask => "partial_mask",
+            RedactionStrategy::TokenReplacement => "token_replacement",
+        }
+    }
+}
+
 /// Redact PII from text based on findings and strategy
 pub fn redact_pii(text: &str, findings: &PiiFindings, strategy: RedactionStrategy) -> String {
     if findings.is_empty() {
@@ -48,17 +58,25 @@ fn type_to_token(pii_type: &PiiType) -> &'static str {
         PiiType::Ssn => "SSN",
         PiiType::CreditCard => "CREDIT_CARD",
         PiiType::IpAddress => "IP_ADDRESS",
+        PiiType::StreetAddress => "ADDRESS",
+        PiiType::DriversLicense => "DRIVERS_LICENSE",
+        PiiType::DateOfBirth => "DOB",
+        PiiType::PersonName => "NAME",
     }
 }
 
 /// Partially mask a value while preserving some readability
-fn mask_value(value: &str, pii_type: &PiiType) -> String {
+pub fn mask_value(value: &str, pii_type: &PiiType) -> String {
     match pii_type {
         PiiType::Email => mask_email(value),
         PiiType::Phone => mask_phone(value),
         PiiType::Ssn => mask_ssn(value),
         PiiType::CreditCard => mask_credit_card(value),
         PiiType::IpAddress => mask_ip(value),
+        PiiType::StreetAddress => mask_street_address(value),
+        PiiType::DriversLicense => mask_drivers_license(value),
+        PiiType::DateOfBirth => mask_date_of_birth(value),
+        PiiType::PersonName => mask_person_name(value),
     }
 }
 
@@ -168,6 +186,50 @@ fn mask_ip(ip: &str) -> String {
     }
 }
 
+/// Mask street address: 1234 Lake St, Apt 5 -> **** Lake St
+fn mask_street_address(address: &str) -> String {
+    let street = address
+        .split([',', '#'])
+        .next()
+        .unwrap_or_default()
+        .trim_end();
+    match street.split_once(char::is_whitespace) {
+        Some((_, rest)) => format!("**** {}", rest.trim_start()),
+        None => "**** ***".to_string(),
+    }
+}
+
+/// Mask driver's licence: A123-456-789-012 -> A*********012
+fn mask_drivers_license(license: &str) -> String {
+    let chars: Vec<char> = license.chars().filter(|c| c.is_ascii_alphanumeric()).collect();
+    if chars.len() > 4 {
+        let last_three: String = chars[chars.len() - 3..].iter().collect();
+        format!("{}{}{}", chars[0], "*".repeat(chars.len() - 4), last_three)
+    } else {
+        "*************".to_string()
+    }
+}
+
+/// Mask date of birth, keeping only the year: 03/14/1985 -> **/**/1985
+fn mask_date_of_birth(date: &str) -> String {
+    let year = date
+        .split(|c: char| !c.is_ascii_digit())
+        .find(|part| part.len() == 4);
+    match year {
+        Some(year) => format!("**/**/{}", year),
+        None => "**/**/****".to_string(),
+    }
+}
+
+/// Mask person name to initials: John Smith -> J. S.
+fn mask_person_name(name: &str) -> String {
+    name.split_whitespace()
+        .filter_map(|word| word.chars().next())
+        .map(|initial| format!("{}.", initial))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -243,6 +305,44 @@ mod tests {
         assert_eq!(mask_ip("10.0.0.5"), "10.0.*.*");
     }
 
+    #[test]
+    fn test_mask_street_address() {
+        assert_eq!(mask_street_address("1234 Lake St, Apt 5"), "**** Lake St");
+        assert_eq!(mask_street_address("77 Pine Ave #3"), "**** Pine Ave");
+    }
+
+    #[test]
+    fn test_mask_drivers_license() {
+        assert_eq!(mask_drivers_license("A123456789012"), "A*********012");
+        assert_eq!(mask_drivers_license("A-123-456-789-012"), "A*********012");
+    }
+
+    #[test]
+    fn test_mask_date_of_birth() {
+        assert_eq!(mask_date_of_birth("03/14/1985"), "**/**/1985");
+        assert_eq!(mask_date_of_birth("March 14, 1985"), "**/**/1985");
+        assert_eq!(mask_date_of_birth("3/14/85"), "**/**/****");
+    }
+
+    #[test]
+    fn test_mask_person_name() {
+        assert_eq!(mask_person_name("John Smith"), "J. S.");
+        assert_eq!(mask_person_name("Ana"), "A.");
+    }
+
+    #[test]
+    fn test_token_replacement_for_new_types() {
+        let text = "My neighbor John Smith lives at 1234 Lake St. DOB 03/14/1985.";
+        let findings = detect_structured_pii(text);
+
+        let result = redact_pii(text, &findings, RedactionStrategy::TokenReplacement);
+
+        assert_eq!(
+            result,
+            "My neighbor [NAME] lives at [ADDRESS] DOB [DOB]."
+        );
+    }
+
     #[test]
     fn test_multiple_pii_types() {
         let text = "Email john@example.com, phone 555-123-4567, IP 192.168.1.1";
@@ -0,0 +1,73 @@
+//! Resolve a member's role grants into `CapabilityGrant`s for the JWT.
+
+use anyhow::Result;
+use sqlx::PgPool;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::common::auth::{AdminCapability, CapabilityGrant};
+use crate::domains::auth::models::{MemberCapabilityRow, MemberRole};
+
+/// Capabilities the member holds through their roles, one grant per
+/// capability. A capability held anywhere without a county is unscoped.
+pub async fn resolve_grants(member_id: Uuid, pool: &PgPool) -> Result<Vec<CapabilityGrant>> {
+    let rows = MemberRole::capabilities_for_member(member_id, pool).await?;
+    Ok(collapse_grants(&rows))
+}
+
+fn collapse_grants(rows: &[MemberCapabilityRow]) -> Vec<CapabilityGrant> {
+    let mut grants: Vec<CapabilityGrant> = Vec::new();
+    for row in rows {
+        let Some(capability) = AdminCapability::parse(&row.capability) else {
+            warn!(capability = %row.capability, "Ignoring unknown capability in role_capabilities");
+            continue;
+        };
+        let grant = match grants.iter_mut().find(|g| g.capability == capability) {
+            Some(grant) => grant,
+            None => {
+                grants.push(CapabilityGrant {
+                    capability,
+                    county_ids: Some(Vec::new()),
+                });
+                grants.last_mut().expect("just pushed")
+            }
+        };
+        match (row.county_id, grant.county_ids.as_mut()) {
+            (None, _) => grant.county_ids = None,
+            (Some(county_id), Some(ids)) if !ids.contains(&county_id) => ids.push(county_id),
+            _ => {}
+        }
+    }
+    grants
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(capability: &str, county_id: Option<Uuid>) -> MemberCapabilityRow {
+        MemberCapabilityRow {
+            capability: capability.to_string(),
+            county_id,
+        }
+    }
+
+    #[test]
+    fn collapses_county_rows_and_lets_unscoped_win() {
+        let hennepin = Uuid::new_v4();
+        let anoka = Uuid::new_v4();
+        let grants = collapse_grants(&[
+            row("editions:publish", Some(hennepin)),
+            row("editions:publish", Some(anoka)),
+            row("posts:edit", Some(hennepin)),
+            row("posts:edit", None),
+            row("posts:teleport", None),
+        ]);
+
+        assert_eq!(grants.len(), 2);
+        assert_eq!(grants[0].capability, AdminCapability::PublishEditions);
+        assert_eq!(grants[0].county_ids, Some(vec![hennepin, anoka]));
+        assert_eq!(grants[1].capability, AdminCapability::EditPosts);
+        assert_eq!(grants[1].county_ids, None);
+    }
+}
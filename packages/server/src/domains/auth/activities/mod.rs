@@ -3,8 +3,17 @@
 //! Actions return events directly. HTTP handlers call actions via activities
 //! and orchestrate the execution flow.
 
+mod grants;
+pub mod otp_limits;
 mod send_otp;
+mod sessions;
 mod verify_otp;
 
+pub use grants::resolve_grants;
+pub use otp_limits::RateLimitedError;
 pub use send_otp::{send_otp, NotAuthorizedError};
+pub use sessions::{
+    logout, refresh_session, revoke_member_sessions, start_session, InvalidRefreshTokenError,
+    SessionTokens, SESSION_TTL_DAYS,
+};
 pub use verify_otp::{verify_otp, VerificationFailedError};
@@ -0,0 +1,335 @@
+//! Budgets and lockouts for the OTP endpoints.
+//!
+//! Sending an OTP costs an SMS, so `send_otp` is budgeted per identifier,
+//! per client IP and globally (the global budget caps an SMS-pumping run
+//! spread across many numbers and addresses). `verify_otp` is budgeted the
+//! same way, and wrong codes lock the identifier (and, at a higher
+//! threshold, the IP) out with exponential backoff.
+//!
+//! Identifier keys use the identifier hash, never the raw value. Exhausted
+//! budgets (once per window) and lockouts are recorded as security events.
+
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use tracing::warn;
+
+use crate::domains::auth::models::NormalizedIdentifier;
+use crate::kernel::rate_limit::{Budget, LockoutPolicy, NewRateLimitEvent};
+use crate::kernel::BaseRateLimiter;
+
+/// Error returned when a budget is exhausted or a lockout is active.
+#[derive(Debug, thiserror::Error)]
+#[error("Too many attempts. Try again in {retry_after_secs} seconds.")]
+pub struct RateLimitedError {
+    pub retry_after_secs: u64,
+    /// Which budget or lockout tripped, e.g. `otp_send:ip`.
+    pub scope: String,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Scope {
+    Identifier,
+    Ip,
+    Global,
+}
+
+impl Scope {
+    fn as_str(self) -> &'static str {
+        match self {
+            Scope::Identifier => "id",
+            Scope::Ip => "ip",
+            Scope::Global => "global",
+        }
+    }
+}
+
+const SEND_BUDGETS: [(Scope, Budget); 4] = [
+    (Scope::Identifier, Budget::new(3, 10 * 60)),
+    (Scope::Identifier, Budget::new(10, 24 * 3600)),
+    (Scope::Ip, Budget::new(10, 3600)),
+    (Scope::Global, Budget::new(500, 3600)),
+];
+
+const VERIFY_BUDGETS: [(Scope, Budget); 3] = [
+    (Scope::Identifier, Budget::new(10, 10 * 60)),
+    (Scope::Ip, Budget::new(30, 3600)),
+    (Scope::Global, Budget::new(2000, 3600)),
+];
+
+const IDENTIFIER_LOCKOUT: LockoutPolicy = LockoutPolicy {
+    threshold: 5,
+    failure_window: Duration::minutes(15),
+    base_lockout: Duration::minutes(15),
+    max_lockout: Duration::hours(24),
+    strike_decay: Duration::hours(24),
+};
+
+const IP_LOCKOUT: LockoutPolicy = LockoutPolicy {
+    threshold: 20,
+    failure_window: Duration::hours(1),
+    base_lockout: Duration::minutes(30),
+    max_lockout: Duration::hours(24),
+    strike_decay: Duration::hours(24),
+};
+
+/// Who is asking: the (hashed) identifier and the client IP, if known.
+#[derive(Debug, Clone)]
+pub struct OtpRequester {
+    identifier_hash: String,
+    ip: Option<String>,
+}
+
+impl OtpRequester {
+    pub fn new(identifier: &NormalizedIdentifier, ip: Option<String>) -> Self {
+        Self {
+            identifier_hash: identifier.hash(),
+            ip,
+        }
+    }
+
+    fn key(&self, action: &str, scope: Scope) -> Option<String> {
+        let subject = match scope {
+            Scope::Identifier => Some(self.identifier_hash.as_str()),
+            Scope::Ip => self.ip.as_deref(),
+            Scope::Global => None,
+        };
+        match (scope, subject) {
+            (Scope::Global, _) => Some(format!("{}:{}", action, scope.as_str())),
+            (_, Some(subject)) => Some(format!("{}:{}:{}", action, scope.as_str(), subject)),
+            (_, None) => None,
+        }
+    }
+
+    fn lockouts(&self) -> Vec<(String, &'static LockoutPolicy)> {
+        [
+            (Scope::Identifier, &IDENTIFIER_LOCKOUT),
+            (Scope::Ip, &IP_LOCKOUT),
+        ]
+        .into_iter()
+        .filter_map(|(scope, policy)| Some((self.key("otp_verify", scope)?, policy)))
+        .collect()
+    }
+}
+
+/// Count an OTP send against its budgets.
+pub async fn check_send(
+    requester: &OtpRequester,
+    limiter: &dyn BaseRateLimiter,
+    now: DateTime<Utc>,
+) -> Result<()> {
+    consume(requester, "otp_send", &SEND_BUDGETS, limiter, now).await
+}
+
+/// Refuse a verification while a lockout is active, then count it against
+/// its budgets.
+pub async fn check_verify(
+    requester: &OtpRequester,
+    limiter: &dyn BaseRateLimiter,
+    now: DateTime<Utc>,
+) -> Result<()> {
+    for (key, _) in requester.lockouts() {
+        let Some(state) = limiter.lockout_state(&key).await? else {
+            continue;
+        };
+        if let Some(remaining) = state.locked_for(now) {
+            return Err(limited(&key, remaining));
+        }
+    }
+    consume(requester, "otp_verify", &VERIFY_BUDGETS, limiter, now).await
+}
+
+/// Record a wrong code, locking the identifier or IP out once it has
+/// failed too often.
+pub async fn record_verify_failure(
+    requester: &OtpRequester,
+    limiter: &dyn BaseRateLimiter,
+    now: DateTime<Utc>,
+) -> Result<()> {
+    for (key, policy) in requester.lockouts() {
+        let (next, lockout) = limiter.record_failure(&key, policy, now).await?;
+
+        if let Some(lockout) = lockout {
+            warn!(
+                key = %key,
+                strikes = next.strikes,
+                lockout_secs = lockout.num_seconds(),
+                "OTP lockout"
+            );
+            limiter
+                .record_event(NewRateLimitEvent {
+                    event_type: "lockout".to_string(),
+                    subject: key,
+                    ip_address: requester.ip.clone(),
+                    detail: serde_json::json!({
+                        "strikes": next.strikes,
+                        "lockout_secs": lockout.num_seconds(),
+                        "locked_until": next.locked_until,
+                    }),
+                })
+                .await?;
+        }
+    }
+    Ok(())
+}
+
+/// Forget the identifier's failures after a correct code. IP failures
+/// stand, so one good login can't launder a spray across many numbers.
+pub async fn record_verify_success(
+    requester: &OtpRequester,
+    limiter: &dyn BaseRateLimiter,
+) -> Result<()> {
+    if let Some(key) = requester.key("otp_verify", Scope::Identifier) {
+        limiter.clear_lockout(&key).await?;
+    }
+    Ok(())
+}
+
+/// Lift an identifier's verification lockout (admin action).
+pub async fn clear_identifier_lockout(
+    identifier: &NormalizedIdentifier,
+    limiter: &dyn BaseRateLimiter,
+) -> Result<()> {
+    record_verify_success(&OtpRequester::new(identifier, None), limiter).await
+}
+
+async fn consume(
+    requester: &OtpRequester,
+    action: &str,
+    budgets: &[(Scope, Budget)],
+    limiter: &dyn BaseRateLimiter,
+    now: DateTime<Utc>,
+) -> Result<()> {
+    for (scope, budget) in budgets {
+        let Some(key) = requester.key(action, *scope) else {
+            continue;
+        };
+        // Budgets sharing a scope differ by window; keep their counters apart.
+        let key = format!("{}:{}", key, budget.window.num_seconds());
+        let hit = limiter.hit(&key, budget.window, now).await?;
+        if hit.count <= budget.limit {
+            continue;
+        }
+
+        // Record the first refusal in each window, not every one after it.
+        if hit.count == budget.limit + 1 {
+            warn!(key = %key, limit = budget.limit, "Rate limit budget exhausted");
+            limiter
+                .record_event(NewRateLimitEvent {
+                    event_type: "budget_exceeded".to_string(),
+                    subject: key.clone(),
+                    ip_address: requester.ip.clone(),
+                    detail: serde_json::json!({
+                        "limit": budget.limit,
+                        "window_secs": budget.window.num_seconds(),
+                        "resets_at": hit.resets_at,
+                    }),
+                })
+                .await?;
+        }
+        return Err(limited(&key, hit.resets_at - now));
+    }
+    Ok(())
+}
+
+fn limited(key: &str, remaining: Duration) -> anyhow::Error {
+    // `otp_send:id:<hash>:600` -> `otp_send:id`
+    let scope = key.splitn(3, ':').take(2).collect::<Vec<_>>().join(":");
+    RateLimitedError {
+        retry_after_secs: remaining.num_seconds().max(1) as u64,
+        scope,
+    }
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kernel::InMemoryRateLimiter;
+
+    fn now() -> DateTime<Utc> {
+        DateTime::from_timestamp(1_800_000_000, 0).unwrap()
+    }
+
+    fn requester(identifier: &str, ip: Option<&str>) -> OtpRequester {
+        let identifier = NormalizedIdentifier::parse(identifier).unwrap();
+        OtpRequester::new(&identifier, ip.map(str::to_string))
+    }
+
+    fn rate_limited(result: Result<()>) -> RateLimitedError {
+        result
+            .unwrap_err()
+            .downcast::<RateLimitedError>()
+            .expect("expected RateLimitedError")
+    }
+
+    #[tokio::test]
+    async fn send_budget_per_identifier_and_retry_after() {
+        let limiter = InMemoryRateLimiter::new();
+        let phone = requester("+16125550100", Some("203.0.113.7"));
+
+        for _ in 0..3 {
+            check_send(&phone, &limiter, now()).await.unwrap();
+        }
+        let err = rate_limited(check_send(&phone, &limiter, now()).await);
+        assert_eq!(err.scope, "otp_send:id");
+        assert!(err.retry_after_secs > 0 && err.retry_after_secs <= 600);
+
+        // Another number from the same IP still has budget
+        let other = requester("+16125550101", Some("203.0.113.7"));
+        check_send(&other, &limiter, now()).await.unwrap();
+
+        // One event per exhausted window, not per refusal
+        let _ = check_send(&phone, &limiter, now()).await;
+        let events = limiter.events().await;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, "budget_exceeded");
+        assert!(!events[0].subject.contains("6125550100"));
+    }
+
+    #[tokio::test]
+    async fn send_budget_per_ip() {
+        let limiter = InMemoryRateLimiter::new();
+        for i in 0..10 {
+            let number = format!("+1612555{:04}", i);
+            check_send(&requester(&number, Some("198.51.100.1")), &limiter, now())
+                .await
+                .unwrap();
+        }
+        let blocked = requester("+16125559999", Some("198.51.100.1"));
+        let err = rate_limited(check_send(&blocked, &limiter, now()).await);
+        assert_eq!(err.scope, "otp_send:ip");
+    }
+
+    #[tokio::test]
+    async fn repeated_failures_lock_out_until_success_clears_them() {
+        let limiter = InMemoryRateLimiter::new();
+        let editor = requester("Editor@Example.com", None);
+
+        for _ in 0..5 {
+            check_verify(&editor, &limiter, now()).await.unwrap();
+            record_verify_failure(&editor, &limiter, now()).await.unwrap();
+        }
+        let err = rate_limited(check_verify(&editor, &limiter, now()).await);
+        assert_eq!(err.scope, "otp_verify:id");
+        assert_eq!(err.retry_after_secs, 15 * 60);
+
+        // Case differences don't dodge the lockout
+        let same = requester("editor@example.com", None);
+        assert!(check_verify(&same, &limiter, now()).await.is_err());
+
+        let events = limiter.events().await;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, "lockout");
+
+        // After the lockout, the next run of failures locks out for twice as long
+        let later = now() + Duration::minutes(16);
+        for _ in 0..5 {
+            record_verify_failure(&editor, &limiter, later).await.unwrap();
+        }
+        let err = rate_limited(check_verify(&editor, &limiter, later).await);
+        assert_eq!(err.retry_after_secs, 30 * 60);
+
+        record_verify_success(&editor, &limiter).await.unwrap();
+        check_verify(&editor, &limiter, later).await.unwrap();
+    }
+}
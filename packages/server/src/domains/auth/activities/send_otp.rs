@@ -1,10 +1,12 @@
 //! Send OTP action
 
 use anyhow::Result;
+use chrono::Utc;
 use tracing::{error, info};
 
+use crate::domains::auth::activities::otp_limits::{self, OtpRequester};
 use crate::domains::auth::models::{
-    hash_phone_number, is_admin_identifier, is_test_identifier, Identifier,
+    is_admin_identifier, is_test_identifier, Identifier, IdentifierKind, NormalizedIdentifier,
 };
 use crate::domains::auth::types::OtpSent;
 use crate::kernel::ServerDeps;
@@ -14,45 +16,66 @@ use crate::kernel::ServerDeps;
 #[error("Not authorized")]
 pub struct NotAuthorizedError;
 
-/// Send OTP to phone number or email via Twilio.
+/// Send OTP to phone number (SMS) or email via Twilio Verify.
+///
+/// The identifier is normalized first; malformed input fails with
+/// `InvalidIdentifierError`.
 ///
 /// Authorization: identifier must exist, or be an admin/test identifier.
-/// Test identifiers skip actual Twilio send.
+/// Test identifiers skip actual Twilio send and the rate limits.
+///
+/// Sends are budgeted per identifier, per client IP and globally; an
+/// exhausted budget fails with `RateLimitedError` before any lookup.
 ///
 /// Returns `OtpSent` on success.
-pub async fn send_otp(phone_number: String, deps: &ServerDeps) -> Result<OtpSent> {
-    let phone_hash = hash_phone_number(&phone_number);
-    let identifier_exists = Identifier::find_by_phone_hash(&phone_hash, &deps.db_pool)
+pub async fn send_otp(
+    identifier: String,
+    client_ip: Option<String>,
+    deps: &ServerDeps,
+) -> Result<OtpSent> {
+    let identifier = NormalizedIdentifier::parse(&identifier)?;
+    let value = &identifier.value;
+
+    let is_test = deps.test_identifier_enabled && is_test_identifier(value);
+    if !is_test {
+        let requester = OtpRequester::new(&identifier, client_ip);
+        otp_limits::check_send(&requester, deps.rate_limiter.as_ref(), Utc::now()).await?;
+    }
+
+    let identifier_exists = Identifier::find_by_identifier(&identifier, &deps.db_pool)
         .await?
         .is_some();
 
-    let is_admin = is_admin_identifier(&phone_number, &deps.admin_identifiers);
-    let is_test = deps.test_identifier_enabled && is_test_identifier(&phone_number);
+    let is_admin = is_admin_identifier(value, &deps.admin_identifiers);
 
     // Must be registered, admin, or test identifier
     if !identifier_exists && !is_admin && !is_test {
-        info!("Identifier not authorized: {}", phone_number);
+        info!("Identifier not authorized: {}", value);
         return Err(NotAuthorizedError.into());
     }
 
     // Test identifiers skip actual OTP send
     if is_test {
-        info!("Test identifier: skipping OTP send for {}", phone_number);
+        info!("Test identifier: skipping OTP send for {}", value);
         return Ok(OtpSent {
-            phone_number: phone_number.clone(),
+            phone_number: value.clone(),
             success: true,
         });
     }
 
-    // Send OTP via Twilio
-    deps.twilio.send_otp(&phone_number).await.map_err(|e| {
+    // Send OTP via Twilio: SMS for phones, the Verify email channel for emails
+    let sent = match identifier.kind {
+        IdentifierKind::Phone => deps.twilio.send_otp(value).await,
+        IdentifierKind::Email => deps.twilio.send_email_otp(value).await,
+    };
+    sent.map_err(|e| {
         error!("Failed to send OTP: {}", e);
         anyhow::anyhow!("Failed to send OTP: {}", e)
     })?;
 
-    info!("OTP sent to {}", phone_number);
+    info!("OTP sent to {} ({})", value, identifier.kind.as_str());
     Ok(OtpSent {
-        phone_number: phone_number.clone(),
+        phone_number: value.clone(),
         success: true,
     })
 }
@@ -0,0 +1,238 @@
+//! Session lifecycle: started at OTP verification, extended with rotating
+//! refresh tokens, ended by logout or an admin revoking a member's access.
+
+use anyhow::Result;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use rand::RngCore;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::common::auth::CapabilityGrant;
+use crate::common::pii::{mask_value, PiiType};
+use crate::domains::auth::activities::resolve_grants;
+use crate::domains::auth::models::{
+    AuthSession, Identifier, NewAuthSession, RevokedToken, SessionRotation,
+};
+use crate::domains::auth::types::SessionRefreshed;
+use crate::domains::auth::IssuedToken;
+use crate::kernel::ServerDeps;
+
+/// A session lasts this long from login; refreshing doesn't extend it.
+pub const SESSION_TTL_DAYS: i64 = 30;
+
+/// Error returned when a refresh token is unknown, expired, revoked or
+/// already used. Deliberately doesn't say which.
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid refresh token")]
+pub struct InvalidRefreshTokenError;
+
+/// Tokens handed to the client when a session starts.
+#[derive(Debug, Clone)]
+pub struct SessionTokens {
+    pub access: IssuedToken,
+    pub refresh_token: String,
+}
+
+/// Open a session for a member who just verified an OTP.
+pub async fn start_session(
+    member_id: Uuid,
+    identifier: &str,
+    is_admin: bool,
+    grants: Vec<CapabilityGrant>,
+    deps: &ServerDeps,
+) -> Result<SessionTokens> {
+    let session_id = Uuid::new_v4();
+    let hint = identifier_hint(identifier);
+    let access = deps.jwt_service.create_token(
+        member_id,
+        hint.clone(),
+        is_admin,
+        grants,
+        Some(session_id),
+    )?;
+    let refresh_token = generate_refresh_token();
+
+    AuthSession::create(
+        NewAuthSession {
+            id: session_id,
+            member_id,
+            identifier_hint: hint,
+            refresh_token_hash: AuthSession::hash_refresh_token(&refresh_token),
+            access_jti: access.jti.clone(),
+            access_expires_at: access.expires_at,
+            expires_at: Utc::now() + chrono::Duration::days(SESSION_TTL_DAYS),
+        },
+        &deps.db_pool,
+    )
+    .await?;
+
+    Ok(SessionTokens {
+        access,
+        refresh_token,
+    })
+}
+
+/// Exchange a refresh token for a new access token and a new refresh token.
+///
+/// Admin status and role grants are re-read, so changes apply from the next
+/// refresh. Presenting a refresh token that was already rotated away means
+/// it leaked; the whole session is revoked.
+pub async fn refresh_session(refresh_token: &str, deps: &ServerDeps) -> Result<SessionRefreshed> {
+    let pool = &deps.db_pool;
+    let hash = AuthSession::hash_refresh_token(refresh_token);
+
+    let Some(session) = AuthSession::find_by_refresh_hash(&hash, pool).await? else {
+        if let Some(session) = AuthSession::find_by_previous_refresh_hash(&hash, pool).await? {
+            warn!(session_id = %session.id, member_id = %session.member_id, "Refresh token reused; revoking session");
+            revoke_session(&session, "refresh_token_reuse", deps).await?;
+        }
+        return Err(InvalidRefreshTokenError.into());
+    };
+    if !session.is_active(Utc::now()) {
+        return Err(InvalidRefreshTokenError.into());
+    }
+
+    let is_admin = Identifier::is_admin_member(session.member_id, pool).await?;
+    let grants = if is_admin {
+        Vec::new()
+    } else {
+        resolve_grants(session.member_id, pool).await?
+    };
+
+    let access = deps.jwt_service.create_token(
+        session.member_id,
+        session.identifier_hint.clone(),
+        is_admin,
+        grants.clone(),
+        Some(session.id),
+    )?;
+    let new_refresh_token = generate_refresh_token();
+
+    let rotated = AuthSession::rotate(
+        session.id,
+        &hash,
+        SessionRotation {
+            refresh_token_hash: AuthSession::hash_refresh_token(&new_refresh_token),
+            access_jti: access.jti.clone(),
+            access_expires_at: access.expires_at,
+        },
+        pool,
+    )
+    .await?;
+    if rotated.is_none() {
+        // Lost a race with another refresh (or a revocation) of this token.
+        return Err(InvalidRefreshTokenError.into());
+    }
+
+    Ok(SessionRefreshed {
+        member_id: session.member_id,
+        is_admin,
+        grants,
+        token: access.token,
+        expires_at: access.expires_at,
+        refresh_token: new_refresh_token,
+    })
+}
+
+/// End the caller's session: revoke the access token presented and, if it
+/// belongs to a session, the session's refresh token.
+pub async fn logout(
+    member_id: Uuid,
+    jti: &str,
+    token_expires_at: DateTime<Utc>,
+    session_id: Option<Uuid>,
+    deps: &ServerDeps,
+) -> Result<()> {
+    revoke_jti(jti, member_id, token_expires_at, "logout", deps).await?;
+
+    if let Some(session_id) = session_id {
+        if let Some(session) = AuthSession::find_by_id(session_id, &deps.db_pool).await? {
+            if session.member_id == member_id {
+                revoke_session(&session, "logout", deps).await?;
+            }
+        }
+    }
+
+    info!(member_id = %member_id, "Logged out");
+    Ok(())
+}
+
+/// Revoke every session a member holds. Access tokens carry their session
+/// id, so every token issued for those sessions is rejected from then on.
+/// Returns the number of sessions revoked.
+pub async fn revoke_member_sessions(
+    member_id: Uuid,
+    reason: &str,
+    deps: &ServerDeps,
+) -> Result<u64> {
+    let sessions = AuthSession::revoke_all_for_member(member_id, reason, &deps.db_pool).await?;
+    for session in &sessions {
+        deps.token_revocations
+            .mark_session_revoked(session.id)
+            .await;
+    }
+    info!(member_id = %member_id, sessions = sessions.len(), reason, "Revoked member sessions");
+    Ok(sessions.len() as u64)
+}
+
+async fn revoke_session(session: &AuthSession, reason: &str, deps: &ServerDeps) -> Result<()> {
+    if let Some(revoked) = AuthSession::revoke(session.id, reason, &deps.db_pool).await? {
+        deps.token_revocations
+            .mark_session_revoked(revoked.id)
+            .await;
+    }
+    Ok(())
+}
+
+async fn revoke_jti(
+    jti: &str,
+    member_id: Uuid,
+    expires_at: DateTime<Utc>,
+    reason: &str,
+    deps: &ServerDeps,
+) -> Result<()> {
+    RevokedToken::revoke(jti, member_id, expires_at, reason, &deps.db_pool).await?;
+    deps.token_revocations.mark_revoked(jti, expires_at).await;
+    Ok(())
+}
+
+/// 32 random bytes, base64-url encoded (43 chars).
+fn generate_refresh_token() -> String {
+    let mut bytes = [0u8; 32];
+    rand::rngs::OsRng.fill_bytes(&mut bytes);
+    base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// Masked form of the login identifier, carried in tokens for logging:
+/// `e***@example.com`, or the last four digits of a phone number.
+fn identifier_hint(identifier: &str) -> String {
+    if identifier.contains('@') {
+        return mask_value(identifier, &PiiType::Email);
+    }
+    let digits: Vec<char> = identifier.chars().filter(|c| c.is_ascii_digit()).collect();
+    let last_four: String = digits[digits.len().saturating_sub(4)..].iter().collect();
+    format!("***{}", last_four)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn refresh_tokens_are_unique_and_url_safe() {
+        let a = generate_refresh_token();
+        let b = generate_refresh_token();
+        assert_ne!(a, b);
+        assert_eq!(a.len(), 43);
+        assert!(a
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+    }
+
+    #[test]
+    fn identifier_hint_never_contains_the_raw_identifier() {
+        assert_eq!(identifier_hint("+16125550100"), "***0100");
+        assert!(!identifier_hint("editor@example.com").contains("editor"));
+    }
+}
@@ -1,12 +1,15 @@
 //! Verify OTP action
 
 use anyhow::Result;
+use chrono::Utc;
 use tracing::{error, info};
 use uuid::Uuid;
 
 use crate::domains::auth::models::{
-    hash_phone_number, is_admin_identifier, is_test_identifier, Identifier,
+    is_admin_identifier, is_test_identifier, Identifier, NormalizedIdentifier,
 };
+use crate::domains::auth::activities::otp_limits::{self, OtpRequester};
+use crate::domains::auth::activities::{resolve_grants, start_session};
 use crate::domains::auth::types::OtpVerified;
 use crate::domains::member::models::Member;
 use crate::kernel::ServerDeps;
@@ -20,67 +23,99 @@ pub struct VerificationFailedError {
 
 /// Verify OTP code, create member if needed, and return OtpVerified.
 ///
-/// Test identifiers skip Twilio verification.
+/// Accepts a phone number or email, normalized the same way as `send_otp`.
+/// Test identifiers skip Twilio verification and the rate limits.
 /// Creates member + identifier on first successful verification.
 ///
+/// Attempts are budgeted like sends, and repeated wrong codes lock the
+/// identifier (and the client IP) out; both fail with `RateLimitedError`.
+/// Twilio transport errors fail the attempt without counting as a wrong code.
+///
 /// Returns OtpVerified with token on success.
 pub async fn verify_otp(
-    phone_number: String,
+    identifier: String,
     code: String,
+    client_ip: Option<String>,
     deps: &ServerDeps,
 ) -> Result<OtpVerified> {
-    let is_test = deps.test_identifier_enabled && is_test_identifier(&phone_number);
+    let identifier = NormalizedIdentifier::parse(&identifier)?;
+    let value = &identifier.value;
+    let is_test = deps.test_identifier_enabled && is_test_identifier(value);
 
     // Verify with Twilio (unless test identifier)
     if !is_test {
-        if let Err(e) = deps.twilio.verify_otp(&phone_number, &code).await {
-            error!("OTP verification failed: {}", e);
-            return Err(VerificationFailedError {
-                reason: e.to_string(),
+        let limiter = deps.rate_limiter.as_ref();
+        let requester = OtpRequester::new(&identifier, client_ip);
+        otp_limits::check_verify(&requester, limiter, Utc::now()).await?;
+
+        // Only a definite rejection counts toward a lockout; an outage at
+        // Twilio says nothing about the code.
+        match deps.twilio.verify_otp(value, &code).await {
+            Ok(true) => {}
+            Ok(false) => {
+                otp_limits::record_verify_failure(&requester, limiter, Utc::now()).await?;
+                return Err(VerificationFailedError {
+                    reason: "Error verifying OTP".to_string(),
+                }
+                .into());
+            }
+            Err(e) => {
+                error!("OTP verification failed: {}", e);
+                return Err(VerificationFailedError {
+                    reason: e.to_string(),
+                }
+                .into());
             }
-            .into());
         }
+        otp_limits::record_verify_success(&requester, limiter).await?;
     } else {
-        info!(
-            "Test identifier: skipping Twilio verification for {}",
-            phone_number
-        );
+        info!("Test identifier: skipping Twilio verification for {}", value);
     }
 
     // Find or create member
-    let phone_hash = hash_phone_number(&phone_number);
     let (member_id, is_admin) =
-        match Identifier::find_by_phone_hash(&phone_hash, &deps.db_pool).await? {
-            Some(identifier) => (identifier.member_id, identifier.is_admin),
+        match Identifier::find_by_identifier(&identifier, &deps.db_pool).await? {
+            Some(found) => (found.member_id, found.is_admin),
             None => {
-                let is_admin = is_admin_identifier(&phone_number, &deps.admin_identifiers);
-                let member_id = create_member(&phone_number, &deps.db_pool).await?;
-                Identifier::create(member_id, phone_hash, is_admin, &deps.db_pool).await?;
-                info!("Created new member {} for {}", member_id, phone_number);
+                let is_admin = is_admin_identifier(value, &deps.admin_identifiers);
+                let member_id = create_member(&identifier, &deps.db_pool).await?;
+                Identifier::create(member_id, &identifier, is_admin, &deps.db_pool).await?;
+                info!("Created new member {} for {}", member_id, value);
                 (member_id, is_admin)
             }
         };
 
     info!("OTP verified for member {}", member_id);
 
-    // Create JWT token
-    let token = deps
-        .jwt_service
-        .create_token(member_id, phone_number.clone(), is_admin)?;
+    // Full admins bypass grants; everyone else carries their role grants.
+    let grants = if is_admin {
+        Vec::new()
+    } else {
+        resolve_grants(member_id, &deps.db_pool).await?
+    };
+
+    // Open a session: short-lived access token plus a refresh token
+    let session = start_session(member_id, value, is_admin, grants.clone(), deps).await?;
 
     Ok(OtpVerified {
         member_id,
-        phone_number,
+        phone_number: value.clone(),
         is_admin,
-        token,
+        grants,
+        token: session.access.token,
+        expires_at: session.access.expires_at,
+        refresh_token: session.refresh_token,
     })
 }
 
 /// Create a new member for the given identifier.
-async fn create_member(phone_number: &str, pool: &sqlx::PgPool) -> Result<Uuid> {
+///
+/// The placeholder push token carries the identifier hash, not the raw
+/// phone number or email.
+async fn create_member(identifier: &NormalizedIdentifier, pool: &sqlx::PgPool) -> Result<Uuid> {
     let member = Member {
         id: Uuid::new_v4(),
-        expo_push_token: format!("pending:{}", phone_number),
+        expo_push_token: format!("pending:{}", identifier.hash()),
         searchable_text: String::new(),
         latitude: None,
         longitude: None,
@@ -88,7 +123,7 @@ async fn create_member(phone_number: &str, pool: &sqlx::PgPool) -> Result<Uuid>
         active: true,
         notification_count_this_week: 0,
         paused_until: None,
-        created_at: chrono::Utc::now(),
+        created_at: Utc::now(),
     };
 
     let member = member.insert(pool).await?;
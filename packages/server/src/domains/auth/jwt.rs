@@ -1,19 +1,41 @@
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::common::auth::CapabilityGrant;
+
 /// JWT Claims - data stored in the token
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Claims {
     pub sub: String,          // Subject (member_id as string)
     pub member_id: Uuid,      // Member UUID
-    pub phone_number: String, // Phone number (for logging/debugging)
+    pub phone_number: String, // Masked login identifier (for logging/debugging)
     pub is_admin: bool,       // Admin flag
     pub exp: i64,             // Expiration timestamp
     pub iat: i64,             // Issued at timestamp
     pub iss: String,          // Issuer
     pub jti: String,          // JWT ID (unique token identifier)
+    /// Role grants resolved at login (empty for full admins and members
+    /// without roles; absent in tokens issued before roles existed)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub grants: Vec<CapabilityGrant>,
+    /// Session the token was minted for (absent in tokens issued before
+    /// sessions existed)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sid: Option<Uuid>,
+}
+
+/// Lifetime of an access token. Sessions outlive it through refresh tokens.
+pub const ACCESS_TOKEN_TTL_MINUTES: i64 = 15;
+
+/// A freshly minted access token with the identifiers needed to revoke it.
+#[derive(Debug, Clone)]
+pub struct IssuedToken {
+    pub token: String,
+    pub jti: String,
+    pub expires_at: DateTime<Utc>,
 }
 
 /// JWT Service - creates and verifies JWT tokens
@@ -34,17 +56,20 @@ impl JwtService {
         }
     }
 
-    /// Create a new JWT token for a member
+    /// Create a new access token for a member
     ///
-    /// Token expires after 24 hours
+    /// Token expires after `ACCESS_TOKEN_TTL_MINUTES`
     pub fn create_token(
         &self,
         member_id: Uuid,
         phone_number: String,
         is_admin: bool,
-    ) -> Result<String> {
+        grants: Vec<CapabilityGrant>,
+        session_id: Option<Uuid>,
+    ) -> Result<IssuedToken> {
         let now = chrono::Utc::now();
-        let exp = now + chrono::Duration::hours(24);
+        let exp = now + chrono::Duration::minutes(ACCESS_TOKEN_TTL_MINUTES);
+        let jti = Uuid::new_v4().to_string(); // Unique token ID
 
         let claims = Claims {
             sub: member_id.to_string(),
@@ -54,10 +79,17 @@ impl JwtService {
             exp: exp.timestamp(),
             iat: now.timestamp(),
             iss: self.issuer.clone(),
-            jti: Uuid::new_v4().to_string(), // Unique token ID
+            jti: jti.clone(),
+            grants,
+            sid: session_id,
         };
 
-        encode(&Header::default(), &claims, &self.encoding_key).map_err(Into::into)
+        let token = encode(&Header::default(), &claims, &self.encoding_key)?;
+        Ok(IssuedToken {
+            token,
+            jti,
+            expires_at: exp,
+        })
     }
 
     /// Verify and decode a JWT token
@@ -83,8 +115,9 @@ mod tests {
         let member_id = Uuid::new_v4();
 
         let token = service
-            .create_token(member_id, "+1234567890".to_string(), true)
-            .unwrap();
+            .create_token(member_id, "+1234567890".to_string(), true, vec![], None)
+            .unwrap()
+            .token;
 
         let claims = service.verify_token(&token).unwrap();
         assert_eq!(claims.member_id, member_id);
@@ -93,6 +126,54 @@ mod tests {
         assert_eq!(claims.iss, "test_issuer");
     }
 
+    #[test]
+    fn test_grants_round_trip() {
+        use crate::common::auth::AdminCapability;
+
+        let service = JwtService::new("test_secret_key", "test_issuer".to_string());
+        let county_id = Uuid::new_v4();
+        let grants = vec![CapabilityGrant {
+            capability: AdminCapability::PublishEditions,
+            county_ids: Some(vec![county_id]),
+        }];
+
+        let token = service
+            .create_token(
+                Uuid::new_v4(),
+                "+1234567890".to_string(),
+                false,
+                grants.clone(),
+                None,
+            )
+            .unwrap()
+            .token;
+
+        let claims = service.verify_token(&token).unwrap();
+        assert_eq!(claims.grants, grants);
+    }
+
+    #[test]
+    fn test_session_id_and_short_expiry() {
+        let service = JwtService::new("test_secret_key", "test_issuer".to_string());
+        let session_id = Uuid::new_v4();
+
+        let issued = service
+            .create_token(
+                Uuid::new_v4(),
+                "+1234567890".to_string(),
+                false,
+                vec![],
+                Some(session_id),
+            )
+            .unwrap();
+
+        let claims = service.verify_token(&issued.token).unwrap();
+        assert_eq!(claims.sid, Some(session_id));
+        assert_eq!(claims.jti, issued.jti);
+        assert_eq!(claims.exp, issued.expires_at.timestamp());
+        assert_eq!(claims.exp - claims.iat, ACCESS_TOKEN_TTL_MINUTES * 60);
+    }
+
     #[test]
     fn test_invalid_token() {
         let service = JwtService::new("test_secret_key", "test_issuer".to_string());
@@ -107,8 +188,9 @@ mod tests {
 
         let member_id = Uuid::new_v4();
         let token = service1
-            .create_token(member_id, "+1234567890".to_string(), false)
-            .unwrap();
+            .create_token(member_id, "+1234567890".to_string(), false, vec![], None)
+            .unwrap()
+            .token;
 
         // Token created with secret1 should not verify with secret2
         let result = service2.verify_token(&token);
@@ -123,15 +205,16 @@ mod tests {
         let member_id = Uuid::new_v4();
 
         let token = service
-            .create_token(member_id, "+1234567890".to_string(), false)
-            .unwrap();
+            .create_token(member_id, "+1234567890".to_string(), false, vec![], None)
+            .unwrap()
+            .token;
 
         let claims = service.verify_token(&token).unwrap();
 
-        // Token should expire in ~24 hours
+        // Token should expire in ~15 minutes
         let now = chrono::Utc::now().timestamp();
         let expires_in = claims.exp - now;
-        assert!(expires_in > 23 * 3600); // At least 23 hours
-        assert!(expires_in <= 24 * 3600); // At most 24 hours
+        assert!(expires_in > (ACCESS_TOKEN_TTL_MINUTES - 1) * 60); // At least 14 minutes
+        assert!(expires_in <= ACCESS_TOKEN_TTL_MINUTES * 60); // At most 15 minutes
     }
 }
@@ -5,13 +5,16 @@
 //!
 //! Responsibilities:
 //! - Phone-based OTP authentication via Twilio
-//! - Session/JWT token management
+//! - Session/JWT token management (short-lived access tokens, rotating
+//!   refresh tokens, jti revocation)
 //! - Phone number hashing for privacy
 
 pub mod activities;
 pub mod jwt;
 pub mod models;
+pub mod revocation;
 pub mod types;
 
-pub use jwt::{Claims, JwtService};
+pub use jwt::{Claims, IssuedToken, JwtService};
+pub use revocation::TokenRevocationCache;
 pub use types::{OtpSent, OtpVerified};
@@ -0,0 +1,45 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// An exhausted rate-limit budget or a lockout on the auth endpoints.
+/// Written by `PostgresRateLimiter`; read here for the admin view.
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct AuthSecurityEvent {
+    pub id: Uuid,
+    pub event_type: String,
+    /// Limited key, e.g. `otp_verify:id:<sha256>` or `otp_send:global:3600`.
+    pub subject: String,
+    pub ip_address: Option<String>,
+    pub detail: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+}
+
+// =============================================================================
+// SQL Queries - ALL queries must be in models/
+// =============================================================================
+
+impl AuthSecurityEvent {
+    /// Most recent events first, optionally of one type.
+    pub async fn list_recent(
+        event_type: Option<&str>,
+        limit: i64,
+        pool: &PgPool,
+    ) -> Result<Vec<Self>> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            SELECT * FROM auth_security_events
+            WHERE ($1::text IS NULL OR event_type = $1)
+            ORDER BY created_at DESC
+            LIMIT $2
+            "#,
+        )
+        .bind(event_type)
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+        .map_err(Into::into)
+    }
+}
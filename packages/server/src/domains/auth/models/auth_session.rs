@@ -0,0 +1,199 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// A login session. Owns one rotating refresh token (stored hashed) and
+/// tracks the jti of the access token most recently minted for it. Access
+/// tokens carry the session id as `sid`, so revoking the session rejects
+/// every one of them.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct AuthSession {
+    pub id: Uuid,
+    pub member_id: Uuid,
+    pub identifier_hint: String,
+    pub refresh_token_hash: String,
+    pub previous_refresh_token_hash: Option<String>,
+    pub access_jti: String,
+    pub access_expires_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub last_refreshed_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub revoked_reason: Option<String>,
+}
+
+/// Insert payload for [`AuthSession::create`].
+#[derive(Debug, Clone)]
+pub struct NewAuthSession {
+    pub id: Uuid,
+    pub member_id: Uuid,
+    pub identifier_hint: String,
+    pub refresh_token_hash: String,
+    pub access_jti: String,
+    pub access_expires_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Rotation payload for [`AuthSession::rotate`].
+#[derive(Debug, Clone)]
+pub struct SessionRotation {
+    pub refresh_token_hash: String,
+    pub access_jti: String,
+    pub access_expires_at: DateTime<Utc>,
+}
+
+impl AuthSession {
+    /// SHA-256 hex digest of a refresh token. Only the digest is stored.
+    pub fn hash_refresh_token(token: &str) -> String {
+        let mut h = Sha256::new();
+        h.update(token.as_bytes());
+        format!("{:x}", h.finalize())
+    }
+
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.revoked_at.is_none() && self.expires_at > now
+    }
+}
+
+// =============================================================================
+// SQL Queries - ALL queries must be in models/
+// =============================================================================
+
+impl AuthSession {
+    pub async fn create(new: NewAuthSession, pool: &PgPool) -> Result<Self> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            INSERT INTO auth_sessions
+                (id, member_id, identifier_hint, refresh_token_hash,
+                 access_jti, access_expires_at, expires_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING *
+            "#,
+        )
+        .bind(new.id)
+        .bind(new.member_id)
+        .bind(&new.identifier_hint)
+        .bind(&new.refresh_token_hash)
+        .bind(&new.access_jti)
+        .bind(new.access_expires_at)
+        .bind(new.expires_at)
+        .fetch_one(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    pub async fn find_by_id(id: Uuid, pool: &PgPool) -> Result<Option<Self>> {
+        sqlx::query_as::<_, Self>("SELECT * FROM auth_sessions WHERE id = $1")
+            .bind(id)
+            .fetch_optional(pool)
+            .await
+            .map_err(Into::into)
+    }
+
+    pub async fn find_by_refresh_hash(hash: &str, pool: &PgPool) -> Result<Option<Self>> {
+        sqlx::query_as::<_, Self>("SELECT * FROM auth_sessions WHERE refresh_token_hash = $1")
+            .bind(hash)
+            .fetch_optional(pool)
+            .await
+            .map_err(Into::into)
+    }
+
+    /// The session whose *previous* refresh token this is — i.e. a refresh
+    /// token that was already rotated away and is being replayed.
+    pub async fn find_by_previous_refresh_hash(hash: &str, pool: &PgPool) -> Result<Option<Self>> {
+        sqlx::query_as::<_, Self>(
+            "SELECT * FROM auth_sessions WHERE previous_refresh_token_hash = $1",
+        )
+        .bind(hash)
+        .fetch_optional(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Swap in a new refresh token and access jti. Guarded on the expected
+    /// current hash so two concurrent refreshes with the same token can't
+    /// both succeed; returns `None` if the session moved on or was revoked.
+    pub async fn rotate(
+        id: Uuid,
+        expected_refresh_hash: &str,
+        rotation: SessionRotation,
+        pool: &PgPool,
+    ) -> Result<Option<Self>> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            UPDATE auth_sessions
+            SET previous_refresh_token_hash = refresh_token_hash,
+                refresh_token_hash = $3,
+                access_jti = $4,
+                access_expires_at = $5,
+                last_refreshed_at = NOW()
+            WHERE id = $1
+              AND refresh_token_hash = $2
+              AND revoked_at IS NULL
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(expected_refresh_hash)
+        .bind(&rotation.refresh_token_hash)
+        .bind(&rotation.access_jti)
+        .bind(rotation.access_expires_at)
+        .fetch_optional(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Revoke one session. Returns it if it was still active.
+    pub async fn revoke(id: Uuid, reason: &str, pool: &PgPool) -> Result<Option<Self>> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            UPDATE auth_sessions
+            SET revoked_at = NOW(), revoked_reason = $2
+            WHERE id = $1 AND revoked_at IS NULL
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(reason)
+        .fetch_optional(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Revoke every active session for a member. Returns the sessions revoked.
+    pub async fn revoke_all_for_member(
+        member_id: Uuid,
+        reason: &str,
+        pool: &PgPool,
+    ) -> Result<Vec<Self>> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            UPDATE auth_sessions
+            SET revoked_at = NOW(), revoked_reason = $2
+            WHERE member_id = $1 AND revoked_at IS NULL
+            RETURNING *
+            "#,
+        )
+        .bind(member_id)
+        .bind(reason)
+        .fetch_all(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Delete sessions that expired or were revoked more than `days` ago.
+    pub async fn prune_older_than_days(days: i32, pool: &PgPool) -> Result<u64> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM auth_sessions
+            WHERE COALESCE(revoked_at, expires_at) < NOW() - make_interval(days => $1)
+            "#,
+        )
+        .bind(days)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
@@ -14,6 +14,8 @@ pub struct Identifier {
     pub id: Uuid,
     pub member_id: Uuid,
     pub phone_hash: String, // Actually stores hash of phone number OR email
+    /// `phone` or `email` (see [`IdentifierKind`])
+    pub kind: String,
     pub is_admin: bool,
     pub created_at: DateTime<Utc>,
 }
@@ -44,40 +46,202 @@ impl Identifier {
         Ok(exists)
     }
 
+    /// Find the row for a normalized identifier.
+    ///
+    /// Identifiers used to be hashed as typed: phone numbers without
+    /// normalizing to E.164, emails also without a kind prefix. A row stored
+    /// that way is found by its legacy hash and rewritten to the current hash
+    /// on the way out.
+    pub async fn find_by_identifier(
+        identifier: &NormalizedIdentifier,
+        pool: &PgPool,
+    ) -> Result<Option<Self>> {
+        if let Some(found) = Self::find_by_phone_hash(&identifier.hash(), pool).await? {
+            return Ok(Some(found));
+        }
+
+        for legacy_hash in identifier.legacy_hashes() {
+            if Self::find_by_phone_hash(&legacy_hash, pool).await?.is_none() {
+                continue;
+            }
+            let migrated = sqlx::query_as::<_, Identifier>(
+                r#"
+                UPDATE identifiers SET phone_hash = $2, kind = $3
+                WHERE phone_hash = $1
+                RETURNING *
+                "#,
+            )
+            .bind(&legacy_hash)
+            .bind(identifier.hash())
+            .bind(identifier.kind.as_str())
+            .fetch_optional(pool)
+            .await?;
+            return Ok(migrated);
+        }
+        Ok(None)
+    }
+
     /// Create identifier for a member
     pub async fn create(
         member_id: Uuid,
-        phone_hash: String,
+        identifier: &NormalizedIdentifier,
         is_admin: bool,
         pool: &PgPool,
     ) -> Result<Self> {
         let identifier = sqlx::query_as::<_, Identifier>(
             r#"
-            INSERT INTO identifiers (member_id, phone_hash, is_admin)
-            VALUES ($1, $2, $3)
+            INSERT INTO identifiers (member_id, phone_hash, kind, is_admin)
+            VALUES ($1, $2, $3, $4)
             RETURNING *
             "#,
         )
         .bind(member_id)
-        .bind(phone_hash)
+        .bind(identifier.hash())
+        .bind(identifier.kind.as_str())
         .bind(is_admin)
         .fetch_one(pool)
         .await?;
         Ok(identifier)
     }
+
+    /// Whether any of the member's identifiers is an admin. Re-read on
+    /// token refresh so a demotion takes effect without a new login.
+    pub async fn is_admin_member(member_id: Uuid, pool: &PgPool) -> Result<bool> {
+        let is_admin = sqlx::query_scalar::<_, bool>(
+            "SELECT COALESCE(bool_or(is_admin), false) FROM identifiers WHERE member_id = $1",
+        )
+        .bind(member_id)
+        .fetch_one(pool)
+        .await?;
+        Ok(is_admin)
+    }
 }
 
 // =============================================================================
 // Utility Functions
 // =============================================================================
 
+/// Error returned when a login identifier is neither a phone number nor an
+/// email address
+#[derive(Debug, thiserror::Error)]
+#[error("Enter a phone number (e.g. +16125550100) or an email address")]
+pub struct InvalidIdentifierError;
+
+/// What kind of login identifier a member uses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IdentifierKind {
+    Phone,
+    Email,
+}
+
+impl IdentifierKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            IdentifierKind::Phone => "phone",
+            IdentifierKind::Email => "email",
+        }
+    }
+}
+
+/// A login identifier in canonical form: E.164 for phone numbers,
+/// trimmed and lowercased for emails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NormalizedIdentifier {
+    pub kind: IdentifierKind,
+    pub value: String,
+    /// The input as given (trimmed), for matching legacy hashes
+    raw: String,
+}
+
+impl NormalizedIdentifier {
+    /// Parse a phone number or email address.
+    ///
+    /// Phone numbers may contain spaces, dashes, dots and parentheses.
+    /// Ten-digit numbers without a country code are taken as US numbers.
+    pub fn parse(input: &str) -> std::result::Result<Self, InvalidIdentifierError> {
+        let raw = input.trim();
+        let (kind, value) = if raw.contains('@') {
+            (IdentifierKind::Email, normalize_email(raw)?)
+        } else {
+            (IdentifierKind::Phone, normalize_phone(raw)?)
+        };
+        Ok(Self {
+            kind,
+            value,
+            raw: raw.to_string(),
+        })
+    }
+
+    /// Lookup hash for the `identifiers` table.
+    ///
+    /// Phone numbers hash as before (SHA-256 of the E.164 string), so
+    /// existing rows still match. Emails hash with an `email:` prefix.
+    pub fn hash(&self) -> String {
+        match self.kind {
+            IdentifierKind::Phone => hash_phone_number(&self.value),
+            IdentifierKind::Email => hash_phone_number(&format!("email:{}", self.value)),
+        }
+    }
+
+    /// Hashes older code may have stored for this identifier
+    fn legacy_hashes(&self) -> Vec<String> {
+        let mut hashes = Vec::new();
+        if self.raw != self.value {
+            hashes.push(hash_phone_number(&self.raw));
+        }
+        if self.kind == IdentifierKind::Email {
+            hashes.push(hash_phone_number(&self.value));
+        }
+        hashes
+    }
+}
+
+fn normalize_email(raw: &str) -> std::result::Result<String, InvalidIdentifierError> {
+    let email = raw.to_lowercase();
+    let (local, domain) = email.split_once('@').ok_or(InvalidIdentifierError)?;
+    let valid = !local.is_empty()
+        && !domain.contains('@')
+        && domain.contains('.')
+        && !domain.starts_with('.')
+        && !domain.ends_with('.')
+        && !email.chars().any(char::is_whitespace);
+    if valid {
+        Ok(email)
+    } else {
+        Err(InvalidIdentifierError)
+    }
+}
+
+fn normalize_phone(raw: &str) -> std::result::Result<String, InvalidIdentifierError> {
+    let compact: String = raw
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '.' | '(' | ')'))
+        .collect();
+    let (has_plus, digits) = match compact.strip_prefix('+') {
+        Some(rest) => (true, rest),
+        None => (false, compact.as_str()),
+    };
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(InvalidIdentifierError);
+    }
+
+    let e164 = match (has_plus, digits.len()) {
+        (true, 8..=15) => format!("+{}", digits),
+        (false, 10) => format!("+1{}", digits),
+        (false, 11) if digits.starts_with('1') => format!("+{}", digits),
+        _ => return Err(InvalidIdentifierError),
+    };
+    Ok(e164)
+}
+
 /// Hash an identifier (phone number or email) using SHA256
 ///
 /// Identifiers are hashed for privacy - we never store raw identifiers.
 /// The hash is used as a lookup key in the identifiers table.
 ///
-/// Note: Function named `hash_phone_number` for backward compatibility,
-/// but it works for any string identifier (phone or email).
+/// Prefer [`NormalizedIdentifier::hash`], which normalizes first and keeps
+/// phone and email hashes apart.
 pub fn hash_phone_number(phone_number: &str) -> String {
     let mut hasher = Sha256::new();
     hasher.update(phone_number.as_bytes());
@@ -87,19 +251,15 @@ pub fn hash_phone_number(phone_number: &str) -> String {
 /// Check if an identifier (email or phone) should be granted admin privileges
 ///
 /// Returns true if the identifier is in the admin_identifiers list.
-/// Supports both emails and phone numbers.
-///
-/// - For emails: case-insensitive matching
-/// - For phone numbers: exact match (E.164 format)
+/// Supports both emails and phone numbers. Both sides are normalized, so
+/// emails match case-insensitively and phone numbers match regardless of
+/// formatting (`(612) 555-0100` matches `+16125550100`).
 pub fn is_admin_identifier(identifier: &str, admin_identifiers: &[String]) -> bool {
+    let Ok(identifier) = NormalizedIdentifier::parse(identifier) else {
+        return false;
+    };
     admin_identifiers.iter().any(|admin_id| {
-        // Case-insensitive match for emails
-        if identifier.contains('@') && admin_id.contains('@') {
-            admin_id.eq_ignore_ascii_case(identifier)
-        } else {
-            // Exact match for phone numbers
-            admin_id == identifier
-        }
+        NormalizedIdentifier::parse(admin_id).is_ok_and(|admin| admin.value == identifier.value)
     })
 }
 
@@ -184,6 +344,61 @@ mod tests {
         assert!(!is_admin_identifier("+9876543210", &admin_identifiers));
     }
 
+    #[test]
+    fn test_is_admin_identifier_normalizes_phone_formatting() {
+        let admin_identifiers = vec!["(612) 555-0100".to_string()];
+
+        assert!(is_admin_identifier("+16125550100", &admin_identifiers));
+        assert!(is_admin_identifier("612.555.0100", &admin_identifiers));
+        assert!(!is_admin_identifier("+16125550101", &admin_identifiers));
+    }
+
+    #[test]
+    fn test_normalize_phone_numbers() {
+        let parse = |s: &str| NormalizedIdentifier::parse(s).map(|n| n.value).ok();
+
+        assert_eq!(parse("+1 (612) 555-0100").as_deref(), Some("+16125550100"));
+        assert_eq!(parse("612-555-0100").as_deref(), Some("+16125550100"));
+        assert_eq!(parse("16125550100").as_deref(), Some("+16125550100"));
+        assert_eq!(parse("+44 20 7946 0958").as_deref(), Some("+442079460958"));
+        assert_eq!(parse("5550100"), None);
+        assert_eq!(parse("+1612555010x"), None);
+        assert_eq!(parse(""), None);
+    }
+
+    #[test]
+    fn test_normalize_emails() {
+        let editor = NormalizedIdentifier::parse("  Editor@Example.COM ").unwrap();
+        assert_eq!(editor.kind, IdentifierKind::Email);
+        assert_eq!(editor.value, "editor@example.com");
+
+        assert!(NormalizedIdentifier::parse("editor@example").is_err());
+        assert!(NormalizedIdentifier::parse("@example.com").is_err());
+        assert!(NormalizedIdentifier::parse("a@b@example.com").is_err());
+        assert!(NormalizedIdentifier::parse("edi tor@example.com").is_err());
+    }
+
+    #[test]
+    fn test_hashes_are_separated_by_kind() {
+        let phone = NormalizedIdentifier::parse("(612) 555-0100").unwrap();
+        assert_eq!(phone.hash(), hash_phone_number("+16125550100"));
+
+        let email = NormalizedIdentifier::parse("Editor@Example.com").unwrap();
+        let same_email = NormalizedIdentifier::parse("editor@example.com").unwrap();
+        assert_eq!(email.hash(), same_email.hash());
+        assert_ne!(email.hash(), hash_phone_number("editor@example.com"));
+        assert!(email
+            .legacy_hashes()
+            .contains(&hash_phone_number("Editor@Example.com")));
+
+        assert_eq!(
+            phone.legacy_hashes(),
+            vec![hash_phone_number("(612) 555-0100")]
+        );
+        let e164 = NormalizedIdentifier::parse("+16125550100").unwrap();
+        assert!(e164.legacy_hashes().is_empty());
+    }
+
     #[test]
     fn test_is_admin_identifier_mixed() {
         let admin_identifiers = vec!["admin@example.com".to_string(), "+1234567890".to_string()];
@@ -0,0 +1,94 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// A member holding a role, everywhere (`county_id = None`) or for one county.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct MemberRole {
+    pub id: Uuid,
+    pub member_id: Uuid,
+    pub role_id: Uuid,
+    pub county_id: Option<Uuid>,
+    pub granted_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// One (capability, county) pair a member holds through their roles.
+/// `county_id = None` means every county.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct MemberCapabilityRow {
+    pub capability: String,
+    pub county_id: Option<Uuid>,
+}
+
+// =============================================================================
+// SQL Queries - ALL queries must be in models/
+// =============================================================================
+
+impl MemberRole {
+    /// Grant a role. Idempotent per (member, role, county).
+    pub async fn assign(
+        member_id: Uuid,
+        role_id: Uuid,
+        county_id: Option<Uuid>,
+        granted_by: Option<Uuid>,
+        pool: &PgPool,
+    ) -> Result<Self> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            INSERT INTO member_roles (member_id, role_id, county_id, granted_by)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (member_id, role_id, COALESCE(county_id, '00000000-0000-0000-0000-000000000000'::uuid))
+            DO UPDATE SET granted_by = EXCLUDED.granted_by
+            RETURNING *
+            "#,
+        )
+        .bind(member_id)
+        .bind(role_id)
+        .bind(county_id)
+        .bind(granted_by)
+        .fetch_one(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    pub async fn delete(id: Uuid, pool: &PgPool) -> Result<bool> {
+        let result = sqlx::query("DELETE FROM member_roles WHERE id = $1")
+            .bind(id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn find_for_member(member_id: Uuid, pool: &PgPool) -> Result<Vec<Self>> {
+        sqlx::query_as::<_, Self>(
+            "SELECT * FROM member_roles WHERE member_id = $1 ORDER BY created_at",
+        )
+        .bind(member_id)
+        .fetch_all(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Every capability the member holds, with the county each is held for.
+    pub async fn capabilities_for_member(
+        member_id: Uuid,
+        pool: &PgPool,
+    ) -> Result<Vec<MemberCapabilityRow>> {
+        sqlx::query_as::<_, MemberCapabilityRow>(
+            r#"
+            SELECT DISTINCT rc.capability, mr.county_id
+            FROM member_roles mr
+            JOIN role_capabilities rc ON rc.role_id = mr.role_id
+            WHERE mr.member_id = $1
+            ORDER BY rc.capability
+            "#,
+        )
+        .bind(member_id)
+        .fetch_all(pool)
+        .await
+        .map_err(Into::into)
+    }
+}
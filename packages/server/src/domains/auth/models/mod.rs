@@ -1,3 +1,13 @@
+pub mod auth_security_event;
+pub mod auth_session;
 pub mod identifier;
+pub mod member_role;
+pub mod revoked_token;
+pub mod role;
 
+pub use auth_security_event::AuthSecurityEvent;
+pub use auth_session::{AuthSession, NewAuthSession, SessionRotation};
 pub use identifier::*;
+pub use member_role::{MemberCapabilityRow, MemberRole};
+pub use revoked_token::RevokedToken;
+pub use role::Role;
@@ -0,0 +1,70 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// An access token (by `jti`) that must be rejected before it expires.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct RevokedToken {
+    pub jti: String,
+    pub member_id: Uuid,
+    pub expires_at: DateTime<Utc>,
+    pub reason: String,
+    pub revoked_at: DateTime<Utc>,
+}
+
+// =============================================================================
+// SQL Queries - ALL queries must be in models/
+// =============================================================================
+
+impl RevokedToken {
+    /// Add a jti to the revocation list. Idempotent.
+    pub async fn revoke(
+        jti: &str,
+        member_id: Uuid,
+        expires_at: DateTime<Utc>,
+        reason: &str,
+        pool: &PgPool,
+    ) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO revoked_tokens (jti, member_id, expires_at, reason)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (jti) DO NOTHING
+            "#,
+        )
+        .bind(jti)
+        .bind(member_id)
+        .bind(expires_at)
+        .bind(reason)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Whether `jti` is on the list, or `sid` names a revoked session.
+    pub async fn is_revoked(jti: &str, sid: Option<Uuid>, pool: &PgPool) -> Result<bool> {
+        sqlx::query_scalar::<_, bool>(
+            r#"
+            SELECT EXISTS(SELECT 1 FROM revoked_tokens WHERE jti = $1)
+                OR EXISTS(
+                    SELECT 1 FROM auth_sessions
+                    WHERE id = $2 AND revoked_at IS NOT NULL
+                )
+            "#,
+        )
+        .bind(jti)
+        .bind(sid)
+        .fetch_one(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Delete entries whose token has expired anyway.
+    pub async fn prune_expired(pool: &PgPool) -> Result<u64> {
+        let result = sqlx::query("DELETE FROM revoked_tokens WHERE expires_at < NOW()")
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+}
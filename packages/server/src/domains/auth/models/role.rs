@@ -0,0 +1,108 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// A named bundle of capabilities (`county_desk_editor`, `copy_editor`, …).
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Role {
+    pub id: Uuid,
+    pub slug: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// =============================================================================
+// SQL Queries - ALL queries must be in models/
+// =============================================================================
+
+impl Role {
+    pub async fn find_all(pool: &PgPool) -> Result<Vec<Self>> {
+        sqlx::query_as::<_, Self>("SELECT * FROM roles ORDER BY name")
+            .fetch_all(pool)
+            .await
+            .map_err(Into::into)
+    }
+
+    pub async fn find_by_id(id: Uuid, pool: &PgPool) -> Result<Option<Self>> {
+        sqlx::query_as::<_, Self>("SELECT * FROM roles WHERE id = $1")
+            .bind(id)
+            .fetch_optional(pool)
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Create a role, or update the name/description of the one with this slug.
+    pub async fn upsert(
+        slug: &str,
+        name: &str,
+        description: Option<&str>,
+        pool: &PgPool,
+    ) -> Result<Self> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            INSERT INTO roles (slug, name, description)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (slug) DO UPDATE
+            SET name = EXCLUDED.name,
+                description = EXCLUDED.description,
+                updated_at = NOW()
+            RETURNING *
+            "#,
+        )
+        .bind(slug)
+        .bind(name)
+        .bind(description)
+        .fetch_one(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    pub async fn delete(id: Uuid, pool: &PgPool) -> Result<()> {
+        sqlx::query("DELETE FROM roles WHERE id = $1")
+            .bind(id)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Capability strings granted by this role.
+    pub async fn capabilities(id: Uuid, pool: &PgPool) -> Result<Vec<String>> {
+        sqlx::query_scalar(
+            "SELECT capability FROM role_capabilities WHERE role_id = $1 ORDER BY capability",
+        )
+        .bind(id)
+        .fetch_all(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Replace the role's capability set.
+    pub async fn set_capabilities(id: Uuid, capabilities: &[String], pool: &PgPool) -> Result<()> {
+        let mut tx = pool.begin().await?;
+        sqlx::query("DELETE FROM role_capabilities WHERE role_id = $1")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query(
+            r#"
+            INSERT INTO role_capabilities (role_id, capability)
+            SELECT $1, UNNEST($2::text[])
+            ON CONFLICT DO NOTHING
+            "#,
+        )
+        .bind(id)
+        .bind(capabilities)
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query("UPDATE roles SET updated_at = NOW() WHERE id = $1")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+}
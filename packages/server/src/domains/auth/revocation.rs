@@ -0,0 +1,170 @@
+//! In-memory cache in front of the `revoked_tokens` table and revoked
+//! `auth_sessions`.
+//!
+//! Every authenticated request checks its token's `jti`, and its session
+//! (`sid`) when it has one: revoking a session rejects every access token
+//! issued for it, not just the latest. Revocations made by
+//! this process are cached immediately and permanently (until the token
+//! would have expired anyway). Tokens found *not* revoked are re-checked
+//! after [`NOT_REVOKED_TTL`], so a revocation made by another replica takes
+//! effect within that window.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+use crate::domains::auth::models::RevokedToken;
+
+/// How long a "not revoked" answer is trusted before asking Postgres again.
+pub const NOT_REVOKED_TTL: Duration = Duration::from_secs(30);
+
+/// Entry count above which stale entries are swept on insert.
+const SWEEP_THRESHOLD: usize = 10_000;
+
+#[derive(Debug, Clone, Copy)]
+enum CacheEntry {
+    /// Revoked; safe to forget once the token has expired.
+    Revoked { token_expires_at: DateTime<Utc> },
+    /// Not revoked as of `checked_at`.
+    NotRevoked { checked_at: Instant },
+}
+
+#[derive(Debug, Default)]
+pub struct TokenRevocationCache {
+    entries: RwLock<HashMap<String, CacheEntry>>,
+    /// Sessions revoked by this process, with when their last access token
+    /// expires at the latest.
+    revoked_sessions: RwLock<HashMap<Uuid, DateTime<Utc>>>,
+}
+
+impl TokenRevocationCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether `jti`, or the session `sid` it was issued for, has been
+    /// revoked, consulting Postgres on a cache miss.
+    pub async fn is_revoked(&self, jti: &str, sid: Option<Uuid>, pool: &PgPool) -> Result<bool> {
+        if let Some(sid) = sid {
+            if self.revoked_sessions.read().await.contains_key(&sid) {
+                return Ok(true);
+            }
+        }
+        if let Some(revoked) = self.cached(jti, Instant::now()).await {
+            return Ok(revoked);
+        }
+        let revoked = RevokedToken::is_revoked(jti, sid, pool).await?;
+        let entry = if revoked {
+            // Expiry isn't known here; keep it for the longest access-token
+            // lifetime, after which the JWT check rejects it regardless.
+            CacheEntry::Revoked {
+                token_expires_at: Utc::now()
+                    + chrono::Duration::minutes(super::jwt::ACCESS_TOKEN_TTL_MINUTES),
+            }
+        } else {
+            CacheEntry::NotRevoked {
+                checked_at: Instant::now(),
+            }
+        };
+        self.insert(jti, entry).await;
+        Ok(revoked)
+    }
+
+    /// Record a revocation made by this process.
+    pub async fn mark_revoked(&self, jti: &str, token_expires_at: DateTime<Utc>) {
+        self.insert(jti, CacheEntry::Revoked { token_expires_at })
+            .await;
+    }
+
+    /// Record a session revocation made by this process. Tokens issued for
+    /// it are rejected until the longest access-token lifetime has passed.
+    pub async fn mark_session_revoked(&self, sid: Uuid) {
+        let utc_now = Utc::now();
+        let mut sessions = self.revoked_sessions.write().await;
+        if sessions.len() >= SWEEP_THRESHOLD {
+            sessions.retain(|_, expires_at| *expires_at > utc_now);
+        }
+        sessions.insert(
+            sid,
+            utc_now + chrono::Duration::minutes(super::jwt::ACCESS_TOKEN_TTL_MINUTES),
+        );
+    }
+
+    async fn cached(&self, jti: &str, now: Instant) -> Option<bool> {
+        match self.entries.read().await.get(jti)? {
+            CacheEntry::Revoked { .. } => Some(true),
+            CacheEntry::NotRevoked { checked_at }
+                if now.duration_since(*checked_at) < NOT_REVOKED_TTL =>
+            {
+                Some(false)
+            }
+            CacheEntry::NotRevoked { .. } => None,
+        }
+    }
+
+    async fn insert(&self, jti: &str, entry: CacheEntry) {
+        let mut entries = self.entries.write().await;
+        if entries.len() >= SWEEP_THRESHOLD {
+            let now = Instant::now();
+            let utc_now = Utc::now();
+            entries.retain(|_, e| match e {
+                CacheEntry::Revoked { token_expires_at } => *token_expires_at > utc_now,
+                CacheEntry::NotRevoked { checked_at } => {
+                    now.duration_since(*checked_at) < NOT_REVOKED_TTL
+                }
+            });
+        }
+        entries.insert(jti.to_string(), entry);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn marked_revocations_are_served_from_cache() {
+        let cache = TokenRevocationCache::new();
+        assert_eq!(cache.cached("abc", Instant::now()).await, None);
+
+        cache
+            .mark_revoked("abc", Utc::now() + chrono::Duration::minutes(5))
+            .await;
+        assert_eq!(cache.cached("abc", Instant::now()).await, Some(true));
+        assert_eq!(cache.cached("other", Instant::now()).await, None);
+    }
+
+    #[tokio::test]
+    async fn revoked_sessions_reject_every_token_issued_for_them() {
+        // A lazy pool: a session hit must answer without querying.
+        let pool = PgPool::connect_lazy("postgres://localhost/unused").unwrap();
+        let cache = TokenRevocationCache::new();
+        let sid = Uuid::new_v4();
+        cache.mark_session_revoked(sid).await;
+
+        assert!(cache.is_revoked("first", Some(sid), &pool).await.unwrap());
+        assert!(cache.is_revoked("second", Some(sid), &pool).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn not_revoked_answers_expire() {
+        let cache = TokenRevocationCache::new();
+        let checked_at = Instant::now();
+        cache
+            .insert("abc", CacheEntry::NotRevoked { checked_at })
+            .await;
+
+        assert_eq!(cache.cached("abc", checked_at).await, Some(false));
+        assert_eq!(
+            cache
+                .cached("abc", checked_at + NOT_REVOKED_TTL + Duration::from_secs(1))
+                .await,
+            None
+        );
+    }
+}
@@ -2,9 +2,12 @@
 //!
 //! Simple, serializable types returned by auth activities.
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::common::auth::CapabilityGrant;
+
 /// Result of sending an OTP
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OtpSent {
@@ -18,5 +21,23 @@ pub struct OtpVerified {
     pub member_id: Uuid,
     pub phone_number: String,
     pub is_admin: bool,
+    /// Role grants carried in the token (empty for full admins)
+    pub grants: Vec<CapabilityGrant>,
+    /// Short-lived access token
+    pub token: String,
+    /// When `token` expires
+    pub expires_at: DateTime<Utc>,
+    /// Single-use token for `/Auth/refresh`; rotated on every refresh
+    pub refresh_token: String,
+}
+
+/// Result of refreshing a session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionRefreshed {
+    pub member_id: Uuid,
+    pub is_admin: bool,
+    pub grants: Vec<CapabilityGrant>,
     pub token: String,
+    pub expires_at: DateTime<Utc>,
+    pub refresh_token: String,
 }
@@ -1,6 +1,6 @@
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool};
 use uuid::Uuid;
 
 use crate::common::PostId;
@@ -90,7 +90,7 @@ impl Contact {
         contact_value: String,
         contact_label: Option<String>,
         display_order: Option<i32>,
-        pool: &PgPool,
+        db: impl PgExecutor<'_>,
     ) -> Result<Self> {
         sqlx::query_as::<_, Self>(
             "INSERT INTO contacts (contactable_type, contactable_id, contact_type, contact_value, contact_label, display_order)
@@ -103,7 +103,7 @@ impl Contact {
         .bind(contact_value)
         .bind(contact_label)
         .bind(display_order)
-        .fetch_one(pool)
+        .fetch_one(db)
         .await
         .map_err(Into::into)
     }
@@ -199,13 +199,13 @@ impl Contact {
     pub async fn delete_all_for_entity(
         contactable_type: &str,
         contactable_id: Uuid,
-        pool: &PgPool,
+        db: impl PgExecutor<'_>,
     ) -> Result<u64> {
         let result =
             sqlx::query("DELETE FROM contacts WHERE contactable_type = $1 AND contactable_id = $2")
                 .bind(contactable_type)
                 .bind(contactable_id)
-                .execute(pool)
+                .execute(db)
                 .await?;
 
         Ok(result.rows_affected())
@@ -246,8 +246,8 @@ impl Contact {
     }
 
     /// Delete all contacts for a post
-    pub async fn delete_all_for_post(post_id: PostId, pool: &PgPool) -> Result<u64> {
-        Self::delete_all_for_entity("post", post_id.into_uuid(), pool).await
+    pub async fn delete_all_for_post(post_id: PostId, db: impl PgExecutor<'_>) -> Result<u64> {
+        Self::delete_all_for_entity("post", post_id.into_uuid(), db).await
     }
 
     /// Delete a single contact by its ID
@@ -0,0 +1,352 @@
+//! Public broadsheet assembly and the publish-time snapshot that freezes it.
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::domains::contacts::models::contact::Contact;
+use crate::domains::editions::data::broadsheet::*;
+use crate::domains::editions::models::county::County;
+use crate::domains::editions::models::edition::Edition;
+use crate::domains::editions::models::edition_row::EditionRow;
+use crate::domains::editions::models::edition_section::EditionSection;
+use crate::domains::editions::models::edition_slot::EditionSlot;
+use crate::domains::editions::models::edition_snapshot::EditionSnapshot;
+use crate::domains::editions::models::row_template_config::RowTemplateConfig;
+use crate::domains::posts::activities::tags::load_tags_and_notes;
+use crate::domains::posts::models::post::Post;
+use crate::domains::posts::models::{
+    PostDatetimeRecord, PostItem, PostLinkRecord, PostMediaRecord, PostMetaRecord,
+    PostPersonRecord, PostScheduleEntry, PostSourceAttr, PostStatusRecord,
+};
+use crate::domains::widgets::Widget;
+
+/// Freeze the public broadsheet of an edition being published, on the
+/// publishing connection so the status change and its snapshot land
+/// together. A re-publish with unchanged content keeps the existing
+/// snapshot.
+pub async fn freeze_snapshot(
+    edition: &Edition,
+    conn: &mut PgConnection,
+    pool: &PgPool,
+) -> Result<EditionSnapshot> {
+    let county = County::find_by_id(edition.county_id, pool)
+        .await?
+        .ok_or_else(|| anyhow!("County not found: {}", edition.county_id))?;
+    let broadsheet = build_public_broadsheet(edition, &county, pool).await?;
+    EditionSnapshot::freeze(edition, &serde_json::to_value(&broadsheet)?, conn).await
+}
+
+/// Build an edition's public broadsheet (used by the public and preview
+/// endpoints and by publish-time snapshots).
+pub async fn build_public_broadsheet(
+    edition: &Edition,
+    county: &County,
+    pool: &PgPool,
+) -> Result<PublicBroadsheetResult> {
+    let rows = EditionRow::find_by_edition(edition.id, pool).await?;
+    let all_templates = RowTemplateConfig::find_all(pool).await?;
+
+    // Collect all post IDs across all rows for batch loading
+    let mut all_slots_by_row: Vec<Vec<EditionSlot>> = Vec::new();
+    let mut all_post_ids: Vec<Uuid> = Vec::new();
+    let mut all_widget_ids: Vec<Uuid> = Vec::new();
+
+    for row in &rows {
+        let slots = EditionSlot::find_by_row(row.id, pool).await?;
+        for slot in &slots {
+            if let Some(post_id) = slot.post_id {
+                all_post_ids.push(post_id);
+            }
+            if let Some(widget_id) = slot.widget_id {
+                all_widget_ids.push(widget_id);
+            }
+        }
+        all_slots_by_row.push(slots);
+    }
+
+    // Batch load full post data, tags, urgent notes, and org info
+    let posts_by_id: HashMap<Uuid, Post> = if !all_post_ids.is_empty() {
+        Post::find_by_ids(&all_post_ids, pool)
+            .await?
+            .into_iter()
+            .map(|p| (p.id.into_uuid(), p))
+            .collect()
+    } else {
+        HashMap::new()
+    };
+
+    // Batch load widgets
+    let widgets_by_id: HashMap<Uuid, Widget> = if !all_widget_ids.is_empty() {
+        let mut map = HashMap::new();
+        for wid in &all_widget_ids {
+            if let Some(w) = Widget::find_by_id(*wid, pool).await? {
+                map.insert(w.id, w);
+            }
+        }
+        map
+    } else {
+        HashMap::new()
+    };
+
+    let (mut tags_by_post, mut urgent_notes_by_post) =
+        load_tags_and_notes(&all_post_ids, pool).await?;
+
+    let mut org_info = Post::find_org_info_for_posts(&all_post_ids, pool).await?;
+
+    // Batch load contacts for all posts
+    let all_contacts = Contact::find_by_post_ids(&all_post_ids, pool).await?;
+    let mut contacts_by_post: HashMap<Uuid, Vec<BroadsheetContactResult>> = HashMap::new();
+    for c in all_contacts {
+        contacts_by_post
+            .entry(c.contactable_id)
+            .or_default()
+            .push(BroadsheetContactResult {
+                contact_type: c.contact_type,
+                contact_value: c.contact_value,
+                contact_label: c.contact_label,
+            });
+    }
+
+    // Batch load field groups for all posts
+    let all_media = PostMediaRecord::find_by_post_ids(&all_post_ids, pool).await?;
+    let mut media_by_post: HashMap<Uuid, Vec<BroadsheetMediaResult>> = HashMap::new();
+    for m in all_media {
+        media_by_post
+            .entry(m.post_id)
+            .or_default()
+            .push(BroadsheetMediaResult {
+                image_url: m.image_url,
+                caption: m.caption,
+                credit: m.credit,
+            });
+    }
+
+    let all_items = PostItem::find_by_post_ids(&all_post_ids, pool).await?;
+    let mut items_by_post: HashMap<Uuid, Vec<BroadsheetItemResult>> = HashMap::new();
+    for item in all_items {
+        items_by_post
+            .entry(item.post_id)
+            .or_default()
+            .push(BroadsheetItemResult {
+                name: item.name,
+                detail: item.detail,
+            });
+    }
+
+    let all_schedule = PostScheduleEntry::find_by_post_ids(&all_post_ids, pool).await?;
+    let mut schedule_by_post: HashMap<Uuid, Vec<BroadsheetScheduleEntryResult>> = HashMap::new();
+    for entry in all_schedule {
+        schedule_by_post
+            .entry(entry.post_id)
+            .or_default()
+            .push(BroadsheetScheduleEntryResult {
+                day: entry.day,
+                opens: entry.opens,
+                closes: entry.closes,
+            });
+    }
+
+    // 1:1 field groups
+    let all_persons = PostPersonRecord::find_by_post_ids(&all_post_ids, pool).await?;
+    let mut persons_by_post: HashMap<Uuid, BroadsheetPersonResult> = all_persons
+        .into_iter()
+        .map(|p| {
+            (
+                p.post_id,
+                BroadsheetPersonResult {
+                    name: p.name,
+                    role: p.role,
+                    bio: p.bio,
+                    photo_url: p.photo_url,
+                    quote: p.quote,
+                },
+            )
+        })
+        .collect();
+
+    let all_links = PostLinkRecord::find_by_post_ids(&all_post_ids, pool).await?;
+    let mut links_by_post: HashMap<Uuid, BroadsheetLinkResult> = all_links
+        .into_iter()
+        .map(|l| {
+            (
+                l.post_id,
+                BroadsheetLinkResult {
+                    label: l.label,
+                    url: l.url,
+                    deadline: l.deadline.map(|d| d.to_string()),
+                },
+            )
+        })
+        .collect();
+
+    let all_source_attrs = PostSourceAttr::find_by_post_ids(&all_post_ids, pool).await?;
+    let mut source_attrs_by_post: HashMap<Uuid, BroadsheetSourceAttributionResult> =
+        all_source_attrs
+            .into_iter()
+            .map(|s| {
+                (
+                    s.post_id,
+                    BroadsheetSourceAttributionResult {
+                        source_name: s.source_name,
+                        attribution: s.attribution,
+                    },
+                )
+            })
+            .collect();
+
+    let all_metas = PostMetaRecord::find_by_post_ids(&all_post_ids, pool).await?;
+    let mut metas_by_post: HashMap<Uuid, BroadsheetMetaResult> = all_metas
+        .into_iter()
+        .map(|m| {
+            (
+                m.post_id,
+                BroadsheetMetaResult {
+                    kicker: m.kicker,
+                    byline: m.byline,
+                    timestamp: m.timestamp.map(|t| t.to_rfc3339()),
+                    updated: m.updated,
+                    deck: m.deck,
+                },
+            )
+        })
+        .collect();
+
+    let all_datetimes = PostDatetimeRecord::find_by_post_ids(&all_post_ids, pool).await?;
+    let mut datetimes_by_post: HashMap<Uuid, BroadsheetDatetimeResult> = all_datetimes
+        .into_iter()
+        .map(|d| {
+            (
+                d.post_id,
+                BroadsheetDatetimeResult {
+                    start: d.start_at.map(|t| t.to_rfc3339()),
+                    end: d.end_at.map(|t| t.to_rfc3339()),
+                    cost: d.cost,
+                    recurring: d.recurring,
+                },
+            )
+        })
+        .collect();
+
+    let all_statuses = PostStatusRecord::find_by_post_ids(&all_post_ids, pool).await?;
+    let mut statuses_by_post: HashMap<Uuid, BroadsheetStatusResult> = all_statuses
+        .into_iter()
+        .map(|s| {
+            (
+                s.post_id,
+                BroadsheetStatusResult {
+                    state: s.state,
+                    verified: s.verified,
+                },
+            )
+        })
+        .collect();
+
+    // Assemble rows
+    let mut row_results = Vec::new();
+    for (row, slots) in rows.iter().zip(all_slots_by_row.iter()) {
+        let template = all_templates
+            .iter()
+            .find(|t| t.id == row.row_template_config_id);
+
+        let slot_results: Vec<PublicBroadsheetSlotResult> = slots
+            .iter()
+            .filter_map(|slot| {
+                match slot.kind.as_str() {
+                    "post" => {
+                        let post_id = slot.post_id?;
+                        let post = posts_by_id.get(&post_id)?;
+                        let id = post.id.into_uuid();
+                        let org_name = org_info.remove(&id).map(|(_, name)| name);
+
+                        Some(PublicBroadsheetSlotResult {
+                            kind: "post".to_string(),
+                            post_template: slot.post_template.clone(),
+                            widget_template: None,
+                            slot_index: slot.slot_index,
+                            post: Some(PublicBroadsheetPostResult {
+                                id,
+                                title: post.title.clone(),
+                                body_raw: post.body_raw.clone(),
+                                post_type: post.post_type.clone(),
+                                weight: post.weight.clone(),
+                                is_urgent: post.is_urgent,
+                                pencil_mark: post.pencil_mark.clone(),
+                                location: post.location.clone(),
+                                organization_name: org_name,
+                                published_at: post.published_at.map(|dt| dt.to_rfc3339()),
+                                tags: tags_by_post.remove(&id).unwrap_or_default(),
+                                contacts: contacts_by_post.remove(&id).unwrap_or_default(),
+                                urgent_notes: urgent_notes_by_post.remove(&id).unwrap_or_default(),
+                                body_heavy: post.body_heavy.clone(),
+                                body_medium: post.body_medium.clone(),
+                                body_light: post.body_light.clone(),
+                                // Field groups
+                                media: media_by_post.remove(&id).unwrap_or_default(),
+                                items: items_by_post.remove(&id).unwrap_or_default(),
+                                person: persons_by_post.remove(&id),
+                                link: links_by_post.remove(&id),
+                                source_attribution: source_attrs_by_post.remove(&id),
+                                meta: metas_by_post.remove(&id),
+                                datetime: datetimes_by_post.remove(&id),
+                                post_status: statuses_by_post.remove(&id),
+                                schedule: schedule_by_post.remove(&id).unwrap_or_default(),
+                            }),
+                            widget: None,
+                        })
+                    }
+                    "widget" => {
+                        let widget_id = slot.widget_id?;
+                        let widget = widgets_by_id.get(&widget_id)?;
+
+                        Some(PublicBroadsheetSlotResult {
+                            kind: "widget".to_string(),
+                            post_template: None,
+                            widget_template: slot.widget_template.clone(),
+                            slot_index: slot.slot_index,
+                            post: None,
+                            widget: Some(PublicBroadsheetWidgetResult {
+                                id: widget.id,
+                                widget_type: widget.widget_type.clone(),
+                                authoring_mode: widget.authoring_mode.clone(),
+                                data: widget.data.clone(),
+                            }),
+                        })
+                    }
+                    _ => None,
+                }
+            })
+            .collect();
+
+        row_results.push(PublicBroadsheetRowResult {
+            row_template_slug: template.map(|t| t.slug.clone()).unwrap_or_default(),
+            layout_variant: template
+                .map(|t| t.layout_variant.clone())
+                .unwrap_or_else(|| "full".to_string()),
+            sort_order: row.sort_order,
+            section_id: row.section_id,
+            slots: slot_results,
+        });
+    }
+
+    // Load sections
+    let sections = EditionSection::find_by_edition(edition.id, pool).await?;
+    let section_results: Vec<EditionSectionResult> =
+        sections.iter().map(section_to_result).collect();
+
+    Ok(PublicBroadsheetResult {
+        edition: edition_to_result(edition),
+        county: CountyResult {
+            id: county.id,
+            fips_code: county.fips_code.clone(),
+            name: county.name.clone(),
+            state: county.state.clone(),
+            target_content_weight: county.target_content_weight,
+            is_pseudo: county.is_pseudo,
+        },
+        rows: row_results,
+        sections: section_results,
+    })
+}
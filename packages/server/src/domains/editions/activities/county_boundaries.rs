@@ -0,0 +1,392 @@
+//! County boundary polygons and the point-in-polygon index over them.
+//!
+//! Boundaries arrive as a GeoJSON FeatureCollection (one feature per
+//! county, `Polygon` or `MultiPolygon` in lng/lat) and are stored per
+//! county in `county_boundaries` (migration 258). `CountyIndex` answers
+//! "which county holds this point" with a bounding-box prefilter and an
+//! even-odd ray cast per ring, so holes (an enclave city carved out of a
+//! county) work without special cases.
+//!
+//! Features are matched to `counties` by FIPS: a 5-digit `GEOID`/`fips`
+//! property, or a 3-digit county code (`COUNTYFP`, `CTY_FIPS`) prefixed
+//! with Minnesota's state code.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use serde::Serialize;
+use serde_json::Value;
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::domains::editions::models::county::County;
+use crate::domains::editions::models::county_boundary::CountyBoundary;
+
+/// Minnesota's state FIPS code, prefixed to 3-digit county codes.
+const MN_STATE_FIPS: &str = "27";
+
+#[derive(Debug, thiserror::Error)]
+pub enum CountyBoundaryError {
+    #[error("invalid GeoJSON: {0}")]
+    Invalid(String),
+    #[error(transparent)]
+    Db(#[from] anyhow::Error),
+}
+
+/// A ring of `(lng, lat)` vertices. The closing vertex may or may not
+/// repeat the first.
+type Ring = Vec<(f64, f64)>;
+
+#[derive(Debug, Clone)]
+struct Polygon {
+    exterior: Ring,
+    holes: Vec<Ring>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct BBox {
+    min_lat: f64,
+    max_lat: f64,
+    min_lng: f64,
+    max_lng: f64,
+}
+
+impl BBox {
+    fn of(polygons: &[Polygon]) -> Option<Self> {
+        let mut points = polygons.iter().flat_map(|p| p.exterior.iter());
+        let &(lng, lat) = points.next()?;
+        let mut bbox = Self {
+            min_lat: lat,
+            max_lat: lat,
+            min_lng: lng,
+            max_lng: lng,
+        };
+        for &(lng, lat) in points {
+            bbox.min_lat = bbox.min_lat.min(lat);
+            bbox.max_lat = bbox.max_lat.max(lat);
+            bbox.min_lng = bbox.min_lng.min(lng);
+            bbox.max_lng = bbox.max_lng.max(lng);
+        }
+        Some(bbox)
+    }
+
+    fn contains(&self, lat: f64, lng: f64) -> bool {
+        (self.min_lat..=self.max_lat).contains(&lat) && (self.min_lng..=self.max_lng).contains(&lng)
+    }
+}
+
+/// Even-odd ray cast: does a ray east from the point cross the ring an
+/// odd number of times?
+fn ring_contains(ring: &[(f64, f64)], lat: f64, lng: f64) -> bool {
+    let mut inside = false;
+    let n = ring.len();
+    if n < 3 {
+        return false;
+    }
+    let mut j = n - 1;
+    for i in 0..n {
+        let (xi, yi) = ring[i];
+        let (xj, yj) = ring[j];
+        if (yi > lat) != (yj > lat) && lng < (xj - xi) * (lat - yi) / (yj - yi) + xi {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
+
+impl Polygon {
+    fn contains(&self, lat: f64, lng: f64) -> bool {
+        ring_contains(&self.exterior, lat, lng)
+            && !self.holes.iter().any(|h| ring_contains(h, lat, lng))
+    }
+}
+
+fn parse_ring(value: &Value) -> Result<Ring, CountyBoundaryError> {
+    let invalid = || CountyBoundaryError::Invalid("ring must be an array of [lng, lat]".into());
+    value
+        .as_array()
+        .ok_or_else(invalid)?
+        .iter()
+        .map(|pt| {
+            let pt = pt.as_array().ok_or_else(invalid)?;
+            match (pt.first().and_then(Value::as_f64), pt.get(1).and_then(Value::as_f64)) {
+                (Some(lng), Some(lat)) => Ok((lng, lat)),
+                _ => Err(invalid()),
+            }
+        })
+        .collect()
+}
+
+fn parse_polygon(value: &Value) -> Result<Polygon, CountyBoundaryError> {
+    let rings = value
+        .as_array()
+        .ok_or_else(|| CountyBoundaryError::Invalid("polygon must be an array of rings".into()))?;
+    let mut rings = rings.iter().map(parse_ring);
+    let exterior = rings
+        .next()
+        .ok_or_else(|| CountyBoundaryError::Invalid("polygon has no rings".into()))??;
+    Ok(Polygon {
+        exterior,
+        holes: rings.collect::<Result<_, _>>()?,
+    })
+}
+
+/// Parse a GeoJSON `Polygon` or `MultiPolygon` geometry.
+fn parse_geometry(geometry: &Value) -> Result<Vec<Polygon>, CountyBoundaryError> {
+    let coordinates = geometry
+        .get("coordinates")
+        .ok_or_else(|| CountyBoundaryError::Invalid("geometry has no coordinates".into()))?;
+    match geometry.get("type").and_then(Value::as_str) {
+        Some("Polygon") => Ok(vec![parse_polygon(coordinates)?]),
+        Some("MultiPolygon") => coordinates
+            .as_array()
+            .ok_or_else(|| CountyBoundaryError::Invalid("MultiPolygon must be an array".into()))?
+            .iter()
+            .map(parse_polygon)
+            .collect(),
+        other => Err(CountyBoundaryError::Invalid(format!(
+            "unsupported geometry type {:?}",
+            other
+        ))),
+    }
+}
+
+/// 5-digit county FIPS code from a feature's properties, if it has one.
+fn feature_fips(properties: &Value) -> Option<String> {
+    let text = |key: &str| -> Option<String> {
+        match properties.get(key)? {
+            Value::String(s) => Some(s.trim().to_string()),
+            Value::Number(n) => n.as_u64().map(|n| n.to_string()),
+            _ => None,
+        }
+    };
+    if let Some(full) = ["GEOID", "fips", "FIPS", "fips_code"].iter().find_map(|k| text(k)) {
+        return Some(full);
+    }
+    ["COUNTYFP", "CTY_FIPS", "COUNTY_FIPS"]
+        .iter()
+        .find_map(|k| text(k))
+        .map(|county| format!("{}{:0>3}", MN_STATE_FIPS, county))
+}
+
+struct CountyShape {
+    county_id: Uuid,
+    bbox: BBox,
+    polygons: Vec<Polygon>,
+}
+
+/// In-memory point-in-polygon index over county boundaries.
+pub struct CountyIndex {
+    shapes: Vec<CountyShape>,
+}
+
+impl CountyIndex {
+    /// Build from stored boundaries. A row whose geometry no longer parses
+    /// is skipped with a warning rather than failing every lookup.
+    pub fn from_boundaries(boundaries: &[CountyBoundary]) -> Self {
+        let shapes = boundaries
+            .iter()
+            .filter_map(|b| match parse_geometry(&b.geometry) {
+                Ok(polygons) => Some(CountyShape {
+                    county_id: b.county_id,
+                    bbox: BBox {
+                        min_lat: b.min_lat,
+                        max_lat: b.max_lat,
+                        min_lng: b.min_lng,
+                        max_lng: b.max_lng,
+                    },
+                    polygons,
+                }),
+                Err(e) => {
+                    tracing::warn!(
+                        county_id = %b.county_id,
+                        error = %e,
+                        "skipping county boundary"
+                    );
+                    None
+                }
+            })
+            .collect();
+        Self { shapes }
+    }
+
+    /// Load every stored boundary.
+    pub async fn load(pool: &PgPool) -> Result<Self> {
+        Ok(Self::from_boundaries(&CountyBoundary::find_all(pool).await?))
+    }
+
+    /// Load only the boundaries whose bounding box holds one of the points
+    /// — the cheap path for resolving a single post.
+    pub async fn load_around(points: &[(f64, f64)], conn: &mut PgConnection) -> Result<Self> {
+        let mut boundaries: Vec<CountyBoundary> = Vec::new();
+        for &(lat, lng) in points {
+            for b in CountyBoundary::find_containing_bbox(lat, lng, &mut *conn).await? {
+                if !boundaries.iter().any(|seen| seen.county_id == b.county_id) {
+                    boundaries.push(b);
+                }
+            }
+        }
+        Ok(Self::from_boundaries(&boundaries))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.shapes.is_empty()
+    }
+
+    /// The county whose boundary contains the point. Shared edges are
+    /// resolved by whichever county was loaded first.
+    pub fn locate(&self, lat: f64, lng: f64) -> Option<Uuid> {
+        self.shapes
+            .iter()
+            .filter(|s| s.bbox.contains(lat, lng))
+            .find(|s| s.polygons.iter().any(|p| p.contains(lat, lng)))
+            .map(|s| s.county_id)
+    }
+}
+
+/// Result of loading a boundary FeatureCollection.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BoundaryLoadSummary {
+    /// FIPS codes stored.
+    pub loaded: Vec<String>,
+    /// FIPS codes (or feature indexes, when a feature has none) that
+    /// matched no county.
+    pub unmatched: Vec<String>,
+}
+
+/// Store every feature of a GeoJSON FeatureCollection as its county's
+/// boundary. Geometry is validated before anything is written, so a bad
+/// file leaves the existing boundaries untouched.
+pub async fn load_boundaries(
+    collection: &Value,
+    source: Option<&str>,
+    pool: &PgPool,
+) -> Result<BoundaryLoadSummary, CountyBoundaryError> {
+    if collection.get("type").and_then(Value::as_str) != Some("FeatureCollection") {
+        return Err(CountyBoundaryError::Invalid("expected a FeatureCollection".into()));
+    }
+    let features = collection
+        .get("features")
+        .and_then(Value::as_array)
+        .ok_or_else(|| CountyBoundaryError::Invalid("FeatureCollection has no features".into()))?;
+
+    let counties: HashMap<String, Uuid> = County::find_all(pool)
+        .await?
+        .into_iter()
+        .map(|c| (c.fips_code, c.id))
+        .collect();
+
+    let mut summary = BoundaryLoadSummary::default();
+    let mut parsed = Vec::new();
+    for (i, feature) in features.iter().enumerate() {
+        let geometry = feature
+            .get("geometry")
+            .ok_or_else(|| CountyBoundaryError::Invalid(format!("feature {} has no geometry", i)))?;
+        let polygons = parse_geometry(geometry)?;
+        let bbox = BBox::of(&polygons)
+            .ok_or_else(|| CountyBoundaryError::Invalid(format!("feature {} is empty", i)))?;
+        let fips = feature.get("properties").and_then(feature_fips);
+        let county_id = fips.as_ref().and_then(|f| counties.get(f)).copied();
+        match (county_id, fips) {
+            (Some(county_id), Some(fips)) => parsed.push((county_id, fips, geometry, bbox)),
+            (_, fips) => summary
+                .unmatched
+                .push(fips.unwrap_or_else(|| format!("feature {}", i))),
+        }
+    }
+
+    for (county_id, fips, geometry, bbox) in parsed {
+        CountyBoundary::upsert(
+            county_id,
+            geometry,
+            bbox.min_lat,
+            bbox.max_lat,
+            bbox.min_lng,
+            bbox.max_lng,
+            source,
+            pool,
+        )
+        .await?;
+        summary.loaded.push(fips);
+    }
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn index(features: &[(Uuid, Value)]) -> CountyIndex {
+        let shapes = features
+            .iter()
+            .map(|(id, geometry)| {
+                let polygons = parse_geometry(geometry).unwrap();
+                CountyShape {
+                    county_id: *id,
+                    bbox: BBox::of(&polygons).unwrap(),
+                    polygons,
+                }
+            })
+            .collect();
+        CountyIndex { shapes }
+    }
+
+    #[test]
+    fn locates_point_by_polygon_not_bounding_box() {
+        // Two counties split along a diagonal: their bounding boxes overlap
+        // completely, only the polygons tell them apart.
+        let west = Uuid::new_v4();
+        let east = Uuid::new_v4();
+        let idx = index(&[
+            (
+                west,
+                json!({"type": "Polygon", "coordinates": [
+                    [[-94.0, 45.0], [-93.0, 46.0], [-94.0, 46.0], [-94.0, 45.0]]
+                ]}),
+            ),
+            (
+                east,
+                json!({"type": "Polygon", "coordinates": [
+                    [[-94.0, 45.0], [-93.0, 45.0], [-93.0, 46.0], [-94.0, 45.0]]
+                ]}),
+            ),
+        ]);
+        assert_eq!(idx.locate(45.8, -93.8), Some(west));
+        assert_eq!(idx.locate(45.2, -93.2), Some(east));
+        assert_eq!(idx.locate(47.0, -93.5), None);
+    }
+
+    #[test]
+    fn holes_and_multipolygons() {
+        let county = Uuid::new_v4();
+        let idx = index(&[(
+            county,
+            json!({"type": "MultiPolygon", "coordinates": [
+                [
+                    [[-94.0, 45.0], [-93.0, 45.0], [-93.0, 46.0], [-94.0, 46.0], [-94.0, 45.0]],
+                    [[-93.6, 45.4], [-93.4, 45.4], [-93.4, 45.6], [-93.6, 45.6], [-93.6, 45.4]]
+                ],
+                [[[-92.0, 47.0], [-91.5, 47.0], [-91.5, 47.5], [-92.0, 47.5], [-92.0, 47.0]]]
+            ]}),
+        )]);
+        assert_eq!(idx.locate(45.2, -93.8), Some(county));
+        assert_eq!(idx.locate(45.5, -93.5), None, "inside the hole");
+        assert_eq!(idx.locate(47.2, -91.7), Some(county), "second polygon");
+    }
+
+    #[test]
+    fn feature_fips_accepts_geoid_or_county_code() {
+        assert_eq!(feature_fips(&json!({"GEOID": "27053"})).as_deref(), Some("27053"));
+        assert_eq!(feature_fips(&json!({"CTY_FIPS": 53})).as_deref(), Some("27053"));
+        assert_eq!(feature_fips(&json!({"COUNTYFP": "003"})).as_deref(), Some("27003"));
+        assert_eq!(feature_fips(&json!({"name": "Hennepin"})), None);
+    }
+
+    #[test]
+    fn rejects_unsupported_geometry() {
+        let err = parse_geometry(&json!({"type": "Point", "coordinates": [-93.0, 45.0]}));
+        assert!(matches!(err, Err(CountyBoundaryError::Invalid(_))));
+    }
+}
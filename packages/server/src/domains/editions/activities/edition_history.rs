@@ -0,0 +1,366 @@
+//! Edition history — the `edition_events` log, undo and redo.
+//!
+//! Every layout edit is wrapped in a `LayoutEdit`: a transaction that locks
+//! the edition, captures the layout (sections, rows and slots, as table
+//! rows) before the edit runs and again after, and stores both on the
+//! event. Undoing the last N edits
+//! restores the `before` of the oldest of them; redo restores the `after`
+//! of the last edit it re-applies. Restoring rewrites the layout with the
+//! original ids, so later events keep pointing at the right rows. Slots
+//! whose post has since been archived or deleted are left out.
+
+use anyhow::{anyhow, Result};
+use serde_json::Value;
+use sqlx::{PgConnection, PgExecutor, Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::domains::editions::models::edition::Edition;
+use crate::domains::editions::models::edition_event::{EditionEvent, EditionEventRef};
+use crate::kernel::ServerDeps;
+
+/// Capture an edition's full layout as JSON.
+pub async fn capture_layout(edition_id: Uuid, db: impl PgExecutor<'_>) -> Result<Value> {
+    sqlx::query_scalar(
+        r#"
+        SELECT jsonb_build_object(
+            'sections', COALESCE((
+                SELECT jsonb_agg(to_jsonb(s) ORDER BY s.sort_order, s.id)
+                FROM edition_sections s WHERE s.edition_id = $1
+            ), '[]'::jsonb),
+            'rows', COALESCE((
+                SELECT jsonb_agg(to_jsonb(r) ORDER BY r.sort_order, r.id)
+                FROM edition_rows r WHERE r.edition_id = $1
+            ), '[]'::jsonb),
+            'slots', COALESCE((
+                SELECT jsonb_agg(to_jsonb(es) ORDER BY es.slot_index, es.sort_order, es.id)
+                FROM edition_slots es
+                JOIN edition_rows r ON r.id = es.edition_row_id
+                WHERE r.edition_id = $1
+            ), '[]'::jsonb)
+        )
+        "#,
+    )
+    .bind(edition_id)
+    .fetch_one(db)
+    .await
+    .map_err(Into::into)
+}
+
+/// Lock an edition and capture its layout ahead of an edit made inside
+/// the caller's transaction. Pair with [`record_edit`] before committing.
+pub async fn lock_and_capture(edition_id: Uuid, conn: &mut PgConnection) -> Result<Value> {
+    Edition::lock(edition_id, &mut *conn)
+        .await?
+        .ok_or_else(|| anyhow!("Edition not found: {}", edition_id))?;
+    capture_layout(edition_id, conn).await
+}
+
+/// Record an edit made inside the caller's transaction, capturing the
+/// layout after it from the same transaction.
+pub async fn record_edit(
+    edition_id: Uuid,
+    before: Value,
+    actor_id: Option<Uuid>,
+    op: &str,
+    params: Value,
+    conn: &mut PgConnection,
+) -> Result<EditionEvent> {
+    let after = capture_layout(edition_id, &mut *conn).await?;
+    EditionEvent::record_layout(edition_id, actor_id, op, params, before, after, conn).await
+}
+
+/// Replace an edition's layout with a captured one, inside the caller's
+/// transaction. Columns added since the layout was captured take their
+/// defaults. Slots whose post has since been archived, deleted or turned
+/// into a revision, or whose widget has been deleted, are dropped.
+async fn restore_layout(edition_id: Uuid, layout: &Value, conn: &mut PgConnection) -> Result<()> {
+    sqlx::query("DELETE FROM edition_sections WHERE edition_id = $1")
+        .bind(edition_id)
+        .execute(&mut *conn)
+        .await?;
+    sqlx::query("DELETE FROM edition_rows WHERE edition_id = $1")
+        .bind(edition_id)
+        .execute(&mut *conn)
+        .await?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO edition_sections
+        SELECT * FROM jsonb_populate_recordset(
+            NULL::edition_sections,
+            (SELECT jsonb_agg('{"pinned": false}'::jsonb || e) FROM jsonb_array_elements($2) e)
+        ) s
+        WHERE s.edition_id = $1
+        "#,
+    )
+    .bind(edition_id)
+    .bind(&layout["sections"])
+    .execute(&mut *conn)
+    .await?;
+    sqlx::query(
+        r#"
+        INSERT INTO edition_rows
+        SELECT * FROM jsonb_populate_recordset(
+            NULL::edition_rows,
+            (SELECT jsonb_agg('{"pinned": false}'::jsonb || e) FROM jsonb_array_elements($2) e)
+        ) r
+        WHERE r.edition_id = $1
+        "#,
+    )
+    .bind(edition_id)
+    .bind(&layout["rows"])
+    .execute(&mut *conn)
+    .await?;
+    let dropped = sqlx::query_scalar::<_, i64>(
+        r#"
+        WITH snapshot AS (
+            SELECT * FROM jsonb_populate_recordset(
+                NULL::edition_slots,
+                (SELECT jsonb_agg('{"pinned": false}'::jsonb || e) FROM jsonb_array_elements($2) e)
+            ) es
+            WHERE EXISTS (
+                SELECT 1 FROM edition_rows r
+                WHERE r.id = es.edition_row_id AND r.edition_id = $1
+            )
+        ),
+        restored AS (
+            INSERT INTO edition_slots
+            SELECT es.* FROM snapshot es
+            WHERE (es.post_id IS NULL OR EXISTS (
+                      SELECT 1 FROM posts p
+                      WHERE p.id = es.post_id
+                        AND p.status <> 'archived'
+                        AND p.deleted_at IS NULL
+                        AND p.revision_of_post_id IS NULL
+                  ))
+              AND (es.widget_id IS NULL OR EXISTS (SELECT 1 FROM widgets w WHERE w.id = es.widget_id))
+            RETURNING 1
+        )
+        SELECT (SELECT COUNT(*) FROM snapshot) - (SELECT COUNT(*) FROM restored)
+        "#,
+    )
+    .bind(edition_id)
+    .bind(&layout["slots"])
+    .fetch_one(&mut *conn)
+    .await?;
+    if dropped > 0 {
+        tracing::info!(
+            edition_id = %edition_id,
+            dropped,
+            "Edition history: left out slots whose post or widget is gone"
+        );
+    }
+    Ok(())
+}
+
+/// A layout edit in progress: a transaction holding the edition's row
+/// lock and the layout from before the edit. Run the edit's writes on
+/// [`LayoutEdit::conn`]; [`LayoutEdit::commit`] records the event and
+/// commits them together.
+pub struct LayoutEdit {
+    edition_id: Uuid,
+    before: Value,
+    tx: Transaction<'static, Postgres>,
+}
+
+impl LayoutEdit {
+    /// Lock the edition and capture the layout ahead of an edit.
+    pub async fn begin(edition_id: Uuid, deps: &ServerDeps) -> Result<Self> {
+        let mut tx = deps.db_pool.begin().await?;
+        let before = lock_and_capture(edition_id, &mut tx).await?;
+        Ok(Self {
+            edition_id,
+            before,
+            tx,
+        })
+    }
+
+    /// The edit's transaction.
+    pub fn conn(&mut self) -> &mut PgConnection {
+        &mut self.tx
+    }
+
+    /// Record the finished edit as `op` with its request `params`, and
+    /// commit it.
+    pub async fn commit(
+        self,
+        actor_id: Option<Uuid>,
+        op: &str,
+        params: Value,
+    ) -> Result<EditionEvent> {
+        let Self {
+            edition_id,
+            before,
+            mut tx,
+        } = self;
+        let event = record_edit(edition_id, before, actor_id, op, params, &mut tx).await?;
+        tx.commit().await?;
+        Ok(event)
+    }
+}
+
+/// Record a status change or other non-layout event.
+pub async fn record_lifecycle(
+    edition_id: Uuid,
+    actor_id: Option<Uuid>,
+    op: &str,
+    params: Value,
+    deps: &ServerDeps,
+) -> Result<EditionEvent> {
+    EditionEvent::record_lifecycle(edition_id, actor_id, op, params, &deps.db_pool).await
+}
+
+/// Which events an undo or redo touches, and which one's stored layout
+/// to restore (its `before` for undo, its `after` for redo).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HistoryPlan {
+    pub event_ids: Vec<Uuid>,
+    pub restore_from: Uuid,
+}
+
+/// Plan undoing the last `steps` applied layout events.
+pub fn plan_undo(stack: &[EditionEventRef], steps: usize) -> Option<HistoryPlan> {
+    let taken: Vec<&EditionEventRef> = stack
+        .iter()
+        .rev()
+        .filter(|e| e.status == "applied")
+        .take(steps)
+        .collect();
+    let oldest = taken.last()?;
+    Some(HistoryPlan {
+        restore_from: oldest.id,
+        event_ids: taken.iter().map(|e| e.id).collect(),
+    })
+}
+
+/// Plan redoing the next `steps` undone layout events.
+pub fn plan_redo(stack: &[EditionEventRef], steps: usize) -> Option<HistoryPlan> {
+    let last_applied = stack
+        .iter()
+        .filter(|e| e.status == "applied")
+        .map(|e| e.seq)
+        .max()
+        .unwrap_or(i64::MIN);
+    let taken: Vec<&EditionEventRef> = stack
+        .iter()
+        .filter(|e| e.status == "undone" && e.seq > last_applied)
+        .take(steps)
+        .collect();
+    let newest = taken.last()?;
+    Some(HistoryPlan {
+        restore_from: newest.id,
+        event_ids: taken.iter().map(|e| e.id).collect(),
+    })
+}
+
+/// Undo the last `steps` layout edits. Returns the undone events.
+pub async fn undo_edits(
+    edition_id: Uuid,
+    steps: usize,
+    deps: &ServerDeps,
+) -> Result<Vec<EditionEvent>> {
+    apply_plan(edition_id, steps, true, deps).await
+}
+
+/// Redo the next `steps` undone layout edits. Returns the re-applied events.
+pub async fn redo_edits(
+    edition_id: Uuid,
+    steps: usize,
+    deps: &ServerDeps,
+) -> Result<Vec<EditionEvent>> {
+    apply_plan(edition_id, steps, false, deps).await
+}
+
+async fn apply_plan(
+    edition_id: Uuid,
+    steps: usize,
+    undo: bool,
+    deps: &ServerDeps,
+) -> Result<Vec<EditionEvent>> {
+    let action = if undo { "undo" } else { "redo" };
+
+    // Lock the edition so an edit or another undo can't land between
+    // reading the stack and restoring from it.
+    let mut tx = deps.db_pool.begin().await?;
+    let edition = Edition::lock(edition_id, &mut *tx)
+        .await?
+        .ok_or_else(|| anyhow!("Edition not found: {}", edition_id))?;
+    if edition.status == "published" || edition.status == "archived" {
+        return Err(anyhow!(
+            "Cannot {} edits on a {} edition — unpublish it first",
+            action,
+            edition.status
+        ));
+    }
+
+    let stack = EditionEvent::layout_stack(edition_id, &mut *tx).await?;
+    let plan = if undo {
+        plan_undo(&stack, steps)
+    } else {
+        plan_redo(&stack, steps)
+    }
+    .ok_or_else(|| anyhow!("Nothing to {}", action))?;
+
+    let events = EditionEvent::find_by_ids(&plan.event_ids, &mut *tx).await?;
+    let source = events
+        .iter()
+        .find(|e| e.id == plan.restore_from)
+        .ok_or_else(|| anyhow!("Edition event disappeared: {}", plan.restore_from))?;
+    let layout = if undo { &source.before } else { &source.after };
+    let layout = layout
+        .as_ref()
+        .ok_or_else(|| anyhow!("Edition event {} has no stored layout", source.id))?;
+
+    restore_layout(edition_id, layout, &mut tx).await?;
+    EditionEvent::set_status(
+        &plan.event_ids,
+        if undo { "undone" } else { "applied" },
+        &mut *tx,
+    )
+    .await?;
+    let events = EditionEvent::find_by_ids(&plan.event_ids, &mut *tx).await?;
+    tx.commit().await?;
+    Ok(events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stack(statuses: &[&str]) -> Vec<EditionEventRef> {
+        statuses
+            .iter()
+            .enumerate()
+            .map(|(i, s)| EditionEventRef {
+                id: Uuid::from_u128(i as u128 + 1),
+                seq: i as i64 + 1,
+                status: s.to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn undo_restores_the_oldest_of_the_last_n() {
+        let s = stack(&["applied", "applied", "applied", "undone"]);
+        let plan = plan_undo(&s, 2).unwrap();
+        assert_eq!(plan.event_ids, vec![Uuid::from_u128(3), Uuid::from_u128(2)]);
+        assert_eq!(plan.restore_from, Uuid::from_u128(2));
+
+        // Asking for more than exist undoes everything
+        let plan = plan_undo(&s, 10).unwrap();
+        assert_eq!(plan.restore_from, Uuid::from_u128(1));
+        assert!(plan_undo(&stack(&["undone"]), 1).is_none());
+    }
+
+    #[test]
+    fn redo_reapplies_in_order_after_the_last_applied() {
+        let s = stack(&["applied", "undone", "undone", "undone"]);
+        let plan = plan_redo(&s, 2).unwrap();
+        assert_eq!(plan.event_ids, vec![Uuid::from_u128(2), Uuid::from_u128(3)]);
+        assert_eq!(plan.restore_from, Uuid::from_u128(3));
+
+        assert!(plan_redo(&stack(&["applied", "applied"]), 1).is_none());
+        let all_undone = plan_redo(&stack(&["undone", "undone"]), 1).unwrap();
+        assert_eq!(all_undone.restore_from, Uuid::from_u128(1));
+    }
+}
@@ -3,17 +3,23 @@
 use anyhow::{anyhow, Result};
 use chrono::NaiveDate;
 use serde::Serialize;
+use sqlx::PgConnection;
 use uuid::Uuid;
 
-use crate::domains::editions::data::types::BatchGenerateResult;
+use crate::domains::editions::data::types::{
+    BatchGenerateResult, LayoutOptions, LayoutPins, LayoutReport,
+};
 use crate::domains::editions::models::county::County;
 use crate::domains::editions::models::edition::Edition;
+use crate::domains::editions::models::edition_layout_trace::EditionLayoutTrace;
 use crate::domains::editions::models::edition_row::EditionRow;
 use crate::domains::editions::models::edition_section::EditionSection;
 use crate::domains::editions::models::edition_slot::EditionSlot;
 use crate::kernel::ServerDeps;
 
+use super::broadsheet::freeze_snapshot;
 use super::layout_engine;
+use super::layout_pins;
 
 /// One item in a batch lifecycle operation's error list. Carries the edition
 /// id that failed and a human-readable reason (e.g. "Cannot publish an
@@ -82,11 +88,32 @@ fn default_edition_title(county: &County, period_start: NaiveDate) -> String {
 }
 
 /// Generate (or re-generate) the layout for an edition using the layout engine.
-/// Clears any existing rows/slots and replaces them with fresh placements.
+/// Clears any existing rows/slots and replaces them with fresh placements,
+/// laid out around the slots, rows and sections editors pinned.
 pub async fn generate_edition(edition_id: Uuid, deps: &ServerDeps) -> Result<Edition> {
-    let pool = &deps.db_pool;
+    let mut tx = deps.db_pool.begin().await?;
+    let (edition, _) =
+        generate_edition_with_options(edition_id, &LayoutOptions::default(), true, &mut tx, deps)
+            .await?;
+    tx.commit().await?;
+    Ok(edition)
+}
 
-    let edition = Edition::find_by_id(edition_id, pool)
+/// `generate_edition` with an explicit layout strategy. Also returns the
+/// layout report (strategy used, score breakdown, fallback reason, pins
+/// that couldn't be honoured).
+///
+/// Without `honour_pins`, pins are ignored and the edition is laid out
+/// from scratch. The edition is locked and rewritten on `conn`; the caller
+/// commits.
+pub async fn generate_edition_with_options(
+    edition_id: Uuid,
+    options: &LayoutOptions,
+    honour_pins: bool,
+    conn: &mut PgConnection,
+    deps: &ServerDeps,
+) -> Result<(Edition, Option<LayoutReport>)> {
+    let edition = Edition::lock(edition_id, &mut *conn)
         .await?
         .ok_or_else(|| anyhow!("Edition not found: {}", edition_id))?;
 
@@ -99,17 +126,25 @@ pub async fn generate_edition(edition_id: Uuid, deps: &ServerDeps) -> Result<Edi
 
     // Reset to draft if the edition was in review or approved
     if edition.status != "draft" {
-        Edition::reset_to_draft(edition_id, pool).await?;
+        Edition::reset_to_draft(edition_id, &mut *conn).await?;
     }
 
+    let pins = if honour_pins {
+        layout_pins::load_pins(edition_id, &mut *conn).await?
+    } else {
+        LayoutPins::default()
+    };
+
     // Clear existing layout
-    Edition::clear_layout(edition_id, pool).await?;
+    Edition::clear_layout(edition_id, &mut *conn).await?;
 
     // Run layout engine
     let draft = layout_engine::generate_broadsheet(
         edition.county_id,
         edition.period_start,
         edition.period_end,
+        options,
+        &pins,
         deps,
     )
     .await?;
@@ -122,13 +157,14 @@ pub async fn generate_edition(edition_id: Uuid, deps: &ServerDeps) -> Result<Edi
     // with the next sort_order. This preserves the relative order of both
     // post rows and widget rows.
     let mut edition_row_ids: Vec<Uuid> = Vec::new();
+    let mut widget_edition_row_ids: Vec<Option<Uuid>> = vec![None; draft.widget_rows.len()];
     let mut sort_order: i32 = 0;
     let widget_rows = &draft.widget_rows;
 
     for (post_row_idx, row) in draft.rows.iter().enumerate() {
         // Create the post row
         let edition_row =
-            EditionRow::create(edition_id, row.row_template_id, sort_order, pool).await?;
+            EditionRow::create(edition_id, row.row_template_id, sort_order, &mut *conn).await?;
         sort_order += 1;
 
         let slot_data: Vec<(Uuid, String, i32)> = row
@@ -137,27 +173,51 @@ pub async fn generate_edition(edition_id: Uuid, deps: &ServerDeps) -> Result<Edi
             .map(|s| (s.post_id, s.post_template_slug.clone(), s.slot_index))
             .collect();
 
-        EditionSlot::replace_for_row(edition_row.id, &slot_data, pool).await?;
+        EditionSlot::replace_for_row(edition_row.id, &slot_data, &mut *conn).await?;
         edition_row_ids.push(edition_row.id);
 
+        let pinned_posts: Vec<Uuid> =
+            row.slots.iter().filter(|s| s.pinned).map(|s| s.post_id).collect();
+        if !pinned_posts.is_empty() {
+            EditionSlot::pin_posts_in_row(edition_row.id, &pinned_posts, &mut *conn).await?;
+        }
+        if row.pinned {
+            EditionRow::set_pinned(edition_row.id, true, &mut *conn).await?;
+        }
+
         // Insert any widget rows that should follow this post row
-        for widget_row in widget_rows.iter().filter(|w| w.insert_after == post_row_idx) {
-            let widget_edition_row =
-                EditionRow::create(edition_id, widget_row.row_template_id, sort_order, pool).await?;
+        for (widget_row_idx, widget_row) in widget_rows
+            .iter()
+            .enumerate()
+            .filter(|(_, w)| w.insert_after == post_row_idx)
+        {
+            let widget_edition_row = EditionRow::create(
+                edition_id,
+                widget_row.row_template_id,
+                sort_order,
+                &mut *conn,
+            )
+            .await?;
             sort_order += 1;
 
             // Create one slot per widget in this row (1 for standalone, 2 for pair, 3 for trio)
             for ws in &widget_row.widgets {
-                EditionSlot::create_widget_slot(
+                let slot = EditionSlot::create_widget_slot(
                     widget_edition_row.id,
                     ws.widget_id,
                     ws.widget_template.as_deref(),
                     ws.slot_index,
-                    pool,
+                    &mut *conn,
                 )
                 .await?;
+                if ws.pinned {
+                    EditionSlot::set_pinned(slot.id, true, &mut *conn).await?;
+                }
+            }
+            if widget_row.pinned {
+                EditionRow::set_pinned(widget_edition_row.id, true, &mut *conn).await?;
             }
-            edition_row_ids.push(widget_edition_row.id);
+            widget_edition_row_ids[widget_row_idx] = Some(widget_edition_row.id);
         }
     }
 
@@ -169,21 +229,34 @@ pub async fn generate_edition(edition_id: Uuid, deps: &ServerDeps) -> Result<Edi
             section.subtitle.as_deref(),
             section.topic_slug.as_deref(),
             sort_order as i32,
-            pool,
+            &mut *conn,
         )
         .await?;
 
         for &row_idx in &section.row_indices {
             if let Some(&row_id) = edition_row_ids.get(row_idx) {
-                EditionRow::assign_to_section(row_id, Some(es.id), pool).await?;
+                EditionRow::assign_to_section(row_id, Some(es.id), &mut *conn).await?;
             }
         }
+        for &widget_row_idx in &section.widget_row_indices {
+            if let Some(&Some(row_id)) = widget_edition_row_ids.get(widget_row_idx) {
+                EditionRow::assign_to_section(row_id, Some(es.id), &mut *conn).await?;
+            }
+        }
+        if section.pinned {
+            EditionSection::set_pinned(es.id, true, &mut *conn).await?;
+        }
+    }
+
+    if let Some(ref trace) = draft.trace {
+        EditionLayoutTrace::upsert(edition_id, trace, &mut *conn).await?;
     }
 
     // Re-fetch to return up-to-date edition
-    Edition::find_by_id(edition_id, pool)
+    let edition = Edition::find_by_id(edition_id, &mut *conn)
         .await?
-        .ok_or_else(|| anyhow!("Edition disappeared after generation"))
+        .ok_or_else(|| anyhow!("Edition disappeared after generation"))?;
+    Ok((edition, draft.report))
 }
 
 /// Count the number of populated slots (post or widget assigned) in
@@ -236,6 +309,43 @@ async fn require_populated_edition(
     Ok(())
 }
 
+/// Posts slotted into an edition whose `published_at` is still in the
+/// future.
+async fn find_embargoed_slot_posts(
+    edition_id: Uuid,
+    pool: &sqlx::PgPool,
+) -> Result<Vec<(Uuid, String)>> {
+    sqlx::query_as(
+        r#"
+        SELECT DISTINCT p.id, p.title
+        FROM edition_slots es
+        JOIN edition_rows er ON er.id = es.edition_row_id
+        JOIN posts p ON p.id = es.post_id
+        WHERE er.edition_id = $1
+          AND p.published_at > NOW()
+        "#,
+    )
+    .bind(edition_id)
+    .fetch_all(pool)
+    .await
+    .map_err(Into::into)
+}
+
+/// Guard: refuse to publish an edition carrying an embargoed post. The
+/// layout engine skips them, but a post can be slotted by hand, or have its
+/// `published_at` moved later after the layout ran.
+async fn require_no_embargoed_posts(edition_id: Uuid, pool: &sqlx::PgPool) -> Result<()> {
+    let embargoed = find_embargoed_slot_posts(edition_id, pool).await?;
+    if embargoed.is_empty() {
+        return Ok(());
+    }
+    let titles: Vec<String> = embargoed.into_iter().map(|(_, title)| title).collect();
+    Err(anyhow!(
+        "Cannot publish an edition with embargoed posts (not yet published): {}",
+        titles.join(", ")
+    ))
+}
+
 /// Transition a draft edition to in_review (editor has opened it).
 pub async fn review_edition(edition_id: Uuid, deps: &ServerDeps) -> Result<Edition> {
     let pool = &deps.db_pool;
@@ -274,7 +384,8 @@ pub async fn approve_edition(edition_id: Uuid, deps: &ServerDeps) -> Result<Edit
     Edition::approve(edition_id, pool).await
 }
 
-/// Publish an approved edition.
+/// Publish an approved edition and freeze its public broadsheet as the
+/// edition's paper of record (see `EditionSnapshot`).
 pub async fn publish_edition(edition_id: Uuid, deps: &ServerDeps) -> Result<Edition> {
     let pool = &deps.db_pool;
 
@@ -294,7 +405,12 @@ pub async fn publish_edition(edition_id: Uuid, deps: &ServerDeps) -> Result<Edit
     }
 
     require_populated_edition(edition_id, "publish", pool).await?;
-    Edition::publish(edition_id, pool).await
+    require_no_embargoed_posts(edition_id, pool).await?;
+    let mut tx = pool.begin().await?;
+    let edition = Edition::publish(edition_id, &mut *tx).await?;
+    freeze_snapshot(&edition, &mut tx, pool).await?;
+    tx.commit().await?;
+    Ok(edition)
 }
 
 /// Move a published edition back to `approved` so an editor can revise it.
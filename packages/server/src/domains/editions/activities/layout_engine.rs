@@ -6,36 +6,51 @@
 //! - **Family consistency**: prefer same post_type within a row
 //! - **Density progression**: heavy features at top → medium mid → light/ticker bottom
 //!
-//! The algorithm is greedy (no backtracking) but produces visually balanced broadsheets
-//! by using integer height estimates on post templates.
+//! The default algorithm is greedy (no backtracking) but produces visually balanced
+//! broadsheets by using integer height estimates on post templates. The `Search`
+//! strategy (see `layout_search`) scores whole layouts against the same goals and
+//! keeps the greedy layout unless it finds a better one.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use anyhow::Result;
-use chrono::NaiveDate;
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
 use sqlx::PgPool;
 use uuid::Uuid;
 
 use crate::common::utils::slugs::county_service_area_slug;
 use crate::domains::editions::data::types::{
-    BroadsheetDraft, BroadsheetRow, BroadsheetSection, BroadsheetSlot, BroadsheetWidgetRow,
-    BroadsheetWidgetSlot, LayoutPost,
+    BroadsheetDraft, BroadsheetRow, BroadsheetSection, BroadsheetSlot, LayoutOptions, LayoutPins,
+    LayoutPost,
 };
 use crate::domains::editions::models::post_template_config::PostTemplateConfig;
 use crate::domains::editions::models::row_template_config::RowTemplateWithSlots;
 use crate::domains::editions::models::row_template_config::RowTemplateConfig;
+use crate::domains::editions::models::widget_placement_rule::WidgetPlacementRule;
+use crate::domains::schedules::models::schedule::Schedule;
 use crate::domains::widgets::models::widget::Widget;
 use crate::kernel::ServerDeps;
 
+use super::layout_pins;
+use super::layout_search::{self, LayoutInputs};
+use super::layout_trace;
+use super::widget_placement;
+
 // ---------------------------------------------------------------------------
 // Public API
 // ---------------------------------------------------------------------------
 
 /// Generate a broadsheet draft for a county and date range.
+///
+/// `options` picks the placement strategy; the draft's `report` records the
+/// strategy used, its score breakdown and any pins that couldn't be
+/// honoured. `pins` are laid out as fixed constraints (see `layout_pins`).
 pub async fn generate_broadsheet(
     county_id: Uuid,
     period_start: NaiveDate,
     _period_end: NaiveDate,
+    options: &LayoutOptions,
+    pins: &LayoutPins,
     deps: &ServerDeps,
 ) -> Result<BroadsheetDraft> {
     let pool = &deps.db_pool;
@@ -70,30 +85,81 @@ pub async fn generate_broadsheet(
     let templates = RowTemplateConfig::find_all_with_slots(pool).await?;
     let post_templates = PostTemplateConfig::find_all(pool).await?;
 
-    // Build height_units lookup from post templates.
-    let height_map: HashMap<String, i32> = post_templates
+    let (height_map, height_override_map) = height_maps(&post_templates);
+
+    // Load evergreen widgets available for this county+date. Pinned ones
+    // are checked against this list and held back from the rule-based rows.
+    let available_widgets = Widget::find_available(county_id, period_start, pool).await?;
+
+    let widget_ids: HashSet<Uuid> = available_widgets.iter().map(|w| w.id).collect();
+    let resolved = layout_pins::resolve(pins, &posts, &templates, &post_templates, &widget_ids);
+    let unpinned_posts: Vec<LayoutPost> = posts
         .iter()
-        .map(|pt| (pt.slug.clone(), pt.height_units))
+        .filter(|p| !resolved.post_ids.contains(&p.id))
+        .cloned()
+        .collect();
+    let unpinned_widgets: Vec<Widget> = available_widgets
+        .into_iter()
+        .filter(|w| !resolved.widget_ids.contains(&w.id))
         .collect();
 
-    // Build per-(template, post_type) height override map from the DB.
-    // Format: {("ledger", "reference") => 6, ("bulletin", "reference") => 10}
-    let mut height_override_map: HashMap<(String, String), i32> = HashMap::new();
-    for pt in &post_templates {
-        if let Some(ref overrides) = pt.height_override {
-            if let Some(obj) = overrides.as_object() {
-                for (post_type, val) in obj {
-                    if let Some(h) = val.as_i64() {
-                        height_override_map
-                            .insert((pt.slug.clone(), post_type.clone()), h as i32);
-                    }
-                }
-            }
-        }
+    // Lay out the unpinned posts toward what's left of the weight target.
+    // The search can score thousands of layouts, so it runs off the async
+    // workers on its own copies of the lookups.
+    let mut draft = {
+        let options = options.clone();
+        let templates = templates.clone();
+        let post_templates = post_templates.clone();
+        let height_map = height_map.clone();
+        let height_override_map = height_override_map.clone();
+        let remaining_weight = (target_content_weight - resolved.weight).max(0);
+        tokio::task::spawn_blocking(move || {
+            let inputs = LayoutInputs::new(
+                &unpinned_posts,
+                &templates,
+                &post_templates,
+                &height_map,
+                &height_override_map,
+                remaining_weight,
+            );
+            layout_search::place_with_options(&inputs, &options)
+        })
+        .await?
+    };
+
+    // Widget rows go where the county's placement rules (or the global
+    // ones) say; see `widget_placement`.
+    let widget_template_ids = widget_placement::widget_template_ids(pool).await?;
+    if !widget_template_ids.is_empty() {
+        let rules = WidgetPlacementRule::find_effective(county_id, pool).await?;
+        draft.widget_rows = widget_placement::place_widgets(
+            &draft.rows,
+            &draft.sections,
+            &unpinned_widgets,
+            &widget_template_ids,
+            &rules,
+        )
+        .widget_rows;
     }
 
-    let mut draft = place_posts(
-        posts,
+    // Put the pins back around the fresh layout.
+    layout_pins::apply(&mut draft, &resolved, &posts, &templates, &post_templates);
+    for pin in &resolved.unhonoured {
+        tracing::warn!(
+            county_id = %county_id,
+            pin_id = %pin.pin_id,
+            post_id = ?pin.post_id,
+            widget_id = ?pin.widget_id,
+            reason = ?pin.reason,
+            "Layout engine: pin not honoured"
+        );
+    }
+    if let Some(report) = draft.report.as_mut() {
+        report.pins_honoured = resolved.post_ids.len() + resolved.widget_ids.len();
+        report.unhonoured_pins = resolved.unhonoured;
+    }
+    let trace_inputs = LayoutInputs::new(
+        &posts,
         &templates,
         &post_templates,
         &height_map,
@@ -101,26 +167,23 @@ pub async fn generate_broadsheet(
         target_content_weight,
     );
 
-    // Load evergreen widgets available for this county+date and interleave
-    // widget-standalone rows into the draft at rule-based positions.
-    let available_widgets = Widget::find_available(county_id, period_start, pool).await?;
-
-    // Look up widget row template IDs by slug
-    let widget_template_ids: HashMap<String, Uuid> = sqlx::query_as::<_, (String, Uuid)>(
-        "SELECT slug, id FROM row_template_configs WHERE slug LIKE 'widget%'"
-    )
-    .fetch_all(pool)
-    .await?
-    .into_iter()
-    .collect();
-
-    if !widget_template_ids.is_empty() {
-        draft.widget_rows = place_widgets(&draft.rows, &available_widgets, &widget_template_ids);
-    }
+    // Record why each candidate post did or didn't make the edition.
+    let (statewide, candidates) =
+        layout_trace::load_candidates(county_id, period_start, pool).await?;
+    draft.trace = Some(layout_trace::build_trace(
+        county_id,
+        period_start,
+        statewide,
+        &candidates,
+        &draft,
+        &trace_inputs,
+    ));
 
     tracing::info!(
         rows = draft.rows.len(),
         widget_rows = draft.widget_rows.len(),
+        strategy = ?draft.report.as_ref().map(|r| r.used),
+        score = draft.report.as_ref().map(|r| r.score.total),
         total_slots = draft.rows.iter().map(|r| r.slots.len()).sum::<usize>(),
         sections = draft.sections.len(),
         "Layout engine: placement complete"
@@ -129,151 +192,31 @@ pub async fn generate_broadsheet(
     Ok(draft)
 }
 
-/// Place widget-standalone rows at rule-based positions within the broadsheet.
-///
-/// Rules (applied best-effort; widget availability may skip some positions):
-///   - After row 2: section_sep (establishes mid-page transition)
-///   - After row 4: pull_quote (editorial interlude)
-///   - After row 6: section_sep (another section break)
-///   - After row 8: resource_bar (support info near the bottom)
-///   - After row 9: section_sep (before dense/classifieds zone)
-///
-/// Widgets are picked round-robin from the available pool of each type.
-///
-/// Section separator variant:
-///   - Default: ledger (center-aligned) — feels like a "chapter break"
-///   - When the FOLLOWING row is a ticker-style full-width left-aligned row,
-///     use the default section-sep (left-aligned) so the separator aligns with
-///     the content below it.
-fn place_widgets(
-    rows: &[BroadsheetRow],
-    widgets: &[Widget],
-    template_ids: &HashMap<String, Uuid>,
-) -> Vec<BroadsheetWidgetRow> {
-    if rows.is_empty() || widgets.is_empty() {
-        return Vec::new();
-    }
-
-    let standalone_id = match template_ids.get("widget-standalone") {
-        Some(id) => *id,
-        None => return Vec::new(),
-    };
-    let trio_id = template_ids.get("widget-trio").copied().unwrap_or(standalone_id);
-    let pair_id = template_ids.get("widget-pair").copied().unwrap_or(standalone_id);
-
-    // Group widgets by type for round-robin selection
-    let mut by_type: HashMap<&str, Vec<&Widget>> = HashMap::new();
-    for w in widgets {
-        by_type.entry(w.widget_type.as_str()).or_default().push(w);
-    }
-
-    // Widget placement rules.
-    // "count" = how many widgets in the row (1=standalone, 2=pair, 3=trio).
-    // Never two widget rows back-to-back (enforced after rule application).
-    struct Rule { after: usize, wtype: &'static str, count: usize }
-    // Rules use 0-based post row indices. The last rule must leave at least
-    // 1 post row after it (no dangling section_sep with nothing below).
-    let last_row = rows.len().saturating_sub(1);
-    let rules = [
-        Rule { after: 2, wtype: "section_sep",  count: 1 },
-        Rule { after: 4, wtype: "number",       count: 3 },  // stat-card trio
-        Rule { after: 5, wtype: "section_sep",  count: 1 },
-        Rule { after: 6, wtype: "photo",        count: 1 },
-        Rule { after: 7, wtype: "pull_quote",   count: 1 },
-        Rule { after: 8, wtype: "number",       count: 2 },  // number-block pair
-        Rule { after: 9, wtype: "resource_bar", count: 1 },
-    ];
-
-    let mut type_cursor: HashMap<&str, usize> = HashMap::new();
-    let mut result: Vec<BroadsheetWidgetRow> = Vec::new();
-
-    for rule in &rules {
-        if rule.after >= rows.len() { continue; }
-        // Section seps must have at least one post row after them
-        if rule.wtype == "section_sep" && rule.after >= last_row { continue; }
-
-        let pool = match by_type.get(rule.wtype) {
-            Some(p) if p.len() >= rule.count => p,
-            _ => continue,
-        };
+/// Height lookups for placement: base `height_units` per post template,
+/// and per-(template, post_type) overrides from `height_override`.
+/// Override format: {("ledger", "reference") => 6, ("bulletin", "reference") => 10}
+pub(super) fn height_maps(
+    post_templates: &[PostTemplateConfig],
+) -> (HashMap<String, i32>, HashMap<(String, String), i32>) {
+    let height_map: HashMap<String, i32> = post_templates
+        .iter()
+        .map(|pt| (pt.slug.clone(), pt.height_units))
+        .collect();
 
-        // No back-to-back: skip if the previous widget row inserts at the
-        // SAME position (both after the same post row). Widgets at adjacent
-        // positions (e.g., after row 6 and after row 7) are fine — there's
-        // a post row between them.
-        if let Some(last) = result.last() {
-            if last.insert_after == rule.after {
-                continue; // Would be truly adjacent — skip this rule
+    let mut height_override_map: HashMap<(String, String), i32> = HashMap::new();
+    for pt in post_templates {
+        if let Some(ref overrides) = pt.height_override {
+            if let Some(obj) = overrides.as_object() {
+                for (post_type, val) in obj {
+                    if let Some(h) = val.as_i64() {
+                        height_override_map
+                            .insert((pt.slug.clone(), post_type.clone()), h as i32);
+                    }
+                }
             }
         }
-
-        let cursor = type_cursor.entry(rule.wtype).or_insert(0);
-
-        // For multi-widget rows (count > 1), all widgets must share the same
-        // visual variant ("harmony within"). Filter to widgets matching the
-        // first pick's widget_template.
-        let first_pick = pool[*cursor % pool.len()];
-        let first_template = first_pick.data.get("widget_template")
-            .and_then(|v| v.as_str())
-            .unwrap_or("");
-        let filtered: Vec<&&Widget> = if rule.count > 1 {
-            pool.iter()
-                .filter(|w| {
-                    w.data.get("widget_template")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("") == first_template
-                })
-                .collect()
-        } else {
-            pool.iter().collect()
-        };
-        if filtered.len() < rule.count { continue; }
-
-        // Pick widget(s) round-robin from the filtered (same-variant) pool
-        let mut slots: Vec<BroadsheetWidgetSlot> = Vec::new();
-        let mut local_cursor = 0usize;
-        for i in 0..rule.count {
-            let picked = filtered[local_cursor % filtered.len()];
-            local_cursor += 1;
-
-            let widget_template = if rule.wtype == "number" {
-                picked.data.get("widget_template")
-                    .and_then(|v| v.as_str())
-                    .map(|s| s.to_string())
-            } else if rule.wtype == "section_sep" {
-                let next_row = rows.get(rule.after + 1);
-                let next_is_ticker = next_row
-                    .map(|r| r.row_template_slug == "ticker" || r.row_template_slug == "ticker-updates")
-                    .unwrap_or(false);
-                if next_is_ticker { None } else { Some("ledger".to_string()) }
-            } else {
-                None
-            };
-
-            slots.push(BroadsheetWidgetSlot {
-                widget_id: picked.id,
-                widget_template,
-                slot_index: i as i32,
-            });
-        }
-
-        let row_template_id = match rule.count {
-            3 => trio_id,
-            2 => pair_id,
-            _ => standalone_id,
-        };
-
-        // Advance global cursor so the next pick of this type starts fresh
-        *cursor += rule.count;
-
-        result.push(BroadsheetWidgetRow {
-            widgets: slots,
-            insert_after: rule.after,
-            row_template_id,
-        });
     }
-
-    result
+    (height_map, height_override_map)
 }
 
 // ---------------------------------------------------------------------------
@@ -286,7 +229,7 @@ fn place_widgets(
 /// 1. Select row templates in density-progression order (hero → mid → dense)
 /// 2. Fill each row's slots with height-balanced, family-consistent posts
 /// 3. Only emit fully-filled rows (no partial rows)
-fn place_posts(
+pub(super) fn place_posts(
     posts: Vec<LayoutPost>,
     templates: &[RowTemplateWithSlots],
     post_templates: &[PostTemplateConfig],
@@ -295,7 +238,13 @@ fn place_posts(
     target_content_weight: i32,
 ) -> BroadsheetDraft {
     if posts.is_empty() {
-        return BroadsheetDraft { rows: vec![], sections: vec![], widget_rows: vec![] };
+        return BroadsheetDraft {
+            rows: vec![],
+            sections: vec![],
+            widget_rows: vec![],
+            report: None,
+            trace: None,
+        };
     }
 
     let heavy_count = posts.iter().filter(|p| p.weight == "heavy").count();
@@ -396,13 +345,15 @@ fn place_posts(
         rows: broadsheet_rows,
         sections,
         widget_rows: Vec::new(), // Widget placement runs separately in generate_broadsheet.
+        report: None,
+        trace: None,
     }
 }
 
 /// Fill a single row template with posts, using height-balanced placement.
 ///
 /// Returns None if the row can't be fully filled (all slot groups must be satisfied).
-fn fill_row(
+pub(super) fn fill_row(
     template: &RowTemplateConfig,
     template_with_slots: &RowTemplateWithSlots,
     posts: &[LayoutPost],
@@ -508,6 +459,7 @@ fn fill_row(
         row_template_id: template.id,
         slots: row_slots,
         max_priority: row_max_priority,
+        pinned: false,
     })
 }
 
@@ -615,7 +567,7 @@ fn fill_slot_group(
         match (a_target, b_target) {
             (true, false) => std::cmp::Ordering::Less,
             (false, true) => std::cmp::Ordering::Greater,
-            _ => b.2.cmp(&a.2),
+            _ => b.2.cmp(&a.2).then_with(|| occurrence_order(a.1, b.1)),
         }
     });
 
@@ -684,16 +636,43 @@ fn fill_slot_group(
             post_id: posts[*i].id,
             post_template_slug: pt_slug,
             slot_index: slot_def.slot_index,
+            pinned: false,
         });
         placed[*i] = true;
         cumulative_height += h;
     }
 
+    // Date-driven cells (several events stacked in one cell) read in date
+    // order, soonest first.
+    let date_of = |post_id: Uuid| {
+        posts
+            .iter()
+            .find(|p| p.id == post_id)
+            .and_then(|p| p.next_occurrence)
+    };
+    if filled.iter().filter(|s| date_of(s.post_id).is_some()).count() > 1 {
+        filled.sort_by_key(|s| {
+            let at = date_of(s.post_id);
+            (at.is_none(), at)
+        });
+    }
+
     filled
 }
 
+/// Tie-break between equally scored posts: the sooner event first, and
+/// events before undated posts.
+fn occurrence_order(a: &LayoutPost, b: &LayoutPost) -> std::cmp::Ordering {
+    match (a.next_occurrence, b.next_occurrence) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    }
+}
+
 /// Resolve the post template slug for a post in a slot.
-fn resolve_post_template(
+pub(super) fn resolve_post_template(
     post: &LayoutPost,
     slot_def: &crate::domains::editions::models::row_template_slot::RowTemplateSlot,
     post_templates: &[PostTemplateConfig],
@@ -720,7 +699,7 @@ fn resolve_post_template(
 /// JSONB column) for a per-type override. Falls back to the template's base
 /// `height_units`. This replaces the hardcoded outlier table — new overrides
 /// are added via SQL UPDATE on `post_template_configs`, not code changes.
-fn effective_height(
+pub(super) fn effective_height(
     template_slug: &str,
     post_type: &str,
     height_map: &HashMap<String, i32>,
@@ -734,7 +713,7 @@ fn effective_height(
     height_map.get(template_slug).copied().unwrap_or(4)
 }
 
-fn weight_score(w: &str) -> i32 {
+pub(super) fn weight_score(w: &str) -> i32 {
     match w {
         "heavy" => 3,
         "medium" => 2,
@@ -1192,30 +1171,16 @@ fn deduct_from_pool_by_type(
 /// Zone 1 (hero): rows with heavy posts → top of broadsheet
 /// Zone 2 (mid): rows with medium posts → middle
 /// Zone 3 (dense): rows with only light posts → bottom
-fn order_rows_by_density(rows: &mut Vec<BroadsheetRow>, templates: &[RowTemplateWithSlots]) {
+pub(super) fn order_rows_by_density(
+    rows: &mut [BroadsheetRow],
+    templates: &[RowTemplateWithSlots],
+) {
     // Build a slug → layout_variant lookup
     let variant_map: HashMap<&str, &str> = templates
         .iter()
         .map(|t| (t.config.slug.as_str(), t.config.layout_variant.as_str()))
         .collect();
 
-    // Classify each row into a density zone
-    fn density_zone(row: &BroadsheetRow, variant_map: &HashMap<&str, &str>) -> i32 {
-        let variant = variant_map.get(row.row_template_slug.as_str()).copied().unwrap_or("full");
-        // Hero zone: lead-stack and full rows tend to have heavy content
-        // But we classify by the actual template, not just variant
-        match variant {
-            "lead-stack" | "lead" => 0, // Hero zone
-            "full" => {
-                // Full-width could be hero (single feature) or dense (tickers)
-                // Use max_priority as a heuristic: high priority = hero
-                if row.max_priority >= 70 { 0 } else { 2 }
-            }
-            "pair" | "pair-stack" | "trio" => 1, // Mid zone
-            _ => 2, // Dense zone
-        }
-    }
-
     rows.sort_by(|a, b| {
         let zone_a = density_zone(a, &variant_map);
         let zone_b = density_zone(b, &variant_map);
@@ -1267,12 +1232,29 @@ fn order_rows_by_density(rows: &mut Vec<BroadsheetRow>, templates: &[RowTemplate
     }
 }
 
+/// Classify a row into a density zone: 0 = hero, 1 = mid, 2 = dense.
+pub(super) fn density_zone(row: &BroadsheetRow, variant_map: &HashMap<&str, &str>) -> i32 {
+    let variant = variant_map.get(row.row_template_slug.as_str()).copied().unwrap_or("full");
+    // Hero zone: lead-stack and full rows tend to have heavy content
+    // But we classify by the actual template, not just variant
+    match variant {
+        "lead-stack" | "lead" => 0, // Hero zone
+        "full" => {
+            // Full-width could be hero (single feature) or dense (tickers)
+            // Use max_priority as a heuristic: high priority = hero
+            if row.max_priority >= 70 { 0 } else { 2 }
+        }
+        "pair" | "pair-stack" | "trio" => 1, // Mid zone
+        _ => 2, // Dense zone
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
 
 /// Find the best compatible post template for a post, based on slot weight.
-fn find_compatible_post_template(
+pub(super) fn find_compatible_post_template(
     post: &LayoutPost,
     slot_weight: &str,
     post_templates: &[PostTemplateConfig],
@@ -1292,121 +1274,219 @@ fn find_compatible_post_template(
     None
 }
 
-/// Load active posts relevant to a county.
+/// Future events stay eligible for this long after an edition's
+/// period_start. Mirrors the `INTERVAL '8 weeks'` in `ELIGIBILITY_CLAUSES`.
+pub(super) const EVENT_HORIZON_WEEKS: i64 = 8;
+
+/// Every eligibility clause for post `p`, one boolean column each. Binds
+/// `$1` county id, `$2` period start and `$3` the county's service_area
+/// slug. `load_county_posts` filters on these columns and the layout trace
+/// reports them, so the two can't disagree about why a post is in or out.
+///
+/// - status: `not_embargoed` (embargoed posts wait for their publish time;
+///   `p.status` itself is checked by the caller)
+/// - geography: `resolved_county` (boundary point-in-polygon, else ZIP —
+///   see `posts::activities::county_resolution`), `zip_county` (not resolved
+///   yet, locationable ZIP maps here), `service_area_tag`, `statewide_tag`,
+///   `no_location` (no location, no resolved county and no service_area
+///   tag pinning it elsewhere); statewide editions use
+///   `statewide_service_area` alone
+/// - window: `evergreen`, `unpublished`, `published_in_window` (last 7
+///   days), `upcoming_event` (a one-off event inside the 8-week horizon),
+///   `recurring_candidate` (a recurring schedule that could fall inside the
+///   horizon; expanded in Rust to decide)
+pub(super) const ELIGIBILITY_CLAUSES: &str = r#"
+    (p.published_at IS NULL OR p.published_at <= NOW()) AS not_embargoed,
+    COALESCE(p.county_id = $1, false) AS resolved_county,
+    p.county_id IS NULL AND EXISTS (
+        SELECT 1 FROM locationables la
+        JOIN locations loc ON loc.id = la.location_id
+        JOIN zip_counties zc ON zc.zip_code = loc.postal_code
+        WHERE la.locatable_id = p.id
+          AND la.locatable_type = 'post'
+          AND la.is_primary = true
+          AND zc.county_id = $1
+    ) AS zip_county,
+    EXISTS (
+        SELECT 1 FROM taggables t
+        JOIN tags tg ON t.tag_id = tg.id
+        WHERE t.taggable_type = 'post'
+          AND t.taggable_id = p.id
+          AND tg.kind = 'service_area'
+          AND tg.value = $3
+    ) AS service_area_tag,
+    EXISTS (
+        SELECT 1 FROM taggables t
+        JOIN tags tg ON t.tag_id = tg.id
+        WHERE t.taggable_type = 'post'
+          AND t.taggable_id = p.id
+          AND tg.value = 'statewide'
+    ) AS statewide_tag,
+    EXISTS (
+        SELECT 1 FROM taggables t
+        JOIN tags tg ON t.tag_id = tg.id
+        WHERE t.taggable_type = 'post'
+          AND t.taggable_id = p.id
+          AND tg.kind = 'service_area'
+          AND tg.value = 'statewide'
+    ) AS statewide_service_area,
+    (
+        p.county_id IS NULL
+        AND NOT EXISTS (
+            SELECT 1 FROM locationables la
+            WHERE la.locatable_id = p.id
+              AND la.locatable_type = 'post'
+              AND la.is_primary = true
+        )
+        AND NOT EXISTS (
+            SELECT 1 FROM taggables t
+            JOIN tags tg ON t.tag_id = tg.id
+            WHERE t.taggable_type = 'post'
+              AND t.taggable_id = p.id
+              AND tg.kind = 'service_area'
+        )
+    ) AS no_location,
+    p.is_evergreen AS evergreen,
+    p.published_at IS NULL AS unpublished,
+    COALESCE(p.published_at >= ($2::date - INTERVAL '7 days'), false) AS published_in_window,
+    EXISTS (
+        SELECT 1 FROM schedules s
+        WHERE s.schedulable_type = 'post'
+          AND s.schedulable_id = p.id
+          AND s.rrule IS NULL
+          AND s.dtstart IS NOT NULL
+          AND s.dtstart::date >= $2::date
+          AND s.dtstart::date < ($2::date + INTERVAL '8 weeks')
+    ) AS upcoming_event,
+    EXISTS (
+        SELECT 1 FROM schedules s
+        WHERE s.schedulable_type = 'post'
+          AND s.schedulable_id = p.id
+          AND NULLIF(s.rrule, '') IS NOT NULL
+          AND s.dtstart IS NOT NULL
+          AND s.dtstart::date < ($2::date + INTERVAL '8 weeks')
+          AND (s.valid_to IS NULL OR s.valid_to >= $2::date)
+    ) AS recurring_candidate
+"#;
+
+/// Geography over the `ELIGIBILITY_CLAUSES` columns. Binds `$4`: whether
+/// the edition is the Statewide pseudo-county's.
+pub(super) const GEOGRAPHY_MATCH: &str = r#"
+    CASE WHEN $4
+        THEN statewide_service_area
+        ELSE resolved_county OR zip_county OR service_area_tag OR statewide_tag OR no_location
+    END
+"#;
+
+/// A candidate post row from `load_county_posts`.
+///
+/// `in_window` covers every date clause that SQL can decide (evergreen,
+/// unpublished, published in the last week, a one-off event inside the
+/// horizon). Rows outside the window were selected only because a
+/// recurring schedule *could* fall inside the horizon; whether it does is
+/// decided by expanding the rrule.
+#[derive(Debug, sqlx::FromRow)]
+struct CandidatePostRow {
+    id: Uuid,
+    post_type: Option<String>,
+    weight: Option<String>,
+    priority: Option<i32>,
+    in_window: bool,
+}
+
+/// Earliest event occurrence of a post inside an edition's horizon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct EventOccurrence {
+    pub at: DateTime<Utc>,
+    /// A recurring schedule has an occurrence inside the horizon.
+    pub recurring: bool,
+}
+
+/// Load active posts relevant to a county (see `ELIGIBILITY_CLAUSES`).
 ///
 /// A post is eligible when any of the following holds:
-///   - Its locationable's zip_code maps to this county.
-///   - Its direct `posts.zip_code` maps to this county (legacy path).
+///   - Its resolved `posts.county_id` is this county (coordinates inside
+///     the county boundary, else its ZIP's primary county — see
+///     `posts::activities::county_resolution`).
+///   - It has no resolved county yet and its locationable's zip_code maps
+///     to this county.
 ///   - It has a `service_area` tag matching this county (e.g. `scott-county`).
 ///   - It has the explicit `statewide` tag.
-///   - It has NO location at all AND no `service_area` tag (truly statewide).
+///   - It has NO location, NO resolved county AND no `service_area` tag
+///     (truly statewide).
 ///
 /// The critical rule: posts with an explicit `service_area` tag are locked
 /// to that county — they won't fall through the "no location = statewide"
 /// fallback. Without this, county-specific references (e.g. "Scott County
 /// Food Shelves") leak into every county's broadsheet.
-async fn load_county_posts(
+///
+/// The Statewide pseudo-county's edition is narrower: only posts
+/// explicitly tagged `service_area = 'statewide'`. Posts with NO
+/// service_area tag at all are excluded on purpose — those default into
+/// every real county's edition via the "truly ambient" fallback, and we
+/// don't want to double-count them by also surfacing them on the
+/// statewide page. If editors want a post to appear in the statewide
+/// edition, they explicitly tag it.
+///
+/// Either way the date window is the same: published in the 7 days before
+/// period_start, evergreen, or a future event — one-off or recurring —
+/// within the 8-week horizon. Future-event posts stay eligible right up
+/// until the event occurs: an event 3 weeks away should appear in the next
+/// 3 editions, not just the one published during the writing week.
+pub(super) async fn load_county_posts(
     county_id: Uuid,
     period_start: NaiveDate,
     pool: &PgPool,
 ) -> Result<Vec<LayoutPost>> {
-    #[derive(Debug, sqlx::FromRow)]
-    struct PostRow {
-        id: Uuid,
-        post_type: Option<String>,
-        weight: Option<String>,
-        priority: Option<i32>,
-    }
-
-    // Fetch the county's service_area slug so we can match `service_area`
-    // tags. For pseudo counties (e.g. "Statewide") we take a different
-    // path entirely — only posts explicitly tagged `statewide` or wholly
-    // ambient (no service_area at all) belong in a statewide edition.
     let (county_name, is_pseudo): (String, bool) =
         sqlx::query_as("SELECT name, is_pseudo FROM counties WHERE id = $1")
             .bind(county_id)
             .fetch_one(pool)
             .await?;
-
-    if is_pseudo {
-        return load_statewide_posts(period_start, pool).await;
-    }
-
     let service_area = county_service_area_slug(&county_name);
 
-    let rows = sqlx::query_as::<_, PostRow>(
+    let sql = format!(
         r#"
-        SELECT DISTINCT p.id, p.post_type, p.weight, p.priority
-        FROM posts p
-        LEFT JOIN locationables la
-            ON la.locatable_id = p.id
-            AND la.locatable_type = 'post'
-            AND la.is_primary = true
-        LEFT JOIN locations loc ON loc.id = la.location_id
-        LEFT JOIN zip_counties zc ON loc.postal_code = zc.zip_code
-        WHERE p.status = 'active'
-          AND (
-            -- Explicit county match via locationable
-            zc.county_id = $1
-            -- Explicit match via this county's service_area tag
-            OR EXISTS (
-              SELECT 1 FROM taggables t
-              JOIN tags tg ON t.tag_id = tg.id
-              WHERE t.taggable_type = 'post'
-                AND t.taggable_id = p.id
-                AND tg.kind = 'service_area'
-                AND tg.value = $3
-            )
-            -- Explicit statewide tag
-            OR EXISTS (
-              SELECT 1 FROM taggables t
-              JOIN tags tg ON t.tag_id = tg.id
-              WHERE t.taggable_type = 'post'
-                AND t.taggable_id = p.id
-                AND tg.value = 'statewide'
-            )
-            -- No location at all AND no service_area tag pinning it elsewhere
-            OR (
-              la.id IS NULL
-              AND NOT EXISTS (
-                SELECT 1 FROM taggables t
-                JOIN tags tg ON t.tag_id = tg.id
-                WHERE t.taggable_type = 'post'
-                  AND t.taggable_id = p.id
-                  AND tg.kind = 'service_area'
-              )
-            )
-          )
-          AND (
-            p.is_evergreen = true
-            OR p.published_at IS NULL
-            OR p.published_at >= ($2::date - INTERVAL '7 days')
-            -- Future-event posts stay eligible right up until the event
-            -- occurs (capped at an 8-week horizon so we don't pull in
-            -- things scheduled far out). This matches editorial
-            -- intuition: an event 3 weeks away should appear in the
-            -- next 3 editions, not just the one published during the
-            -- writing week. Only one-off events (`rrule IS NULL`) are
-            -- considered — recurring schedules are usually operating
-            -- hours for evergreen posts, which already pass above.
-            OR EXISTS (
-              SELECT 1 FROM schedules s
-              WHERE s.schedulable_type = 'post'
-                AND s.schedulable_id = p.id
-                AND s.rrule IS NULL
-                AND s.dtstart IS NOT NULL
-                AND s.dtstart::date >= $2::date
-                AND s.dtstart::date < ($2::date + INTERVAL '8 weeks')
-            )
-          )
-        ORDER BY p.priority DESC NULLS LAST
+        WITH clauses AS (
+            SELECT p.id, p.post_type, p.weight, p.priority, {}
+            FROM posts p
+            WHERE p.status = 'active'
+        )
+        SELECT id, post_type, weight, priority,
+               evergreen OR unpublished OR published_in_window OR upcoming_event AS in_window
+        FROM clauses
+        WHERE not_embargoed
+          AND {}
+          AND (evergreen OR unpublished OR published_in_window OR upcoming_event
+               OR recurring_candidate)
+        ORDER BY priority DESC NULLS LAST
         "#,
-    )
-    .bind(county_id)
-    .bind(period_start)
-    .bind(&service_area)
-    .fetch_all(pool)
-    .await?;
+        ELIGIBILITY_CLAUSES, GEOGRAPHY_MATCH
+    );
+    let rows = sqlx::query_as::<_, CandidatePostRow>(&sql)
+        .bind(county_id)
+        .bind(period_start)
+        .bind(&service_area)
+        .bind(is_pseudo)
+        .fetch_all(pool)
+        .await?;
+
+    finish_candidate_posts(rows, period_start, pool).await
+}
+
+/// Drop recurring-only candidates with no occurrence inside the horizon,
+/// and attach topics and next event dates.
+async fn finish_candidate_posts(
+    rows: Vec<CandidatePostRow>,
+    period_start: NaiveDate,
+    pool: &PgPool,
+) -> Result<Vec<LayoutPost>> {
+    let post_ids: Vec<Uuid> = rows.iter().map(|r| r.id).collect();
+    let occurrences = load_event_occurrences(&post_ids, period_start, pool).await?;
+    let rows: Vec<CandidatePostRow> = rows
+        .into_iter()
+        .filter(|r| r.in_window || occurrences.get(&r.id).is_some_and(|o| o.recurring))
+        .collect();
 
     let post_ids: Vec<Uuid> = rows.iter().map(|r| r.id).collect();
     let topic_tags = load_topic_tags(&post_ids, pool).await?;
@@ -1421,6 +1501,7 @@ async fn load_county_posts(
                 weight: r.weight.unwrap_or_else(|| "medium".to_string()),
                 priority: r.priority.unwrap_or(50),
                 topic_slug,
+                next_occurrence: occurrences.get(&r.id).map(|o| o.at),
             }
         })
         .collect();
@@ -1428,83 +1509,44 @@ async fn load_county_posts(
     Ok(posts)
 }
 
-/// Load posts eligible for the Statewide pseudo-county's edition.
-///
-/// Narrower than `load_county_posts`: only pulls posts explicitly
-/// tagged `service_area = 'statewide'`, skipping every county-specific
-/// post. Posts with NO service_area tag at all are excluded here on
-/// purpose — those default into every real county's edition via the
-/// "truly ambient" fallback, and we don't want to double-count them by
-/// also surfacing them on the statewide page. If editors want a post
-/// to appear in the statewide edition, they explicitly tag it.
-///
-/// Same date-eligibility envelope as county editions (7-day window
-/// relative to period_start, plus evergreen posts and future events
-/// within an 8-week horizon).
-async fn load_statewide_posts(
+/// Each post's earliest event occurrence inside the horizon that starts at
+/// `period_start`, expanding recurring schedules and their exdates.
+pub(super) async fn load_event_occurrences(
+    post_ids: &[Uuid],
     period_start: NaiveDate,
     pool: &PgPool,
-) -> Result<Vec<LayoutPost>> {
-    #[derive(Debug, sqlx::FromRow)]
-    struct PostRow {
-        id: Uuid,
-        post_type: Option<String>,
-        weight: Option<String>,
-        priority: Option<i32>,
+) -> Result<HashMap<Uuid, EventOccurrence>> {
+    if post_ids.is_empty() {
+        return Ok(HashMap::new());
     }
+    let schedules = Schedule::find_for_post_ids(post_ids, pool).await?;
+    let from = period_start.and_time(NaiveTime::MIN).and_utc();
+    let to = from + Duration::weeks(EVENT_HORIZON_WEEKS);
+    Ok(earliest_occurrences(&schedules, from, to))
+}
 
-    let rows = sqlx::query_as::<_, PostRow>(
-        r#"
-        SELECT DISTINCT p.id, p.post_type, p.weight, p.priority
-        FROM posts p
-        WHERE p.status = 'active'
-          AND EXISTS (
-              SELECT 1 FROM taggables t
-              JOIN tags tg ON t.tag_id = tg.id
-              WHERE t.taggable_type = 'post'
-                AND t.taggable_id = p.id
-                AND tg.kind = 'service_area'
-                AND tg.value = 'statewide'
-          )
-          AND (
-            p.is_evergreen = true
-            OR p.published_at IS NULL
-            OR p.published_at >= ($1::date - INTERVAL '7 days')
-            OR EXISTS (
-              SELECT 1 FROM schedules s
-              WHERE s.schedulable_type = 'post'
-                AND s.schedulable_id = p.id
-                AND s.rrule IS NULL
-                AND s.dtstart IS NOT NULL
-                AND s.dtstart::date >= $1::date
-                AND s.dtstart::date < ($1::date + INTERVAL '8 weeks')
-            )
-          )
-        ORDER BY p.priority DESC NULLS LAST
-        "#,
-    )
-    .bind(period_start)
-    .fetch_all(pool)
-    .await?;
-
-    let post_ids: Vec<Uuid> = rows.iter().map(|r| r.id).collect();
-    let topic_tags = load_topic_tags(&post_ids, pool).await?;
-
-    let posts = rows
-        .into_iter()
-        .map(|r| {
-            let topic_slug = topic_tags.get(&r.id).cloned();
-            LayoutPost {
-                id: r.id,
-                post_type: r.post_type.unwrap_or_else(|| "update".to_string()),
-                weight: r.weight.unwrap_or_else(|| "medium".to_string()),
-                priority: r.priority.unwrap_or(50),
-                topic_slug,
-            }
-        })
-        .collect();
-
-    Ok(posts)
+/// Pure core of `load_event_occurrences`. Schedules without a `dtstart`
+/// (operating hours) aren't events and are skipped.
+pub(super) fn earliest_occurrences(
+    schedules: &[Schedule],
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> HashMap<Uuid, EventOccurrence> {
+    let mut earliest: HashMap<Uuid, EventOccurrence> = HashMap::new();
+    for schedule in schedules.iter().filter(|s| s.dtstart.is_some()) {
+        let recurring = schedule.rrule.as_deref().is_some_and(|r| !r.is_empty());
+        let Some(at) = schedule.occurrences_between(from, to, 1).into_iter().next() else {
+            continue;
+        };
+        earliest
+            .entry(schedule.schedulable_id)
+            .and_modify(|o| {
+                o.at = o.at.min(at);
+                o.recurring |= recurring;
+            })
+            .or_insert(EventOccurrence { at, recurring });
+    }
+    earliest
 }
 
 /// Load topic tags for a batch of post IDs.
@@ -1545,7 +1587,7 @@ async fn load_topic_tags(
 }
 
 /// Build topic sections from the placed rows.
-fn build_topic_sections(
+pub(super) fn build_topic_sections(
     rows: &[BroadsheetRow],
     posts: &[LayoutPost],
 ) -> Vec<BroadsheetSection> {
@@ -1563,6 +1605,8 @@ fn build_topic_sections(
                     *topic_counts.entry(topic).or_insert(0) += 1;
                 }
             }
+            // Ties go to the alphabetically first topic so the result
+            // doesn't depend on HashMap iteration order.
             topic_counts
                 .into_iter()
                 .max_by_key(|(_, count)| *count)
@@ -1587,6 +1631,8 @@ fn build_topic_sections(
                         subtitle: None,
                         topic_slug: Some(t.to_string()),
                         row_indices: vec![i],
+                        widget_row_indices: Vec::new(),
+                        pinned: false,
                     });
                     current_topic = Some(*t);
                 }
@@ -1630,6 +1676,7 @@ mod tests {
             weight: "medium".to_string(),
             priority: 50,
             topic_slug: topic.map(|t| t.to_string()),
+            next_occurrence: None,
         }
     }
 
@@ -1644,9 +1691,11 @@ mod tests {
                     post_id: p.id,
                     post_template_slug: "gazette".to_string(),
                     slot_index: i as i32,
+                    pinned: false,
                 })
                 .collect(),
             max_priority: 50,
+            pinned: false,
         }
     }
 
@@ -1756,6 +1805,7 @@ mod tests {
             weight: "light".to_string(),
             priority,
             topic_slug: None,
+            next_occurrence: None,
         }
     }
 
@@ -1943,4 +1993,32 @@ mod tests {
                 "tied counts, higher summed priority wins");
         }
     }
+
+    #[test]
+    fn event_cells_list_the_soonest_occurrence_first() {
+        let slot = make_slot(3, "digest");
+        let templates = vec![make_template("digest", &["event"])];
+        let now = Utc::now();
+        let mut posts: Vec<LayoutPost> = (0..3).map(|_| make_layout_post("event", 50)).collect();
+        posts[0].next_occurrence = Some(now + chrono::Duration::days(20));
+        posts[1].next_occurrence = Some(now + chrono::Duration::days(3));
+        let mut placed = vec![false; posts.len()];
+        let heights: HashMap<String, i32> = [("digest".to_string(), 2)].into_iter().collect();
+        let overrides: HashMap<(String, String), i32> = HashMap::new();
+
+        let filled = fill_slot_group(
+            &slot,
+            &posts,
+            &mut placed,
+            &templates,
+            &heights,
+            &overrides,
+            &None,
+            0,
+            "trio",
+        );
+
+        let order: Vec<Uuid> = filled.iter().map(|s| s.post_id).collect();
+        assert_eq!(order, vec![posts[1].id, posts[0].id, posts[2].id]);
+    }
 }
@@ -0,0 +1,677 @@
+//! Editor pins as layout constraints.
+//!
+//! Editors can pin a slot (its post or widget and template), a row (its
+//! template and every slot in it) or a section (its heading and every row
+//! in it). Regeneration then works in three steps:
+//!
+//!   1. `resolve` checks each pinned slot against this run's post pool,
+//!      widgets and templates. Pinned posts and widgets that pass are taken
+//!      out of the pool; the rest are reported and go back into it.
+//!   2. The engine lays out the remaining posts, aiming for the county's
+//!      weight target less the pinned weight.
+//!   3. `apply` puts the pinned rows back at their old positions, fills the
+//!      unpinned slots of slot-pinned rows from the posts left over, and
+//!      restores pinned sections around their rows.
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+use crate::domains::editions::data::types::{
+    BroadsheetDraft, BroadsheetRow, BroadsheetSection, BroadsheetSlot, BroadsheetWidgetRow,
+    BroadsheetWidgetSlot, LayoutPins, LayoutPost, PinFailure, PinKind, PinnedCell, PinnedRow,
+    PinnedSection, UnhonouredPin,
+};
+use crate::domains::editions::models::edition_row::EditionRow;
+use crate::domains::editions::models::edition_section::EditionSection;
+use crate::domains::editions::models::edition_slot::EditionSlot;
+use crate::domains::editions::models::post_template_config::PostTemplateConfig;
+use crate::domains::editions::models::row_template_config::RowTemplateWithSlots;
+
+use super::layout_engine::{find_compatible_post_template, weight_score};
+
+/// Read an edition's pins ahead of regeneration.
+pub async fn load_pins(edition_id: Uuid, conn: &mut PgConnection) -> Result<LayoutPins> {
+    let sections = EditionSection::find_by_edition(edition_id, &mut *conn).await?;
+    let rows = EditionRow::find_by_edition(edition_id, &mut *conn).await?;
+    let slots = EditionSlot::find_by_edition(edition_id, &mut *conn).await?;
+
+    let mut pins = LayoutPins::default();
+    let mut pinned_sections: HashMap<Uuid, usize> = HashMap::new();
+    for (position, section) in sections.into_iter().enumerate() {
+        if section.pinned {
+            pinned_sections.insert(section.id, pins.sections.len());
+            pins.sections.push(PinnedSection {
+                section_id: section.id,
+                position,
+                title: section.title,
+                subtitle: section.subtitle,
+                topic_slug: section.topic_slug,
+            });
+        }
+    }
+
+    for (position, row) in rows.iter().enumerate() {
+        let section = row
+            .section_id
+            .and_then(|id| pinned_sections.get(&id).copied());
+        let kind = match (section, row.pinned) {
+            (Some(_), _) => PinKind::Section,
+            (None, true) => PinKind::Row,
+            (None, false) => PinKind::Slot,
+        };
+        let cells: Vec<PinnedCell> = slots
+            .iter()
+            .filter(|s| s.edition_row_id == row.id && (kind != PinKind::Slot || s.pinned))
+            .filter(|s| s.post_id.is_some() || s.widget_id.is_some())
+            .map(|s| PinnedCell {
+                slot_id: s.id,
+                slot_index: s.slot_index,
+                post_id: s.post_id,
+                post_template: s.post_template.clone(),
+                widget_id: s.widget_id,
+                widget_template: s.widget_template.clone(),
+            })
+            .collect();
+        if cells.is_empty() {
+            continue;
+        }
+        pins.rows.push(PinnedRow {
+            row_id: row.id,
+            kind,
+            position,
+            row_template_id: row.row_template_config_id,
+            section,
+            cells,
+        });
+    }
+
+    Ok(pins)
+}
+
+/// Pins that passed their checks, ready to merge into a draft.
+#[derive(Debug, Clone, Default)]
+pub struct ResolvedPins {
+    pub rows: Vec<ResolvedRow>,
+    pub sections: Vec<PinnedSection>,
+    pub unhonoured: Vec<UnhonouredPin>,
+    pub post_ids: HashSet<Uuid>,
+    pub widget_ids: HashSet<Uuid>,
+    /// Editorial weight of the pinned posts (heavy=3, medium=2, light=1).
+    pub weight: i32,
+}
+
+/// A pinned row with its honoured slots. Holds either posts or widgets.
+#[derive(Debug, Clone)]
+pub struct ResolvedRow {
+    pub position: usize,
+    pub row_template_id: Uuid,
+    pub row_template_slug: String,
+    /// The whole row is pinned (directly or through its section), so its
+    /// unpinned slots stay empty rather than being refilled.
+    pub whole_row: bool,
+    pub section: Option<usize>,
+    pub slots: Vec<BroadsheetSlot>,
+    pub widgets: Vec<BroadsheetWidgetSlot>,
+    pub max_priority: i32,
+}
+
+/// Check pins against the eligible posts, available widgets and templates.
+pub fn resolve(
+    pins: &LayoutPins,
+    posts: &[LayoutPost],
+    templates: &[RowTemplateWithSlots],
+    post_templates: &[PostTemplateConfig],
+    widget_ids: &HashSet<Uuid>,
+) -> ResolvedPins {
+    let posts_by_id: HashMap<Uuid, &LayoutPost> = posts.iter().map(|p| (p.id, p)).collect();
+    let mut resolved = ResolvedPins {
+        sections: pins.sections.clone(),
+        ..Default::default()
+    };
+
+    for row in &pins.rows {
+        let template = templates.iter().find(|t| t.config.id == row.row_template_id);
+        let has_posts = row.cells.iter().any(|c| c.post_id.is_some());
+        let mut slots = Vec::new();
+        let mut widgets = Vec::new();
+
+        for cell in &row.cells {
+            let pin_id = match (row.kind, row.section) {
+                (PinKind::Slot, _) => cell.slot_id,
+                (_, Some(s)) => pins.sections[s].section_id,
+                (_, None) => row.row_id,
+            };
+            let mut fail = |reason: PinFailure, detail: String| {
+                resolved.unhonoured.push(UnhonouredPin {
+                    kind: row.kind,
+                    pin_id,
+                    row_position: row.position,
+                    slot_index: cell.slot_index,
+                    post_id: cell.post_id,
+                    widget_id: cell.widget_id,
+                    reason,
+                    detail,
+                });
+            };
+
+            let Some(template) = template else {
+                fail(
+                    PinFailure::RowTemplateMissing,
+                    "the row template was deleted".to_string(),
+                );
+                continue;
+            };
+            if !template.slots.iter().any(|s| s.slot_index == cell.slot_index) {
+                fail(
+                    PinFailure::SlotMissing,
+                    format!(
+                        "row template '{}' has no slot {}",
+                        template.config.slug, cell.slot_index
+                    ),
+                );
+                continue;
+            }
+
+            match (cell.post_id, cell.widget_id) {
+                (Some(post_id), _) => {
+                    let Some(post) = posts_by_id.get(&post_id) else {
+                        fail(
+                            PinFailure::PostIneligible,
+                            "the post is no longer eligible for this edition (expired, \
+                             archived or out of the county's window)"
+                                .to_string(),
+                        );
+                        continue;
+                    };
+                    let slug = cell.post_template.clone().unwrap_or_default();
+                    if !post_templates.iter().any(|pt| pt.slug == slug) {
+                        fail(
+                            PinFailure::PostTemplateMissing,
+                            format!("post template '{}' was deleted", slug),
+                        );
+                        continue;
+                    }
+                    if !resolved.post_ids.insert(post_id) {
+                        fail(PinFailure::Duplicate, "the post is pinned twice".to_string());
+                        continue;
+                    }
+                    resolved.weight += weight_score(&post.weight);
+                    slots.push(BroadsheetSlot {
+                        post_id,
+                        post_template_slug: slug,
+                        slot_index: cell.slot_index,
+                        pinned: true,
+                    });
+                }
+                (None, Some(widget_id)) => {
+                    if has_posts {
+                        fail(
+                            PinFailure::MixedRow,
+                            "widgets pinned in a row of posts are dropped".to_string(),
+                        );
+                        continue;
+                    }
+                    if !widget_ids.contains(&widget_id) {
+                        fail(
+                            PinFailure::WidgetUnavailable,
+                            "the widget was deleted or is outside its date range".to_string(),
+                        );
+                        continue;
+                    }
+                    if !resolved.widget_ids.insert(widget_id) {
+                        fail(PinFailure::Duplicate, "the widget is pinned twice".to_string());
+                        continue;
+                    }
+                    widgets.push(BroadsheetWidgetSlot {
+                        widget_id,
+                        widget_template: cell.widget_template.clone(),
+                        slot_index: cell.slot_index,
+                        pinned: true,
+                    });
+                }
+                (None, None) => {}
+            }
+        }
+
+        if let (Some(template), false) = (template, slots.is_empty() && widgets.is_empty()) {
+            let max_priority = slots
+                .iter()
+                .filter_map(|s| posts_by_id.get(&s.post_id))
+                .map(|p| p.priority)
+                .max()
+                .unwrap_or(0);
+            resolved.rows.push(ResolvedRow {
+                position: row.position,
+                row_template_id: template.config.id,
+                row_template_slug: template.config.slug.clone(),
+                whole_row: row.kind != PinKind::Slot,
+                section: row.section,
+                slots,
+                widgets,
+                max_priority,
+            });
+        }
+    }
+
+    resolved
+}
+
+/// A draft row in rendered order.
+enum Entry {
+    Post {
+        row: BroadsheetRow,
+        draft_index: Option<usize>,
+        section: Option<usize>,
+    },
+    Widget {
+        row: BroadsheetWidgetRow,
+        section: Option<usize>,
+    },
+}
+
+/// Merge resolved pins into a draft laid out without them. `posts` is the
+/// full eligible pool; posts the draft didn't place fill the unpinned
+/// slots of slot-pinned rows.
+pub fn apply(
+    draft: &mut BroadsheetDraft,
+    resolved: &ResolvedPins,
+    posts: &[LayoutPost],
+    templates: &[RowTemplateWithSlots],
+    post_templates: &[PostTemplateConfig],
+) {
+    // Flatten the draft into rendered order: each post row, then the
+    // widget rows inserted after it.
+    let rows = std::mem::take(&mut draft.rows);
+    let mut widget_rows = std::mem::take(&mut draft.widget_rows);
+    widget_rows.sort_by_key(|w| w.insert_after);
+    let mut widget_rows = widget_rows.into_iter().peekable();
+    let last_row = rows.len().saturating_sub(1);
+    let mut entries: Vec<Entry> = Vec::new();
+    for (i, row) in rows.into_iter().enumerate() {
+        entries.push(Entry::Post {
+            row,
+            draft_index: Some(i),
+            section: None,
+        });
+        while let Some(w) = widget_rows.next_if(|w| w.insert_after == i || i == last_row) {
+            entries.push(Entry::Widget { row: w, section: None });
+        }
+    }
+
+    let mut used: HashSet<Uuid> = entries
+        .iter()
+        .filter_map(|e| match e {
+            Entry::Post { row, .. } => Some(row.slots.iter().map(|s| s.post_id)),
+            Entry::Widget { .. } => None,
+        })
+        .flatten()
+        .chain(resolved.post_ids.iter().copied())
+        .collect();
+
+    let mut pinned_rows: Vec<&ResolvedRow> = resolved.rows.iter().collect();
+    pinned_rows.sort_by_key(|r| r.position);
+    for pin in pinned_rows {
+        let entry = if pin.slots.is_empty() {
+            Entry::Widget {
+                row: BroadsheetWidgetRow {
+                    widgets: pin.widgets.clone(),
+                    insert_after: 0,
+                    row_template_id: pin.row_template_id,
+                    pinned: pin.whole_row,
+                },
+                section: pin.section,
+            }
+        } else {
+            let mut slots = pin.slots.clone();
+            if !pin.whole_row {
+                if let Some(template) = templates.iter().find(|t| t.config.id == pin.row_template_id)
+                {
+                    fill_around(&mut slots, template, posts, post_templates, &mut used);
+                }
+            }
+            let max_priority = slots
+                .iter()
+                .filter_map(|s| posts.iter().find(|p| p.id == s.post_id))
+                .map(|p| p.priority)
+                .max()
+                .unwrap_or(pin.max_priority);
+            Entry::Post {
+                row: BroadsheetRow {
+                    row_template_slug: pin.row_template_slug.clone(),
+                    row_template_id: pin.row_template_id,
+                    slots,
+                    max_priority,
+                    pinned: pin.whole_row,
+                },
+                draft_index: None,
+                section: pin.section,
+            }
+        };
+        let at = pin.position.min(entries.len());
+        entries.insert(at, entry);
+    }
+
+    // Unflatten. A widget row ahead of every post row can only go after
+    // the first one.
+    let mut new_index: HashMap<usize, usize> = HashMap::new();
+    let mut section_rows: HashMap<usize, (Vec<usize>, Vec<usize>)> = HashMap::new();
+    for entry in entries {
+        match entry {
+            Entry::Post {
+                row,
+                draft_index,
+                section,
+            } => {
+                if let Some(i) = draft_index {
+                    new_index.insert(i, draft.rows.len());
+                }
+                if let Some(s) = section {
+                    section_rows.entry(s).or_default().0.push(draft.rows.len());
+                }
+                draft.rows.push(row);
+            }
+            Entry::Widget { mut row, section } => {
+                row.insert_after = draft.rows.len().saturating_sub(1);
+                if let Some(s) = section {
+                    section_rows.entry(s).or_default().1.push(draft.widget_rows.len());
+                }
+                draft.widget_rows.push(row);
+            }
+        }
+    }
+
+    for section in &mut draft.sections {
+        section.row_indices = section
+            .row_indices
+            .iter()
+            .filter_map(|i| new_index.get(i).copied())
+            .collect();
+    }
+    for (i, pinned) in resolved.sections.iter().enumerate() {
+        let (row_indices, widget_row_indices) = section_rows.remove(&i).unwrap_or_default();
+        let at = pinned.position.min(draft.sections.len());
+        draft.sections.insert(
+            at,
+            BroadsheetSection {
+                title: pinned.title.clone(),
+                subtitle: pinned.subtitle.clone(),
+                topic_slug: pinned.topic_slug.clone(),
+                row_indices,
+                widget_row_indices,
+                pinned: true,
+            },
+        );
+    }
+}
+
+/// Fill the unpinned slots of a slot-pinned row with unplaced posts that
+/// fit the slot's weight and accepted types, highest priority first.
+fn fill_around(
+    slots: &mut Vec<BroadsheetSlot>,
+    template: &RowTemplateWithSlots,
+    posts: &[LayoutPost],
+    post_templates: &[PostTemplateConfig],
+    used: &mut HashSet<Uuid>,
+) {
+    let pinned_indices: HashSet<i32> = slots.iter().map(|s| s.slot_index).collect();
+    let mut candidates: Vec<&LayoutPost> = posts.iter().collect();
+    candidates.sort_by_key(|p| std::cmp::Reverse(p.priority));
+
+    for slot_def in template
+        .slots
+        .iter()
+        .filter(|s| !pinned_indices.contains(&s.slot_index))
+    {
+        let fitting = candidates.iter().filter(|p| {
+            p.weight == slot_def.weight
+                && slot_def
+                    .accepts
+                    .as_ref()
+                    .is_none_or(|a| a.contains(&p.post_type))
+        });
+        let mut filled = 0;
+        for post in fitting {
+            if filled >= slot_def.count.max(1) {
+                break;
+            }
+            if used.contains(&post.id) {
+                continue;
+            }
+            let template_slug = slot_def
+                .post_template_slug
+                .clone()
+                .filter(|slug| {
+                    post_templates
+                        .iter()
+                        .any(|pt| &pt.slug == slug && pt.is_compatible(&post.post_type))
+                })
+                .or_else(|| find_compatible_post_template(post, &slot_def.weight, post_templates));
+            let Some(post_template_slug) = template_slug else {
+                continue;
+            };
+            used.insert(post.id);
+            slots.push(BroadsheetSlot {
+                post_id: post.id,
+                post_template_slug,
+                slot_index: slot_def.slot_index,
+                pinned: false,
+            });
+            filled += 1;
+        }
+    }
+    slots.sort_by_key(|s| s.slot_index);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domains::editions::models::row_template_config::RowTemplateConfig;
+    use crate::domains::editions::models::row_template_slot::RowTemplateSlot;
+    use chrono::Utc;
+
+    fn post(weight: &str, priority: i32) -> LayoutPost {
+        LayoutPost {
+            id: Uuid::new_v4(),
+            post_type: "story".to_string(),
+            weight: weight.to_string(),
+            priority,
+            topic_slug: None,
+            next_occurrence: None,
+        }
+    }
+
+    fn post_template(slug: &str, weight: &str) -> PostTemplateConfig {
+        PostTemplateConfig {
+            id: Uuid::new_v4(),
+            slug: slug.to_string(),
+            display_name: slug.to_string(),
+            description: None,
+            compatible_types: vec!["story".to_string()],
+            body_target: 100,
+            body_max: 200,
+            title_max: 80,
+            sort_order: 0,
+            weight: weight.to_string(),
+            height_units: 1,
+            height_override: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// (slot_index, weight, post template)
+    fn row_template(slug: &str, slots: &[(i32, &str, &str)]) -> RowTemplateWithSlots {
+        let id = Uuid::new_v4();
+        RowTemplateWithSlots {
+            config: RowTemplateConfig {
+                id,
+                slug: slug.to_string(),
+                display_name: slug.to_string(),
+                description: None,
+                layout_variant: "pair".to_string(),
+                sort_order: 0,
+                created_at: Utc::now(),
+            },
+            slots: slots
+                .iter()
+                .map(|&(slot_index, weight, template)| RowTemplateSlot {
+                    id: Uuid::new_v4(),
+                    row_template_config_id: id,
+                    slot_index,
+                    weight: weight.to_string(),
+                    count: 1,
+                    count_min: 1,
+                    count_max: 1,
+                    accepts: None,
+                    post_template_slug: Some(template.to_string()),
+                })
+                .collect(),
+        }
+    }
+
+    fn cell(slot_index: i32, post_id: Uuid, template: &str) -> PinnedCell {
+        PinnedCell {
+            slot_id: Uuid::new_v4(),
+            slot_index,
+            post_id: Some(post_id),
+            post_template: Some(template.to_string()),
+            widget_id: None,
+            widget_template: None,
+        }
+    }
+
+    fn pinned_row(position: usize, template: Uuid, cells: Vec<PinnedCell>) -> PinnedRow {
+        PinnedRow {
+            row_id: Uuid::new_v4(),
+            kind: PinKind::Slot,
+            position,
+            row_template_id: template,
+            section: None,
+            cells,
+        }
+    }
+
+    fn draft_row(template: &RowTemplateWithSlots, post: &LayoutPost) -> BroadsheetRow {
+        BroadsheetRow {
+            row_template_slug: template.config.slug.clone(),
+            row_template_id: template.config.id,
+            slots: vec![BroadsheetSlot {
+                post_id: post.id,
+                post_template_slug: "gazette".to_string(),
+                slot_index: 0,
+                pinned: false,
+            }],
+            max_priority: post.priority,
+            pinned: false,
+        }
+    }
+
+    #[test]
+    fn expired_and_missing_pins_are_reported_and_the_rest_kept() {
+        let pair = row_template("pair", &[(0, "medium", "gazette"), (1, "medium", "gazette")]);
+        let post_templates = vec![post_template("gazette", "medium")];
+        let live = post("medium", 60);
+        let expired = Uuid::new_v4();
+
+        let pins = LayoutPins {
+            rows: vec![
+                pinned_row(
+                    0,
+                    pair.config.id,
+                    vec![
+                        cell(0, live.id, "gazette"),
+                        cell(1, expired, "gazette"),
+                        cell(2, live.id, "gazette"),
+                    ],
+                ),
+                pinned_row(3, Uuid::new_v4(), vec![cell(0, live.id, "gazette")]),
+            ],
+            sections: Vec::new(),
+        };
+        let resolved = resolve(
+            &pins,
+            std::slice::from_ref(&live),
+            std::slice::from_ref(&pair),
+            &post_templates,
+            &HashSet::new(),
+        );
+
+        assert_eq!(resolved.rows.len(), 1);
+        assert_eq!(resolved.rows[0].slots.len(), 1);
+        assert!(resolved.post_ids.contains(&live.id));
+        assert_eq!(resolved.weight, 2);
+        let reasons: Vec<PinFailure> = resolved.unhonoured.iter().map(|u| u.reason).collect();
+        assert_eq!(
+            reasons,
+            vec![
+                PinFailure::PostIneligible,
+                PinFailure::SlotMissing,
+                PinFailure::RowTemplateMissing,
+            ]
+        );
+        assert_eq!(resolved.unhonoured[0].post_id, Some(expired));
+    }
+
+    #[test]
+    fn pinned_rows_go_back_at_their_position_and_are_filled_around() {
+        let pair = row_template("pair", &[(0, "medium", "gazette"), (1, "medium", "gazette")]);
+        let templates = vec![pair.clone()];
+        let post_templates = vec![post_template("gazette", "medium")];
+        let pinned = post("medium", 10);
+        let placed: Vec<LayoutPost> = (0..3).map(|i| post("medium", 50 - i)).collect();
+        let spare = post("medium", 5);
+        let mut posts = placed.clone();
+        posts.push(pinned.clone());
+        posts.push(spare.clone());
+
+        let pins = LayoutPins {
+            rows: vec![pinned_row(1, pair.config.id, vec![cell(1, pinned.id, "gazette")])],
+            sections: vec![PinnedSection {
+                section_id: Uuid::new_v4(),
+                position: 0,
+                title: "Pinned".to_string(),
+                subtitle: None,
+                topic_slug: None,
+            }],
+        };
+        let resolved = resolve(&pins, &posts, &templates, &post_templates, &HashSet::new());
+
+        let mut draft = BroadsheetDraft {
+            rows: placed.iter().map(|p| draft_row(&pair, p)).collect(),
+            sections: vec![BroadsheetSection {
+                title: "Housing".to_string(),
+                subtitle: None,
+                topic_slug: Some("housing".to_string()),
+                row_indices: vec![1, 2],
+                widget_row_indices: Vec::new(),
+                pinned: false,
+            }],
+            widget_rows: vec![BroadsheetWidgetRow {
+                widgets: Vec::new(),
+                insert_after: 1,
+                row_template_id: Uuid::new_v4(),
+                pinned: false,
+            }],
+            report: None,
+            trace: None,
+        };
+        apply(&mut draft, &resolved, &posts, &templates, &post_templates);
+
+        assert_eq!(draft.rows.len(), 4);
+        let row = &draft.rows[1];
+        assert_eq!(row.slots.len(), 2);
+        assert_eq!(row.slots[0].post_id, spare.id);
+        assert!(!row.slots[0].pinned);
+        assert_eq!(row.slots[1].post_id, pinned.id);
+        assert!(row.slots[1].pinned);
+
+        // Draft rows after the pin shift down, and the widget row with them
+        assert_eq!(draft.rows[2].slots[0].post_id, placed[1].id);
+        assert_eq!(draft.widget_rows[0].insert_after, 2);
+        assert_eq!(draft.sections.len(), 2);
+        assert!(draft.sections[0].pinned);
+        assert_eq!(draft.sections[1].row_indices, vec![2, 3]);
+    }
+}
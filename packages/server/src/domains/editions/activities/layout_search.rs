@@ -0,0 +1,843 @@
+//! Scored layout search — beam search over row sequences.
+//!
+//! The greedy engine picks row templates phase by phase and never revisits a
+//! choice, so a row that grabs the wrong posts early can strand heavy posts or
+//! leave a stack towering over its lead. This module scores complete layouts
+//! against an explicit objective (see `LayoutScore`):
+//!
+//! - **Target weight**: placed weight vs the county's `target_content_weight`
+//!   (anything inside the engine's 1.3× flex band is ideal)
+//! - **Height balance**: stacked cells vs their anchor cell, via `effective_height`
+//! - **Family cohesion**: posts sharing a cell share a post_type
+//! - **Density progression**: hero → mid → dense, counted as zone inversions
+//! - **Topic grouping**: each topic forms one contiguous section
+//!
+//! The search extends partial layouts one row at a time (every template that
+//! `fill_row` can fill, under the greedy engine's variety rules), keeps the
+//! `beam_width` best, and stops when no row fits, the weight ceiling is hit or
+//! the state budget (`max_states` scored layouts) runs out. Ties are broken by
+//! a seeded RNG and the budget counts states rather than wall-clock time, so a
+//! given seed and post pool always produce the same layout. The greedy layout is always
+//! computed first and is returned unless the search beats its score.
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::time::Instant;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use uuid::Uuid;
+
+use crate::domains::editions::data::types::{
+    BroadsheetDraft, BroadsheetRow, LayoutOptions, LayoutPost, LayoutReport, LayoutScore,
+    LayoutStrategy,
+};
+use crate::domains::editions::models::post_template_config::PostTemplateConfig;
+use crate::domains::editions::models::row_template_config::RowTemplateWithSlots;
+
+use super::layout_engine::{
+    build_topic_sections, density_zone, effective_height, fill_row, order_rows_by_density,
+    place_posts, weight_score,
+};
+
+/// Relative weight of each objective term in `LayoutScore::total`.
+const TARGET_WEIGHT_COEF: f64 = 4.0;
+const HEIGHT_BALANCE_COEF: f64 = 2.0;
+const FAMILY_COHESION_COEF: f64 = 1.5;
+const DENSITY_PROGRESSION_COEF: f64 = 1.5;
+const TOPIC_GROUPING_COEF: f64 = 1.0;
+
+/// Same soft ceiling the greedy engine applies to the weight target.
+const OVERSHOOT: f64 = 1.3;
+/// Upper bounds so a request can't ask for an unbounded search.
+const MAX_BEAM_WIDTH: usize = 64;
+const MAX_STATES: usize = 20_000;
+/// Rows per layout, and catchall rows per layout (as in greedy spillover).
+const MAX_ROWS: usize = 40;
+const MAX_CATCHALL_ROWS: usize = 20;
+/// Minimum improvement over the greedy score for the search layout to win.
+const EPSILON: f64 = 1e-9;
+
+/// Everything placement needs, with the lookups scoring uses built once.
+pub struct LayoutInputs<'a> {
+    pub posts: &'a [LayoutPost],
+    pub templates: &'a [RowTemplateWithSlots],
+    pub post_templates: &'a [PostTemplateConfig],
+    pub height_map: &'a HashMap<String, i32>,
+    pub height_override_map: &'a HashMap<(String, String), i32>,
+    pub target_content_weight: i32,
+    post_index: HashMap<Uuid, usize>,
+    template_by_slug: HashMap<&'a str, &'a RowTemplateWithSlots>,
+    variant_map: HashMap<&'a str, &'a str>,
+}
+
+impl<'a> LayoutInputs<'a> {
+    pub fn new(
+        posts: &'a [LayoutPost],
+        templates: &'a [RowTemplateWithSlots],
+        post_templates: &'a [PostTemplateConfig],
+        height_map: &'a HashMap<String, i32>,
+        height_override_map: &'a HashMap<(String, String), i32>,
+        target_content_weight: i32,
+    ) -> Self {
+        Self {
+            posts,
+            templates,
+            post_templates,
+            height_map,
+            height_override_map,
+            target_content_weight,
+            post_index: posts.iter().enumerate().map(|(i, p)| (p.id, i)).collect(),
+            template_by_slug: templates
+                .iter()
+                .map(|t| (t.config.slug.as_str(), t))
+                .collect(),
+            variant_map: templates
+                .iter()
+                .map(|t| (t.config.slug.as_str(), t.config.layout_variant.as_str()))
+                .collect(),
+        }
+    }
+
+    fn post(&self, id: Uuid) -> Option<&'a LayoutPost> {
+        self.post_index.get(&id).map(|&i| &self.posts[i])
+    }
+}
+
+/// Place posts with the requested strategy. Pure function — no I/O.
+///
+/// Always runs the greedy engine; `Search` then looks for a better-scoring
+/// layout and falls back to the greedy one if it finds none in time.
+pub fn place_with_options(inputs: &LayoutInputs, options: &LayoutOptions) -> BroadsheetDraft {
+    let started = Instant::now();
+
+    let mut draft = place_posts(
+        inputs.posts.to_vec(),
+        inputs.templates,
+        inputs.post_templates,
+        inputs.height_map,
+        inputs.height_override_map,
+        inputs.target_content_weight,
+    );
+    let greedy_score = score_layout(&draft.rows, inputs);
+
+    let mut report = LayoutReport {
+        requested: options.strategy,
+        used: LayoutStrategy::Greedy,
+        seed: options.seed,
+        fallback_reason: None,
+        states_explored: 0,
+        elapsed_ms: 0,
+        score: greedy_score.clone(),
+        greedy_score,
+        pins_honoured: 0,
+        unhonoured_pins: Vec::new(),
+    };
+
+    if options.strategy == LayoutStrategy::Search {
+        let outcome = beam_search(inputs, options, report.greedy_score.total);
+        report.states_explored = outcome.states_explored;
+        match outcome.best {
+            Some(best) => {
+                draft.sections = build_topic_sections(&best.rows, inputs.posts);
+                draft.rows = best.rows;
+                report.used = LayoutStrategy::Search;
+                report.score = best.score;
+            }
+            None => {
+                let reason = if outcome.exhausted {
+                    "state budget ran out before the search beat the greedy layout"
+                } else {
+                    "search found no layout scoring above the greedy layout"
+                };
+                report.fallback_reason = Some(reason.to_string());
+            }
+        }
+        tracing::info!(
+            used = ?report.used,
+            seed = options.seed,
+            states = report.states_explored,
+            greedy_score = report.greedy_score.total,
+            score = report.score.total,
+            fallback = ?report.fallback_reason,
+            "Layout engine: search finished"
+        );
+    }
+
+    report.elapsed_ms = started.elapsed().as_millis() as u64;
+    draft.report = Some(report);
+    draft
+}
+
+// ---------------------------------------------------------------------------
+// Objective
+// ---------------------------------------------------------------------------
+
+/// Score a layout whose rows are in final (rendered) order.
+pub fn score_layout(rows: &[BroadsheetRow], inputs: &LayoutInputs) -> LayoutScore {
+    let placed_weight: i32 = rows
+        .iter()
+        .flat_map(|r| &r.slots)
+        .filter_map(|s| inputs.post(s.post_id))
+        .map(|p| weight_score(&p.weight))
+        .sum();
+    let pool_weight: i32 = inputs.posts.iter().map(|p| weight_score(&p.weight)).sum();
+
+    let target_weight = target_term(placed_weight, pool_weight, inputs.target_content_weight);
+    let height_balance = height_balance_term(rows, inputs);
+    let family_cohesion = family_cohesion_term(rows, inputs);
+    let density_progression = density_progression_term(rows, inputs);
+    let topic_grouping = topic_grouping_term(rows, inputs);
+
+    let total = (TARGET_WEIGHT_COEF * target_weight
+        + HEIGHT_BALANCE_COEF * height_balance
+        + FAMILY_COHESION_COEF * family_cohesion
+        + DENSITY_PROGRESSION_COEF * density_progression
+        + TOPIC_GROUPING_COEF * topic_grouping)
+        / (TARGET_WEIGHT_COEF
+            + HEIGHT_BALANCE_COEF
+            + FAMILY_COHESION_COEF
+            + DENSITY_PROGRESSION_COEF
+            + TOPIC_GROUPING_COEF);
+
+    LayoutScore {
+        target_weight,
+        height_balance,
+        family_cohesion,
+        density_progression,
+        topic_grouping,
+        total,
+    }
+}
+
+/// 1.0 anywhere between the goal and the overshoot ceiling. The goal is the
+/// target, or the whole pool on weeks too thin to reach it.
+fn target_term(placed: i32, pool: i32, target: i32) -> f64 {
+    let goal = target.min(pool).max(1) as f64;
+    let ceiling = target.max(1) as f64 * OVERSHOOT;
+    let placed = placed as f64;
+    if placed < goal {
+        placed / goal
+    } else if placed <= ceiling {
+        1.0
+    } else {
+        (1.0 - (placed - ceiling) / goal).max(0.0)
+    }
+}
+
+/// Mean per-row balance. A row's balance is 1 minus the mean relative
+/// height gap between its stacked cells (non-anchor slot groups with
+/// count > 1 in a multi-cell row) and its anchor cell; rows without stacked
+/// cells count as balanced.
+fn height_balance_term(rows: &[BroadsheetRow], inputs: &LayoutInputs) -> f64 {
+    if rows.is_empty() {
+        return 1.0;
+    }
+    let balances: Vec<f64> = rows.iter().map(|row| row_balance(row, inputs)).collect();
+    mean(&balances)
+}
+
+fn row_balance(row: &BroadsheetRow, inputs: &LayoutInputs) -> f64 {
+    let Some(tws) = inputs.template_by_slug.get(row.row_template_slug.as_str()) else {
+        return 1.0;
+    };
+    let is_multi_cell = matches!(
+        tws.config.layout_variant.as_str(),
+        "lead-stack" | "pair-stack" | "trio" | "pair" | "classifieds"
+    );
+    if !is_multi_cell {
+        return 1.0;
+    }
+
+    let mut heights: BTreeMap<i32, i32> = BTreeMap::new();
+    for slot in &row.slots {
+        let post_type = inputs
+            .post(slot.post_id)
+            .map_or("", |p| p.post_type.as_str());
+        *heights.entry(slot.slot_index).or_insert(0) += effective_height(
+            &slot.post_template_slug,
+            post_type,
+            inputs.height_map,
+            inputs.height_override_map,
+        );
+    }
+    let Some(&anchor) = heights.get(&0) else {
+        return 1.0;
+    };
+
+    let gaps: Vec<f64> = tws
+        .slots
+        .iter()
+        .filter(|s| s.slot_index != 0 && s.count > 1)
+        .filter_map(|s| heights.get(&s.slot_index))
+        .map(|&stack| (stack - anchor).abs() as f64 / stack.max(anchor).max(1) as f64)
+        .collect();
+    1.0 - mean(&gaps)
+}
+
+/// Mean share of the most common post_type in each cell holding 2+ posts.
+fn family_cohesion_term(rows: &[BroadsheetRow], inputs: &LayoutInputs) -> f64 {
+    let mut shares: Vec<f64> = Vec::new();
+    for row in rows {
+        let mut cells: BTreeMap<i32, HashMap<&str, usize>> = BTreeMap::new();
+        for slot in &row.slots {
+            let post_type = inputs
+                .post(slot.post_id)
+                .map_or("", |p| p.post_type.as_str());
+            *cells
+                .entry(slot.slot_index)
+                .or_default()
+                .entry(post_type)
+                .or_insert(0) += 1;
+        }
+        for types in cells.values() {
+            let size: usize = types.values().sum();
+            if size < 2 {
+                continue;
+            }
+            let majority = types.values().copied().max().unwrap_or(0);
+            shares.push(majority as f64 / size as f64);
+        }
+    }
+    if shares.is_empty() {
+        1.0
+    } else {
+        mean(&shares)
+    }
+}
+
+/// 1 minus the fraction of row pairs whose density zones are out of order.
+fn density_progression_term(rows: &[BroadsheetRow], inputs: &LayoutInputs) -> f64 {
+    let zones: Vec<i32> = rows
+        .iter()
+        .map(|r| density_zone(r, &inputs.variant_map))
+        .collect();
+    let pairs = zones.len() * zones.len().saturating_sub(1) / 2;
+    if pairs == 0 {
+        return 1.0;
+    }
+    let inversions = (0..zones.len())
+        .flat_map(|i| (i + 1..zones.len()).map(move |j| (i, j)))
+        .filter(|&(i, j)| zones[i] > zones[j])
+        .count();
+    1.0 - inversions as f64 / pairs as f64
+}
+
+/// Distinct topics over topic sections: 1.0 when no topic is split in two.
+fn topic_grouping_term(rows: &[BroadsheetRow], inputs: &LayoutInputs) -> f64 {
+    let sections = build_topic_sections(rows, inputs.posts);
+    if sections.is_empty() {
+        return 1.0;
+    }
+    let distinct: HashSet<&str> = sections
+        .iter()
+        .filter_map(|s| s.topic_slug.as_deref())
+        .collect();
+    distinct.len() as f64 / sections.len() as f64
+}
+
+fn mean(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        0.0
+    } else {
+        values.iter().sum::<f64>() / values.len() as f64
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Beam search
+// ---------------------------------------------------------------------------
+
+/// A partial layout: rows in the order they were chosen.
+#[derive(Clone)]
+struct Partial<'a> {
+    rows: Vec<BroadsheetRow>,
+    variants: Vec<&'a str>,
+    placed: Vec<bool>,
+    weight: i32,
+    catchall_rows: usize,
+}
+
+/// A scored partial layout, with its rows in rendered order.
+struct Node<'a> {
+    partial: Partial<'a>,
+    rows: Vec<BroadsheetRow>,
+    score: LayoutScore,
+    tiebreak: u64,
+}
+
+struct SearchOutcome {
+    /// Best layout found, if it beat the greedy baseline.
+    best: Option<ScoredLayout>,
+    states_explored: usize,
+    exhausted: bool,
+}
+
+struct ScoredLayout {
+    rows: Vec<BroadsheetRow>,
+    score: LayoutScore,
+}
+
+fn beam_search(
+    inputs: &LayoutInputs,
+    options: &LayoutOptions,
+    baseline: f64,
+) -> SearchOutcome {
+    let budget = options.max_states.min(MAX_STATES);
+    let beam_width = options.beam_width.clamp(1, MAX_BEAM_WIDTH);
+    let ceiling = (inputs.target_content_weight as f64 * OVERSHOOT).round() as i32;
+    let mut rng = StdRng::seed_from_u64(options.seed);
+
+    let mut frontier = vec![Partial {
+        rows: Vec::new(),
+        variants: Vec::new(),
+        placed: vec![false; inputs.posts.len()],
+        weight: 0,
+        catchall_rows: 0,
+    }];
+    // The same rows chosen in a different order render identically once
+    // sorted by density, so expand each set of choices once.
+    let mut seen: HashSet<(Vec<Uuid>, Vec<bool>)> = HashSet::new();
+    let mut outcome = SearchOutcome {
+        best: None,
+        states_explored: 0,
+        exhausted: false,
+    };
+    let mut best_total = baseline;
+
+    for _ in 0..MAX_ROWS {
+        let mut children: Vec<Node> = Vec::new();
+        for partial in &frontier {
+            if outcome.states_explored >= budget {
+                outcome.exhausted = true;
+                break;
+            }
+            for child in expand(partial, inputs, ceiling) {
+                if outcome.states_explored >= budget {
+                    outcome.exhausted = true;
+                    break;
+                }
+                let mut key: Vec<Uuid> = child.rows.iter().map(|r| r.row_template_id).collect();
+                key.sort();
+                if !seen.insert((key, child.placed.clone())) {
+                    continue;
+                }
+                outcome.states_explored += 1;
+
+                let mut rows = child.rows.clone();
+                order_rows_by_density(&mut rows, inputs.templates);
+                let score = score_layout(&rows, inputs);
+                children.push(Node {
+                    partial: child,
+                    rows,
+                    score,
+                    tiebreak: rng.gen(),
+                });
+            }
+        }
+        if children.is_empty() {
+            break;
+        }
+
+        children.sort_by(|a, b| {
+            b.score
+                .total
+                .partial_cmp(&a.score.total)
+                .unwrap_or(Ordering::Equal)
+                .then(a.tiebreak.cmp(&b.tiebreak))
+        });
+        children.truncate(beam_width);
+
+        let leader = &children[0];
+        if leader.score.total > best_total + EPSILON {
+            best_total = leader.score.total;
+            outcome.best = Some(ScoredLayout {
+                rows: leader.rows.clone(),
+                score: leader.score.clone(),
+            });
+        }
+        if outcome.exhausted {
+            break;
+        }
+        frontier = children.into_iter().map(|n| n.partial).collect();
+    }
+
+    outcome
+}
+
+/// Every one-row extension of `partial`. Catchall templates are only tried
+/// once no other template fits, as in the greedy spillover phase.
+fn expand<'a>(partial: &Partial<'a>, inputs: &LayoutInputs<'a>, ceiling: i32) -> Vec<Partial<'a>> {
+    if partial.weight >= ceiling {
+        return Vec::new();
+    }
+
+    let candidates = inputs
+        .templates
+        .iter()
+        .filter(|t| !t.slots.is_empty() && !t.config.slug.starts_with("widget"));
+    let (catchalls, regular): (Vec<_>, Vec<_>) =
+        candidates.partition(|t| t.config.slug.starts_with("catchall-"));
+
+    let mut children = Vec::new();
+    for tws in regular {
+        let variant = tws.config.layout_variant.as_str();
+        // Same variety rules as pick_best_template: no variant back-to-back,
+        // at most two full-width rows.
+        if partial.variants.last() == Some(&variant) {
+            continue;
+        }
+        if variant == "full" && partial.variants.iter().filter(|v| **v == "full").count() >= 2 {
+            continue;
+        }
+        if let Some(child) = extend(partial, tws, inputs, false) {
+            children.push(child);
+        }
+    }
+
+    if children.is_empty() && partial.catchall_rows < MAX_CATCHALL_ROWS {
+        for tws in catchalls {
+            if let Some(child) = extend(partial, tws, inputs, true) {
+                children.push(child);
+            }
+        }
+    }
+    children
+}
+
+fn extend<'a>(
+    partial: &Partial<'a>,
+    tws: &'a RowTemplateWithSlots,
+    inputs: &LayoutInputs<'a>,
+    catchall: bool,
+) -> Option<Partial<'a>> {
+    let mut placed = partial.placed.clone();
+    let row = fill_row(
+        &tws.config,
+        tws,
+        inputs.posts,
+        &mut placed,
+        inputs.post_templates,
+        inputs.height_map,
+        inputs.height_override_map,
+    )?;
+    let row_weight: i32 = row
+        .slots
+        .iter()
+        .filter_map(|s| inputs.post(s.post_id))
+        .map(|p| weight_score(&p.weight))
+        .sum();
+
+    let mut child = partial.clone();
+    child.rows.push(row);
+    child.variants.push(tws.config.layout_variant.as_str());
+    child.placed = placed;
+    child.weight += row_weight;
+    if catchall {
+        child.catchall_rows += 1;
+    }
+    Some(child)
+}
+
+// =============================================================================
+// Unit tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domains::editions::data::types::BroadsheetSlot;
+    use crate::domains::editions::models::row_template_config::RowTemplateConfig;
+    use crate::domains::editions::models::row_template_slot::RowTemplateSlot;
+    use chrono::Utc;
+
+    fn post(weight: &str, post_type: &str, priority: i32, topic: Option<&str>) -> LayoutPost {
+        LayoutPost {
+            id: Uuid::new_v4(),
+            post_type: post_type.to_string(),
+            weight: weight.to_string(),
+            priority,
+            topic_slug: topic.map(|t| t.to_string()),
+            next_occurrence: None,
+        }
+    }
+
+    fn post_template(slug: &str, weight: &str, height_units: i32) -> PostTemplateConfig {
+        PostTemplateConfig {
+            id: Uuid::new_v4(),
+            slug: slug.to_string(),
+            display_name: slug.to_string(),
+            description: None,
+            compatible_types: ["story", "event", "update"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            body_target: 100,
+            body_max: 200,
+            title_max: 80,
+            sort_order: 0,
+            weight: weight.to_string(),
+            height_units,
+            height_override: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// (slot_index, weight, count_min, count_max, post template)
+    fn row_template(
+        slug: &str,
+        variant: &str,
+        slots: &[(i32, &str, i32, i32, &str)],
+    ) -> RowTemplateWithSlots {
+        let id = Uuid::new_v4();
+        RowTemplateWithSlots {
+            config: RowTemplateConfig {
+                id,
+                slug: slug.to_string(),
+                display_name: slug.to_string(),
+                description: None,
+                layout_variant: variant.to_string(),
+                sort_order: 0,
+                created_at: Utc::now(),
+            },
+            slots: slots
+                .iter()
+                .map(
+                    |&(slot_index, weight, count_min, count_max, template)| RowTemplateSlot {
+                        id: Uuid::new_v4(),
+                        row_template_config_id: id,
+                        slot_index,
+                        weight: weight.to_string(),
+                        count: count_max,
+                        count_min,
+                        count_max,
+                        accepts: None,
+                        post_template_slug: Some(template.to_string()),
+                    },
+                )
+                .collect(),
+        }
+    }
+
+    struct Fixture {
+        posts: Vec<LayoutPost>,
+        templates: Vec<RowTemplateWithSlots>,
+        post_templates: Vec<PostTemplateConfig>,
+        height_map: HashMap<String, i32>,
+        overrides: HashMap<(String, String), i32>,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            let mut posts = vec![
+                post("heavy", "story", 95, Some("housing")),
+                post("heavy", "story", 85, Some("food-access")),
+            ];
+            for (i, t) in ["story", "story", "story", "event", "event", "event"]
+                .iter()
+                .enumerate()
+            {
+                let topic = if i % 2 == 0 { "housing" } else { "food-access" };
+                posts.push(post("medium", t, 70 - i as i32, Some(topic)));
+            }
+            for i in 0..10 {
+                posts.push(post("light", "update", 40 - i, None));
+            }
+
+            let post_templates = vec![
+                post_template("feature", "heavy", 6),
+                post_template("gazette", "medium", 3),
+                post_template("digest", "light", 1),
+            ];
+            let templates = vec![
+                row_template(
+                    "hero-digest",
+                    "lead-stack",
+                    &[(0, "heavy", 1, 1, "feature"), (1, "light", 2, 4, "digest")],
+                ),
+                row_template(
+                    "pair-gazette",
+                    "pair",
+                    &[
+                        (0, "medium", 1, 1, "gazette"),
+                        (1, "medium", 1, 1, "gazette"),
+                    ],
+                ),
+                row_template(
+                    "trio-gazette",
+                    "trio",
+                    &[
+                        (0, "medium", 1, 1, "gazette"),
+                        (1, "medium", 1, 1, "gazette"),
+                        (2, "medium", 1, 1, "gazette"),
+                    ],
+                ),
+                row_template("ticker", "full", &[(0, "light", 3, 6, "digest")]),
+            ];
+            let height_map = post_templates
+                .iter()
+                .map(|pt| (pt.slug.clone(), pt.height_units))
+                .collect();
+
+            Self {
+                posts,
+                templates,
+                post_templates,
+                height_map,
+                overrides: HashMap::new(),
+            }
+        }
+
+        fn inputs(&self, target: i32) -> LayoutInputs<'_> {
+            LayoutInputs::new(
+                &self.posts,
+                &self.templates,
+                &self.post_templates,
+                &self.height_map,
+                &self.overrides,
+                target,
+            )
+        }
+
+        fn row(&self, template: &str, post_indices: &[(usize, i32)]) -> BroadsheetRow {
+            let tws = self
+                .templates
+                .iter()
+                .find(|t| t.config.slug == template)
+                .unwrap();
+            let slots: Vec<BroadsheetSlot> = post_indices
+                .iter()
+                .map(|&(i, slot_index)| {
+                    let slot_def = tws
+                        .slots
+                        .iter()
+                        .find(|s| s.slot_index == slot_index)
+                        .unwrap();
+                    BroadsheetSlot {
+                        post_id: self.posts[i].id,
+                        post_template_slug: slot_def.post_template_slug.clone().unwrap(),
+                        slot_index,
+                        pinned: false,
+                    }
+                })
+                .collect();
+            BroadsheetRow {
+                row_template_slug: template.to_string(),
+                row_template_id: tws.config.id,
+                max_priority: post_indices
+                    .iter()
+                    .map(|&(i, _)| self.posts[i].priority)
+                    .max()
+                    .unwrap(),
+                slots,
+                pinned: false,
+            }
+        }
+    }
+
+    fn search(seed: u64, max_states: usize) -> LayoutOptions {
+        LayoutOptions {
+            strategy: LayoutStrategy::Search,
+            seed,
+            beam_width: 4,
+            max_states,
+        }
+    }
+
+    fn placements(draft: &BroadsheetDraft) -> Vec<(String, Vec<Uuid>)> {
+        draft
+            .rows
+            .iter()
+            .map(|r| {
+                (
+                    r.row_template_slug.clone(),
+                    r.slots.iter().map(|s| s.post_id).collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn score_penalises_inverted_density_and_split_topics() {
+        let f = Fixture::new();
+        let inputs = f.inputs(20);
+        let hero = f.row("hero-digest", &[(0, 0), (8, 1), (9, 1)]);
+        let pair = f.row("pair-gazette", &[(2, 0), (4, 1)]);
+        let ticker = f.row("ticker", &[(10, 0), (11, 0), (12, 0)]);
+
+        let ordered = score_layout(&[hero.clone(), pair.clone(), ticker.clone()], &inputs);
+        let inverted = score_layout(&[ticker, pair, hero], &inputs);
+        assert_eq!(ordered.density_progression, 1.0);
+        assert_eq!(inverted.density_progression, 0.0);
+        assert!(ordered.total > inverted.total);
+
+        // housing, food-access, housing: two sections for one topic
+        let split = [
+            f.row("pair-gazette", &[(2, 0), (4, 1)]),
+            f.row("trio-gazette", &[(3, 0), (5, 1), (7, 2)]),
+            f.row("pair-gazette", &[(6, 0), (0, 1)]),
+        ];
+        let score = score_layout(&split, &inputs);
+        assert!((score.topic_grouping - 2.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn score_measures_stack_height_against_anchor() {
+        let f = Fixture::new();
+        let inputs = f.inputs(20);
+        // feature (6) beside a 2-digest stack (2): gap of 4/6
+        let short = f.row("hero-digest", &[(0, 0), (8, 1), (9, 1)]);
+        let score = score_layout(&[short], &inputs);
+        assert!((score.height_balance - (1.0 - 4.0 / 6.0)).abs() < 1e-9);
+
+        let taller = f.row("hero-digest", &[(0, 0), (8, 1), (9, 1), (10, 1), (11, 1)]);
+        assert!(score_layout(&[taller], &inputs).height_balance > score.height_balance);
+    }
+
+    #[test]
+    fn greedy_strategy_reports_its_score() {
+        let f = Fixture::new();
+        let draft = place_with_options(&f.inputs(20), &LayoutOptions::default());
+        let report = draft.report.expect("report");
+
+        assert_eq!(report.used, LayoutStrategy::Greedy);
+        assert_eq!(report.states_explored, 0);
+        assert_eq!(report.score, report.greedy_score);
+        assert!(report.score.total > 0.0);
+    }
+
+    #[test]
+    fn search_is_seeded_and_beats_greedy_on_a_thin_week() {
+        let f = Fixture::new();
+        // Target above the pool's weight (28): the best layout places the most.
+        let first = place_with_options(&f.inputs(30), &search(7, 10_000));
+        let second = place_with_options(&f.inputs(30), &search(7, 10_000));
+        assert_eq!(placements(&first), placements(&second));
+
+        let report = first.report.expect("report");
+        assert_eq!(report.requested, LayoutStrategy::Search);
+        assert_eq!(report.used, LayoutStrategy::Search);
+        assert!(report.fallback_reason.is_none());
+        assert!(report.states_explored > 0);
+        assert!(report.score.total > report.greedy_score.total);
+        assert!(report.score.target_weight > report.greedy_score.target_weight);
+        assert_eq!(first.rows[0].row_template_slug, "hero-digest");
+    }
+
+    #[test]
+    fn exhausted_state_budget_falls_back_to_greedy() {
+        let f = Fixture::new();
+        let greedy = place_with_options(&f.inputs(20), &LayoutOptions::default());
+        let draft = place_with_options(&f.inputs(20), &search(7, 0));
+        let report = draft.report.as_ref().expect("report");
+
+        assert_eq!(report.used, LayoutStrategy::Greedy);
+        assert_eq!(report.states_explored, 0);
+        assert!(report
+            .fallback_reason
+            .as_deref()
+            .unwrap()
+            .contains("state budget"));
+        assert_eq!(placements(&draft), placements(&greedy));
+    }
+}
@@ -0,0 +1,547 @@
+//! Layout trace — why each candidate post did or didn't make an edition.
+//!
+//! `load_county_posts` filters on status, geography and the date window in
+//! one query, and `place_posts` drops posts that don't fit without saying
+//! why. The trace selects the same clause columns (`ELIGIBILITY_CLAUSES`)
+//! for every candidate post without filtering on them, then explains each
+//! eligible post's placement against the finished draft. `generate_edition` stores it per edition and
+//! `/Editions/layout_trace` serves it.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use chrono::NaiveDate;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::common::utils::slugs::county_service_area_slug;
+use crate::domains::editions::data::types::{
+    BroadsheetDraft, EligibilityClause, LayoutPost, LayoutTrace, PostPlacement, PostTrace,
+    UnplacedReason,
+};
+use crate::domains::editions::models::post_template_config::PostTemplateConfig;
+use crate::domains::editions::models::row_template_config::RowTemplateWithSlots;
+
+use super::layout_engine::{
+    load_event_occurrences, resolve_post_template, weight_score, ELIGIBILITY_CLAUSES,
+    GEOGRAPHY_MATCH,
+};
+use super::layout_search::LayoutInputs;
+
+/// Non-active posts whose status changed within this many days of the
+/// period start are still traced, so "why did it drop out?" has an answer.
+const RECENT_STATUS_CHANGE_DAYS: i32 = 14;
+
+/// One candidate post with each eligibility clause evaluated separately
+/// (the `ELIGIBILITY_CLAUSES` columns `load_county_posts` filters on).
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct CandidateRow {
+    pub id: Uuid,
+    pub title: String,
+    pub status: String,
+    pub post_type: Option<String>,
+    pub weight: Option<String>,
+    pub priority: Option<i32>,
+    /// Not waiting on a future `published_at`.
+    pub not_embargoed: bool,
+    /// `posts.county_id` is this county.
+    pub resolved_county: bool,
+    /// No resolved county yet, and the primary location's ZIP maps here.
+    pub zip_county: bool,
+    pub service_area_tag: bool,
+    pub statewide_tag: bool,
+    pub statewide_service_area: bool,
+    pub no_location: bool,
+    pub evergreen: bool,
+    pub unpublished: bool,
+    pub published_in_window: bool,
+    pub upcoming_event: bool,
+    /// Set after loading, by expanding the post's recurring schedules.
+    #[sqlx(default)]
+    pub recurring_event: bool,
+}
+
+impl CandidateRow {
+    /// The post as the layout engine sees it (same defaults as the loaders).
+    fn layout_post(&self) -> LayoutPost {
+        LayoutPost {
+            id: self.id,
+            post_type: self
+                .post_type
+                .clone()
+                .unwrap_or_else(|| "update".to_string()),
+            weight: self.weight.clone().unwrap_or_else(|| "medium".to_string()),
+            priority: self.priority.unwrap_or(50),
+            topic_slug: None,
+            next_occurrence: None,
+        }
+    }
+
+    /// Evaluate each clause. County editions accept any of the county
+    /// geography clauses; statewide editions only the statewide
+    /// service_area tag.
+    pub fn clauses(&self, statewide: bool) -> Vec<EligibilityClause> {
+        let clause = |group: &str, clause: &str, passed: bool| EligibilityClause {
+            group: group.to_string(),
+            clause: clause.to_string(),
+            passed,
+        };
+
+        let mut clauses = vec![
+            clause("status", "active", self.status == "active"),
+            clause("status", "not_embargoed", self.not_embargoed),
+        ];
+        if statewide {
+            clauses.push(clause(
+                "geography",
+                "statewide_service_area",
+                self.statewide_service_area,
+            ));
+        } else {
+            clauses.extend([
+                clause("geography", "resolved_county", self.resolved_county),
+                clause("geography", "zip_county", self.zip_county),
+                clause("geography", "service_area_tag", self.service_area_tag),
+                clause("geography", "statewide_tag", self.statewide_tag),
+                clause("geography", "no_location", self.no_location),
+            ]);
+        }
+        clauses.extend([
+            clause("window", "evergreen", self.evergreen),
+            clause("window", "unpublished", self.unpublished),
+            clause("window", "published_in_window", self.published_in_window),
+            clause("window", "upcoming_event", self.upcoming_event),
+            clause("window", "recurring_event", self.recurring_event),
+        ]);
+        clauses
+    }
+}
+
+/// Eligible when every status clause passes and at least one geography
+/// and one window clause pass.
+pub fn is_eligible(clauses: &[EligibilityClause]) -> bool {
+    let any = |group: &str| clauses.iter().any(|c| c.group == group && c.passed);
+    let all = |group: &str| {
+        clauses
+            .iter()
+            .filter(|c| c.group == group)
+            .all(|c| c.passed)
+    };
+    all("status") && any("geography") && any("window")
+}
+
+/// Load every candidate post for a county's edition with its clauses.
+pub async fn load_candidates(
+    county_id: Uuid,
+    period_start: NaiveDate,
+    pool: &PgPool,
+) -> Result<(bool, Vec<CandidateRow>)> {
+    let (county_name, is_pseudo): (String, bool) =
+        sqlx::query_as("SELECT name, is_pseudo FROM counties WHERE id = $1")
+            .bind(county_id)
+            .fetch_one(pool)
+            .await?;
+    let service_area = county_service_area_slug(&county_name);
+
+    let sql = format!(
+        r#"
+        WITH clauses AS (
+            SELECT p.id, p.title, p.status, p.post_type, p.weight, p.priority, p.updated_at, {}
+            FROM posts p
+        )
+        SELECT
+            id, title, status, post_type, weight, priority, not_embargoed, resolved_county,
+            zip_county, service_area_tag, statewide_tag, statewide_service_area, no_location,
+            evergreen, unpublished, published_in_window, upcoming_event
+        FROM clauses
+        WHERE (status = 'active' OR updated_at >= ($2::date - make_interval(days => $5)))
+          AND {}
+        ORDER BY priority DESC NULLS LAST, id
+        "#,
+        ELIGIBILITY_CLAUSES, GEOGRAPHY_MATCH
+    );
+    let rows = sqlx::query_as::<_, CandidateRow>(&sql)
+        .bind(county_id)
+        .bind(period_start)
+        .bind(&service_area)
+        .bind(is_pseudo)
+        .bind(RECENT_STATUS_CHANGE_DAYS)
+        .fetch_all(pool)
+        .await?;
+
+    let post_ids: Vec<Uuid> = rows.iter().map(|r| r.id).collect();
+    let occurrences = load_event_occurrences(&post_ids, period_start, pool).await?;
+    let rows = rows
+        .into_iter()
+        .map(|mut r| {
+            r.recurring_event = occurrences.get(&r.id).is_some_and(|o| o.recurring);
+            r
+        })
+        .collect();
+
+    Ok((is_pseudo, rows))
+}
+
+/// Build the trace for a finished draft. Pure function — no I/O.
+pub fn build_trace(
+    county_id: Uuid,
+    period_start: NaiveDate,
+    statewide: bool,
+    candidates: &[CandidateRow],
+    draft: &BroadsheetDraft,
+    inputs: &LayoutInputs,
+) -> LayoutTrace {
+    // post_id -> (row index, slot index, post template)
+    let mut placed: HashMap<Uuid, (usize, i32, &str)> = HashMap::new();
+    for (row_index, row) in draft.rows.iter().enumerate() {
+        for slot in &row.slots {
+            placed.insert(
+                slot.post_id,
+                (row_index, slot.slot_index, slot.post_template_slug.as_str()),
+            );
+        }
+    }
+    let by_id: HashMap<Uuid, &CandidateRow> = candidates.iter().map(|c| (c.id, c)).collect();
+    let placed_weight: i32 = placed
+        .keys()
+        .filter_map(|id| by_id.get(id))
+        .map(|c| weight_score(&c.layout_post().weight))
+        .sum();
+
+    let posts = candidates
+        .iter()
+        .map(|candidate| {
+            let post = candidate.layout_post();
+            let clauses = candidate.clauses(statewide);
+            let eligible = is_eligible(&clauses);
+
+            let placement = match placed.get(&post.id) {
+                Some(&(row_index, slot_index, post_template_slug)) => PostPlacement::Placed {
+                    row_index,
+                    row_template_slug: draft.rows[row_index].row_template_slug.clone(),
+                    slot_index,
+                    post_template_slug: post_template_slug.to_string(),
+                },
+                None if !eligible => PostPlacement::Ineligible,
+                None => unplaced(
+                    &post,
+                    draft,
+                    inputs.templates,
+                    inputs.post_templates,
+                    placed_weight >= inputs.target_content_weight,
+                ),
+            };
+
+            PostTrace {
+                post_id: post.id,
+                title: candidate.title.clone(),
+                status: candidate.status.clone(),
+                post_type: post.post_type,
+                weight: post.weight,
+                priority: post.priority,
+                eligible,
+                clauses,
+                placement,
+            }
+        })
+        .collect();
+
+    LayoutTrace {
+        county_id,
+        period_start,
+        statewide,
+        target_content_weight: inputs.target_content_weight,
+        placed_weight,
+        layout: draft.report.clone(),
+        posts,
+    }
+}
+
+/// Explain why an eligible post has no slot, from the most to the least
+/// fundamental cause.
+fn unplaced(
+    post: &LayoutPost,
+    draft: &BroadsheetDraft,
+    templates: &[RowTemplateWithSlots],
+    post_templates: &[PostTemplateConfig],
+    target_met: bool,
+) -> PostPlacement {
+    let result =
+        |reason: UnplacedReason, detail: String, compatible: Vec<String>| PostPlacement::Unplaced {
+            reason,
+            detail,
+            compatible_row_templates: compatible,
+        };
+
+    let has_post_template = post_templates
+        .iter()
+        .any(|pt| pt.is_compatible(&post.post_type) && pt.weight == post.weight);
+    if !has_post_template {
+        return result(
+            UnplacedReason::NoPostTemplate,
+            format!(
+                "No post template renders a {} post at {} weight.",
+                post.post_type, post.weight
+            ),
+            Vec::new(),
+        );
+    }
+
+    let compatible: Vec<String> = templates
+        .iter()
+        .filter(|t| !t.config.slug.starts_with("widget"))
+        .filter(|t| {
+            t.slots.iter().any(|slot| {
+                slot.weight == post.weight
+                    && slot.accepts_type(&post.post_type)
+                    && resolve_post_template(post, slot, post_templates).is_some()
+            })
+        })
+        .map(|t| t.config.slug.clone())
+        .collect();
+    if compatible.is_empty() {
+        return result(
+            UnplacedReason::NoRowTemplate,
+            format!(
+                "No row template has a {} slot that accepts {} posts.",
+                post.weight, post.post_type
+            ),
+            compatible,
+        );
+    }
+
+    let used: Vec<usize> = draft
+        .rows
+        .iter()
+        .enumerate()
+        .filter(|(_, row)| compatible.contains(&row.row_template_slug))
+        .map(|(i, _)| i)
+        .collect();
+    if !used.is_empty() {
+        let rows = used
+            .iter()
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        return result(
+            UnplacedReason::Outcompeted,
+            format!(
+                "Rows {} could hold it but were filled by other posts.",
+                rows
+            ),
+            compatible,
+        );
+    }
+    if target_met {
+        return result(
+            UnplacedReason::WeightTargetMet,
+            "The edition reached its weight target before a row that fits it was chosen."
+                .to_string(),
+            compatible,
+        );
+    }
+    result(
+        UnplacedReason::NotSelected,
+        "No row that fits it was chosen (variety rules, or its other slots couldn't be filled)."
+            .to_string(),
+        compatible,
+    )
+}
+
+// =============================================================================
+// Unit tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domains::editions::data::types::{BroadsheetRow, BroadsheetSlot};
+    use crate::domains::editions::models::row_template_config::RowTemplateConfig;
+    use crate::domains::editions::models::row_template_slot::RowTemplateSlot;
+    use chrono::Utc;
+
+    fn candidate(post_type: &str, weight: &str) -> CandidateRow {
+        CandidateRow {
+            id: Uuid::new_v4(),
+            title: format!("A {} post", post_type),
+            status: "active".to_string(),
+            post_type: Some(post_type.to_string()),
+            weight: Some(weight.to_string()),
+            priority: Some(50),
+            not_embargoed: true,
+            resolved_county: false,
+            zip_county: true,
+            service_area_tag: false,
+            statewide_tag: false,
+            statewide_service_area: false,
+            no_location: false,
+            evergreen: false,
+            unpublished: false,
+            published_in_window: true,
+            upcoming_event: false,
+            recurring_event: false,
+        }
+    }
+
+    fn post_template(slug: &str, weight: &str, compatible: &[&str]) -> PostTemplateConfig {
+        PostTemplateConfig {
+            id: Uuid::new_v4(),
+            slug: slug.to_string(),
+            display_name: slug.to_string(),
+            description: None,
+            compatible_types: compatible.iter().map(|s| s.to_string()).collect(),
+            body_target: 100,
+            body_max: 200,
+            title_max: 80,
+            sort_order: 0,
+            weight: weight.to_string(),
+            height_units: 2,
+            height_override: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn row_template(slug: &str, weight: &str, post_template_slug: &str) -> RowTemplateWithSlots {
+        let id = Uuid::new_v4();
+        RowTemplateWithSlots {
+            config: RowTemplateConfig {
+                id,
+                slug: slug.to_string(),
+                display_name: slug.to_string(),
+                description: None,
+                layout_variant: "full".to_string(),
+                sort_order: 0,
+                created_at: Utc::now(),
+            },
+            slots: vec![RowTemplateSlot {
+                id: Uuid::new_v4(),
+                row_template_config_id: id,
+                slot_index: 0,
+                weight: weight.to_string(),
+                count: 1,
+                count_min: 1,
+                count_max: 1,
+                accepts: None,
+                post_template_slug: Some(post_template_slug.to_string()),
+            }],
+        }
+    }
+
+    fn draft_with(rows: Vec<(&RowTemplateWithSlots, Uuid)>) -> BroadsheetDraft {
+        BroadsheetDraft {
+            rows: rows
+                .into_iter()
+                .map(|(tws, post_id)| BroadsheetRow {
+                    row_template_slug: tws.config.slug.clone(),
+                    row_template_id: tws.config.id,
+                    slots: vec![BroadsheetSlot {
+                        post_id,
+                        post_template_slug: tws.slots[0].post_template_slug.clone().unwrap(),
+                        slot_index: 0,
+                        pinned: false,
+                    }],
+                    max_priority: 50,
+                    pinned: false,
+                })
+                .collect(),
+            sections: vec![],
+            widget_rows: vec![],
+            report: None,
+            trace: None,
+        }
+    }
+
+    #[test]
+    fn eligibility_needs_status_geography_and_window() {
+        let mut row = candidate("story", "medium");
+        assert!(is_eligible(&row.clauses(false)));
+
+        row.published_in_window = false;
+        let clauses = row.clauses(false);
+        assert!(!is_eligible(&clauses));
+        assert!(clauses
+            .iter()
+            .filter(|c| c.group == "window")
+            .all(|c| !c.passed));
+
+        row.evergreen = true;
+        row.status = "expired".to_string();
+        assert!(!is_eligible(&row.clauses(false)));
+
+        // Embargoed posts fail the status group even while active
+        let mut row = candidate("story", "medium");
+        row.not_embargoed = false;
+        assert!(!is_eligible(&row.clauses(false)));
+
+        // Statewide editions only look at the statewide service_area tag
+        let row = candidate("story", "medium");
+        assert!(!is_eligible(&row.clauses(true)));
+    }
+
+    #[test]
+    fn trace_explains_placed_and_unplaced_posts() {
+        let post_templates = vec![
+            post_template("gazette", "medium", &["story", "event"]),
+            post_template("digest", "light", &["update"]),
+        ];
+        let gazette = row_template("gazette-row", "medium", "gazette");
+        let templates = vec![gazette.clone(), row_template("ticker", "light", "digest")];
+
+        let placed = candidate("story", "medium");
+        let outcompeted = candidate("event", "medium");
+        let not_selected = candidate("update", "light");
+        let no_template = candidate("update", "heavy");
+        let mut ineligible = candidate("story", "medium");
+        ineligible.status = "draft".to_string();
+
+        let candidates = vec![
+            placed.clone(),
+            outcompeted.clone(),
+            not_selected.clone(),
+            no_template.clone(),
+            ineligible.clone(),
+        ];
+        let draft = draft_with(vec![(&gazette, placed.id)]);
+        let posts: Vec<LayoutPost> = candidates.iter().map(|c| c.layout_post()).collect();
+        let (heights, overrides) = (HashMap::new(), HashMap::new());
+        let inputs = LayoutInputs::new(
+            &posts,
+            &templates,
+            &post_templates,
+            &heights,
+            &overrides,
+            20,
+        );
+        let trace = build_trace(
+            Uuid::new_v4(),
+            NaiveDate::from_ymd_opt(2026, 10, 12).unwrap(),
+            false,
+            &candidates,
+            &draft,
+            &inputs,
+        );
+
+        assert_eq!(trace.placed_weight, 2);
+        let placement = |id: Uuid| {
+            trace
+                .posts
+                .iter()
+                .find(|p| p.post_id == id)
+                .map(|p| p.placement.clone())
+                .unwrap()
+        };
+        assert!(matches!(
+            placement(placed.id),
+            PostPlacement::Placed { row_index: 0, ref post_template_slug, .. }
+                if post_template_slug == "gazette"
+        ));
+        let reason = |id: Uuid| match placement(id) {
+            PostPlacement::Unplaced { reason, .. } => Some(reason),
+            _ => None,
+        };
+        assert_eq!(reason(outcompeted.id), Some(UnplacedReason::Outcompeted));
+        assert_eq!(reason(not_selected.id), Some(UnplacedReason::NotSelected));
+        assert_eq!(reason(no_template.id), Some(UnplacedReason::NoPostTemplate));
+        assert_eq!(placement(ineligible.id), PostPlacement::Ineligible);
+    }
+}
@@ -1,7 +1,20 @@
 //! Editions domain activities — layout engine + edition operations
 
+pub mod broadsheet;
+pub mod county_boundaries;
+pub mod edition_history;
 pub mod edition_ops;
 pub mod layout_engine;
+pub mod layout_pins;
+pub mod layout_search;
+pub mod layout_trace;
+pub mod scheduled_publish;
+pub mod template_authoring;
+pub mod widget_placement;
 
+pub use edition_history::record_lifecycle;
 pub use edition_ops::*;
 pub use layout_engine::*;
+pub use scheduled_publish::{
+    parse_publish_at, publish_scheduled_editions, schedule_edition_publish,
+};
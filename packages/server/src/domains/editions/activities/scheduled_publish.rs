@@ -0,0 +1,218 @@
+//! Scheduled edition publishing.
+//!
+//! Editors approve an edition and set `publish_at`; the
+//! `publish_scheduled_editions` job publishes it once that time passes.
+//! Publishing goes through `publish_edition`, so the populated-slots gate
+//! and the snapshot freeze apply exactly as they do for a manual publish.
+//! The seed-contamination gate is stricter here: the admin app lets an
+//! editor confirm publishing seed content, but nobody is around to confirm
+//! a scheduled run, so it refuses.
+//!
+//! Results are posted to the `editions` StreamHub topic.
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, LocalResult, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::domains::editions::models::edition::Edition;
+use crate::kernel::ServerDeps;
+
+use super::edition_history::record_lifecycle;
+use super::edition_ops::publish_edition;
+
+/// Editions are scheduled in Minnesota time.
+pub const EDITION_TIMEZONE: Tz = chrono_tz::America::Chicago;
+
+/// StreamHub topic for scheduled publish results.
+pub const EDITIONS_STREAM_TOPIC: &str = "editions";
+
+/// Parse a `publish_at` value. RFC 3339 timestamps keep their offset; a
+/// bare local time (`2026-10-19T06:00`) is read as America/Chicago. Times
+/// skipped by a DST change are rejected; repeated ones take the earlier.
+pub fn parse_publish_at(s: &str) -> Result<DateTime<Utc>> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+        return Ok(dt.with_timezone(&Utc));
+    }
+    let naive = NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S")
+        .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M"))
+        .map_err(|_| {
+            anyhow!(
+                "Invalid publish_at '{}': expected RFC 3339 or a local YYYY-MM-DDTHH:MM",
+                s
+            )
+        })?;
+    match EDITION_TIMEZONE.from_local_datetime(&naive) {
+        LocalResult::Single(dt) | LocalResult::Ambiguous(dt, _) => Ok(dt.with_timezone(&Utc)),
+        LocalResult::None => Err(anyhow!(
+            "publish_at {} does not exist in {} (skipped by a daylight saving change)",
+            s,
+            EDITION_TIMEZONE
+        )),
+    }
+}
+
+/// Set or clear an edition's scheduled publish time. The time must be in
+/// the future; the edition may still be in draft or review, but only an
+/// approved edition is published when the time comes.
+pub async fn schedule_edition_publish(
+    edition_id: Uuid,
+    publish_at: Option<DateTime<Utc>>,
+    deps: &ServerDeps,
+) -> Result<Edition> {
+    let pool = &deps.db_pool;
+
+    let edition = Edition::find_by_id(edition_id, pool)
+        .await?
+        .ok_or_else(|| anyhow!("Edition not found: {}", edition_id))?;
+
+    if publish_at.is_some() && !matches!(edition.status.as_str(), "draft" | "in_review" | "approved")
+    {
+        return Err(anyhow!("Cannot schedule a {} edition for publishing", edition.status));
+    }
+    if let Some(at) = publish_at {
+        if at <= Utc::now() {
+            return Err(anyhow!("publish_at must be in the future"));
+        }
+    }
+
+    Edition::schedule_publish(edition_id, publish_at, pool).await
+}
+
+/// Publish every edition whose `publish_at` has passed. Editions that
+/// aren't approved by then, or fail a gate, are left unpublished with the
+/// reason in `publish_error`. Returns the number published.
+pub async fn publish_scheduled_editions(deps: &ServerDeps) -> Result<u64> {
+    let pool = &deps.db_pool;
+    let due = Edition::find_due_for_publish(Utc::now(), pool).await?;
+
+    let mut published = 0u64;
+    for edition in due {
+        match publish_scheduled(&edition, deps).await {
+            Ok(edition) => {
+                published += 1;
+                info!(edition_id = %edition.id, "Scheduled edition published");
+                record_lifecycle(
+                    edition.id,
+                    None,
+                    "publish_edition",
+                    serde_json::json!({ "status": edition.status, "scheduled": true }),
+                    deps,
+                )
+                .await?;
+                deps.stream_hub
+                    .publish(
+                        EDITIONS_STREAM_TOPIC,
+                        serde_json::json!({
+                            "type": "edition_published",
+                            "edition_id": edition.id,
+                            "county_id": edition.county_id,
+                            "period_start": edition.period_start,
+                            "published_at": edition.published_at,
+                        }),
+                    )
+                    .await;
+            }
+            Err(e) => {
+                let message = e.to_string();
+                warn!(edition_id = %edition.id, error = %message, "Scheduled publish failed");
+                Edition::record_publish_failure(edition.id, &message, pool).await?;
+                record_lifecycle(
+                    edition.id,
+                    None,
+                    "scheduled_publish_failed",
+                    serde_json::json!({ "error": message }),
+                    deps,
+                )
+                .await?;
+                deps.stream_hub
+                    .publish(
+                        EDITIONS_STREAM_TOPIC,
+                        serde_json::json!({
+                            "type": "edition_publish_failed",
+                            "edition_id": edition.id,
+                            "county_id": edition.county_id,
+                            "period_start": edition.period_start,
+                            "error": message,
+                        }),
+                    )
+                    .await;
+            }
+        }
+    }
+    Ok(published)
+}
+
+async fn publish_scheduled(edition: &Edition, deps: &ServerDeps) -> Result<Edition> {
+    if edition.status != "approved" {
+        return Err(anyhow!(
+            "Edition was still {} at its scheduled publish time — only approved editions are published on schedule",
+            edition.status
+        ));
+    }
+    require_seed_free_edition(edition.id, &deps.db_pool).await?;
+    publish_edition(edition.id, deps).await
+}
+
+/// Guard: refuse editions with any dev-seed post or widget slotted.
+async fn require_seed_free_edition(edition_id: Uuid, pool: &sqlx::PgPool) -> Result<()> {
+    let n: i64 = sqlx::query_scalar(
+        r#"
+        SELECT COUNT(*)
+        FROM edition_slots es
+        JOIN edition_rows er ON er.id = es.edition_row_id
+        LEFT JOIN posts p ON p.id = es.post_id
+        LEFT JOIN widgets w ON w.id = es.widget_id
+        WHERE er.edition_id = $1
+          AND (p.is_seed = true OR w.is_seed = true)
+        "#,
+    )
+    .bind(edition_id)
+    .fetch_one(pool)
+    .await?;
+
+    if n > 0 {
+        return Err(anyhow!(
+            "Edition contains {} seed slot(s); swap them for real content before it can publish on schedule",
+            n
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn local_publish_times_are_chicago() {
+        // CDT (UTC-5)
+        assert_eq!(
+            parse_publish_at("2026-10-19T06:00").unwrap(),
+            Utc.with_ymd_and_hms(2026, 10, 19, 11, 0, 0).unwrap()
+        );
+        // CST (UTC-6)
+        assert_eq!(
+            parse_publish_at("2026-11-16T06:00:00").unwrap(),
+            Utc.with_ymd_and_hms(2026, 11, 16, 12, 0, 0).unwrap()
+        );
+        // Explicit offsets are kept
+        assert_eq!(
+            parse_publish_at("2026-10-19T06:00:00Z").unwrap(),
+            Utc.with_ymd_and_hms(2026, 10, 19, 6, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn dst_gaps_and_overlaps() {
+        // 2:30 on spring-forward day doesn't exist
+        assert!(parse_publish_at("2026-03-08T02:30").is_err());
+        // 1:30 on fall-back day happens twice; take the first (CDT)
+        assert_eq!(
+            parse_publish_at("2026-11-01T01:30").unwrap(),
+            Utc.with_ymd_and_hms(2026, 11, 1, 6, 30, 0).unwrap()
+        );
+        assert!(parse_publish_at("next monday").is_err());
+    }
+}
@@ -0,0 +1,851 @@
+//! Row-template and post-template authoring.
+//!
+//! Templates used to change only through hand-written migrations. Admins now
+//! create, update and delete them through `/Editions/*_template` endpoints.
+//! Every change is checked against the same rules the layout engine relies on:
+//!
+//! - **Slots**: at least one, counts within `0 ≤ count_min ≤ count ≤ count_max`,
+//!   and at least one slot that needs a post (a row of optional slots never
+//!   abandons, so it would swallow the pool).
+//! - **Weight tiers**: slot and post template weights are heavy/medium/light,
+//!   and a slot's named post template has the slot's weight.
+//! - **Compatibility**: every slot has a post template of its weight that
+//!   renders at least one of the types it accepts. A post template change is
+//!   rechecked against every existing row template, so narrowing
+//!   `compatible_types` can't leave a row unfillable.
+//! - **References**: nothing in `edition_rows`/`edition_slots` may be
+//!   orphaned — no deleting a template in use, no renaming a post template
+//!   that slots refer to by slug, no dropping a slot index an edition row
+//!   still fills, and no narrowing a post template away from posts it
+//!   already renders.
+//!
+//! Saves and deletes run these checks inside the writing transaction, with
+//! the template tables locked against other authoring writes.
+//!
+//! `widget*` row templates are placed by `place_widgets`, not by slot
+//! filling, and stay out of reach here.
+//!
+//! A dry run (`preview`) lays out a county's current pool with the proposed
+//! templates in place of the stored ones and writes nothing.
+
+use std::collections::HashSet;
+
+use chrono::{NaiveDate, Utc};
+use serde::Serialize;
+use sqlx::{PgConnection, PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::domains::editions::data::types::{
+    BroadsheetDraft, BroadsheetRow, LayoutOptions, PostTemplateDraft, RowTemplateDraft,
+};
+use crate::domains::editions::models::county::County;
+use crate::domains::editions::models::post_template_config::PostTemplateConfig;
+use crate::domains::editions::models::row_template_config::{
+    RowTemplateConfig, RowTemplateWithSlots,
+};
+use crate::domains::editions::models::row_template_slot::RowTemplateSlot;
+use crate::domains::posts::activities::ingest_post::{VALID_POST_TYPES, VALID_WEIGHTS};
+use crate::kernel::ServerDeps;
+
+use super::layout_engine::{fill_row, height_maps, load_county_posts};
+use super::layout_search::{self, LayoutInputs};
+
+/// CSS grid layouts the broadsheet renderer knows (web-app `row-map.ts`).
+const LAYOUT_VARIANTS: &[&str] = &["full", "lead", "lead-stack", "pair", "pair-stack", "trio"];
+/// Row templates with this slug prefix hold widgets, not posts.
+const WIDGET_PREFIX: &str = "widget";
+/// Largest slot group any shipped template uses is 6; leave headroom.
+const MAX_SLOT_COUNT: i32 = 12;
+/// `feature` is the tallest shipped template at 12 units.
+const MAX_HEIGHT_UNITS: i32 = 24;
+
+#[derive(Debug, thiserror::Error)]
+pub enum TemplateAuthoringError {
+    #[error("{0}")]
+    Invalid(String),
+    #[error("{0}")]
+    InUse(String),
+    #[error("{0}")]
+    NotFound(String),
+    #[error(transparent)]
+    Db(#[from] anyhow::Error),
+}
+
+type AuthoringResult<T> = std::result::Result<T, TemplateAuthoringError>;
+
+fn invalid(problems: Vec<String>) -> AuthoringResult<()> {
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(TemplateAuthoringError::Invalid(problems.join("; ")))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Field rules (pure)
+// ---------------------------------------------------------------------------
+
+fn check_slug(slug: &str, problems: &mut Vec<String>) {
+    let well_formed = !slug.is_empty()
+        && !slug.starts_with('-')
+        && !slug.ends_with('-')
+        && slug
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+    if !well_formed {
+        problems.push(format!(
+            "slug '{slug}' must be lowercase letters, digits and inner hyphens"
+        ));
+    }
+}
+
+fn check_post_types(field: &str, types: &[String], problems: &mut Vec<String>) {
+    if types.is_empty() {
+        problems.push(format!("{field} must list at least one post type"));
+    }
+    for t in types {
+        if !VALID_POST_TYPES.contains(&t.as_str()) {
+            problems.push(format!(
+                "{field}: unknown post type '{t}' (expected {})",
+                VALID_POST_TYPES.join("|")
+            ));
+        }
+    }
+    let distinct: HashSet<&String> = types.iter().collect();
+    if distinct.len() != types.len() {
+        problems.push(format!("{field} lists a post type twice"));
+    }
+}
+
+fn check_weight(field: &str, weight: &str, problems: &mut Vec<String>) {
+    if !VALID_WEIGHTS.contains(&weight) {
+        problems.push(format!(
+            "{field} '{weight}' must be one of {}",
+            VALID_WEIGHTS.join("|")
+        ));
+    }
+}
+
+/// Field-level problems with a post template draft.
+pub fn validate_post_template(draft: &PostTemplateDraft) -> Vec<String> {
+    let mut problems = Vec::new();
+    check_slug(&draft.slug, &mut problems);
+    if draft.display_name.trim().is_empty() {
+        problems.push("display_name is required".to_string());
+    }
+    check_weight("weight", &draft.weight, &mut problems);
+    check_post_types("compatible_types", &draft.compatible_types, &mut problems);
+    if draft.body_target < 1 || draft.body_target > draft.body_max {
+        problems.push(format!(
+            "body_target ({}) must be between 1 and body_max ({})",
+            draft.body_target, draft.body_max
+        ));
+    }
+    if draft.title_max < 1 {
+        problems.push("title_max must be positive".to_string());
+    }
+    if !(1..=MAX_HEIGHT_UNITS).contains(&draft.height_units) {
+        problems.push(format!("height_units must be between 1 and {MAX_HEIGHT_UNITS}"));
+    }
+    match &draft.height_override {
+        None | Some(serde_json::Value::Null) => {}
+        Some(serde_json::Value::Object(overrides)) => {
+            for (post_type, value) in overrides {
+                if !draft.compatible_types.contains(post_type) {
+                    problems.push(format!(
+                        "height_override: '{post_type}' is not in compatible_types"
+                    ));
+                }
+                let in_range = value
+                    .as_i64()
+                    .is_some_and(|h| (1..=MAX_HEIGHT_UNITS as i64).contains(&h));
+                if !in_range {
+                    problems.push(format!(
+                        "height_override: '{post_type}' must be an integer between 1 and \
+                         {MAX_HEIGHT_UNITS}"
+                    ));
+                }
+            }
+        }
+        Some(_) => problems.push(
+            "height_override must be an object of post type to height units".to_string(),
+        ),
+    }
+    problems
+}
+
+/// Why no post template can render a slot, if none can. A slot naming a
+/// template must get one of its weight rendering an accepted type; an
+/// unnamed slot needs any such template.
+fn slot_template_problem(
+    weight: &str,
+    accepts: Option<&[String]>,
+    post_template_slug: Option<&str>,
+    post_templates: &[PostTemplateConfig],
+) -> Option<String> {
+    let renders_accepted = |pt: &PostTemplateConfig| match accepts {
+        None => !pt.compatible_types.is_empty(),
+        Some(types) => types.iter().any(|t| pt.is_compatible(t)),
+    };
+    match post_template_slug {
+        Some(slug) => {
+            let Some(pt) = post_templates.iter().find(|pt| pt.slug == slug) else {
+                return Some(format!("post template '{slug}' does not exist"));
+            };
+            if pt.weight != weight {
+                Some(format!(
+                    "post template '{slug}' is {}, the slot is {weight}",
+                    pt.weight
+                ))
+            } else if !renders_accepted(pt) {
+                Some(format!(
+                    "post template '{slug}' renders none of the accepted post types"
+                ))
+            } else {
+                None
+            }
+        }
+        None => {
+            let any = post_templates
+                .iter()
+                .any(|pt| pt.weight == weight && renders_accepted(pt));
+            (!any).then(|| {
+                format!("no {weight} post template renders any of the accepted post types")
+            })
+        }
+    }
+}
+
+/// Field-level and compatibility problems with a row template draft.
+pub fn validate_row_template(
+    draft: &RowTemplateDraft,
+    post_templates: &[PostTemplateConfig],
+) -> Vec<String> {
+    let mut problems = Vec::new();
+    check_slug(&draft.slug, &mut problems);
+    if draft.slug.starts_with(WIDGET_PREFIX) {
+        problems.push(format!(
+            "slugs starting with '{WIDGET_PREFIX}' are reserved for widget rows"
+        ));
+    }
+    if draft.display_name.trim().is_empty() {
+        problems.push("display_name is required".to_string());
+    }
+    if !LAYOUT_VARIANTS.contains(&draft.layout_variant.as_str()) {
+        problems.push(format!(
+            "layout_variant '{}' must be one of {}",
+            draft.layout_variant,
+            LAYOUT_VARIANTS.join("|")
+        ));
+    }
+    if draft.slots.is_empty() {
+        problems.push("a row template needs at least one slot".to_string());
+    } else if draft.slots.iter().all(|s| s.count_min() == 0) {
+        problems.push("at least one slot needs count_min of 1 or more".to_string());
+    }
+
+    for (i, slot) in draft.slots.iter().enumerate() {
+        let mut slot_problems = Vec::new();
+        check_weight("weight", &slot.weight, &mut slot_problems);
+        let (min, count, max) = (slot.count_min(), slot.count, slot.count_max());
+        if !(0..=count).contains(&min) || !(count.max(1)..=MAX_SLOT_COUNT).contains(&max) {
+            slot_problems.push(format!(
+                "counts must satisfy 0 ≤ count_min ≤ count ≤ count_max ≤ {MAX_SLOT_COUNT} \
+                 with count_max ≥ 1 (got {min}/{count}/{max})"
+            ));
+        }
+        if let Some(accepts) = &slot.accepts {
+            check_post_types("accepts", accepts, &mut slot_problems);
+        }
+        if slot_problems.is_empty() {
+            slot_problems.extend(slot_template_problem(
+                &slot.weight,
+                slot.accepts.as_deref(),
+                slot.post_template_slug.as_deref(),
+                post_templates,
+            ));
+        }
+        problems.extend(slot_problems.into_iter().map(|p| format!("slot {i}: {p}")));
+    }
+    problems
+}
+
+/// Existing row templates that a post template set would leave with an
+/// unrenderable slot.
+fn unfillable_rows(
+    rows: &[RowTemplateWithSlots],
+    post_templates: &[PostTemplateConfig],
+) -> Vec<String> {
+    rows.iter()
+        .filter(|r| !r.config.slug.starts_with(WIDGET_PREFIX))
+        .flat_map(|r| {
+            r.slots.iter().filter_map(move |s| {
+                slot_template_problem(
+                    &s.weight,
+                    s.accepts.as_deref(),
+                    s.post_template_slug.as_deref(),
+                    post_templates,
+                )
+                .map(|p| format!("row template '{}' slot {}: {p}", r.config.slug, s.slot_index))
+            })
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Proposals
+// ---------------------------------------------------------------------------
+
+/// The full template set with one proposed change applied.
+#[derive(Debug, Clone)]
+pub struct ProposedTemplates {
+    pub rows: Vec<RowTemplateWithSlots>,
+    pub post_templates: Vec<PostTemplateConfig>,
+    /// Slug of the row template being proposed, if it is one.
+    pub row_slug: Option<String>,
+    /// Slug of the post template being proposed, if it is one.
+    pub post_template_slug: Option<String>,
+}
+
+fn post_template_from_draft(id: Uuid, draft: &PostTemplateDraft) -> PostTemplateConfig {
+    PostTemplateConfig {
+        id,
+        slug: draft.slug.clone(),
+        display_name: draft.display_name.clone(),
+        description: draft.description.clone(),
+        compatible_types: draft.compatible_types.clone(),
+        body_target: draft.body_target,
+        body_max: draft.body_max,
+        title_max: draft.title_max,
+        sort_order: draft.sort_order,
+        weight: draft.weight.clone(),
+        height_units: draft.height_units,
+        height_override: draft.height_override.clone(),
+        created_at: Utc::now(),
+    }
+}
+
+fn row_template_from_draft(id: Uuid, draft: &RowTemplateDraft) -> RowTemplateWithSlots {
+    RowTemplateWithSlots {
+        config: RowTemplateConfig {
+            id,
+            slug: draft.slug.clone(),
+            display_name: draft.display_name.clone(),
+            description: draft.description.clone(),
+            layout_variant: draft.layout_variant.clone(),
+            sort_order: draft.sort_order,
+            created_at: Utc::now(),
+        },
+        slots: draft
+            .slots
+            .iter()
+            .enumerate()
+            .map(|(i, s)| RowTemplateSlot {
+                id: Uuid::new_v4(),
+                row_template_config_id: id,
+                slot_index: i as i32,
+                weight: s.weight.clone(),
+                count: s.count,
+                count_min: s.count_min(),
+                count_max: s.count_max(),
+                accepts: s.accepts.clone(),
+                post_template_slug: s.post_template_slug.clone(),
+            })
+            .collect(),
+    }
+}
+
+/// Validate a post template create (`id` = None) or update, and return
+/// the template set it would produce.
+pub async fn check_post_template(
+    id: Option<Uuid>,
+    draft: &PostTemplateDraft,
+    pool: &PgPool,
+) -> AuthoringResult<ProposedTemplates> {
+    let mut conn = pool.acquire().await.map_err(anyhow::Error::from)?;
+    propose_post_template(id, draft, &mut conn).await
+}
+
+async fn propose_post_template(
+    id: Option<Uuid>,
+    draft: &PostTemplateDraft,
+    conn: &mut PgConnection,
+) -> AuthoringResult<ProposedTemplates> {
+    invalid(validate_post_template(draft))?;
+
+    let mut post_templates = PostTemplateConfig::find_all(&mut *conn).await?;
+    if post_templates
+        .iter()
+        .any(|pt| pt.slug == draft.slug && Some(pt.id) != id)
+    {
+        return Err(TemplateAuthoringError::Invalid(format!(
+            "post template slug '{}' is already used",
+            draft.slug
+        )));
+    }
+    let rows = RowTemplateConfig::find_all_with_slots(&mut *conn).await?;
+
+    let proposed = post_template_from_draft(id.unwrap_or_else(Uuid::new_v4), draft);
+    match id {
+        None => post_templates.push(proposed),
+        Some(id) => {
+            let current = post_templates
+                .iter_mut()
+                .find(|pt| pt.id == id)
+                .ok_or_else(|| {
+                    TemplateAuthoringError::NotFound(format!("Post template not found: {id}"))
+                })?;
+            if current.slug != draft.slug {
+                let slots =
+                    PostTemplateConfig::count_edition_slots(&current.slug, &mut *conn).await?;
+                let recipes =
+                    RowTemplateSlot::count_by_post_template(&current.slug, &mut *conn).await?;
+                if slots + recipes > 0 {
+                    return Err(TemplateAuthoringError::InUse(format!(
+                        "post template '{}' is referenced by {slots} edition slots and \
+                         {recipes} row template slots; its slug can't change",
+                        current.slug
+                    )));
+                }
+            }
+            let stranded = PostTemplateConfig::count_incompatible_edition_slots(
+                &current.slug,
+                &draft.compatible_types,
+                &mut *conn,
+            )
+            .await?;
+            if stranded > 0 {
+                return Err(TemplateAuthoringError::InUse(format!(
+                    "{stranded} edition slots render posts with '{}' whose type the new \
+                     compatible_types leave out",
+                    current.slug
+                )));
+            }
+            *current = proposed;
+            let broken = unfillable_rows(&rows, &post_templates);
+            if !broken.is_empty() {
+                return Err(TemplateAuthoringError::InUse(broken.join("; ")));
+            }
+        }
+    }
+
+    Ok(ProposedTemplates {
+        rows,
+        post_templates,
+        row_slug: None,
+        post_template_slug: Some(draft.slug.clone()),
+    })
+}
+
+/// Validate a row template create (`id` = None) or update, and return the
+/// template set it would produce.
+pub async fn check_row_template(
+    id: Option<Uuid>,
+    draft: &RowTemplateDraft,
+    pool: &PgPool,
+) -> AuthoringResult<ProposedTemplates> {
+    let mut conn = pool.acquire().await.map_err(anyhow::Error::from)?;
+    propose_row_template(id, draft, &mut conn).await
+}
+
+async fn propose_row_template(
+    id: Option<Uuid>,
+    draft: &RowTemplateDraft,
+    conn: &mut PgConnection,
+) -> AuthoringResult<ProposedTemplates> {
+    let post_templates = PostTemplateConfig::find_all(&mut *conn).await?;
+    invalid(validate_row_template(draft, &post_templates))?;
+
+    let mut rows = RowTemplateConfig::find_all_with_slots(&mut *conn).await?;
+    if rows
+        .iter()
+        .any(|r| r.config.slug == draft.slug && Some(r.config.id) != id)
+    {
+        return Err(TemplateAuthoringError::Invalid(format!(
+            "row template slug '{}' is already used",
+            draft.slug
+        )));
+    }
+
+    let proposed = row_template_from_draft(id.unwrap_or_else(Uuid::new_v4), draft);
+    match id {
+        None => rows.push(proposed),
+        Some(id) => {
+            let current = rows.iter_mut().find(|r| r.config.id == id).ok_or_else(|| {
+                TemplateAuthoringError::NotFound(format!("Row template not found: {id}"))
+            })?;
+            if current.config.slug.starts_with(WIDGET_PREFIX) {
+                return Err(TemplateAuthoringError::Invalid(format!(
+                    "'{}' is a widget row template and can't be edited here",
+                    current.config.slug
+                )));
+            }
+            if let Some(max_index) =
+                RowTemplateConfig::max_edition_slot_index(id, &mut *conn).await?
+            {
+                if max_index as usize >= draft.slots.len() {
+                    return Err(TemplateAuthoringError::InUse(format!(
+                        "edition rows using '{}' fill slot {max_index}; the proposal has only \
+                         {} slots",
+                        current.config.slug,
+                        draft.slots.len()
+                    )));
+                }
+            }
+            *current = proposed;
+        }
+    }
+
+    Ok(ProposedTemplates {
+        rows,
+        post_templates,
+        row_slug: Some(draft.slug.clone()),
+        post_template_slug: None,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Writes
+// ---------------------------------------------------------------------------
+
+/// Open a transaction that holds the template tables against other
+/// authoring writes, so a change is checked against the set it lands on.
+async fn begin_authoring(pool: &PgPool) -> anyhow::Result<Transaction<'static, Postgres>> {
+    let mut tx = pool.begin().await?;
+    sqlx::query(
+        "LOCK TABLE post_template_configs, row_template_configs IN SHARE ROW EXCLUSIVE MODE",
+    )
+    .execute(&mut *tx)
+    .await?;
+    Ok(tx)
+}
+
+pub async fn save_post_template(
+    id: Option<Uuid>,
+    draft: &PostTemplateDraft,
+    pool: &PgPool,
+) -> AuthoringResult<PostTemplateConfig> {
+    let mut tx = begin_authoring(pool).await?;
+    propose_post_template(id, draft, &mut tx).await?;
+    let saved = match id {
+        None => PostTemplateConfig::create(draft, &mut *tx).await?,
+        Some(id) => PostTemplateConfig::update(id, draft, &mut *tx).await?,
+    };
+    tx.commit().await.map_err(anyhow::Error::from)?;
+    tracing::info!(slug = %saved.slug, created = id.is_none(), "Post template saved");
+    Ok(saved)
+}
+
+pub async fn save_row_template(
+    id: Option<Uuid>,
+    draft: &RowTemplateDraft,
+    pool: &PgPool,
+) -> AuthoringResult<RowTemplateWithSlots> {
+    let mut tx = begin_authoring(pool).await?;
+    propose_row_template(id, draft, &mut tx).await?;
+    let saved = match id {
+        None => RowTemplateConfig::create_with_slots(draft, &mut *tx).await?,
+        Some(id) => RowTemplateConfig::update_with_slots(id, draft, &mut *tx).await?,
+    };
+    tx.commit().await.map_err(anyhow::Error::from)?;
+    tracing::info!(slug = %saved.config.slug, created = id.is_none(), "Row template saved");
+    Ok(saved)
+}
+
+/// Delete a post template no edition slot or row template refers to, and
+/// whose loss leaves every row template fillable.
+pub async fn delete_post_template(id: Uuid, pool: &PgPool) -> AuthoringResult<()> {
+    let mut tx = begin_authoring(pool).await?;
+    let current = PostTemplateConfig::find_by_id(id, &mut *tx)
+        .await?
+        .ok_or_else(|| TemplateAuthoringError::NotFound(format!("Post template not found: {id}")))?;
+    let slots = PostTemplateConfig::count_edition_slots(&current.slug, &mut *tx).await?;
+    let recipes = RowTemplateSlot::count_by_post_template(&current.slug, &mut *tx).await?;
+    if slots + recipes > 0 {
+        return Err(TemplateAuthoringError::InUse(format!(
+            "post template '{}' is referenced by {slots} edition slots and {recipes} row \
+             template slots",
+            current.slug
+        )));
+    }
+    let mut remaining = PostTemplateConfig::find_all(&mut *tx).await?;
+    remaining.retain(|pt| pt.id != id);
+    let rows = RowTemplateConfig::find_all_with_slots(&mut *tx).await?;
+    let broken = unfillable_rows(&rows, &remaining);
+    if !broken.is_empty() {
+        return Err(TemplateAuthoringError::InUse(broken.join("; ")));
+    }
+    PostTemplateConfig::delete(id, &mut *tx).await?;
+    tx.commit().await.map_err(anyhow::Error::from)?;
+    tracing::info!(slug = %current.slug, "Post template deleted");
+    Ok(())
+}
+
+/// Delete a row template no edition row is laid out with.
+pub async fn delete_row_template(id: Uuid, pool: &PgPool) -> AuthoringResult<()> {
+    let mut tx = begin_authoring(pool).await?;
+    let current = RowTemplateConfig::find_by_id(id, &mut *tx)
+        .await?
+        .ok_or_else(|| TemplateAuthoringError::NotFound(format!("Row template not found: {id}")))?;
+    if current.slug.starts_with(WIDGET_PREFIX) {
+        return Err(TemplateAuthoringError::Invalid(format!(
+            "'{}' is a widget row template and can't be deleted here",
+            current.slug
+        )));
+    }
+    let rows = RowTemplateConfig::count_edition_rows(id, &mut *tx).await?;
+    if rows > 0 {
+        return Err(TemplateAuthoringError::InUse(format!(
+            "row template '{}' lays out {rows} edition rows",
+            current.slug
+        )));
+    }
+    RowTemplateConfig::delete(id, &mut *tx).await?;
+    tx.commit().await.map_err(anyhow::Error::from)?;
+    tracing::info!(slug = %current.slug, "Row template deleted");
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Dry run
+// ---------------------------------------------------------------------------
+
+/// A sample broadsheet laid out with a proposed template set.
+#[derive(Debug, Clone, Serialize)]
+pub struct TemplatePreview {
+    pub draft: BroadsheetDraft,
+    /// The proposed row template filled from the whole pool on its own, so
+    /// it can be seen even when the engine doesn't choose it. None when the
+    /// pool can't fill it (or the proposal is a post template).
+    pub sample_row: Option<BroadsheetRow>,
+    /// Rows in `draft` that use the proposed row or post template.
+    pub rows_using_proposal: usize,
+    pub pool_size: usize,
+}
+
+/// Lay out `county_id`'s pool for the edition starting `period_start` with
+/// the proposed templates. Greedy placement, no pins or widgets, no writes.
+pub async fn preview(
+    proposed: &ProposedTemplates,
+    county_id: Uuid,
+    period_start: NaiveDate,
+    deps: &ServerDeps,
+) -> AuthoringResult<TemplatePreview> {
+    let pool = &deps.db_pool;
+    let county = County::find_by_id(county_id, pool)
+        .await?
+        .ok_or_else(|| TemplateAuthoringError::NotFound(format!("County not found: {county_id}")))?;
+    let posts = load_county_posts(county_id, period_start, pool).await?;
+
+    let (height_map, height_override_map) = height_maps(&proposed.post_templates);
+    let inputs = LayoutInputs::new(
+        &posts,
+        &proposed.rows,
+        &proposed.post_templates,
+        &height_map,
+        &height_override_map,
+        county.target_content_weight,
+    );
+    let draft = layout_search::place_with_options(&inputs, &LayoutOptions::default());
+
+    let sample_row = proposed.row_slug.as_deref().and_then(|slug| {
+        let tws = proposed.rows.iter().find(|r| r.config.slug == slug)?;
+        let mut placed = vec![false; posts.len()];
+        fill_row(
+            &tws.config,
+            tws,
+            &posts,
+            &mut placed,
+            &proposed.post_templates,
+            &height_map,
+            &height_override_map,
+        )
+    });
+
+    let rows_using_proposal = draft
+        .rows
+        .iter()
+        .filter(|row| {
+            proposed.row_slug.as_deref() == Some(row.row_template_slug.as_str())
+                || row.slots.iter().any(|s| {
+                    proposed.post_template_slug.as_deref() == Some(s.post_template_slug.as_str())
+                })
+        })
+        .count();
+
+    Ok(TemplatePreview {
+        draft,
+        sample_row,
+        rows_using_proposal,
+        pool_size: posts.len(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domains::editions::data::types::RowSlotDraft;
+
+    fn post_template(slug: &str, weight: &str, types: &[&str]) -> PostTemplateConfig {
+        post_template_from_draft(
+            Uuid::new_v4(),
+            &PostTemplateDraft {
+                slug: slug.to_string(),
+                display_name: slug.to_string(),
+                description: None,
+                compatible_types: types.iter().map(|t| t.to_string()).collect(),
+                body_target: 200,
+                body_max: 400,
+                title_max: 80,
+                sort_order: 0,
+                weight: weight.to_string(),
+                height_units: 4,
+                height_override: None,
+            },
+        )
+    }
+
+    fn slot(
+        weight: &str,
+        count: i32,
+        accepts: Option<&[&str]>,
+        tmpl: Option<&str>,
+    ) -> RowSlotDraft {
+        RowSlotDraft {
+            weight: weight.to_string(),
+            count,
+            count_min: None,
+            count_max: None,
+            accepts: accepts.map(|a| a.iter().map(|t| t.to_string()).collect()),
+            post_template_slug: tmpl.map(str::to_string),
+        }
+    }
+
+    fn row(slug: &str, variant: &str, slots: Vec<RowSlotDraft>) -> RowTemplateDraft {
+        RowTemplateDraft {
+            slug: slug.to_string(),
+            display_name: slug.to_string(),
+            description: None,
+            layout_variant: variant.to_string(),
+            sort_order: 0,
+            slots,
+        }
+    }
+
+    fn templates() -> Vec<PostTemplateConfig> {
+        vec![
+            post_template("feature", "heavy", &["story", "event"]),
+            post_template("gazette", "medium", &["story", "update", "event"]),
+            post_template("ledger", "light", &["need", "aid", "reference"]),
+        ]
+    }
+
+    #[test]
+    fn accepts_a_well_formed_row_template() {
+        let draft = row(
+            "pair-stack-ledger",
+            "pair-stack",
+            vec![
+                slot("medium", 1, Some(&["story"]), Some("gazette")),
+                slot("light", 3, Some(&["need", "aid"]), None),
+            ],
+        );
+        assert!(validate_row_template(&draft, &templates()).is_empty());
+    }
+
+    #[test]
+    fn rejects_bad_counts_weights_and_variants() {
+        let mut bad_counts = slot("light", 2, None, None);
+        bad_counts.count_min = Some(3);
+        let draft = row(
+            "Bad Slug",
+            "mosaic",
+            vec![bad_counts, slot("enormous", 1, Some(&["story"]), None)],
+        );
+        let problems = validate_row_template(&draft, &templates());
+        assert!(problems.iter().any(|p| p.starts_with("slug 'Bad Slug'")));
+        assert!(problems.iter().any(|p| p.starts_with("layout_variant 'mosaic'")));
+        assert!(problems.iter().any(|p| p.starts_with("slot 0: counts")));
+        assert!(problems.iter().any(|p| p.starts_with("slot 1: weight 'enormous'")));
+    }
+
+    #[test]
+    fn requires_a_compatible_template_per_slot() {
+        let draft = row(
+            "lead-mismatch",
+            "lead",
+            vec![
+                // No heavy template renders needs.
+                slot("heavy", 1, Some(&["need"]), None),
+                // Named template has the wrong tier.
+                slot("light", 1, None, Some("gazette")),
+                // Named template renders none of the accepted types.
+                slot("medium", 1, Some(&["person"]), Some("gazette")),
+            ],
+        );
+        let problems = validate_row_template(&draft, &templates());
+        assert_eq!(problems.len(), 3, "{problems:?}");
+        assert!(problems[0].contains("no heavy post template"));
+        assert!(problems[1].contains("is medium, the slot is light"));
+        assert!(problems[2].contains("renders none of the accepted"));
+    }
+
+    #[test]
+    fn narrowing_a_post_template_flags_dependent_rows() {
+        let mut post_templates = templates();
+        let rows = vec![row_template_from_draft(
+            Uuid::new_v4(),
+            &row(
+                "pair-gazette",
+                "pair",
+                vec![slot("medium", 2, Some(&["update"]), Some("gazette"))],
+            ),
+        )];
+        assert!(unfillable_rows(&rows, &post_templates).is_empty());
+
+        post_templates[1].compatible_types = vec!["story".to_string()];
+        let broken = unfillable_rows(&rows, &post_templates);
+        assert_eq!(broken.len(), 1);
+        assert!(broken[0].starts_with("row template 'pair-gazette' slot 0"));
+    }
+
+    #[test]
+    fn deleting_the_only_template_of_a_weight_flags_dependent_rows() {
+        let mut post_templates = templates();
+        let rows = vec![row_template_from_draft(
+            Uuid::new_v4(),
+            &row("ledger-list", "full", vec![slot("light", 3, None, None)]),
+        )];
+        assert!(unfillable_rows(&rows, &post_templates).is_empty());
+
+        post_templates.retain(|pt| pt.slug != "ledger");
+        let broken = unfillable_rows(&rows, &post_templates);
+        assert_eq!(broken.len(), 1);
+        assert!(broken[0].starts_with("row template 'ledger-list' slot 0"));
+    }
+
+    #[test]
+    fn post_template_height_override_must_match_types() {
+        let mut draft = PostTemplateDraft {
+            slug: "bulletin".to_string(),
+            display_name: "Bulletin".to_string(),
+            description: None,
+            compatible_types: vec!["story".to_string(), "reference".to_string()],
+            body_target: 300,
+            body_max: 200,
+            title_max: 90,
+            sort_order: 0,
+            weight: "medium".to_string(),
+            height_units: 7,
+            height_override: Some(serde_json::json!({"reference": 10, "event": 0})),
+        };
+        let problems = validate_post_template(&draft);
+        assert_eq!(problems.len(), 3, "{problems:?}");
+        assert!(problems[0].starts_with("body_target"));
+        assert!(problems.iter().any(|p| p.contains("'event' is not in compatible_types")));
+
+        draft.body_max = 600;
+        draft.height_override = Some(serde_json::json!({"reference": 10}));
+        assert!(validate_post_template(&draft).is_empty());
+    }
+}
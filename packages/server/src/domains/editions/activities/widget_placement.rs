@@ -0,0 +1,555 @@
+//! Widget placement — inserts widget rows between a layout's post rows.
+//!
+//! Where widgets go is data, not code: `widget_placement_rules` (migration
+//! 260) holds a global rule set and optional per-county overrides. Each rule
+//! names a position ("after post row 4"), a widget type and how many widgets
+//! share the row (1 standalone, 2 pair, 3 trio), plus conditions that must
+//! hold for it to fire:
+//!
+//! - `min_rows`: the broadsheet has at least this many post rows
+//! - `rows_after`: at least this many post rows follow the position
+//! - `min_gap`: post rows between this and every widget row already placed
+//! - `at_section_start`: a topic section starts right below the position
+//!
+//! Rules are evaluated in order and skipped, never moved, when a condition
+//! fails or too few widgets of the type are available. Widgets are picked
+//! round-robin per type. `preview` runs a proposed rule set against a
+//! county's current pool without saving it.
+
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use serde::Serialize;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domains::editions::data::types::{
+    BroadsheetDraft, BroadsheetRow, BroadsheetSection, BroadsheetWidgetRow, BroadsheetWidgetSlot,
+    LayoutOptions, WidgetRuleDraft,
+};
+use crate::domains::editions::models::county::County;
+use crate::domains::editions::models::post_template_config::PostTemplateConfig;
+use crate::domains::editions::models::row_template_config::RowTemplateConfig;
+use crate::domains::editions::models::widget_placement_rule::WidgetPlacementRule;
+use crate::domains::widgets::models::widget::WIDGET_TYPES;
+use crate::domains::widgets::Widget;
+use crate::kernel::ServerDeps;
+
+use super::layout_engine::{height_maps, load_county_posts};
+use super::layout_search::{self, LayoutInputs};
+
+/// Bounds on an authored rule set. The layout search caps a broadsheet at
+/// 40 post rows, so positions past that never fire.
+const MAX_RULES: usize = 50;
+const MAX_AFTER_ROW: i32 = 40;
+
+#[derive(Debug, thiserror::Error)]
+pub enum WidgetRuleError {
+    #[error("{0}")]
+    Invalid(String),
+    #[error("{0}")]
+    NotFound(String),
+    #[error(transparent)]
+    Db(#[from] anyhow::Error),
+}
+
+/// Why a rule didn't place a widget row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleSkip {
+    /// The broadsheet is shorter than `min_rows` or ends before the position.
+    TooFewRows,
+    /// Fewer than `rows_after` post rows follow the position.
+    NoRowsAfter,
+    /// `at_section_start` is set and no topic section starts below.
+    NotSectionStart,
+    /// Another widget row is within `min_gap` post rows.
+    TooClose,
+    /// Fewer than `count` available widgets of the type (sharing a visual
+    /// variant, for pairs and trios).
+    NotEnoughWidgets,
+}
+
+/// What one rule did, in evaluation order.
+#[derive(Debug, Clone, Serialize)]
+pub struct RuleOutcome {
+    pub sort_order: i32,
+    pub after_row: i32,
+    pub widget_type: String,
+    pub skipped: Option<RuleSkip>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct WidgetPlacement {
+    pub widget_rows: Vec<BroadsheetWidgetRow>,
+    pub outcomes: Vec<RuleOutcome>,
+}
+
+/// IDs of the widget row templates, by slug (`widget-standalone`,
+/// `widget-pair`, `widget-trio`).
+pub async fn widget_template_ids(pool: &PgPool) -> anyhow::Result<HashMap<String, Uuid>> {
+    let ids = sqlx::query_as::<_, (String, Uuid)>(
+        "SELECT slug, id FROM row_template_configs WHERE slug LIKE 'widget%'",
+    )
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .collect();
+    Ok(ids)
+}
+
+/// The condition a rule fails at `after`, if any.
+fn check_position(
+    rule: &WidgetPlacementRule,
+    after: usize,
+    rows: &[BroadsheetRow],
+    sections: &[BroadsheetSection],
+    placed: &[BroadsheetWidgetRow],
+) -> Option<RuleSkip> {
+    if after >= rows.len() || rows.len() < rule.min_rows.max(0) as usize {
+        return Some(RuleSkip::TooFewRows);
+    }
+    if rows.len() - 1 - after < rule.rows_after.max(0) as usize {
+        return Some(RuleSkip::NoRowsAfter);
+    }
+    if rule.at_section_start
+        && !sections
+            .iter()
+            .any(|s| s.row_indices.iter().min() == Some(&(after + 1)))
+    {
+        return Some(RuleSkip::NotSectionStart);
+    }
+    let min_gap = rule.min_gap.max(0) as usize;
+    if placed.iter().any(|w| w.insert_after.abs_diff(after) < min_gap) {
+        return Some(RuleSkip::TooClose);
+    }
+    None
+}
+
+/// Place widget rows by `rules` (enabled rules only, in order). Pure — no I/O.
+///
+/// Section separator variant:
+///   - Default: ledger (center-aligned) — feels like a "chapter break"
+///   - When the FOLLOWING row is a ticker-style full-width left-aligned row,
+///     use the default section-sep (left-aligned) so the separator aligns with
+///     the content below it.
+pub fn place_widgets(
+    rows: &[BroadsheetRow],
+    sections: &[BroadsheetSection],
+    widgets: &[Widget],
+    template_ids: &HashMap<String, Uuid>,
+    rules: &[WidgetPlacementRule],
+) -> WidgetPlacement {
+    let mut placement = WidgetPlacement::default();
+    if rows.is_empty() || widgets.is_empty() {
+        return placement;
+    }
+
+    let standalone_id = match template_ids.get("widget-standalone") {
+        Some(id) => *id,
+        None => return placement,
+    };
+    let trio_id = template_ids.get("widget-trio").copied().unwrap_or(standalone_id);
+    let pair_id = template_ids.get("widget-pair").copied().unwrap_or(standalone_id);
+
+    // Group widgets by type for round-robin selection
+    let mut by_type: HashMap<&str, Vec<&Widget>> = HashMap::new();
+    for w in widgets {
+        by_type.entry(w.widget_type.as_str()).or_default().push(w);
+    }
+
+    let mut type_cursor: HashMap<&str, usize> = HashMap::new();
+
+    for rule in rules.iter().filter(|r| r.enabled) {
+        let after = rule.after_row.max(0) as usize;
+        let count = rule.count.clamp(1, 3) as usize;
+        let wtype = rule.widget_type.as_str();
+        let picked = match check_position(rule, after, rows, sections, &placement.widget_rows) {
+            Some(reason) => Err(reason),
+            None => pick_widgets(rule, after, count, rows, &by_type, &mut type_cursor),
+        };
+        let skipped = match picked {
+            Ok(slots) => {
+                placement.widget_rows.push(BroadsheetWidgetRow {
+                    widgets: slots,
+                    insert_after: after,
+                    row_template_id: match count {
+                        3 => trio_id,
+                        2 => pair_id,
+                        _ => standalone_id,
+                    },
+                    pinned: false,
+                });
+                None
+            }
+            Err(reason) => Some(reason),
+        };
+        if let Some(reason) = skipped {
+            tracing::debug!(
+                widget_type = wtype,
+                after_row = after,
+                reason = ?reason,
+                "Layout engine: widget rule skipped"
+            );
+        }
+        placement.outcomes.push(RuleOutcome {
+            sort_order: rule.sort_order,
+            after_row: rule.after_row,
+            widget_type: rule.widget_type.clone(),
+            skipped,
+        });
+    }
+
+    placement
+}
+
+/// Pick the widgets for a rule whose position conditions hold.
+fn pick_widgets<'a>(
+    rule: &'a WidgetPlacementRule,
+    after: usize,
+    count: usize,
+    rows: &[BroadsheetRow],
+    by_type: &HashMap<&str, Vec<&Widget>>,
+    type_cursor: &mut HashMap<&'a str, usize>,
+) -> Result<Vec<BroadsheetWidgetSlot>, RuleSkip> {
+    let wtype = rule.widget_type.as_str();
+    let pool = match by_type.get(wtype) {
+        Some(p) if p.len() >= count => p,
+        _ => return Err(RuleSkip::NotEnoughWidgets),
+    };
+
+    let cursor = type_cursor.entry(wtype).or_insert(0);
+
+    // For multi-widget rows (count > 1), all widgets must share the same
+    // visual variant ("harmony within"). Filter to widgets matching the
+    // first pick's widget_template.
+    let first_pick = pool[*cursor % pool.len()];
+    let first_template = first_pick.data.get("widget_template")
+        .and_then(|v| v.as_str())
+        .unwrap_or("");
+    let filtered: Vec<&&Widget> = if count > 1 {
+        pool.iter()
+            .filter(|w| {
+                w.data.get("widget_template")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("") == first_template
+            })
+            .collect()
+    } else {
+        pool.iter().collect()
+    };
+    if filtered.len() < count {
+        return Err(RuleSkip::NotEnoughWidgets);
+    }
+
+    // Pick widget(s) round-robin from the filtered (same-variant) pool
+    let mut slots: Vec<BroadsheetWidgetSlot> = Vec::new();
+    for i in 0..count {
+        let picked = filtered[i % filtered.len()];
+
+        let widget_template = if wtype == "number" {
+            picked.data.get("widget_template")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string())
+        } else if wtype == "section_sep" {
+            let next_row = rows.get(after + 1);
+            let next_is_ticker = next_row
+                .map(|r| r.row_template_slug == "ticker" || r.row_template_slug == "ticker-updates")
+                .unwrap_or(false);
+            if next_is_ticker { None } else { Some("ledger".to_string()) }
+        } else {
+            None
+        };
+
+        slots.push(BroadsheetWidgetSlot {
+            widget_id: picked.id,
+            widget_template,
+            slot_index: i as i32,
+            pinned: false,
+        });
+    }
+
+    // Advance global cursor so the next pick of this type starts fresh
+    *cursor += count;
+
+    Ok(slots)
+}
+
+// ---------------------------------------------------------------------------
+// Authoring
+// ---------------------------------------------------------------------------
+
+/// Problems with a proposed rule set.
+pub fn validate_rules(rules: &[WidgetRuleDraft]) -> Vec<String> {
+    let mut problems = Vec::new();
+    if rules.len() > MAX_RULES {
+        problems.push(format!("at most {MAX_RULES} rules per scope"));
+    }
+    for (i, rule) in rules.iter().enumerate() {
+        let mut push = |p: String| problems.push(format!("rule {i}: {p}"));
+        if !WIDGET_TYPES.contains(&rule.widget_type.as_str()) {
+            push(format!(
+                "unknown widget type '{}' (expected {})",
+                rule.widget_type,
+                WIDGET_TYPES.join("|")
+            ));
+        }
+        if !(0..=MAX_AFTER_ROW).contains(&rule.after_row) {
+            push(format!("after_row must be between 0 and {MAX_AFTER_ROW}"));
+        }
+        if !(1..=3).contains(&rule.count) {
+            push("count must be 1 (standalone), 2 (pair) or 3 (trio)".to_string());
+        }
+        if rule.min_rows < 0 || rule.rows_after < 0 || rule.min_gap < 0 {
+            push("min_rows, rows_after and min_gap can't be negative".to_string());
+        }
+        if rule.min_rows > MAX_AFTER_ROW + 1 {
+            push(format!("min_rows above {} can never hold", MAX_AFTER_ROW + 1));
+        }
+    }
+    problems
+}
+
+/// Replace the rule set for a county (or the global set when None). An
+/// empty list for a county returns it to the global rules.
+pub async fn save_rules(
+    county_id: Option<Uuid>,
+    rules: &[WidgetRuleDraft],
+    pool: &PgPool,
+) -> Result<Vec<WidgetPlacementRule>, WidgetRuleError> {
+    let problems = validate_rules(rules);
+    if !problems.is_empty() {
+        return Err(WidgetRuleError::Invalid(problems.join("; ")));
+    }
+    if let Some(id) = county_id {
+        County::find_by_id(id, pool)
+            .await?
+            .ok_or_else(|| WidgetRuleError::NotFound(format!("County not found: {id}")))?;
+    }
+    let saved = WidgetPlacementRule::replace_for_scope(county_id, rules, pool).await?;
+    tracing::info!(county_id = ?county_id, rules = saved.len(), "Widget placement rules saved");
+    Ok(saved)
+}
+
+/// A sample broadsheet with widget rows placed by a proposed rule set.
+#[derive(Debug, Clone, Serialize)]
+pub struct WidgetRulePreview {
+    pub draft: BroadsheetDraft,
+    pub outcomes: Vec<RuleOutcome>,
+}
+
+/// Lay out `county_id`'s pool for the edition starting `period_start`
+/// (greedy, no pins) and place widgets with `rules` in place of the
+/// county's effective rules. Writes nothing.
+pub async fn preview(
+    rules: &[WidgetRuleDraft],
+    county_id: Uuid,
+    period_start: NaiveDate,
+    deps: &ServerDeps,
+) -> Result<WidgetRulePreview, WidgetRuleError> {
+    let problems = validate_rules(rules);
+    if !problems.is_empty() {
+        return Err(WidgetRuleError::Invalid(problems.join("; ")));
+    }
+    let pool = &deps.db_pool;
+    let county = County::find_by_id(county_id, pool)
+        .await?
+        .ok_or_else(|| WidgetRuleError::NotFound(format!("County not found: {county_id}")))?;
+
+    let posts = load_county_posts(county_id, period_start, pool).await?;
+    let templates = RowTemplateConfig::find_all_with_slots(pool).await?;
+    let post_templates = PostTemplateConfig::find_all(pool).await?;
+    let (height_map, height_override_map) = height_maps(&post_templates);
+    let inputs = LayoutInputs::new(
+        &posts,
+        &templates,
+        &post_templates,
+        &height_map,
+        &height_override_map,
+        county.target_content_weight,
+    );
+    let mut draft = layout_search::place_with_options(&inputs, &LayoutOptions::default());
+
+    let rules: Vec<WidgetPlacementRule> = rules
+        .iter()
+        .enumerate()
+        .map(|(i, r)| WidgetPlacementRule::from_draft(Some(county_id), i as i32, r))
+        .collect();
+    let widgets = Widget::find_available(county_id, period_start, pool).await?;
+    let template_ids = widget_template_ids(pool).await?;
+    let placement = place_widgets(&draft.rows, &draft.sections, &widgets, &template_ids, &rules);
+    draft.widget_rows = placement.widget_rows;
+
+    Ok(WidgetRulePreview {
+        draft,
+        outcomes: placement.outcomes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domains::editions::data::types::BroadsheetSlot;
+    use chrono::Utc;
+
+    fn rows(n: usize) -> Vec<BroadsheetRow> {
+        (0..n)
+            .map(|i| BroadsheetRow {
+                row_template_slug: if i == 3 { "ticker" } else { "trio-gazette" }.to_string(),
+                row_template_id: Uuid::new_v4(),
+                slots: vec![BroadsheetSlot {
+                    post_id: Uuid::new_v4(),
+                    post_template_slug: "gazette".to_string(),
+                    slot_index: 0,
+                    pinned: false,
+                }],
+                max_priority: 50,
+                pinned: false,
+            })
+            .collect()
+    }
+
+    fn widget(widget_type: &str) -> Widget {
+        Widget {
+            id: Uuid::new_v4(),
+            widget_type: widget_type.to_string(),
+            authoring_mode: "human".to_string(),
+            data: serde_json::json!({}),
+            zip_code: None,
+            city: None,
+            county_id: None,
+            start_date: None,
+            end_date: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            is_seed: false,
+        }
+    }
+
+    fn rule(after_row: i32, widget_type: &str, count: i32) -> WidgetPlacementRule {
+        WidgetPlacementRule::from_draft(
+            None,
+            0,
+            &WidgetRuleDraft {
+                after_row,
+                widget_type: widget_type.to_string(),
+                count,
+                min_rows: 0,
+                rows_after: 0,
+                min_gap: 1,
+                at_section_start: false,
+                enabled: true,
+            },
+        )
+    }
+
+    fn template_ids() -> HashMap<String, Uuid> {
+        ["widget-standalone", "widget-pair", "widget-trio"]
+            .into_iter()
+            .map(|s| (s.to_string(), Uuid::new_v4()))
+            .collect()
+    }
+
+    #[test]
+    fn seeded_rules_match_the_old_table() {
+        // Migration 260's global rules on a 12-row broadsheet.
+        let mut seed = vec![
+            rule(2, "section_sep", 1),
+            rule(4, "number", 3),
+            rule(5, "section_sep", 1),
+            rule(6, "photo", 1),
+            rule(7, "pull_quote", 1),
+            rule(8, "number", 2),
+            rule(9, "resource_bar", 1),
+        ];
+        seed[0].rows_after = 1;
+        seed[2].rows_after = 1;
+        let widgets: Vec<Widget> = ["section_sep", "number", "number", "number", "photo"]
+            .into_iter()
+            .chain(["pull_quote", "resource_bar", "number", "number"])
+            .map(widget)
+            .collect();
+        let ids = template_ids();
+
+        let placement = place_widgets(&rows(12), &[], &widgets, &ids, &seed);
+        let positions: Vec<usize> = placement.widget_rows.iter().map(|w| w.insert_after).collect();
+        assert_eq!(positions, vec![2, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(placement.widget_rows[1].widgets.len(), 3);
+        assert_eq!(placement.widget_rows[1].row_template_id, ids["widget-trio"]);
+        // Row 3 is a ticker, so the separator after row 2 is left-aligned.
+        assert_eq!(placement.widget_rows[0].widgets[0].widget_template, None);
+        assert_eq!(
+            placement.widget_rows[2].widgets[0].widget_template.as_deref(),
+            Some("ledger")
+        );
+
+        // On a 6-row broadsheet the later rules fall off the end, and the
+        // separator after row 5 has nothing below it.
+        let placement = place_widgets(&rows(6), &[], &widgets, &ids, &seed);
+        let skips: Vec<Option<RuleSkip>> = placement.outcomes.iter().map(|o| o.skipped).collect();
+        assert_eq!(skips[2], Some(RuleSkip::NoRowsAfter));
+        assert_eq!(skips[3], Some(RuleSkip::TooFewRows));
+    }
+
+    #[test]
+    fn conditions_gate_rules() {
+        let widgets = vec![widget("pull_quote"), widget("pull_quote"), widget("photo")];
+        let ids = template_ids();
+        let sections = vec![BroadsheetSection {
+            title: "Food".to_string(),
+            subtitle: None,
+            topic_slug: Some("food".to_string()),
+            row_indices: vec![4, 5],
+            widget_row_indices: Vec::new(),
+            pinned: false,
+        }];
+
+        let mut at_section = rule(2, "pull_quote", 1);
+        at_section.at_section_start = true;
+        let mut section_match = rule(3, "pull_quote", 1);
+        section_match.at_section_start = true;
+        let mut spaced = rule(5, "pull_quote", 1);
+        spaced.min_gap = 3;
+        let mut long_only = rule(6, "photo", 1);
+        long_only.min_rows = 10;
+        let pair = rule(7, "pull_quote", 2);
+
+        let placement = place_widgets(
+            &rows(8),
+            &sections,
+            &widgets,
+            &ids,
+            &[at_section, section_match, spaced, long_only, pair],
+        );
+        let skips: Vec<Option<RuleSkip>> = placement.outcomes.iter().map(|o| o.skipped).collect();
+        assert_eq!(
+            skips,
+            vec![
+                Some(RuleSkip::NotSectionStart),
+                None,
+                Some(RuleSkip::TooClose),
+                Some(RuleSkip::TooFewRows),
+                None,
+            ]
+        );
+        assert_eq!(placement.widget_rows.len(), 2);
+        assert_eq!(placement.widget_rows[1].row_template_id, ids["widget-pair"]);
+    }
+
+    #[test]
+    fn validate_rules_flags_bad_fields() {
+        let draft = WidgetRuleDraft {
+            after_row: -1,
+            widget_type: "banner".to_string(),
+            count: 4,
+            min_rows: 0,
+            rows_after: -2,
+            min_gap: 1,
+            at_section_start: false,
+            enabled: true,
+        };
+        let problems = validate_rules(&[draft]);
+        assert_eq!(problems.len(), 4, "{problems:?}");
+        assert!(problems.iter().all(|p| p.starts_with("rule 0: ")));
+    }
+}
@@ -0,0 +1,244 @@
+//! The public broadsheet: a published edition as readers see it, with
+//! full post data. Served by the public and preview endpoints and frozen
+//! into `edition_snapshots` on publish.
+
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::domains::editions::models::edition::Edition;
+use crate::domains::editions::models::edition_section::EditionSection;
+use crate::domains::posts::data::types::{PublicTagResult, UrgentNoteInfo};
+
+#[derive(Debug, Serialize)]
+pub struct CountyResult {
+    pub id: Uuid,
+    pub fips_code: String,
+    pub name: String,
+    pub state: String,
+    pub target_content_weight: i32,
+    /// Synthetic row (e.g. Statewide) rather than a real MN county.
+    /// Frontends use this to group/flag pseudo counties in pickers
+    /// and to exclude them from "N of 87 counties" roll-ups.
+    pub is_pseudo: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EditionResult {
+    pub id: Uuid,
+    pub county_id: Uuid,
+    pub title: Option<String>,
+    pub period_start: String,
+    pub period_end: String,
+    pub status: String,
+    pub published_at: Option<String>,
+    pub created_at: String,
+    pub publish_at: Option<String>,
+    pub publish_error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub row_count: Option<i64>,
+}
+
+// =============================================================================
+// Public broadsheet result types (unauthenticated, full post data)
+// =============================================================================
+
+#[derive(Debug, Serialize)]
+pub struct PublicBroadsheetResult {
+    pub edition: EditionResult,
+    pub county: CountyResult,
+    pub rows: Vec<PublicBroadsheetRowResult>,
+    pub sections: Vec<EditionSectionResult>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PublicBroadsheetRowResult {
+    pub row_template_slug: String,
+    pub layout_variant: String,
+    pub sort_order: i32,
+    pub section_id: Option<Uuid>,
+    pub slots: Vec<PublicBroadsheetSlotResult>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PublicBroadsheetSlotResult {
+    pub kind: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_template: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub widget_template: Option<String>,
+    pub slot_index: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post: Option<PublicBroadsheetPostResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub widget: Option<PublicBroadsheetWidgetResult>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PublicBroadsheetWidgetResult {
+    pub id: Uuid,
+    pub widget_type: String,
+    pub authoring_mode: String,
+    pub data: serde_json::Value,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PublicBroadsheetPostResult {
+    pub id: Uuid,
+    pub title: String,
+    pub body_raw: String,
+    pub post_type: String,
+    pub weight: String,
+    pub is_urgent: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pencil_mark: Option<String>,
+    pub location: Option<String>,
+    pub organization_name: Option<String>,
+    pub published_at: Option<String>,
+    pub tags: Vec<PublicTagResult>,
+    pub contacts: Vec<BroadsheetContactResult>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub urgent_notes: Vec<UrgentNoteInfo>,
+    // Weight-specific body text from Root Signal
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_heavy: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_medium: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_light: Option<String>,
+    // Field groups
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub media: Vec<BroadsheetMediaResult>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub items: Vec<BroadsheetItemResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub person: Option<BroadsheetPersonResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link: Option<BroadsheetLinkResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_attribution: Option<BroadsheetSourceAttributionResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta: Option<BroadsheetMetaResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub datetime: Option<BroadsheetDatetimeResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_status: Option<BroadsheetStatusResult>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub schedule: Vec<BroadsheetScheduleEntryResult>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BroadsheetContactResult {
+    pub contact_type: String,
+    pub contact_value: String,
+    pub contact_label: Option<String>,
+}
+
+// =============================================================================
+// Field group result types
+// =============================================================================
+
+#[derive(Debug, Serialize)]
+pub struct BroadsheetMediaResult {
+    pub image_url: Option<String>,
+    pub caption: Option<String>,
+    pub credit: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BroadsheetItemResult {
+    pub name: String,
+    pub detail: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BroadsheetPersonResult {
+    pub name: Option<String>,
+    pub role: Option<String>,
+    pub bio: Option<String>,
+    pub photo_url: Option<String>,
+    pub quote: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BroadsheetLinkResult {
+    pub label: Option<String>,
+    pub url: Option<String>,
+    pub deadline: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BroadsheetSourceAttributionResult {
+    pub source_name: Option<String>,
+    pub attribution: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BroadsheetMetaResult {
+    pub kicker: Option<String>,
+    pub byline: Option<String>,
+    pub timestamp: Option<String>,
+    pub updated: Option<String>,
+    pub deck: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BroadsheetDatetimeResult {
+    pub start: Option<String>,
+    pub end: Option<String>,
+    pub cost: Option<String>,
+    pub recurring: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BroadsheetStatusResult {
+    pub state: Option<String>,
+    pub verified: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BroadsheetScheduleEntryResult {
+    pub day: String,
+    pub opens: String,
+    pub closes: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EditionSectionResult {
+    pub id: Uuid,
+    pub edition_id: Uuid,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub topic_slug: Option<String>,
+    pub sort_order: i32,
+    pub pinned: bool,
+    pub created_at: String,
+}
+
+pub fn section_to_result(s: &EditionSection) -> EditionSectionResult {
+    EditionSectionResult {
+        id: s.id,
+        edition_id: s.edition_id,
+        title: s.title.clone(),
+        subtitle: s.subtitle.clone(),
+        topic_slug: s.topic_slug.clone(),
+        sort_order: s.sort_order,
+        pinned: s.pinned,
+        created_at: s.created_at.to_rfc3339(),
+    }
+}
+
+pub fn edition_to_result(e: &Edition) -> EditionResult {
+    EditionResult {
+        id: e.id,
+        county_id: e.county_id,
+        title: e.title.clone(),
+        period_start: e.period_start.to_string(),
+        period_end: e.period_end.to_string(),
+        status: e.status.clone(),
+        published_at: e.published_at.map(|t| t.to_rfc3339()),
+        created_at: e.created_at.to_rfc3339(),
+        publish_at: e.publish_at.map(|t| t.to_rfc3339()),
+        publish_error: e.publish_error.clone(),
+        row_count: None,
+    }
+}
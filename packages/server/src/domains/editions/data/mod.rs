@@ -1,3 +1,5 @@
+pub mod broadsheet;
 pub mod types;
 
+pub use broadsheet::*;
 pub use types::*;
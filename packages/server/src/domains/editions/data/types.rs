@@ -1,5 +1,6 @@
 //! Edition-specific data transfer types used by activities and HTTP handlers.
 
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -11,6 +12,165 @@ pub struct BroadsheetDraft {
     /// Widget-standalone rows to interleave with the post rows at persistence time.
     /// Each entry specifies which row index it should be inserted AFTER.
     pub widget_rows: Vec<BroadsheetWidgetRow>,
+    /// How the rows were chosen and how they scored. Set by `generate_broadsheet`.
+    #[serde(default)]
+    pub report: Option<LayoutReport>,
+    /// Per-post eligibility and placement decisions. Set by `generate_broadsheet`.
+    #[serde(default)]
+    pub trace: Option<LayoutTrace>,
+}
+
+/// How the layout engine picks row templates.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LayoutStrategy {
+    /// Phase-budgeted greedy selection (no backtracking).
+    #[default]
+    Greedy,
+    /// Beam search over row sequences, scored by `LayoutScore`. Falls back
+    /// to the greedy layout when it can't beat it within the state budget.
+    Search,
+}
+
+/// Options for a layout run. The defaults reproduce the greedy engine.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LayoutOptions {
+    pub strategy: LayoutStrategy,
+    /// Seeds tie-breaking between equally scored candidates, so the same
+    /// seed and post pool always produce the same layout.
+    pub seed: u64,
+    /// Partial layouts kept per search step.
+    pub beam_width: usize,
+    /// Scored partial layouts the search may expand before it stops. A
+    /// count rather than a deadline, so the result doesn't depend on load.
+    pub max_states: usize,
+}
+
+impl Default for LayoutOptions {
+    fn default() -> Self {
+        Self {
+            strategy: LayoutStrategy::Greedy,
+            seed: 0,
+            beam_width: 8,
+            max_states: 5_000,
+        }
+    }
+}
+
+/// Per-term breakdown of the layout objective. Each term is in [0, 1]
+/// (1 = ideal); `total` is their weighted mean.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LayoutScore {
+    /// Placed editorial weight against the county's `target_content_weight`.
+    pub target_weight: f64,
+    /// Stacked cells matching their anchor cell's height.
+    pub height_balance: f64,
+    /// Posts sharing a cell sharing a post_type.
+    pub family_cohesion: f64,
+    /// Hero rows above mid rows above dense rows.
+    pub density_progression: f64,
+    /// Rows of the same topic sitting together.
+    pub topic_grouping: f64,
+    pub total: f64,
+}
+
+/// How a draft was produced: the strategy asked for and used, and the
+/// score of both the chosen layout and the greedy baseline.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayoutReport {
+    pub requested: LayoutStrategy,
+    pub used: LayoutStrategy,
+    pub seed: u64,
+    /// Why a search run returned the greedy layout instead.
+    pub fallback_reason: Option<String>,
+    /// Partial layouts scored by the search (0 for greedy runs).
+    pub states_explored: usize,
+    pub elapsed_ms: u64,
+    pub score: LayoutScore,
+    pub greedy_score: LayoutScore,
+    /// Pinned slots put back in place.
+    #[serde(default)]
+    pub pins_honoured: usize,
+    /// Pins that were dropped, and why.
+    #[serde(default)]
+    pub unhonoured_pins: Vec<UnhonouredPin>,
+}
+
+/// Why each candidate post did or didn't make an edition. Candidates are the
+/// posts matching the county's geography that are active or changed status
+/// recently; posts outside that set were never considered.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayoutTrace {
+    pub county_id: Uuid,
+    pub period_start: NaiveDate,
+    /// Pseudo counties (Statewide) use the statewide eligibility rules.
+    pub statewide: bool,
+    pub target_content_weight: i32,
+    /// Editorial weight of the placed posts (heavy=3, medium=2, light=1).
+    pub placed_weight: i32,
+    pub layout: Option<LayoutReport>,
+    pub posts: Vec<PostTrace>,
+}
+
+/// One candidate post: each eligibility clause, then where it landed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PostTrace {
+    pub post_id: Uuid,
+    pub title: String,
+    pub status: String,
+    pub post_type: String,
+    pub weight: String,
+    pub priority: i32,
+    pub eligible: bool,
+    pub clauses: Vec<EligibilityClause>,
+    pub placement: PostPlacement,
+}
+
+/// A post is eligible when every `status` clause passes and at least one
+/// clause in each of the `geography` and `window` groups passes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EligibilityClause {
+    /// `status`, `geography` or `window`.
+    pub group: String,
+    /// e.g. `resolved_county`, `service_area_tag`, `published_in_window`.
+    pub clause: String,
+    pub passed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum PostPlacement {
+    Placed {
+        row_index: usize,
+        row_template_slug: String,
+        slot_index: i32,
+        post_template_slug: String,
+    },
+    Unplaced {
+        reason: UnplacedReason,
+        detail: String,
+        /// Row templates with a slot that accepts this post's type and weight.
+        compatible_row_templates: Vec<String>,
+    },
+    /// Failed eligibility; see the clauses.
+    Ineligible,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UnplacedReason {
+    /// No post template renders this post_type at this weight.
+    NoPostTemplate,
+    /// No row template has a slot for this post_type at this weight.
+    NoRowTemplate,
+    /// The edition hit its weight target before a fitting row was chosen.
+    WeightTargetMet,
+    /// Fitting row templates exist but none was chosen (variety rules, or
+    /// their other slots couldn't be filled).
+    NotSelected,
+    /// Fitting rows were chosen but filled by other posts.
+    Outcompeted,
 }
 
 /// A widget row to be inserted into the broadsheet at a specific position.
@@ -25,6 +185,9 @@ pub struct BroadsheetWidgetRow {
     pub insert_after: usize,
     /// The row_template_config_id (widget-standalone, widget-pair, or widget-trio).
     pub row_template_id: Uuid,
+    /// An editor pinned the whole row.
+    #[serde(default)]
+    pub pinned: bool,
 }
 
 /// A single widget placement within a widget row.
@@ -33,6 +196,8 @@ pub struct BroadsheetWidgetSlot {
     pub widget_id: Uuid,
     pub widget_template: Option<String>,
     pub slot_index: i32,
+    #[serde(default)]
+    pub pinned: bool,
 }
 
 /// A topic section in the broadsheet draft (created from Root Signal topic data).
@@ -43,6 +208,13 @@ pub struct BroadsheetSection {
     pub topic_slug: Option<String>,
     /// Indices into BroadsheetDraft.rows that belong to this section.
     pub row_indices: Vec<usize>,
+    /// Indices into BroadsheetDraft.widget_rows that belong to this section.
+    /// Only pinned sections carry widget rows.
+    #[serde(default)]
+    pub widget_row_indices: Vec<usize>,
+    /// An editor pinned the section (heading and rows).
+    #[serde(default)]
+    pub pinned: bool,
 }
 
 /// A single row in the broadsheet draft.
@@ -53,6 +225,9 @@ pub struct BroadsheetRow {
     pub slots: Vec<BroadsheetSlot>,
     /// Priority of highest-priority post in this row (for ordering).
     pub max_priority: i32,
+    /// An editor pinned the whole row.
+    #[serde(default)]
+    pub pinned: bool,
 }
 
 /// A post placement within a broadsheet row.
@@ -61,6 +236,96 @@ pub struct BroadsheetSlot {
     pub post_id: Uuid,
     pub post_template_slug: String,
     pub slot_index: i32,
+    #[serde(default)]
+    pub pinned: bool,
+}
+
+/// Editor pins read from an edition before it is regenerated.
+#[derive(Debug, Clone, Default)]
+pub struct LayoutPins {
+    pub rows: Vec<PinnedRow>,
+    pub sections: Vec<PinnedSection>,
+}
+
+/// A row with something pinned in it: the whole row (directly or through
+/// its section) or just some of its slots.
+#[derive(Debug, Clone)]
+pub struct PinnedRow {
+    pub row_id: Uuid,
+    /// What the editor pinned. `Slot` means only `cells` are pinned and the
+    /// rest of the row is refilled.
+    pub kind: PinKind,
+    /// 0-based index of the row in the edition before regeneration.
+    pub position: usize,
+    pub row_template_id: Uuid,
+    /// Index into `LayoutPins.sections` when the row's section is pinned.
+    pub section: Option<usize>,
+    pub cells: Vec<PinnedCell>,
+}
+
+/// One pinned slot: a post with its post template, or a widget.
+#[derive(Debug, Clone)]
+pub struct PinnedCell {
+    pub slot_id: Uuid,
+    pub slot_index: i32,
+    pub post_id: Option<Uuid>,
+    pub post_template: Option<String>,
+    pub widget_id: Option<Uuid>,
+    pub widget_template: Option<String>,
+}
+
+/// A pinned section heading.
+#[derive(Debug, Clone)]
+pub struct PinnedSection {
+    pub section_id: Uuid,
+    /// 0-based index of the section in the edition before regeneration.
+    pub position: usize,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub topic_slug: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PinKind {
+    Slot,
+    Row,
+    Section,
+}
+
+/// A pinned slot the layout engine dropped. Its post goes back into the
+/// pool and may still be placed elsewhere.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnhonouredPin {
+    pub kind: PinKind,
+    /// The pinned slot, row or section, as it was before regeneration.
+    pub pin_id: Uuid,
+    pub row_position: usize,
+    pub slot_index: i32,
+    pub post_id: Option<Uuid>,
+    pub widget_id: Option<Uuid>,
+    pub reason: PinFailure,
+    pub detail: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PinFailure {
+    /// The post is no longer eligible: expired, archived, deleted, or
+    /// outside the county or the edition's window.
+    PostIneligible,
+    /// The widget was deleted or is outside its date range.
+    WidgetUnavailable,
+    /// The row template was deleted.
+    RowTemplateMissing,
+    /// The row template no longer has a slot at this index.
+    SlotMissing,
+    /// The post template was deleted.
+    PostTemplateMissing,
+    /// An earlier pin already holds this post or widget.
+    Duplicate,
+    /// Posts and widgets pinned in the same row; the widgets are dropped.
+    MixedRow,
 }
 
 /// A lightweight post representation used by the layout engine.
@@ -73,6 +338,9 @@ pub struct LayoutPost {
     pub priority: i32,
     /// Topic slug from Root Signal (via tags with kind='topic').
     pub topic_slug: Option<String>,
+    /// Earliest event occurrence (one-off or recurring) inside the
+    /// edition's event horizon. Date-driven cells list events in this order.
+    pub next_occurrence: Option<DateTime<Utc>>,
 }
 
 /// Result of batch edition generation.
@@ -93,3 +361,96 @@ pub struct EditionKanbanStats {
     pub approved: i32,
     pub published: i32,
 }
+
+/// A proposed post template, as authored in the admin. Create and update
+/// both take the full definition (see `template_authoring`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PostTemplateDraft {
+    pub slug: String,
+    pub display_name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub compatible_types: Vec<String>,
+    pub body_target: i32,
+    pub body_max: i32,
+    pub title_max: i32,
+    #[serde(default)]
+    pub sort_order: i32,
+    pub weight: String,
+    pub height_units: i32,
+    /// Per-post-type height overrides: {"reference": 6, ...}.
+    #[serde(default)]
+    pub height_override: Option<serde_json::Value>,
+}
+
+/// A proposed row template with its slots. Slots are listed in order;
+/// the position in the list is the slot's `slot_index`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RowTemplateDraft {
+    pub slug: String,
+    pub display_name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub layout_variant: String,
+    #[serde(default)]
+    pub sort_order: i32,
+    pub slots: Vec<RowSlotDraft>,
+}
+
+/// One slot group of a `RowTemplateDraft`. `count_min`/`count_max`
+/// default to `count` (exact fill), as in migration 225.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RowSlotDraft {
+    pub weight: String,
+    pub count: i32,
+    #[serde(default)]
+    pub count_min: Option<i32>,
+    #[serde(default)]
+    pub count_max: Option<i32>,
+    #[serde(default)]
+    pub accepts: Option<Vec<String>>,
+    #[serde(default)]
+    pub post_template_slug: Option<String>,
+}
+
+impl RowSlotDraft {
+    pub fn count_min(&self) -> i32 {
+        self.count_min.unwrap_or(self.count)
+    }
+
+    pub fn count_max(&self) -> i32 {
+        self.count_max.unwrap_or(self.count)
+    }
+}
+
+/// A proposed widget placement rule. Optional fields take the column
+/// defaults from migration 260.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WidgetRuleDraft {
+    pub after_row: i32,
+    pub widget_type: String,
+    #[serde(default = "default_widget_count")]
+    pub count: i32,
+    #[serde(default)]
+    pub min_rows: i32,
+    #[serde(default)]
+    pub rows_after: i32,
+    #[serde(default = "default_min_gap")]
+    pub min_gap: i32,
+    #[serde(default)]
+    pub at_section_start: bool,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_widget_count() -> i32 {
+    1
+}
+
+fn default_min_gap() -> i32 {
+    1
+}
+
+fn default_enabled() -> bool {
+    true
+}
@@ -0,0 +1,94 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
+
+/// A county's boundary as GeoJSON (`Polygon` or `MultiPolygon`, lng/lat),
+/// with its bounding box. See migration 258.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct CountyBoundary {
+    pub county_id: Uuid,
+    pub geometry: serde_json::Value,
+    pub min_lat: f64,
+    pub max_lat: f64,
+    pub min_lng: f64,
+    pub max_lng: f64,
+    pub source: Option<String>,
+    pub loaded_at: DateTime<Utc>,
+}
+
+impl CountyBoundary {
+    /// Insert or replace a county's boundary.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn upsert(
+        county_id: Uuid,
+        geometry: &serde_json::Value,
+        min_lat: f64,
+        max_lat: f64,
+        min_lng: f64,
+        max_lng: f64,
+        source: Option<&str>,
+        pool: &PgPool,
+    ) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO county_boundaries
+                (county_id, geometry, min_lat, max_lat, min_lng, max_lng, source)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (county_id) DO UPDATE
+            SET geometry = EXCLUDED.geometry,
+                min_lat = EXCLUDED.min_lat,
+                max_lat = EXCLUDED.max_lat,
+                min_lng = EXCLUDED.min_lng,
+                max_lng = EXCLUDED.max_lng,
+                source = EXCLUDED.source,
+                loaded_at = NOW()
+            "#,
+        )
+        .bind(county_id)
+        .bind(geometry)
+        .bind(min_lat)
+        .bind(max_lat)
+        .bind(min_lng)
+        .bind(max_lng)
+        .bind(source)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn find_all(pool: &PgPool) -> Result<Vec<Self>> {
+        sqlx::query_as::<_, Self>("SELECT * FROM county_boundaries")
+            .fetch_all(pool)
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Boundaries whose bounding box contains the point — usually one,
+    /// two or three near a county line.
+    pub async fn find_containing_bbox(
+        lat: f64,
+        lng: f64,
+        db: impl PgExecutor<'_>,
+    ) -> Result<Vec<Self>> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            SELECT * FROM county_boundaries
+            WHERE $1 BETWEEN min_lat AND max_lat
+              AND $2 BETWEEN min_lng AND max_lng
+            "#,
+        )
+        .bind(lat)
+        .bind(lng)
+        .fetch_all(db)
+        .await
+        .map_err(Into::into)
+    }
+
+    pub async fn count(pool: &PgPool) -> Result<i64> {
+        sqlx::query_scalar("SELECT COUNT(*) FROM county_boundaries")
+            .fetch_one(pool)
+            .await
+            .map_err(Into::into)
+    }
+}
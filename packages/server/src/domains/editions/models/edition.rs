@@ -1,6 +1,6 @@
 use anyhow::Result;
 use chrono::{DateTime, NaiveDate, Utc};
-use sqlx::PgPool;
+use sqlx::{Acquire, PgExecutor, PgPool, Postgres};
 use uuid::Uuid;
 
 /// A county-scoped weekly edition (broadsheet). One edition per county per period.
@@ -15,12 +15,19 @@ pub struct Edition {
     pub published_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// When the scheduled publisher should publish this edition.
+    pub publish_at: Option<DateTime<Utc>>,
+    /// Why the last scheduled publish attempt failed, if it did.
+    pub publish_error: Option<String>,
 }
 
 /// Filters for listing editions.
 #[derive(Debug, Default)]
 pub struct EditionFilters {
     pub county_id: Option<Uuid>,
+    /// Only these counties — a county-scoped editor's grants. `None` is
+    /// no restriction.
+    pub county_ids: Option<Vec<Uuid>>,
     pub status: Option<String>,
     pub period_start: Option<NaiveDate>,
     pub period_end: Option<NaiveDate>,
@@ -54,10 +61,20 @@ impl Edition {
     }
 
     /// Find an edition by ID.
-    pub async fn find_by_id(id: Uuid, pool: &PgPool) -> Result<Option<Self>> {
+    pub async fn find_by_id(id: Uuid, db: impl PgExecutor<'_>) -> Result<Option<Self>> {
         sqlx::query_as::<_, Self>("SELECT * FROM editions WHERE id = $1")
             .bind(id)
-            .fetch_optional(pool)
+            .fetch_optional(db)
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Lock an edition's row for the rest of the transaction, so layout
+    /// edits to the same edition queue behind each other.
+    pub async fn lock(id: Uuid, db: impl PgExecutor<'_>) -> Result<Option<Self>> {
+        sqlx::query_as::<_, Self>("SELECT * FROM editions WHERE id = $1 FOR UPDATE")
+            .bind(id)
+            .fetch_optional(db)
             .await
             .map_err(Into::into)
     }
@@ -116,14 +133,19 @@ impl Edition {
 
     /// Returns the most recent edition for every county (one row per county).
     /// Uses PostgreSQL DISTINCT ON to efficiently pick the latest per county_id.
-    pub async fn latest_per_county(pool: &PgPool) -> Result<Vec<Self>> {
+    pub async fn latest_per_county(
+        county_ids: Option<&[Uuid]>,
+        pool: &PgPool,
+    ) -> Result<Vec<Self>> {
         sqlx::query_as::<_, Self>(
             r#"
             SELECT DISTINCT ON (county_id) *
             FROM editions
+            WHERE $1::uuid[] IS NULL OR county_id = ANY($1)
             ORDER BY county_id, period_start DESC, created_at DESC
             "#,
         )
+        .bind(county_ids)
         .fetch_all(pool)
         .await
         .map_err(Into::into)
@@ -141,12 +163,14 @@ impl Edition {
               AND ($2::text IS NULL OR status = $2)
               AND ($3::date IS NULL OR period_start = $3)
               AND ($4::date IS NULL OR period_end = $4)
+              AND ($5::uuid[] IS NULL OR county_id = ANY($5))
             "#,
         )
         .bind(filters.county_id)
         .bind(&filters.status)
         .bind(filters.period_start)
         .bind(filters.period_end)
+        .bind(&filters.county_ids)
         .fetch_one(pool)
         .await?;
 
@@ -157,6 +181,7 @@ impl Edition {
               AND ($2::text IS NULL OR status = $2)
               AND ($5::date IS NULL OR period_start = $5)
               AND ($6::date IS NULL OR period_end = $6)
+              AND ($7::uuid[] IS NULL OR county_id = ANY($7))
             ORDER BY period_start DESC, created_at DESC
             LIMIT $3 OFFSET $4
             "#,
@@ -167,6 +192,7 @@ impl Edition {
         .bind(offset)
         .bind(filters.period_start)
         .bind(filters.period_end)
+        .bind(&filters.county_ids)
         .fetch_all(pool)
         .await?;
 
@@ -209,18 +235,77 @@ impl Edition {
     /// time — re-publishes after an unpublish preserve the original timestamp
     /// (semantics: "when did this edition first go live"). `updated_at` bumps
     /// every call so row-level history still moves forward.
-    pub async fn publish(id: Uuid, pool: &PgPool) -> Result<Self> {
+    pub async fn publish(id: Uuid, db: impl PgExecutor<'_>) -> Result<Self> {
         sqlx::query_as::<_, Self>(
             r#"
             UPDATE editions
             SET status = 'published',
                 published_at = COALESCE(published_at, NOW()),
+                publish_at = NULL,
+                publish_error = NULL,
                 updated_at = NOW()
             WHERE id = $1 AND status IN ('approved', 'draft')
             RETURNING *
             "#,
         )
         .bind(id)
+        .fetch_one(db)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Set (or with `None`, clear) the scheduled publish time. Clears any
+    /// error left by a previous scheduled attempt.
+    pub async fn schedule_publish(
+        id: Uuid,
+        publish_at: Option<DateTime<Utc>>,
+        pool: &PgPool,
+    ) -> Result<Self> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            UPDATE editions
+            SET publish_at = $2, publish_error = NULL, updated_at = NOW()
+            WHERE id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(publish_at)
+        .fetch_one(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Find unpublished editions whose scheduled publish time has passed.
+    pub async fn find_due_for_publish(now: DateTime<Utc>, pool: &PgPool) -> Result<Vec<Self>> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            SELECT * FROM editions
+            WHERE publish_at IS NOT NULL
+              AND publish_at <= $1
+              AND status IN ('draft', 'in_review', 'approved')
+            ORDER BY publish_at
+            "#,
+        )
+        .bind(now)
+        .fetch_all(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Record a failed scheduled publish and drop the schedule so the
+    /// attempt isn't repeated until an editor reschedules.
+    pub async fn record_publish_failure(id: Uuid, error: &str, pool: &PgPool) -> Result<Self> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            UPDATE editions
+            SET publish_at = NULL, publish_error = $2, updated_at = NOW()
+            WHERE id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(error)
         .fetch_one(pool)
         .await
         .map_err(Into::into)
@@ -266,6 +351,7 @@ impl Edition {
     pub async fn count_by_status(
         period_start: NaiveDate,
         period_end: NaiveDate,
+        county_ids: Option<&[Uuid]>,
         pool: &PgPool,
     ) -> Result<Vec<(String, i64)>> {
         sqlx::query_as::<_, (String, i64)>(
@@ -273,16 +359,95 @@ impl Edition {
             SELECT status, COUNT(*)::bigint
             FROM editions
             WHERE period_start = $1 AND period_end = $2
+              AND ($3::uuid[] IS NULL OR county_id = ANY($3))
             GROUP BY status
             "#,
         )
         .bind(period_start)
         .bind(period_end)
+        .bind(county_ids)
         .fetch_all(pool)
         .await
         .map_err(Into::into)
     }
 
+    /// County of an edition, for authorization. `None` if it doesn't exist.
+    pub async fn find_county_id(id: Uuid, pool: &PgPool) -> Result<Option<Uuid>> {
+        sqlx::query_scalar("SELECT county_id FROM editions WHERE id = $1")
+            .bind(id)
+            .fetch_optional(pool)
+            .await
+            .map_err(Into::into)
+    }
+
+    /// County of the edition an edition row belongs to.
+    pub async fn find_county_id_by_row(row_id: Uuid, pool: &PgPool) -> Result<Option<Uuid>> {
+        sqlx::query_scalar(
+            r#"
+            SELECT e.county_id
+            FROM edition_rows er
+            JOIN editions e ON e.id = er.edition_id
+            WHERE er.id = $1
+            "#,
+        )
+        .bind(row_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// The edition an edition slot belongs to.
+    pub async fn find_id_by_slot(slot_id: Uuid, pool: &PgPool) -> Result<Option<Uuid>> {
+        sqlx::query_scalar(
+            r#"
+            SELECT er.edition_id
+            FROM edition_slots es
+            JOIN edition_rows er ON er.id = es.edition_row_id
+            WHERE es.id = $1
+            "#,
+        )
+        .bind(slot_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// County of the edition an edition slot belongs to.
+    pub async fn find_county_id_by_slot(slot_id: Uuid, pool: &PgPool) -> Result<Option<Uuid>> {
+        sqlx::query_scalar(
+            r#"
+            SELECT e.county_id
+            FROM edition_slots es
+            JOIN edition_rows er ON er.id = es.edition_row_id
+            JOIN editions e ON e.id = er.edition_id
+            WHERE es.id = $1
+            "#,
+        )
+        .bind(slot_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// County of the edition an edition section belongs to.
+    pub async fn find_county_id_by_section(
+        section_id: Uuid,
+        pool: &PgPool,
+    ) -> Result<Option<Uuid>> {
+        sqlx::query_scalar(
+            r#"
+            SELECT e.county_id
+            FROM edition_sections s
+            JOIN editions e ON e.id = s.edition_id
+            WHERE s.id = $1
+            "#,
+        )
+        .bind(section_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(Into::into)
+    }
+
     /// Delete an edition (cascades to rows + slots).
     pub async fn delete(id: Uuid, pool: &PgPool) -> Result<()> {
         sqlx::query("DELETE FROM editions WHERE id = $1")
@@ -293,7 +458,7 @@ impl Edition {
     }
 
     /// Reset an edition back to draft status (used when regenerating a reviewed/approved edition).
-    pub async fn reset_to_draft(id: Uuid, pool: &PgPool) -> Result<Self> {
+    pub async fn reset_to_draft(id: Uuid, db: impl PgExecutor<'_>) -> Result<Self> {
         sqlx::query_as::<_, Self>(
             r#"
             UPDATE editions
@@ -303,23 +468,25 @@ impl Edition {
             "#,
         )
         .bind(id)
-        .fetch_one(pool)
+        .fetch_one(db)
         .await
         .map_err(Into::into)
     }
 
     /// Clear all rows and slots for an edition (used before re-generating).
-    pub async fn clear_layout(id: Uuid, pool: &PgPool) -> Result<()> {
+    pub async fn clear_layout(id: Uuid, db: impl Acquire<'_, Database = Postgres>) -> Result<()> {
+        let mut tx = db.begin().await?;
         // Delete sections first (ON DELETE SET NULL will unlink rows from sections)
         sqlx::query("DELETE FROM edition_sections WHERE edition_id = $1")
             .bind(id)
-            .execute(pool)
+            .execute(&mut *tx)
             .await?;
         // Then delete rows (cascades to slots and widgets)
         sqlx::query("DELETE FROM edition_rows WHERE edition_id = $1")
             .bind(id)
-            .execute(pool)
+            .execute(&mut *tx)
             .await?;
+        tx.commit().await?;
         Ok(())
     }
 }
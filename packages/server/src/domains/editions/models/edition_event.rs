@@ -0,0 +1,169 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use sqlx::{Acquire, PgExecutor, PgPool, Postgres};
+use uuid::Uuid;
+
+/// One recorded mutation of an edition. Layout events carry the full
+/// layout before and after and can be undone; lifecycle events (status
+/// changes) carry neither.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct EditionEvent {
+    pub id: Uuid,
+    pub seq: i64,
+    pub edition_id: Uuid,
+    pub actor_id: Option<Uuid>,
+    pub op: String,
+    pub params: serde_json::Value,
+    pub before: Option<serde_json::Value>,
+    pub after: Option<serde_json::Value>,
+    pub status: String,
+    pub created_at: DateTime<Utc>,
+    pub reverted_at: Option<DateTime<Utc>>,
+}
+
+/// Position of a layout event on the undo/redo stack.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct EditionEventRef {
+    pub id: Uuid,
+    pub seq: i64,
+    pub status: String,
+}
+
+impl EditionEvent {
+    /// Record a layout event. Anything that could still be redone is
+    /// discarded: the new edit starts a new branch of history.
+    pub async fn record_layout(
+        edition_id: Uuid,
+        actor_id: Option<Uuid>,
+        op: &str,
+        params: serde_json::Value,
+        before: serde_json::Value,
+        after: serde_json::Value,
+        db: impl Acquire<'_, Database = Postgres>,
+    ) -> Result<Self> {
+        let mut tx = db.begin().await?;
+        sqlx::query(
+            r#"
+            UPDATE edition_events
+            SET status = 'discarded'
+            WHERE edition_id = $1 AND status = 'undone'
+            "#,
+        )
+        .bind(edition_id)
+        .execute(&mut *tx)
+        .await?;
+
+        let event = sqlx::query_as::<_, Self>(
+            r#"
+            INSERT INTO edition_events (edition_id, actor_id, op, params, before, after)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING *
+            "#,
+        )
+        .bind(edition_id)
+        .bind(actor_id)
+        .bind(op)
+        .bind(params)
+        .bind(before)
+        .bind(after)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(event)
+    }
+
+    /// Record a lifecycle event (review, approve, publish, ...).
+    pub async fn record_lifecycle(
+        edition_id: Uuid,
+        actor_id: Option<Uuid>,
+        op: &str,
+        params: serde_json::Value,
+        pool: &PgPool,
+    ) -> Result<Self> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            INSERT INTO edition_events (edition_id, actor_id, op, params)
+            VALUES ($1, $2, $3, $4)
+            RETURNING *
+            "#,
+        )
+        .bind(edition_id)
+        .bind(actor_id)
+        .bind(op)
+        .bind(params)
+        .fetch_one(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// An edition's events, newest first.
+    pub async fn find_by_edition(
+        edition_id: Uuid,
+        limit: i64,
+        offset: i64,
+        pool: &PgPool,
+    ) -> Result<Vec<Self>> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            SELECT * FROM edition_events
+            WHERE edition_id = $1
+            ORDER BY seq DESC
+            LIMIT $2 OFFSET $3
+            "#,
+        )
+        .bind(edition_id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Find events by id.
+    pub async fn find_by_ids(ids: &[Uuid], db: impl PgExecutor<'_>) -> Result<Vec<Self>> {
+        sqlx::query_as::<_, Self>("SELECT * FROM edition_events WHERE id = ANY($1) ORDER BY seq")
+            .bind(ids)
+            .fetch_all(db)
+            .await
+            .map_err(Into::into)
+    }
+
+    /// The undo/redo stack: an edition's applied and undone layout events,
+    /// oldest first.
+    pub async fn layout_stack(
+        edition_id: Uuid,
+        db: impl PgExecutor<'_>,
+    ) -> Result<Vec<EditionEventRef>> {
+        sqlx::query_as::<_, EditionEventRef>(
+            r#"
+            SELECT id, seq, status FROM edition_events
+            WHERE edition_id = $1
+              AND before IS NOT NULL
+              AND status IN ('applied', 'undone')
+            ORDER BY seq ASC
+            "#,
+        )
+        .bind(edition_id)
+        .fetch_all(db)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Mark events undone (`reverted_at` set) or re-applied (cleared).
+    pub async fn set_status(ids: &[Uuid], status: &str, db: impl PgExecutor<'_>) -> Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE edition_events
+            SET status = $2,
+                reverted_at = CASE WHEN $2 = 'undone' THEN NOW() ELSE NULL END
+            WHERE id = ANY($1)
+            "#,
+        )
+        .bind(ids)
+        .bind(status)
+        .execute(db)
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
+
+use crate::domains::editions::data::types::LayoutTrace;
+
+/// The layout trace recorded the last time an edition was generated.
+/// One row per edition, replaced on every regeneration.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct EditionLayoutTrace {
+    pub edition_id: Uuid,
+    pub trace: serde_json::Value,
+    pub generated_at: DateTime<Utc>,
+}
+
+impl EditionLayoutTrace {
+    /// Store (or replace) the trace for an edition.
+    pub async fn upsert(
+        edition_id: Uuid,
+        trace: &LayoutTrace,
+        db: impl PgExecutor<'_>,
+    ) -> Result<Self> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            INSERT INTO edition_layout_traces (edition_id, trace, generated_at)
+            VALUES ($1, $2, NOW())
+            ON CONFLICT (edition_id)
+            DO UPDATE SET trace = EXCLUDED.trace, generated_at = EXCLUDED.generated_at
+            RETURNING *
+            "#,
+        )
+        .bind(edition_id)
+        .bind(serde_json::to_value(trace)?)
+        .fetch_one(db)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Find the trace for an edition, if it has been generated since traces
+    /// were introduced.
+    pub async fn find_by_edition(edition_id: Uuid, pool: &PgPool) -> Result<Option<Self>> {
+        sqlx::query_as::<_, Self>("SELECT * FROM edition_layout_traces WHERE edition_id = $1")
+            .bind(edition_id)
+            .fetch_optional(pool)
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Deserialize the stored trace.
+    pub fn parsed(&self) -> Result<LayoutTrace> {
+        serde_json::from_value(self.trace.clone()).map_err(Into::into)
+    }
+}
@@ -1,6 +1,6 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
-use sqlx::PgPool;
+use sqlx::{Acquire, PgExecutor, PgPool, Postgres};
 use uuid::Uuid;
 
 /// An ordered row within an edition, referencing a row template.
@@ -13,6 +13,8 @@ pub struct EditionRow {
     pub sort_order: i32,
     pub section_id: Option<Uuid>,
     pub created_at: DateTime<Utc>,
+    /// Kept, with all its slots, when the edition is regenerated.
+    pub pinned: bool,
 }
 
 impl EditionRow {
@@ -21,7 +23,7 @@ impl EditionRow {
         edition_id: Uuid,
         row_template_config_id: Uuid,
         sort_order: i32,
-        pool: &PgPool,
+        db: impl PgExecutor<'_>,
     ) -> Result<Self> {
         sqlx::query_as::<_, Self>(
             r#"
@@ -33,22 +35,32 @@ impl EditionRow {
         .bind(edition_id)
         .bind(row_template_config_id)
         .bind(sort_order)
-        .fetch_one(pool)
+        .fetch_one(db)
         .await
         .map_err(Into::into)
     }
 
     /// Find all rows for an edition, ordered by sort_order.
-    pub async fn find_by_edition(edition_id: Uuid, pool: &PgPool) -> Result<Vec<Self>> {
+    pub async fn find_by_edition(edition_id: Uuid, db: impl PgExecutor<'_>) -> Result<Vec<Self>> {
         sqlx::query_as::<_, Self>(
             "SELECT * FROM edition_rows WHERE edition_id = $1 ORDER BY sort_order ASC",
         )
         .bind(edition_id)
-        .fetch_all(pool)
+        .fetch_all(db)
         .await
         .map_err(Into::into)
     }
 
+    /// Pin or unpin a row.
+    pub async fn set_pinned(id: Uuid, pinned: bool, db: impl PgExecutor<'_>) -> Result<Self> {
+        sqlx::query_as::<_, Self>("UPDATE edition_rows SET pinned = $2 WHERE id = $1 RETURNING *")
+            .bind(id)
+            .bind(pinned)
+            .fetch_one(db)
+            .await
+            .map_err(Into::into)
+    }
+
     /// Find a single row by ID.
     pub async fn find_by_id(id: Uuid, pool: &PgPool) -> Result<Option<Self>> {
         sqlx::query_as::<_, Self>("SELECT * FROM edition_rows WHERE id = $1")
@@ -63,7 +75,7 @@ impl EditionRow {
         id: Uuid,
         row_template_config_id: Option<Uuid>,
         sort_order: Option<i32>,
-        pool: &PgPool,
+        db: impl PgExecutor<'_>,
     ) -> Result<Self> {
         sqlx::query_as::<_, Self>(
             r#"
@@ -77,13 +89,18 @@ impl EditionRow {
         .bind(id)
         .bind(row_template_config_id)
         .bind(sort_order)
-        .fetch_one(pool)
+        .fetch_one(db)
         .await
         .map_err(Into::into)
     }
 
     /// Reorder rows within an edition. Takes the row IDs in their new order.
-    pub async fn reorder(edition_id: Uuid, row_ids: &[Uuid], pool: &PgPool) -> Result<Vec<Self>> {
+    pub async fn reorder(
+        edition_id: Uuid,
+        row_ids: &[Uuid],
+        db: impl Acquire<'_, Database = Postgres>,
+    ) -> Result<Vec<Self>> {
+        let mut tx = db.begin().await?;
         for (i, row_id) in row_ids.iter().enumerate() {
             sqlx::query(
                 "UPDATE edition_rows SET sort_order = $1 WHERE id = $2 AND edition_id = $3",
@@ -91,18 +108,20 @@ impl EditionRow {
             .bind(i as i32)
             .bind(row_id)
             .bind(edition_id)
-            .execute(pool)
+            .execute(&mut *tx)
             .await?;
         }
 
-        Self::find_by_edition(edition_id, pool).await
+        let reordered = Self::find_by_edition(edition_id, &mut *tx).await?;
+        tx.commit().await?;
+        Ok(reordered)
     }
 
     /// Assign a row to a section (or ungroup it by passing None).
     pub async fn assign_to_section(
         id: Uuid,
         section_id: Option<Uuid>,
-        pool: &PgPool,
+        db: impl PgExecutor<'_>,
     ) -> Result<Self> {
         sqlx::query_as::<_, Self>(
             r#"
@@ -113,16 +132,16 @@ impl EditionRow {
         )
         .bind(id)
         .bind(section_id)
-        .fetch_one(pool)
+        .fetch_one(db)
         .await
         .map_err(Into::into)
     }
 
     /// Delete a row (cascades to its slots).
-    pub async fn delete(id: Uuid, pool: &PgPool) -> Result<()> {
+    pub async fn delete(id: Uuid, db: impl PgExecutor<'_>) -> Result<()> {
         sqlx::query("DELETE FROM edition_rows WHERE id = $1")
             .bind(id)
-            .execute(pool)
+            .execute(db)
             .await?;
         Ok(())
     }
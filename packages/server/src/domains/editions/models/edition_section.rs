@@ -1,6 +1,6 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
-use sqlx::PgPool;
+use sqlx::{Acquire, PgExecutor, PgPool, Postgres};
 use uuid::Uuid;
 
 /// A topic section within an edition. Groups rows by topic for visual separation.
@@ -18,6 +18,8 @@ pub struct EditionSection {
     pub topic_slug: Option<String>,
     pub sort_order: i32,
     pub created_at: DateTime<Utc>,
+    /// Kept, with all its rows, when the edition is regenerated.
+    pub pinned: bool,
 }
 
 impl EditionSection {
@@ -28,7 +30,7 @@ impl EditionSection {
         subtitle: Option<&str>,
         topic_slug: Option<&str>,
         sort_order: i32,
-        pool: &PgPool,
+        db: impl PgExecutor<'_>,
     ) -> Result<Self> {
         sqlx::query_as::<_, Self>(
             r#"
@@ -42,18 +44,30 @@ impl EditionSection {
         .bind(subtitle)
         .bind(topic_slug)
         .bind(sort_order)
-        .fetch_one(pool)
+        .fetch_one(db)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Pin or unpin a section.
+    pub async fn set_pinned(id: Uuid, pinned: bool, db: impl PgExecutor<'_>) -> Result<Self> {
+        sqlx::query_as::<_, Self>(
+            "UPDATE edition_sections SET pinned = $2 WHERE id = $1 RETURNING *",
+        )
+        .bind(id)
+        .bind(pinned)
+        .fetch_one(db)
         .await
         .map_err(Into::into)
     }
 
     /// Find all sections for an edition, ordered by sort_order.
-    pub async fn find_by_edition(edition_id: Uuid, pool: &PgPool) -> Result<Vec<Self>> {
+    pub async fn find_by_edition(edition_id: Uuid, db: impl PgExecutor<'_>) -> Result<Vec<Self>> {
         sqlx::query_as::<_, Self>(
             "SELECT * FROM edition_sections WHERE edition_id = $1 ORDER BY sort_order ASC",
         )
         .bind(edition_id)
-        .fetch_all(pool)
+        .fetch_all(db)
         .await
         .map_err(Into::into)
     }
@@ -73,7 +87,7 @@ impl EditionSection {
         title: Option<&str>,
         subtitle: Option<Option<&str>>,
         topic_slug: Option<Option<&str>>,
-        pool: &PgPool,
+        db: impl PgExecutor<'_>,
     ) -> Result<Self> {
         // Build update dynamically to handle nullable optional fields
         sqlx::query_as::<_, Self>(
@@ -92,13 +106,18 @@ impl EditionSection {
         .bind(subtitle.flatten())
         .bind(topic_slug.is_some())
         .bind(topic_slug.flatten())
-        .fetch_one(pool)
+        .fetch_one(db)
         .await
         .map_err(Into::into)
     }
 
     /// Reorder sections within an edition.
-    pub async fn reorder(edition_id: Uuid, section_ids: &[Uuid], pool: &PgPool) -> Result<Vec<Self>> {
+    pub async fn reorder(
+        edition_id: Uuid,
+        section_ids: &[Uuid],
+        db: impl Acquire<'_, Database = Postgres>,
+    ) -> Result<Vec<Self>> {
+        let mut tx = db.begin().await?;
         for (i, section_id) in section_ids.iter().enumerate() {
             sqlx::query(
                 "UPDATE edition_sections SET sort_order = $1 WHERE id = $2 AND edition_id = $3",
@@ -106,19 +125,21 @@ impl EditionSection {
             .bind(i as i32)
             .bind(section_id)
             .bind(edition_id)
-            .execute(pool)
+            .execute(&mut *tx)
             .await?;
         }
 
-        Self::find_by_edition(edition_id, pool).await
+        let reordered = Self::find_by_edition(edition_id, &mut *tx).await?;
+        tx.commit().await?;
+        Ok(reordered)
     }
 
     /// Delete a section. Rows assigned to it will have section_id set to NULL
     /// (via ON DELETE SET NULL in the FK constraint).
-    pub async fn delete(id: Uuid, pool: &PgPool) -> Result<()> {
+    pub async fn delete(id: Uuid, db: impl PgExecutor<'_>) -> Result<()> {
         sqlx::query("DELETE FROM edition_sections WHERE id = $1")
             .bind(id)
-            .execute(pool)
+            .execute(db)
             .await?;
         Ok(())
     }
@@ -1,6 +1,6 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
-use sqlx::PgPool;
+use sqlx::{Acquire, PgExecutor, PgPool, Postgres};
 use uuid::Uuid;
 
 /// A slot within an edition row — can hold either a post or a widget.
@@ -16,6 +16,8 @@ pub struct EditionSlot {
     pub slot_index: i32,
     pub sort_order: i32,
     pub created_at: DateTime<Utc>,
+    /// Kept, with its post and template, when the edition is regenerated.
+    pub pinned: bool,
 }
 
 /// A post slot with its post data pre-loaded (avoids N+1 queries).
@@ -31,6 +33,7 @@ pub struct SlotWithPost {
     pub slot_index: i32,
     pub sort_order: i32,
     pub created_at: DateTime<Utc>,
+    pub pinned: bool,
     // Post fields (joined from posts table, nullable for widget slots)
     pub post_title: Option<String>,
     pub post_post_type: Option<String>,
@@ -57,10 +60,11 @@ impl EditionSlot {
         post_id: Uuid,
         post_template: &str,
         slot_index: i32,
-        pool: &PgPool,
+        db: impl Acquire<'_, Database = Postgres>,
     ) -> Result<Self> {
-        let sort_order = Self::next_sort_order(edition_row_id, slot_index, pool).await?;
-        sqlx::query_as::<_, Self>(
+        let mut tx = db.begin().await?;
+        let sort_order = Self::next_sort_order(edition_row_id, slot_index, &mut *tx).await?;
+        let slot = sqlx::query_as::<_, Self>(
             r#"
             INSERT INTO edition_slots (edition_row_id, kind, post_id, post_template, slot_index, sort_order)
             VALUES ($1, 'post', $2, $3, $4, $5)
@@ -72,9 +76,10 @@ impl EditionSlot {
         .bind(post_template)
         .bind(slot_index)
         .bind(sort_order)
-        .fetch_one(pool)
-        .await
-        .map_err(Into::into)
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(slot)
     }
 
     /// Create a new widget slot.
@@ -83,10 +88,11 @@ impl EditionSlot {
         widget_id: Uuid,
         widget_template: Option<&str>,
         slot_index: i32,
-        pool: &PgPool,
+        db: impl Acquire<'_, Database = Postgres>,
     ) -> Result<Self> {
-        let sort_order = Self::next_sort_order(edition_row_id, slot_index, pool).await?;
-        sqlx::query_as::<_, Self>(
+        let mut tx = db.begin().await?;
+        let sort_order = Self::next_sort_order(edition_row_id, slot_index, &mut *tx).await?;
+        let slot = sqlx::query_as::<_, Self>(
             r#"
             INSERT INTO edition_slots (edition_row_id, kind, widget_id, widget_template, slot_index, sort_order)
             VALUES ($1, 'widget', $2, $3, $4, $5)
@@ -98,23 +104,24 @@ impl EditionSlot {
         .bind(widget_template)
         .bind(slot_index)
         .bind(sort_order)
-        .fetch_one(pool)
-        .await
-        .map_err(Into::into)
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(slot)
     }
 
     /// Next free `sort_order` value for a (row, slot_index) pair.
     async fn next_sort_order(
         edition_row_id: Uuid,
         slot_index: i32,
-        pool: &PgPool,
+        db: impl PgExecutor<'_>,
     ) -> Result<i32> {
         let max: Option<i32> = sqlx::query_scalar(
             "SELECT MAX(sort_order) FROM edition_slots WHERE edition_row_id = $1 AND slot_index = $2",
         )
         .bind(edition_row_id)
         .bind(slot_index)
-        .fetch_one(pool)
+        .fetch_one(db)
         .await?;
         Ok(max.map(|n| n + 1).unwrap_or(0))
     }
@@ -132,7 +139,7 @@ impl EditionSlot {
     }
 
     /// Find all slots across all rows of an edition (via JOIN).
-    pub async fn find_by_edition(edition_id: Uuid, pool: &PgPool) -> Result<Vec<Self>> {
+    pub async fn find_by_edition(edition_id: Uuid, db: impl PgExecutor<'_>) -> Result<Vec<Self>> {
         sqlx::query_as::<_, Self>(
             r#"
             SELECT es.*
@@ -143,7 +150,7 @@ impl EditionSlot {
             "#,
         )
         .bind(edition_id)
-        .fetch_all(pool)
+        .fetch_all(db)
         .await
         .map_err(Into::into)
     }
@@ -157,9 +164,9 @@ impl EditionSlot {
         target_row_id: Uuid,
         slot_index: i32,
         sort_order: Option<i32>,
-        pool: &PgPool,
+        db: impl Acquire<'_, Database = Postgres>,
     ) -> Result<Self> {
-        let mut tx = pool.begin().await?;
+        let mut tx = db.begin().await?;
 
         // Determine the target sort_order — if unspecified, append to end.
         let resolved_sort_order: i32 = match sort_order {
@@ -242,7 +249,11 @@ impl EditionSlot {
     }
 
     /// Change the post template (visual treatment) for a post slot.
-    pub async fn change_template(id: Uuid, post_template: &str, pool: &PgPool) -> Result<Self> {
+    pub async fn change_template(
+        id: Uuid,
+        post_template: &str,
+        db: impl PgExecutor<'_>,
+    ) -> Result<Self> {
         sqlx::query_as::<_, Self>(
             r#"
             UPDATE edition_slots
@@ -253,16 +264,42 @@ impl EditionSlot {
         )
         .bind(id)
         .bind(post_template)
-        .fetch_one(pool)
+        .fetch_one(db)
         .await
         .map_err(Into::into)
     }
 
+    /// Pin or unpin a slot.
+    pub async fn set_pinned(id: Uuid, pinned: bool, db: impl PgExecutor<'_>) -> Result<Self> {
+        sqlx::query_as::<_, Self>("UPDATE edition_slots SET pinned = $2 WHERE id = $1 RETURNING *")
+            .bind(id)
+            .bind(pinned)
+            .fetch_one(db)
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Mark the slots in a row holding any of `post_ids` as pinned.
+    pub async fn pin_posts_in_row(
+        edition_row_id: Uuid,
+        post_ids: &[Uuid],
+        db: impl PgExecutor<'_>,
+    ) -> Result<()> {
+        sqlx::query(
+            "UPDATE edition_slots SET pinned = true WHERE edition_row_id = $1 AND post_id = ANY($2)",
+        )
+        .bind(edition_row_id)
+        .bind(post_ids)
+        .execute(db)
+        .await?;
+        Ok(())
+    }
+
     /// Delete a slot (remove a post or widget from the edition).
-    pub async fn delete(id: Uuid, pool: &PgPool) -> Result<()> {
+    pub async fn delete(id: Uuid, db: impl PgExecutor<'_>) -> Result<()> {
         sqlx::query("DELETE FROM edition_slots WHERE id = $1")
             .bind(id)
-            .execute(pool)
+            .execute(db)
             .await?;
         Ok(())
     }
@@ -277,6 +314,7 @@ impl EditionSlot {
             SELECT
                 es.id, es.edition_row_id, es.kind, es.post_id, es.widget_id,
                 es.post_template, es.widget_template, es.slot_index, es.sort_order, es.created_at,
+                es.pinned,
                 p.title AS post_title,
                 p.post_type AS post_post_type,
                 p.weight AS post_weight,
@@ -304,19 +342,27 @@ impl EditionSlot {
     pub async fn replace_for_row(
         edition_row_id: Uuid,
         slots: &[(Uuid, String, i32)], // (post_id, post_template, slot_index)
-        pool: &PgPool,
+        db: impl Acquire<'_, Database = Postgres>,
     ) -> Result<Vec<Self>> {
+        let mut tx = db.begin().await?;
         sqlx::query("DELETE FROM edition_slots WHERE edition_row_id = $1")
             .bind(edition_row_id)
-            .execute(pool)
+            .execute(&mut *tx)
             .await?;
 
         let mut results = Vec::with_capacity(slots.len());
         for (post_id, post_template, slot_index) in slots {
-            let slot =
-                Self::create(edition_row_id, *post_id, post_template, *slot_index, pool).await?;
+            let slot = Self::create(
+                edition_row_id,
+                *post_id,
+                post_template,
+                *slot_index,
+                &mut *tx,
+            )
+            .await?;
             results.push(slot);
         }
+        tx.commit().await?;
         Ok(results)
     }
 }
@@ -0,0 +1,215 @@
+use anyhow::Result;
+use chrono::{DateTime, NaiveDate, Utc};
+use sha2::{Digest, Sha256};
+use sqlx::{PgConnection, PgExecutor, PgPool};
+use uuid::Uuid;
+
+use crate::domains::editions::models::edition::Edition;
+
+/// A frozen copy of a published edition's public broadsheet. Rows are
+/// immutable; re-publishing with changed content adds the next version.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct EditionSnapshot {
+    pub id: Uuid,
+    pub edition_id: Uuid,
+    pub county_id: Uuid,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub version: i32,
+    pub content_hash: String,
+    pub snapshot: serde_json::Value,
+    pub published_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// One back issue in a county's archive: the latest snapshot of a
+/// published (or since archived) edition, without the snapshot body.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct BackIssue {
+    pub edition_id: Uuid,
+    pub title: Option<String>,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub version: i32,
+    pub content_hash: String,
+    pub published_at: DateTime<Utc>,
+}
+
+/// SHA-256 (hex) of a snapshot, over a canonical serialization with
+/// object keys sorted at every level, so equal content hashes the same
+/// however its maps were built.
+pub fn content_hash(snapshot: &serde_json::Value) -> String {
+    let mut canonical = String::new();
+    write_canonical(snapshot, &mut canonical);
+    let mut h = Sha256::new();
+    h.update(canonical.as_bytes());
+    format!("{:x}", h.finalize())
+}
+
+fn write_canonical(value: &serde_json::Value, out: &mut String) {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by_key(|(k, _)| k.as_str());
+            out.push('{');
+            for (i, (key, value)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&serde_json::Value::from(key.as_str()).to_string());
+                out.push(':');
+                write_canonical(value, out);
+            }
+            out.push('}');
+        }
+        serde_json::Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(']');
+        }
+        scalar => out.push_str(&scalar.to_string()),
+    }
+}
+
+impl EditionSnapshot {
+    /// Freeze a published edition's broadsheet. Returns the latest snapshot
+    /// unchanged when its content hash matches; otherwise inserts the next
+    /// version.
+    pub async fn freeze(
+        edition: &Edition,
+        snapshot: &serde_json::Value,
+        conn: &mut PgConnection,
+    ) -> Result<Self> {
+        let hash = content_hash(snapshot);
+        let latest = Self::find_latest_for_edition(edition.id, &mut *conn).await?;
+        if let Some(latest) = latest.as_ref().filter(|s| s.content_hash == hash) {
+            return Ok(latest.clone());
+        }
+        let version = latest.map(|s| s.version + 1).unwrap_or(1);
+
+        sqlx::query_as::<_, Self>(
+            r#"
+            INSERT INTO edition_snapshots
+                (edition_id, county_id, period_start, period_end, version,
+                 content_hash, snapshot, published_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, COALESCE($8, NOW()))
+            RETURNING *
+            "#,
+        )
+        .bind(edition.id)
+        .bind(edition.county_id)
+        .bind(edition.period_start)
+        .bind(edition.period_end)
+        .bind(version)
+        .bind(&hash)
+        .bind(snapshot)
+        .bind(edition.published_at)
+        .fetch_one(conn)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Find the most recent snapshot of an edition.
+    pub async fn find_latest_for_edition(
+        edition_id: Uuid,
+        db: impl PgExecutor<'_>,
+    ) -> Result<Option<Self>> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            SELECT * FROM edition_snapshots
+            WHERE edition_id = $1
+            ORDER BY version DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(edition_id)
+        .fetch_optional(db)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// List a county's back issues, newest period first. Editions that
+    /// were unpublished are withheld until they are published again.
+    pub async fn list_back_issues(
+        county_id: Uuid,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+        limit: i64,
+        offset: i64,
+        pool: &PgPool,
+    ) -> Result<Vec<BackIssue>> {
+        sqlx::query_as::<_, BackIssue>(
+            r#"
+            SELECT DISTINCT ON (e.period_start, s.edition_id)
+                s.edition_id, e.title, s.period_start, s.period_end,
+                s.version, s.content_hash, s.published_at
+            FROM edition_snapshots s
+            JOIN editions e ON e.id = s.edition_id
+            WHERE s.county_id = $1
+              AND e.status IN ('published', 'archived')
+              AND ($2::date IS NULL OR s.period_start >= $2)
+              AND ($3::date IS NULL OR s.period_start <= $3)
+            ORDER BY e.period_start DESC, s.edition_id, s.version DESC
+            LIMIT $4 OFFSET $5
+            "#,
+        )
+        .bind(county_id)
+        .bind(from)
+        .bind(to)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Find a county's back issue for a period: the given version, or the
+    /// latest. Withheld while the edition is unpublished.
+    pub async fn find_back_issue(
+        county_id: Uuid,
+        period_start: NaiveDate,
+        version: Option<i32>,
+        pool: &PgPool,
+    ) -> Result<Option<Self>> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            SELECT s.* FROM edition_snapshots s
+            JOIN editions e ON e.id = s.edition_id
+            WHERE s.county_id = $1
+              AND s.period_start = $2
+              AND ($3::int IS NULL OR s.version = $3)
+              AND e.status IN ('published', 'archived')
+            ORDER BY s.version DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(county_id)
+        .bind(period_start)
+        .bind(version)
+        .fetch_optional(pool)
+        .await
+        .map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn content_hash_ignores_key_order() {
+        let a = json!({"edition": {"id": 1, "title": "Aitkin"}, "rows": []});
+        let b = json!({"rows": [], "edition": {"title": "Aitkin", "id": 1}});
+        assert_eq!(content_hash(&a), content_hash(&b));
+        assert_eq!(content_hash(&a).len(), 64);
+
+        let edited = json!({"edition": {"id": 1, "title": "Aitkin County"}, "rows": []});
+        assert_ne!(content_hash(&a), content_hash(&edited));
+    }
+}
@@ -1,9 +1,14 @@
 pub mod county;
+pub mod county_boundary;
 pub mod edition;
+pub mod edition_event;
+pub mod edition_layout_trace;
 pub mod edition_row;
 pub mod edition_section;
+pub mod edition_snapshot;
 pub mod edition_slot;
 pub mod post_template_config;
 pub mod row_template_config;
 pub mod row_template_slot;
 pub mod zip_county;
+pub mod widget_placement_rule;
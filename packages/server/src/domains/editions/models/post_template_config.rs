@@ -1,8 +1,10 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool};
 use uuid::Uuid;
 
+use crate::domains::editions::data::types::PostTemplateDraft;
+
 /// A post visual treatment template (e.g. "feature", "gazette", "ticker").
 /// Defines which post types it can render, character limits, and weight tier.
 #[derive(Debug, Clone, sqlx::FromRow)]
@@ -27,9 +29,9 @@ pub struct PostTemplateConfig {
 
 impl PostTemplateConfig {
     /// Load all post templates, ordered by sort_order.
-    pub async fn find_all(pool: &PgPool) -> Result<Vec<Self>> {
+    pub async fn find_all(db: impl PgExecutor<'_>) -> Result<Vec<Self>> {
         sqlx::query_as::<_, Self>("SELECT * FROM post_template_configs ORDER BY sort_order ASC")
-            .fetch_all(pool)
+            .fetch_all(db)
             .await
             .map_err(Into::into)
     }
@@ -43,6 +45,123 @@ impl PostTemplateConfig {
             .map_err(Into::into)
     }
 
+    /// Find a post template by id.
+    pub async fn find_by_id(id: Uuid, db: impl PgExecutor<'_>) -> Result<Option<Self>> {
+        sqlx::query_as::<_, Self>("SELECT * FROM post_template_configs WHERE id = $1")
+            .bind(id)
+            .fetch_optional(db)
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Insert a post template. Validate first (`template_authoring`).
+    pub async fn create(draft: &PostTemplateDraft, db: impl PgExecutor<'_>) -> Result<Self> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            INSERT INTO post_template_configs
+                (slug, display_name, description, compatible_types, body_target,
+                 body_max, title_max, sort_order, weight, height_units, height_override)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            RETURNING *
+            "#,
+        )
+        .bind(&draft.slug)
+        .bind(&draft.display_name)
+        .bind(&draft.description)
+        .bind(&draft.compatible_types)
+        .bind(draft.body_target)
+        .bind(draft.body_max)
+        .bind(draft.title_max)
+        .bind(draft.sort_order)
+        .bind(&draft.weight)
+        .bind(draft.height_units)
+        .bind(&draft.height_override)
+        .fetch_one(db)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Replace every editable field of a post template.
+    pub async fn update(
+        id: Uuid,
+        draft: &PostTemplateDraft,
+        db: impl PgExecutor<'_>,
+    ) -> Result<Self> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            UPDATE post_template_configs
+            SET slug = $2,
+                display_name = $3,
+                description = $4,
+                compatible_types = $5,
+                body_target = $6,
+                body_max = $7,
+                title_max = $8,
+                sort_order = $9,
+                weight = $10,
+                height_units = $11,
+                height_override = $12
+            WHERE id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(&draft.slug)
+        .bind(&draft.display_name)
+        .bind(&draft.description)
+        .bind(&draft.compatible_types)
+        .bind(draft.body_target)
+        .bind(draft.body_max)
+        .bind(draft.title_max)
+        .bind(draft.sort_order)
+        .bind(&draft.weight)
+        .bind(draft.height_units)
+        .bind(&draft.height_override)
+        .fetch_one(db)
+        .await
+        .map_err(Into::into)
+    }
+
+    pub async fn delete(id: Uuid, db: impl PgExecutor<'_>) -> Result<()> {
+        sqlx::query("DELETE FROM post_template_configs WHERE id = $1")
+            .bind(id)
+            .execute(db)
+            .await?;
+        Ok(())
+    }
+
+    /// Number of edition slots rendered with this template.
+    pub async fn count_edition_slots(slug: &str, db: impl PgExecutor<'_>) -> Result<i64> {
+        sqlx::query_scalar("SELECT COUNT(*) FROM edition_slots WHERE post_template = $1")
+            .bind(slug)
+            .fetch_one(db)
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Number of edition slots rendered with this template whose post's type
+    /// is not in `compatible_types`.
+    pub async fn count_incompatible_edition_slots(
+        slug: &str,
+        compatible_types: &[String],
+        db: impl PgExecutor<'_>,
+    ) -> Result<i64> {
+        sqlx::query_scalar(
+            r#"
+            SELECT COUNT(*)
+            FROM edition_slots es
+            JOIN posts p ON p.id = es.post_id
+            WHERE es.post_template = $1
+              AND NOT (p.post_type = ANY($2))
+            "#,
+        )
+        .bind(slug)
+        .bind(compatible_types)
+        .fetch_one(db)
+        .await
+        .map_err(Into::into)
+    }
+
     /// Check if this template is compatible with a given post type.
     pub fn is_compatible(&self, post_type: &str) -> bool {
         self.compatible_types.iter().any(|t| t == post_type)
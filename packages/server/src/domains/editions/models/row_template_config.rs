@@ -1,9 +1,10 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
-use sqlx::PgPool;
+use sqlx::{Acquire, PgExecutor, PgPool, Postgres, Transaction};
 use uuid::Uuid;
 
 use super::row_template_slot::RowTemplateSlot;
+use crate::domains::editions::data::types::{RowSlotDraft, RowTemplateDraft};
 
 /// A row layout template (recipe) for the broadsheet.
 /// Each template defines a CSS grid layout (`layout_variant`) and slot definitions
@@ -30,9 +31,9 @@ pub struct RowTemplateWithSlots {
 
 impl RowTemplateConfig {
     /// Load all row templates, ordered by sort_order.
-    pub async fn find_all(pool: &PgPool) -> Result<Vec<Self>> {
+    pub async fn find_all(db: impl PgExecutor<'_>) -> Result<Vec<Self>> {
         sqlx::query_as::<_, Self>("SELECT * FROM row_template_configs ORDER BY sort_order ASC")
-            .fetch_all(pool)
+            .fetch_all(db)
             .await
             .map_err(Into::into)
     }
@@ -47,18 +48,120 @@ impl RowTemplateConfig {
     }
 
     /// Find a row template by id.
-    pub async fn find_by_id(id: Uuid, pool: &PgPool) -> Result<Option<Self>> {
+    pub async fn find_by_id(id: Uuid, db: impl PgExecutor<'_>) -> Result<Option<Self>> {
         sqlx::query_as::<_, Self>("SELECT * FROM row_template_configs WHERE id = $1")
             .bind(id)
-            .fetch_optional(pool)
+            .fetch_optional(db)
             .await
             .map_err(Into::into)
     }
 
+    /// Insert a row template and its slots. Validate first
+    /// (`template_authoring`).
+    pub async fn create_with_slots(
+        draft: &RowTemplateDraft,
+        db: impl Acquire<'_, Database = Postgres>,
+    ) -> Result<RowTemplateWithSlots> {
+        let mut tx = db.begin().await?;
+        let config = sqlx::query_as::<_, Self>(
+            r#"
+            INSERT INTO row_template_configs
+                (slug, display_name, description, layout_variant, sort_order)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING *
+            "#,
+        )
+        .bind(&draft.slug)
+        .bind(&draft.display_name)
+        .bind(&draft.description)
+        .bind(&draft.layout_variant)
+        .bind(draft.sort_order)
+        .fetch_one(&mut *tx)
+        .await?;
+        let slots = insert_slots(config.id, &draft.slots, &mut tx).await?;
+        tx.commit().await?;
+        Ok(RowTemplateWithSlots { config, slots })
+    }
+
+    /// Replace a row template's fields and its whole slot list.
+    pub async fn update_with_slots(
+        id: Uuid,
+        draft: &RowTemplateDraft,
+        db: impl Acquire<'_, Database = Postgres>,
+    ) -> Result<RowTemplateWithSlots> {
+        let mut tx = db.begin().await?;
+        let config = sqlx::query_as::<_, Self>(
+            r#"
+            UPDATE row_template_configs
+            SET slug = $2,
+                display_name = $3,
+                description = $4,
+                layout_variant = $5,
+                sort_order = $6
+            WHERE id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(&draft.slug)
+        .bind(&draft.display_name)
+        .bind(&draft.description)
+        .bind(&draft.layout_variant)
+        .bind(draft.sort_order)
+        .fetch_one(&mut *tx)
+        .await?;
+        sqlx::query("DELETE FROM row_template_slots WHERE row_template_config_id = $1")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+        let slots = insert_slots(id, &draft.slots, &mut tx).await?;
+        tx.commit().await?;
+        Ok(RowTemplateWithSlots { config, slots })
+    }
+
+    /// Delete a row template (cascades to its slots).
+    pub async fn delete(id: Uuid, db: impl PgExecutor<'_>) -> Result<()> {
+        sqlx::query("DELETE FROM row_template_configs WHERE id = $1")
+            .bind(id)
+            .execute(db)
+            .await?;
+        Ok(())
+    }
+
+    /// Number of edition rows laid out with this template.
+    pub async fn count_edition_rows(id: Uuid, db: impl PgExecutor<'_>) -> Result<i64> {
+        sqlx::query_scalar("SELECT COUNT(*) FROM edition_rows WHERE row_template_config_id = $1")
+            .bind(id)
+            .fetch_one(db)
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Highest `slot_index` any edition slot uses in a row laid out with
+    /// this template.
+    pub async fn max_edition_slot_index(id: Uuid, db: impl PgExecutor<'_>) -> Result<Option<i32>> {
+        sqlx::query_scalar(
+            r#"
+            SELECT MAX(es.slot_index)
+            FROM edition_slots es
+            JOIN edition_rows er ON er.id = es.edition_row_id
+            WHERE er.row_template_config_id = $1
+            "#,
+        )
+        .bind(id)
+        .fetch_one(db)
+        .await
+        .map_err(Into::into)
+    }
+
     /// Load all row templates with their slot definitions.
-    pub async fn find_all_with_slots(pool: &PgPool) -> Result<Vec<RowTemplateWithSlots>> {
-        let configs = Self::find_all(pool).await?;
-        let all_slots = RowTemplateSlot::find_all(pool).await?;
+    pub async fn find_all_with_slots(
+        db: impl Acquire<'_, Database = Postgres>,
+    ) -> Result<Vec<RowTemplateWithSlots>> {
+        let mut tx = db.begin().await?;
+        let configs = Self::find_all(&mut *tx).await?;
+        let all_slots = RowTemplateSlot::find_all(&mut *tx).await?;
+        tx.commit().await?;
 
         let results = configs
             .into_iter()
@@ -75,3 +178,35 @@ impl RowTemplateConfig {
         Ok(results)
     }
 }
+
+/// Insert `slots` for a template, numbering them by position.
+async fn insert_slots(
+    template_id: Uuid,
+    slots: &[RowSlotDraft],
+    tx: &mut Transaction<'_, Postgres>,
+) -> Result<Vec<RowTemplateSlot>> {
+    let mut inserted = Vec::with_capacity(slots.len());
+    for (slot_index, slot) in slots.iter().enumerate() {
+        let row = sqlx::query_as::<_, RowTemplateSlot>(
+            r#"
+            INSERT INTO row_template_slots
+                (row_template_config_id, slot_index, weight, count, count_min, count_max,
+                 accepts, post_template_slug)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING *
+            "#,
+        )
+        .bind(template_id)
+        .bind(slot_index as i32)
+        .bind(&slot.weight)
+        .bind(slot.count)
+        .bind(slot.count_min())
+        .bind(slot.count_max())
+        .bind(&slot.accepts)
+        .bind(&slot.post_template_slug)
+        .fetch_one(&mut **tx)
+        .await?;
+        inserted.push(row);
+    }
+    Ok(inserted)
+}
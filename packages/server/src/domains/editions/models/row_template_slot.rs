@@ -1,5 +1,5 @@
 use anyhow::Result;
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool};
 use uuid::Uuid;
 
 /// A slot definition within a row template. Defines weight constraint, post count,
@@ -38,15 +38,24 @@ impl RowTemplateSlot {
     }
 
     /// Load all slot definitions across all templates (for bulk loading).
-    pub async fn find_all(pool: &PgPool) -> Result<Vec<Self>> {
+    pub async fn find_all(db: impl PgExecutor<'_>) -> Result<Vec<Self>> {
         sqlx::query_as::<_, Self>(
             "SELECT * FROM row_template_slots ORDER BY row_template_config_id, slot_index ASC",
         )
-        .fetch_all(pool)
+        .fetch_all(db)
         .await
         .map_err(Into::into)
     }
 
+    /// Number of slot definitions that name this post template.
+    pub async fn count_by_post_template(slug: &str, db: impl PgExecutor<'_>) -> Result<i64> {
+        sqlx::query_scalar("SELECT COUNT(*) FROM row_template_slots WHERE post_template_slug = $1")
+            .bind(slug)
+            .fetch_one(db)
+            .await
+            .map_err(Into::into)
+    }
+
     /// Check if this slot accepts a given post type.
     /// Returns true if `accepts` is None (any type) or contains the type.
     pub fn accepts_type(&self, post_type: &str) -> bool {
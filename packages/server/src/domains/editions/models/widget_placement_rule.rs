@@ -0,0 +1,117 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domains::editions::data::types::WidgetRuleDraft;
+
+/// Where the layout engine inserts a widget row. `county_id` None is the
+/// global rule set; a county with rules of its own uses only those.
+/// See migration 260 for the meaning of each constraint.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct WidgetPlacementRule {
+    pub id: Uuid,
+    pub county_id: Option<Uuid>,
+    pub sort_order: i32,
+    pub after_row: i32,
+    pub widget_type: String,
+    pub count: i32,
+    pub min_rows: i32,
+    pub rows_after: i32,
+    pub min_gap: i32,
+    pub at_section_start: bool,
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl WidgetPlacementRule {
+    /// Rules stored for one scope (a county, or global when None), in
+    /// evaluation order, disabled ones included.
+    pub async fn find_by_scope(county_id: Option<Uuid>, pool: &PgPool) -> Result<Vec<Self>> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            SELECT * FROM widget_placement_rules
+            WHERE county_id IS NOT DISTINCT FROM $1
+            ORDER BY sort_order ASC, after_row ASC
+            "#,
+        )
+        .bind(county_id)
+        .fetch_all(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// The rules a county's layout uses: its own if it has any, otherwise
+    /// the global set. Disabled rules are dropped.
+    pub async fn find_effective(county_id: Uuid, pool: &PgPool) -> Result<Vec<Self>> {
+        let mut rules = Self::find_by_scope(Some(county_id), pool).await?;
+        if rules.is_empty() {
+            rules = Self::find_by_scope(None, pool).await?;
+        }
+        rules.retain(|r| r.enabled);
+        Ok(rules)
+    }
+
+    /// Replace a scope's whole rule set; `sort_order` follows list order.
+    /// An empty list for a county drops its overrides, so it inherits the
+    /// global set again.
+    pub async fn replace_for_scope(
+        county_id: Option<Uuid>,
+        rules: &[WidgetRuleDraft],
+        pool: &PgPool,
+    ) -> Result<Vec<Self>> {
+        let mut tx = pool.begin().await?;
+        sqlx::query("DELETE FROM widget_placement_rules WHERE county_id IS NOT DISTINCT FROM $1")
+            .bind(county_id)
+            .execute(&mut *tx)
+            .await?;
+        let mut saved = Vec::with_capacity(rules.len());
+        for (sort_order, rule) in rules.iter().enumerate() {
+            let row = sqlx::query_as::<_, Self>(
+                r#"
+                INSERT INTO widget_placement_rules
+                    (county_id, sort_order, after_row, widget_type, count, min_rows,
+                     rows_after, min_gap, at_section_start, enabled)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+                RETURNING *
+                "#,
+            )
+            .bind(county_id)
+            .bind(sort_order as i32)
+            .bind(rule.after_row)
+            .bind(&rule.widget_type)
+            .bind(rule.count)
+            .bind(rule.min_rows)
+            .bind(rule.rows_after)
+            .bind(rule.min_gap)
+            .bind(rule.at_section_start)
+            .bind(rule.enabled)
+            .fetch_one(&mut *tx)
+            .await?;
+            saved.push(row);
+        }
+        tx.commit().await?;
+        Ok(saved)
+    }
+
+    /// An unsaved rule built from a draft, for dry runs.
+    pub fn from_draft(county_id: Option<Uuid>, sort_order: i32, draft: &WidgetRuleDraft) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            county_id,
+            sort_order,
+            after_row: draft.after_row,
+            widget_type: draft.widget_type.clone(),
+            count: draft.count,
+            min_rows: draft.min_rows,
+            rows_after: draft.rows_after,
+            min_gap: draft.min_gap,
+            at_section_start: draft.at_section_start,
+            enabled: draft.enabled,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
@@ -1,10 +1,11 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use sqlx::PgPool;
+use sqlx::{PgConnection, PgPool};
 use typed_builder::TypedBuilder;
 
 use crate::common::LocationId;
+use crate::kernel::GeocodeMatch;
 
 /// Physical, virtual, or postal location where services are delivered (HSDS-aligned)
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
@@ -23,6 +24,9 @@ pub struct Location {
     pub transportation_notes: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Set when the coordinates came from the geocoder (migration 259).
+    pub geocode_precision: Option<String>,
+    pub geocode_confidence: Option<f64>,
 }
 
 #[derive(TypedBuilder)]
@@ -83,6 +87,55 @@ impl Location {
         Ok(())
     }
 
+    /// Find or create a location for a geocoder match. Reuses a location at
+    /// the same coordinates and ZIP (every "Brainerd, MN" resolves to one
+    /// row), otherwise creates one recording the match's precision and
+    /// confidence.
+    pub async fn find_or_create_geocoded(
+        geocoded: &GeocodeMatch,
+        conn: &mut PgConnection,
+    ) -> Result<Self> {
+        let existing = sqlx::query_as::<_, Self>(
+            r#"
+            SELECT * FROM locations
+            WHERE latitude = $1 AND longitude = $2
+              AND postal_code IS NOT DISTINCT FROM $3
+              AND geocode_precision = $4
+            ORDER BY created_at
+            LIMIT 1
+            "#,
+        )
+        .bind(geocoded.latitude)
+        .bind(geocoded.longitude)
+        .bind(geocoded.postal_code.as_deref())
+        .bind(geocoded.precision.as_str())
+        .fetch_optional(&mut *conn)
+        .await?;
+        if let Some(loc) = existing {
+            return Ok(loc);
+        }
+
+        sqlx::query_as::<_, Self>(
+            r#"
+            INSERT INTO locations
+                (city, state, postal_code, latitude, longitude, location_type,
+                 geocode_precision, geocode_confidence)
+            VALUES ($1, $2, $3, $4, $5, 'physical', $6, $7)
+            RETURNING *
+            "#,
+        )
+        .bind(geocoded.city.as_deref())
+        .bind(geocoded.state.as_deref())
+        .bind(geocoded.postal_code.as_deref())
+        .bind(geocoded.latitude)
+        .bind(geocoded.longitude)
+        .bind(geocoded.precision.as_str())
+        .bind(geocoded.confidence)
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(Into::into)
+    }
+
     /// Find or create a location from AI-extracted structured data.
     ///
     /// Looks up the zip code to get lat/lng, then upserts by postal_code.
@@ -20,4 +20,53 @@ impl ZipCode {
             .map_err(Into::into)
     }
 
+    /// The ZIP whose centroid is closest to a point.
+    pub async fn find_nearest(lat: f64, lng: f64, pool: &PgPool) -> Result<Option<Self>> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            SELECT * FROM zip_codes
+            WHERE latitude BETWEEN $1 - 0.5 AND $1 + 0.5
+              AND longitude BETWEEN $2 - 0.7 AND $2 + 0.7
+            ORDER BY haversine_distance($1, $2, latitude, longitude)
+            LIMIT 1
+            "#,
+        )
+        .bind(lat)
+        .bind(lng)
+        .fetch_optional(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// City centroids (mean of the city's ZIP centroids) for the given
+    /// normalized city names — lowercase, with a leading "St." spelled
+    /// "saint". `zip_code` is the city's lowest ZIP.
+    pub async fn find_city_centroids(names: &[String], pool: &PgPool) -> Result<Vec<CityCentroid>> {
+        sqlx::query_as::<_, CityCentroid>(
+            r#"
+            SELECT MIN(city) AS city, MIN(state) AS state,
+                   AVG(latitude) AS latitude, AVG(longitude) AS longitude,
+                   MIN(zip_code) AS zip_code,
+                   regexp_replace(lower(city), '^(st\.?|saint)\s+', 'saint ') AS normalized
+            FROM zip_codes
+            WHERE regexp_replace(lower(city), '^(st\.?|saint)\s+', 'saint ') = ANY($1)
+            GROUP BY normalized
+            "#,
+        )
+        .bind(names)
+        .fetch_all(pool)
+        .await
+        .map_err(Into::into)
+    }
+}
+
+/// A city's centroid from `ZipCode::find_city_centroids`.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct CityCentroid {
+    pub city: String,
+    pub state: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub zip_code: String,
+    pub normalized: String,
 }
@@ -9,7 +9,7 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use serde::Serialize;
-use sqlx::PgPool;
+use sqlx::{Acquire, PgPool, Postgres};
 use uuid::Uuid;
 
 #[derive(Debug, Clone, Serialize, sqlx::FromRow)]
@@ -81,9 +81,9 @@ impl MediaReference {
         referenceable_type: &str,
         referenceable_id: Uuid,
         desired: &[DesiredRef],
-        pool: &PgPool,
+        db: impl Acquire<'_, Database = Postgres>,
     ) -> Result<()> {
-        let mut tx = pool.begin().await?;
+        let mut tx = db.begin().await?;
 
         sqlx::query(
             "DELETE FROM media_references
@@ -7,6 +7,7 @@ pub mod media;
 pub mod member;
 pub mod notes;
 pub mod organization;
+pub mod pii;
 pub mod posts;
 pub mod schedules;
 pub mod tag;
@@ -1,7 +1,9 @@
 pub mod organization;
+pub mod organization_calendar_feed;
 pub mod organization_checklist;
 pub mod organization_link;
 
 pub use organization::Organization;
+pub use organization_calendar_feed::OrganizationCalendarFeed;
 pub use organization_checklist::OrganizationChecklistItem;
 pub use organization_link::OrganizationLink;
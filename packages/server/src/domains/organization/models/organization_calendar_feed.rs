@@ -0,0 +1,152 @@
+//! Organization Calendar Feeds — iCalendar URLs the calendar importer
+//! pulls events from (see `posts::activities::calendar_import`).
+//!
+//! Each feed owns a `sources` row (`source_type = 'ical'`) that imported
+//! posts cite, created with the feed so `post_sources` always has
+//! something to point at. See migration 254 for the dedup design.
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::common::{OrganizationCalendarFeedId, OrganizationId};
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct OrganizationCalendarFeed {
+    pub id: OrganizationCalendarFeedId,
+    pub organization_id: OrganizationId,
+    pub url: String,
+    pub source_id: Option<Uuid>,
+    pub active: bool,
+    pub last_fetched_at: Option<DateTime<Utc>>,
+    /// Why the last import failed; cleared by the next good one.
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl OrganizationCalendarFeed {
+    /// All feeds for an org, oldest first.
+    pub async fn find_by_organization(
+        organization_id: OrganizationId,
+        pool: &PgPool,
+    ) -> Result<Vec<Self>> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            SELECT * FROM organization_calendar_feeds
+            WHERE organization_id = $1
+            ORDER BY created_at ASC
+            "#,
+        )
+        .bind(organization_id)
+        .fetch_all(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    pub async fn find_by_id(id: OrganizationCalendarFeedId, pool: &PgPool) -> Result<Option<Self>> {
+        sqlx::query_as::<_, Self>("SELECT * FROM organization_calendar_feeds WHERE id = $1")
+            .bind(id)
+            .fetch_optional(pool)
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Active feeds, for the periodic import job.
+    pub async fn find_active(pool: &PgPool) -> Result<Vec<Self>> {
+        sqlx::query_as::<_, Self>(
+            "SELECT * FROM organization_calendar_feeds WHERE active = true ORDER BY created_at ASC",
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Register a feed and its `ical` source in one transaction. The URL
+    /// must already have passed the SSRF guard.
+    pub async fn create(
+        organization_id: OrganizationId,
+        url: &str,
+        pool: &PgPool,
+    ) -> Result<Self> {
+        let mut tx = pool.begin().await?;
+        let source_id: Uuid = sqlx::query_scalar(
+            r#"
+            INSERT INTO sources (source_type, url, organization_id, status, active)
+            VALUES ('ical', $1, $2, 'approved', true)
+            RETURNING id
+            "#,
+        )
+        .bind(url)
+        .bind(organization_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let feed = sqlx::query_as::<_, Self>(
+            r#"
+            INSERT INTO organization_calendar_feeds (organization_id, url, source_id)
+            VALUES ($1, $2, $3)
+            RETURNING *
+            "#,
+        )
+        .bind(organization_id)
+        .bind(url)
+        .bind(source_id)
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(feed)
+    }
+
+    /// Pause or resume periodic imports.
+    pub async fn set_active(
+        id: OrganizationCalendarFeedId,
+        active: bool,
+        pool: &PgPool,
+    ) -> Result<Self> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            UPDATE organization_calendar_feeds
+            SET active = $2, updated_at = NOW()
+            WHERE id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(active)
+        .fetch_one(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Record the outcome of an import run. `error` is None on success.
+    pub async fn record_fetch(
+        id: OrganizationCalendarFeedId,
+        error: Option<&str>,
+        pool: &PgPool,
+    ) -> Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE organization_calendar_feeds
+            SET last_fetched_at = NOW(), last_error = $2, updated_at = NOW()
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .bind(error)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Remove a feed. Posts it imported keep their source citation.
+    pub async fn delete(id: OrganizationCalendarFeedId, pool: &PgPool) -> Result<()> {
+        sqlx::query("DELETE FROM organization_calendar_feeds WHERE id = $1")
+            .bind(id)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,211 @@
+//! Run the configured PII detector over content at write time.
+//!
+//! Each write path picks a [`ScrubPolicy`] (detection context + redaction
+//! strategy), scrubs its text fields *before* persisting them, and then
+//! records what was removed in `pii_findings` once the entity id is known.
+//! Content tied to an organisation passes that organisation's addresses as
+//! a [`PiiAllowList`] so its own street address isn't redacted.
+
+use anyhow::Result;
+use sqlx::{Acquire, PgPool, Postgres};
+use uuid::Uuid;
+
+use crate::common::pii::{mask_value, DetectionContext, PiiAllowList, RedactionStrategy};
+use crate::domains::locations::models::Locationable;
+use crate::domains::pii::models::{NewPiiFinding, PiiFinding};
+use crate::kernel::ServerDeps;
+
+/// How a given write path scrubs.
+#[derive(Debug, Clone, Copy)]
+pub struct ScrubPolicy {
+    pub context: DetectionContext,
+    pub strategy: RedactionStrategy,
+    /// Recorded on each finding (`pii_findings.source`).
+    pub source: &'static str,
+}
+
+impl ScrubPolicy {
+    /// Root Signal ingest. Machine-extracted community content: published
+    /// organisational contact details are expected and kept, anything else
+    /// becomes a typed token (`[PHONE]`) so the reviewing editor can see
+    /// what was taken out.
+    pub const INGEST: Self = Self {
+        context: DetectionContext::PublicContent,
+        strategy: RedactionStrategy::TokenReplacement,
+        source: "ingest",
+    };
+
+    /// Editor saves. Partial masking keeps enough of the value for the
+    /// editor to recognise what they typed (`j***@example.com`).
+    pub const EDITOR: Self = Self {
+        context: DetectionContext::PublicContent,
+        strategy: RedactionStrategy::PartialMask,
+        source: "editor",
+    };
+
+    /// Public notes shown alongside posts and organisations.
+    pub const PUBLIC_NOTE: Self = Self {
+        context: DetectionContext::PublicContent,
+        strategy: RedactionStrategy::TokenReplacement,
+        source: "note",
+    };
+}
+
+/// The scrubbed form of one text field plus the findings to record.
+#[derive(Debug, Clone)]
+pub struct PiiFieldScrub {
+    pub clean_text: String,
+    pub findings: Vec<NewPiiFinding>,
+}
+
+impl PiiFieldScrub {
+    pub fn pii_detected(&self) -> bool {
+        !self.findings.is_empty()
+    }
+}
+
+/// Allow list built from the street addresses of the given organisations'
+/// `Location`s.
+pub async fn organization_allow_list(
+    organization_ids: &[Uuid],
+    pool: &PgPool,
+) -> Result<PiiAllowList> {
+    let mut allow_list = PiiAllowList::new();
+    for org_id in organization_ids {
+        for location in Locationable::find_for_entity("organization", *org_id, pool).await? {
+            if let Some(line) = location.address_line_1.as_deref() {
+                allow_list.add_address(line);
+            }
+        }
+    }
+    Ok(allow_list)
+}
+
+/// Scrub a single text field.
+pub async fn scrub_field(
+    field: &str,
+    text: &str,
+    policy: ScrubPolicy,
+    allow_list: &PiiAllowList,
+    deps: &ServerDeps,
+) -> Result<PiiFieldScrub> {
+    let result = deps
+        .pii_detector
+        .scrub(text, policy.context, policy.strategy, allow_list)
+        .await?;
+    let findings = result
+        .findings
+        .matches
+        .iter()
+        .map(|m| NewPiiFinding {
+            field: field.to_string(),
+            pii_type: m.pii_type.as_str().to_string(),
+            masked_value: mask_value(&m.value, &m.pii_type),
+            start_offset: m.start as i32,
+            end_offset: m.end as i32,
+            detection_context: policy.context.as_str().to_string(),
+            redaction_strategy: policy.strategy.as_str().to_string(),
+        })
+        .collect();
+    Ok(PiiFieldScrub {
+        clean_text: result.clean_text,
+        findings,
+    })
+}
+
+/// Scrub an optional field, passing `None` through untouched.
+pub async fn scrub_optional_field(
+    field: &str,
+    text: Option<&str>,
+    policy: ScrubPolicy,
+    allow_list: &PiiAllowList,
+    deps: &ServerDeps,
+) -> Result<Option<PiiFieldScrub>> {
+    match text {
+        Some(t) => Ok(Some(scrub_field(field, t, policy, allow_list, deps).await?)),
+        None => Ok(None),
+    }
+}
+
+/// Scrub every `text` leaf of a rich-text body AST (Plate nodes). Returns
+/// the rewritten AST and the findings from all leaves under `body_ast`.
+pub async fn scrub_body_ast(
+    ast: &serde_json::Value,
+    policy: ScrubPolicy,
+    allow_list: &PiiAllowList,
+    deps: &ServerDeps,
+) -> Result<(serde_json::Value, Vec<NewPiiFinding>)> {
+    let mut out = ast.clone();
+    let mut leaves: Vec<&mut String> = Vec::new();
+    collect_text_leaves(&mut out, &mut leaves);
+
+    let mut findings = Vec::new();
+    for leaf in leaves {
+        let scrubbed = scrub_field("body_ast", leaf, policy, allow_list, deps).await?;
+        if scrubbed.pii_detected() {
+            *leaf = scrubbed.clean_text;
+            findings.extend(scrubbed.findings);
+        }
+    }
+    Ok((out, findings))
+}
+
+fn collect_text_leaves<'a>(value: &'a mut serde_json::Value, out: &mut Vec<&'a mut String>) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, v) in map.iter_mut() {
+                if key == "text" && v.is_string() {
+                    if let serde_json::Value::String(s) = v {
+                        out.push(s);
+                    }
+                } else {
+                    collect_text_leaves(v, out);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for v in items.iter_mut() {
+                collect_text_leaves(v, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Details for a `pii_detected` review flag: how many findings, and of
+/// which types.
+pub fn pii_detected_details(findings: &[NewPiiFinding]) -> serde_json::Value {
+    let mut pii_types: Vec<&str> = findings.iter().map(|f| f.pii_type.as_str()).collect();
+    pii_types.sort_unstable();
+    pii_types.dedup();
+    serde_json::json!({ "finding_count": findings.len(), "pii_types": pii_types })
+}
+
+/// Persist findings for an entity under the policy's source.
+pub async fn record_findings(
+    entity_type: &str,
+    entity_id: Uuid,
+    policy: ScrubPolicy,
+    findings: &[NewPiiFinding],
+    db: impl Acquire<'_, Database = Postgres>,
+) -> Result<()> {
+    PiiFinding::insert_many(entity_type, entity_id, policy.source, findings, db).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn collects_only_text_leaves() {
+        let mut ast = json!([
+            { "type": "p", "children": [{ "text": "call 612-555-0100" }, { "text": "" }] },
+            { "type": "photo_a", "mediaId": "abc", "children": [{ "text": "caption" }] },
+        ]);
+        let mut leaves = Vec::new();
+        collect_text_leaves(&mut ast, &mut leaves);
+        let texts: Vec<&str> = leaves.iter().map(|s| s.as_str()).collect();
+        assert_eq!(texts, vec!["call 612-555-0100", "", "caption"]);
+    }
+}
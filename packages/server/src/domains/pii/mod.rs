@@ -0,0 +1,7 @@
+//! PII scrubbing at content write paths, plus the `pii_findings` audit log.
+
+pub mod activities;
+pub mod models;
+
+pub use activities::scrub::{PiiFieldScrub, ScrubPolicy};
+pub use models::PiiFinding;
@@ -0,0 +1,3 @@
+pub mod pii_finding;
+
+pub use pii_finding::{NewPiiFinding, PiiFinding};
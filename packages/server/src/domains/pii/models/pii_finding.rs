@@ -0,0 +1,95 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::{Acquire, PgPool, Postgres};
+use uuid::Uuid;
+
+/// One redacted PII match, recorded against the entity it was found in.
+/// Holds a masked rendering of the value, never the raw value.
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct PiiFinding {
+    pub id: Uuid,
+    pub entity_type: String,
+    pub entity_id: Uuid,
+    pub field: String,
+    pub pii_type: String,
+    pub masked_value: String,
+    pub start_offset: i32,
+    pub end_offset: i32,
+    pub detection_context: String,
+    pub redaction_strategy: String,
+    pub source: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Insert payload for [`PiiFinding::insert_many`].
+#[derive(Debug, Clone)]
+pub struct NewPiiFinding {
+    pub field: String,
+    pub pii_type: String,
+    pub masked_value: String,
+    pub start_offset: i32,
+    pub end_offset: i32,
+    pub detection_context: String,
+    pub redaction_strategy: String,
+}
+
+impl PiiFinding {
+    /// Record a batch of findings for one entity in a single transaction.
+    pub async fn insert_many(
+        entity_type: &str,
+        entity_id: Uuid,
+        source: &str,
+        findings: &[NewPiiFinding],
+        db: impl Acquire<'_, Database = Postgres>,
+    ) -> Result<()> {
+        if findings.is_empty() {
+            return Ok(());
+        }
+        let mut tx = db.begin().await?;
+        for f in findings {
+            sqlx::query(
+                r#"
+                INSERT INTO pii_findings
+                    (entity_type, entity_id, field, pii_type, masked_value,
+                     start_offset, end_offset, detection_context, redaction_strategy, source)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+                "#,
+            )
+            .bind(entity_type)
+            .bind(entity_id)
+            .bind(&f.field)
+            .bind(&f.pii_type)
+            .bind(&f.masked_value)
+            .bind(f.start_offset)
+            .bind(f.end_offset)
+            .bind(&f.detection_context)
+            .bind(&f.redaction_strategy)
+            .bind(source)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// All findings for one entity, newest first.
+    pub async fn find_for_entity(
+        entity_type: &str,
+        entity_id: Uuid,
+        pool: &PgPool,
+    ) -> Result<Vec<Self>> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            SELECT * FROM pii_findings
+            WHERE entity_type = $1 AND entity_id = $2
+            ORDER BY created_at DESC, start_offset
+            "#,
+        )
+        .bind(entity_type)
+        .bind(entity_id)
+        .fetch_all(pool)
+        .await
+        .map_err(Into::into)
+    }
+}
@@ -1,12 +1,21 @@
 //! Backfill activities for posts - admin batch operations
 
 use anyhow::Result;
-use tracing::info;
+use sqlx::PgConnection;
+use tracing::{info, warn};
 
+use crate::common::PostId;
 use crate::domains::locations::models::Location;
+use crate::domains::posts::activities::county_resolution;
 use crate::domains::posts::models::post::Post;
 use crate::domains::posts::models::post_location::PostLocation;
-use crate::kernel::ServerDeps;
+use crate::kernel::{GeocodeMatch, GeocodePrecision, ServerDeps};
+
+/// How sure a landmark or ZIP geocode must be before its point goes on
+/// the post itself. Anything coarser (a city or region centroid) only
+/// describes the location row; on the post it would pin the map marker
+/// and proximity search to a spot the source never named.
+const POST_COORDINATES_MIN_CONFIDENCE: f64 = 0.7;
 
 /// Result of backfilling post locations
 #[derive(Debug)]
@@ -16,23 +25,13 @@ pub struct BackfillLocationsResult {
     pub remaining: i32,
 }
 
-/// Extract a 5-digit zip code from location text
-fn extract_zip_from_text(text: &str) -> Option<String> {
-    let re = regex::Regex::new(r"\b(\d{5})\b").ok()?;
-    re.find(text).map(|m| m.as_str().to_string())
-}
-
-/// Extract a city name from location text (assumes "City, ST" or "City, State" pattern)
-fn extract_city_from_text(text: &str) -> Option<String> {
-    let re = regex::Regex::new(r"(?i)^([A-Za-z\s]+),\s*(?:MN|Minnesota)").ok()?;
-    re.captures(text)
-        .map(|c| c.get(1).unwrap().as_str().trim().to_string())
-}
-
 /// Backfill location records for posts that have location text but no post_locations.
 ///
-/// Parses existing `location` text field to extract city/state/zip and creates
-/// Location + PostLocation records.
+/// Geocodes the `location` text with `deps.geocoder` (ZIP, city, or a
+/// gazetteer place) and creates Location + PostLocation records carrying
+/// the geocode's precision and confidence. Posts without coordinates get
+/// the geocoded ones when the match is precise enough (see
+/// [`attach_location`]), and their county is re-resolved.
 pub async fn backfill_post_locations(
     batch_size: i64,
     deps: &ServerDeps,
@@ -65,34 +64,11 @@ pub async fn backfill_post_locations(
     for post in &posts {
         let location_text = post.location.as_deref().unwrap_or_default();
 
-        let zip = extract_zip_from_text(location_text);
-        let city = extract_city_from_text(location_text);
-
-        if zip.is_none() && city.is_none() {
-            failed += 1;
-            continue;
-        }
-
-        match Location::find_or_create_from_extraction(
-            city.as_deref(),
-            Some("MN"),
-            zip.as_deref(),
-            None,
-            &deps.db_pool,
-        )
-        .await
-        {
-            Ok(loc) => {
-                if PostLocation::create(post.id, loc.id, true, None, &deps.db_pool)
-                    .await
-                    .is_ok()
-                {
-                    processed += 1;
-                } else {
-                    failed += 1;
-                }
-            }
-            Err(_) => {
+        match attach_geocoded_location(post.id, location_text, deps).await {
+            Ok(true) => processed += 1,
+            Ok(false) => failed += 1,
+            Err(e) => {
+                warn!(post_id = %post.id, error = %e, "Geocoded location backfill failed");
                 failed += 1;
             }
         }
@@ -121,3 +97,78 @@ pub async fn backfill_post_locations(
         remaining,
     })
 }
+
+/// Geocode `location_text` and attach the result as the post's primary
+/// location. Returns false when the text didn't geocode. Used by the
+/// backfill; ingest geocodes up front and calls [`attach_location`].
+pub async fn attach_geocoded_location(
+    post_id: PostId,
+    location_text: &str,
+    deps: &ServerDeps,
+) -> Result<bool> {
+    let Some(geocoded) = deps.geocoder.geocode(location_text).await? else {
+        return Ok(false);
+    };
+    let mut conn = deps.db_pool.acquire().await?;
+    attach_location(post_id, &geocoded, &mut conn).await?;
+    Ok(true)
+}
+
+/// Attach a geocode as the post's primary location and re-resolve its
+/// county. The point is copied onto a post without coordinates only for a
+/// confident landmark or ZIP match; lower-precision results stay on the
+/// location row.
+pub async fn attach_location(
+    post_id: PostId,
+    geocoded: &GeocodeMatch,
+    conn: &mut PgConnection,
+) -> Result<()> {
+    let loc = Location::find_or_create_geocoded(geocoded, &mut *conn).await?;
+    PostLocation::create(post_id, loc.id, true, None, &mut *conn).await?;
+    if pins_post(geocoded) {
+        Post::set_coordinates_if_missing(
+            post_id,
+            geocoded.latitude,
+            geocoded.longitude,
+            &mut *conn,
+        )
+        .await?;
+    }
+    county_resolution::resolve_post_county(post_id, &mut *conn).await?;
+    Ok(())
+}
+
+/// Whether a geocode is precise enough to become the post's coordinates.
+fn pins_post(geocoded: &GeocodeMatch) -> bool {
+    matches!(
+        geocoded.precision,
+        GeocodePrecision::Landmark | GeocodePrecision::PostalCode
+    ) && geocoded.confidence >= POST_COORDINATES_MIN_CONFIDENCE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn geocode(precision: GeocodePrecision, confidence: f64) -> GeocodeMatch {
+        GeocodeMatch {
+            latitude: 46.358,
+            longitude: -94.2,
+            city: Some("Brainerd".into()),
+            state: Some("MN".into()),
+            postal_code: Some("56401".into()),
+            precision,
+            confidence,
+        }
+    }
+
+    #[test]
+    fn only_confident_landmark_and_zip_matches_pin_the_post() {
+        assert!(pins_post(&geocode(GeocodePrecision::Landmark, 0.85)));
+        assert!(pins_post(&geocode(GeocodePrecision::PostalCode, 0.7)));
+        assert!(!pins_post(&geocode(GeocodePrecision::PostalCode, 0.5)));
+        assert!(!pins_post(&geocode(GeocodePrecision::City, 0.9)));
+        assert!(!pins_post(&geocode(GeocodePrecision::Area, 0.9)));
+        assert!(!pins_post(&geocode(GeocodePrecision::Region, 0.9)));
+    }
+}
@@ -0,0 +1,57 @@
+//! Calendar export - iCalendar for one event post, and per-county feeds
+
+use anyhow::Result;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::domains::editions::models::county::County;
+use crate::domains::posts::models::Post;
+use crate::domains::schedules::ical::{render_calendar, CalendarEvent};
+use crate::domains::schedules::models::Schedule;
+use crate::kernel::ServerDeps;
+
+/// One VEVENT per dated schedule on the post. Visibility is the caller's
+/// concern; this renders whatever post it is given.
+pub async fn post_calendar(post: &Post, deps: &ServerDeps) -> Result<String> {
+    let schedules = Schedule::find_for_post(post.id.into_uuid(), &deps.db_pool).await?;
+    let events: Vec<CalendarEvent<'_>> =
+        schedules.iter().map(|s| calendar_event(post, s)).collect();
+    Ok(render_calendar(&post.title, &events))
+}
+
+/// Subscribable feed of every active event post in the county's service
+/// area (see `Post::find_events_for_county`).
+pub async fn county_calendar(county: &County, deps: &ServerDeps) -> Result<String> {
+    let pool = &deps.db_pool;
+    let posts = Post::find_events_for_county(county.id, pool).await?;
+    let post_ids: Vec<Uuid> = posts.iter().map(|p| p.id.into_uuid()).collect();
+
+    let mut by_post: HashMap<Uuid, Vec<Schedule>> = HashMap::new();
+    for schedule in Schedule::find_for_post_ids(&post_ids, pool).await? {
+        by_post
+            .entry(schedule.schedulable_id)
+            .or_default()
+            .push(schedule);
+    }
+
+    let events: Vec<CalendarEvent<'_>> = posts
+        .iter()
+        .flat_map(|post| {
+            by_post
+                .get(&post.id.into_uuid())
+                .into_iter()
+                .flatten()
+                .map(move |s| calendar_event(post, s))
+        })
+        .collect();
+    Ok(render_calendar(&format!("{} events", county.name), &events))
+}
+
+fn calendar_event<'a>(post: &'a Post, schedule: &'a Schedule) -> CalendarEvent<'a> {
+    CalendarEvent {
+        schedule,
+        summary: &post.title,
+        description: post.body_light.as_deref().or(Some(post.body_raw.as_str())),
+        location: post.location.as_deref(),
+    }
+}
@@ -0,0 +1,473 @@
+//! iCalendar import — partner calendar feeds as a second ingest source.
+//!
+//! Entry point is [`import_feed`], run from the `import_calendar_feed`
+//! work item (every six hours per active feed) or on demand from
+//! `/Organizations/import_calendar_feed`.
+//!
+//! Lifecycle per feed:
+//!
+//!   1. `ssrf::validate_url` + `fetch::fetch` — the media-ingest guard and
+//!      fetcher (HTTPS only, 5s, 5 MiB, redirect targets re-checked).
+//!   2. `ical::parse_calendar`. VEVENTs without a UID or DTSTART and
+//!      overridden instances (`RECURRENCE-ID`) are skipped.
+//!   3. Per event, in its own transaction, the dedup key is
+//!      `content_hash_dedup::compute_ical_uid_hash` (organisation + UID),
+//!      stored in `posts.content_hash`:
+//!      - miss → new `event` post, `in_review`, `submission_type = 'ical_import'`
+//!      - hit, same fingerprint → unchanged (bump `last_seen_at`)
+//!      - hit, new fingerprint → revision post (`revision_of_post_id`), prior
+//!        archived and its editions reflowed — same as Root Signal revisions
+//!        (see `revision_reflow`).
+//!
+//!      New and revised posts get an `ical_import` review flag (and
+//!      `pii_detected` if the title or description was scrubbed).
+//!
+//!      A `STATUS:CANCELLED` event archives the post imported for its UID.
+//!   4. Events this feed imported before whose UID is no longer in it are
+//!      archived too, and their citations marked `disappeared_at`. An empty
+//!      calendar archives nothing (more likely a publisher glitch than every
+//!      event being withdrawn at once).
+//!   5. Record the outcome on the feed (`last_fetched_at`, `last_error`).
+//!
+//! Archived posts have their editions reflowed, like revised priors.
+//!
+//! The fingerprint is a hash of everything we copy out of the VEVENT and
+//! lives on the event's `post_sources` row (`platform_id` = UID, with the
+//! feed in `calendar_feed_id`) as `content_hash`. SEQUENCE is not part of
+//! it: some publishers bump it without changing anything.
+
+use anyhow::anyhow;
+use chrono::Utc;
+use serde::Serialize;
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use sqlx::{PgConnection, PgPool};
+use tracing::info;
+use uuid::Uuid;
+
+use crate::common::{OrganizationCalendarFeedId, PostId};
+use crate::domains::media::ingest::fetch::{self, FetchError};
+use crate::domains::media::ingest::ssrf::{self, SsrfError};
+use crate::domains::organization::models::{Organization, OrganizationCalendarFeed};
+use crate::domains::pii::activities::scrub;
+use crate::domains::pii::models::NewPiiFinding;
+use crate::domains::pii::ScrubPolicy;
+use crate::domains::posts::activities::{content_hash_dedup, revision_reflow};
+use crate::domains::posts::models::{
+    CreatePost, NewReviewFlag, Post, PostReviewFlag, PostSource, PostSourceAttr,
+    PostSourceInsert,
+};
+use crate::domains::schedules::ical::{parse_calendar, IcalParseError, ParsedEvent};
+use crate::domains::schedules::models::{
+    CreateOneOffSchedule, CreateRecurringSchedule, Schedule,
+};
+use crate::kernel::work_queue::WorkRequest;
+use crate::kernel::ServerDeps;
+
+/// `submission_type` of imported posts; the Signal inbox flags these.
+pub const ICAL_SUBMISSION_TYPE: &str = "ical_import";
+
+#[derive(Debug, thiserror::Error)]
+pub enum CalendarImportError {
+    #[error("calendar feed not found")]
+    FeedNotFound,
+    #[error("ssrf: {0}")]
+    Ssrf(#[from] SsrfError),
+    #[error("fetch: {0}")]
+    Fetch(#[from] FetchError),
+    #[error("parse: {0}")]
+    Parse(#[from] IcalParseError),
+    #[error("db: {0}")]
+    Db(#[from] anyhow::Error),
+}
+
+/// What one import run did.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CalendarImportSummary {
+    pub created: Vec<Uuid>,
+    pub revised: Vec<Uuid>,
+    pub unchanged: usize,
+    /// Posts archived because their event was cancelled.
+    pub cancelled: Vec<Uuid>,
+    /// Posts archived because their event is no longer in the feed.
+    pub removed: Vec<Uuid>,
+    /// One line per VEVENT that wasn't imported, with the reason.
+    pub skipped: Vec<String>,
+}
+
+enum EventOutcome {
+    Created(Uuid),
+    Revised(Uuid),
+    Unchanged,
+}
+
+/// Fetch a feed and import its events, recording the outcome on the feed.
+pub async fn import_feed(
+    feed_id: OrganizationCalendarFeedId,
+    deps: &ServerDeps,
+) -> Result<CalendarImportSummary, CalendarImportError> {
+    let pool = &deps.db_pool;
+    let feed = OrganizationCalendarFeed::find_by_id(feed_id, pool)
+        .await?
+        .ok_or(CalendarImportError::FeedNotFound)?;
+
+    let result = fetch_and_import(&feed, deps).await;
+    let error = result.as_ref().err().map(|e| e.to_string());
+    OrganizationCalendarFeed::record_fetch(feed.id, error.as_deref(), pool).await?;
+
+    if let Ok(summary) = &result {
+        info!(
+            feed_id = %feed.id,
+            created = summary.created.len(),
+            revised = summary.revised.len(),
+            unchanged = summary.unchanged,
+            cancelled = summary.cancelled.len(),
+            removed = summary.removed.len(),
+            skipped = summary.skipped.len(),
+            "calendar import completed"
+        );
+    }
+    result
+}
+
+async fn fetch_and_import(
+    feed: &OrganizationCalendarFeed,
+    deps: &ServerDeps,
+) -> Result<CalendarImportSummary, CalendarImportError> {
+    let pool = &deps.db_pool;
+    let url = ssrf::validate_url(&feed.url)?;
+    let fetched = fetch::fetch(url).await?;
+    let calendar = parse_calendar(&String::from_utf8_lossy(&fetched.bytes))?;
+
+    let org = Organization::find_by_id(feed.organization_id, pool).await?;
+    let source_id = match feed.source_id {
+        Some(id) => id,
+        None => Organization::primary_source_id(org.id, pool)
+            .await?
+            .ok_or_else(|| anyhow!("organization {} has no source to cite", org.id))?,
+    };
+
+    let mut summary = CalendarImportSummary {
+        skipped: calendar.skipped,
+        ..Default::default()
+    };
+    for event in &calendar.events {
+        if event.cancelled {
+            let archived = archive_imported(feed, std::slice::from_ref(&event.uid), true, pool).await?;
+            if archived.is_empty() {
+                summary.skipped.push(format!("{}: cancelled", event.uid));
+            }
+            summary.cancelled.extend(archived);
+            continue;
+        }
+        match import_event(feed, &org, source_id, event, deps).await? {
+            EventOutcome::Created(id) => summary.created.push(id),
+            EventOutcome::Revised(id) => summary.revised.push(id),
+            EventOutcome::Unchanged => summary.unchanged += 1,
+        }
+    }
+
+    if !calendar.events.is_empty() {
+        let current: Vec<String> = calendar.events.iter().map(|e| e.uid.clone()).collect();
+        summary.removed = archive_imported(feed, &current, false, pool).await?;
+    }
+    Ok(summary)
+}
+
+/// Archive the live posts this feed imported for the given UIDs (or, with
+/// `matching = false`, for every UID *not* given), one transaction per
+/// post, and queue their editions for reflow. Returns the archived ids.
+async fn archive_imported(
+    feed: &OrganizationCalendarFeed,
+    uids: &[String],
+    matching: bool,
+    pool: &PgPool,
+) -> anyhow::Result<Vec<Uuid>> {
+    let post_ids: Vec<Uuid> = sqlx::query_scalar(
+        r#"
+        SELECT DISTINCT p.id
+        FROM post_sources ps
+        JOIN posts p ON p.id = ps.post_id
+        WHERE ps.calendar_feed_id = $1
+          AND (ps.platform_id = ANY($2)) = $3
+          AND p.submission_type = 'ical_import'
+          AND p.status IN ('active', 'in_review')
+          AND p.deleted_at IS NULL
+        "#,
+    )
+    .bind(feed.id.into_uuid())
+    .bind(uids)
+    .bind(matching)
+    .fetch_all(pool)
+    .await?;
+
+    for &post_id in &post_ids {
+        let mut tx = pool.begin().await?;
+        revision_reflow::archive_prior(post_id, &mut *tx).await?;
+        if !matching {
+            sqlx::query(
+                r#"
+                UPDATE post_sources
+                SET disappeared_at = NOW(), updated_at = NOW()
+                WHERE post_id = $1 AND calendar_feed_id = $2 AND disappeared_at IS NULL
+                "#,
+            )
+            .bind(post_id)
+            .bind(feed.id.into_uuid())
+            .execute(&mut *tx)
+            .await?;
+        }
+        WorkRequest::ReflowEditions { post_id }
+            .enqueue(&mut *tx)
+            .await?;
+        tx.commit().await?;
+    }
+    Ok(post_ids)
+}
+
+async fn import_event(
+    feed: &OrganizationCalendarFeed,
+    org: &Organization,
+    source_id: Uuid,
+    event: &ParsedEvent,
+    deps: &ServerDeps,
+) -> anyhow::Result<EventOutcome> {
+    let pool = &deps.db_pool;
+    let uid_hash = content_hash_dedup::compute_ical_uid_hash(org.id.into_uuid(), &event.uid);
+    let fingerprint = event_fingerprint(event);
+
+    let revision_of = content_hash_dedup::find_existing_by_hash(&uid_hash, pool).await?;
+    if revision_of.is_some() {
+        // Compare against this event's own citation: after a merge the
+        // post found by UID may be a survivor whose primary source is
+        // something else entirely.
+        let cited =
+            PostSource::find_latest_by_platform_id("ical", source_id, &event.uid, pool).await?;
+        if let Some(cited) = cited {
+            if cited.content_hash.as_deref() == Some(fingerprint.as_str()) {
+                PostSource::touch_last_seen(cited.id, pool).await?;
+                return Ok(EventOutcome::Unchanged);
+            }
+        }
+    }
+
+    let allow_list = scrub::organization_allow_list(&[org.id.into_uuid()], pool).await?;
+    let title = scrub::scrub_field(
+        "title",
+        &event.summary,
+        ScrubPolicy::INGEST,
+        &allow_list,
+        deps,
+    )
+    .await?;
+    let body = scrub::scrub_field(
+        "body_raw",
+        event.description.as_deref().unwrap_or_default(),
+        ScrubPolicy::INGEST,
+        &allow_list,
+        deps,
+    )
+    .await?;
+
+    let mut pii_findings = title.findings;
+    pii_findings.extend(body.findings);
+
+    let mut tx = pool.begin().await?;
+    let post = Post::create(
+        CreatePost::builder()
+            .title(title.clean_text.clone())
+            .body_raw(body.clean_text.clone())
+            .post_type("event".to_string())
+            .weight("light".to_string())
+            .location(event.location.clone())
+            .status("in_review".to_string())
+            .submission_type(Some(ICAL_SUBMISSION_TYPE.to_string()))
+            .revision_of_post_id(revision_of.map(PostId::from_uuid))
+            .published_at(Some(Utc::now()))
+            .build(),
+        &mut *tx,
+    )
+    .await?;
+    let post_uuid = post.id.into_uuid();
+
+    content_hash_dedup::set_content_hash(post_uuid, &uid_hash, &mut *tx).await?;
+    scrub::record_findings(
+        "post",
+        post_uuid,
+        ScrubPolicy::INGEST,
+        &pii_findings,
+        &mut *tx,
+    )
+    .await?;
+    let flags = review_flags(feed, event, revision_of, &pii_findings);
+    PostReviewFlag::raise_all(post.id, &flags, &mut *tx).await?;
+    create_schedule(post_uuid, event, &mut tx).await?;
+
+    PostSource::insert_full(
+        PostSourceInsert {
+            post_id: post.id,
+            source_type: "ical",
+            source_id,
+            source_url: Some(event.url.as_deref().unwrap_or(&feed.url)),
+            content_hash: Some(&fingerprint),
+            platform_id: Some(&event.uid),
+            is_primary: true,
+            calendar_feed_id: Some(feed.id.into_uuid()),
+            ..Default::default()
+        },
+        &mut *tx,
+    )
+    .await?;
+    PostSourceAttr::upsert(post_uuid, Some(&org.name), None, &mut *tx).await?;
+
+    let outcome = match revision_of {
+        Some(prior) => {
+            revision_reflow::archive_prior(prior, &mut *tx).await?;
+            WorkRequest::ReflowEditions { post_id: prior }
+                .enqueue(&mut *tx)
+                .await?;
+            EventOutcome::Revised(post_uuid)
+        }
+        None => EventOutcome::Created(post_uuid),
+    };
+    tx.commit().await?;
+    Ok(outcome)
+}
+
+/// One schedule per event: recurring when the VEVENT has an RRULE (with its
+/// EXDATEs), one-off otherwise.
+async fn create_schedule(
+    post_id: Uuid,
+    event: &ParsedEvent,
+    conn: &mut PgConnection,
+) -> anyhow::Result<()> {
+    match event.rrule.as_deref() {
+        Some(rrule) => {
+            let exdates = (!event.exdates.is_empty()).then(|| {
+                event
+                    .exdates
+                    .iter()
+                    .map(|d| d.format("%Y-%m-%d").to_string())
+                    .collect::<Vec<_>>()
+                    .join(",")
+            });
+            let duration_minutes = event
+                .dtend
+                .map(|end| (end - event.dtstart).num_minutes() as i32);
+            Schedule::create_recurring(
+                &CreateRecurringSchedule::builder()
+                    .schedulable_type("post")
+                    .schedulable_id(post_id)
+                    .dtstart(event.dtstart)
+                    .rrule(rrule)
+                    .timezone(event.timezone.as_str())
+                    .duration_minutes(duration_minutes)
+                    .exdates(exdates.as_deref())
+                    .is_all_day(event.is_all_day)
+                    .build(),
+                &mut *conn,
+            )
+            .await?;
+        }
+        None => {
+            Schedule::create_one_off(
+                &CreateOneOffSchedule::builder()
+                    .schedulable_type("post")
+                    .schedulable_id(post_id)
+                    .dtstart(event.dtstart)
+                    .dtend(event.dtend.unwrap_or(event.dtstart))
+                    .is_all_day(event.is_all_day)
+                    .timezone(event.timezone.as_str())
+                    .build(),
+                &mut *conn,
+            )
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+/// Why the inbox is holding an imported event: always `ical_import`, plus
+/// `pii_detected` when the title or description had PII scrubbed out.
+fn review_flags(
+    feed: &OrganizationCalendarFeed,
+    event: &ParsedEvent,
+    revision_of: Option<Uuid>,
+    findings: &[NewPiiFinding],
+) -> Vec<NewReviewFlag> {
+    let mut flags = vec![NewReviewFlag::blocking(
+        ICAL_SUBMISSION_TYPE,
+        json!({
+            "feed_id": feed.id.into_uuid(),
+            "uid": event.uid,
+            "revision_of_post_id": revision_of,
+        }),
+    )];
+    if !findings.is_empty() {
+        flags.push(NewReviewFlag::blocking(
+            "pii_detected",
+            scrub::pii_detected_details(findings),
+        ));
+    }
+    flags
+}
+
+/// Hash of the VEVENT fields the import copies into the post and schedule.
+fn event_fingerprint(event: &ParsedEvent) -> String {
+    let exdates: Vec<String> = event.exdates.iter().map(|d| d.to_string()).collect();
+    let fields = [
+        event.summary.as_str(),
+        event.description.as_deref().unwrap_or_default(),
+        event.location.as_deref().unwrap_or_default(),
+        event.url.as_deref().unwrap_or_default(),
+        &event.dtstart.to_rfc3339(),
+        &event.dtend.map(|d| d.to_rfc3339()).unwrap_or_default(),
+        if event.is_all_day { "all_day" } else { "timed" },
+        event.timezone.as_str(),
+        event.rrule.as_deref().unwrap_or_default(),
+        &exdates.join(","),
+    ];
+    let mut h = Sha256::new();
+    for field in fields {
+        h.update(field.as_bytes());
+        h.update(b"\n");
+    }
+    format!("{:x}", h.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, TimeZone};
+
+    fn event() -> ParsedEvent {
+        ParsedEvent {
+            uid: "food-shelf@partner.example.org".to_string(),
+            summary: "Weekly distribution".to_string(),
+            description: Some("Bring a bag".to_string()),
+            location: None,
+            url: None,
+            dtstart: Utc.with_ymd_and_hms(2026, 4, 7, 23, 0, 0).unwrap(),
+            dtend: None,
+            is_all_day: false,
+            timezone: "America/Chicago".to_string(),
+            rrule: Some("FREQ=WEEKLY;BYDAY=TU".to_string()),
+            exdates: vec![],
+            cancelled: false,
+        }
+    }
+
+    #[test]
+    fn fingerprint_tracks_schedule_and_text_changes() {
+        let base = event_fingerprint(&event());
+        assert_eq!(base, event_fingerprint(&event()));
+
+        let mut skipped = event();
+        skipped.exdates.push(NaiveDate::from_ymd_opt(2026, 4, 14).unwrap());
+        assert_ne!(base, event_fingerprint(&skipped));
+
+        let mut retitled = event();
+        retitled.summary = "Weekly food distribution".to_string();
+        assert_ne!(base, event_fingerprint(&retitled));
+    }
+}
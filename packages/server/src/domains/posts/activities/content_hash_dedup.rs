@@ -28,9 +28,11 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use sha2::{Digest, Sha256};
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool};
 use uuid::Uuid;
 
+use crate::domains::posts::models::PostMerge;
+
 pub fn compute_content_hash(
     title: &str,
     source_url: Option<&str>,
@@ -61,6 +63,19 @@ pub fn compute_content_hash(
     format!("{:x}", h.finalize())
 }
 
+/// Dedup key for a post imported from an iCalendar feed: the VEVENT UID,
+/// scoped to the organisation that publishes the feed. Stored in
+/// `posts.content_hash`, so [`find_existing_by_hash`] finds the post a
+/// previous import made for the same event.
+pub fn compute_ical_uid_hash(organization_id: Uuid, uid: &str) -> String {
+    let mut h = Sha256::new();
+    h.update(b"ical\n");
+    h.update(organization_id.to_string().as_bytes());
+    h.update(b"\n");
+    h.update(uid.trim().as_bytes());
+    format!("{:x}", h.finalize())
+}
+
 fn normalise_text(s: &str) -> String {
     let lowered = s.to_lowercase();
     lowered.split_whitespace().collect::<Vec<_>>().join(" ")
@@ -85,12 +100,13 @@ fn normalise_url_for_hash(url: &str) -> String {
     stripped.trim_end_matches('/').to_string()
 }
 
-/// Find an existing non-deleted post with the same content hash.
+/// Find an existing non-deleted post with the same content hash. A post
+/// merged into another (see `post_merge`) resolves to its survivor.
 pub async fn find_existing_by_hash(
     content_hash: &str,
     pool: &PgPool,
 ) -> Result<Option<Uuid>> {
-    sqlx::query_scalar::<_, Uuid>(
+    let existing = sqlx::query_scalar::<_, Uuid>(
         r#"
         SELECT id FROM posts
         WHERE content_hash = $1
@@ -102,8 +118,11 @@ pub async fn find_existing_by_hash(
     )
     .bind(content_hash)
     .fetch_optional(pool)
-    .await
-    .map_err(Into::into)
+    .await?;
+    match existing {
+        Some(id) => Ok(Some(id)),
+        None => PostMerge::find_survivor_by_content_hash(content_hash, pool).await,
+    }
 }
 
 /// On dedup hit: refresh `published_at` to NOW() (extends 7-day eligibility)
@@ -125,11 +144,11 @@ pub async fn refresh_existing(post_id: Uuid, pool: &PgPool) -> Result<()> {
 }
 
 /// Write the hash into the post row. Called on insert path.
-pub async fn set_content_hash(post_id: Uuid, hash: &str, pool: &PgPool) -> Result<()> {
+pub async fn set_content_hash(post_id: Uuid, hash: &str, db: impl PgExecutor<'_>) -> Result<()> {
     sqlx::query("UPDATE posts SET content_hash = $2 WHERE id = $1")
         .bind(post_id)
         .bind(hash)
-        .execute(pool)
+        .execute(db)
         .await?;
     Ok(())
 }
@@ -196,6 +215,15 @@ mod tests {
         assert_eq!(a, b);
     }
 
+    #[test]
+    fn ical_uid_hash_is_scoped_to_the_organisation() {
+        let org = Uuid::new_v4();
+        let a = compute_ical_uid_hash(org, "event-1@example.org");
+        assert_eq!(a, compute_ical_uid_hash(org, " event-1@example.org "));
+        assert_ne!(a, compute_ical_uid_hash(Uuid::new_v4(), "event-1@example.org"));
+        assert_ne!(a, compute_ical_uid_hash(org, "event-2@example.org"));
+    }
+
     #[test]
     fn distinct_urls_distinct_hash() {
         let a = compute_content_hash("x", Some("https://example.com/road"), None, &[]);
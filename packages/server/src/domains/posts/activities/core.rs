@@ -8,11 +8,17 @@ use anyhow::Result;
 use tracing::info;
 use uuid::Uuid;
 
+use super::county_resolution;
 use super::post_operations::{self, UpdateAndApprovePost};
-use crate::common::auth::{Actor, AdminCapability};
+use crate::common::auth::{Actor, AdminCapability, CapabilityGrant};
+use crate::common::pii::PiiAllowList;
 use crate::common::{MemberId, PostId};
 use crate::domains::posts::data::{EditPostInput, SubmitPostInput};
-use crate::domains::posts::models::{CreatePost, Post, UpdatePostContent};
+use crate::domains::pii::activities::scrub;
+use crate::domains::pii::ScrubPolicy;
+use crate::domains::posts::models::{
+    CreatePost, NewReviewFlag, Post, PostReviewFlag, UpdatePostContent,
+};
 use crate::kernel::ServerDeps;
 
 /// Submit a post from user input (public, goes to active)
@@ -53,6 +59,7 @@ pub async fn approve_post(
     post_id: Uuid,
     member_id: Uuid,
     is_admin: bool,
+    grants: &[CapabilityGrant],
     deps: &ServerDeps,
 ) -> Result<PostId> {
     let post_id = PostId::from_uuid(post_id);
@@ -61,12 +68,15 @@ pub async fn approve_post(
     info!(post_id = %post_id, "Approving post");
 
     Actor::new(requested_by, is_admin)
-        .can(AdminCapability::ManageNeeds)
+        .with_grants(grants)
+        .can(AdminCapability::ReviewPosts)
         .check(deps)
         .await
         .map_err(|auth_err| anyhow::anyhow!("Authorization denied: {}", auth_err))?;
 
     post_operations::update_post_status(post_id, "active".to_string(), &deps.db_pool).await?;
+    // Approving outright clears whatever the inbox was holding it for.
+    PostReviewFlag::resolve_all_for_post(post_id, requested_by, &deps.db_pool).await?;
 
     Ok(post_id)
 }
@@ -77,6 +87,7 @@ pub async fn reject_post(
     reason: String,
     member_id: Uuid,
     is_admin: bool,
+    grants: &[CapabilityGrant],
     deps: &ServerDeps,
 ) -> Result<()> {
     let post_id = PostId::from_uuid(post_id);
@@ -85,7 +96,8 @@ pub async fn reject_post(
     info!(post_id = %post_id, reason = %reason, "Rejecting post");
 
     Actor::new(requested_by, is_admin)
-        .can(AdminCapability::ManageNeeds)
+        .with_grants(grants)
+        .can(AdminCapability::ReviewPosts)
         .check(deps)
         .await
         .map_err(|auth_err| anyhow::anyhow!("Authorization denied: {}", auth_err))?;
@@ -102,6 +114,7 @@ pub async fn edit_and_approve_post(
     input: EditPostInput,
     member_id: Uuid,
     is_admin: bool,
+    grants: &[CapabilityGrant],
     deps: &ServerDeps,
 ) -> Result<PostId> {
     let post_id = PostId::from_uuid(post_id);
@@ -110,7 +123,8 @@ pub async fn edit_and_approve_post(
     info!(post_id = %post_id, title = ?input.title, "Editing and approving post");
 
     Actor::new(requested_by, is_admin)
-        .can(AdminCapability::ManageNeeds)
+        .with_grants(grants)
+        .can(AdminCapability::ReviewPosts)
         .check(deps)
         .await
         .map_err(|auth_err| anyhow::anyhow!("Authorization denied: {}", auth_err))?;
@@ -126,6 +140,7 @@ pub async fn edit_and_approve_post(
         &deps.db_pool,
     )
     .await?;
+    PostReviewFlag::resolve_all_for_post(post_id, requested_by, &deps.db_pool).await?;
 
     Ok(post_id)
 }
@@ -135,6 +150,7 @@ pub async fn delete_post(
     post_id: Uuid,
     member_id: Uuid,
     is_admin: bool,
+    grants: &[CapabilityGrant],
     deps: &ServerDeps,
 ) -> Result<()> {
     let post_id = PostId::from_uuid(post_id);
@@ -143,7 +159,8 @@ pub async fn delete_post(
     info!(post_id = %post_id, "Deleting post");
 
     Actor::new(requested_by, is_admin)
-        .can(AdminCapability::FullAdmin)
+        .with_grants(grants)
+        .can(AdminCapability::DeletePosts)
         .check(deps)
         .await
         .map_err(|auth_err| anyhow::anyhow!("Authorization denied: {}", auth_err))?;
@@ -166,6 +183,7 @@ pub async fn expire_post(
     post_id: Uuid,
     member_id: Uuid,
     is_admin: bool,
+    grants: &[CapabilityGrant],
     deps: &ServerDeps,
 ) -> Result<PostId> {
     let post_id = PostId::from_uuid(post_id);
@@ -174,7 +192,8 @@ pub async fn expire_post(
     info!(post_id = %post_id, "Expiring post");
 
     Actor::new(requested_by, is_admin)
-        .can(AdminCapability::ManagePosts)
+        .with_grants(grants)
+        .can(AdminCapability::ReviewPosts)
         .check(deps)
         .await
         .map_err(|auth_err| anyhow::anyhow!("Authorization denied: {}", auth_err))?;
@@ -189,6 +208,7 @@ pub async fn archive_post(
     post_id: Uuid,
     member_id: Uuid,
     is_admin: bool,
+    grants: &[CapabilityGrant],
     deps: &ServerDeps,
 ) -> Result<PostId> {
     let post_id = PostId::from_uuid(post_id);
@@ -197,7 +217,8 @@ pub async fn archive_post(
     info!(post_id = %post_id, "Archiving post");
 
     Actor::new(requested_by, is_admin)
-        .can(AdminCapability::ManagePosts)
+        .with_grants(grants)
+        .can(AdminCapability::DeletePosts)
         .check(deps)
         .await
         .map_err(|auth_err| anyhow::anyhow!("Authorization denied: {}", auth_err))?;
@@ -361,7 +382,52 @@ pub async fn admin_update_post(
     let post_id = PostId::from_uuid(post_id);
     info!(post_id = %post_id, "Admin updating post content");
 
-    let post = Post::update_content(
+    // Scrub PII from the title and body before they are saved. The post's
+    // organisation's own addresses are allowed.
+    let policy = ScrubPolicy::EDITOR;
+    let mut pii_findings = Vec::new();
+    let allow_list = if title.is_some() || body_raw.is_some() || body_ast.is_some() {
+        let org_id = Post::find_organization_id(post_id, &deps.db_pool).await?;
+        scrub::organization_allow_list(org_id.as_slice(), &deps.db_pool).await?
+    } else {
+        PiiAllowList::new()
+    };
+    let title =
+        match scrub::scrub_optional_field("title", title.as_deref(), policy, &allow_list, deps)
+            .await?
+        {
+            Some(scrubbed) => {
+                pii_findings.extend(scrubbed.findings);
+                Some(scrubbed.clean_text)
+            }
+            None => None,
+        };
+    let body_raw = match scrub::scrub_optional_field(
+        "body_raw",
+        body_raw.as_deref(),
+        policy,
+        &allow_list,
+        deps,
+    )
+    .await?
+    {
+        Some(scrubbed) => {
+            pii_findings.extend(scrubbed.findings);
+            Some(scrubbed.clean_text)
+        }
+        None => None,
+    };
+    let body_ast = match body_ast {
+        Some(ast) => {
+            let (clean, findings) = scrub::scrub_body_ast(&ast, policy, &allow_list, deps).await?;
+            pii_findings.extend(findings);
+            Some(clean)
+        }
+        None => None,
+    };
+
+    let zip_changed = zip_code.is_some();
+    let mut post = Post::update_content(
         UpdatePostContent::builder()
             .id(post_id)
             .title(title)
@@ -379,6 +445,29 @@ pub async fn admin_update_post(
     )
     .await?;
 
+    // A new ZIP can move the post into another county.
+    if zip_changed {
+        let mut conn = deps.db_pool.acquire().await?;
+        county_resolution::resolve_post_county(post_id, &mut conn).await?;
+    }
+
+    // Redacted PII pulls a live post back to review, with a `pii_detected`
+    // flag, so an editor signs off on the redacted text before it is public
+    // again.
+    if !pii_findings.is_empty() {
+        let mut tx = deps.db_pool.begin().await?;
+        scrub::record_findings("post", post_id.into_uuid(), policy, &pii_findings, &mut *tx)
+            .await?;
+        let flag =
+            NewReviewFlag::blocking("pii_detected", scrub::pii_detected_details(&pii_findings));
+        PostReviewFlag::raise_all(post_id, &[flag], &mut *tx).await?;
+        if post.status == "active" {
+            info!(post_id = %post_id, findings = pii_findings.len(), "PII redacted on save; moving post to in_review");
+            post = Post::update_status(post_id, "in_review", &mut *tx).await?;
+        }
+        tx.commit().await?;
+    }
+
     // Reconcile post_body media references from the saved AST. Walks the
     // tree for any `mediaId` string (photo_a / photo_b / photo_block nodes
     // all use the same attribute name) and rebuilds the ref set for this
@@ -0,0 +1,89 @@
+//! Resolve each post to a single county and store it on `posts.county_id`.
+//!
+//! Order of precedence:
+//! 1. The post's own latitude/longitude inside a county boundary.
+//! 2. Its primary location's coordinates inside a county boundary.
+//! 3. The primary county of its ZIP (see `Post::find_county_inputs`).
+//!
+//! Edition eligibility reads the stored county directly, so a post on a
+//! ZIP that straddles a county line lands in one county, and a post with
+//! coordinates but no ZIP is no longer treated as ambient. Without loaded
+//! boundaries every post resolves by ZIP, which matches the old behaviour
+//! apart from straddling ZIPs.
+
+use anyhow::Result;
+use serde::Serialize;
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::common::PostId;
+use crate::domains::editions::activities::county_boundaries::CountyIndex;
+use crate::domains::posts::models::{Post, PostCountyInputs};
+
+pub const VIA_COORDINATES: &str = "coordinates";
+pub const VIA_ZIP: &str = "zip";
+
+/// Coordinates to try, in order of precedence.
+fn points(inputs: &PostCountyInputs) -> Vec<(f64, f64)> {
+    [
+        inputs.latitude.zip(inputs.longitude),
+        inputs.location_latitude.zip(inputs.location_longitude),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Pick the county for one post's inputs.
+pub fn resolve(inputs: &PostCountyInputs, index: &CountyIndex) -> Option<(Uuid, &'static str)> {
+    points(inputs)
+        .into_iter()
+        .find_map(|(lat, lng)| index.locate(lat, lng))
+        .map(|county_id| (county_id, VIA_COORDINATES))
+        .or_else(|| inputs.zip_county_id.map(|county_id| (county_id, VIA_ZIP)))
+}
+
+/// Resolve and store one post's county. Only the boundaries around the
+/// post's coordinates are loaded.
+pub async fn resolve_post_county(post_id: PostId, conn: &mut PgConnection) -> Result<Option<Uuid>> {
+    let Some(inputs) = Post::find_county_inputs(post_id, &mut *conn).await? else {
+        return Ok(None);
+    };
+    let index = CountyIndex::load_around(&points(&inputs), &mut *conn).await?;
+    let (county_id, via) = resolve(&inputs, &index).unzip();
+    Post::set_county(post_id, county_id, via, &mut *conn).await?;
+    Ok(county_id)
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CountyResolutionSummary {
+    pub via_coordinates: usize,
+    pub via_zip: usize,
+    pub unresolved: usize,
+}
+
+/// Re-resolve every non-deleted post against the full boundary index, in
+/// pages of 500.
+pub async fn resolve_all(pool: &PgPool) -> Result<CountyResolutionSummary> {
+    let index = CountyIndex::load(pool).await?;
+    let mut summary = CountyResolutionSummary::default();
+    let mut after = None;
+    loop {
+        let page = Post::find_county_inputs_page(after, 500, pool).await?;
+        let Some(last) = page.last() else {
+            break;
+        };
+        after = Some(last.id);
+        for inputs in &page {
+            let resolved = resolve(inputs, &index);
+            match resolved {
+                Some((_, VIA_COORDINATES)) => summary.via_coordinates += 1,
+                Some(_) => summary.via_zip += 1,
+                None => summary.unresolved += 1,
+            }
+            let (county_id, via) = resolved.unzip();
+            Post::set_county(PostId::from_uuid(inputs.id), county_id, via, pool).await?;
+        }
+    }
+    Ok(summary)
+}
@@ -12,13 +12,23 @@
 //!      auto-creates and flips to `in_review`.
 //!   4. Compute `content_hash` (§1.5) and look for an existing match. Hit →
 //!      refresh `published_at` and return the existing `post_id`.
-//!   5. Resolve source (org or individual dedup). Determine `status` (active
-//!      vs in_review) from soft-fail signals.
-//!   6. Insert `posts` row. Apply field groups + tags + post_sources (primary
-//!      from envelope `source`, extra from Addendum citations).
-//!   7. If `revision_of_post_id` is set: archive prior + reflow any active
+//!   5. Resolve source (org or individual dedup). Scrub PII from the body
+//!      fields. Determine `status` (active vs in_review) from soft-fail
+//!      signals — redacted PII is one of them.
+//!   6. Geocode `location` when the envelope has no coordinates, and look
+//!      for near-duplicates (`near_duplicate`); a close match in the same
+//!      county is one more soft-fail signal, so the row is inserted with
+//!      its final status.
+//!   7. Insert `posts` row. Apply tags, attach the geocoded location, store
+//!      the post's county (`county_resolution`) and its similarity
+//!      signature. One `post_review_flags` row per signal. Apply field
+//!      groups + post_sources (primary from envelope `source`, extra from
+//!      Addendum citations).
+//!   8. Enqueue deferred side effects on the work queue: media fetch when
+//!      the envelope carried images, and — if `revision_of_post_id` is set —
+//!      archive the prior post inline and enqueue a reflow of any active
 //!      editions containing it.
-//!   8. Build the 201 response shape.
+//!   9. Build the 201 response shape.
 //!
 //! The handler wraps this result in idempotency-key storage (see
 //! `ApiIdempotencyKey`). This activity is side-effectful but doesn't manage
@@ -28,20 +38,29 @@ use anyhow::Result;
 use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use serde_json::json;
+use sqlx::{PgConnection, PgExecutor};
 use tracing::info;
 use uuid::Uuid;
 
 use crate::api::error::{ApiError, ErrorCode, FieldError, FieldErrors};
+use crate::common::pii::PiiAllowList;
 use crate::common::PostId;
 use crate::domains::contacts::Contact as ContactModel;
 use crate::domains::posts::activities::{
-    content_hash_dedup, individual_dedup, organization_dedup, revision_reflow, tag_resolution,
+    backfill, content_hash_dedup, county_resolution, individual_dedup, near_duplicate,
+    organization_dedup, revision_reflow, tag_resolution,
 };
 use crate::domains::posts::models::{
-    CreatePost, Post, PostDatetimeRecord, PostItem, PostItemInput, PostLinkRecord,
-    PostMediaInput, PostMediaRecord, PostMetaRecord, PostPersonRecord, PostScheduleEntry,
-    PostScheduleInput, PostSource, PostSourceAttr, PostSourceInsert, PostStatusRecord,
+    CreatePost, NewReviewFlag, Post, PostDatetimeRecord, PostItem, PostItemInput,
+    PostLinkRecord, PostMediaInput, PostMediaRecord, PostMetaRecord, PostPersonRecord,
+    PostReviewFlag, PostScheduleEntry, PostScheduleInput, PostSource, PostSourceAttr,
+    PostSourceInsert, PostStatusRecord,
 };
+use crate::domains::pii::activities::scrub;
+use crate::domains::pii::models::NewPiiFinding;
+use crate::domains::pii::ScrubPolicy;
+use crate::kernel::work_queue::WorkRequest;
 use crate::kernel::ServerDeps;
 
 // =============================================================================
@@ -344,10 +363,10 @@ pub struct IngestResult {
 // Validation
 // =============================================================================
 
-const VALID_POST_TYPES: &[&str] = &[
+pub(crate) const VALID_POST_TYPES: &[&str] = &[
     "story", "update", "action", "event", "need", "aid", "person", "business", "reference",
 ];
-const VALID_WEIGHTS: &[&str] = &["heavy", "medium", "light"];
+pub(crate) const VALID_WEIGHTS: &[&str] = &["heavy", "medium", "light"];
 
 pub fn validate_envelope(env: &IngestEnvelope) -> Result<(), ApiError> {
     let mut errs = FieldErrors::new();
@@ -904,18 +923,24 @@ pub async fn ingest_post(
 
     // ---- primary source dedup ----
     let mut soft_flags = SoftFlags::default();
-    if env.source.extraction_confidence.map(|c| c < 60).unwrap_or(false) {
-        soft_flags.low_confidence = true;
+    if let Some(confidence) = env.source.extraction_confidence.filter(|c| *c < 60) {
+        soft_flags.raise("low_confidence", json!({ "extraction_confidence": confidence }));
     }
     let duplicate_of = env.editorial.as_ref().and_then(|e| e.duplicate_of_id);
-    if duplicate_of.is_some() {
-        soft_flags.possible_duplicate = true;
+    if let Some(duplicate_of_id) = duplicate_of {
+        soft_flags.raise("possible_duplicate", json!({ "duplicate_of_id": duplicate_of_id }));
     }
     if env.weight == "heavy" && env.meta.deck.as_ref().map(|d| d.trim().is_empty()).unwrap_or(true) {
-        soft_flags.deck_missing_on_heavy = true;
+        soft_flags.raise("deck_missing_on_heavy", json!({}));
     }
     if tag_res.unknown_topic_auto_created {
-        soft_flags.unknown_topic = true;
+        let created: Vec<&str> = tag_res
+            .tags
+            .iter()
+            .filter(|t| t.auto_created && t.kind == "topic")
+            .map(|t| t.value.as_str())
+            .collect();
+        soft_flags.raise("unknown_topic", json!({ "topics": created }));
     }
 
     let (organization_id, individual_id, primary_source) = resolve_primary_source(
@@ -923,14 +948,91 @@ pub async fn ingest_post(
     )
     .await?;
 
+    // ---- PII scrub (title and bodies are stored redacted; the source
+    // org's own addresses are allowed) ----
+    let allow_list =
+        scrub::organization_allow_list(organization_id.as_slice(), pool).await?;
+    let bodies = scrub_bodies(&env, &allow_list, deps).await?;
+    if !bodies.findings.is_empty() {
+        soft_flags.raise(
+            "pii_detected",
+            scrub::pii_detected_details(&bodies.findings),
+        );
+    }
+
+    // ---- geocode (no coordinates on the envelope) ----
+    let geocode_query = match (env.latitude, env.longitude) {
+        (Some(_), Some(_)) => None,
+        _ => env.location.as_deref().or(env.zip_code.as_deref()),
+    };
+    let geocoded = match geocode_query {
+        Some(query) => deps.geocoder.geocode(query).await.unwrap_or_else(|e| {
+            tracing::warn!(error = %e, "geocoding failed");
+            None
+        }),
+        None => None,
+    };
+
+    // ---- near-duplicates (before insert: the post is stored with its
+    // final status). Candidates must share a county, judged from the same
+    // ZIP and service_area signals `Post::find_county_ids` reads. ----
+    let revision_of = env.editorial.as_ref().and_then(|e| e.revision_of_post_id);
+    let signature = near_duplicate::Signature::of(&bodies.title, &bodies.body_raw);
+    if let Some(signature) = &signature {
+        let zip_codes: Vec<String> = env
+            .zip_code
+            .iter()
+            .chain(geocoded.as_ref().and_then(|g| g.postal_code.as_ref()))
+            .cloned()
+            .collect();
+        let county_ids =
+            Post::find_county_ids_for_signals(&zip_codes, &tag_res.service_area_slugs, pool)
+                .await?;
+        let matches = near_duplicate::find_near_duplicates(
+            signature,
+            published_at,
+            &county_ids,
+            revision_of,
+            pool,
+        )
+        .await?;
+        if !matches.is_empty() {
+            let flag = near_duplicate::review_flag(&matches);
+            // Root Signal already named a duplicate: list ours alongside it.
+            match soft_flags.flags.iter_mut().find(|f| f.flag == flag.flag) {
+                Some(existing) => {
+                    existing.details["near_duplicates"] = flag.details["matches"].clone();
+                }
+                None => soft_flags.flags.push(flag),
+            }
+        }
+    }
+
+    // Citation sources are found or created up front, like the primary.
+    let mut citation_sources = Vec::new();
+    if let Some(citations) = &env.citations {
+        for citation in citations.iter().filter(|c| c.is_primary != Some(true)) {
+            // Same citation as primary (by source_url) — skip; the primary row covers it.
+            if Some(&citation.source_url) == env.source.source_url.as_ref() {
+                continue;
+            }
+            let source = resolve_citation_source(citation, pool).await?;
+            citation_sources.push((citation, source));
+        }
+    }
+
+    // Everything from the insert to the deferred side effects lands
+    // together: a failure part-way leaves no half-built post, no archived
+    // prior without its revision, and no queued work for a missing post.
+    let mut tx = pool.begin().await.map_err(|e| anyhow::anyhow!(e))?;
+
     // ---- insert posts row ----
     let status = if soft_flags.any() { "in_review" } else { "active" };
-    let revision_of = env.editorial.as_ref().and_then(|e| e.revision_of_post_id);
 
     let post = Post::create(
         CreatePost::builder()
-            .title(env.title.clone())
-            .body_raw(env.body_raw.clone())
+            .title(bodies.title.clone())
+            .body_raw(bodies.body_raw.clone())
             .post_type(env.post_type.clone())
             .weight(env.weight.clone())
             .priority(env.priority)
@@ -944,7 +1046,7 @@ pub async fn ingest_post(
             .translation_of_id(None::<PostId>)
             .published_at(Some(published_at))
             .build(),
-        pool,
+        &mut *tx,
     )
     .await?;
     let post_id = post.id;
@@ -953,27 +1055,54 @@ pub async fn ingest_post(
     // Body tiers and body_ast aren't on CreatePost — write them in a follow-up.
     apply_body_tiers(
         post_uuid,
-        env.body_heavy.as_deref(),
-        env.body_medium.as_deref(),
-        env.body_light.as_deref(),
-        env.body_ast.as_ref(),
+        bodies.body_heavy.as_deref(),
+        bodies.body_medium.as_deref(),
+        bodies.body_light.as_deref(),
+        bodies.body_ast.as_ref(),
         env.zip_code.as_deref(),
         env.latitude,
         env.longitude,
         env.is_evergreen,
         duplicate_of,
-        pool,
+        &mut *tx,
     )
     .await?;
 
     // ---- content hash ----
-    content_hash_dedup::set_content_hash(post_uuid, &content_hash, pool).await?;
+    content_hash_dedup::set_content_hash(post_uuid, &content_hash, &mut *tx).await?;
+
+    // ---- PII audit ----
+    scrub::record_findings(
+        "post",
+        post_uuid,
+        ScrubPolicy::INGEST,
+        &bodies.findings,
+        &mut *tx,
+    )
+    .await?;
 
     // ---- tags ----
-    tag_resolution::apply_tags(post_id, &tag_res, pool).await?;
+    tag_resolution::apply_tags(post_id, &tag_res, &mut tx).await?;
+
+    // ---- location + county ----
+    match &geocoded {
+        // Attaching resolves the county from the new point.
+        Some(geocoded) => backfill::attach_location(post_id, geocoded, &mut tx).await?,
+        None => {
+            county_resolution::resolve_post_county(post_id, &mut tx).await?;
+        }
+    }
+
+    // ---- near-duplicate index ----
+    if let Some(signature) = &signature {
+        near_duplicate::store_signature(post_id, signature, &mut *tx).await?;
+    }
+
+    // ---- review flags (Signal Inbox) ----
+    PostReviewFlag::raise_all(post_id, &soft_flags.flags, &mut *tx).await?;
 
     // ---- meta / field groups ----
-    apply_field_groups(post_uuid, &env, pool).await?;
+    apply_field_groups(post_uuid, &env, &mut tx).await?;
 
     // ---- post_sources: primary + additional citations ----
     let mut citation_ids: Vec<Uuid> = Vec::new();
@@ -990,21 +1119,16 @@ pub async fn ingest_post(
             platform_post_type_hint: None,
             is_primary: true,
             retrieved_at: None,
+            calendar_feed_id: None,
         },
-        pool,
+        &mut *tx,
     )
     .await?;
     citation_ids.push(primary_row.id.into_uuid());
 
-    if let Some(citations) = env.citations.clone() {
-        for citation in citations.iter().filter(|c| c.is_primary != Some(true)) {
-            // Same citation as primary (by source_url) — skip; the primary row covers it.
-            if Some(&citation.source_url) == env.source.source_url.as_ref() {
-                continue;
-            }
-            let cit_row = persist_citation(post_id, citation, pool).await?;
-            citation_ids.push(cit_row);
-        }
+    for (citation, source) in &citation_sources {
+        let cit_row = persist_citation(post_id, citation, source, &mut *tx).await?;
+        citation_ids.push(cit_row);
     }
 
     // ---- public attribution line ----
@@ -1018,19 +1142,32 @@ pub async fn ingest_post(
         post_uuid,
         source_name.as_deref(),
         Some(env.source.attribution_line.as_str()),
-        pool,
+        &mut *tx,
     )
     .await?;
 
-    // ---- revision reflow ----
+    // ---- deferred side effects (work queue) ----
+    let has_media = env
+        .field_groups
+        .as_ref()
+        .and_then(|g| g.media.as_ref())
+        .is_some_and(|m| !m.is_empty());
+    if has_media {
+        WorkRequest::IngestPostMedia { post_id: post_uuid }
+            .enqueue(&mut *tx)
+            .await?;
+    }
+
+    // ---- revision: archive now, reflow on the queue ----
     if let Some(prior) = revision_of {
-        let _ = revision_reflow::archive_and_reflow(prior, deps).await
-            .map_err(|e| {
-                tracing::warn!(error = %e, prior_post_id = %prior, "revision reflow failed");
-                e
-            });
+        revision_reflow::archive_prior(prior, &mut *tx).await?;
+        WorkRequest::ReflowEditions { post_id: prior }
+            .enqueue(&mut *tx)
+            .await?;
     }
 
+    tx.commit().await.map_err(|e| anyhow::anyhow!(e))?;
+
     info!(
         post_id = %post_uuid,
         status = %status,
@@ -1053,27 +1190,107 @@ pub async fn ingest_post(
     })
 }
 
+/// Soft-fail reasons raised while validating an envelope. Any of them
+/// lands the post `in_review`; each is persisted as a `post_review_flags`
+/// row: low_confidence, possible_duplicate, deck_missing_on_heavy,
+/// unknown_topic, source_stale, individual_no_consent, pii_detected.
 #[derive(Debug, Default)]
 struct SoftFlags {
-    low_confidence: bool,
-    possible_duplicate: bool,
-    deck_missing_on_heavy: bool,
-    unknown_topic: bool,
-    source_stale: bool,
-    individual_no_consent: bool,
+    flags: Vec<NewReviewFlag>,
 }
 
 impl SoftFlags {
+    fn raise(&mut self, flag: &'static str, details: serde_json::Value) {
+        self.flags.push(NewReviewFlag::blocking(flag, details));
+    }
+
     fn any(&self) -> bool {
-        self.low_confidence
-            || self.possible_duplicate
-            || self.deck_missing_on_heavy
-            || self.unknown_topic
-            || self.source_stale
-            || self.individual_no_consent
+        !self.flags.is_empty()
     }
 }
 
+/// Envelope title and body fields after PII redaction, plus what was
+/// redacted.
+struct ScrubbedBodies {
+    title: String,
+    body_raw: String,
+    body_heavy: Option<String>,
+    body_medium: Option<String>,
+    body_light: Option<String>,
+    body_ast: Option<serde_json::Value>,
+    findings: Vec<NewPiiFinding>,
+}
+
+async fn scrub_bodies(
+    env: &IngestEnvelope,
+    allow_list: &PiiAllowList,
+    deps: &ServerDeps,
+) -> Result<ScrubbedBodies> {
+    let policy = ScrubPolicy::INGEST;
+    let mut findings = Vec::new();
+
+    let title = scrub::scrub_field("title", &env.title, policy, allow_list, deps).await?;
+    findings.extend(title.findings);
+    let raw = scrub::scrub_field("body_raw", &env.body_raw, policy, allow_list, deps).await?;
+    findings.extend(raw.findings);
+
+    let mut tier = |scrubbed: Option<scrub::PiiFieldScrub>| {
+        scrubbed.map(|s| {
+            findings.extend(s.findings);
+            s.clean_text
+        })
+    };
+    let body_heavy = tier(
+        scrub::scrub_optional_field(
+            "body_heavy",
+            env.body_heavy.as_deref(),
+            policy,
+            allow_list,
+            deps,
+        )
+        .await?,
+    );
+    let body_medium = tier(
+        scrub::scrub_optional_field(
+            "body_medium",
+            env.body_medium.as_deref(),
+            policy,
+            allow_list,
+            deps,
+        )
+        .await?,
+    );
+    let body_light = tier(
+        scrub::scrub_optional_field(
+            "body_light",
+            env.body_light.as_deref(),
+            policy,
+            allow_list,
+            deps,
+        )
+        .await?,
+    );
+
+    let body_ast = match env.body_ast.as_ref() {
+        Some(ast) => {
+            let (clean, ast_findings) = scrub::scrub_body_ast(ast, policy, allow_list, deps).await?;
+            findings.extend(ast_findings);
+            Some(clean)
+        }
+        None => None,
+    };
+
+    Ok(ScrubbedBodies {
+        title: title.clean_text,
+        body_raw: raw.clean_text,
+        body_heavy,
+        body_medium,
+        body_light,
+        body_ast,
+        findings,
+    })
+}
+
 struct PrimarySource {
     source_type: String,
     source_id: Uuid,
@@ -1102,7 +1319,10 @@ async fn resolve_primary_source(
             )
             .await?;
             if resolved.stale {
-                flags.source_stale = true;
+                flags.raise(
+                    "source_stale",
+                    json!({ "organization_id": resolved.org.id.into_uuid() }),
+                );
             }
             Ok((
                 Some(resolved.org.id.into_uuid()),
@@ -1120,7 +1340,7 @@ async fn resolve_primary_source(
                 .as_ref()
                 .expect("individual presence validated upstream");
             if !ind_block.consent_to_publish {
-                flags.individual_no_consent = true;
+                flags.raise("individual_no_consent", json!({}));
             }
             let resolved = individual_dedup::resolve_individual(
                 individual_dedup::IndividualSubmission {
@@ -1149,12 +1369,13 @@ async fn resolve_primary_source(
     }
 }
 
-async fn persist_citation(
-    post_id: PostId,
+/// Find or create the organization / individual a citation credits. Runs
+/// before the ingest transaction opens, like the primary source.
+async fn resolve_citation_source(
     citation: &IngestCitation,
     pool: &sqlx::PgPool,
-) -> Result<Uuid, ApiError> {
-    let (source_type, source_id) = match citation.kind.as_str() {
+) -> Result<(String, Uuid), ApiError> {
+    let source = match citation.kind.as_str() {
         "organization" => {
             let org = citation.organization.as_ref().expect("validated");
             let resolved = organization_dedup::resolve_organization(
@@ -1189,7 +1410,15 @@ async fn persist_citation(
         }
         _ => unreachable!("citation.kind validated upstream"),
     };
+    Ok(source)
+}
 
+async fn persist_citation(
+    post_id: PostId,
+    citation: &IngestCitation,
+    (source_type, source_id): &(String, Uuid),
+    db: impl PgExecutor<'_>,
+) -> Result<Uuid, ApiError> {
     let retrieved_at = DateTime::parse_from_rfc3339(&citation.retrieved_at)
         .ok()
         .map(|d| d.with_timezone(&Utc));
@@ -1205,8 +1434,8 @@ async fn persist_citation(
     let row = PostSource::insert_full(
         PostSourceInsert {
             post_id,
-            source_type: &source_type,
-            source_id,
+            source_type,
+            source_id: *source_id,
             source_url: Some(&citation.source_url),
             content_hash: Some(&citation.content_hash),
             snippet: citation.snippet.as_deref(),
@@ -1215,8 +1444,9 @@ async fn persist_citation(
             platform_post_type_hint,
             is_primary: false,
             retrieved_at,
+            calendar_feed_id: None,
         },
-        pool,
+        db,
     )
     .await?;
     Ok(row.id.into_uuid())
@@ -1234,7 +1464,7 @@ async fn apply_body_tiers(
     longitude: Option<f64>,
     is_evergreen: bool,
     duplicate_of: Option<Uuid>,
-    pool: &sqlx::PgPool,
+    db: impl PgExecutor<'_>,
 ) -> Result<(), ApiError> {
     let lat_dec = latitude.and_then(|f| Decimal::try_from(f).ok());
     let lng_dec = longitude.and_then(|f| Decimal::try_from(f).ok());
@@ -1265,7 +1495,7 @@ async fn apply_body_tiers(
     .bind(lng_dec)
     .bind(is_evergreen)
     .bind(duplicate_of)
-    .execute(pool)
+    .execute(db)
     .await
     .map_err(|e| anyhow::anyhow!(e))?;
     Ok(())
@@ -1274,7 +1504,7 @@ async fn apply_body_tiers(
 async fn apply_field_groups(
     post_id: Uuid,
     env: &IngestEnvelope,
-    pool: &sqlx::PgPool,
+    conn: &mut PgConnection,
 ) -> Result<(), ApiError> {
     // Meta (1:1)
     PostMetaRecord::upsert(
@@ -1283,7 +1513,7 @@ async fn apply_field_groups(
         Some(env.meta.byline.as_str()),
         env.meta.deck.as_deref(),
         env.meta.updated.as_deref(),
-        pool,
+        &mut *conn,
     )
     .await?;
 
@@ -1294,7 +1524,7 @@ async fn apply_field_groups(
         sqlx::query("UPDATE post_meta SET pull_quote = $2 WHERE post_id = $1")
             .bind(post_id)
             .bind(pq)
-            .execute(pool)
+            .execute(&mut *conn)
             .await
             .map_err(|e| anyhow::anyhow!(e))?;
     }
@@ -1320,7 +1550,7 @@ async fn apply_field_groups(
             end,
             dt.cost.as_deref(),
             dt.recurring,
-            pool,
+            &mut *conn,
         )
         .await?;
     }
@@ -1335,7 +1565,7 @@ async fn apply_field_groups(
                     closes: e.closes.clone(),
                 })
                 .collect();
-            PostScheduleEntry::replace_all(post_id, &inputs, pool).await?;
+            PostScheduleEntry::replace_all(post_id, &inputs, &mut *conn).await?;
         }
     }
 
@@ -1348,7 +1578,7 @@ async fn apply_field_groups(
             p.photo_url.as_deref(),
             p.quote.as_deref(),
             None,
-            pool,
+            &mut *conn,
         )
         .await?;
     }
@@ -1362,7 +1592,7 @@ async fn apply_field_groups(
                     detail: i.detail.clone(),
                 })
                 .collect();
-            PostItem::replace_all(post_id, &inputs, pool).await?;
+            PostItem::replace_all(post_id, &inputs, &mut *conn).await?;
         }
     }
 
@@ -1370,7 +1600,7 @@ async fn apply_field_groups(
         if !contacts.is_empty() {
             // Wipe + re-insert; ingest is the source of truth for this post's
             // contacts block.
-            ContactModel::delete_all_for_post(PostId::from_uuid(post_id), pool).await?;
+            ContactModel::delete_all_for_post(PostId::from_uuid(post_id), &mut *conn).await?;
             for (i, c) in contacts.iter().enumerate() {
                 let ctype = c
                     .contact_type
@@ -1389,7 +1619,7 @@ async fn apply_field_groups(
                     c.contact_value.clone(),
                     c.contact_label.clone(),
                     Some(i as i32),
-                    pool,
+                    &mut *conn,
                 )
                 .await?;
             }
@@ -1408,7 +1638,7 @@ async fn apply_field_groups(
             link.label.as_deref(),
             link.url.as_deref(),
             deadline,
-            pool,
+            &mut *conn,
         )
         .await?;
     }
@@ -1425,7 +1655,7 @@ async fn apply_field_groups(
                     media_id: None,
                 })
                 .collect();
-            PostMediaRecord::replace_all(post_id, &inputs, pool).await?;
+            PostMediaRecord::replace_all(post_id, &inputs, &mut *conn).await?;
         }
     }
 
@@ -1440,8 +1670,13 @@ async fn apply_field_groups(
                     v.as_str().unwrap_or("").to_string()
                 }
             });
-        PostStatusRecord::upsert(post_id, status.state.as_deref(), verified.as_deref(), pool)
-            .await?;
+        PostStatusRecord::upsert(
+            post_id,
+            status.state.as_deref(),
+            verified.as_deref(),
+            &mut *conn,
+        )
+        .await?;
     }
 
     Ok(())
@@ -0,0 +1,290 @@
+//! Map points — active posts with coordinates as GeoJSON, clustered when
+//! zoomed out.
+//!
+//! The map page (docs/architecture/MAP_PAGE_PLAN.md) asks for a bounding
+//! box and a zoom level:
+//!
+//! 1. At zoom `CLUSTER_BELOW_ZOOM` and above, each located post is its own
+//!    `Point` feature (`kind: "post"`), capped at `MAX_POINTS`.
+//! 2. Below it, posts are counted per square grid cell of
+//!    `cell_size_degrees(zoom)` and each non-empty cell is one `Point`
+//!    feature (`kind: "cluster"`) at the mean of its posts, with a
+//!    per-post_type breakdown.
+//!
+//! Both use the same `post_locations → locations → zip_codes` joins and
+//! schedule/embargo filters as the public list (see `Post::find_map_points`).
+
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use serde::Serialize;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domains::posts::models::{MapCluster, MapFilters, MapPoint, Post};
+
+/// Zoom level at which the map switches from clusters to individual points.
+pub const CLUSTER_BELOW_ZOOM: u8 = 11;
+
+/// Most individual points returned for one viewport.
+pub const MAX_POINTS: i64 = 2000;
+
+/// Deepest zoom accepted (web map tiles stop around here).
+pub const MAX_ZOOM: u8 = 22;
+
+/// Grid cell size in degrees for a zoom level: a quarter of a 256px tile,
+/// i.e. roughly 64px cells on screen at any zoom.
+pub fn cell_size_degrees(zoom: u8) -> f64 {
+    360.0 / f64::from(1u32 << zoom.min(MAX_ZOOM)) / 4.0
+}
+
+/// Map viewport and filters from the request.
+#[derive(Debug, Clone)]
+pub struct MapQuery {
+    pub min_lat: f64,
+    pub max_lat: f64,
+    pub min_lng: f64,
+    pub max_lng: f64,
+    pub zoom: u8,
+    pub post_type: Option<String>,
+    pub tags: Vec<String>,
+    pub county_id: Option<Uuid>,
+}
+
+impl MapQuery {
+    /// Reject boxes that are out of range or inverted. The map never spans
+    /// the antimeridian, so `min_lng > max_lng` is an error rather than a
+    /// wraparound.
+    pub fn validate(&self) -> std::result::Result<(), String> {
+        let lat_ok = |v: f64| (-90.0..=90.0).contains(&v);
+        let lng_ok = |v: f64| (-180.0..=180.0).contains(&v);
+        if !(lat_ok(self.min_lat) && lat_ok(self.max_lat)) {
+            return Err("latitude must be between -90 and 90".into());
+        }
+        if !(lng_ok(self.min_lng) && lng_ok(self.max_lng)) {
+            return Err("longitude must be between -180 and 180".into());
+        }
+        if self.min_lat > self.max_lat || self.min_lng > self.max_lng {
+            return Err("bounding box minimum exceeds maximum".into());
+        }
+        if self.zoom > MAX_ZOOM {
+            return Err(format!("zoom must be at most {}", MAX_ZOOM));
+        }
+        Ok(())
+    }
+
+    pub fn is_clustered(&self) -> bool {
+        self.zoom < CLUSTER_BELOW_ZOOM
+    }
+}
+
+/// GeoJSON `FeatureCollection`. `clustered` and `truncated` are foreign
+/// members: whether features are cells, and whether points hit `MAX_POINTS`.
+#[derive(Debug, Clone, Serialize)]
+pub struct FeatureCollection {
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub features: Vec<Feature>,
+    pub clustered: bool,
+    pub truncated: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Feature {
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub geometry: PointGeometry,
+    pub properties: FeatureProperties,
+}
+
+/// GeoJSON point; `coordinates` are `[longitude, latitude]`.
+#[derive(Debug, Clone, Serialize)]
+pub struct PointGeometry {
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub coordinates: [f64; 2],
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum FeatureProperties {
+    Post {
+        id: Uuid,
+        title: String,
+        post_type: String,
+        location_text: Option<String>,
+        postal_code: Option<String>,
+    },
+    Cluster {
+        /// `[cell_x, cell_y]` grid index, stable across pans at one zoom.
+        cell: [i64; 2],
+        post_count: i64,
+        post_types: BTreeMap<String, i64>,
+    },
+}
+
+impl Feature {
+    fn point(latitude: f64, longitude: f64, properties: FeatureProperties) -> Self {
+        Self {
+            kind: "Feature",
+            geometry: PointGeometry {
+                kind: "Point",
+                coordinates: [longitude, latitude],
+            },
+            properties,
+        }
+    }
+}
+
+/// Load the features for a validated `MapQuery`.
+pub async fn load_map(query: &MapQuery, pool: &PgPool) -> Result<FeatureCollection> {
+    let filters = MapFilters {
+        min_lat: query.min_lat,
+        max_lat: query.max_lat,
+        min_lng: query.min_lng,
+        max_lng: query.max_lng,
+        post_type: query.post_type.as_deref(),
+        tags: (!query.tags.is_empty()).then_some(query.tags.as_slice()),
+        county_id: query.county_id,
+    };
+
+    if query.is_clustered() {
+        let rows =
+            Post::find_map_clusters(&filters, cell_size_degrees(query.zoom), pool).await?;
+        return Ok(FeatureCollection {
+            kind: "FeatureCollection",
+            features: cluster_features(rows),
+            clustered: true,
+            truncated: false,
+        });
+    }
+
+    let mut points = Post::find_map_points(&filters, MAX_POINTS + 1, pool).await?;
+    let truncated = points.len() as i64 > MAX_POINTS;
+    points.truncate(MAX_POINTS as usize);
+    Ok(FeatureCollection {
+        kind: "FeatureCollection",
+        features: points.into_iter().map(point_feature).collect(),
+        clustered: false,
+        truncated,
+    })
+}
+
+fn point_feature(p: MapPoint) -> Feature {
+    Feature::point(
+        p.latitude,
+        p.longitude,
+        FeatureProperties::Post {
+            id: p.id.into_uuid(),
+            title: p.title,
+            post_type: p.post_type,
+            location_text: p.location_text,
+            postal_code: p.postal_code,
+        },
+    )
+}
+
+/// Fold per-(cell, post_type) rows into one feature per cell. The cell
+/// position is the count-weighted mean of its rows. A post with two types
+/// can't happen, so summing per-type counts gives the cell total.
+fn cluster_features(rows: Vec<MapCluster>) -> Vec<Feature> {
+    struct Cell {
+        count: i64,
+        lat_sum: f64,
+        lng_sum: f64,
+        post_types: BTreeMap<String, i64>,
+    }
+
+    let mut cells: BTreeMap<(i64, i64), Cell> = BTreeMap::new();
+    for row in rows {
+        let cell = cells.entry((row.cell_x, row.cell_y)).or_insert(Cell {
+            count: 0,
+            lat_sum: 0.0,
+            lng_sum: 0.0,
+            post_types: BTreeMap::new(),
+        });
+        cell.count += row.post_count;
+        cell.lat_sum += row.latitude * row.post_count as f64;
+        cell.lng_sum += row.longitude * row.post_count as f64;
+        *cell.post_types.entry(row.post_type).or_default() += row.post_count;
+    }
+
+    cells
+        .into_iter()
+        .filter(|(_, c)| c.count > 0)
+        .map(|((x, y), c)| {
+            Feature::point(
+                c.lat_sum / c.count as f64,
+                c.lng_sum / c.count as f64,
+                FeatureProperties::Cluster {
+                    cell: [x, y],
+                    post_count: c.count,
+                    post_types: c.post_types,
+                },
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(x: i64, y: i64, post_type: &str, count: i64, lat: f64, lng: f64) -> MapCluster {
+        MapCluster {
+            cell_x: x,
+            cell_y: y,
+            post_type: post_type.into(),
+            post_count: count,
+            latitude: lat,
+            longitude: lng,
+        }
+    }
+
+    #[test]
+    fn cell_size_halves_per_zoom_level() {
+        assert_eq!(cell_size_degrees(0), 90.0);
+        assert_eq!(cell_size_degrees(7), cell_size_degrees(6) / 2.0);
+        assert_eq!(cell_size_degrees(40), cell_size_degrees(MAX_ZOOM));
+    }
+
+    #[test]
+    fn clusters_merge_post_types_per_cell_with_weighted_centroid() {
+        let features = cluster_features(vec![
+            row(1, 2, "story", 3, 45.0, -93.0),
+            row(1, 2, "event", 1, 46.0, -94.0),
+            row(5, 5, "notice", 2, 44.0, -92.0),
+        ]);
+        assert_eq!(features.len(), 2);
+
+        let f = &features[0];
+        assert_eq!(f.geometry.coordinates, [-93.25, 45.25]);
+        match &f.properties {
+            FeatureProperties::Cluster { cell, post_count, post_types } => {
+                assert_eq!(*cell, [1, 2]);
+                assert_eq!(*post_count, 4);
+                assert_eq!(post_types.get("story"), Some(&3));
+                assert_eq!(post_types.get("event"), Some(&1));
+            }
+            other => panic!("expected cluster, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn validate_rejects_inverted_box() {
+        let mut q = MapQuery {
+            min_lat: 43.5,
+            max_lat: 49.4,
+            min_lng: -97.2,
+            max_lng: -89.5,
+            zoom: 7,
+            post_type: None,
+            tags: vec![],
+            county_id: None,
+        };
+        assert!(q.validate().is_ok());
+        assert!(q.is_clustered());
+        q.min_lng = -80.0;
+        assert!(q.validate().is_err());
+    }
+}
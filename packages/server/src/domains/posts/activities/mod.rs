@@ -5,16 +5,23 @@
 //! auth checks, and return final models/results.
 
 pub mod backfill;
+pub mod calendar_export;
+pub mod calendar_import;
 pub mod content_hash_dedup;
 pub mod contacts;
 pub mod core;
+pub mod county_resolution;
 pub mod create_post;
 pub mod expire_scheduled_posts;
 pub mod individual_dedup;
 pub mod ingest_post;
+pub mod map_points;
+pub mod near_duplicate;
 pub mod organization_dedup;
+pub mod post_merge;
 pub mod post_operations;
 pub mod reports;
+pub mod review_flags;
 pub mod revision_actions;
 pub mod revision_reflow;
 pub mod schedule;
@@ -28,6 +35,7 @@ pub use core::*;
 pub use create_post::{create_extracted_post, tag_post_from_extracted};
 pub use reports::ReportCreated;
 pub use reports::*;
+pub use review_flags::resolve_review_flag;
 pub use revision_actions::{
     approve_revision, count_pending_revisions, get_pending_revisions, get_revision_for_post,
     reject_revision,
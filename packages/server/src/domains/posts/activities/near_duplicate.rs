@@ -0,0 +1,443 @@
+//! Near-duplicate detection — MinHash over word shingles.
+//!
+//! Content-hash dedup only catches exact repeats. This catches the same
+//! story reworded, or the same event written up by two sources:
+//!
+//!   1. Shingle the title and body into lowercase word 3-grams.
+//!   2. MinHash the shingle set into a 128-value signature. The fraction of
+//!      positions two signatures agree on estimates the Jaccard similarity
+//!      of their shingle sets.
+//!   3. Cut the signature into 32 bands of 4 and hash each band to a
+//!      bucket (locality-sensitive hashing). Posts sharing a bucket are
+//!      candidates; only those are compared in full.
+//!
+//! Ingest signs every new post before storing it and looks for candidates
+//! published within [`WINDOW_DAYS`] that share a county (or, for posts with
+//! no county signal, also have none), so the post is inserted with its final
+//! status. The post a revision replaces is never a candidate. Matches at or
+//! above [`SIMILARITY_THRESHOLD`] become a `possible_duplicate` review flag.
+//! Storage is `models::PostSimilarity` (migration 257).
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use serde_json::json;
+use sqlx::{Acquire, PgPool, Postgres};
+use uuid::Uuid;
+
+use crate::common::PostId;
+use crate::domains::posts::models::{NewReviewFlag, Post, PostSimilarity};
+
+const NUM_HASHES: usize = 128;
+const ROWS_PER_BAND: usize = 4;
+const SHINGLE_WORDS: usize = 3;
+
+/// Estimated Jaccard similarity at which two posts are flagged.
+pub const SIMILARITY_THRESHOLD: f64 = 0.6;
+/// How far either side of a post's publish date to look for duplicates.
+pub const WINDOW_DAYS: i64 = 14;
+/// Most matches listed on one flag.
+const MAX_MATCHES: usize = 5;
+
+/// A post's MinHash signature.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signature {
+    pub hashes: Vec<u32>,
+    pub shingle_count: usize,
+}
+
+impl Signature {
+    /// Signature of a post's title and body. None when there are no words
+    /// to shingle.
+    pub fn of(title: &str, body: &str) -> Option<Self> {
+        let shingles = shingles(&format!("{title} {body}"));
+        if shingles.is_empty() {
+            return None;
+        }
+        let hashes = hash_params()
+            .iter()
+            .map(|&(a, b)| {
+                shingles
+                    .iter()
+                    .map(|&s| (a.wrapping_mul(s).wrapping_add(b) >> 32) as u32)
+                    .min()
+                    .unwrap_or(u32::MAX)
+            })
+            .collect();
+        Some(Self {
+            hashes,
+            shingle_count: shingles.len(),
+        })
+    }
+
+    fn from_stored(stored: &[i64]) -> Self {
+        Self {
+            hashes: stored.iter().map(|&h| h as u32).collect(),
+            shingle_count: 0,
+        }
+    }
+
+    fn to_stored(&self) -> Vec<i64> {
+        self.hashes.iter().map(|&h| h as i64).collect()
+    }
+
+    /// Estimated Jaccard similarity, 0.0–1.0.
+    pub fn similarity(&self, other: &Self) -> f64 {
+        if self.hashes.is_empty() || self.hashes.len() != other.hashes.len() {
+            return 0.0;
+        }
+        let agree = self
+            .hashes
+            .iter()
+            .zip(&other.hashes)
+            .filter(|(a, b)| a == b)
+            .count();
+        agree as f64 / self.hashes.len() as f64
+    }
+
+    /// One bucket per band of [`ROWS_PER_BAND`] hashes.
+    pub fn band_buckets(&self) -> Vec<i64> {
+        self.hashes
+            .chunks(ROWS_PER_BAND)
+            .enumerate()
+            .map(|(band, rows)| {
+                let mut bytes = Vec::with_capacity(2 + rows.len() * 4);
+                bytes.extend_from_slice(&(band as u16).to_le_bytes());
+                for row in rows {
+                    bytes.extend_from_slice(&row.to_le_bytes());
+                }
+                fnv1a(&bytes) as i64
+            })
+            .collect()
+    }
+}
+
+/// Lowercase word 3-grams, hashed. Texts shorter than a shingle hash as
+/// one shingle of all their words.
+fn shingles(text: &str) -> HashSet<u64> {
+    let words: Vec<String> = text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+    if words.is_empty() {
+        return HashSet::new();
+    }
+    if words.len() < SHINGLE_WORDS {
+        return HashSet::from([fnv1a(words.join(" ").as_bytes())]);
+    }
+    words
+        .windows(SHINGLE_WORDS)
+        .map(|w| fnv1a(w.join(" ").as_bytes()))
+        .collect()
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for &b in bytes {
+        hash ^= b as u64;
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash
+}
+
+/// Fixed (a, b) pairs for the multiply-shift hash family, from splitmix64
+/// so signatures stay comparable across deploys. `a` is forced odd.
+fn hash_params() -> Vec<(u64, u64)> {
+    let mut state: u64 = 0x6d6e_746f_6765_7468;
+    let mut next = || {
+        state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    };
+    (0..NUM_HASHES).map(|_| (next() | 1, next())).collect()
+}
+
+/// A post that looks like another, with the estimated similarity.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NearDuplicate {
+    pub post_id: Uuid,
+    pub similarity: f64,
+}
+
+/// Compute and store a post's signature. None when the post has no text.
+pub async fn index_post(
+    post_id: PostId,
+    title: &str,
+    body: &str,
+    pool: &PgPool,
+) -> Result<Option<Signature>> {
+    let Some(signature) = Signature::of(title, body) else {
+        return Ok(None);
+    };
+    store_signature(post_id, &signature, pool).await?;
+    Ok(Some(signature))
+}
+
+/// Store an already computed signature for a post.
+pub async fn store_signature(
+    post_id: PostId,
+    signature: &Signature,
+    db: impl Acquire<'_, Database = Postgres>,
+) -> Result<()> {
+    PostSimilarity::upsert(
+        post_id,
+        &signature.to_stored(),
+        signature.shingle_count as i32,
+        &signature.band_buckets(),
+        db,
+    )
+    .await
+}
+
+/// Posts within [`WINDOW_DAYS`] of `published_at` that share a county with
+/// `county_ids` (or, when it is empty, have no county either), whose
+/// similarity to `signature` is at least [`SIMILARITY_THRESHOLD`]. Most
+/// similar first. Runs before the new post is stored, so it never matches
+/// itself; `revision_of` and its own predecessors are never matches.
+pub async fn find_near_duplicates(
+    signature: &Signature,
+    published_at: DateTime<Utc>,
+    county_ids: &[Uuid],
+    revision_of: Option<Uuid>,
+    pool: &PgPool,
+) -> Result<Vec<NearDuplicate>> {
+    let window = Duration::days(WINDOW_DAYS);
+    let candidates = PostSimilarity::find_candidates(
+        &signature.band_buckets(),
+        published_at - window,
+        published_at + window,
+        revision_of,
+        pool,
+    )
+    .await?;
+
+    let scored: Vec<NearDuplicate> = candidates
+        .iter()
+        .map(|c| NearDuplicate {
+            post_id: c.post_id,
+            similarity: signature.similarity(&Signature::from_stored(&c.signature)),
+        })
+        .filter(|m| m.similarity >= SIMILARITY_THRESHOLD)
+        .collect();
+    if scored.is_empty() {
+        return Ok(scored);
+    }
+
+    let ids: Vec<Uuid> = scored.iter().map(|m| m.post_id).collect();
+    let counties = Post::find_county_ids_for_posts(&ids, pool).await?;
+    let mut matches: Vec<NearDuplicate> = scored
+        .into_iter()
+        .filter(|m| {
+            let theirs = counties.get(&m.post_id).map(Vec::as_slice).unwrap_or(&[]);
+            if county_ids.is_empty() {
+                theirs.is_empty()
+            } else {
+                theirs.iter().any(|c| county_ids.contains(c))
+            }
+        })
+        .collect();
+    matches.sort_by(|a, b| b.similarity.total_cmp(&a.similarity));
+    matches.truncate(MAX_MATCHES);
+    Ok(matches)
+}
+
+/// The `possible_duplicate` review flag for a set of matches.
+pub fn review_flag(matches: &[NearDuplicate]) -> NewReviewFlag {
+    let listed: Vec<_> = matches
+        .iter()
+        .map(|m| json!({ "post_id": m.post_id, "similarity": round3(m.similarity) }))
+        .collect();
+    NewReviewFlag::blocking(
+        "possible_duplicate",
+        json!({ "method": "minhash", "matches": listed }),
+    )
+}
+
+fn round3(x: f64) -> f64 {
+    (x * 1000.0).round() / 1000.0
+}
+
+/// Index up to `limit` active posts that have no signature yet (the
+/// `index_post_similarity` job). Returns how many were indexed.
+pub async fn index_unindexed(limit: i64, pool: &PgPool) -> Result<usize> {
+    let posts = PostSimilarity::find_active_unindexed(limit, pool).await?;
+    let mut indexed = 0;
+    for post in &posts {
+        if index_post(PostId::from_uuid(post.id), &post.title, &post.body_raw, pool)
+            .await?
+            .is_some()
+        {
+            indexed += 1;
+        }
+    }
+    Ok(indexed)
+}
+
+/// Two posts in a cluster and how alike they are.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SimilarPair {
+    pub a: Uuid,
+    pub b: Uuid,
+    pub similarity: f64,
+}
+
+/// Posts connected by similar pairs.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DuplicateCluster {
+    pub post_ids: Vec<Uuid>,
+    pub pairs: Vec<SimilarPair>,
+}
+
+/// Most candidate pairs [`find_clusters`] compares in one call.
+pub const MAX_CLUSTER_PAIRS: i64 = 5000;
+
+/// Clusters of near-duplicate active posts published since `since` at or
+/// above `min_similarity`, largest first. With `county_ids`, only posts
+/// resolved to those counties are considered.
+pub async fn find_clusters(
+    min_similarity: f64,
+    since: DateTime<Utc>,
+    county_ids: Option<&[Uuid]>,
+    pool: &PgPool,
+) -> Result<Vec<DuplicateCluster>> {
+    let bucket_pairs =
+        PostSimilarity::find_active_bucket_pairs(since, county_ids, MAX_CLUSTER_PAIRS, pool)
+            .await?;
+    let ids: Vec<Uuid> = bucket_pairs
+        .iter()
+        .flat_map(|&(a, b)| [a, b])
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    let signatures: HashMap<Uuid, Signature> = PostSimilarity::find_signatures(&ids, pool)
+        .await?
+        .into_iter()
+        .map(|(id, stored)| (id, Signature::from_stored(&stored)))
+        .collect();
+
+    let pairs: Vec<SimilarPair> = bucket_pairs
+        .into_iter()
+        .filter_map(|(a, b)| {
+            let similarity = signatures.get(&a)?.similarity(signatures.get(&b)?);
+            (similarity >= min_similarity).then(|| SimilarPair {
+                a,
+                b,
+                similarity: round3(similarity),
+            })
+        })
+        .collect();
+    Ok(cluster_pairs(pairs))
+}
+
+/// Group pairs into connected components.
+fn cluster_pairs(pairs: Vec<SimilarPair>) -> Vec<DuplicateCluster> {
+    fn root(parent: &mut HashMap<Uuid, Uuid>, id: Uuid) -> Uuid {
+        let p = *parent.entry(id).or_insert(id);
+        if p == id {
+            return id;
+        }
+        let r = root(parent, p);
+        parent.insert(id, r);
+        r
+    }
+
+    let mut parent: HashMap<Uuid, Uuid> = HashMap::new();
+    for pair in &pairs {
+        let (ra, rb) = (root(&mut parent, pair.a), root(&mut parent, pair.b));
+        if ra != rb {
+            parent.insert(ra.max(rb), ra.min(rb));
+        }
+    }
+
+    let mut clusters: HashMap<Uuid, DuplicateCluster> = HashMap::new();
+    for pair in pairs {
+        let r = root(&mut parent, pair.a);
+        let cluster = clusters.entry(r).or_insert_with(|| DuplicateCluster {
+            post_ids: Vec::new(),
+            pairs: Vec::new(),
+        });
+        for id in [pair.a, pair.b] {
+            if !cluster.post_ids.contains(&id) {
+                cluster.post_ids.push(id);
+            }
+        }
+        cluster.pairs.push(pair);
+    }
+
+    let mut clusters: Vec<DuplicateCluster> = clusters.into_values().collect();
+    for c in &mut clusters {
+        c.post_ids.sort();
+        c.pairs.sort_by(|x, y| y.similarity.total_cmp(&x.similarity));
+    }
+    clusters.sort_by(|x, y| {
+        y.post_ids
+            .len()
+            .cmp(&x.post_ids.len())
+            .then_with(|| x.post_ids.cmp(&y.post_ids))
+    });
+    clusters
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STORY: &str = "The Sabathani Community Center will host free tax preparation \
+        for seniors and low-income families every Saturday in April, from 9am \
+        to 1pm. Bring photo ID, Social Security cards and last year's return.";
+    const REWORDED: &str = "The Sabathani Community Center will host free tax preparation \
+        for seniors and low-income families every Saturday in April, from 9am \
+        to 2pm. Please bring photo ID, Social Security cards and last year's return.";
+    const UNRELATED: &str = "Hennepin County crews will close the Lake Street bridge \
+        overnight for deck repairs starting Monday; detours follow Minnehaha Avenue.";
+
+    #[test]
+    fn reworded_story_scores_high_and_unrelated_low() {
+        let a = Signature::of("Free tax prep at Sabathani", STORY).unwrap();
+        let b = Signature::of("Free tax help at Sabathani", REWORDED).unwrap();
+        let c = Signature::of("Lake Street bridge closure", UNRELATED).unwrap();
+
+        assert_eq!(a.similarity(&a), 1.0);
+        assert!(a.similarity(&b) >= SIMILARITY_THRESHOLD, "{}", a.similarity(&b));
+        assert!(a.similarity(&c) < 0.2, "{}", a.similarity(&c));
+
+        // Signatures are deterministic, and similar posts share a bucket.
+        assert_eq!(a, Signature::of("Free tax prep at Sabathani", STORY).unwrap());
+        let shared = a
+            .band_buckets()
+            .iter()
+            .zip(b.band_buckets())
+            .any(|(x, y)| *x == y);
+        assert!(shared);
+        assert_eq!(a.band_buckets().len(), NUM_HASHES / ROWS_PER_BAND);
+    }
+
+    #[test]
+    fn text_without_words_has_no_signature() {
+        assert!(Signature::of("", " -- ").is_none());
+        assert_eq!(Signature::of("Hi", "").unwrap().shingle_count, 1);
+    }
+
+    #[test]
+    fn pairs_cluster_transitively() {
+        let ids: Vec<Uuid> = (0..5).map(|_| Uuid::new_v4()).collect();
+        let pair = |a: usize, b: usize, similarity: f64| SimilarPair {
+            a: ids[a],
+            b: ids[b],
+            similarity,
+        };
+        let clusters = cluster_pairs(vec![pair(0, 1, 0.7), pair(3, 4, 0.9), pair(1, 2, 0.8)]);
+
+        assert_eq!(clusters.len(), 2);
+        let mut big = vec![ids[0], ids[1], ids[2]];
+        big.sort();
+        assert_eq!(clusters[0].post_ids, big);
+        assert_eq!(clusters[0].pairs[0].similarity, 0.8);
+        assert_eq!(clusters[1].pairs.len(), 1);
+    }
+}
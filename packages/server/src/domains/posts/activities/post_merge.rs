@@ -0,0 +1,561 @@
+//! Duplicate merge — fold one post into another.
+//!
+//! Entry point is [`merge_into`], called from `/Post/{id}/merge_into`.
+//! Everything below runs in one transaction, which first locks both posts
+//! (re-checking that neither has been deleted or merged away) and every
+//! edition it will touch:
+//!
+//!   1. Union onto the survivor: `post_sources` citations (the duplicate's
+//!      primary becomes a secondary citation), tags, contacts, schedules
+//!      and notes. Rows the survivor already has are left behind.
+//!   2. Field groups: the survivor keeps its own unless the editor picked
+//!      the duplicate's, in which case the survivor's rows are replaced by
+//!      the duplicate's (even if the duplicate has none). `media_references`
+//!      follow the group that uses them — `post_hero` with `media`,
+//!      `post_person` with `person`. Body references stay with the body,
+//!      which is always the survivor's.
+//!   3. Edition slots in draft / in_review / approved editions are
+//!      re-pointed at the survivor; where the survivor is already in the
+//!      edition the duplicate's slot is dropped instead. Published editions
+//!      are left as printed. Each touched edition gets a `merge_posts`
+//!      history event, so the slot change can be undone there.
+//!   4. The duplicate is archived with `duplicate_of_id` pointing at the
+//!      survivor, its open review flags are resolved, and a `post_merges`
+//!      row records the merge (see migration 256) so content-hash dedup
+//!      resolves re-ingested duplicate content to the survivor.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Postgres, Transaction};
+use tracing::info;
+use uuid::Uuid;
+
+use crate::domains::editions::activities::edition_history;
+use crate::kernel::ServerDeps;
+
+/// A post's field groups, as returned by `/Post/{id}/field_groups`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FieldGroup {
+    Media,
+    Items,
+    Person,
+    Link,
+    SourceAttribution,
+    Meta,
+    Datetime,
+    PostStatus,
+    Schedule,
+}
+
+impl FieldGroup {
+    fn table(self) -> &'static str {
+        match self {
+            Self::Media => "post_media",
+            Self::Items => "post_items",
+            Self::Person => "post_person",
+            Self::Link => "post_link",
+            Self::SourceAttribution => "post_source_attribution",
+            Self::Meta => "post_meta",
+            Self::Datetime => "post_datetime",
+            Self::PostStatus => "post_status",
+            Self::Schedule => "post_schedule",
+        }
+    }
+
+    /// `media_references.referenceable_type` values owned by this group.
+    fn media_reference_types(self) -> &'static [&'static str] {
+        match self {
+            Self::Media => &["post_hero"],
+            Self::Person => &["post_person"],
+            _ => &[],
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Media => "media",
+            Self::Items => "items",
+            Self::Person => "person",
+            Self::Link => "link",
+            Self::SourceAttribution => "source_attribution",
+            Self::Meta => "meta",
+            Self::Datetime => "datetime",
+            Self::PostStatus => "post_status",
+            Self::Schedule => "schedule",
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PostMergeError {
+    #[error("post not found: {0}")]
+    NotFound(Uuid),
+    #[error("a post cannot be merged into itself")]
+    SamePost,
+    #[error("post {0} has already been merged into another post")]
+    AlreadyMerged(Uuid),
+    #[error("db: {0}")]
+    Db(#[from] anyhow::Error),
+}
+
+impl From<sqlx::Error> for PostMergeError {
+    fn from(err: sqlx::Error) -> Self {
+        Self::Db(err.into())
+    }
+}
+
+/// What a merge moved.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PostMergeSummary {
+    pub survivor_post_id: Uuid,
+    pub merged_post_id: Uuid,
+    pub sources: u64,
+    pub tags: u64,
+    pub contacts: u64,
+    pub schedules: u64,
+    pub notes: u64,
+    pub media_references: u64,
+    pub field_groups_from_duplicate: Vec<FieldGroup>,
+    pub edition_slots_repointed: u64,
+    pub edition_slots_removed: u64,
+    pub editions: Vec<Uuid>,
+}
+
+/// Merge `duplicate_id` into `survivor_id`. `keep_from_duplicate` lists the
+/// field groups to take from the duplicate; the survivor keeps the rest.
+pub async fn merge_into(
+    duplicate_id: Uuid,
+    survivor_id: Uuid,
+    keep_from_duplicate: &[FieldGroup],
+    member_id: Uuid,
+    deps: &ServerDeps,
+) -> Result<PostMergeSummary, PostMergeError> {
+    if duplicate_id == survivor_id {
+        return Err(PostMergeError::SamePost);
+    }
+
+    let mut keep: Vec<FieldGroup> = Vec::new();
+    for &group in keep_from_duplicate {
+        if !keep.contains(&group) {
+            keep.push(group);
+        }
+    }
+
+    let mut tx = deps.db_pool.begin().await?;
+    lock_posts(&mut tx, duplicate_id, survivor_id).await?;
+
+    // Lock each edition and snapshot its layout before the slots move, so
+    // its history can undo the merge's part in it.
+    let editions = unpublished_editions_containing(&mut tx, duplicate_id).await?;
+    let mut befores = Vec::with_capacity(editions.len());
+    for &edition_id in &editions {
+        befores.push(edition_history::lock_and_capture(edition_id, &mut tx).await?);
+    }
+
+    let mut summary = PostMergeSummary {
+        survivor_post_id: survivor_id,
+        merged_post_id: duplicate_id,
+        field_groups_from_duplicate: keep.clone(),
+        editions: editions.clone(),
+        ..Default::default()
+    };
+    move_associations(&mut tx, duplicate_id, survivor_id, &mut summary).await?;
+    for &group in &keep {
+        summary.media_references +=
+            take_field_group(&mut tx, group, duplicate_id, survivor_id).await?;
+    }
+    repoint_edition_slots(&mut tx, duplicate_id, survivor_id, &editions, &mut summary).await?;
+    retire_duplicate(&mut tx, duplicate_id, survivor_id, &keep, member_id, &summary).await?;
+
+    let params = serde_json::json!({
+        "merged_post_id": duplicate_id,
+        "survivor_post_id": survivor_id,
+    });
+    for (&edition_id, before) in editions.iter().zip(befores) {
+        edition_history::record_edit(
+            edition_id,
+            before,
+            Some(member_id),
+            "merge_posts",
+            params.clone(),
+            &mut tx,
+        )
+        .await?;
+    }
+    tx.commit().await?;
+
+    info!(
+        merged_post_id = %duplicate_id,
+        survivor_post_id = %survivor_id,
+        editions = summary.editions.len(),
+        "merged duplicate post"
+    );
+    Ok(summary)
+}
+
+/// Lock both posts (in id order, so two merges of the same pair can't
+/// deadlock) and check that neither is deleted or already merged away.
+/// A `duplicate_of_id` alone doesn't count: ingest sets it on possible
+/// duplicates, which are exactly the posts waiting to be merged. Only an
+/// archived post carrying one has been folded away.
+/// Holding the locks until commit keeps a concurrent merge from folding
+/// either post elsewhere in the meantime.
+async fn lock_posts(
+    tx: &mut Transaction<'_, Postgres>,
+    duplicate_id: Uuid,
+    survivor_id: Uuid,
+) -> Result<(), PostMergeError> {
+    let mut ids = [duplicate_id, survivor_id];
+    ids.sort();
+    for id in ids {
+        let row: Option<(Option<DateTime<Utc>>, Option<Uuid>, String)> = sqlx::query_as(
+            "SELECT deleted_at, duplicate_of_id, status FROM posts WHERE id = $1 FOR UPDATE",
+        )
+        .bind(id)
+        .fetch_optional(&mut **tx)
+        .await?;
+        let Some((deleted_at, duplicate_of_id, status)) = row else {
+            return Err(PostMergeError::NotFound(id));
+        };
+        if deleted_at.is_some() {
+            return Err(PostMergeError::NotFound(id));
+        }
+        let archived_duplicate = status == "archived" && duplicate_of_id.is_some();
+        if archived_duplicate || is_merged(tx, id).await? {
+            return Err(PostMergeError::AlreadyMerged(id));
+        }
+    }
+    Ok(())
+}
+
+async fn is_merged(tx: &mut Transaction<'_, Postgres>, post_id: Uuid) -> anyhow::Result<bool> {
+    sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM post_merges WHERE merged_post_id = $1)")
+        .bind(post_id)
+        .fetch_one(&mut **tx)
+        .await
+        .map_err(Into::into)
+}
+
+/// Ids of the draft / in_review / approved editions slotting `post_id`, in
+/// id order (the order their rows are locked in).
+async fn unpublished_editions_containing(
+    tx: &mut Transaction<'_, Postgres>,
+    post_id: Uuid,
+) -> anyhow::Result<Vec<Uuid>> {
+    sqlx::query_scalar(
+        r#"
+        SELECT DISTINCT e.id
+        FROM editions e
+        JOIN edition_rows er ON er.edition_id = e.id
+        JOIN edition_slots es ON es.edition_row_id = er.id
+        WHERE es.post_id = $1
+          AND e.status IN ('draft', 'in_review', 'approved')
+        ORDER BY e.id
+        "#,
+    )
+    .bind(post_id)
+    .fetch_all(&mut **tx)
+    .await
+    .map_err(Into::into)
+}
+
+/// Step 1: union citations, tags, contacts, schedules and notes.
+async fn move_associations(
+    tx: &mut Transaction<'_, Postgres>,
+    from: Uuid,
+    to: Uuid,
+    summary: &mut PostMergeSummary,
+) -> Result<(), PostMergeError> {
+    // Moved citations keep their `platform_id` and `content_hash`, which is
+    // what calendar import matches a re-fetched VEVENT against. One the
+    // survivor already has from the same source stays on the duplicate,
+    // where the import still finds it by `platform_id`.
+    summary.sources = sqlx::query(
+        r#"
+        UPDATE post_sources ps
+        SET post_id = $2, is_primary = false, updated_at = NOW()
+        WHERE ps.post_id = $1
+          AND NOT EXISTS (
+              SELECT 1 FROM post_sources t
+              WHERE t.post_id = $2
+                AND t.source_type = ps.source_type
+                AND t.source_id = ps.source_id
+          )
+        "#,
+    )
+    .bind(from)
+    .bind(to)
+    .execute(&mut **tx)
+    .await?
+    .rows_affected();
+
+    summary.tags = sqlx::query(
+        r#"
+        UPDATE taggables tg
+        SET taggable_id = $2
+        WHERE tg.taggable_type = 'post' AND tg.taggable_id = $1
+          AND NOT EXISTS (
+              SELECT 1 FROM taggables t
+              WHERE t.taggable_type = 'post' AND t.taggable_id = $2 AND t.tag_id = tg.tag_id
+          )
+        "#,
+    )
+    .bind(from)
+    .bind(to)
+    .execute(&mut **tx)
+    .await?
+    .rows_affected();
+
+    summary.contacts = sqlx::query(
+        r#"
+        UPDATE contacts c
+        SET contactable_id = $2
+        WHERE c.contactable_type = 'post' AND c.contactable_id = $1
+          AND NOT EXISTS (
+              SELECT 1 FROM contacts t
+              WHERE t.contactable_type = 'post' AND t.contactable_id = $2
+                AND t.contact_type = c.contact_type
+                AND t.contact_value = c.contact_value
+          )
+        "#,
+    )
+    .bind(from)
+    .bind(to)
+    .execute(&mut **tx)
+    .await?
+    .rows_affected();
+
+    // A schedule identical to one the survivor has would list the event twice.
+    summary.schedules = sqlx::query(
+        r#"
+        UPDATE schedules s
+        SET schedulable_id = $2, updated_at = NOW()
+        WHERE s.schedulable_type = 'post' AND s.schedulable_id = $1
+          AND NOT EXISTS (
+              SELECT 1 FROM schedules t
+              WHERE t.schedulable_type = 'post' AND t.schedulable_id = $2
+                AND t.dtstart IS NOT DISTINCT FROM s.dtstart
+                AND t.dtend IS NOT DISTINCT FROM s.dtend
+                AND t.rrule IS NOT DISTINCT FROM s.rrule
+          )
+        "#,
+    )
+    .bind(from)
+    .bind(to)
+    .execute(&mut **tx)
+    .await?
+    .rows_affected();
+
+    summary.notes = sqlx::query(
+        r#"
+        UPDATE noteables n
+        SET noteable_id = $2
+        WHERE n.noteable_type = 'post' AND n.noteable_id = $1
+          AND NOT EXISTS (
+              SELECT 1 FROM noteables t
+              WHERE t.noteable_type = 'post' AND t.noteable_id = $2 AND t.note_id = n.note_id
+          )
+        "#,
+    )
+    .bind(from)
+    .bind(to)
+    .execute(&mut **tx)
+    .await?
+    .rows_affected();
+
+    Ok(())
+}
+
+/// Step 2: replace the survivor's rows for `group` with the duplicate's.
+/// Returns the number of media references moved with it.
+async fn take_field_group(
+    tx: &mut Transaction<'_, Postgres>,
+    group: FieldGroup,
+    from: Uuid,
+    to: Uuid,
+) -> Result<u64, PostMergeError> {
+    let table = group.table();
+    sqlx::query(&format!("DELETE FROM {table} WHERE post_id = $1"))
+        .bind(to)
+        .execute(&mut **tx)
+        .await?;
+    sqlx::query(&format!("UPDATE {table} SET post_id = $2 WHERE post_id = $1"))
+        .bind(from)
+        .bind(to)
+        .execute(&mut **tx)
+        .await?;
+
+    let ref_types = group.media_reference_types();
+    if ref_types.is_empty() {
+        return Ok(0);
+    }
+    sqlx::query(
+        r#"
+        DELETE FROM media_references
+        WHERE referenceable_type = ANY($2) AND referenceable_id = $1
+        "#,
+    )
+    .bind(to)
+    .bind(ref_types)
+    .execute(&mut **tx)
+    .await?;
+    let moved = sqlx::query(
+        r#"
+        UPDATE media_references
+        SET referenceable_id = $2
+        WHERE referenceable_type = ANY($3) AND referenceable_id = $1
+        "#,
+    )
+    .bind(from)
+    .bind(to)
+    .bind(ref_types)
+    .execute(&mut **tx)
+    .await?
+    .rows_affected();
+    Ok(moved)
+}
+
+/// Step 3: slots in unpublished editions follow the survivor.
+async fn repoint_edition_slots(
+    tx: &mut Transaction<'_, Postgres>,
+    from: Uuid,
+    to: Uuid,
+    editions: &[Uuid],
+    summary: &mut PostMergeSummary,
+) -> Result<(), PostMergeError> {
+    if editions.is_empty() {
+        return Ok(());
+    }
+    summary.edition_slots_removed = sqlx::query(
+        r#"
+        DELETE FROM edition_slots es
+        USING edition_rows er
+        WHERE es.edition_row_id = er.id
+          AND er.edition_id = ANY($3)
+          AND es.post_id = $1
+          AND EXISTS (
+              SELECT 1 FROM edition_slots s2
+              JOIN edition_rows r2 ON r2.id = s2.edition_row_id
+              WHERE r2.edition_id = er.edition_id AND s2.post_id = $2
+          )
+        "#,
+    )
+    .bind(from)
+    .bind(to)
+    .bind(editions)
+    .execute(&mut **tx)
+    .await?
+    .rows_affected();
+
+    summary.edition_slots_repointed = sqlx::query(
+        r#"
+        UPDATE edition_slots es
+        SET post_id = $2
+        FROM edition_rows er
+        WHERE es.edition_row_id = er.id
+          AND er.edition_id = ANY($3)
+          AND es.post_id = $1
+        "#,
+    )
+    .bind(from)
+    .bind(to)
+    .bind(editions)
+    .execute(&mut **tx)
+    .await?
+    .rows_affected();
+    Ok(())
+}
+
+/// Step 4: archive the duplicate behind a redirect and record the merge.
+async fn retire_duplicate(
+    tx: &mut Transaction<'_, Postgres>,
+    from: Uuid,
+    to: Uuid,
+    keep: &[FieldGroup],
+    member_id: Uuid,
+    summary: &PostMergeSummary,
+) -> Result<(), PostMergeError> {
+    let content_hash: Option<String> =
+        sqlx::query_scalar("SELECT content_hash FROM posts WHERE id = $1")
+            .bind(from)
+            .fetch_one(&mut **tx)
+            .await?;
+
+    sqlx::query(
+        r#"
+        UPDATE posts
+        SET status = 'archived', duplicate_of_id = $2, updated_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(from)
+    .bind(to)
+    .execute(&mut **tx)
+    .await?;
+
+    // Anything that pointed at the duplicate now points at the survivor,
+    // so redirects and dedup lookups stay one hop.
+    sqlx::query("UPDATE posts SET duplicate_of_id = $2 WHERE duplicate_of_id = $1 AND id <> $2")
+        .bind(from)
+        .bind(to)
+        .execute(&mut **tx)
+        .await?;
+    sqlx::query("UPDATE post_merges SET survivor_post_id = $2 WHERE survivor_post_id = $1")
+        .bind(from)
+        .bind(to)
+        .execute(&mut **tx)
+        .await?;
+
+    sqlx::query(
+        r#"
+        UPDATE post_review_flags
+        SET resolved_at = NOW(), resolved_by = $2
+        WHERE post_id = $1 AND resolved_at IS NULL
+        "#,
+    )
+    .bind(from)
+    .bind(member_id)
+    .execute(&mut **tx)
+    .await?;
+
+    let groups: Vec<&str> = keep.iter().map(|g| g.as_str()).collect();
+    sqlx::query(
+        r#"
+        INSERT INTO post_merges (
+            survivor_post_id, merged_post_id, merged_content_hash,
+            field_groups, summary, merged_by
+        )
+        VALUES ($1, $2, $3, $4, $5, $6)
+        "#,
+    )
+    .bind(to)
+    .bind(from)
+    .bind(content_hash)
+    .bind(&groups)
+    .bind(serde_json::to_value(summary).map_err(anyhow::Error::from)?)
+    .bind(member_id)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn field_groups_parse_from_the_field_groups_response_names() {
+        let names = serde_json::json!(["media", "source_attribution", "post_status"]);
+        let groups: Vec<FieldGroup> = serde_json::from_value(names).unwrap();
+        assert_eq!(
+            groups,
+            vec![FieldGroup::Media, FieldGroup::SourceAttribution, FieldGroup::PostStatus]
+        );
+        for group in groups {
+            assert_eq!(serde_json::to_value(group).unwrap(), group.as_str());
+        }
+        assert_eq!(FieldGroup::Media.media_reference_types(), ["post_hero"]);
+        assert!(FieldGroup::Meta.media_reference_types().is_empty());
+    }
+}
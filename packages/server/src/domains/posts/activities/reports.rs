@@ -7,7 +7,7 @@
 use anyhow::{Context, Result};
 use uuid::Uuid;
 
-use crate::common::auth::{Actor, AdminCapability};
+use crate::common::auth::{Actor, AdminCapability, CapabilityGrant};
 use crate::common::{MemberId, PostId};
 use crate::domains::posts::models::post_report::{PostReportId, PostReportRecord};
 use crate::kernel::ServerDeps;
@@ -53,6 +53,7 @@ pub async fn resolve_report(
     report_id: Uuid,
     member_id: Uuid,
     is_admin: bool,
+    grants: &[CapabilityGrant],
     resolution_notes: Option<String>,
     action_taken: String,
     deps: &ServerDeps,
@@ -61,7 +62,8 @@ pub async fn resolve_report(
     let resolved_by = MemberId::from_uuid(member_id);
 
     Actor::new(resolved_by, is_admin)
-        .can(AdminCapability::ManageNeeds)
+        .with_grants(grants)
+        .can(AdminCapability::ReviewPosts)
         .check(deps)
         .await
         .map_err(|auth_err| anyhow::anyhow!("Authorization denied: {}", auth_err))?;
@@ -84,6 +86,7 @@ pub async fn dismiss_report(
     report_id: Uuid,
     member_id: Uuid,
     is_admin: bool,
+    grants: &[CapabilityGrant],
     resolution_notes: Option<String>,
     deps: &ServerDeps,
 ) -> Result<()> {
@@ -91,7 +94,8 @@ pub async fn dismiss_report(
     let resolved_by = MemberId::from_uuid(member_id);
 
     Actor::new(resolved_by, is_admin)
-        .can(AdminCapability::ManageNeeds)
+        .with_grants(grants)
+        .can(AdminCapability::ReviewPosts)
         .check(deps)
         .await
         .map_err(|auth_err| anyhow::anyhow!("Authorization denied: {}", auth_err))?;
@@ -0,0 +1,74 @@
+//! Review flag actions - resolving the reasons a post sits in the Signal Inbox
+//!
+//! These are called from HTTP handlers.
+//! Resolving the last open blocking flag on an `in_review` post activates
+//! it, the same as approving it from the inbox. Non-blocking flags are
+//! informational and never hold a post back. Posts already in review when
+//! flags were introduced carry a blocking `legacy_review` flag (migration
+//! 255), since their original reasons weren't recorded.
+
+use anyhow::{Context, Result};
+use tracing::info;
+use uuid::Uuid;
+
+use crate::common::auth::{Actor, AdminCapability, CapabilityGrant};
+use crate::common::{MemberId, PostId, PostReviewFlagId};
+use crate::domains::posts::models::{Post, PostReviewFlag};
+use crate::kernel::ServerDeps;
+
+/// Resolve one review flag on a post (admin only). Returns None if the
+/// flag doesn't belong to the post or is already resolved.
+pub async fn resolve_review_flag(
+    post_id: Uuid,
+    flag_id: Uuid,
+    member_id: Uuid,
+    is_admin: bool,
+    grants: &[CapabilityGrant],
+    deps: &ServerDeps,
+) -> Result<Option<PostReviewFlag>> {
+    let post_id = PostId::from_uuid(post_id);
+    let flag_id = PostReviewFlagId::from_uuid(flag_id);
+    let resolved_by = MemberId::from_uuid(member_id);
+    let pool = &deps.db_pool;
+
+    Actor::new(resolved_by, is_admin)
+        .with_grants(grants)
+        .can(AdminCapability::ReviewPosts)
+        .check(deps)
+        .await
+        .map_err(|auth_err| anyhow::anyhow!("Authorization denied: {}", auth_err))?;
+
+    // Lock the post so concurrent resolutions on it run one at a time and
+    // the last one sees every other flag resolved.
+    let mut tx = pool.begin().await?;
+    let Some(status) = Post::lock_status(post_id, &mut *tx).await? else {
+        return Ok(None);
+    };
+    match PostReviewFlag::find_by_id(flag_id, &mut *tx).await? {
+        Some(flag) if flag.post_id == post_id => {}
+        _ => return Ok(None),
+    }
+    let Some(flag) = PostReviewFlag::resolve(flag_id, resolved_by, &mut *tx)
+        .await
+        .context("Failed to resolve review flag")?
+    else {
+        return Ok(None);
+    };
+
+    let activate = flag.blocking
+        && status == "in_review"
+        && PostReviewFlag::count_open_blocking(post_id, &mut *tx).await? == 0;
+    if activate {
+        Post::update_status(post_id, "active", &mut *tx)
+            .await
+            .context("Failed to update listing status")?;
+    }
+    tx.commit().await?;
+
+    info!(post_id = %post_id, flag = %flag.flag, "Resolved review flag");
+    if activate {
+        info!(post_id = %post_id, "Last blocking review flag resolved; post activated");
+    }
+
+    Ok(Some(flag))
+}
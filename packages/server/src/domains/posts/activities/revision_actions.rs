@@ -87,13 +87,14 @@ pub async fn reject_revision(revision_id: PostId, pool: &PgPool) -> Result<()> {
 /// Get all pending revisions, optionally filtered by source
 pub async fn get_pending_revisions(
     source: Option<(&str, Uuid)>,
+    county_ids: Option<&[Uuid]>,
     pool: &PgPool,
 ) -> Result<Vec<Post>> {
     match source {
         Some((source_type, source_id)) => {
-            Post::find_revisions_by_source(source_type, source_id, pool).await
+            Post::find_revisions_by_source(source_type, source_id, county_ids, pool).await
         }
-        None => Post::find_pending_revisions(pool).await,
+        None => Post::find_pending_revisions(county_ids, pool).await,
     }
 }
 
@@ -4,10 +4,11 @@
 //!
 //!   1. Archive the prior post (`status = 'archived'`). Its `revision_of_post_id`
 //!      chain is preserved so `/admin/posts/{id}` can render history.
-//!   2. Find every active edition the prior post was slotted into and run
-//!      `generate_edition` on each. The layout engine picks up the revised
-//!      post (which now sits where the old one used to) and fills the slot.
-//!      Editors see the updated layout next time they open the edition.
+//!   2. Enqueue a `reflow_editions` work item. The worker finds every active
+//!      edition the prior post was slotted into and runs `generate_edition`
+//!      on each. The layout engine picks up the revised post (which now sits
+//!      where the old one used to) and fills the slot. Editors see the
+//!      updated layout next time they open the edition.
 //!
 //! We don't attempt surgical slot-swap — full regeneration is the only
 //! guaranteed-correct path when the revised post has different weight /
@@ -15,36 +16,34 @@
 //! layout engine is fast enough that this is fine per-edition.
 
 use anyhow::Result;
-use sqlx::PgPool;
+use sqlx::PgExecutor;
 use uuid::Uuid;
 
 use crate::common::PostId;
+use crate::domains::editions::activities::edition_history::LayoutEdit;
 use crate::domains::editions::activities::edition_ops;
+use crate::domains::editions::data::types::LayoutOptions;
 use crate::domains::posts::models::Post;
 use crate::kernel::ServerDeps;
 
-pub struct ReflowResult {
-    pub archived_post_id: Uuid,
-    pub reflowed_edition_ids: Vec<Uuid>,
+/// Archive the prior post. Called by the ingest orchestrator *after* the
+/// new post row is inserted; the edition reflow that follows runs on the
+/// work queue (`WorkRequest::ReflowEditions`) so ingest returns promptly.
+pub async fn archive_prior(prior_post_id: Uuid, db: impl PgExecutor<'_>) -> Result<()> {
+    Post::update_status(PostId::from_uuid(prior_post_id), "archived", db).await?;
+    Ok(())
 }
 
-/// Archive the prior post and reflow every active edition that contained it.
-/// Called by the ingest orchestrator *after* the new post row is inserted,
-/// so the regenerated layout sees the revised post.
-pub async fn archive_and_reflow(
-    prior_post_id: Uuid,
-    deps: &ServerDeps,
-) -> Result<ReflowResult> {
-    let pool = &deps.db_pool;
-    let prior = PostId::from_uuid(prior_post_id);
-
-    // 1. Archive prior post.
-    Post::update_status(prior, "archived", pool).await?;
-
-    // 2. Find every non-published edition that slotted the prior post.
-    //    Published editions are frozen in place — revisions arriving after
-    //    publish are editorial-only concerns (reflow a published edition
-    //    would rewrite the paper of record).
+/// Regenerate every non-published edition that slotted `post_id`.
+/// Returns the reflowed edition ids.
+///
+/// Every edition is attempted even if an earlier one fails; the call then
+/// errors so the work queue retries. Regeneration is idempotent, so
+/// re-running editions that already succeeded is harmless.
+pub async fn reflow_editions_containing(post_id: Uuid, deps: &ServerDeps) -> Result<Vec<Uuid>> {
+    // Published editions are frozen in place — revisions arriving after
+    // publish are editorial-only concerns (reflow a published edition
+    // would rewrite the paper of record).
     let edition_ids: Vec<Uuid> = sqlx::query_scalar(
         r#"
         SELECT DISTINCT e.id
@@ -55,21 +54,20 @@ pub async fn archive_and_reflow(
           AND e.status IN ('draft', 'in_review', 'approved')
         "#,
     )
-    .bind(prior_post_id)
-    .fetch_all(pool)
+    .bind(post_id)
+    .fetch_all(&deps.db_pool)
     .await?;
 
     let mut reflowed = Vec::with_capacity(edition_ids.len());
+    let mut failed = 0usize;
     for eid in edition_ids {
-        match edition_ops::generate_edition(eid, deps).await {
+        match reflow_edition(eid, post_id, deps).await {
             Ok(_) => reflowed.push(eid),
             Err(err) => {
-                // Log and continue — a bad single edition shouldn't block the
-                // ingest from completing. The prior post is already archived
-                // and the new one is saved.
+                failed += 1;
                 tracing::warn!(
                     edition_id = %eid,
-                    prior_post_id = %prior_post_id,
+                    post_id = %post_id,
                     error = %err,
                     "revision reflow failed for edition; continuing"
                 );
@@ -77,8 +75,30 @@ pub async fn archive_and_reflow(
         }
     }
 
-    Ok(ReflowResult {
-        archived_post_id: prior_post_id,
-        reflowed_edition_ids: reflowed,
-    })
+    if failed > 0 {
+        anyhow::bail!(
+            "reflow failed for {} of {} editions containing post {}",
+            failed,
+            failed + reflowed.len(),
+            post_id
+        );
+    }
+    Ok(reflowed)
+}
+
+/// Regenerate one edition around its pins, and record the reflow in its
+/// history so an editor can undo it.
+async fn reflow_edition(edition_id: Uuid, post_id: Uuid, deps: &ServerDeps) -> Result<()> {
+    let mut edit = LayoutEdit::begin(edition_id, deps).await?;
+    edition_ops::generate_edition_with_options(
+        edition_id,
+        &LayoutOptions::default(),
+        true,
+        edit.conn(),
+        deps,
+    )
+    .await?;
+    edit.commit(None, "reflow", serde_json::json!({ "post_id": post_id }))
+        .await?;
+    Ok(())
 }
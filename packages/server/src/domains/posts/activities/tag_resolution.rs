@@ -15,7 +15,7 @@
 //! the post row itself lands.
 
 use anyhow::Result;
-use sqlx::PgPool;
+use sqlx::{PgConnection, PgPool};
 
 use crate::api::error::{ErrorCode, FieldError};
 use crate::common::TagId;
@@ -128,10 +128,10 @@ pub async fn resolve_tags(
 pub async fn apply_tags(
     post_id: PostId,
     resolution: &TagResolution,
-    pool: &PgPool,
+    conn: &mut PgConnection,
 ) -> Result<()> {
     for tag in &resolution.tags {
-        Taggable::create_post_tag(post_id, tag.id, pool).await?;
+        Taggable::create_post_tag(post_id, tag.id, &mut *conn).await?;
     }
     Ok(())
 }
@@ -4,12 +4,16 @@
 //! Actions are self-contained: they take raw input, handle ID parsing, and return results.
 //! Authorization is handled at the API layer.
 
+use std::collections::HashMap;
+
 use anyhow::{Context, Result};
 use sqlx::PgPool;
 use tracing::info;
 use uuid::Uuid;
 
 use crate::common::{PostId, TagId};
+use crate::domains::notes::models::note::Note;
+use crate::domains::posts::data::types::{PublicTagResult, UrgentNoteInfo};
 use crate::domains::posts::models::Post;
 use crate::domains::tag::{Tag, Taggable};
 
@@ -78,3 +82,42 @@ pub async fn remove_post_tag(post_id: Uuid, tag_id: String, pool: &PgPool) -> Re
 
     Ok(true)
 }
+
+/// Batch-load public tags and urgent notes for a set of posts, keyed by
+/// post id.
+pub async fn load_tags_and_notes(
+    post_ids: &[Uuid],
+    pool: &PgPool,
+) -> Result<(
+    HashMap<Uuid, Vec<PublicTagResult>>,
+    HashMap<Uuid, Vec<UrgentNoteInfo>>,
+)> {
+    let tag_rows = Tag::find_public_for_post_ids(post_ids, pool).await?;
+
+    let urgent_rows = Note::find_urgent_note_content_for_posts(post_ids, pool)
+        .await
+        .unwrap_or_default();
+
+    let mut tags_by_post: HashMap<Uuid, Vec<PublicTagResult>> = HashMap::new();
+    for row in tag_rows {
+        tags_by_post
+            .entry(row.taggable_id)
+            .or_default()
+            .push(PublicTagResult {
+                kind: row.tag.kind,
+                value: row.tag.value,
+                display_name: row.tag.display_name,
+                color: row.tag.color,
+            });
+    }
+
+    let mut urgent_notes_by_post: HashMap<Uuid, Vec<UrgentNoteInfo>> = HashMap::new();
+    for (post_id, content, cta_text) in urgent_rows {
+        urgent_notes_by_post
+            .entry(post_id)
+            .or_default()
+            .push(UrgentNoteInfo { content, cta_text });
+    }
+
+    Ok((tags_by_post, urgent_notes_by_post))
+}
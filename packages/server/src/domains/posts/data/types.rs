@@ -133,3 +133,17 @@ pub struct RepostResult {
     pub post: super::PostData,
     pub message: String,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UrgentNoteInfo {
+    pub content: String,
+    pub cta_text: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PublicTagResult {
+    pub kind: String,
+    pub value: String,
+    pub display_name: Option<String>,
+    pub color: Option<String>,
+}
@@ -6,10 +6,13 @@ pub mod post_item;
 pub mod post_link_record;
 pub mod post_location;
 pub mod post_media_record;
+pub mod post_merge;
 pub mod post_meta_record;
 pub mod post_person_record;
 pub mod post_report;
+pub mod post_review_flag;
 pub mod post_schedule_entry;
+pub mod post_similarity;
 pub mod post_source;
 pub mod post_source_attr;
 pub mod post_status_record;
@@ -23,9 +26,12 @@ pub use post_item::*;
 pub use post_link_record::*;
 pub use post_location::*;
 pub use post_media_record::*;
+pub use post_merge::*;
 pub use post_meta_record::*;
 pub use post_person_record::*;
+pub use post_review_flag::*;
 pub use post_schedule_entry::*;
+pub use post_similarity::*;
 pub use post_source::*;
 pub use post_source_attr::*;
 pub use post_status_record::*;
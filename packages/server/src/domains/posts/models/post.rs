@@ -2,12 +2,13 @@ use anyhow::Result;
 use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool};
 use typed_builder::TypedBuilder;
 use uuid::Uuid;
 
 use crate::common::utils::slugs::county_service_area_slug;
 use crate::common::{PaginationDirection, PostId, ValidatedPaginationArgs};
+use crate::domains::posts::models::PostReviewFlag;
 use crate::domains::schedules::models::Schedule;
 
 /// A post — community content in one of the 9 post types defined by
@@ -128,6 +129,59 @@ pub struct PostWithDistance {
     pub distance_miles: f64,
 }
 
+/// What a post's county is resolved from: its own coordinates, its primary
+/// location's coordinates, and the primary county of its ZIP (primary
+/// location's postal code, else the legacy `posts.zip_code`).
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct PostCountyInputs {
+    pub id: Uuid,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub location_latitude: Option<f64>,
+    pub location_longitude: Option<f64>,
+    pub zip_county_id: Option<Uuid>,
+}
+
+/// Map filters shared by `find_map_points` and `find_map_clusters`.
+/// The bounding box is in degrees; `tags` matches tag values of any kind.
+#[derive(Debug, Clone, Default)]
+pub struct MapFilters<'a> {
+    pub min_lat: f64,
+    pub max_lat: f64,
+    pub min_lng: f64,
+    pub max_lng: f64,
+    pub post_type: Option<&'a str>,
+    pub tags: Option<&'a [String]>,
+    pub county_id: Option<Uuid>,
+}
+
+/// One located post for the map. A post with several locations in view
+/// yields one point per location.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct MapPoint {
+    pub id: PostId,
+    pub title: String,
+    pub post_type: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub location_text: Option<String>,
+    pub postal_code: Option<String>,
+}
+
+/// Post count for one grid cell and post type. `cell_x`/`cell_y` are
+/// `floor(lng / cell)` and `floor(lat / cell)`; the coordinates are the mean
+/// of the posts in the cell, so markers sit over the posts rather than
+/// the cell corner.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct MapCluster {
+    pub cell_x: i64,
+    pub cell_y: i64,
+    pub post_type: String,
+    pub post_count: i64,
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
 /// PostWithDistance plus total_count from COUNT(*) OVER() window function
 #[derive(Debug, Clone, sqlx::FromRow)]
 pub struct PostWithDistanceAndCount {
@@ -385,6 +439,9 @@ pub struct PostFilters<'a> {
     pub exclude_submission_type: Option<&'a str>,
     pub county_id: Option<Uuid>,
     pub statewide_only: bool,
+    /// Only posts resolved to one of these counties (`posts.county_id`) —
+    /// a county-scoped editor's grants. `None` is no restriction.
+    pub county_ids: Option<&'a [Uuid]>,
 }
 
 // =============================================================================
@@ -392,6 +449,11 @@ pub struct PostFilters<'a> {
 // =============================================================================
 
 impl Post {
+    /// SQL predicate: filters out embargoed posts — an `active` post whose
+    /// `published_at` is still in the future stays hidden until then.
+    const EMBARGO_FILTER: &'static str =
+        "AND (p.published_at IS NULL OR p.published_at <= NOW())";
+
     /// SQL predicate: filters out posts with all-expired schedules.
     /// Posts without schedules (evergreen) always pass.
     /// Posts with at least one active schedule pass.
@@ -416,6 +478,11 @@ impl Post {
         )
     "#;
 
+    /// True while an `active` post's `published_at` is still in the future.
+    pub fn is_embargoed(&self, now: DateTime<Utc>) -> bool {
+        self.published_at.is_some_and(|at| at > now)
+    }
+
     /// Batch-load posts by IDs (for DataLoader)
     pub async fn find_by_ids(ids: &[Uuid], pool: &PgPool) -> Result<Vec<Self>> {
         sqlx::query_as::<_, Self>("SELECT * FROM posts WHERE id = ANY($1) AND deleted_at IS NULL")
@@ -611,6 +678,7 @@ impl Post {
                       ))
                       AND ($10::bool IS NOT TRUE OR p.zip_code IS NULL)
                       AND ($11::text IS NULL OR p.submission_type IS DISTINCT FROM $11)
+                      AND ($12::uuid[] IS NULL OR p.county_id = ANY($12))
                     ORDER BY p.id ASC
                     LIMIT $3
                     "#,
@@ -626,6 +694,7 @@ impl Post {
                 .bind(filters.county_id)
                 .bind(filters.statewide_only)
                 .bind(filters.exclude_submission_type)
+                .bind(filters.county_ids)
                 .fetch_all(pool)
                 .await?
             }
@@ -654,6 +723,7 @@ impl Post {
                       ))
                       AND ($10::bool IS NOT TRUE OR p.zip_code IS NULL)
                       AND ($11::text IS NULL OR p.submission_type IS DISTINCT FROM $11)
+                      AND ($12::uuid[] IS NULL OR p.county_id = ANY($12))
                     ORDER BY p.id DESC
                     LIMIT $3
                     "#,
@@ -669,6 +739,7 @@ impl Post {
                 .bind(filters.county_id)
                 .bind(filters.statewide_only)
                 .bind(filters.exclude_submission_type)
+                .bind(filters.county_ids)
                 .fetch_all(pool)
                 .await?;
 
@@ -722,6 +793,7 @@ impl Post {
     /// Used by admin views.
     pub async fn find_all_by_organization_id(
         organization_id: Uuid,
+        county_ids: Option<&[Uuid]>,
         pool: &PgPool,
     ) -> Result<Vec<Self>> {
         sqlx::query_as::<_, Post>(
@@ -740,17 +812,19 @@ impl Post {
                   AND sp.status = 'pending'
                   AND sb.status IN ('pending', 'partially_reviewed')
               )
+              AND ($2::uuid[] IS NULL OR p.county_id = ANY($2))
             ORDER BY p.created_at DESC
             "#,
         )
         .bind(organization_id)
+        .bind(county_ids)
         .fetch_all(pool)
         .await
         .map_err(Into::into)
     }
 
     /// Create a new post (returns inserted record with defaults applied)
-    pub async fn create(input: CreatePost, pool: &PgPool) -> Result<Self> {
+    pub async fn create(input: CreatePost, db: impl PgExecutor<'_>) -> Result<Self> {
         let post = sqlx::query_as::<_, Post>(
             r#"
             INSERT INTO posts (
@@ -786,14 +860,14 @@ impl Post {
         .bind(input.revision_of_post_id)
         .bind(input.translation_of_id)
         .bind(input.published_at)
-        .fetch_one(pool)
+        .fetch_one(db)
         .await?;
 
         Ok(post)
     }
 
     /// Update listing status
-    pub async fn update_status(id: PostId, status: &str, pool: &PgPool) -> Result<Self> {
+    pub async fn update_status(id: PostId, status: &str, db: impl PgExecutor<'_>) -> Result<Self> {
         let post = sqlx::query_as::<_, Post>(
             r#"
             UPDATE posts
@@ -804,11 +878,44 @@ impl Post {
         )
         .bind(status)
         .bind(id)
-        .fetch_one(pool)
+        .fetch_one(db)
         .await?;
         Ok(post)
     }
 
+    /// Lock a post's row for the rest of the transaction and return its
+    /// status. None if it doesn't exist.
+    pub async fn lock_status(id: PostId, db: impl PgExecutor<'_>) -> Result<Option<String>> {
+        sqlx::query_scalar("SELECT status FROM posts WHERE id = $1 FOR UPDATE")
+            .bind(id)
+            .fetch_optional(db)
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Fill in coordinates on a post that has none (geocoded locations).
+    /// Coordinates already set by the source are left alone.
+    pub async fn set_coordinates_if_missing(
+        id: PostId,
+        latitude: f64,
+        longitude: f64,
+        db: impl PgExecutor<'_>,
+    ) -> Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE posts
+            SET latitude = $2, longitude = $3
+            WHERE id = $1 AND latitude IS NULL AND longitude IS NULL
+            "#,
+        )
+        .bind(id)
+        .bind(Decimal::try_from(latitude).ok())
+        .bind(Decimal::try_from(longitude).ok())
+        .execute(db)
+        .await?;
+        Ok(())
+    }
+
     /// Update post content (for edit + approve)
     pub async fn update_content(input: UpdatePostContent, pool: &PgPool) -> Result<Self> {
         let post = sqlx::query_as::<_, Post>(
@@ -894,6 +1001,7 @@ impl Post {
               ))
               AND ($5::text IS NULL OR p.post_type = $5)
               AND ($6::text IS NULL OR p.submission_type = $6)
+              AND ($7::uuid[] IS NULL OR p.county_id = ANY($7))
             "#,
         )
         .bind(filters.status)
@@ -902,6 +1010,7 @@ impl Post {
         .bind(search_term)
         .bind(filters.post_type)
         .bind(filters.submission_type)
+        .bind(filters.county_ids)
         .fetch_one(pool)
         .await?;
         Ok(count)
@@ -913,10 +1022,13 @@ impl Post {
     /// relative to the edition. Mirrors `Widget::find_for_edition`.
     ///
     /// Eligibility mirrors `layout_engine::load_county_posts`:
-    ///   - Location (locationable or posts.zip_code) maps to this county, OR
+    ///   - Resolved `posts.county_id` is this county, OR
+    ///   - Not resolved yet and its location (locationable or posts.zip_code)
+    ///     maps to this county, OR
     ///   - `service_area` tag matches this county's slug, OR
     ///   - Explicitly tagged `statewide`, OR
-    ///   - No location data AND no `service_area` tag (truly statewide content).
+    ///   - No location data, no resolved county AND no `service_area` tag
+    ///     (truly statewide content).
     ///
     /// Posts tagged with a `service_area` for a different county are excluded —
     /// they shouldn't leak via the statewide fallback.
@@ -971,9 +1083,10 @@ impl Post {
               AND p.deleted_at IS NULL
               AND p.revision_of_post_id IS NULL
               AND p.translation_of_id IS NULL
+              AND (p.published_at IS NULL OR p.published_at <= NOW())
               AND (
-                zc_loc.county_id = $1
-                OR zc_p.county_id = $1
+                p.county_id = $1
+                OR (p.county_id IS NULL AND (zc_loc.county_id = $1 OR zc_p.county_id = $1))
                 OR EXISTS (
                   SELECT 1 FROM taggables t
                   JOIN tags tg ON t.tag_id = tg.id
@@ -992,6 +1105,7 @@ impl Post {
                 OR (
                   la.id IS NULL
                   AND p.zip_code IS NULL
+                  AND p.county_id IS NULL
                   AND NOT EXISTS (
                     SELECT 1 FROM taggables t
                     JOIN tags tg ON t.tag_id = tg.id
@@ -1026,6 +1140,7 @@ impl Post {
     /// Returns (post_type, submission_type, count) tuples.
     pub async fn stats_by_status(
         status: Option<&str>,
+        county_ids: Option<&[Uuid]>,
         pool: &PgPool,
     ) -> Result<Vec<(Option<String>, Option<String>, i64)>> {
         sqlx::query_as::<_, (Option<String>, Option<String>, i64)>(
@@ -1036,10 +1151,12 @@ impl Post {
               AND p.deleted_at IS NULL
               AND p.revision_of_post_id IS NULL
               AND p.translation_of_id IS NULL
+              AND ($2::uuid[] IS NULL OR p.county_id = ANY($2))
             GROUP BY p.post_type, p.submission_type
             "#,
         )
         .bind(status)
+        .bind(county_ids)
         .fetch_all(pool)
         .await
         .map_err(Into::into)
@@ -1079,16 +1196,24 @@ impl Post {
     // =========================================================================
 
     /// Find all pending revisions (posts that are revisions of other posts)
-    pub async fn find_pending_revisions(pool: &PgPool) -> Result<Vec<Self>> {
+    pub async fn find_pending_revisions(
+        county_ids: Option<&[Uuid]>,
+        pool: &PgPool,
+    ) -> Result<Vec<Self>> {
         let revisions = sqlx::query_as::<_, Post>(
             r#"
-            SELECT * FROM posts
-            WHERE revision_of_post_id IS NOT NULL
-              AND deleted_at IS NULL
-              AND status = 'pending_approval'
-            ORDER BY created_at DESC
+            SELECT p.* FROM posts p
+            WHERE p.revision_of_post_id IS NOT NULL
+              AND p.deleted_at IS NULL
+              AND p.status = 'pending_approval'
+              AND ($1::uuid[] IS NULL OR EXISTS (
+                  SELECT 1 FROM posts o
+                  WHERE o.id = p.revision_of_post_id AND o.county_id = ANY($1)
+              ))
+            ORDER BY p.created_at DESC
             "#,
         )
+        .bind(county_ids)
         .fetch_all(pool)
         .await?;
         Ok(revisions)
@@ -1136,6 +1261,203 @@ impl Post {
             .map_err(Into::into)
     }
 
+    const COUNTY_INPUTS_SELECT: &'static str = r#"
+        SELECT p.id,
+               p.latitude::float8 AS latitude,
+               p.longitude::float8 AS longitude,
+               loc.latitude AS location_latitude,
+               loc.longitude AS location_longitude,
+               (
+                   SELECT zc.county_id FROM zip_counties zc
+                   WHERE zc.zip_code = COALESCE(loc.postal_code, p.zip_code)
+                   ORDER BY zc.is_primary DESC, zc.county_id
+                   LIMIT 1
+               ) AS zip_county_id
+        FROM posts p
+        LEFT JOIN locationables la
+            ON la.locatable_id = p.id
+            AND la.locatable_type = 'post'
+            AND la.is_primary = true
+        LEFT JOIN locations loc ON loc.id = la.location_id
+    "#;
+
+    /// County resolution inputs for one post.
+    pub async fn find_county_inputs(
+        id: PostId,
+        db: impl PgExecutor<'_>,
+    ) -> Result<Option<PostCountyInputs>> {
+        let sql = format!("{} WHERE p.id = $1 LIMIT 1", Self::COUNTY_INPUTS_SELECT);
+        sqlx::query_as::<_, PostCountyInputs>(&sql)
+            .bind(id)
+            .fetch_optional(db)
+            .await
+            .map_err(Into::into)
+    }
+
+    /// County resolution inputs for non-deleted posts, keyset-paginated by
+    /// id (pass the last id seen as `after`).
+    pub async fn find_county_inputs_page(
+        after: Option<Uuid>,
+        limit: i64,
+        pool: &PgPool,
+    ) -> Result<Vec<PostCountyInputs>> {
+        let sql = format!(
+            r#"
+            SELECT DISTINCT ON (id) * FROM ({}
+                WHERE p.deleted_at IS NULL
+                  AND ($1::uuid IS NULL OR p.id > $1)
+            ) inputs
+            ORDER BY id
+            LIMIT $2
+            "#,
+            Self::COUNTY_INPUTS_SELECT
+        );
+        sqlx::query_as::<_, PostCountyInputs>(&sql)
+            .bind(after)
+            .bind(limit)
+            .fetch_all(pool)
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Store a post's resolved county (`via` is `coordinates` or `zip`), or
+    /// clear it when nothing resolves.
+    pub async fn set_county(
+        id: PostId,
+        county_id: Option<Uuid>,
+        via: Option<&str>,
+        db: impl PgExecutor<'_>,
+    ) -> Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE posts
+            SET county_id = $2, county_resolved_via = $3, county_resolved_at = NOW()
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .bind(county_id)
+        .bind(via)
+        .execute(db)
+        .await?;
+        Ok(())
+    }
+
+    /// Located live posts inside a map bounding box. Binds `$1..$7` from
+    /// `MapFilters`; callers append their own SELECT over `located`.
+    /// Coordinates prefer the geocoded location and fall back to the zip
+    /// centroid.
+    fn map_located_cte() -> String {
+        format!(
+            r#"
+            WITH located AS (
+                SELECT DISTINCT ON (p.id, l.id)
+                       p.id, p.title, p.post_type, p.location AS location_text,
+                       l.postal_code,
+                       COALESCE(l.latitude, z.latitude) AS latitude,
+                       COALESCE(l.longitude, z.longitude) AS longitude
+                FROM posts p
+                INNER JOIN post_locations pl ON pl.post_id = p.id
+                INNER JOIN locations l ON l.id = pl.location_id
+                LEFT JOIN zip_codes z ON l.postal_code = z.zip_code
+                WHERE p.status = 'active'
+                  AND p.deleted_at IS NULL
+                  AND p.revision_of_post_id IS NULL
+                  AND p.translation_of_id IS NULL
+                  AND COALESCE(l.latitude, z.latitude) BETWEEN $1 AND $2
+                  AND COALESCE(l.longitude, z.longitude) BETWEEN $3 AND $4
+                  AND ($5::text IS NULL OR p.post_type = $5)
+                  AND ($6::text[] IS NULL OR EXISTS (
+                      SELECT 1 FROM taggables tg
+                      JOIN tags t ON t.id = tg.tag_id
+                      WHERE tg.taggable_type = 'post' AND tg.taggable_id = p.id
+                        AND t.value = ANY($6)
+                  ))
+                  AND ($7::uuid IS NULL OR p.county_id = $7 OR (
+                      p.county_id IS NULL AND EXISTS (
+                          SELECT 1 FROM zip_counties zc
+                          WHERE zc.zip_code = l.postal_code AND zc.county_id = $7
+                      )
+                  ))
+                  {}
+                  {}
+            )
+            "#,
+            Self::SCHEDULE_ACTIVE_FILTER,
+            Self::EMBARGO_FILTER
+        )
+    }
+
+    /// Individual map points inside the bounding box, newest posts first,
+    /// capped at `limit`.
+    pub async fn find_map_points(
+        filters: &MapFilters<'_>,
+        limit: i64,
+        pool: &PgPool,
+    ) -> Result<Vec<MapPoint>> {
+        let sql = format!(
+            r#"
+            {}
+            SELECT located.id, located.title, located.post_type,
+                   located.latitude, located.longitude,
+                   located.location_text, located.postal_code
+            FROM located
+            JOIN posts p ON p.id = located.id
+            ORDER BY COALESCE(p.published_at, p.created_at) DESC, located.id
+            LIMIT $8
+            "#,
+            Self::map_located_cte()
+        );
+        sqlx::query_as::<_, MapPoint>(&sql)
+            .bind(filters.min_lat)
+            .bind(filters.max_lat)
+            .bind(filters.min_lng)
+            .bind(filters.max_lng)
+            .bind(filters.post_type)
+            .bind(filters.tags)
+            .bind(filters.county_id)
+            .bind(limit)
+            .fetch_all(pool)
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Post counts per grid cell (of `cell_degrees` on a side) and post
+    /// type inside the bounding box. A post counts once per cell it has a
+    /// location in.
+    pub async fn find_map_clusters(
+        filters: &MapFilters<'_>,
+        cell_degrees: f64,
+        pool: &PgPool,
+    ) -> Result<Vec<MapCluster>> {
+        let sql = format!(
+            r#"
+            {}
+            SELECT floor(longitude / $8)::bigint AS cell_x,
+                   floor(latitude / $8)::bigint AS cell_y,
+                   post_type,
+                   COUNT(DISTINCT id) AS post_count,
+                   AVG(latitude) AS latitude,
+                   AVG(longitude) AS longitude
+            FROM located
+            GROUP BY 1, 2, post_type
+            "#,
+            Self::map_located_cte()
+        );
+        sqlx::query_as::<_, MapCluster>(&sql)
+            .bind(filters.min_lat)
+            .bind(filters.max_lat)
+            .bind(filters.min_lng)
+            .bind(filters.max_lng)
+            .bind(filters.post_type)
+            .bind(filters.tags)
+            .bind(filters.county_id)
+            .bind(cell_degrees)
+            .fetch_all(pool)
+            .await
+            .map_err(Into::into)
+    }
+
     /// Find posts near a zip code with composable filters and offset pagination.
     /// Uses GROUP BY + MIN(haversine) for multi-location dedup, bounding box pre-filter,
     /// and COUNT(*) OVER() for total count in a single query.
@@ -1182,6 +1504,7 @@ impl Post {
                   SELECT 1 FROM zip_counties zc
                   WHERE zc.zip_code = p.zip_code AND zc.county_id = $11
               ))
+              AND ($12::uuid[] IS NULL OR p.county_id = ANY($12))
               AND z.latitude BETWEEN c.latitude - ($6::float8 / 69.0)
                                  AND c.latitude + ($6::float8 / 69.0)
               AND z.longitude BETWEEN c.longitude - ($6::float8 / (69.0 * cos(radians(c.latitude))))
@@ -1206,6 +1529,7 @@ impl Post {
         .bind(filters.post_type)      // $9
         .bind(filters.submission_type) // $10
         .bind(filters.county_id)      // $11
+        .bind(filters.county_ids)     // $12
         .fetch_all(pool)
         .await?;
 
@@ -1239,6 +1563,7 @@ impl Post {
     pub async fn find_revisions_by_source(
         source_type: &str,
         source_id: Uuid,
+        county_ids: Option<&[Uuid]>,
         pool: &PgPool,
     ) -> Result<Vec<Self>> {
         sqlx::query_as::<_, Post>(
@@ -1249,11 +1574,16 @@ impl Post {
               AND ps.source_type = $1 AND ps.source_id = $2
               AND p.deleted_at IS NULL
               AND p.status = 'pending_approval'
+              AND ($3::uuid[] IS NULL OR EXISTS (
+                  SELECT 1 FROM posts o
+                  WHERE o.id = p.revision_of_post_id AND o.county_id = ANY($3)
+              ))
             ORDER BY p.created_at DESC
             "#,
         )
         .bind(source_type)
         .bind(source_id)
+        .bind(county_ids)
         .fetch_all(pool)
         .await
         .map_err(Into::into)
@@ -1280,6 +1610,85 @@ impl Post {
         .map_err(Into::into)
     }
 
+    /// Active, public event posts in a county's service area that have at
+    /// least one dated schedule — the county calendar feed. Eligibility
+    /// mirrors `find_for_edition` (resolved county, ZIP while unresolved,
+    /// `service_area` tag, `statewide` tag, or no location at all), without
+    /// the edition window.
+    pub async fn find_events_for_county(county_id: Uuid, pool: &PgPool) -> Result<Vec<Self>> {
+        let county_name: String =
+            sqlx::query_scalar("SELECT name FROM counties WHERE id = $1")
+                .bind(county_id)
+                .fetch_one(pool)
+                .await?;
+        let service_area = county_service_area_slug(&county_name);
+
+        let sql = format!(
+            r#"
+            SELECT DISTINCT p.* FROM posts p
+            LEFT JOIN locationables la
+                ON la.locatable_id = p.id
+                AND la.locatable_type = 'post'
+                AND la.is_primary = true
+            LEFT JOIN locations loc ON loc.id = la.location_id
+            LEFT JOIN zip_counties zc_loc ON loc.postal_code = zc_loc.zip_code
+            LEFT JOIN zip_counties zc_p ON p.zip_code = zc_p.zip_code
+            WHERE p.status = 'active'
+              AND p.post_type = 'event'
+              AND p.deleted_at IS NULL
+              AND p.revision_of_post_id IS NULL
+              AND p.translation_of_id IS NULL
+              AND EXISTS (
+                SELECT 1 FROM schedules s
+                WHERE s.schedulable_type = 'post'
+                  AND s.schedulable_id = p.id
+                  AND s.dtstart IS NOT NULL
+              )
+              AND (
+                p.county_id = $1
+                OR (p.county_id IS NULL AND (zc_loc.county_id = $1 OR zc_p.county_id = $1))
+                OR EXISTS (
+                  SELECT 1 FROM taggables t
+                  JOIN tags tg ON t.tag_id = tg.id
+                  WHERE t.taggable_type = 'post'
+                    AND t.taggable_id = p.id
+                    AND tg.kind = 'service_area'
+                    AND tg.value = $2
+                )
+                OR EXISTS (
+                  SELECT 1 FROM taggables t
+                  JOIN tags tg ON t.tag_id = tg.id
+                  WHERE t.taggable_type = 'post'
+                    AND t.taggable_id = p.id
+                    AND tg.value = 'statewide'
+                )
+                OR (
+                  la.id IS NULL
+                  AND p.zip_code IS NULL
+                  AND p.county_id IS NULL
+                  AND NOT EXISTS (
+                    SELECT 1 FROM taggables t
+                    JOIN tags tg ON t.tag_id = tg.id
+                    WHERE t.taggable_type = 'post'
+                      AND t.taggable_id = p.id
+                      AND tg.kind = 'service_area'
+                  )
+                )
+              )
+              {}
+            ORDER BY p.created_at ASC, p.id ASC
+            "#,
+            Self::EMBARGO_FILTER
+        );
+
+        sqlx::query_as::<_, Self>(&sql)
+            .bind(county_id)
+            .bind(&service_area)
+            .fetch_all(pool)
+            .await
+            .map_err(Into::into)
+    }
+
     // =========================================================================
     // Public Filtered Queries (for home page directory)
     // =========================================================================
@@ -1308,10 +1717,12 @@ impl Post {
               AND ($1::text IS NULL OR p.post_type = $1)
               AND ($2::text IS NULL OR t_cat.value = $2)
               {}
+              {}
             ORDER BY p.created_at DESC
             LIMIT $3 OFFSET $4
             "#,
-            Self::SCHEDULE_ACTIVE_FILTER
+            Self::SCHEDULE_ACTIVE_FILTER,
+            Self::EMBARGO_FILTER
         );
         sqlx::query_as::<_, Self>(&sql)
             .bind(post_type)
@@ -1342,8 +1753,10 @@ impl Post {
               AND ($1::text IS NULL OR p.post_type = $1)
               AND ($2::text IS NULL OR t_cat.value = $2)
               {}
+              {}
             "#,
-            Self::SCHEDULE_ACTIVE_FILTER
+            Self::SCHEDULE_ACTIVE_FILTER,
+            Self::EMBARGO_FILTER
         );
         sqlx::query_scalar::<_, i64>(&sql)
             .bind(post_type)
@@ -1391,9 +1804,11 @@ impl Post {
               AND ($3::text IS NULL OR p.post_type = $3)
               AND ($4::text IS NULL OR t_cat.value = $4)
               {}
+              {}
             ORDER BY p.id, distance_miles ASC
             "#,
-            Self::SCHEDULE_ACTIVE_FILTER
+            Self::SCHEDULE_ACTIVE_FILTER,
+            Self::EMBARGO_FILTER
         );
         // Wrap in a subquery to sort by distance and apply limit/offset
         let wrapped = format!(
@@ -1440,8 +1855,10 @@ impl Post {
               AND ($3::text IS NULL OR p.post_type = $3)
               AND ($4::text IS NULL OR t_cat.value = $4)
               {}
+              {}
             "#,
-            Self::SCHEDULE_ACTIVE_FILTER
+            Self::SCHEDULE_ACTIVE_FILTER,
+            Self::EMBARGO_FILTER
         );
         sqlx::query_scalar::<_, i64>(&sql)
             .bind(zip_code)
@@ -1509,6 +1926,126 @@ impl Post {
         Ok(rows.into_iter().map(|(post_id, org_id, org_name)| (post_id, (org_id, org_name))).collect())
     }
 
+    /// Find the organization a post is sourced from (via post_sources → sources → organizations).
+    /// Returns None if the post has no linked organization.
+    pub async fn find_organization_id(id: PostId, pool: &PgPool) -> Result<Option<Uuid>> {
+        sqlx::query_scalar::<_, Uuid>(
+            r#"
+            SELECT s.organization_id
+            FROM post_sources ps
+            JOIN sources s ON ps.source_id = s.id
+            WHERE ps.post_id = $1 AND s.organization_id IS NOT NULL
+            ORDER BY ps.is_primary DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Counties a post belongs to: via the zip code of its primary location
+    /// or its own `zip_code`, and via `service_area` tags naming a county.
+    /// Statewide posts, and posts with no county signal, return an empty list.
+    pub async fn find_county_ids(id: PostId, pool: &PgPool) -> Result<Vec<Uuid>> {
+        let mut by_post = Self::find_county_ids_for_posts(&[id.into_uuid()], pool).await?;
+        Ok(by_post.remove(&id.into_uuid()).unwrap_or_default())
+    }
+
+    /// [`Post::find_county_ids`] for many posts in one query, keyed by post
+    /// id. Posts with no counties are left out of the map.
+    pub async fn find_county_ids_for_posts(
+        ids: &[Uuid],
+        pool: &PgPool,
+    ) -> Result<std::collections::HashMap<Uuid, Vec<Uuid>>> {
+        if ids.is_empty() {
+            return Ok(Default::default());
+        }
+        let rows: Vec<(Uuid, Uuid)> = sqlx::query_as(
+            r#"
+            SELECT post_id, county_id FROM (
+                SELECT p.id AS post_id, zc.county_id
+                FROM posts p
+                LEFT JOIN locationables la
+                    ON la.locatable_id = p.id
+                    AND la.locatable_type = 'post'
+                    AND la.is_primary = true
+                LEFT JOIN locations loc ON loc.id = la.location_id
+                JOIN zip_counties zc
+                    ON zc.zip_code = loc.postal_code OR zc.zip_code = p.zip_code
+                WHERE p.id = ANY($1)
+                UNION
+                SELECT t.taggable_id, c.id
+                FROM taggables t
+                JOIN tags tg ON t.tag_id = tg.id
+                JOIN counties c
+                    ON regexp_replace(
+                           regexp_replace(lower(trim(c.name)), '\s+', '-', 'g'),
+                           '[.,]', '', 'g'
+                       ) || '-county' = tg.value
+                WHERE t.taggable_type = 'post'
+                  AND t.taggable_id = ANY($1)
+                  AND tg.kind = 'service_area'
+            ) ids
+            WHERE NOT EXISTS (
+                SELECT 1
+                FROM taggables t
+                JOIN tags tg ON t.tag_id = tg.id
+                WHERE t.taggable_type = 'post'
+                  AND t.taggable_id = ids.post_id
+                  AND tg.value = 'statewide'
+            )
+            ORDER BY post_id, county_id
+            "#,
+        )
+        .bind(ids)
+        .fetch_all(pool)
+        .await?;
+
+        let mut by_post: std::collections::HashMap<Uuid, Vec<Uuid>> =
+            std::collections::HashMap::new();
+        for (post_id, county_id) in rows {
+            by_post.entry(post_id).or_default().push(county_id);
+        }
+        Ok(by_post)
+    }
+
+    /// Counties named by a not-yet-stored post's signals: the counties of
+    /// its ZIP codes and of its `service_area` tag slugs. Same rules as
+    /// [`Post::find_county_ids`]; a `statewide` tag returns an empty list.
+    pub async fn find_county_ids_for_signals(
+        zip_codes: &[String],
+        service_area_slugs: &[String],
+        pool: &PgPool,
+    ) -> Result<Vec<Uuid>> {
+        if service_area_slugs.iter().any(|s| s == "statewide") {
+            return Ok(Vec::new());
+        }
+        sqlx::query_scalar(
+            r#"
+            SELECT county_id FROM (
+                SELECT zc.county_id
+                FROM zip_counties zc
+                WHERE zc.zip_code = ANY($1)
+                UNION
+                SELECT c.id
+                FROM counties c
+                WHERE regexp_replace(
+                        regexp_replace(lower(trim(c.name)), '\s+', '-', 'g'),
+                        '[.,]', '', 'g'
+                      ) || '-county' = ANY($2)
+            ) ids
+            ORDER BY county_id
+            "#,
+        )
+        .bind(zip_codes)
+        .bind(service_area_slugs)
+        .fetch_all(pool)
+        .await
+        .map_err(Into::into)
+    }
+
     /// Find organization name for a post (via post_sources → sources → organizations).
     /// Returns None if the post has no linked organization.
     pub async fn find_org_name(id: PostId, pool: &PgPool) -> Result<Option<String>> {
@@ -1529,52 +2066,63 @@ impl Post {
         .map_err(Into::into)
     }
 
-    /// Signal Inbox query — fetch posts with `status = 'in_review'` and
-    /// derive per-post `review_flags` at query time.
-    ///
-    /// Flags that can be derived from current schema:
-    ///   - `low_confidence`: `extraction_confidence < 60`
-    ///   - `possible_duplicate`: `duplicate_of_id IS NOT NULL`
-    ///   - `deck_missing_on_heavy`: `weight = 'heavy'` AND no `post_meta.deck`
+    /// Signal Inbox query — fetch posts with `status = 'in_review'` and their
+    /// open `post_review_flags`, optionally only posts with an open `flag`.
     ///
-    /// Other soft-fail conditions from the handoff spec (§11.2) —
-    /// `source_stale`, `individual_no_consent` — depend on schema additions
-    /// that ingest (Worktree 3) owns (source_individuals table,
-    /// source-metadata-snapshot comparison). Posts carrying those flags
-    /// will appear here once ingest writes them.
+    /// Flags are written by ingest (one row per soft-fail reason) and by
+    /// the calendar importer (`ical_import`). Revisions are hidden, except
+    /// calendar-import revisions: a changed event arrives as a revision and
+    /// needs the same review as a new one.
     ///
-    /// Returns a flat list of `(Post, Vec<String>)` ordered by `created_at DESC`
-    /// and the total in_review count (for the list header).
+    /// With `county_ids`, only posts resolved to one of those counties.
+    /// Returns posts ordered by `created_at DESC` with their open flags,
+    /// and the total matching count (for the list header).
     pub async fn find_in_review_with_flags(
+        flag: Option<&str>,
+        county_ids: Option<&[Uuid]>,
         limit: i64,
         offset: i64,
         pool: &PgPool,
-    ) -> Result<(Vec<(Self, Vec<String>)>, i64)> {
+    ) -> Result<(Vec<(Self, Vec<PostReviewFlag>)>, i64)> {
         let total: i64 = sqlx::query_scalar(
             r#"
-            SELECT COUNT(*) FROM posts
-            WHERE status = 'in_review'
-              AND deleted_at IS NULL
-              AND revision_of_post_id IS NULL
-              AND translation_of_id IS NULL
+            SELECT COUNT(*) FROM posts p
+            WHERE p.status = 'in_review'
+              AND p.deleted_at IS NULL
+              AND (p.revision_of_post_id IS NULL OR p.submission_type = 'ical_import')
+              AND p.translation_of_id IS NULL
+              AND ($1::text IS NULL OR EXISTS (
+                  SELECT 1 FROM post_review_flags f
+                  WHERE f.post_id = p.id AND f.flag = $1 AND f.resolved_at IS NULL
+              ))
+              AND ($2::uuid[] IS NULL OR p.county_id = ANY($2))
             "#,
         )
+        .bind(flag)
+        .bind(county_ids)
         .fetch_one(pool)
         .await?;
 
         let posts = sqlx::query_as::<_, Self>(
             r#"
-            SELECT * FROM posts
-            WHERE status = 'in_review'
-              AND deleted_at IS NULL
-              AND revision_of_post_id IS NULL
-              AND translation_of_id IS NULL
-            ORDER BY created_at DESC
-            LIMIT $1 OFFSET $2
+            SELECT p.* FROM posts p
+            WHERE p.status = 'in_review'
+              AND p.deleted_at IS NULL
+              AND (p.revision_of_post_id IS NULL OR p.submission_type = 'ical_import')
+              AND p.translation_of_id IS NULL
+              AND ($1::text IS NULL OR EXISTS (
+                  SELECT 1 FROM post_review_flags f
+                  WHERE f.post_id = p.id AND f.flag = $1 AND f.resolved_at IS NULL
+              ))
+              AND ($4::uuid[] IS NULL OR p.county_id = ANY($4))
+            ORDER BY p.created_at DESC
+            LIMIT $2 OFFSET $3
             "#,
         )
+        .bind(flag)
         .bind(limit)
         .bind(offset)
+        .bind(county_ids)
         .fetch_all(pool)
         .await?;
 
@@ -1583,34 +2131,16 @@ impl Post {
         }
 
         let ids: Vec<Uuid> = posts.iter().map(|p| p.id.into_uuid()).collect();
-        let deck_rows: Vec<(Uuid, Option<String>)> = sqlx::query_as(
-            r#"SELECT post_id, deck FROM post_meta WHERE post_id = ANY($1)"#,
-        )
-        .bind(&ids)
-        .fetch_all(pool)
-        .await?;
-        let decks: std::collections::HashMap<Uuid, Option<String>> =
-            deck_rows.into_iter().collect();
+        let mut flags_by_post: std::collections::HashMap<Uuid, Vec<PostReviewFlag>> =
+            std::collections::HashMap::new();
+        for f in PostReviewFlag::find_open_for_posts(&ids, pool).await? {
+            flags_by_post.entry(f.post_id.into_uuid()).or_default().push(f);
+        }
 
         let with_flags = posts
             .into_iter()
             .map(|p| {
-                let mut flags: Vec<String> = Vec::new();
-                if p.extraction_confidence.map_or(false, |c| c < 60) {
-                    flags.push("low_confidence".to_string());
-                }
-                if p.duplicate_of_id.is_some() {
-                    flags.push("possible_duplicate".to_string());
-                }
-                if p.weight == "heavy" {
-                    let has_deck = decks
-                        .get(&p.id.into_uuid())
-                        .and_then(|d| d.as_deref())
-                        .map_or(false, |d| !d.trim().is_empty());
-                    if !has_deck {
-                        flags.push("deck_missing_on_heavy".to_string());
-                    }
-                }
+                let flags = flags_by_post.remove(&p.id.into_uuid()).unwrap_or_default();
                 (p, flags)
             })
             .collect();
@@ -1,7 +1,7 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use serde::Serialize;
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool};
 use uuid::Uuid;
 
 /// Datetime field group: event timing with optional cost and recurrence.
@@ -35,7 +35,7 @@ impl PostDatetimeRecord {
         end_at: Option<DateTime<Utc>>,
         cost: Option<&str>,
         recurring: bool,
-        pool: &PgPool,
+        db: impl PgExecutor<'_>,
     ) -> Result<Self> {
         let row = sqlx::query_as::<_, Self>(
             r#"
@@ -54,7 +54,7 @@ impl PostDatetimeRecord {
         .bind(end_at)
         .bind(cost)
         .bind(recurring)
-        .fetch_one(pool)
+        .fetch_one(db)
         .await?;
         Ok(row)
     }
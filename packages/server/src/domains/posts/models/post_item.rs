@@ -1,7 +1,7 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use sqlx::PgPool;
+use sqlx::{Acquire, PgPool, Postgres};
 use uuid::Uuid;
 
 /// Items field group: name+detail pairs (exchanges, references).
@@ -37,8 +37,12 @@ impl PostItem {
 
     /// Replace the entire items list for a post. Deletes existing rows, inserts
     /// the provided list in order.
-    pub async fn replace_all(post_id: Uuid, items: &[PostItemInput], pool: &PgPool) -> Result<()> {
-        let mut tx = pool.begin().await?;
+    pub async fn replace_all(
+        post_id: Uuid,
+        items: &[PostItemInput],
+        db: impl Acquire<'_, Database = Postgres>,
+    ) -> Result<()> {
+        let mut tx = db.begin().await?;
         sqlx::query("DELETE FROM post_items WHERE post_id = $1")
             .bind(post_id)
             .execute(&mut *tx)
@@ -1,7 +1,7 @@
 use anyhow::Result;
 use chrono::NaiveDate;
 use serde::Serialize;
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool};
 use uuid::Uuid;
 
 /// Link field group: CTA button with optional deadline.
@@ -33,7 +33,7 @@ impl PostLinkRecord {
         label: Option<&str>,
         url: Option<&str>,
         deadline: Option<NaiveDate>,
-        pool: &PgPool,
+        db: impl PgExecutor<'_>,
     ) -> Result<Self> {
         let row = sqlx::query_as::<_, Self>(
             r#"
@@ -50,7 +50,7 @@ impl PostLinkRecord {
         .bind(label)
         .bind(url)
         .bind(deadline)
-        .fetch_one(pool)
+        .fetch_one(db)
         .await?;
         Ok(row)
     }
@@ -1,7 +1,7 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool};
 
 use crate::common::{LocationId, PostId, PostLocationId};
 
@@ -22,7 +22,7 @@ impl PostLocation {
         location_id: LocationId,
         is_primary: bool,
         notes: Option<&str>,
-        pool: &PgPool,
+        db: impl PgExecutor<'_>,
     ) -> Result<Self> {
         let post_location = sqlx::query_as::<_, Self>(
             r#"
@@ -38,7 +38,7 @@ impl PostLocation {
         .bind(location_id)
         .bind(is_primary)
         .bind(notes)
-        .fetch_one(pool)
+        .fetch_one(db)
         .await?;
         Ok(post_location)
     }
@@ -1,7 +1,7 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use serde::Serialize;
-use sqlx::PgPool;
+use sqlx::{Acquire, PgPool, Postgres};
 use uuid::Uuid;
 
 use crate::domains::media::models::{DesiredRef, MediaReference};
@@ -48,6 +48,17 @@ impl PostMediaRecord {
         Ok(rows)
     }
 
+    /// The primary (lowest sort_order) media record for a post, if any.
+    pub async fn find_primary(post_id: Uuid, pool: &PgPool) -> Result<Option<Self>> {
+        let row = sqlx::query_as::<_, Self>(
+            "SELECT * FROM post_media WHERE post_id = $1 ORDER BY sort_order LIMIT 1",
+        )
+        .bind(post_id)
+        .fetch_optional(pool)
+        .await?;
+        Ok(row)
+    }
+
     /// Upsert the primary media record for a post (sort_order = 0).
     /// When `media_id` is provided, also reconciles `media_references` for
     /// (`post_hero`, post_id) so the Media Library usage panel stays current.
@@ -113,9 +124,9 @@ impl PostMediaRecord {
     pub async fn replace_all(
         post_id: Uuid,
         items: &[PostMediaInput],
-        pool: &PgPool,
+        db: impl Acquire<'_, Database = Postgres>,
     ) -> Result<()> {
-        let mut tx = pool.begin().await?;
+        let mut tx = db.begin().await?;
         sqlx::query("DELETE FROM post_media WHERE post_id = $1")
             .bind(post_id)
             .execute(&mut *tx)
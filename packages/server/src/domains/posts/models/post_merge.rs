@@ -0,0 +1,65 @@
+//! Post merges — the record of a duplicate folded into a survivor.
+//!
+//! Written by `activities::post_merge::merge_into`; read by content-hash
+//! dedup so re-ingested duplicate content resolves to the survivor. See
+//! migration 256.
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::common::{MemberId, PostId};
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct PostMerge {
+    pub id: Uuid,
+    pub survivor_post_id: PostId,
+    pub merged_post_id: PostId,
+    pub merged_content_hash: Option<String>,
+    pub field_groups: Vec<String>,
+    pub summary: serde_json::Value,
+    pub merged_by: Option<MemberId>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl PostMerge {
+    /// Merges into or out of a post, newest first.
+    pub async fn find_for_post(post_id: PostId, pool: &PgPool) -> Result<Vec<Self>> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            SELECT * FROM post_merges
+            WHERE survivor_post_id = $1 OR merged_post_id = $1
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(post_id)
+        .fetch_all(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// The live survivor of a merged post whose content hash was
+    /// `content_hash`, if any.
+    pub async fn find_survivor_by_content_hash(
+        content_hash: &str,
+        pool: &PgPool,
+    ) -> Result<Option<Uuid>> {
+        sqlx::query_scalar::<_, Uuid>(
+            r#"
+            SELECT m.survivor_post_id
+            FROM post_merges m
+            JOIN posts p ON p.id = m.survivor_post_id
+            WHERE m.merged_content_hash = $1
+              AND p.deleted_at IS NULL
+            ORDER BY m.created_at DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(content_hash)
+        .fetch_optional(pool)
+        .await
+        .map_err(Into::into)
+    }
+}
@@ -1,7 +1,7 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use serde::Serialize;
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool};
 use uuid::Uuid;
 
 /// Meta field group: editorial metadata (kicker, byline, timestamps, deck).
@@ -36,7 +36,7 @@ impl PostMetaRecord {
         byline: Option<&str>,
         deck: Option<&str>,
         updated: Option<&str>,
-        pool: &PgPool,
+        db: impl PgExecutor<'_>,
     ) -> Result<Self> {
         let row = sqlx::query_as::<_, Self>(
             r#"
@@ -55,7 +55,7 @@ impl PostMetaRecord {
         .bind(byline)
         .bind(deck)
         .bind(updated)
-        .fetch_one(pool)
+        .fetch_one(db)
         .await?;
         Ok(row)
     }
@@ -1,6 +1,6 @@
 use anyhow::Result;
 use serde::Serialize;
-use sqlx::PgPool;
+use sqlx::{Acquire, PgPool, Postgres};
 use uuid::Uuid;
 
 use crate::domains::media::models::{DesiredRef, MediaReference};
@@ -42,8 +42,9 @@ impl PostPersonRecord {
         photo_url: Option<&str>,
         quote: Option<&str>,
         photo_media_id: Option<Uuid>,
-        pool: &PgPool,
+        db: impl Acquire<'_, Database = Postgres>,
     ) -> Result<Self> {
+        let mut tx = db.begin().await?;
         let row = sqlx::query_as::<_, Self>(
             r#"
             INSERT INTO post_person (post_id, name, role, bio, photo_url, quote, photo_media_id)
@@ -65,14 +66,15 @@ impl PostPersonRecord {
         .bind(photo_url)
         .bind(quote)
         .bind(photo_media_id)
-        .fetch_one(pool)
+        .fetch_one(&mut *tx)
         .await?;
 
         let desired: Vec<DesiredRef> = match photo_media_id {
             Some(mid) => vec![DesiredRef { media_id: mid, field_key: None }],
             None => Vec::new(),
         };
-        MediaReference::reconcile("post_person", post_id, &desired, pool).await?;
+        MediaReference::reconcile("post_person", post_id, &desired, &mut *tx).await?;
+        tx.commit().await?;
 
         Ok(row)
     }
@@ -3,6 +3,7 @@ use anyhow::Result;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
 
 pub struct PostReport;
 pub type PostReportId = Id<PostReport>;
@@ -70,6 +71,7 @@ impl PostReportRecord {
     pub async fn query_pending(
         limit: i64,
         offset: i64,
+        county_ids: Option<&[Uuid]>,
         pool: &PgPool,
     ) -> Result<Vec<PostReportWithDetails>> {
         sqlx::query_as::<_, PostReportWithDetails>(
@@ -79,11 +81,15 @@ impl PostReportRecord {
                     report_count_for_post
              FROM post_reports_with_details
              WHERE status = 'pending'
+               AND ($3::uuid[] IS NULL OR post_id IN (
+                   SELECT id FROM posts WHERE county_id = ANY($3)
+               ))
              ORDER BY created_at DESC
              LIMIT $1 OFFSET $2",
         )
         .bind(limit)
         .bind(offset)
+        .bind(county_ids)
         .fetch_all(pool)
         .await
         .map_err(Into::into)
@@ -92,6 +98,7 @@ impl PostReportRecord {
     pub async fn query_all(
         limit: i64,
         offset: i64,
+        county_ids: Option<&[Uuid]>,
         pool: &PgPool,
     ) -> Result<Vec<PostReportWithDetails>> {
         sqlx::query_as::<_, PostReportWithDetails>(
@@ -100,11 +107,15 @@ impl PostReportRecord {
                     post_title, post_type, post_status,
                     report_count_for_post
              FROM post_reports_with_details
+             WHERE $3::uuid[] IS NULL OR post_id IN (
+                 SELECT id FROM posts WHERE county_id = ANY($3)
+             )
              ORDER BY created_at DESC
              LIMIT $1 OFFSET $2",
         )
         .bind(limit)
         .bind(offset)
+        .bind(county_ids)
         .fetch_all(pool)
         .await
         .map_err(Into::into)
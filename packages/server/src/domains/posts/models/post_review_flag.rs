@@ -0,0 +1,201 @@
+//! Post review flags — why a post is waiting in the Signal Inbox.
+//!
+//! Ingest (and the calendar importer) write one row per soft-fail reason
+//! when they land a post `in_review`; an editor save that redacts PII
+//! writes `pii_detected`. Editors resolve flags one at a time;
+//! see `activities::review_flags` for the auto-activation rule and
+//! migration 255 for the table.
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgExecutor, PgPool};
+use uuid::Uuid;
+
+use crate::common::{MemberId, PostId, PostReviewFlagId};
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct PostReviewFlag {
+    pub id: PostReviewFlagId,
+    pub post_id: PostId,
+    pub flag: String,
+    pub details: serde_json::Value,
+    pub blocking: bool,
+    pub created_at: DateTime<Utc>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub resolved_by: Option<MemberId>,
+}
+
+/// A flag to raise on a post.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewReviewFlag {
+    pub flag: &'static str,
+    pub details: serde_json::Value,
+    pub blocking: bool,
+}
+
+impl NewReviewFlag {
+    pub fn blocking(flag: &'static str, details: serde_json::Value) -> Self {
+        Self {
+            flag,
+            details,
+            blocking: true,
+        }
+    }
+}
+
+impl PostReviewFlag {
+    /// Raise flags on a post. A flag that is already open on the post is
+    /// left as it is.
+    pub async fn raise_all(
+        post_id: PostId,
+        flags: &[NewReviewFlag],
+        db: impl PgExecutor<'_>,
+    ) -> Result<()> {
+        if flags.is_empty() {
+            return Ok(());
+        }
+        let codes: Vec<&str> = flags.iter().map(|f| f.flag).collect();
+        let details: Vec<serde_json::Value> = flags.iter().map(|f| f.details.clone()).collect();
+        let blocking: Vec<bool> = flags.iter().map(|f| f.blocking).collect();
+        sqlx::query(
+            r#"
+            INSERT INTO post_review_flags (post_id, flag, details, blocking)
+            SELECT $1, f.flag, f.details, f.blocking
+            FROM UNNEST($2::text[], $3::jsonb[], $4::bool[]) AS f(flag, details, blocking)
+            ON CONFLICT (post_id, flag) WHERE resolved_at IS NULL DO NOTHING
+            "#,
+        )
+        .bind(post_id)
+        .bind(&codes)
+        .bind(&details)
+        .bind(&blocking)
+        .execute(db)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn find_by_id(
+        id: PostReviewFlagId,
+        db: impl PgExecutor<'_>,
+    ) -> Result<Option<Self>> {
+        sqlx::query_as::<_, Self>("SELECT * FROM post_review_flags WHERE id = $1")
+            .bind(id)
+            .fetch_optional(db)
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Open flags for a set of posts, oldest first within each post.
+    pub async fn find_open_for_posts(post_ids: &[Uuid], pool: &PgPool) -> Result<Vec<Self>> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            SELECT * FROM post_review_flags
+            WHERE post_id = ANY($1) AND resolved_at IS NULL
+            ORDER BY post_id, created_at ASC, flag ASC
+            "#,
+        )
+        .bind(post_ids)
+        .fetch_all(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Every flag a post has had, open and resolved, newest first.
+    pub async fn find_for_post(post_id: PostId, pool: &PgPool) -> Result<Vec<Self>> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            SELECT * FROM post_review_flags
+            WHERE post_id = $1
+            ORDER BY created_at DESC, flag ASC
+            "#,
+        )
+        .bind(post_id)
+        .fetch_all(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Resolve one open flag. None if it doesn't exist or is already
+    /// resolved.
+    pub async fn resolve(
+        id: PostReviewFlagId,
+        resolved_by: MemberId,
+        db: impl PgExecutor<'_>,
+    ) -> Result<Option<Self>> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            UPDATE post_review_flags
+            SET resolved_at = NOW(), resolved_by = $2
+            WHERE id = $1 AND resolved_at IS NULL
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(resolved_by)
+        .fetch_optional(db)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Resolve every open flag on a post (the post was approved outright).
+    pub async fn resolve_all_for_post(
+        post_id: PostId,
+        resolved_by: MemberId,
+        pool: &PgPool,
+    ) -> Result<u64> {
+        let result = sqlx::query(
+            r#"
+            UPDATE post_review_flags
+            SET resolved_at = NOW(), resolved_by = $2
+            WHERE post_id = $1 AND resolved_at IS NULL
+            "#,
+        )
+        .bind(post_id)
+        .bind(resolved_by)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// Open-flag counts per flag code across the posts the inbox lists
+    /// (see `Post::find_in_review_with_flags`), for its group-by-reason
+    /// header.
+    pub async fn count_open_by_flag(
+        county_ids: Option<&[Uuid]>,
+        pool: &PgPool,
+    ) -> Result<Vec<(String, i64)>> {
+        sqlx::query_as::<_, (String, i64)>(
+            r#"
+            SELECT f.flag, COUNT(*)
+            FROM post_review_flags f
+            JOIN posts p ON p.id = f.post_id
+            WHERE f.resolved_at IS NULL
+              AND p.status = 'in_review'
+              AND p.deleted_at IS NULL
+              AND (p.revision_of_post_id IS NULL OR p.submission_type = 'ical_import')
+              AND p.translation_of_id IS NULL
+              AND ($1::uuid[] IS NULL OR p.county_id = ANY($1))
+            GROUP BY f.flag
+            ORDER BY f.flag
+            "#,
+        )
+        .bind(county_ids)
+        .fetch_all(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    pub async fn count_open_blocking(post_id: PostId, db: impl PgExecutor<'_>) -> Result<i64> {
+        sqlx::query_scalar(
+            r#"
+            SELECT COUNT(*) FROM post_review_flags
+            WHERE post_id = $1 AND blocking AND resolved_at IS NULL
+            "#,
+        )
+        .bind(post_id)
+        .fetch_one(db)
+        .await
+        .map_err(Into::into)
+    }
+}
@@ -1,7 +1,7 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use serde::Serialize;
-use sqlx::PgPool;
+use sqlx::{Acquire, PgPool, Postgres};
 use uuid::Uuid;
 
 /// Schedule field group: hours/schedule entries (day + open + close).
@@ -42,9 +42,9 @@ impl PostScheduleEntry {
     pub async fn replace_all(
         post_id: Uuid,
         entries: &[PostScheduleInput],
-        pool: &PgPool,
+        db: impl Acquire<'_, Database = Postgres>,
     ) -> Result<()> {
-        let mut tx = pool.begin().await?;
+        let mut tx = db.begin().await?;
 
         sqlx::query("DELETE FROM post_schedule WHERE post_id = $1")
             .bind(post_id)
@@ -0,0 +1,195 @@
+//! Post similarity signatures — MinHash storage for near-duplicate lookup.
+//!
+//! Signatures and their LSH band buckets are computed in
+//! `activities::near_duplicate`; this module only stores and queries them.
+//! See migration 257.
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use sqlx::{Acquire, FromRow, PgPool, Postgres};
+use uuid::Uuid;
+
+use crate::common::PostId;
+
+/// A candidate post's stored signature.
+#[derive(Debug, Clone, FromRow)]
+pub struct SimilarityCandidate {
+    pub post_id: Uuid,
+    pub signature: Vec<i64>,
+}
+
+/// An active post with no signature yet.
+#[derive(Debug, Clone, FromRow)]
+pub struct UnindexedPost {
+    pub id: Uuid,
+    pub title: String,
+    pub body_raw: String,
+}
+
+pub struct PostSimilarity;
+
+impl PostSimilarity {
+    /// Store (or replace) a post's signature and its band buckets.
+    pub async fn upsert(
+        post_id: PostId,
+        signature: &[i64],
+        shingle_count: i32,
+        buckets: &[i64],
+        db: impl Acquire<'_, Database = Postgres>,
+    ) -> Result<()> {
+        let bands: Vec<i16> = (0..buckets.len() as i16).collect();
+        let mut tx = db.begin().await?;
+        sqlx::query(
+            r#"
+            INSERT INTO post_similarity_signatures (post_id, signature, shingle_count)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (post_id) DO UPDATE
+            SET signature = EXCLUDED.signature,
+                shingle_count = EXCLUDED.shingle_count,
+                created_at = NOW()
+            "#,
+        )
+        .bind(post_id)
+        .bind(signature)
+        .bind(shingle_count)
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query("DELETE FROM post_similarity_bands WHERE post_id = $1")
+            .bind(post_id)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query(
+            r#"
+            INSERT INTO post_similarity_bands (post_id, band, bucket)
+            SELECT $1, b.band, b.bucket
+            FROM UNNEST($2::smallint[], $3::bigint[]) AS b(band, bucket)
+            "#,
+        )
+        .bind(post_id)
+        .bind(&bands)
+        .bind(buckets)
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Live posts sharing at least one band bucket with `buckets`,
+    /// published between `since` and `until`. Excludes posts already
+    /// merged or marked as duplicates, and `revision_of` with the posts it
+    /// in turn revised: a revision always resembles what it replaces.
+    pub async fn find_candidates(
+        buckets: &[i64],
+        since: DateTime<Utc>,
+        until: DateTime<Utc>,
+        revision_of: Option<Uuid>,
+        pool: &PgPool,
+    ) -> Result<Vec<SimilarityCandidate>> {
+        let bands: Vec<i16> = (0..buckets.len() as i16).collect();
+        sqlx::query_as::<_, SimilarityCandidate>(
+            r#"
+            WITH RECURSIVE revision_chain AS (
+                SELECT id, revision_of_post_id FROM posts WHERE id = $5
+                UNION
+                SELECT p.id, p.revision_of_post_id
+                FROM posts p
+                JOIN revision_chain c ON p.id = c.revision_of_post_id
+            )
+            SELECT s.post_id, s.signature
+            FROM post_similarity_signatures s
+            JOIN posts p ON p.id = s.post_id
+            WHERE s.post_id IN (
+                SELECT DISTINCT sb.post_id
+                FROM post_similarity_bands sb
+                JOIN UNNEST($1::smallint[], $2::bigint[]) AS b(band, bucket)
+                    ON sb.band = b.band AND sb.bucket = b.bucket
+            )
+              AND s.post_id NOT IN (SELECT id FROM revision_chain)
+              AND p.deleted_at IS NULL
+              AND p.duplicate_of_id IS NULL
+              AND p.status NOT IN ('rejected', 'archived')
+              AND COALESCE(p.published_at, p.created_at) BETWEEN $3 AND $4
+            "#,
+        )
+        .bind(&bands)
+        .bind(buckets)
+        .bind(since)
+        .bind(until)
+        .bind(revision_of)
+        .fetch_all(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Pairs of active posts published since `since` that share a band
+    /// bucket — the candidate pairs for clustering the active pool. With
+    /// `county_ids`, both posts must be resolved to one of them. At most
+    /// `limit` pairs.
+    pub async fn find_active_bucket_pairs(
+        since: DateTime<Utc>,
+        county_ids: Option<&[Uuid]>,
+        limit: i64,
+        pool: &PgPool,
+    ) -> Result<Vec<(Uuid, Uuid)>> {
+        sqlx::query_as::<_, (Uuid, Uuid)>(
+            r#"
+            WITH pool AS (
+                SELECT p.id
+                FROM posts p
+                WHERE p.status = 'active'
+                  AND p.deleted_at IS NULL
+                  AND COALESCE(p.published_at, p.created_at) >= $1
+                  AND ($2::uuid[] IS NULL OR p.county_id = ANY($2))
+            )
+            SELECT DISTINCT a.post_id, b.post_id
+            FROM post_similarity_bands a
+            JOIN pool pa ON pa.id = a.post_id
+            JOIN post_similarity_bands b
+                ON a.band = b.band AND a.bucket = b.bucket AND a.post_id < b.post_id
+            JOIN pool pb ON pb.id = b.post_id
+            LIMIT $3
+            "#,
+        )
+        .bind(since)
+        .bind(county_ids)
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    pub async fn find_signatures(
+        post_ids: &[Uuid],
+        pool: &PgPool,
+    ) -> Result<Vec<(Uuid, Vec<i64>)>> {
+        sqlx::query_as::<_, (Uuid, Vec<i64>)>(
+            "SELECT post_id, signature FROM post_similarity_signatures WHERE post_id = ANY($1)",
+        )
+        .bind(post_ids)
+        .fetch_all(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Active posts without a signature (ingested before migration 257, or
+    /// created outside ingest), oldest first.
+    pub async fn find_active_unindexed(limit: i64, pool: &PgPool) -> Result<Vec<UnindexedPost>> {
+        sqlx::query_as::<_, UnindexedPost>(
+            r#"
+            SELECT p.id, p.title, p.body_raw
+            FROM posts p
+            WHERE p.status = 'active'
+              AND p.deleted_at IS NULL
+              AND NOT EXISTS (
+                  SELECT 1 FROM post_similarity_signatures s WHERE s.post_id = p.id
+              )
+            ORDER BY p.created_at ASC
+            LIMIT $1
+            "#,
+        )
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+        .map_err(Into::into)
+    }
+}
@@ -1,7 +1,7 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use serde::Serialize;
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool};
 use uuid::Uuid;
 
 use crate::common::{PostId, PostSourceId};
@@ -28,6 +28,8 @@ pub struct PostSource {
     pub platform_id: Option<String>,
     pub platform_post_type_hint: Option<String>,
     pub is_primary: bool,
+    /// The calendar feed an `ical` citation was imported from.
+    pub calendar_feed_id: Option<Uuid>,
 }
 
 /// Input for creating a `post_sources` row from the ingest handler. Carries
@@ -47,6 +49,7 @@ pub struct PostSourceInsert<'a> {
     /// Root Signal's `retrieved_at` for the citation (UTC). If `None`, DB
     /// defaults to NOW().
     pub retrieved_at: Option<DateTime<Utc>>,
+    pub calendar_feed_id: Option<Uuid>,
 }
 
 /// Enriched citation row for the admin Sources panel. Joins
@@ -135,7 +138,7 @@ impl PostSource {
     }
 
     /// Ingest-path insert: full Addendum-01 metadata.
-    pub async fn insert_full(input: PostSourceInsert<'_>, pool: &PgPool) -> Result<Self> {
+    pub async fn insert_full(input: PostSourceInsert<'_>, db: impl PgExecutor<'_>) -> Result<Self> {
         let retrieved = input.retrieved_at.unwrap_or_else(Utc::now);
         sqlx::query_as::<_, Self>(
             r#"
@@ -143,9 +146,9 @@ impl PostSource {
                 post_id, source_type, source_id, source_url,
                 first_seen_at, last_seen_at,
                 content_hash, snippet, confidence,
-                platform_id, platform_post_type_hint, is_primary
+                platform_id, platform_post_type_hint, is_primary, calendar_feed_id
             )
-            VALUES ($1, $2, $3, $4, $5, $5, $6, $7, $8, $9, $10, $11)
+            VALUES ($1, $2, $3, $4, $5, $5, $6, $7, $8, $9, $10, $11, $12)
             RETURNING *
             "#,
         )
@@ -160,7 +163,8 @@ impl PostSource {
         .bind(input.platform_id)
         .bind(input.platform_post_type_hint)
         .bind(input.is_primary)
-        .fetch_one(pool)
+        .bind(input.calendar_feed_id)
+        .fetch_one(db)
         .await
         .map_err(Into::into)
     }
@@ -185,6 +189,31 @@ impl PostSource {
         .map_err(Into::into)
     }
 
+    /// The newest citation of `platform_id` (e.g. a VEVENT UID) from one
+    /// source. Revisions and merges leave older rows behind on archived
+    /// posts; the newest carries the current fingerprint.
+    pub async fn find_latest_by_platform_id(
+        source_type: &str,
+        source_id: Uuid,
+        platform_id: &str,
+        pool: &PgPool,
+    ) -> Result<Option<Self>> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            SELECT * FROM post_sources
+            WHERE source_type = $1 AND source_id = $2 AND platform_id = $3
+            ORDER BY created_at DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(source_type)
+        .bind(source_id)
+        .bind(platform_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(Into::into)
+    }
+
     /// Refresh `last_seen_at` on a matched-by-hash row — the underlying
     /// source hasn't changed, we just re-verified it.
     pub async fn touch_last_seen(id: PostSourceId, pool: &PgPool) -> Result<()> {
@@ -332,12 +361,12 @@ impl PostSource {
                 post_id, source_type, source_id, source_url,
                 first_seen_at, last_seen_at, disappeared_at,
                 content_hash, snippet, confidence,
-                platform_id, platform_post_type_hint, is_primary
+                platform_id, platform_post_type_hint, is_primary, calendar_feed_id
             )
             SELECT $2, source_type, source_id, source_url,
                 first_seen_at, last_seen_at, disappeared_at,
                 content_hash, snippet, confidence,
-                platform_id, platform_post_type_hint, is_primary
+                platform_id, platform_post_type_hint, is_primary, calendar_feed_id
             FROM post_sources
             WHERE post_id = $1
             ON CONFLICT (post_id, source_type, source_id) DO NOTHING
@@ -1,6 +1,6 @@
 use anyhow::Result;
 use serde::Serialize;
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool};
 use uuid::Uuid;
 
 /// Source attribution field group: who issued this content.
@@ -30,7 +30,7 @@ impl PostSourceAttr {
         post_id: Uuid,
         source_name: Option<&str>,
         attribution: Option<&str>,
-        pool: &PgPool,
+        db: impl PgExecutor<'_>,
     ) -> Result<Self> {
         let row = sqlx::query_as::<_, Self>(
             r#"
@@ -45,7 +45,7 @@ impl PostSourceAttr {
         .bind(post_id)
         .bind(source_name)
         .bind(attribution)
-        .fetch_one(pool)
+        .fetch_one(db)
         .await?;
         Ok(row)
     }
@@ -1,6 +1,6 @@
 use anyhow::Result;
 use serde::Serialize;
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool};
 use uuid::Uuid;
 
 /// Status field group: exchange state and verification tracking.
@@ -30,7 +30,7 @@ impl PostStatusRecord {
         post_id: Uuid,
         state: Option<&str>,
         verified: Option<&str>,
-        pool: &PgPool,
+        db: impl PgExecutor<'_>,
     ) -> Result<Self> {
         let row = sqlx::query_as::<_, Self>(
             r#"
@@ -45,7 +45,7 @@ impl PostStatusRecord {
         .bind(post_id)
         .bind(state)
         .bind(verified)
-        .fetch_one(pool)
+        .fetch_one(db)
         .await?;
         Ok(row)
     }
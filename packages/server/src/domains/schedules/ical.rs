@@ -0,0 +1,742 @@
+//! RFC 5545 iCalendar rendering for event schedules.
+//!
+//! One VEVENT per schedule, keyed by the schedule id so subscribed
+//! calendars update events in place. Times in America/Chicago are written
+//! with a TZID and a matching VTIMEZONE; any other zone falls back to UTC.
+//! Operating-hours schedules (no `dtstart`) are not events and are skipped.
+//!
+//! [`parse_calendar`] reads partner feeds back in for the calendar
+//! importer. It is deliberately lenient: a VEVENT it can't use is skipped
+//! with a reason rather than failing the whole feed.
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+
+use super::models::Schedule;
+
+const PRODID: &str = "-//Root Editorial//Events//EN";
+
+/// The only zone we ship a VTIMEZONE for.
+const CALENDAR_TZID: &str = "America/Chicago";
+const CALENDAR_TZ: Tz = chrono_tz::America::Chicago;
+
+/// US Central rules since 2007: CDT from the second Sunday in March, CST
+/// from the first Sunday in November.
+const CHICAGO_VTIMEZONE: &[&str] = &[
+    "BEGIN:VTIMEZONE",
+    "TZID:America/Chicago",
+    "X-LIC-LOCATION:America/Chicago",
+    "BEGIN:DAYLIGHT",
+    "TZOFFSETFROM:-0600",
+    "TZOFFSETTO:-0500",
+    "TZNAME:CDT",
+    "DTSTART:19700308T020000",
+    "RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=2SU",
+    "END:DAYLIGHT",
+    "BEGIN:STANDARD",
+    "TZOFFSETFROM:-0500",
+    "TZOFFSETTO:-0600",
+    "TZNAME:CST",
+    "DTSTART:19701101T020000",
+    "RRULE:FREQ=YEARLY;BYMONTH=11;BYDAY=1SU",
+    "END:STANDARD",
+    "END:VTIMEZONE",
+];
+
+/// A schedule plus the post text shown in the calendar entry.
+#[derive(Debug, Clone, Copy)]
+pub struct CalendarEvent<'a> {
+    pub schedule: &'a Schedule,
+    pub summary: &'a str,
+    pub description: Option<&'a str>,
+    pub location: Option<&'a str>,
+}
+
+/// Render a VCALENDAR holding one VEVENT per event schedule. `name` becomes
+/// the calendar's display name in subscribing clients.
+pub fn render_calendar(name: &str, events: &[CalendarEvent<'_>]) -> String {
+    let mut lines: Vec<String> = vec![
+        "BEGIN:VCALENDAR".into(),
+        "VERSION:2.0".into(),
+        format!("PRODID:{}", PRODID),
+        "CALSCALE:GREGORIAN".into(),
+        "METHOD:PUBLISH".into(),
+        format!("X-WR-CALNAME:{}", escape_text(name)),
+        format!("X-WR-TIMEZONE:{}", CALENDAR_TZID),
+    ];
+    lines.extend(CHICAGO_VTIMEZONE.iter().map(|l| l.to_string()));
+    for event in events {
+        push_event(&mut lines, event);
+    }
+    lines.push("END:VCALENDAR".into());
+
+    let mut out = String::new();
+    for line in &lines {
+        out.push_str(&fold_line(line));
+        out.push_str("\r\n");
+    }
+    out
+}
+
+/// How a schedule's times are written.
+enum TimeForm {
+    /// `VALUE=DATE`, for all-day events.
+    Date,
+    /// Local time with `TZID=America/Chicago`.
+    Chicago,
+    /// UTC with a trailing `Z`.
+    Utc,
+}
+
+impl TimeForm {
+    fn of(schedule: &Schedule) -> Self {
+        if schedule.is_all_day {
+            TimeForm::Date
+        } else if schedule.timezone == CALENDAR_TZID {
+            TimeForm::Chicago
+        } else {
+            TimeForm::Utc
+        }
+    }
+
+    fn local(&self, at: DateTime<Utc>) -> NaiveDateTime {
+        match self {
+            TimeForm::Utc => at.naive_utc(),
+            TimeForm::Date | TimeForm::Chicago => at.with_timezone(&CALENDAR_TZ).naive_local(),
+        }
+    }
+
+    /// `NAME;params:value` for a point in time.
+    fn property(&self, name: &str, at: NaiveDateTime) -> String {
+        match self {
+            TimeForm::Date => format!("{};VALUE=DATE:{}", name, at.format("%Y%m%d")),
+            TimeForm::Chicago => format!(
+                "{};TZID={}:{}",
+                name,
+                CALENDAR_TZID,
+                at.format("%Y%m%dT%H%M%S")
+            ),
+            TimeForm::Utc => format!("{}:{}", name, at.format("%Y%m%dT%H%M%SZ")),
+        }
+    }
+}
+
+fn push_event(lines: &mut Vec<String>, event: &CalendarEvent<'_>) {
+    let schedule = event.schedule;
+    let Some(dtstart) = schedule.dtstart else {
+        return;
+    };
+    let form = TimeForm::of(schedule);
+    let start = form.local(dtstart);
+
+    lines.push("BEGIN:VEVENT".into());
+    lines.push(format!("UID:{}", schedule.id));
+    lines.push(format!("DTSTAMP:{}", utc_stamp(schedule.updated_at)));
+    lines.push(format!("CREATED:{}", utc_stamp(schedule.created_at)));
+    lines.push(format!("LAST-MODIFIED:{}", utc_stamp(schedule.updated_at)));
+    lines.push(format!("SEQUENCE:{}", schedule.sequence));
+    lines.push(form.property("DTSTART", start));
+    if let Some(end) = event_end(schedule, dtstart, &form) {
+        lines.push(form.property("DTEND", end));
+    }
+
+    if let Some(rrule) = recurrence_rule(schedule, &form) {
+        lines.push(format!("RRULE:{}", rrule));
+        match form {
+            TimeForm::Date => {
+                for date in schedule.exdate_dates() {
+                    lines.push(form.property("EXDATE", date.and_time(start.time())));
+                }
+            }
+            TimeForm::Chicago | TimeForm::Utc => {
+                for at in schedule.exdate_starts() {
+                    lines.push(form.property("EXDATE", form.local(at)));
+                }
+            }
+        }
+    }
+
+    lines.push(format!("SUMMARY:{}", escape_text(event.summary)));
+    let description = match (event.description, schedule.notes.as_deref()) {
+        (Some(d), Some(n)) if !n.trim().is_empty() => {
+            Some(format!("{}\n\n{}", d.trim(), n.trim()))
+        }
+        (Some(d), _) => Some(d.trim().to_string()),
+        (None, Some(n)) => Some(n.trim().to_string()),
+        (None, None) => None,
+    };
+    if let Some(description) = description.filter(|d| !d.is_empty()) {
+        lines.push(format!("DESCRIPTION:{}", escape_text(&description)));
+    }
+    if let Some(location) = event.location.filter(|l| !l.trim().is_empty()) {
+        lines.push(format!("LOCATION:{}", escape_text(location.trim())));
+    }
+    lines.push("END:VEVENT".into());
+}
+
+/// End of the first occurrence: `dtend` when it follows `dtstart`, else
+/// `dtstart + duration_minutes`. All-day events end on the following day
+/// at the earliest (DTEND is exclusive for dates).
+fn event_end(
+    schedule: &Schedule,
+    dtstart: DateTime<Utc>,
+    form: &TimeForm,
+) -> Option<NaiveDateTime> {
+    let end = schedule
+        .dtend
+        .filter(|end| *end > dtstart)
+        .or_else(|| {
+            schedule
+                .duration_minutes
+                .filter(|m| *m > 0)
+                .map(|m| dtstart + Duration::minutes(m as i64))
+        });
+
+    match form {
+        TimeForm::Date => {
+            let start_day = form.local(dtstart).date();
+            let end_day = end
+                .map(|e| form.local(e).date())
+                .filter(|d| *d > start_day)
+                .unwrap_or(start_day + Duration::days(1));
+            end_day.and_hms_opt(0, 0, 0)
+        }
+        _ => end.map(|e| form.local(e)),
+    }
+}
+
+/// The stored rrule with any `RRULE:` prefix removed, bounded by
+/// `valid_to` when the rule has no end of its own. UNTIL is a date for
+/// all-day events and a UTC time otherwise, as RFC 5545 requires.
+fn recurrence_rule(schedule: &Schedule, form: &TimeForm) -> Option<String> {
+    let rule = schedule
+        .rrule
+        .as_deref()
+        .map(|r| r.trim().trim_start_matches("RRULE:"))
+        .filter(|r| !r.is_empty())?;
+
+    let upper = rule.to_ascii_uppercase();
+    let bounded = upper.contains("UNTIL=") || upper.contains("COUNT=");
+    match schedule.valid_to {
+        Some(valid_to) if !bounded => {
+            let until = match form {
+                TimeForm::Date => valid_to.format("%Y%m%d").to_string(),
+                _ => until(valid_to),
+            };
+            Some(format!("{};UNTIL={}", rule, until))
+        }
+        _ => Some(rule.to_string()),
+    }
+}
+
+/// Last instant of `day` in Chicago, as a UTC UNTIL value.
+fn until(day: NaiveDate) -> String {
+    let end_of_day = day.and_hms_opt(23, 59, 59).unwrap_or_default();
+    let utc = end_of_day
+        .and_local_timezone(CALENDAR_TZ)
+        .latest()
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_else(|| end_of_day.and_utc());
+    utc_stamp(utc)
+}
+
+fn utc_stamp(at: DateTime<Utc>) -> String {
+    at.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Escape a TEXT value (RFC 5545 §3.3.11).
+fn escape_text(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            ';' => out.push_str("\\;"),
+            ',' => out.push_str("\\,"),
+            '\n' => out.push_str("\\n"),
+            '\r' => {}
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Fold a content line at 75 octets (RFC 5545 §3.1), never splitting a
+/// UTF-8 character. Continuation lines start with a single space.
+fn fold_line(line: &str) -> String {
+    const LIMIT: usize = 75;
+    let mut out = String::with_capacity(line.len() + line.len() / LIMIT * 3);
+    let mut width = 0;
+    for c in line.chars() {
+        let len = c.len_utf8();
+        if width + len > LIMIT {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += len;
+    }
+    out
+}
+
+// =============================================================================
+// Parsing (calendar import)
+// =============================================================================
+
+/// A feed that isn't an iCalendar document at all.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum IcalParseError {
+    #[error("not an iCalendar document (no BEGIN:VCALENDAR)")]
+    NotCalendar,
+}
+
+/// One VEVENT from an imported feed, with times resolved to UTC.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedEvent {
+    pub uid: String,
+    pub summary: String,
+    pub description: Option<String>,
+    pub location: Option<String>,
+    pub url: Option<String>,
+    pub dtstart: DateTime<Utc>,
+    pub dtend: Option<DateTime<Utc>>,
+    pub is_all_day: bool,
+    /// IANA zone the times were written in; floating and unknown zones
+    /// are read as America/Chicago.
+    pub timezone: String,
+    /// Without the `RRULE:` prefix.
+    pub rrule: Option<String>,
+    pub exdates: Vec<NaiveDate>,
+    pub cancelled: bool,
+}
+
+/// Events that could be read, plus why the others were skipped.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParsedCalendar {
+    pub events: Vec<ParsedEvent>,
+    pub skipped: Vec<String>,
+}
+
+/// Parse an iCalendar document. Overridden instances of a recurring event
+/// (`RECURRENCE-ID`) are skipped; the master event carries the series.
+pub fn parse_calendar(text: &str) -> Result<ParsedCalendar, IcalParseError> {
+    let lines = unfold_lines(text);
+    if !lines
+        .iter()
+        .any(|l| l.eq_ignore_ascii_case("BEGIN:VCALENDAR"))
+    {
+        return Err(IcalParseError::NotCalendar);
+    }
+
+    let mut calendar = ParsedCalendar::default();
+    let mut current: Option<Vec<ContentLine>> = None;
+    let mut depth = 0usize;
+    for line in &lines {
+        let Some(content) = ContentLine::parse(line) else {
+            continue;
+        };
+        match (content.name.as_str(), content.value.to_ascii_uppercase().as_str()) {
+            ("BEGIN", "VEVENT") if current.is_none() => {
+                current = Some(Vec::new());
+                depth = 0;
+            }
+            ("BEGIN", _) if current.is_some() => depth += 1,
+            ("END", "VEVENT") if current.is_some() && depth == 0 => {
+                let props = current.take().unwrap_or_default();
+                match event_from_properties(&props) {
+                    Ok(event) => calendar.events.push(event),
+                    Err(reason) => calendar.skipped.push(reason),
+                }
+            }
+            ("END", _) if current.is_some() => depth = depth.saturating_sub(1),
+            _ => {
+                // Properties of nested components (VALARM) aren't the event's.
+                if let Some(props) = current.as_mut().filter(|_| depth == 0) {
+                    props.push(content);
+                }
+            }
+        }
+    }
+    Ok(calendar)
+}
+
+/// `NAME;PARAM=value:VALUE`, name and parameter names uppercased.
+#[derive(Debug, Clone)]
+struct ContentLine {
+    name: String,
+    params: Vec<(String, String)>,
+    value: String,
+}
+
+impl ContentLine {
+    fn parse(line: &str) -> Option<Self> {
+        // The value starts at the first ':' outside a quoted parameter.
+        let mut in_quotes = false;
+        let split = line.char_indices().find_map(|(i, c)| match c {
+            '"' => {
+                in_quotes = !in_quotes;
+                None
+            }
+            ':' if !in_quotes => Some(i),
+            _ => None,
+        })?;
+        let (head, value) = (&line[..split], &line[split + 1..]);
+        let mut parts = head.split(';');
+        let name = parts.next()?.trim().to_ascii_uppercase();
+        let params = parts
+            .filter_map(|p| p.split_once('='))
+            .map(|(k, v)| (k.trim().to_ascii_uppercase(), v.trim_matches('"').to_string()))
+            .collect();
+        Some(Self {
+            name,
+            params,
+            value: value.to_string(),
+        })
+    }
+
+    fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+fn event_from_properties(props: &[ContentLine]) -> Result<ParsedEvent, String> {
+    let find = |name: &str| props.iter().find(|p| p.name == name);
+    let text = |name: &str| {
+        find(name)
+            .map(|p| unescape_text(&p.value))
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    };
+
+    let uid = text("UID").ok_or_else(|| "event without UID".to_string())?;
+    if find("RECURRENCE-ID").is_some() {
+        return Err(format!("{}: overridden instance of a recurring event", uid));
+    }
+    let start_prop = find("DTSTART").ok_or_else(|| format!("{}: no DTSTART", uid))?;
+    let start = parse_time(start_prop).ok_or_else(|| format!("{}: unreadable DTSTART", uid))?;
+
+    let dtend = match find("DTEND").and_then(parse_time) {
+        Some(end) => Some(end.at),
+        None => find("DURATION")
+            .and_then(|p| parse_duration(&p.value))
+            .map(|d| start.at + d),
+    };
+
+    let mut exdates = Vec::new();
+    for prop in props.iter().filter(|p| p.name == "EXDATE") {
+        for value in prop.value.split(',') {
+            let single = ContentLine {
+                value: value.trim().to_string(),
+                ..prop.clone()
+            };
+            if let Some(t) = parse_time(&single) {
+                exdates.push(t.at.with_timezone(&t.zone).date_naive());
+            }
+        }
+    }
+
+    Ok(ParsedEvent {
+        summary: text("SUMMARY").unwrap_or_else(|| "Untitled event".to_string()),
+        description: text("DESCRIPTION"),
+        location: text("LOCATION"),
+        url: text("URL"),
+        dtstart: start.at,
+        dtend: dtend.filter(|end| *end > start.at),
+        is_all_day: start.is_date,
+        timezone: start.zone.name().to_string(),
+        rrule: find("RRULE")
+            .map(|p| p.value.trim().to_string())
+            .filter(|r| !r.is_empty()),
+        exdates,
+        cancelled: find("STATUS").is_some_and(|p| p.value.eq_ignore_ascii_case("CANCELLED")),
+        uid,
+    })
+}
+
+struct ParsedTime {
+    at: DateTime<Utc>,
+    zone: Tz,
+    is_date: bool,
+}
+
+/// DATE, UTC (`...Z`), TZID-qualified and floating DATE-TIME values.
+fn parse_time(prop: &ContentLine) -> Option<ParsedTime> {
+    let value = prop.value.trim();
+    let zone = prop
+        .param("TZID")
+        .and_then(|tz| tz.trim_start_matches('/').parse::<Tz>().ok())
+        .unwrap_or(CALENDAR_TZ);
+
+    if prop.param("VALUE").is_some_and(|v| v.eq_ignore_ascii_case("DATE")) || value.len() == 8 {
+        let date = NaiveDate::parse_from_str(value, "%Y%m%d").ok()?;
+        let at = zone
+            .from_local_datetime(&date.and_hms_opt(0, 0, 0)?)
+            .earliest()?;
+        return Some(ParsedTime {
+            at: at.with_timezone(&Utc),
+            zone,
+            is_date: true,
+        });
+    }
+
+    if let Some(utc) = value.strip_suffix('Z') {
+        let naive = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").ok()?;
+        return Some(ParsedTime {
+            at: naive.and_utc(),
+            zone,
+            is_date: false,
+        });
+    }
+
+    let naive = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").ok()?;
+    let at = zone.from_local_datetime(&naive).earliest()?;
+    Some(ParsedTime {
+        at: at.with_timezone(&Utc),
+        zone,
+        is_date: false,
+    })
+}
+
+/// RFC 5545 DURATION (`PT1H30M`, `P1D`, `P2W`). Negative durations are
+/// not meaningful for DTEND and are ignored.
+fn parse_duration(value: &str) -> Option<Duration> {
+    let rest = value.trim().strip_prefix('+').unwrap_or(value.trim());
+    let rest = rest.strip_prefix('P')?;
+    let mut total = Duration::zero();
+    let mut number = String::new();
+    for c in rest.chars() {
+        match c {
+            '0'..='9' => number.push(c),
+            'T' => {}
+            'W' | 'D' | 'H' | 'M' | 'S' => {
+                let n: i64 = number.parse().ok()?;
+                number.clear();
+                total += match c {
+                    'W' => Duration::weeks(n),
+                    'D' => Duration::days(n),
+                    'H' => Duration::hours(n),
+                    'M' => Duration::minutes(n),
+                    _ => Duration::seconds(n),
+                };
+            }
+            _ => return None,
+        }
+    }
+    Some(total)
+}
+
+/// Undo line folding (RFC 5545 §3.1) and split into content lines.
+fn unfold_lines(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for raw in text.split('\n') {
+        let raw = raw.strip_suffix('\r').unwrap_or(raw);
+        match raw.strip_prefix([' ', '\t']) {
+            Some(continuation) if !lines.is_empty() => {
+                if let Some(last) = lines.last_mut() {
+                    last.push_str(continuation);
+                }
+            }
+            _ if raw.trim().is_empty() => {}
+            _ => lines.push(raw.to_string()),
+        }
+    }
+    lines
+}
+
+/// Reverse of [`escape_text`].
+fn unescape_text(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') | Some('N') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::ScheduleId;
+    use chrono::TimeZone;
+    use uuid::Uuid;
+
+    fn schedule(rrule: Option<&str>, exdates: Option<&str>) -> Schedule {
+        // 6pm CDT
+        let dtstart = Utc.with_ymd_and_hms(2026, 4, 7, 23, 0, 0).unwrap();
+        Schedule {
+            id: ScheduleId::new(),
+            schedulable_type: "post".to_string(),
+            schedulable_id: Uuid::new_v4(),
+            day_of_week: None,
+            opens_at: None,
+            closes_at: None,
+            timezone: "America/Chicago".to_string(),
+            valid_from: None,
+            valid_to: None,
+            notes: Some("Bring a bag, please".to_string()),
+            created_at: dtstart,
+            dtstart: Some(dtstart),
+            dtend: None,
+            rrule: rrule.map(str::to_string),
+            exdates: exdates.map(str::to_string),
+            is_all_day: false,
+            duration_minutes: Some(90),
+            updated_at: dtstart,
+            sequence: 2,
+        }
+    }
+
+    fn render(schedule: &Schedule) -> String {
+        render_calendar(
+            "Food shelf",
+            &[CalendarEvent {
+                schedule,
+                summary: "Weekly distribution",
+                description: None,
+                location: Some("St. Mark's, 1st floor"),
+            }],
+        )
+    }
+
+    #[test]
+    fn recurring_event_renders_local_times_rrule_and_exdates() {
+        let s = schedule(Some("RRULE:FREQ=WEEKLY;BYDAY=TU"), Some("2026-04-14,2026-04-21"));
+        let ics = render(&s);
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert!(ics.contains("BEGIN:VTIMEZONE\r\nTZID:America/Chicago\r\n"));
+        assert!(ics.contains(&format!("UID:{}\r\n", s.id)));
+        assert!(ics.contains("SEQUENCE:2\r\n"));
+        assert!(ics.contains("DTSTART;TZID=America/Chicago:20260407T180000\r\n"));
+        assert!(ics.contains("DTEND;TZID=America/Chicago:20260407T193000\r\n"));
+        assert!(ics.contains("RRULE:FREQ=WEEKLY;BYDAY=TU\r\n"));
+        assert!(ics.contains("EXDATE;TZID=America/Chicago:20260414T180000\r\n"));
+        assert!(ics.contains("EXDATE;TZID=America/Chicago:20260421T180000\r\n"));
+        assert!(ics.contains("DESCRIPTION:Bring a bag\\, please\r\n"));
+        assert!(ics.contains("LOCATION:St. Mark's\\, 1st floor\r\n"));
+    }
+
+    #[test]
+    fn utc_exdates_use_the_local_start_time_of_the_excluded_day() {
+        // 10:30pm PDT, which is already the next day in UTC
+        let mut s = schedule(Some("FREQ=DAILY"), Some("2026-04-14"));
+        s.timezone = "America/Los_Angeles".to_string();
+        s.dtstart = Some(Utc.with_ymd_and_hms(2026, 4, 8, 5, 30, 0).unwrap());
+        let ics = render(&s);
+
+        assert!(ics.contains("DTSTART:20260408T053000Z\r\n"));
+        assert!(ics.contains("EXDATE:20260415T053000Z\r\n"));
+    }
+
+    #[test]
+    fn valid_to_bounds_an_open_ended_rule_and_all_day_uses_dates() {
+        let mut s = schedule(Some("FREQ=MONTHLY;BYDAY=1TU"), None);
+        s.valid_to = NaiveDate::from_ymd_opt(2026, 12, 31);
+        assert!(render(&s).contains("RRULE:FREQ=MONTHLY;BYDAY=1TU;UNTIL=20270101T055959Z\r\n"));
+
+        s.is_all_day = true;
+        let ics = render(&s);
+        assert!(ics.contains("RRULE:FREQ=MONTHLY;BYDAY=1TU;UNTIL=20261231\r\n"));
+        assert!(ics.contains("DTSTART;VALUE=DATE:20260407\r\n"));
+        assert!(ics.contains("DTEND;VALUE=DATE:20260408\r\n"));
+    }
+
+    #[test]
+    fn operating_hours_are_skipped_and_long_lines_fold() {
+        let mut hours = schedule(Some("FREQ=WEEKLY;BYDAY=MO"), None);
+        hours.dtstart = None;
+        assert!(!render(&hours).contains("BEGIN:VEVENT"));
+
+        let folded = fold_line(&format!("SUMMARY:{}", "é".repeat(60)));
+        for line in folded.split("\r\n") {
+            assert!(line.len() <= 75);
+        }
+        assert_eq!(folded.replace("\r\n ", ""), format!("SUMMARY:{}", "é".repeat(60)));
+    }
+
+    const FEED: &str = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+BEGIN:VEVENT\r\n\
+UID:food-shelf@partner.example.org\r\n\
+DTSTART;TZID=America/Chicago:20260407T180000\r\n\
+DTEND;TZID=America/Chicago:20260407T193000\r\n\
+RRULE:FREQ=WEEKLY;BYDAY=TU\r\n\
+EXDATE;TZID=America/Chicago:20260414T180000,20260421T180000\r\n\
+SUMMARY:Weekly distribution\r\n\
+DESCRIPTION:Bring a bag\\, please.\\nNo ID requ\r\n ired.\r\n\
+BEGIN:VALARM\r\n\
+ACTION:DISPLAY\r\n\
+DESCRIPTION:Reminder\r\n\
+END:VALARM\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:food-shelf@partner.example.org\r\n\
+RECURRENCE-ID;TZID=America/Chicago:20260428T180000\r\n\
+DTSTART;TZID=America/Chicago:20260428T170000\r\n\
+SUMMARY:Weekly distribution (early)\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:picnic@partner.example.org\r\n\
+DTSTART;VALUE=DATE:20260704\r\n\
+SUMMARY:Community picnic\r\n\
+STATUS:CANCELLED\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+SUMMARY:No uid\r\n\
+DTSTART:20260501T150000Z\r\n\
+DURATION:PT2H\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+    #[test]
+    fn parse_calendar_reads_series_and_skips_what_it_cannot_use() {
+        let calendar = parse_calendar(FEED).unwrap();
+        assert_eq!(calendar.events.len(), 2);
+        assert_eq!(calendar.skipped.len(), 2);
+
+        let series = &calendar.events[0];
+        assert_eq!(series.uid, "food-shelf@partner.example.org");
+        assert_eq!(series.dtstart, Utc.with_ymd_and_hms(2026, 4, 7, 23, 0, 0).unwrap());
+        assert_eq!(series.dtend, Some(Utc.with_ymd_and_hms(2026, 4, 8, 0, 30, 0).unwrap()));
+        assert_eq!(series.rrule.as_deref(), Some("FREQ=WEEKLY;BYDAY=TU"));
+        assert_eq!(
+            series.exdates,
+            vec![
+                NaiveDate::from_ymd_opt(2026, 4, 14).unwrap(),
+                NaiveDate::from_ymd_opt(2026, 4, 21).unwrap(),
+            ]
+        );
+        assert_eq!(
+            series.description.as_deref(),
+            Some("Bring a bag, please.\nNo ID required.")
+        );
+        assert_eq!(series.timezone, "America/Chicago");
+
+        let picnic = &calendar.events[1];
+        assert!(picnic.is_all_day && picnic.cancelled);
+
+        assert_eq!(parse_calendar("<html>"), Err(IcalParseError::NotCalendar));
+    }
+
+    #[test]
+    fn rendered_calendar_parses_back() {
+        let s = schedule(Some("FREQ=WEEKLY;BYDAY=TU"), Some("2026-04-14"));
+        let calendar = parse_calendar(&render(&s)).unwrap();
+        let event = &calendar.events[0];
+        assert_eq!(event.uid, s.id.to_string());
+        assert_eq!(Some(event.dtstart), s.dtstart);
+        assert_eq!(event.exdates, s.exdate_dates());
+        assert_eq!(event.location.as_deref(), Some("St. Mark's, 1st floor"));
+    }
+}
@@ -1,3 +1,4 @@
+pub mod ical;
 pub mod loader;
 pub mod models;
 
@@ -1,7 +1,8 @@
 use anyhow::Result;
-use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool};
 use typed_builder::TypedBuilder;
 use uuid::Uuid;
 
@@ -33,6 +34,8 @@ pub struct Schedule {
     pub is_all_day: bool,
     pub duration_minutes: Option<i32>,
     pub updated_at: DateTime<Utc>,
+    /// iCalendar SEQUENCE; bumped by every `update`.
+    pub sequence: i32,
 }
 
 // =============================================================================
@@ -73,6 +76,10 @@ pub struct CreateRecurringSchedule<'a> {
     pub day_of_week: Option<i32>,
     #[builder(default)]
     pub notes: Option<&'a str>,
+    #[builder(default)]
+    pub exdates: Option<&'a str>,
+    #[builder(default = false)]
+    pub is_all_day: bool,
 }
 
 #[derive(TypedBuilder)]
@@ -158,58 +165,109 @@ impl Schedule {
     /// Expand this schedule's next N occurrences using rrule (computed, not stored).
     pub fn next_occurrences(&self, limit: usize) -> Vec<DateTime<Utc>> {
         let now = Utc::now();
-        let window_end = now + Duration::days(90);
 
         // One-off event: dtstart set, no rrule
         if self.dtstart.is_some() && self.rrule.is_none() {
             return self.dtstart.into_iter().filter(|d| *d >= now).collect();
         }
 
+        self.occurrences_between(now, now + Duration::days(90), limit)
+    }
+
+    /// Occurrences in `[from, to]`, at most `limit`. Recurring schedules
+    /// are expanded from `dtstart` (or `from` when unset) in the schedule's
+    /// own timezone, so a 7pm event stays at 7pm across DST changes. They
+    /// are clipped to `valid_from`/`valid_to` and have `exdates` removed,
+    /// both read as local calendar days.
+    pub fn occurrences_between(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        limit: usize,
+    ) -> Vec<DateTime<Utc>> {
         let Some(ref rrule_str) = self.rrule else {
-            return vec![];
+            return self
+                .dtstart
+                .into_iter()
+                .filter(|d| *d >= from && *d <= to)
+                .collect();
         };
 
-        let full = if let Some(dtstart) = self.dtstart {
-            format!(
-                "DTSTART:{}\nRRULE:{}",
-                dtstart.format("%Y%m%dT%H%M%SZ"),
-                rrule_str
-            )
-        } else {
-            format!(
-                "DTSTART:{}\nRRULE:{}",
-                now.format("%Y%m%dT%H%M%SZ"),
-                rrule_str
-            )
+        let tz = self.tz();
+        let from = match self.valid_from.and_then(|d| d.and_hms_opt(0, 0, 0)) {
+            Some(valid_from) => from.max(local_to_utc(tz, valid_from)),
+            None => from,
         };
+        let to = match self.valid_to.and_then(|d| d.and_hms_opt(23, 59, 59)) {
+            Some(valid_to) => to.min(local_to_utc(tz, valid_to)),
+            None => to,
+        };
+        if from > to {
+            return vec![];
+        }
+
+        let full = format!(
+            "DTSTART;TZID={}:{}\nRRULE:{}",
+            tz.name(),
+            self.dtstart
+                .unwrap_or(from)
+                .with_timezone(&tz)
+                .format("%Y%m%dT%H%M%S"),
+            rrule_str.trim_start_matches("RRULE:")
+        );
 
         let Ok(rrule_set) = full.parse::<rrule::RRuleSet>() else {
             return vec![];
         };
 
-        let start = now.with_timezone(&rrule::Tz::UTC);
-        let end = window_end.with_timezone(&rrule::Tz::UTC);
-
-        let result = rrule_set.after(start).before(end).all(limit as u16);
+        let start = from.with_timezone(&rrule::Tz::UTC);
+        let end = to.with_timezone(&rrule::Tz::UTC);
 
-        // Apply exdates filter
-        let exdates = parse_exdates(&self.exdates);
+        let exdates = self.exdate_dates();
 
-        result
-            .dates
+        // Skip exdates before applying `limit`, so an excluded day doesn't
+        // use up one of the occurrences asked for.
+        rrule_set
             .into_iter()
-            .filter(|dt| {
-                let utc_dt: DateTime<Utc> = dt.with_timezone(&Utc);
-                !exdates
-                    .iter()
-                    .any(|ex| ex.date_naive() == utc_dt.date_naive())
-            })
+            .skip_while(|dt| *dt < start)
+            .take_while(|dt| *dt <= end)
+            .filter(|dt| !exdates.contains(&dt.with_timezone(&tz).date_naive()))
+            .take(limit)
             .map(|d| d.with_timezone(&Utc))
             .collect()
     }
 
+    /// Exception dates, as calendar days in the schedule's timezone.
+    pub fn exdate_dates(&self) -> Vec<NaiveDate> {
+        parse_exdates(&self.exdates, self.tz())
+    }
+
+    /// Exception dates as the instant the series would have started on
+    /// each: the date at `dtstart`'s wall-clock time in the schedule's
+    /// timezone.
+    pub fn exdate_starts(&self) -> Vec<DateTime<Utc>> {
+        let Some(dtstart) = self.dtstart else {
+            return vec![];
+        };
+        let tz = self.tz();
+        let time = dtstart.with_timezone(&tz).time();
+        self.exdate_dates()
+            .into_iter()
+            .map(|date| local_to_utc(tz, date.and_time(time)))
+            .collect()
+    }
+
+    /// The schedule's timezone; America/Chicago when the stored name
+    /// doesn't parse.
+    fn tz(&self) -> Tz {
+        self.timezone.parse().unwrap_or(chrono_tz::America::Chicago)
+    }
+
     /// Create a one-off event schedule (e.g. workshop on Mar 15 2-4pm)
-    pub async fn create_one_off(params: &CreateOneOffSchedule<'_>, pool: &PgPool) -> Result<Self> {
+    pub async fn create_one_off(
+        params: &CreateOneOffSchedule<'_>,
+        db: impl PgExecutor<'_>,
+    ) -> Result<Self> {
         sqlx::query_as::<_, Self>(
             r#"
             INSERT INTO schedules (
@@ -227,7 +285,7 @@ impl Schedule {
         .bind(params.is_all_day)
         .bind(params.timezone)
         .bind(params.notes)
-        .fetch_one(pool)
+        .fetch_one(db)
         .await
         .map_err(Into::into)
     }
@@ -235,16 +293,16 @@ impl Schedule {
     /// Create a recurring event schedule (e.g. ESL class every Tue 6-8pm)
     pub async fn create_recurring(
         params: &CreateRecurringSchedule<'_>,
-        pool: &PgPool,
+        db: impl PgExecutor<'_>,
     ) -> Result<Self> {
         sqlx::query_as::<_, Self>(
             r#"
             INSERT INTO schedules (
                 schedulable_type, schedulable_id, dtstart, rrule,
                 duration_minutes, opens_at, closes_at, day_of_week,
-                timezone, notes
+                timezone, notes, exdates, is_all_day
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
             RETURNING *
             "#,
         )
@@ -258,7 +316,9 @@ impl Schedule {
         .bind(params.day_of_week)
         .bind(params.timezone)
         .bind(params.notes)
-        .fetch_one(pool)
+        .bind(params.exdates)
+        .bind(params.is_all_day)
+        .fetch_one(db)
         .await
         .map_err(Into::into)
     }
@@ -308,6 +368,7 @@ impl Schedule {
         .map_err(Into::into)
     }
 
+    /// Apply a partial update and bump `sequence`.
     pub async fn update(
         id: ScheduleId,
         params: &UpdateScheduleParams<'_>,
@@ -327,6 +388,7 @@ impl Schedule {
                 duration_minutes = COALESCE($10, duration_minutes),
                 timezone = COALESCE($11, timezone),
                 notes = COALESCE($12, notes),
+                sequence = sequence + 1,
                 updated_at = NOW()
             WHERE id = $1
             RETURNING *
@@ -373,7 +435,7 @@ impl Schedule {
 }
 
 /// Parse exdates from comma-separated ISO date strings.
-fn parse_exdates(exdates: &Option<String>) -> Vec<DateTime<Utc>> {
+fn parse_exdates(exdates: &Option<String>, tz: Tz) -> Vec<NaiveDate> {
     let Some(ref s) = exdates else {
         return vec![];
     };
@@ -383,11 +445,101 @@ fn parse_exdates(exdates: &Option<String>) -> Vec<DateTime<Utc>> {
             if trimmed.is_empty() {
                 return None;
             }
-            trimmed.parse::<DateTime<Utc>>().ok().or_else(|| {
-                chrono::NaiveDate::parse_from_str(trimmed, "%Y-%m-%d")
-                    .ok()
-                    .and_then(|d| d.and_hms_opt(0, 0, 0).map(|dt| dt.and_utc()))
-            })
+            trimmed
+                .parse::<DateTime<Utc>>()
+                .ok()
+                .map(|dt| dt.with_timezone(&tz).date_naive())
+                .or_else(|| NaiveDate::parse_from_str(trimmed, "%Y-%m-%d").ok())
         })
         .collect()
 }
+
+/// A local wall-clock time as UTC. A time skipped by a DST jump resolves
+/// to the hour after; an ambiguous one to its first occurrence.
+fn local_to_utc(tz: Tz, local: NaiveDateTime) -> DateTime<Utc> {
+    tz.from_local_datetime(&local)
+        .earliest()
+        .or_else(|| {
+            tz.from_local_datetime(&(local + Duration::hours(1)))
+                .earliest()
+        })
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_else(|| local.and_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Datelike, TimeZone};
+
+    fn weekly(exdates: Option<&str>, valid_to: Option<NaiveDate>) -> Schedule {
+        let dtstart = Utc.with_ymd_and_hms(2026, 3, 3, 23, 0, 0).unwrap();
+        Schedule {
+            id: ScheduleId::new(),
+            schedulable_type: "post".to_string(),
+            schedulable_id: Uuid::new_v4(),
+            day_of_week: None,
+            opens_at: None,
+            closes_at: None,
+            timezone: "America/Chicago".to_string(),
+            valid_from: None,
+            valid_to,
+            notes: None,
+            created_at: dtstart,
+            dtstart: Some(dtstart),
+            dtend: None,
+            rrule: Some("FREQ=WEEKLY;BYDAY=TU".to_string()),
+            exdates: exdates.map(str::to_string),
+            is_all_day: false,
+            duration_minutes: Some(120),
+            updated_at: dtstart,
+            sequence: 0,
+        }
+    }
+
+    #[test]
+    fn occurrences_between_expands_rrule_and_skips_exdates() {
+        let from = Utc.with_ymd_and_hms(2026, 4, 1, 0, 0, 0).unwrap();
+        let to = from + Duration::weeks(4);
+
+        let all = weekly(None, None).occurrences_between(from, to, 10);
+        let days: Vec<u32> = all.iter().map(|d| d.day()).collect();
+        assert_eq!(days, vec![7, 14, 21, 28]);
+
+        let skipped = weekly(Some("2026-04-14"), None).occurrences_between(from, to, 10);
+        assert_eq!(skipped.len(), 3);
+        assert!(skipped.iter().all(|d| d.day() != 14));
+
+        let limited = weekly(Some("2026-04-07"), None).occurrences_between(from, to, 2);
+        let days: Vec<u32> = limited.iter().map(|d| d.day()).collect();
+        assert_eq!(days, vec![14, 21]);
+
+        let ended = weekly(None, NaiveDate::from_ymd_opt(2026, 4, 10))
+            .occurrences_between(from, to, 10);
+        assert_eq!(ended.len(), 1);
+        assert!(weekly(None, None).occurrences_between(to, from, 10).is_empty());
+    }
+
+    #[test]
+    fn occurrences_keep_local_time_across_dst() {
+        // 6pm Tuesdays in Chicago (23:00 UTC in CDT, 00:00 Wednesday UTC
+        // in CST), across the Nov 1 2026 fall-back.
+        let mut s = weekly(Some("2026-11-03"), None);
+        s.dtstart = Some(Utc.with_ymd_and_hms(2026, 10, 20, 23, 0, 0).unwrap());
+        s.valid_to = NaiveDate::from_ymd_opt(2026, 11, 10);
+        let from = Utc.with_ymd_and_hms(2026, 10, 15, 0, 0, 0).unwrap();
+        let to = from + Duration::weeks(6);
+
+        let all = s.occurrences_between(from, to, 10);
+        let chicago = chrono_tz::America::Chicago;
+        let local: Vec<String> = all
+            .iter()
+            .map(|d| d.with_timezone(&chicago).format("%m-%d %H:%M").to_string())
+            .collect();
+        // Nov 3 is skipped by its local date even though the occurrence
+        // falls on Nov 4 in UTC; Nov 10 is kept through the end of the
+        // local valid_to day.
+        assert_eq!(local, vec!["10-20 18:00", "10-27 18:00", "11-10 18:00"]);
+        assert_eq!(all[2], Utc.with_ymd_and_hms(2026, 11, 11, 0, 0, 0).unwrap());
+    }
+}
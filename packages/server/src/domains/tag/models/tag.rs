@@ -1,7 +1,7 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool};
 use uuid::Uuid;
 
 use crate::common::{OrganizationId, PostId, TagId, TaggableId};
@@ -328,8 +328,12 @@ impl Tag {
 
 impl Taggable {
     /// Associate a tag with a post
-    pub async fn create_post_tag(post_id: PostId, tag_id: TagId, pool: &PgPool) -> Result<Self> {
-        Self::create(tag_id, "post", post_id.as_uuid(), pool).await
+    pub async fn create_post_tag(
+        post_id: PostId,
+        tag_id: TagId,
+        db: impl PgExecutor<'_>,
+    ) -> Result<Self> {
+        Self::create(tag_id, "post", post_id.as_uuid(), db).await
     }
 
     /// Generic create method
@@ -337,7 +341,7 @@ impl Taggable {
         tag_id: TagId,
         taggable_type: &str,
         taggable_id: &Uuid,
-        pool: &PgPool,
+        db: impl PgExecutor<'_>,
     ) -> Result<Self> {
         let taggable = sqlx::query_as::<_, Taggable>(
             r#"
@@ -351,7 +355,7 @@ impl Taggable {
         .bind(tag_id)
         .bind(taggable_type)
         .bind(taggable_id)
-        .fetch_one(pool)
+        .fetch_one(db)
         .await?;
         Ok(taggable)
     }
@@ -10,9 +10,9 @@ use std::sync::Arc;
 use twilio::TwilioService;
 
 use crate::common::auth::HasAuthContext;
-use crate::domains::auth::JwtService;
+use crate::domains::auth::{JwtService, TokenRevocationCache};
 use crate::kernel::{
-    stream_hub::StreamHub, BasePiiDetector, BaseStorageService,
+    rate_limit::TrustedProxies, stream_hub::StreamHub, BaseGeocoder, BasePiiDetector, BaseRateLimiter, BaseStorageService,
     BaseTwilioService,
 };
 
@@ -39,9 +39,17 @@ impl BaseTwilioService for TwilioAdapter {
             .map_err(|e| anyhow::anyhow!("{}", e))
     }
 
-    async fn verify_otp(&self, phone_number: &str, code: &str) -> Result<()> {
+    async fn send_email_otp(&self, email: &str) -> Result<()> {
         self.0
-            .verify_otp(phone_number, code)
+            .send_email_otp(email)
+            .await
+            .map(|_| ())
+            .map_err(|e| anyhow::anyhow!("{}", e))
+    }
+
+    async fn verify_otp(&self, recipient: &str, code: &str) -> Result<bool> {
+        self.0
+            .verify_otp(recipient, code)
             .await
             .map_err(|e| anyhow::anyhow!("{}", e))
     }
@@ -57,10 +65,19 @@ pub struct ServerDeps {
     pub db_pool: PgPool,
     pub twilio: Arc<dyn BaseTwilioService>,
     pub pii_detector: Arc<dyn BasePiiDetector>,
+    /// Counters and lockouts for the OTP endpoints
+    pub rate_limiter: Arc<dyn BaseRateLimiter>,
+    /// Proxies whose forwarding headers name the client IP (none by default)
+    pub trusted_proxies: TrustedProxies,
+    /// Free-text location → coordinates (offline gazetteer by default)
+    pub geocoder: Arc<dyn BaseGeocoder>,
     /// S3-compatible storage for media uploads
     pub storage: Option<Arc<dyn BaseStorageService>>,
     /// JWT service for token creation
     pub jwt_service: Arc<JwtService>,
+    /// Cache in front of the `revoked_tokens` list, checked on every
+    /// authenticated request
+    pub token_revocations: Arc<TokenRevocationCache>,
     /// In-process pub/sub hub for real-time streaming to SSE endpoints
     pub stream_hub: StreamHub,
     pub test_identifier_enabled: bool,
@@ -74,6 +91,8 @@ impl ServerDeps {
         db_pool: PgPool,
         twilio: Arc<dyn BaseTwilioService>,
         pii_detector: Arc<dyn BasePiiDetector>,
+        rate_limiter: Arc<dyn BaseRateLimiter>,
+        geocoder: Arc<dyn BaseGeocoder>,
         storage: Option<Arc<dyn BaseStorageService>>,
         jwt_service: Arc<JwtService>,
         stream_hub: StreamHub,
@@ -84,13 +103,23 @@ impl ServerDeps {
             db_pool,
             twilio,
             pii_detector,
+            rate_limiter,
+            trusted_proxies: TrustedProxies::default(),
+            geocoder,
             storage,
             jwt_service,
+            token_revocations: Arc::new(TokenRevocationCache::new()),
             stream_hub,
             test_identifier_enabled,
             admin_identifiers,
         }
     }
+
+    /// Trust `X-Forwarded-For`/`X-Real-IP` from these proxies.
+    pub fn with_trusted_proxies(mut self, trusted_proxies: TrustedProxies) -> Self {
+        self.trusted_proxies = trusted_proxies;
+        self
+    }
 }
 
 /// Implement HasAuthContext for ServerDeps to enable authorization checks
@@ -0,0 +1,333 @@
+//! Offline geocoder — implements `BaseGeocoder` without a network call.
+//!
+//! Resolves free-text locations against the bundled gazetteer
+//! (`mn_places.tsv` — landmarks, neighborhoods, tribal nations and regions
+//! a ZIP or city can't express, matched as whole-word phrases anywhere in
+//! the text) and the `zip_codes` table. First hit wins:
+//!
+//! 1. A gazetteer landmark ("Como Park Zoo").
+//! 2. A 5-digit ZIP in the text.
+//! 3. A gazetteer area — neighborhood or tribal nation ("Frogtown").
+//! 4. A city name from one of the comma-separated segments ("Brainerd, MN").
+//! 5. A gazetteer region ("Iron Range").
+//!
+//! Every match carries a ZIP (the nearest one for gazetteer places) so
+//! proximity search, which joins `zip_codes`, can find it.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::PgPool;
+use std::sync::Arc;
+
+use super::{BaseGeocoder, GeocodeMatch, GeocodePrecision};
+use crate::domains::locations::models::ZipCode;
+
+const GAZETTEER_TSV: &str = include_str!("mn_places.tsv");
+
+/// Confidence per precision, in the order `geocode` tries them. A ZIP beats
+/// a named area because it is what the text literally said; a region is
+/// barely better than nothing.
+fn confidence(precision: GeocodePrecision) -> f64 {
+    match precision {
+        GeocodePrecision::Landmark => 0.85,
+        GeocodePrecision::PostalCode => 0.7,
+        GeocodePrecision::Area => 0.6,
+        GeocodePrecision::City => 0.55,
+        GeocodePrecision::Region => 0.3,
+    }
+}
+
+/// Lowercase, drop `.`/`'`, turn other punctuation into spaces, collapse
+/// whitespace, and expand the abbreviations place names use ("St. Paul",
+/// "Ft. Snelling", "Mpls"). Abbreviations are only expanded mid-segment,
+/// so the "St" ending "416 S 5th St, Brainerd" stays a street.
+pub fn normalize(text: &str) -> String {
+    text.split(',')
+        .map(normalize_segment)
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn normalize_segment(segment: &str) -> String {
+    let cleaned: String = segment
+        .to_lowercase()
+        .chars()
+        .filter(|c| *c != '.' && *c != '\'')
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect();
+    let tokens: Vec<&str> = cleaned.split_whitespace().collect();
+    tokens
+        .iter()
+        .enumerate()
+        .map(|(i, &t)| {
+            let next_is_word = tokens
+                .get(i + 1)
+                .is_some_and(|n| n.chars().all(char::is_alphabetic));
+            let after_number = i > 0 && tokens[i - 1].chars().any(|c| c.is_ascii_digit());
+            match t {
+                "st" if next_is_word && !after_number => "saint",
+                "ft" if next_is_word && !after_number => "fort",
+                "mpls" => "minneapolis",
+                _ => t,
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// First standalone 5-digit number (ZIP+4 suffixes are ignored).
+fn extract_zip(text: &str) -> Option<&str> {
+    let re = regex::Regex::new(r"\b(\d{5})(?:-\d{4})?\b").ok()?;
+    re.captures(text).and_then(|c| c.get(1)).map(|m| m.as_str())
+}
+
+/// Candidate city names from comma-separated segments, last segment first
+/// (the city usually follows the name and street). State names and ZIPs
+/// are stripped; segments starting with a digit are street addresses.
+fn city_candidates(text: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    for segment in text.split(',').rev() {
+        let normalized = normalize(segment);
+        if normalized.starts_with(|c: char| c.is_ascii_digit()) {
+            continue;
+        }
+        let words: Vec<&str> = normalized
+            .split_whitespace()
+            .filter(|w| !matches!(*w, "mn" | "minnesota" | "usa" | "us"))
+            .filter(|w| !w.chars().all(|c| c.is_ascii_digit()))
+            .collect();
+        if words.is_empty() {
+            continue;
+        }
+        let name = words.join(" ");
+        if !out.contains(&name) {
+            out.push(name);
+        }
+    }
+    out
+}
+
+#[derive(Debug, Clone)]
+struct Place {
+    /// Normalized names, each padded with spaces for whole-word matching.
+    names: Vec<String>,
+    precision: GeocodePrecision,
+    latitude: f64,
+    longitude: f64,
+    city: Option<String>,
+}
+
+/// The bundled place-name list.
+#[derive(Debug, Clone)]
+pub struct Gazetteer {
+    places: Vec<Place>,
+}
+
+impl Gazetteer {
+    pub fn parse(tsv: &str) -> Result<Self> {
+        let mut places = Vec::new();
+        for (i, line) in tsv.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let cols: Vec<&str> = line.split('\t').collect();
+            let bad = || anyhow::anyhow!("gazetteer line {}: {:?}", i + 1, line);
+            if cols.len() < 4 {
+                return Err(bad());
+            }
+            let precision = match cols[1] {
+                "landmark" => GeocodePrecision::Landmark,
+                "area" => GeocodePrecision::Area,
+                "region" => GeocodePrecision::Region,
+                _ => return Err(bad()),
+            };
+            places.push(Place {
+                names: cols[0]
+                    .split('|')
+                    .map(|n| format!(" {} ", normalize(n)))
+                    .collect(),
+                precision,
+                latitude: cols[2].parse().map_err(|_| bad())?,
+                longitude: cols[3].parse().map_err(|_| bad())?,
+                city: cols.get(4).filter(|c| !c.is_empty()).map(|c| c.to_string()),
+            });
+        }
+        Ok(Self { places })
+    }
+
+    pub fn bundled() -> Self {
+        Self::parse(GAZETTEER_TSV).expect("bundled gazetteer parses")
+    }
+
+    /// Best place named in the text: the most precise, then the longest
+    /// matching name ("Minneapolis Central Library" over "Minneapolis").
+    fn lookup(&self, text: &str) -> Option<&Place> {
+        let haystack = format!(" {} ", normalize(text));
+        self.places
+            .iter()
+            .filter_map(|p| {
+                let longest = p
+                    .names
+                    .iter()
+                    .filter(|n| haystack.contains(n.as_str()))
+                    .map(String::len)
+                    .max()?;
+                Some((p, longest))
+            })
+            .max_by(|(a, a_len), (b, b_len)| {
+                confidence(a.precision)
+                    .total_cmp(&confidence(b.precision))
+                    .then(a_len.cmp(b_len))
+            })
+            .map(|(p, _)| p)
+    }
+}
+
+/// `BaseGeocoder` over the bundled gazetteer and the `zip_codes` table.
+pub struct OfflineGeocoder {
+    pool: PgPool,
+    gazetteer: Gazetteer,
+}
+
+impl OfflineGeocoder {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            gazetteer: Gazetteer::bundled(),
+        }
+    }
+
+    async fn place_match(&self, place: &Place) -> Result<GeocodeMatch> {
+        let nearest = ZipCode::find_nearest(place.latitude, place.longitude, &self.pool).await?;
+        Ok(GeocodeMatch {
+            latitude: place.latitude,
+            longitude: place.longitude,
+            city: place
+                .city
+                .clone()
+                .or_else(|| nearest.as_ref().map(|z| z.city.clone())),
+            state: Some("MN".to_string()),
+            postal_code: nearest.map(|z| z.zip_code),
+            precision: place.precision,
+            confidence: confidence(place.precision),
+        })
+    }
+}
+
+#[async_trait]
+impl BaseGeocoder for OfflineGeocoder {
+    async fn geocode(&self, query: &str) -> Result<Option<GeocodeMatch>> {
+        let place = self.gazetteer.lookup(query);
+        if let Some(landmark) = place.filter(|p| p.precision == GeocodePrecision::Landmark) {
+            return self.place_match(landmark).await.map(Some);
+        }
+
+        if let Some(zip) = extract_zip(query) {
+            if let Some(z) = ZipCode::find_by_code(zip, &self.pool).await? {
+                return Ok(Some(GeocodeMatch {
+                    latitude: z.latitude,
+                    longitude: z.longitude,
+                    city: Some(z.city),
+                    state: Some(z.state),
+                    postal_code: Some(z.zip_code),
+                    precision: GeocodePrecision::PostalCode,
+                    confidence: confidence(GeocodePrecision::PostalCode),
+                }));
+            }
+        }
+
+        if let Some(area) = place.filter(|p| p.precision == GeocodePrecision::Area) {
+            return self.place_match(area).await.map(Some);
+        }
+
+        let candidates = city_candidates(query);
+        if !candidates.is_empty() {
+            let centroids = ZipCode::find_city_centroids(&candidates, &self.pool).await?;
+            let best = candidates
+                .iter()
+                .find_map(|name| centroids.iter().find(|c| &c.normalized == name));
+            if let Some(c) = best {
+                return Ok(Some(GeocodeMatch {
+                    latitude: c.latitude,
+                    longitude: c.longitude,
+                    city: Some(c.city.clone()),
+                    state: Some(c.state.clone()),
+                    postal_code: Some(c.zip_code.clone()),
+                    precision: GeocodePrecision::City,
+                    confidence: confidence(GeocodePrecision::City),
+                }));
+            }
+        }
+
+        match place {
+            Some(region) => self.place_match(region).await.map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Geocoder that never matches. Used when geocoding is disabled via config.
+pub struct NoopGeocoder;
+
+#[async_trait]
+impl BaseGeocoder for NoopGeocoder {
+    async fn geocode(&self, _query: &str) -> Result<Option<GeocodeMatch>> {
+        Ok(None)
+    }
+}
+
+/// Create the geocoder based on configuration.
+pub fn create_geocoder(enabled: bool, pool: PgPool) -> Arc<dyn BaseGeocoder> {
+    if !enabled {
+        tracing::info!("Geocoding disabled");
+        return Arc::new(NoopGeocoder);
+    }
+    tracing::info!("Geocoding enabled (offline gazetteer + zip_codes)");
+    Arc::new(OfflineGeocoder::new(pool))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_expands_saint_but_not_street() {
+        assert_eq!(normalize("St. Paul, MN"), "saint paul mn");
+        assert_eq!(normalize("416 S 5th St, Brainerd"), "416 s 5th st brainerd");
+        assert_eq!(normalize("Ft. Snelling"), "fort snelling");
+        assert_eq!(normalize("U.S. Bank Stadium"), "us bank stadium");
+    }
+
+    #[test]
+    fn city_candidates_skip_streets_states_and_zips() {
+        assert_eq!(
+            city_candidates("Brainerd Public Library, 416 S 5th St, Brainerd, MN 56401"),
+            vec!["brainerd", "brainerd public library"]
+        );
+        assert_eq!(city_candidates("St. Cloud MN"), vec!["saint cloud"]);
+    }
+
+    #[test]
+    fn extract_zip_ignores_plus_four() {
+        assert_eq!(extract_zip("Duluth, MN 55802-1234"), Some("55802"));
+        assert_eq!(extract_zip("Suite 1200"), None);
+    }
+
+    #[test]
+    fn bundled_gazetteer_prefers_precise_longest_match() {
+        let g = Gazetteer::bundled();
+        let p = g.lookup("Story time at Como Park Zoo, Saint Paul").unwrap();
+        assert_eq!(p.precision, GeocodePrecision::Landmark);
+        assert_eq!(p.city.as_deref(), Some("Saint Paul"));
+
+        let p = g.lookup("Community dinner in Frogtown").unwrap();
+        assert_eq!(p.precision, GeocodePrecision::Area);
+
+        let p = g.lookup("Food shelves across the Iron Range and Twin Cities").unwrap();
+        assert_eq!(p.precision, GeocodePrecision::Region);
+
+        // Whole words only: "Midwayne" is not Midway.
+        assert!(g.lookup("Midwayne Ave").is_none());
+    }
+}
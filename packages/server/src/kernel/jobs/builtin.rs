@@ -0,0 +1,191 @@
+//! The maintenance jobs the server binary runs out of the box.
+
+use anyhow::Result;
+
+use super::job_run::JobRun;
+use super::registry::{JobDefinition, JobRegistry, JobScope};
+use crate::domains::auth::models::{AuthSession, RevokedToken};
+use crate::domains::editions::activities::publish_scheduled_editions;
+use crate::domains::member::models::member::Member;
+use crate::domains::organization::models::OrganizationCalendarFeed;
+use crate::domains::posts::activities::expire_scheduled_posts::expire_scheduled_posts;
+use crate::domains::posts::activities::near_duplicate;
+use crate::domains::posts::models::api_idempotency_key::ApiIdempotencyKey;
+use crate::kernel::rate_limit::PostgresRateLimiter;
+use crate::kernel::work_queue::{WorkItem, WorkRequest};
+
+/// How long `job_runs` history is kept.
+const JOB_RUN_RETENTION_DAYS: i32 = 30;
+/// A running work item whose worker hasn't reported back in this long is
+/// assumed dead and put back on the queue.
+const WORK_ITEM_LEASE_MINUTES: i32 = 15;
+/// How long succeeded work items are kept.
+const WORK_ITEM_RETENTION_DAYS: i32 = 14;
+/// How long expired or revoked sessions are kept (for audit).
+const AUTH_SESSION_RETENTION_DAYS: i32 = 30;
+
+/// Security events are kept this long; idle lockout rows for a day.
+const AUTH_SECURITY_EVENT_RETENTION_DAYS: i64 = 90;
+
+/// Build the registry of built-in jobs. Schedules are UTC.
+pub fn builtin_registry() -> Result<JobRegistry> {
+    let mut registry = JobRegistry::new();
+
+    registry.register(JobDefinition::new(
+        "expire_scheduled_posts",
+        "Expire posts whose schedules have all passed",
+        "0 5 * * * *",
+        JobScope::Cluster,
+        |deps| async move { expire_scheduled_posts(&deps).await },
+    )?)?;
+
+    registry.register(JobDefinition::new(
+        "publish_scheduled_editions",
+        "Publish approved editions whose publish_at has passed",
+        "0 * * * * *",
+        JobScope::Cluster,
+        |deps| async move { publish_scheduled_editions(&deps).await },
+    )?)?;
+
+    registry.register(JobDefinition::new(
+        "prune_idempotency_keys",
+        "Delete Idempotency-Key cache entries older than 24 hours",
+        "0 20 * * * *",
+        JobScope::Cluster,
+        |deps| async move { ApiIdempotencyKey::prune_older_than_24h(&deps.db_pool).await },
+    )?)?;
+
+    registry.register(JobDefinition::new(
+        "reset_weekly_counts",
+        "Reset every member's weekly notification counter (Mondays 00:00 UTC)",
+        "0 0 0 * * Mon",
+        JobScope::Cluster,
+        |deps| async move { Member::reset_weekly_counts(&deps.db_pool).await },
+    )?)?;
+
+    registry.register(JobDefinition::new(
+        "stream_hub_cleanup",
+        "Drop StreamHub channels with no remaining subscribers (per replica)",
+        "0 */5 * * * *",
+        JobScope::Local,
+        |deps| async move {
+            deps.stream_hub.cleanup().await;
+            Ok(0)
+        },
+    )?)?;
+
+    registry.register(JobDefinition::new(
+        "prune_job_runs",
+        "Delete job run history older than 30 days",
+        "0 40 3 * * *",
+        JobScope::Cluster,
+        |deps| async move { JobRun::prune_older_than_days(JOB_RUN_RETENTION_DAYS, &deps.db_pool).await },
+    )?)?;
+
+    registry.register(JobDefinition::new(
+        "requeue_stale_work_items",
+        "Return work items whose worker lease expired to the queue",
+        "0 * * * * *",
+        JobScope::Cluster,
+        |deps| async move { WorkItem::requeue_stale(WORK_ITEM_LEASE_MINUTES, &deps.db_pool).await },
+    )?)?;
+
+    registry.register(JobDefinition::new(
+        "prune_work_items",
+        "Delete succeeded work items older than 14 days",
+        "0 50 3 * * *",
+        JobScope::Cluster,
+        |deps| async move {
+            WorkItem::prune_succeeded_older_than_days(WORK_ITEM_RETENTION_DAYS, &deps.db_pool).await
+        },
+    )?)?;
+
+    registry.register(JobDefinition::new(
+        "prune_auth_sessions",
+        "Delete expired token revocations and sessions ended more than 30 days ago",
+        "0 10 4 * * *",
+        JobScope::Cluster,
+        |deps| async move {
+            let tokens = RevokedToken::prune_expired(&deps.db_pool).await?;
+            let sessions =
+                AuthSession::prune_older_than_days(AUTH_SESSION_RETENTION_DAYS, &deps.db_pool)
+                    .await?;
+            Ok(tokens + sessions)
+        },
+    )?)?;
+
+    registry.register(JobDefinition::new(
+        "prune_rate_limits",
+        "Delete ended rate-limit windows, idle lockouts and old security events",
+        "0 */15 * * * *",
+        JobScope::Cluster,
+        |deps| async move {
+            PostgresRateLimiter::prune(
+                &deps.db_pool,
+                chrono::Duration::days(1),
+                AUTH_SECURITY_EVENT_RETENTION_DAYS,
+            )
+            .await
+        },
+    )?)?;
+
+    registry.register(JobDefinition::new(
+        "import_calendar_feeds",
+        "Queue an import of every active organization calendar feed",
+        "0 30 */6 * * *",
+        JobScope::Cluster,
+        |deps| async move {
+            let mut queued = 0;
+            for feed in OrganizationCalendarFeed::find_active(&deps.db_pool).await? {
+                let request = WorkRequest::ImportCalendarFeed {
+                    feed_id: feed.id.into_uuid(),
+                };
+                if request.enqueue(&deps.db_pool).await?.is_some() {
+                    queued += 1;
+                }
+            }
+            Ok(queued)
+        },
+    )?)?;
+
+    registry.register(JobDefinition::new(
+        "index_post_similarity",
+        "Compute near-duplicate signatures for active posts that lack one",
+        "0 */10 * * * *",
+        JobScope::Cluster,
+        |deps| async move {
+            let indexed = near_duplicate::index_unindexed(500, &deps.db_pool).await?;
+            Ok(indexed as u64)
+        },
+    )?)?;
+
+    Ok(registry)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_registry_builds() {
+        let registry = builtin_registry().expect("built-in schedules must parse");
+        let names: Vec<_> = registry.jobs().iter().map(|j| j.name).collect();
+        assert_eq!(
+            names,
+            vec![
+                "expire_scheduled_posts",
+                "publish_scheduled_editions",
+                "prune_idempotency_keys",
+                "reset_weekly_counts",
+                "stream_hub_cleanup",
+                "prune_job_runs",
+                "requeue_stale_work_items",
+                "prune_work_items",
+                "prune_auth_sessions",
+                "prune_rate_limits",
+                "import_calendar_feeds",
+                "index_post_similarity",
+            ]
+        );
+    }
+}
@@ -0,0 +1,128 @@
+//! Run history for scheduled jobs (`job_runs` table).
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// One execution of a registered job, scheduled or manual.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct JobRun {
+    pub id: Uuid,
+    pub job_name: String,
+    pub trigger: String,
+    pub scheduled_for: Option<DateTime<Utc>>,
+    pub status: String,
+    pub rows_affected: Option<i64>,
+    pub error: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+impl JobRun {
+    /// Record the start of a run. Returns `None` when a run for the same
+    /// `(job_name, scheduled_for)` already exists — another replica got to
+    /// this fire time first and the caller should skip.
+    pub async fn start(
+        job_name: &str,
+        trigger: &str,
+        scheduled_for: Option<DateTime<Utc>>,
+        pool: &PgPool,
+    ) -> Result<Option<Self>> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            INSERT INTO job_runs (job_name, trigger, scheduled_for)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (job_name, scheduled_for) WHERE scheduled_for IS NOT NULL
+            DO NOTHING
+            RETURNING *
+            "#,
+        )
+        .bind(job_name)
+        .bind(trigger)
+        .bind(scheduled_for)
+        .fetch_optional(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Mark a run as succeeded with the number of rows the job touched.
+    pub async fn succeed(id: Uuid, rows_affected: i64, pool: &PgPool) -> Result<Self> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            UPDATE job_runs
+            SET status = 'succeeded', rows_affected = $2, finished_at = NOW()
+            WHERE id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(rows_affected)
+        .fetch_one(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Mark a run as failed, keeping the error message for the admin UI.
+    pub async fn fail(id: Uuid, error: &str, pool: &PgPool) -> Result<Self> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            UPDATE job_runs
+            SET status = 'failed', error = $2, finished_at = NOW()
+            WHERE id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(error)
+        .fetch_one(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Most recent runs of a single job, newest first.
+    pub async fn find_recent(job_name: &str, limit: i64, pool: &PgPool) -> Result<Vec<Self>> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            SELECT * FROM job_runs
+            WHERE job_name = $1
+            ORDER BY started_at DESC
+            LIMIT $2
+            "#,
+        )
+        .bind(job_name)
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Latest run of every job that has ever run (one row per job_name).
+    pub async fn find_latest_per_job(pool: &PgPool) -> Result<Vec<Self>> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            SELECT DISTINCT ON (job_name) *
+            FROM job_runs
+            ORDER BY job_name, started_at DESC
+            "#,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Drop finished runs older than `days`. Returns number of rows removed.
+    pub async fn prune_older_than_days(days: i32, pool: &PgPool) -> Result<u64> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM job_runs
+            WHERE status <> 'running'
+              AND started_at < NOW() - make_interval(days => $1)
+            "#,
+        )
+        .bind(days)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,22 @@
+//! In-process job scheduler.
+//!
+//! Recurring maintenance work (expiring posts, pruning caches, resetting
+//! counters) registers here as named jobs on cron schedules. The server
+//! binary starts a `JobScheduler` over `builtin_registry()`; the admin
+//! `/Jobs/*` routes list jobs, show run history, and trigger runs.
+//!
+//! Cluster-scoped jobs are safe with several replicas: a Postgres advisory
+//! lock keeps runs mutually exclusive and the `job_runs` scheduled-once
+//! index ensures each fire time runs at most once.
+
+pub mod builtin;
+pub mod job_run;
+pub mod registry;
+pub mod runner;
+pub mod scheduler;
+
+pub use builtin::builtin_registry;
+pub use job_run::JobRun;
+pub use registry::{JobDefinition, JobRegistry, JobScope};
+pub use runner::{run_job, JobOutcome, JobTrigger};
+pub use scheduler::JobScheduler;
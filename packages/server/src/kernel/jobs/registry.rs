@@ -0,0 +1,165 @@
+//! Job definitions and the registry the scheduler iterates.
+
+use std::future::Future;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use futures::future::BoxFuture;
+use futures::FutureExt;
+
+use crate::kernel::ServerDeps;
+
+/// Where a job runs when several server replicas are up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobScope {
+    /// Exactly one replica runs each fire time. Guarded by a Postgres
+    /// advisory lock plus the `job_runs` scheduled-once index. Use for
+    /// anything that touches shared database state.
+    Cluster,
+    /// Every replica runs it against its own in-process state (e.g. the
+    /// StreamHub channel map). No lock, no fire-time dedup.
+    Local,
+}
+
+impl JobScope {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            JobScope::Cluster => "cluster",
+            JobScope::Local => "local",
+        }
+    }
+}
+
+/// Async job body. Returns the number of rows (or items) the run affected.
+pub type JobHandler = Arc<dyn Fn(Arc<ServerDeps>) -> BoxFuture<'static, Result<u64>> + Send + Sync>;
+
+/// A named recurring job.
+///
+/// Schedules use the 6-field cron syntax of the `cron` crate
+/// (`sec min hour day-of-month month day-of-week`), evaluated in UTC.
+#[derive(Clone)]
+pub struct JobDefinition {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub schedule_expr: &'static str,
+    pub scope: JobScope,
+    schedule: cron::Schedule,
+    handler: JobHandler,
+}
+
+impl JobDefinition {
+    pub fn new<F, Fut>(
+        name: &'static str,
+        description: &'static str,
+        schedule_expr: &'static str,
+        scope: JobScope,
+        handler: F,
+    ) -> Result<Self>
+    where
+        F: Fn(Arc<ServerDeps>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<u64>> + Send + 'static,
+    {
+        let schedule = cron::Schedule::from_str(schedule_expr)
+            .map_err(|e| anyhow!("Invalid schedule for job '{}': {}", name, e))?;
+        Ok(Self {
+            name,
+            description,
+            schedule_expr,
+            scope,
+            schedule,
+            handler: Arc::new(move |deps| handler(deps).boxed()),
+        })
+    }
+
+    /// Next fire time strictly after `after`, or `None` if the schedule
+    /// has no future occurrences.
+    pub fn next_run_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.schedule.after(&after).next()
+    }
+
+    /// Invoke the job body. Callers go through `runner::run_job` so locking
+    /// and run recording happen; this is only the raw work.
+    pub(super) fn invoke(&self, deps: Arc<ServerDeps>) -> BoxFuture<'static, Result<u64>> {
+        (self.handler)(deps)
+    }
+}
+
+impl std::fmt::Debug for JobDefinition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JobDefinition")
+            .field("name", &self.name)
+            .field("schedule_expr", &self.schedule_expr)
+            .field("scope", &self.scope)
+            .finish()
+    }
+}
+
+/// Ordered set of jobs, unique by name.
+#[derive(Debug, Clone, Default)]
+pub struct JobRegistry {
+    jobs: Vec<Arc<JobDefinition>>,
+}
+
+impl JobRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a job. Rejects duplicate names — the name is the advisory-lock
+    /// key and the `job_runs` grouping key, so it must be unique.
+    pub fn register(&mut self, job: JobDefinition) -> Result<()> {
+        if self.get(job.name).is_some() {
+            return Err(anyhow!("Job '{}' is already registered", job.name));
+        }
+        self.jobs.push(Arc::new(job));
+        Ok(())
+    }
+
+    pub fn get(&self, name: &str) -> Option<Arc<JobDefinition>> {
+        self.jobs.iter().find(|j| j.name == name).cloned()
+    }
+
+    pub fn jobs(&self) -> &[Arc<JobDefinition>] {
+        &self.jobs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn noop(name: &'static str, expr: &'static str) -> Result<JobDefinition> {
+        JobDefinition::new(name, "test job", expr, JobScope::Cluster, |_deps| async {
+            Ok(0)
+        })
+    }
+
+    #[test]
+    fn invalid_schedule_is_rejected() {
+        assert!(noop("bad", "every tuesday").is_err());
+    }
+
+    #[test]
+    fn duplicate_names_are_rejected() {
+        let mut registry = JobRegistry::new();
+        registry
+            .register(noop("dup", "0 0 * * * *").unwrap())
+            .unwrap();
+        assert!(registry
+            .register(noop("dup", "0 30 * * * *").unwrap())
+            .is_err());
+    }
+
+    #[test]
+    fn next_run_after_is_strictly_later() {
+        let job = noop("hourly", "0 5 * * * *").unwrap();
+        let at_fire_time = Utc.with_ymd_and_hms(2026, 4, 20, 10, 5, 0).unwrap();
+        assert_eq!(
+            job.next_run_after(at_fire_time),
+            Some(Utc.with_ymd_and_hms(2026, 4, 20, 11, 5, 0).unwrap())
+        );
+    }
+}
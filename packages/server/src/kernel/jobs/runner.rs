@@ -0,0 +1,148 @@
+//! Execute a single job: advisory lock → run record → body → outcome.
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use sqlx::pool::PoolConnection;
+use sqlx::Postgres;
+
+use super::job_run::JobRun;
+use super::registry::{JobDefinition, JobScope};
+use crate::kernel::ServerDeps;
+
+/// First key of the two-key advisory lock form. Keeps job locks in their
+/// own namespace so they can't collide with any other advisory lock user.
+const JOB_LOCK_NAMESPACE: i32 = 0x4a4f42; // "JOB"
+
+/// What caused a run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobTrigger {
+    Schedule,
+    Manual,
+}
+
+impl JobTrigger {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            JobTrigger::Schedule => "schedule",
+            JobTrigger::Manual => "manual",
+        }
+    }
+}
+
+/// Result of asking the runner to execute a job.
+#[derive(Debug)]
+pub enum JobOutcome {
+    /// The job ran (successfully or not — check `JobRun.status`).
+    Ran(JobRun),
+    /// Another replica holds the advisory lock for this job right now.
+    Locked,
+    /// A run for this fire time was already recorded by another replica.
+    AlreadyRan,
+}
+
+/// Run `job` once, recording the run in `job_runs`.
+///
+/// Cluster jobs take a session-level advisory lock on a dedicated pool
+/// connection for the duration of the run; if the lock is held elsewhere
+/// the call returns `JobOutcome::Locked` without running. The body is
+/// spawned onto its own task so a panic is recorded as a failed run
+/// instead of tearing down the scheduler loop.
+pub async fn run_job(
+    job: &JobDefinition,
+    trigger: JobTrigger,
+    scheduled_for: Option<DateTime<Utc>>,
+    deps: &Arc<ServerDeps>,
+) -> Result<JobOutcome> {
+    let pool = &deps.db_pool;
+
+    let lock_conn = match job.scope {
+        JobScope::Cluster => match try_lock(job.name, pool).await? {
+            Some(conn) => Some(conn),
+            None => return Ok(JobOutcome::Locked),
+        },
+        JobScope::Local => None,
+    };
+
+    // Fire-time dedup only applies to cluster jobs; local jobs legitimately
+    // run once per replica.
+    let dedup_key = match job.scope {
+        JobScope::Cluster => scheduled_for,
+        JobScope::Local => None,
+    };
+
+    let outcome = execute(job, trigger, dedup_key, deps).await;
+
+    if let Some(conn) = lock_conn {
+        unlock(job.name, conn).await;
+    }
+
+    outcome
+}
+
+async fn execute(
+    job: &JobDefinition,
+    trigger: JobTrigger,
+    scheduled_for: Option<DateTime<Utc>>,
+    deps: &Arc<ServerDeps>,
+) -> Result<JobOutcome> {
+    let pool = &deps.db_pool;
+
+    let run = match JobRun::start(job.name, trigger.as_str(), scheduled_for, pool).await? {
+        Some(run) => run,
+        None => return Ok(JobOutcome::AlreadyRan),
+    };
+
+    let body = job.invoke(deps.clone());
+    let result = match tokio::spawn(body).await {
+        Ok(result) => result,
+        Err(join_err) => Err(anyhow::anyhow!("job task panicked: {}", join_err)),
+    };
+
+    let finished = match result {
+        Ok(rows) => {
+            tracing::info!(
+                job = job.name,
+                trigger = trigger.as_str(),
+                rows_affected = rows,
+                "Job succeeded"
+            );
+            JobRun::succeed(run.id, rows as i64, pool).await?
+        }
+        Err(e) => {
+            tracing::error!(job = job.name, trigger = trigger.as_str(), error = %e, "Job failed");
+            JobRun::fail(run.id, &e.to_string(), pool).await?
+        }
+    };
+
+    Ok(JobOutcome::Ran(finished))
+}
+
+/// Try to take the job's advisory lock. Returns the connection holding it
+/// (session locks belong to a connection, so it must stay checked out
+/// until `unlock`), or `None` if another session has it.
+async fn try_lock(job_name: &str, pool: &sqlx::PgPool) -> Result<Option<PoolConnection<Postgres>>> {
+    let mut conn = pool.acquire().await?;
+    let acquired: bool = sqlx::query_scalar("SELECT pg_try_advisory_lock($1, hashtext($2))")
+        .bind(JOB_LOCK_NAMESPACE)
+        .bind(job_name)
+        .fetch_one(&mut *conn)
+        .await?;
+    Ok(if acquired { Some(conn) } else { None })
+}
+
+/// Release the advisory lock. If the unlock query itself fails, detach the
+/// connection from the pool and drop it — closing the session releases
+/// the lock, and we must never hand a lock-holding connection back.
+async fn unlock(job_name: &str, mut conn: PoolConnection<Postgres>) {
+    let released = sqlx::query_scalar::<_, bool>("SELECT pg_advisory_unlock($1, hashtext($2))")
+        .bind(JOB_LOCK_NAMESPACE)
+        .bind(job_name)
+        .fetch_one(&mut *conn)
+        .await;
+    if let Err(e) = released {
+        tracing::warn!(job = job_name, error = %e, "Failed to release job lock — closing connection");
+        drop(conn.detach());
+    }
+}
@@ -0,0 +1,76 @@
+//! Scheduler loop: one tokio task per registered job.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use super::registry::{JobDefinition, JobRegistry, JobScope};
+use super::runner::{run_job, JobOutcome, JobTrigger};
+use crate::kernel::ServerDeps;
+
+/// Drives every job in a registry on its cron schedule.
+///
+/// Each job gets its own loop so a slow job never delays another. Loops
+/// compute the next fire time from wall-clock "now" after each run, so a
+/// run that overshoots its next fire time skips it rather than stacking.
+pub struct JobScheduler {
+    registry: JobRegistry,
+    deps: Arc<ServerDeps>,
+}
+
+impl JobScheduler {
+    pub fn new(registry: JobRegistry, deps: Arc<ServerDeps>) -> Self {
+        Self { registry, deps }
+    }
+
+    /// Spawn the per-job loops and return immediately. The loops live for
+    /// the life of the process.
+    pub fn start(self) {
+        for job in self.registry.jobs() {
+            tracing::info!(
+                job = job.name,
+                schedule = job.schedule_expr,
+                scope = job.scope.as_str(),
+                "Scheduling job"
+            );
+            tokio::spawn(job_loop(job.clone(), self.deps.clone()));
+        }
+    }
+}
+
+async fn job_loop(job: Arc<JobDefinition>, deps: Arc<ServerDeps>) {
+    loop {
+        let now = Utc::now();
+        let Some(fire_at) = job.next_run_after(now) else {
+            tracing::warn!(
+                job = job.name,
+                "Schedule has no future fire times — stopping loop"
+            );
+            return;
+        };
+
+        let wait = (fire_at - now).to_std().unwrap_or_default();
+        tokio::time::sleep(wait).await;
+
+        let scheduled_for = match job.scope {
+            JobScope::Cluster => Some(fire_at),
+            JobScope::Local => None,
+        };
+
+        match run_job(&job, JobTrigger::Schedule, scheduled_for, &deps).await {
+            Ok(JobOutcome::Ran(_)) => {}
+            Ok(JobOutcome::Locked) => {
+                tracing::debug!(
+                    job = job.name,
+                    "Job lock held by another replica — skipping"
+                );
+            }
+            Ok(JobOutcome::AlreadyRan) => {
+                tracing::debug!(job = job.name, %fire_at, "Fire time already handled — skipping");
+            }
+            Err(e) => {
+                tracing::error!(job = job.name, error = %e, "Job runner error");
+            }
+        }
+    }
+}
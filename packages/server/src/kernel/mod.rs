@@ -1,16 +1,24 @@
 //! Kernel module - server infrastructure and dependencies.
 
 pub mod deps;
+pub mod geocoding;
+pub mod jobs;
 pub mod pii;
+pub mod rate_limit;
 pub mod sse;
 pub mod storage;
 pub mod stream_hub;
 pub mod test_dependencies;
 pub mod traits;
+pub mod work_queue;
 
 // Other exports
 pub use deps::{ServerDeps, TwilioAdapter};
+pub use geocoding::{create_geocoder, NoopGeocoder, OfflineGeocoder};
+pub use jobs::{JobRegistry, JobScheduler};
 pub use pii::{create_pii_detector, NoopPiiDetector, RegexPiiDetector};
+pub use rate_limit::{InMemoryRateLimiter, PostgresRateLimiter};
 pub use stream_hub::StreamHub;
 pub use test_dependencies::TestDependencies;
 pub use traits::*;
+pub use work_queue::{WorkRequest, WorkerPool};
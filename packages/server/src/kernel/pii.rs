@@ -5,7 +5,8 @@ use async_trait::async_trait;
 use std::sync::Arc;
 
 use crate::common::pii::{
-    detect_pii_contextual, redact_pii, DetectionContext, PiiFindings, RedactionStrategy,
+    detect_pii_with_allow_list, redact_pii, DetectionContext, PiiAllowList, PiiFindings,
+    RedactionStrategy,
 };
 use crate::kernel::traits::{BasePiiDetector, PiiScrubResult};
 
@@ -14,7 +15,8 @@ use crate::kernel::traits::{BasePiiDetector, PiiScrubResult};
 // =============================================================================
 
 /// Fast regex-based PII detector
-/// Detects structured PII: emails, phones, SSNs, credit cards, IPs
+/// Detects structured PII: emails, phones, SSNs, credit cards, IPs, street
+/// addresses, driver's licences, dates of birth and cue-word names
 pub struct RegexPiiDetector;
 
 impl RegexPiiDetector {
@@ -25,8 +27,13 @@ impl RegexPiiDetector {
 
 #[async_trait]
 impl BasePiiDetector for RegexPiiDetector {
-    async fn detect(&self, text: &str, context: DetectionContext) -> Result<PiiFindings> {
-        Ok(detect_pii_contextual(text, context))
+    async fn detect(
+        &self,
+        text: &str,
+        context: DetectionContext,
+        allow_list: &PiiAllowList,
+    ) -> Result<PiiFindings> {
+        Ok(detect_pii_with_allow_list(text, context, allow_list))
     }
 
     async fn scrub(
@@ -34,8 +41,9 @@ impl BasePiiDetector for RegexPiiDetector {
         text: &str,
         context: DetectionContext,
         strategy: RedactionStrategy,
+        allow_list: &PiiAllowList,
     ) -> Result<PiiScrubResult> {
-        let findings = detect_pii_contextual(text, context);
+        let findings = detect_pii_with_allow_list(text, context, allow_list);
         let pii_detected = !findings.is_empty();
         let clean_text = redact_pii(text, &findings, strategy);
 
@@ -63,7 +71,12 @@ impl NoopPiiDetector {
 
 #[async_trait]
 impl BasePiiDetector for NoopPiiDetector {
-    async fn detect(&self, _text: &str, _context: DetectionContext) -> Result<PiiFindings> {
+    async fn detect(
+        &self,
+        _text: &str,
+        _context: DetectionContext,
+        _allow_list: &PiiAllowList,
+    ) -> Result<PiiFindings> {
         Ok(PiiFindings::new())
     }
 
@@ -72,6 +85,7 @@ impl BasePiiDetector for NoopPiiDetector {
         text: &str,
         _context: DetectionContext,
         _strategy: RedactionStrategy,
+        _allow_list: &PiiAllowList,
     ) -> Result<PiiScrubResult> {
         Ok(PiiScrubResult {
             clean_text: text.to_string(),
@@ -109,6 +123,7 @@ mod tests {
                 "Email me at john@example.com",
                 DetectionContext::PersonalMessage,
                 RedactionStrategy::PartialMask,
+                &PiiAllowList::new(),
             )
             .await
             .unwrap();
@@ -126,6 +141,7 @@ mod tests {
                 "Email me at john@example.com",
                 DetectionContext::PersonalMessage,
                 RedactionStrategy::PartialMask,
+                &PiiAllowList::new(),
             )
             .await
             .unwrap();
@@ -143,6 +159,7 @@ mod tests {
                 "Email me at john@example.com",
                 DetectionContext::PersonalMessage,
                 RedactionStrategy::PartialMask,
+                &PiiAllowList::new(),
             )
             .await
             .unwrap();
@@ -159,10 +176,31 @@ mod tests {
                 "Email me at john@example.com",
                 DetectionContext::PersonalMessage,
                 RedactionStrategy::PartialMask,
+                &PiiAllowList::new(),
             )
             .await
             .unwrap();
 
         assert!(result.pii_detected);
     }
+
+    #[tokio::test]
+    async fn test_regex_detector_allow_list() {
+        let detector = RegexPiiDetector::new();
+        let mut allow_list = PiiAllowList::new();
+        allow_list.add_address("1200 Lake Street");
+
+        let result = detector
+            .scrub(
+                "Drop-off at 1200 Lake St",
+                DetectionContext::PublicContent,
+                RedactionStrategy::TokenReplacement,
+                &allow_list,
+            )
+            .await
+            .unwrap();
+
+        assert!(!result.pii_detected);
+        assert_eq!(result.clean_text, "Drop-off at 1200 Lake St");
+    }
 }
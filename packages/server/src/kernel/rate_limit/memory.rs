@@ -0,0 +1,92 @@
+//! In-memory rate limiter for tests and single-process tools.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use tokio::sync::Mutex;
+
+use super::{window_start, LockoutPolicy, LockoutState, NewRateLimitEvent, WindowHit};
+use crate::kernel::BaseRateLimiter;
+
+#[derive(Default)]
+pub struct InMemoryRateLimiter {
+    counters: Mutex<HashMap<(String, DateTime<Utc>), u32>>,
+    lockouts: Mutex<HashMap<String, LockoutState>>,
+    events: Mutex<Vec<NewRateLimitEvent>>,
+}
+
+impl InMemoryRateLimiter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Events recorded so far, oldest first.
+    pub async fn events(&self) -> Vec<NewRateLimitEvent> {
+        self.events.lock().await.clone()
+    }
+}
+
+#[async_trait]
+impl BaseRateLimiter for InMemoryRateLimiter {
+    async fn hit(&self, key: &str, window: Duration, now: DateTime<Utc>) -> Result<WindowHit> {
+        let start = window_start(now, window);
+        let mut counters = self.counters.lock().await;
+        counters.retain(|(_, s), _| *s + window > now || *s == start);
+        let count = counters.entry((key.to_string(), start)).or_insert(0);
+        *count += 1;
+        Ok(WindowHit {
+            count: *count,
+            resets_at: start + window,
+        })
+    }
+
+    async fn lockout_state(&self, key: &str) -> Result<Option<LockoutState>> {
+        Ok(self.lockouts.lock().await.get(key).cloned())
+    }
+
+    async fn record_failure(
+        &self,
+        key: &str,
+        policy: &LockoutPolicy,
+        now: DateTime<Utc>,
+    ) -> Result<(LockoutState, Option<Duration>)> {
+        let mut lockouts = self.lockouts.lock().await;
+        let state = lockouts.entry(key.to_string()).or_default();
+        let (next, lockout) = policy.record_failure(state, now);
+        *state = next.clone();
+        Ok((next, lockout))
+    }
+
+    async fn clear_lockout(&self, key: &str) -> Result<()> {
+        self.lockouts.lock().await.remove(key);
+        Ok(())
+    }
+
+    async fn record_event(&self, event: NewRateLimitEvent) -> Result<()> {
+        self.events.lock().await.push(event);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn counts_per_key_and_window() {
+        let limiter = InMemoryRateLimiter::new();
+        let window = Duration::minutes(1);
+        let now = DateTime::from_timestamp(1_800_000_000, 0).unwrap();
+
+        assert_eq!(limiter.hit("a", window, now).await.unwrap().count, 1);
+        assert_eq!(limiter.hit("a", window, now).await.unwrap().count, 2);
+        assert_eq!(limiter.hit("b", window, now).await.unwrap().count, 1);
+
+        let next_window = now + Duration::minutes(1);
+        let hit = limiter.hit("a", window, next_window).await.unwrap();
+        assert_eq!(hit.count, 1);
+        assert_eq!(hit.resets_at, next_window + window);
+    }
+}
@@ -0,0 +1,215 @@
+//! Rate limiting and lockouts.
+//!
+//! Two primitives, both keyed by an opaque string (`otp_send:ip:203.0.113.7`):
+//!
+//! - **Budgets** — at most `limit` hits per fixed `window`. Counting is done
+//!   by a [`BaseRateLimiter`](super::BaseRateLimiter), so budgets are shared
+//!   across replicas when backed by Postgres.
+//! - **Lockouts** — `threshold` failures within `failure_window` lock the key
+//!   out for `base_lockout`, doubling with each further lockout up to
+//!   `max_lockout`. The doubling resets once a key has had no failures for
+//!   `strike_decay`.
+//!
+//! The backing store only persists counters, lockout state and events; the
+//! policy arithmetic lives here so both implementations behave identically.
+
+pub mod memory;
+pub mod postgres;
+pub mod trusted_proxy;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+
+pub use memory::InMemoryRateLimiter;
+pub use postgres::PostgresRateLimiter;
+pub use trusted_proxy::TrustedProxies;
+
+/// At most `limit` hits per `window`.
+#[derive(Debug, Clone, Copy)]
+pub struct Budget {
+    pub limit: u32,
+    pub window: Duration,
+}
+
+impl Budget {
+    pub const fn new(limit: u32, window_secs: i64) -> Self {
+        Self {
+            limit,
+            window: Duration::seconds(window_secs),
+        }
+    }
+}
+
+/// Outcome of counting one hit against a budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowHit {
+    /// Hits in the current window, including this one.
+    pub count: u32,
+    /// When the current window ends.
+    pub resets_at: DateTime<Utc>,
+}
+
+/// Start of the fixed window of length `window` containing `now`.
+pub fn window_start(now: DateTime<Utc>, window: Duration) -> DateTime<Utc> {
+    let secs = window.num_seconds().max(1);
+    let start = now.timestamp().div_euclid(secs) * secs;
+    DateTime::from_timestamp(start, 0).unwrap_or(now)
+}
+
+/// When repeated failures lock a key out, and for how long.
+#[derive(Debug, Clone, Copy)]
+pub struct LockoutPolicy {
+    pub threshold: u32,
+    pub failure_window: Duration,
+    pub base_lockout: Duration,
+    pub max_lockout: Duration,
+    pub strike_decay: Duration,
+}
+
+/// Persisted failure/lockout state for one key.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LockoutState {
+    /// Failures since the last lockout (or since the failure window lapsed).
+    pub failures: u32,
+    /// Lockouts served so far; each doubles the next one.
+    pub strikes: u32,
+    pub locked_until: Option<DateTime<Utc>>,
+    pub last_failure_at: Option<DateTime<Utc>>,
+}
+
+impl LockoutState {
+    /// Remaining lockout, if the key is locked at `now`.
+    pub fn locked_for(&self, now: DateTime<Utc>) -> Option<Duration> {
+        self.locked_until
+            .filter(|until| *until > now)
+            .map(|until| until - now)
+    }
+}
+
+impl LockoutPolicy {
+    /// Apply one failure at `now`. Returns the new state and, if this
+    /// failure triggered a lockout, its length.
+    pub fn record_failure(
+        &self,
+        state: &LockoutState,
+        now: DateTime<Utc>,
+    ) -> (LockoutState, Option<Duration>) {
+        let mut next = state.clone();
+        let last = state.last_failure_at;
+
+        if last.is_some_and(|t| now - t > self.strike_decay) {
+            next.strikes = 0;
+        }
+        if last.is_none_or(|t| now - t > self.failure_window) {
+            next.failures = 0;
+        }
+        next.failures += 1;
+        next.last_failure_at = Some(now);
+
+        if next.failures < self.threshold {
+            return (next, None);
+        }
+
+        let lockout = self.lockout_for_strike(next.strikes);
+        next.failures = 0;
+        next.strikes += 1;
+        next.locked_until = Some(now + lockout);
+        (next, Some(lockout))
+    }
+
+    /// Length of the lockout served after `strikes` earlier ones.
+    pub fn lockout_for_strike(&self, strikes: u32) -> Duration {
+        let factor = 2i64.saturating_pow(strikes.min(30));
+        let secs = self.base_lockout.num_seconds().saturating_mul(factor);
+        Duration::seconds(secs.min(self.max_lockout.num_seconds()))
+    }
+}
+
+/// A noteworthy rate-limit event (budget exhausted, lockout), kept for
+/// admins in `auth_security_events`.
+#[derive(Debug, Clone, Serialize)]
+pub struct NewRateLimitEvent {
+    /// `budget_exceeded` or `lockout`.
+    pub event_type: String,
+    /// The limited key. Identifier keys carry a hash, never the raw value.
+    pub subject: String,
+    pub ip_address: Option<String>,
+    pub detail: serde_json::Value,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> LockoutPolicy {
+        LockoutPolicy {
+            threshold: 3,
+            failure_window: Duration::minutes(10),
+            base_lockout: Duration::minutes(5),
+            max_lockout: Duration::minutes(60),
+            strike_decay: Duration::hours(24),
+        }
+    }
+
+    fn at(secs: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(1_800_000_000 + secs, 0).unwrap()
+    }
+
+    #[test]
+    fn windows_are_aligned() {
+        let w = Duration::minutes(10);
+        assert_eq!(window_start(at(0), w), window_start(at(1), w));
+        let start = window_start(at(0), w);
+        assert_eq!(start.timestamp() % 600, 0);
+        assert!(start <= at(0) && at(0) < start + w);
+    }
+
+    #[test]
+    fn threshold_failures_lock_out() {
+        let p = policy();
+        let (s1, l1) = p.record_failure(&LockoutState::default(), at(0));
+        let (s2, l2) = p.record_failure(&s1, at(10));
+        let (s3, l3) = p.record_failure(&s2, at(20));
+        assert_eq!((l1, l2), (None, None));
+        assert_eq!(l3, Some(Duration::minutes(5)));
+        assert_eq!(s3.strikes, 1);
+        assert_eq!(s3.failures, 0);
+        assert_eq!(s3.locked_for(at(20)), Some(Duration::minutes(5)));
+        assert_eq!(s3.locked_for(at(20 + 300)), None);
+    }
+
+    #[test]
+    fn failures_outside_the_window_start_over() {
+        let p = policy();
+        let (s1, _) = p.record_failure(&LockoutState::default(), at(0));
+        let (s2, _) = p.record_failure(&s1, at(10));
+        let (s3, lockout) = p.record_failure(&s2, at(11 * 60));
+        assert_eq!(lockout, None);
+        assert_eq!(s3.failures, 1);
+    }
+
+    #[test]
+    fn lockouts_back_off_exponentially_and_cap() {
+        let p = policy();
+        assert_eq!(p.lockout_for_strike(0), Duration::minutes(5));
+        assert_eq!(p.lockout_for_strike(1), Duration::minutes(10));
+        assert_eq!(p.lockout_for_strike(2), Duration::minutes(20));
+        assert_eq!(p.lockout_for_strike(4), Duration::minutes(60));
+        assert_eq!(p.lockout_for_strike(40), Duration::minutes(60));
+    }
+
+    #[test]
+    fn strikes_decay_after_a_quiet_period() {
+        let p = policy();
+        let state = LockoutState {
+            failures: 2,
+            strikes: 3,
+            locked_until: None,
+            last_failure_at: Some(at(0)),
+        };
+        let (next, lockout) = p.record_failure(&state, at(25 * 3600));
+        assert_eq!(lockout, None);
+        assert_eq!(next.strikes, 0);
+        assert_eq!(next.failures, 1);
+    }
+}
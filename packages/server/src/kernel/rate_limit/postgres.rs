@@ -0,0 +1,186 @@
+//! Postgres-backed rate limiter (`rate_limit_counters`, `rate_limit_lockouts`,
+//! `auth_security_events`), shared by every replica.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use sqlx::PgPool;
+
+use super::{window_start, LockoutPolicy, LockoutState, NewRateLimitEvent, WindowHit};
+use crate::kernel::BaseRateLimiter;
+
+pub struct PostgresRateLimiter {
+    pool: PgPool,
+}
+
+#[derive(sqlx::FromRow)]
+struct LockoutRow {
+    failures: i32,
+    strikes: i32,
+    locked_until: Option<DateTime<Utc>>,
+    last_failure_at: Option<DateTime<Utc>>,
+}
+
+impl From<LockoutRow> for LockoutState {
+    fn from(r: LockoutRow) -> Self {
+        LockoutState {
+            failures: r.failures.max(0) as u32,
+            strikes: r.strikes.max(0) as u32,
+            locked_until: r.locked_until,
+            last_failure_at: r.last_failure_at,
+        }
+    }
+}
+
+impl PostgresRateLimiter {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Delete counters whose window has ended, lockouts idle for longer than
+    /// `idle`, and security events older than `event_retention_days`.
+    pub async fn prune(pool: &PgPool, idle: Duration, event_retention_days: i64) -> Result<u64> {
+        let counters = sqlx::query("DELETE FROM rate_limit_counters WHERE expires_at < NOW()")
+            .execute(pool)
+            .await?
+            .rows_affected();
+        let lockouts = sqlx::query(
+            r#"
+            DELETE FROM rate_limit_lockouts
+            WHERE updated_at < NOW() - make_interval(secs => $1)
+              AND (locked_until IS NULL OR locked_until < NOW())
+            "#,
+        )
+        .bind(idle.num_seconds() as f64)
+        .execute(pool)
+        .await?
+        .rows_affected();
+        let events = sqlx::query(
+            "DELETE FROM auth_security_events WHERE created_at < NOW() - make_interval(days => $1)",
+        )
+        .bind(event_retention_days as i32)
+        .execute(pool)
+        .await?
+        .rows_affected();
+        Ok(counters + lockouts + events)
+    }
+}
+
+#[async_trait]
+impl BaseRateLimiter for PostgresRateLimiter {
+    async fn hit(&self, key: &str, window: Duration, now: DateTime<Utc>) -> Result<WindowHit> {
+        let start = window_start(now, window);
+        let resets_at = start + window;
+        let count = sqlx::query_scalar::<_, i32>(
+            r#"
+            INSERT INTO rate_limit_counters (key, window_start, count, expires_at)
+            VALUES ($1, $2, 1, $3)
+            ON CONFLICT (key, window_start)
+            DO UPDATE SET count = rate_limit_counters.count + 1
+            RETURNING count
+            "#,
+        )
+        .bind(key)
+        .bind(start)
+        .bind(resets_at)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(WindowHit {
+            count: count.max(0) as u32,
+            resets_at,
+        })
+    }
+
+    async fn lockout_state(&self, key: &str) -> Result<Option<LockoutState>> {
+        let row = sqlx::query_as::<_, LockoutRow>(
+            r#"
+            SELECT failures, strikes, locked_until, last_failure_at
+            FROM rate_limit_lockouts
+            WHERE key = $1
+            "#,
+        )
+        .bind(key)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row.map(LockoutState::from))
+    }
+
+    async fn record_failure(
+        &self,
+        key: &str,
+        policy: &LockoutPolicy,
+        now: DateTime<Utc>,
+    ) -> Result<(LockoutState, Option<Duration>)> {
+        let mut tx = self.pool.begin().await?;
+
+        // Seed the row so concurrent first failures serialize on its lock
+        // instead of both reading "no failures yet".
+        sqlx::query(
+            r#"
+            INSERT INTO rate_limit_lockouts (key, failures, strikes, updated_at)
+            VALUES ($1, 0, 0, NOW())
+            ON CONFLICT (key) DO NOTHING
+            "#,
+        )
+        .bind(key)
+        .execute(&mut *tx)
+        .await?;
+        let row = sqlx::query_as::<_, LockoutRow>(
+            r#"
+            SELECT failures, strikes, locked_until, last_failure_at
+            FROM rate_limit_lockouts
+            WHERE key = $1
+            FOR UPDATE
+            "#,
+        )
+        .bind(key)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let (next, lockout) = policy.record_failure(&row.into(), now);
+        sqlx::query(
+            r#"
+            UPDATE rate_limit_lockouts SET
+                failures = $2,
+                strikes = $3,
+                locked_until = $4,
+                last_failure_at = $5,
+                updated_at = NOW()
+            WHERE key = $1
+            "#,
+        )
+        .bind(key)
+        .bind(next.failures as i32)
+        .bind(next.strikes as i32)
+        .bind(next.locked_until)
+        .bind(next.last_failure_at)
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok((next, lockout))
+    }
+
+    async fn clear_lockout(&self, key: &str) -> Result<()> {
+        sqlx::query("DELETE FROM rate_limit_lockouts WHERE key = $1")
+            .bind(key)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn record_event(&self, event: NewRateLimitEvent) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO auth_security_events (event_type, subject, ip_address, detail)
+            VALUES ($1, $2, $3, $4)
+            "#,
+        )
+        .bind(&event.event_type)
+        .bind(&event.subject)
+        .bind(&event.ip_address)
+        .bind(&event.detail)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+}
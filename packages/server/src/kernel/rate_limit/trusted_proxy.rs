@@ -0,0 +1,150 @@
+//! Which peers may tell us the client IP.
+//!
+//! `X-Forwarded-For` and `X-Real-IP` are only as trustworthy as whoever set
+//! them. A client talking to us directly can put anything there, so the
+//! headers are honoured only when the socket peer is a configured proxy
+//! (`TRUSTED_PROXIES`, comma-separated addresses or CIDR ranges). With no
+//! proxies configured the socket peer address is the client IP.
+
+use std::net::IpAddr;
+
+/// Addresses and CIDR ranges of the reverse proxies in front of us.
+#[derive(Debug, Clone, Default)]
+pub struct TrustedProxies {
+    ranges: Vec<(IpAddr, u8)>,
+}
+
+impl TrustedProxies {
+    /// Parse a comma-separated list of addresses and CIDR ranges, skipping
+    /// (and logging) entries that don't parse.
+    pub fn parse(list: &str) -> Self {
+        let ranges = list
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .filter_map(|entry| match parse_range(entry) {
+                Some(range) => Some(range),
+                None => {
+                    tracing::warn!(entry, "Ignoring invalid TRUSTED_PROXIES entry");
+                    None
+                }
+            })
+            .collect();
+        Self { ranges }
+    }
+
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        self.ranges
+            .iter()
+            .any(|(network, prefix)| in_range(ip, *network, *prefix))
+    }
+
+    /// The client IP for a request from `peer`.
+    ///
+    /// Forwarding headers count only when `peer` is a trusted proxy. Then
+    /// `X-Forwarded-For` is walked right to left past our own proxies, and
+    /// the first address they didn't add is the client; `X-Real-IP` is the
+    /// fallback. Otherwise (or without a peer) the peer itself is the client.
+    pub fn client_ip(
+        &self,
+        peer: Option<IpAddr>,
+        forwarded_for: Option<&str>,
+        real_ip: Option<&str>,
+    ) -> Option<IpAddr> {
+        let peer = peer?;
+        if !self.contains(peer) {
+            return Some(peer);
+        }
+
+        if let Some(forwarded_for) = forwarded_for {
+            let hops = forwarded_for
+                .rsplit(',')
+                .map(|hop| hop.trim().parse::<IpAddr>());
+            for hop in hops {
+                match hop {
+                    Ok(ip) if self.contains(ip) => continue,
+                    Ok(ip) => return Some(ip),
+                    // A garbled hop means nothing to its left can be trusted.
+                    Err(_) => return Some(peer),
+                }
+            }
+        }
+        real_ip
+            .and_then(|ip| ip.trim().parse::<IpAddr>().ok())
+            .or(Some(peer))
+    }
+}
+
+fn parse_range(entry: &str) -> Option<(IpAddr, u8)> {
+    let (addr, prefix) = match entry.split_once('/') {
+        Some((addr, prefix)) => (addr.parse::<IpAddr>().ok()?, Some(prefix.parse::<u8>().ok()?)),
+        None => (entry.parse::<IpAddr>().ok()?, None),
+    };
+    let max = match addr {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    };
+    let prefix = prefix.unwrap_or(max);
+    (prefix <= max).then_some((addr, prefix))
+}
+
+fn in_range(ip: IpAddr, network: IpAddr, prefix: u8) -> bool {
+    match (ip, network) {
+        (IpAddr::V4(ip), IpAddr::V4(network)) => {
+            let mask = u32::MAX.checked_shl(32 - prefix as u32).unwrap_or(0);
+            u32::from(ip) & mask == u32::from(network) & mask
+        }
+        (IpAddr::V6(ip), IpAddr::V6(network)) => {
+            let mask = u128::MAX.checked_shl(128 - prefix as u32).unwrap_or(0);
+            u128::from(ip) & mask == u128::from(network) & mask
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip(s: &str) -> Option<IpAddr> {
+        Some(s.parse().unwrap())
+    }
+
+    #[test]
+    fn direct_clients_cannot_spoof_forwarding_headers() {
+        let none = TrustedProxies::default();
+        assert_eq!(
+            none.client_ip(ip("198.51.100.9"), Some("203.0.113.1"), Some("203.0.113.2")),
+            ip("198.51.100.9")
+        );
+
+        let proxies = TrustedProxies::parse("10.0.0.0/8");
+        assert_eq!(
+            proxies.client_ip(ip("198.51.100.9"), Some("203.0.113.1"), None),
+            ip("198.51.100.9")
+        );
+    }
+
+    #[test]
+    fn trusted_proxies_forward_the_first_untrusted_hop() {
+        let proxies = TrustedProxies::parse("10.0.0.0/8, 192.0.2.5, not-an-ip");
+        assert!(proxies.contains("10.1.2.3".parse().unwrap()));
+        assert!(!proxies.contains("11.0.0.1".parse().unwrap()));
+
+        // Client-supplied entries to the left of the real client are ignored
+        assert_eq!(
+            proxies.client_ip(
+                ip("10.0.0.7"),
+                Some("1.2.3.4, 203.0.113.7, 192.0.2.5"),
+                None
+            ),
+            ip("203.0.113.7")
+        );
+        assert_eq!(
+            proxies.client_ip(ip("10.0.0.7"), None, Some("203.0.113.8")),
+            ip("203.0.113.8")
+        );
+        assert_eq!(proxies.client_ip(ip("10.0.0.7"), None, None), ip("10.0.0.7"));
+        assert_eq!(proxies.client_ip(None, Some("203.0.113.7"), None), None);
+    }
+}
@@ -5,12 +5,14 @@
 use anyhow::Result;
 use async_trait::async_trait;
 use sqlx::PgPool;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
-use super::{BasePiiDetector, BaseStorageService, PiiScrubResult};
-use crate::common::pii::{DetectionContext, PiiFindings, RedactionStrategy};
+use super::{
+    BaseGeocoder, BasePiiDetector, BaseStorageService, BaseTwilioService, PiiScrubResult,
+};
+use crate::common::pii::{DetectionContext, PiiAllowList, PiiFindings, RedactionStrategy};
 use crate::domains::auth::JwtService;
-use crate::kernel::{ServerDeps, StreamHub, TwilioAdapter};
+use crate::kernel::{InMemoryRateLimiter, NoopGeocoder, ServerDeps, StreamHub};
 
 // =============================================================================
 // Mock PII Detector
@@ -36,10 +38,17 @@ impl MockPiiDetector {
 
 #[async_trait]
 impl BasePiiDetector for MockPiiDetector {
-    async fn detect(&self, text: &str, context: DetectionContext) -> Result<PiiFindings> {
+    async fn detect(
+        &self,
+        text: &str,
+        context: DetectionContext,
+        allow_list: &PiiAllowList,
+    ) -> Result<PiiFindings> {
         if self.scrub_enabled {
             // Use real detection for tests
-            Ok(crate::common::pii::detect_pii_contextual(text, context))
+            Ok(crate::common::pii::detect_pii_with_allow_list(
+                text, context, allow_list,
+            ))
         } else {
             Ok(PiiFindings::new())
         }
@@ -50,9 +59,10 @@ impl BasePiiDetector for MockPiiDetector {
         text: &str,
         context: DetectionContext,
         strategy: RedactionStrategy,
+        allow_list: &PiiAllowList,
     ) -> Result<PiiScrubResult> {
         if self.scrub_enabled {
-            let findings = self.detect(text, context).await?;
+            let findings = self.detect(text, context, allow_list).await?;
             let pii_detected = !findings.is_empty();
             let clean_text = crate::common::pii::redact_pii(text, &findings, strategy);
 
@@ -71,24 +81,102 @@ impl BasePiiDetector for MockPiiDetector {
     }
 }
 
+// =============================================================================
+// Mock Twilio Service
+// =============================================================================
+
+/// Records OTP sends instead of calling Twilio. Accepts one fixed code.
+pub struct MockTwilioService {
+    code: String,
+    sent: Mutex<Vec<SentOtp>>,
+}
+
+/// An OTP "sent" by [`MockTwilioService`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SentOtp {
+    /// `sms` or `email`
+    pub channel: &'static str,
+    pub recipient: String,
+}
+
+impl MockTwilioService {
+    /// Accepts the code `123456`.
+    pub fn new() -> Self {
+        Self::with_code("123456")
+    }
+
+    pub fn with_code(code: &str) -> Self {
+        Self {
+            code: code.to_string(),
+            sent: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// OTPs sent so far, oldest first.
+    pub fn sent(&self) -> Vec<SentOtp> {
+        self.sent.lock().unwrap().clone()
+    }
+
+    fn record(&self, channel: &'static str, recipient: &str) {
+        self.sent.lock().unwrap().push(SentOtp {
+            channel,
+            recipient: recipient.to_string(),
+        });
+    }
+}
+
+impl Default for MockTwilioService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl BaseTwilioService for MockTwilioService {
+    async fn send_otp(&self, phone_number: &str) -> Result<()> {
+        self.record("sms", phone_number);
+        Ok(())
+    }
+
+    async fn send_email_otp(&self, email: &str) -> Result<()> {
+        self.record("email", email);
+        Ok(())
+    }
+
+    async fn verify_otp(&self, _recipient: &str, code: &str) -> Result<bool> {
+        Ok(code == self.code)
+    }
+}
+
 // =============================================================================
 // TestDependencies - Builder for test dependencies
 // =============================================================================
 
 #[derive(Clone)]
 pub struct TestDependencies {
+    pub twilio: Arc<MockTwilioService>,
     pub pii_detector: Arc<MockPiiDetector>,
     pub storage: Option<Arc<dyn BaseStorageService>>,
+    pub geocoder: Option<Arc<dyn BaseGeocoder>>,
 }
 
 impl TestDependencies {
     pub fn new() -> Self {
         Self {
+            twilio: Arc::new(MockTwilioService::new()),
             pii_detector: Arc::new(MockPiiDetector::new()),
             storage: None,
+            geocoder: None,
         }
     }
 
+    /// Set a mock Twilio service. Keep a clone of the `Arc` to inspect
+    /// what was sent.
+    pub fn mock_twilio(mut self, twilio: Arc<MockTwilioService>) -> Self {
+        self.twilio = twilio;
+        self
+    }
+
     /// Set a mock PII detector
     pub fn mock_pii(mut self, detector: MockPiiDetector) -> Self {
         self.pii_detector = Arc::new(detector);
@@ -102,19 +190,23 @@ impl TestDependencies {
         self
     }
 
+    /// Inject a geocoder. Without one, tests get `NoopGeocoder` so ingest
+    /// doesn't create locations the test didn't ask for.
+    pub fn with_geocoder(mut self, geocoder: Arc<dyn BaseGeocoder>) -> Self {
+        self.geocoder = Some(geocoder);
+        self
+    }
+
     /// Convert into ServerDeps for testing
     pub fn into_server_deps(self, db_pool: PgPool) -> ServerDeps {
-        let twilio = Arc::new(twilio::TwilioService::new(twilio::TwilioOptions {
-            account_sid: "test_account_sid".to_string(),
-            auth_token: "test_auth_token".to_string(),
-            service_id: "test_service_id".to_string(),
-        }));
         let jwt_service = Arc::new(JwtService::new("test_secret", "test_issuer".to_string()));
 
         ServerDeps::new(
             db_pool,
-            Arc::new(TwilioAdapter::new(twilio)),
+            self.twilio,
             self.pii_detector,
+            Arc::new(InMemoryRateLimiter::new()),
+            self.geocoder.unwrap_or_else(|| Arc::new(NoopGeocoder)),
             self.storage,
             jwt_service,
             StreamHub::new(),
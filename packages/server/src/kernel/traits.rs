@@ -15,8 +15,51 @@ pub trait BaseTwilioService: Send + Sync {
     /// Send OTP code via SMS to phone number
     async fn send_otp(&self, phone_number: &str) -> Result<()>;
 
-    /// Verify OTP code for phone number
-    async fn verify_otp(&self, phone_number: &str, code: &str) -> Result<()>;
+    /// Send OTP code via email (Twilio Verify email channel)
+    async fn send_email_otp(&self, email: &str) -> Result<()>;
+
+    /// Verify OTP code for the phone number or email it was sent to.
+    /// `Ok(false)` is a rejected code; `Err` means Twilio couldn't be asked.
+    async fn verify_otp(&self, recipient: &str, code: &str) -> Result<bool>;
+}
+
+// =============================================================================
+// Rate Limiter Trait (Infrastructure - counters and lockout state)
+// =============================================================================
+
+use crate::kernel::rate_limit::{LockoutPolicy, LockoutState, NewRateLimitEvent, WindowHit};
+
+/// Storage for rate-limit counters and lockouts. Policy (limits, backoff)
+/// lives in `kernel::rate_limit`; implementations only count and persist.
+#[async_trait]
+pub trait BaseRateLimiter: Send + Sync {
+    /// Count one hit for `key` in the fixed window of length `window`
+    /// containing `now`.
+    async fn hit(
+        &self,
+        key: &str,
+        window: chrono::Duration,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<WindowHit>;
+
+    /// Failure/lockout state for `key`, if any failures have been recorded.
+    async fn lockout_state(&self, key: &str) -> Result<Option<LockoutState>>;
+
+    /// Apply one failure to `key` under `policy`, atomically with respect
+    /// to concurrent failures on the same key. Returns the new state and,
+    /// if this failure triggered a lockout, its length.
+    async fn record_failure(
+        &self,
+        key: &str,
+        policy: &LockoutPolicy,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(LockoutState, Option<chrono::Duration>)>;
+
+    /// Forget failures and any active lockout for `key`.
+    async fn clear_lockout(&self, key: &str) -> Result<()>;
+
+    /// Record a lockout or exhausted budget for admins to review.
+    async fn record_event(&self, event: NewRateLimitEvent) -> Result<()>;
 }
 
 // =============================================================================
@@ -54,7 +97,7 @@ pub trait BaseStorageService: Send + Sync {
     fn public_url(&self, key: &str) -> String;
 }
 
-use crate::common::pii::{DetectionContext, PiiFindings, RedactionStrategy};
+use crate::common::pii::{DetectionContext, PiiAllowList, PiiFindings, RedactionStrategy};
 
 /// Result of PII detection and redaction
 #[derive(Debug, Clone)]
@@ -66,8 +109,13 @@ pub struct PiiScrubResult {
 
 #[async_trait]
 pub trait BasePiiDetector: Send + Sync {
-    /// Detect PII in text with context
-    async fn detect(&self, text: &str, context: DetectionContext) -> Result<PiiFindings>;
+    /// Detect PII in text with context, ignoring values on the allow list
+    async fn detect(
+        &self,
+        text: &str,
+        context: DetectionContext,
+        allow_list: &PiiAllowList,
+    ) -> Result<PiiFindings>;
 
     /// Detect and redact PII in one call (convenience method)
     async fn scrub(
@@ -75,5 +123,60 @@ pub trait BasePiiDetector: Send + Sync {
         text: &str,
         context: DetectionContext,
         strategy: RedactionStrategy,
+        allow_list: &PiiAllowList,
     ) -> Result<PiiScrubResult>;
 }
+
+// =============================================================================
+// Geocoder Trait (Infrastructure - place text → coordinates)
+// =============================================================================
+
+/// How specific a geocode is, most to least precise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GeocodePrecision {
+    /// A named place — library, park, campus, stadium.
+    Landmark,
+    /// ZIP code centroid.
+    PostalCode,
+    /// Neighborhood or tribal nation centroid.
+    Area,
+    /// City centroid.
+    City,
+    /// Broad region ("Iron Range", "Twin Cities").
+    Region,
+}
+
+impl GeocodePrecision {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Landmark => "landmark",
+            Self::PostalCode => "postal_code",
+            Self::Area => "area",
+            Self::City => "city",
+            Self::Region => "region",
+        }
+    }
+}
+
+/// A resolved location. `postal_code` is the ZIP the text named, or the
+/// nearest known ZIP, so proximity search (which joins `zip_codes`) can
+/// find it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeocodeMatch {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub city: Option<String>,
+    pub state: Option<String>,
+    pub postal_code: Option<String>,
+    pub precision: GeocodePrecision,
+    /// 0.0–1.0; how sure the geocoder is this is the place the text means.
+    pub confidence: f64,
+}
+
+#[async_trait]
+pub trait BaseGeocoder: Send + Sync {
+    /// Resolve free-text location ("Brainerd Public Library, 416 S 5th St",
+    /// "Frogtown", "55104") to coordinates. `None` when nothing matches.
+    async fn geocode(&self, query: &str) -> Result<Option<GeocodeMatch>>;
+}
@@ -0,0 +1,238 @@
+//! Work kinds, their payloads, and the handler each dispatches to.
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use super::work_item::WorkItem;
+use crate::common::OrganizationCalendarFeedId;
+use crate::domains::media::activities::{ingest_source_image, IngestError};
+use crate::domains::media::ingest::fetch::FetchError;
+use crate::domains::posts::activities::calendar_import::{self, CalendarImportError};
+use crate::domains::posts::activities::county_resolution;
+use crate::domains::posts::activities::revision_reflow;
+use crate::domains::posts::models::PostMediaRecord;
+use crate::kernel::ServerDeps;
+
+/// A unit of work the queue knows how to run. Serialised (tagged by
+/// `kind`) into `work_items.payload`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum WorkRequest {
+    /// Fetch + normalise the post's primary `source_image_url` into the
+    /// Media Library and link it to the post.
+    IngestPostMedia { post_id: Uuid },
+    /// Regenerate every non-published edition that slotted this post.
+    ReflowEditions { post_id: Uuid },
+    /// Fetch an organisation's iCalendar feed and import its events.
+    ImportCalendarFeed { feed_id: Uuid },
+    /// Re-resolve every post's county against the stored boundaries.
+    ResolvePostCounties,
+}
+
+impl WorkRequest {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            WorkRequest::IngestPostMedia { .. } => "ingest_post_media",
+            WorkRequest::ReflowEditions { .. } => "reflow_editions",
+            WorkRequest::ImportCalendarFeed { .. } => "import_calendar_feed",
+            WorkRequest::ResolvePostCounties => "resolve_post_counties",
+        }
+    }
+
+    /// Idempotency key: one pending item per post (or feed) per kind, and
+    /// one pending county resolution overall.
+    pub fn dedup_key(&self) -> String {
+        match self {
+            WorkRequest::IngestPostMedia { post_id } | WorkRequest::ReflowEditions { post_id } => {
+                post_id.to_string()
+            }
+            WorkRequest::ImportCalendarFeed { feed_id } => feed_id.to_string(),
+            WorkRequest::ResolvePostCounties => "all".to_string(),
+        }
+    }
+
+    /// Queue this request. Returns `None` if an identical request is
+    /// already pending.
+    pub async fn enqueue(&self, db: impl PgExecutor<'_>) -> Result<Option<WorkItem>> {
+        let payload = serde_json::to_value(self)?;
+        WorkItem::enqueue(self.kind(), &payload, Some(&self.dedup_key()), db).await
+    }
+
+    pub fn from_item(item: &WorkItem) -> Result<Self> {
+        serde_json::from_value(item.payload.clone()).map_err(|e| {
+            anyhow!(
+                "Unreadable payload for work item {} ({}): {}",
+                item.id,
+                item.kind,
+                e
+            )
+        })
+    }
+}
+
+/// Failure of a handler. Retryable failures go back on the queue with
+/// backoff; permanent ones go straight to dead.
+#[derive(Debug, thiserror::Error)]
+pub enum WorkError {
+    #[error("{0}")]
+    Retryable(anyhow::Error),
+    #[error("{0}")]
+    Permanent(anyhow::Error),
+}
+
+impl From<anyhow::Error> for WorkError {
+    fn from(err: anyhow::Error) -> Self {
+        WorkError::Retryable(err)
+    }
+}
+
+/// Run the handler for `request`.
+pub async fn handle(request: &WorkRequest, deps: &ServerDeps) -> Result<(), WorkError> {
+    match request {
+        WorkRequest::IngestPostMedia { post_id } => ingest_post_media(*post_id, deps).await,
+        WorkRequest::ReflowEditions { post_id } => {
+            revision_reflow::reflow_editions_containing(*post_id, deps).await?;
+            Ok(())
+        }
+        WorkRequest::ImportCalendarFeed { feed_id } => {
+            calendar_import::import_feed(OrganizationCalendarFeedId::from_uuid(*feed_id), deps)
+                .await
+                .map(|_| ())
+                .map_err(classify_calendar_error)
+        }
+        WorkRequest::ResolvePostCounties => {
+            let summary = county_resolution::resolve_all(&deps.db_pool).await?;
+            tracing::info!(
+                via_coordinates = summary.via_coordinates,
+                via_zip = summary.via_zip,
+                unresolved = summary.unresolved,
+                "Resolved post counties"
+            );
+            Ok(())
+        }
+    }
+}
+
+async fn ingest_post_media(post_id: Uuid, deps: &ServerDeps) -> Result<(), WorkError> {
+    let Some(primary) = PostMediaRecord::find_primary(post_id, &deps.db_pool).await? else {
+        return Ok(());
+    };
+    // Already library-backed (an editor attached media, or an earlier run
+    // finished) — nothing to fetch.
+    if primary.media_id.is_some() {
+        return Ok(());
+    }
+    let Some(source_url) = primary.image_url.as_deref() else {
+        return Ok(());
+    };
+
+    ingest_source_image(
+        source_url,
+        post_id,
+        primary.caption.as_deref(),
+        primary.credit.as_deref(),
+        primary.alt_text.as_deref(),
+        deps,
+    )
+    .await
+    .map(|_| ())
+    .map_err(classify_ingest_error)
+}
+
+/// Transient network/storage problems are worth retrying; a URL the SSRF
+/// guard rejects or bytes that aren't an image will fail the same way
+/// every time.
+fn classify_ingest_error(err: IngestError) -> WorkError {
+    let retryable = match &err {
+        IngestError::Fetch(FetchError::Timeout(_)) | IngestError::Fetch(FetchError::Http(_)) => {
+            true
+        }
+        IngestError::Fetch(FetchError::UpstreamStatus(status)) => {
+            *status >= 500 || *status == 408 || *status == 429
+        }
+        IngestError::Storage(_) | IngestError::Db(_) => true,
+        IngestError::Ssrf(_)
+        | IngestError::Fetch(FetchError::Ssrf(_))
+        | IngestError::Fetch(FetchError::BodyTooLarge)
+        | IngestError::Validate(_)
+        | IngestError::Normalise(_)
+        | IngestError::MissingStorage => false,
+    };
+    let err = anyhow!("media ingest: {}", err);
+    if retryable {
+        WorkError::Retryable(err)
+    } else {
+        WorkError::Permanent(err)
+    }
+}
+
+/// Same split as media ingest: network hiccups and 5xx retry; a feed the
+/// SSRF guard refuses, a 4xx, or a body that isn't iCalendar won't get
+/// better by itself (the next scheduled run tries again anyway).
+fn classify_calendar_error(err: CalendarImportError) -> WorkError {
+    let retryable = match &err {
+        CalendarImportError::Fetch(FetchError::Timeout(_))
+        | CalendarImportError::Fetch(FetchError::Http(_))
+        | CalendarImportError::Db(_) => true,
+        CalendarImportError::Fetch(FetchError::UpstreamStatus(status)) => {
+            *status >= 500 || *status == 408 || *status == 429
+        }
+        CalendarImportError::FeedNotFound
+        | CalendarImportError::Ssrf(_)
+        | CalendarImportError::Fetch(FetchError::Ssrf(_))
+        | CalendarImportError::Fetch(FetchError::BodyTooLarge)
+        | CalendarImportError::Parse(_) => false,
+    };
+    let err = anyhow!("calendar import: {}", err);
+    if retryable {
+        WorkError::Retryable(err)
+    } else {
+        WorkError::Permanent(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn payload_round_trips_through_tagged_json() {
+        let id = Uuid::new_v4();
+        let request = WorkRequest::ReflowEditions { post_id: id };
+        let value = serde_json::to_value(&request).unwrap();
+        assert_eq!(value["kind"], "reflow_editions");
+        assert_eq!(value["post_id"], id.to_string());
+        assert_eq!(
+            serde_json::from_value::<WorkRequest>(value).unwrap(),
+            request
+        );
+
+        let value = serde_json::to_value(WorkRequest::ResolvePostCounties).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({ "kind": "resolve_post_counties" })
+        );
+        assert_eq!(
+            serde_json::from_value::<WorkRequest>(value).unwrap(),
+            WorkRequest::ResolvePostCounties
+        );
+    }
+
+    #[test]
+    fn upstream_client_errors_are_permanent() {
+        assert!(matches!(
+            classify_ingest_error(IngestError::Fetch(FetchError::UpstreamStatus(404))),
+            WorkError::Permanent(_)
+        ));
+        assert!(matches!(
+            classify_ingest_error(IngestError::Fetch(FetchError::UpstreamStatus(503))),
+            WorkError::Retryable(_)
+        ));
+        assert!(matches!(
+            classify_ingest_error(IngestError::MissingStorage),
+            WorkError::Permanent(_)
+        ));
+    }
+}
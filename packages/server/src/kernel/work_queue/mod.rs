@@ -0,0 +1,17 @@
+//! Durable Postgres-backed work queue.
+//!
+//! Side effects that shouldn't hold up a request (media fetch for an
+//! ingested post, edition reflow after a revision) are enqueued as
+//! `WorkRequest`s into `work_items`. The `WorkerPool` started by the server
+//! binary claims them with `FOR UPDATE SKIP LOCKED`, retries transient
+//! failures with exponential backoff, and parks permanent failures (or
+//! items out of attempts) as `dead` for an admin to inspect and retry via
+//! `/WorkQueue/*`.
+
+pub mod handlers;
+pub mod work_item;
+pub mod worker;
+
+pub use handlers::{WorkError, WorkRequest};
+pub use work_item::{WorkItem, WorkItemCount};
+pub use worker::WorkerPool;
@@ -0,0 +1,300 @@
+//! Queue rows (`work_items` table) and the claim/complete/retry SQL.
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
+
+/// One unit of queued work.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct WorkItem {
+    pub id: Uuid,
+    pub kind: String,
+    pub payload: serde_json::Value,
+    pub dedup_key: Option<String>,
+    pub status: String,
+    pub attempts: i32,
+    pub max_attempts: i32,
+    pub run_after: DateTime<Utc>,
+    pub locked_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+/// Per-(kind, status) row count for the admin overview.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct WorkItemCount {
+    pub kind: String,
+    pub status: String,
+    pub count: i64,
+}
+
+impl WorkItem {
+    /// Insert a pending item. Returns `None` when a pending item with the
+    /// same `(kind, dedup_key)` already exists — the existing one covers it.
+    pub async fn enqueue(
+        kind: &str,
+        payload: &serde_json::Value,
+        dedup_key: Option<&str>,
+        db: impl PgExecutor<'_>,
+    ) -> Result<Option<Self>> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            INSERT INTO work_items (kind, payload, dedup_key)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (kind, dedup_key) WHERE status = 'pending' AND dedup_key IS NOT NULL
+            DO NOTHING
+            RETURNING *
+            "#,
+        )
+        .bind(kind)
+        .bind(payload)
+        .bind(dedup_key)
+        .fetch_optional(db)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Claim the oldest due pending item, marking it running and counting
+    /// the attempt. `SKIP LOCKED` lets concurrent workers pass over rows
+    /// another worker is mid-claim on.
+    pub async fn claim_next(pool: &PgPool) -> Result<Option<Self>> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            UPDATE work_items
+            SET status = 'running',
+                attempts = attempts + 1,
+                locked_at = NOW(),
+                updated_at = NOW()
+            WHERE id = (
+                SELECT id FROM work_items
+                WHERE status = 'pending' AND run_after <= NOW()
+                ORDER BY run_after
+                FOR UPDATE SKIP LOCKED
+                LIMIT 1
+            )
+            RETURNING *
+            "#,
+        )
+        .fetch_optional(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    pub async fn mark_succeeded(id: Uuid, pool: &PgPool) -> Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE work_items
+            SET status = 'succeeded', locked_at = NULL, last_error = NULL,
+                finished_at = NOW(), updated_at = NOW()
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Put a failed item back in the queue to run again at `run_after`.
+    ///
+    /// If another pending item with the same dedup key was enqueued while
+    /// this one was running, that item already covers the work, so this
+    /// one is closed out as dead with the error kept for inspection. A twin
+    /// enqueued between the check and the update trips the pending-dedup
+    /// index instead; that lands in the same place.
+    pub async fn mark_retry(
+        id: Uuid,
+        error: &str,
+        run_after: DateTime<Utc>,
+        pool: &PgPool,
+    ) -> Result<()> {
+        let result = sqlx::query(
+            r#"
+            WITH superseded AS (
+                SELECT EXISTS (
+                    SELECT 1 FROM work_items me
+                    JOIN work_items twin
+                      ON twin.kind = me.kind AND twin.dedup_key = me.dedup_key
+                    WHERE me.id = $1
+                      AND twin.status = 'pending'
+                      AND twin.id <> me.id
+                ) AS yes
+            )
+            UPDATE work_items w
+            SET status = CASE WHEN superseded.yes THEN 'dead' ELSE 'pending' END,
+                finished_at = CASE WHEN superseded.yes THEN NOW() END,
+                run_after = $3,
+                last_error = $2,
+                locked_at = NULL,
+                updated_at = NOW()
+            FROM superseded
+            WHERE w.id = $1
+            "#,
+        )
+        .bind(id)
+        .bind(error)
+        .bind(run_after)
+        .execute(pool)
+        .await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(e)
+                if e.as_database_error()
+                    .is_some_and(|d| d.is_unique_violation()) =>
+            {
+                Self::mark_dead(id, error, pool).await
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Give up on an item: attempts exhausted or the failure is permanent.
+    pub async fn mark_dead(id: Uuid, error: &str, pool: &PgPool) -> Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE work_items
+            SET status = 'dead', last_error = $2, locked_at = NULL,
+                finished_at = NOW(), updated_at = NOW()
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .bind(error)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Admin retry of a dead item: back to pending with a fresh attempt
+    /// budget. Returns `None` if the item isn't dead, or if a pending item
+    /// with the same dedup key already exists.
+    pub async fn retry_dead(id: Uuid, pool: &PgPool) -> Result<Option<Self>> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            UPDATE work_items w
+            SET status = 'pending', attempts = 0, run_after = NOW(),
+                finished_at = NULL, updated_at = NOW()
+            WHERE w.id = $1
+              AND w.status = 'dead'
+              AND NOT EXISTS (
+                  SELECT 1 FROM work_items twin
+                  WHERE twin.status = 'pending'
+                    AND twin.kind = w.kind
+                    AND twin.dedup_key = w.dedup_key
+              )
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Recover items whose worker died mid-run: any `running` row claimed
+    /// more than `lease_minutes` ago goes back to pending (or dead, if it
+    /// has used up its attempts). Returns number of rows recovered.
+    pub async fn requeue_stale(lease_minutes: i32, pool: &PgPool) -> Result<u64> {
+        let result = sqlx::query(
+            r#"
+            UPDATE work_items w
+            SET status = next.status,
+                finished_at = CASE WHEN next.status = 'dead' THEN NOW() END,
+                last_error = 'worker lease expired',
+                locked_at = NULL,
+                run_after = NOW(),
+                updated_at = NOW()
+            FROM (
+                SELECT s.id, CASE
+                        WHEN s.attempts >= s.max_attempts THEN 'dead'
+                        -- A pending twin already covers the work, or another
+                        -- stale twin is being requeued in this same statement.
+                        WHEN EXISTS (
+                            SELECT 1 FROM work_items twin
+                            WHERE twin.kind = s.kind
+                              AND twin.dedup_key = s.dedup_key
+                              AND (twin.status = 'pending'
+                                   OR (twin.status = 'running'
+                                       AND twin.locked_at < NOW() - make_interval(mins => $1)
+                                       AND twin.id < s.id))
+                        ) THEN 'dead'
+                        ELSE 'pending'
+                    END AS status
+                FROM work_items s
+                WHERE s.status = 'running'
+                  AND s.locked_at < NOW() - make_interval(mins => $1)
+            ) next
+            WHERE w.id = next.id
+            "#,
+        )
+        .bind(lease_minutes)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    pub async fn find_by_id(id: Uuid, pool: &PgPool) -> Result<Option<Self>> {
+        sqlx::query_as::<_, Self>("SELECT * FROM work_items WHERE id = $1")
+            .bind(id)
+            .fetch_optional(pool)
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Most recently updated items, optionally filtered by status and kind.
+    pub async fn list(
+        status: Option<&str>,
+        kind: Option<&str>,
+        limit: i64,
+        pool: &PgPool,
+    ) -> Result<Vec<Self>> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            SELECT * FROM work_items
+            WHERE ($1::text IS NULL OR status = $1)
+              AND ($2::text IS NULL OR kind = $2)
+            ORDER BY updated_at DESC
+            LIMIT $3
+            "#,
+        )
+        .bind(status)
+        .bind(kind)
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    pub async fn count_by_kind_and_status(pool: &PgPool) -> Result<Vec<WorkItemCount>> {
+        sqlx::query_as::<_, WorkItemCount>(
+            r#"
+            SELECT kind, status, COUNT(*) AS count
+            FROM work_items
+            GROUP BY kind, status
+            ORDER BY kind, status
+            "#,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Drop succeeded items older than `days`. Dead items are kept until an
+    /// admin retries them. Returns number of rows removed.
+    pub async fn prune_succeeded_older_than_days(days: i32, pool: &PgPool) -> Result<u64> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM work_items
+            WHERE status = 'succeeded'
+              AND finished_at < NOW() - make_interval(days => $1)
+            "#,
+        )
+        .bind(days)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
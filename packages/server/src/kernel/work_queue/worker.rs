@@ -0,0 +1,119 @@
+//! Worker pool: claim → handle → succeed / retry / dead.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+
+use super::handlers::{handle, WorkError, WorkRequest};
+use super::work_item::WorkItem;
+use crate::kernel::ServerDeps;
+
+/// First retry waits this long; each further retry doubles it.
+const BASE_BACKOFF_SECS: i64 = 30;
+/// Upper bound on a single backoff.
+const MAX_BACKOFF_SECS: i64 = 60 * 60;
+/// How long an idle worker sleeps before polling again.
+const IDLE_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Delay before the next attempt, given how many attempts have been made
+/// (`attempts >= 1`): 30s, 60s, 120s, … capped at one hour.
+pub fn backoff_delay(attempts: i32) -> chrono::Duration {
+    let exponent = attempts.saturating_sub(1).clamp(0, 20) as u32;
+    let secs = BASE_BACKOFF_SECS
+        .saturating_mul(2i64.saturating_pow(exponent))
+        .min(MAX_BACKOFF_SECS);
+    chrono::Duration::seconds(secs)
+}
+
+/// A fixed number of tokio tasks draining `work_items`.
+pub struct WorkerPool {
+    deps: Arc<ServerDeps>,
+    workers: usize,
+}
+
+impl WorkerPool {
+    pub fn new(deps: Arc<ServerDeps>, workers: usize) -> Self {
+        Self { deps, workers }
+    }
+
+    /// Spawn the workers and return immediately.
+    pub fn start(self) {
+        tracing::info!(workers = self.workers, "Starting work queue workers");
+        for worker_id in 0..self.workers {
+            tokio::spawn(worker_loop(worker_id, self.deps.clone()));
+        }
+    }
+}
+
+async fn worker_loop(worker_id: usize, deps: Arc<ServerDeps>) {
+    loop {
+        match WorkItem::claim_next(&deps.db_pool).await {
+            Ok(Some(item)) => process(worker_id, item, &deps).await,
+            Ok(None) => tokio::time::sleep(IDLE_POLL_INTERVAL).await,
+            Err(e) => {
+                tracing::error!(worker_id, error = %e, "Failed to claim work item");
+                tokio::time::sleep(IDLE_POLL_INTERVAL).await;
+            }
+        }
+    }
+}
+
+/// Run one claimed item to completion and record the outcome. The handler
+/// runs on its own task so a panic is recorded as a failed attempt.
+pub async fn process(worker_id: usize, item: WorkItem, deps: &Arc<ServerDeps>) {
+    let pool = &deps.db_pool;
+
+    let result = match WorkRequest::from_item(&item) {
+        Ok(request) => {
+            let task_deps = deps.clone();
+            match tokio::spawn(async move { handle(&request, &task_deps).await }).await {
+                Ok(result) => result,
+                Err(join_err) => Err(WorkError::Retryable(anyhow::anyhow!(
+                    "handler panicked: {}",
+                    join_err
+                ))),
+            }
+        }
+        Err(e) => Err(WorkError::Permanent(e)),
+    };
+
+    let recorded = match result {
+        Ok(()) => {
+            tracing::info!(worker_id, item_id = %item.id, kind = %item.kind, attempts = item.attempts, "Work item succeeded");
+            WorkItem::mark_succeeded(item.id, pool).await
+        }
+        Err(WorkError::Retryable(e)) if item.attempts < item.max_attempts => {
+            let run_after = Utc::now() + backoff_delay(item.attempts);
+            tracing::warn!(worker_id, item_id = %item.id, kind = %item.kind, attempts = item.attempts, %run_after, error = %e, "Work item failed; will retry");
+            WorkItem::mark_retry(item.id, &e.to_string(), run_after, pool).await
+        }
+        Err(WorkError::Retryable(e)) | Err(WorkError::Permanent(e)) => {
+            tracing::error!(worker_id, item_id = %item.id, kind = %item.kind, attempts = item.attempts, error = %e, "Work item dead");
+            WorkItem::mark_dead(item.id, &e.to_string(), pool).await
+        }
+    };
+
+    if let Err(e) = recorded {
+        // The lease-expiry job will pick the item up again.
+        tracing::error!(worker_id, item_id = %item.id, error = %e, "Failed to record work item outcome");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_from_thirty_seconds() {
+        assert_eq!(backoff_delay(1).num_seconds(), 30);
+        assert_eq!(backoff_delay(2).num_seconds(), 60);
+        assert_eq!(backoff_delay(3).num_seconds(), 120);
+    }
+
+    #[test]
+    fn backoff_is_capped_at_one_hour() {
+        assert_eq!(backoff_delay(8).num_seconds(), 3600);
+        assert_eq!(backoff_delay(i32::MAX).num_seconds(), 3600);
+    }
+}
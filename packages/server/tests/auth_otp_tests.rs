@@ -0,0 +1,69 @@
+//! OTP login against a real Postgres, with Twilio mocked.
+//!
+//! Members are created through `verify_otp` (the mock accepts `123456`),
+//! then `send_otp` is checked for channel routing and for finding rows
+//! stored under pre-normalization hashes.
+
+mod common;
+
+use anyhow::Result;
+use common::TestHarness;
+use server_core::domains::auth::activities::{send_otp, verify_otp};
+use server_core::domains::auth::models::{hash_phone_number, NormalizedIdentifier};
+use server_core::kernel::test_dependencies::SentOtp;
+
+async fn register(h: &TestHarness, identifier: &str) -> Result<()> {
+    verify_otp(identifier.to_string(), "123456".to_string(), None, &h.deps).await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn send_otp_routes_phones_to_sms_and_emails_to_email() -> Result<()> {
+    let h = TestHarness::new().await?;
+    register(&h, "+16125550100").await?;
+    register(&h, "editor@example.com").await?;
+
+    send_otp("(612) 555-0100".to_string(), None, &h.deps).await?;
+    send_otp("Editor@Example.com".to_string(), None, &h.deps).await?;
+
+    assert_eq!(
+        h.twilio.sent(),
+        vec![
+            SentOtp {
+                channel: "sms",
+                recipient: "+16125550100".to_string(),
+            },
+            SentOtp {
+                channel: "email",
+                recipient: "editor@example.com".to_string(),
+            },
+        ]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn send_otp_finds_and_migrates_legacy_phone_hashes() -> Result<()> {
+    let h = TestHarness::new().await?;
+    register(&h, "+16125550100").await?;
+
+    // Older code hashed the number as typed
+    let current = NormalizedIdentifier::parse("+16125550100")?.hash();
+    let legacy = hash_phone_number("612-555-0100");
+    sqlx::query("UPDATE identifiers SET phone_hash = $2 WHERE phone_hash = $1")
+        .bind(&current)
+        .bind(&legacy)
+        .execute(&h.pool)
+        .await?;
+
+    send_otp("612-555-0100".to_string(), None, &h.deps).await?;
+
+    assert_eq!(h.twilio.sent().len(), 1);
+    let migrated: bool =
+        sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM identifiers WHERE phone_hash = $1)")
+            .bind(&current)
+            .fetch_one(&h.pool)
+            .await?;
+    assert!(migrated);
+    Ok(())
+}
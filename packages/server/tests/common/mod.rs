@@ -25,9 +25,11 @@ use uuid::Uuid;
 
 use server_core::api;
 use server_core::api::state::AppState;
+use server_core::domains::auth::activities::verify_otp;
 use server_core::domains::editions::models::county::County;
 use server_core::domains::posts::models::ApiKey;
 use server_core::domains::tag::models::tag::Tag;
+use server_core::kernel::test_dependencies::MockTwilioService;
 use server_core::kernel::{ServerDeps, TestDependencies};
 
 pub struct TestHarness {
@@ -36,6 +38,9 @@ pub struct TestHarness {
     pub container: ContainerAsync<Postgres>,
     pub pool: PgPool,
     pub deps: Arc<ServerDeps>,
+    /// The OTP sender behind `deps`, for asserting what was sent.
+    #[allow(dead_code)]
+    pub twilio: Arc<MockTwilioService>,
     pub router: Router,
 }
 
@@ -50,7 +55,10 @@ impl TestHarness {
         // Apply every migration in packages/server/migrations/ in filename order.
         sqlx::migrate!("./migrations").run(&pool).await?;
 
-        let deps_raw = TestDependencies::new().into_server_deps(pool.clone());
+        let twilio = Arc::new(MockTwilioService::new());
+        let deps_raw = TestDependencies::new()
+            .mock_twilio(twilio.clone())
+            .into_server_deps(pool.clone());
         let deps = Arc::new(deps_raw);
         let state = AppState { deps: deps.clone() };
         let router = api::router(state);
@@ -66,6 +74,7 @@ impl TestHarness {
             container,
             pool,
             deps,
+            twilio,
             router,
         })
     }
@@ -83,6 +92,14 @@ impl TestHarness {
         Ok(issued.plaintext)
     }
 
+    /// Sign in with the test identifier and return its member id, for
+    /// activities that record who acted.
+    #[allow(dead_code)]
+    pub async fn staff_member(&self) -> Result<Uuid> {
+        let verified = verify_otp("+1234567890".into(), "000000".into(), None, &self.deps).await?;
+        Ok(verified.member_id)
+    }
+
     /// POST an envelope to `/Posts/create_post`. Returns (status, json body).
     pub async fn ingest(
         &self,
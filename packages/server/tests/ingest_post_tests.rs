@@ -13,6 +13,8 @@
 //!   * organisation dedup (no duplicate org row)
 //!   * individual-source consent gate (soft-fail → in_review)
 //!   * revision: prior post archived
+//!   * PII in body → redacted, recorded in pii_findings, routed to in_review
+//!   * PII in title → redacted with the same policy
 //!   * multi-citation (citations[] → citation_ids[] in 201)
 //!   * unknown service_area → 422; unknown topic → auto-creates + in_review
 //!   * auth: missing Bearer → 401; wrong scope → 403
@@ -22,7 +24,8 @@ mod common;
 use common::{minimal_update_envelope, TestHarness};
 use server_core::common::PostId;
 use server_core::domains::organization::models::Organization;
-use server_core::domains::posts::models::{Post, PostSource};
+use server_core::domains::pii::PiiFinding;
+use server_core::domains::posts::models::{Post, PostReviewFlag, PostSource};
 use serde_json::json;
 use uuid::Uuid;
 
@@ -114,6 +117,14 @@ async fn unknown_topic_auto_creates_and_lands_in_review() {
 
     assert_eq!(status.as_u16(), 201, "body = {body}");
     assert_eq!(body["status"], "in_review");
+    let post_id: Uuid = serde_json::from_value(body["post_id"].clone()).expect("post_id");
+
+    let flags = PostReviewFlag::find_for_post(PostId::from_uuid(post_id), &h.pool)
+        .await
+        .expect("flags");
+    let topic = flags.iter().find(|f| f.flag == "unknown_topic").expect("topic flag");
+    assert_eq!(topic.details["topics"], json!(["a-brand-new-topic-slug"]));
+    assert!(topic.blocking && topic.resolved_at.is_none());
 }
 
 #[tokio::test]
@@ -249,6 +260,73 @@ async fn revision_archives_prior_post() {
     assert_eq!(prior.status, "archived", "prior post archived on revision");
 }
 
+#[tokio::test]
+async fn pii_in_body_is_redacted_and_routes_to_in_review() {
+    let h = TestHarness::new().await.expect("harness");
+    let token = h.issue_test_key().await.expect("key");
+
+    let mut env = minimal_update_envelope();
+    let body = format!(
+        "{} Volunteers asked that filers not share details like 123-45-6789 over email.",
+        env["body_raw"].as_str().unwrap()
+    );
+    env["body_raw"] = json!(body);
+
+    let (status, body) = h.ingest(&token, Some(Uuid::now_v7()), &env).await.expect("ingest");
+    assert_eq!(status.as_u16(), 201, "body = {body}");
+    assert_eq!(body["status"], "in_review");
+    let post_id: Uuid = serde_json::from_value(body["post_id"].clone()).expect("post_id");
+
+    let post = Post::find_by_id(PostId::from_uuid(post_id), &h.pool)
+        .await
+        .expect("query")
+        .expect("exists");
+    assert!(!post.body_raw.contains("123-45-6789"), "raw SSN stored");
+    assert!(post.body_raw.contains("[SSN]"));
+
+    let findings = PiiFinding::find_for_entity("post", post_id, &h.pool)
+        .await
+        .expect("findings");
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].pii_type, "ssn");
+    assert_eq!(findings[0].field, "body_raw");
+    assert_eq!(findings[0].source, "ingest");
+    assert!(!findings[0].masked_value.contains("123-45"));
+
+    let flags = PostReviewFlag::find_for_post(PostId::from_uuid(post_id), &h.pool)
+        .await
+        .expect("flags");
+    let pii = flags.iter().find(|f| f.flag == "pii_detected").expect("pii flag");
+    assert_eq!(pii.details["finding_count"], 1);
+}
+
+#[tokio::test]
+async fn pii_in_title_is_redacted() {
+    let h = TestHarness::new().await.expect("harness");
+    let token = h.issue_test_key().await.expect("key");
+
+    let mut env = minimal_update_envelope();
+    env["title"] = json!("Tax-Help Hours Extended; Questions Go to jane.doe@gmail.com");
+
+    let (status, body) = h.ingest(&token, Some(Uuid::now_v7()), &env).await.expect("ingest");
+    assert_eq!(status.as_u16(), 201, "body = {body}");
+    assert_eq!(body["status"], "in_review");
+    let post_id: Uuid = serde_json::from_value(body["post_id"].clone()).expect("post_id");
+
+    let post = Post::find_by_id(PostId::from_uuid(post_id), &h.pool)
+        .await
+        .expect("query")
+        .expect("exists");
+    assert_eq!(post.title, "Tax-Help Hours Extended; Questions Go to [EMAIL]");
+
+    let findings = PiiFinding::find_for_entity("post", post_id, &h.pool)
+        .await
+        .expect("findings");
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].pii_type, "email");
+    assert_eq!(findings[0].field, "title");
+}
+
 #[tokio::test]
 async fn multi_citation_returns_citation_ids() {
     let h = TestHarness::new().await.expect("harness");
@@ -0,0 +1,72 @@
+//! Duplicate merge against a real Postgres.
+//!
+//! Coverage:
+//!   * a post ingested as a possible duplicate (`duplicate_of_id` set by
+//!     Root Signal) merges into its original
+//!   * the duplicate is archived behind a redirect to the survivor, and a
+//!     re-ingest of its content resolves to the survivor
+//!   * merging it a second time is refused
+
+mod common;
+
+use common::{minimal_update_envelope, TestHarness};
+use serde_json::json;
+use server_core::common::PostId;
+use server_core::domains::posts::activities::post_merge::{merge_into, PostMergeError};
+use server_core::domains::posts::models::Post;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn ingested_possible_duplicate_merges_into_its_original() {
+    let h = TestHarness::new().await.expect("harness");
+    let token = h.issue_test_key().await.expect("key");
+    let member_id = h.staff_member().await.expect("member");
+
+    let (status, body) = h
+        .ingest(&token, Some(Uuid::now_v7()), &minimal_update_envelope())
+        .await
+        .expect("ingest original");
+    assert_eq!(status.as_u16(), 201, "body = {body}");
+    let original_id: Uuid = serde_json::from_value(body["post_id"].clone()).expect("post_id");
+
+    let mut dup_env = minimal_update_envelope();
+    dup_env["title"] = json!("Free Tax Help at Sabathani Now Runs Through April 29");
+    dup_env["source"]["source_url"] = json!("https://www.example-newspaper.com/sabathani-taxes");
+    dup_env["editorial"]["duplicate_of_id"] = json!(original_id);
+    let (status, body) = h
+        .ingest(&token, Some(Uuid::now_v7()), &dup_env)
+        .await
+        .expect("ingest duplicate");
+    assert_eq!(status.as_u16(), 201, "body = {body}");
+    let duplicate_id: Uuid = serde_json::from_value(body["post_id"].clone()).expect("post_id");
+
+    let duplicate = Post::find_by_id(PostId::from_uuid(duplicate_id), &h.pool)
+        .await
+        .expect("query")
+        .expect("exists");
+    assert_eq!(duplicate.duplicate_of_id, Some(PostId::from_uuid(original_id)));
+
+    let summary = merge_into(duplicate_id, original_id, &[], member_id, &h.deps)
+        .await
+        .expect("merge");
+    assert_eq!(summary.survivor_post_id, original_id);
+
+    let duplicate = Post::find_by_id(PostId::from_uuid(duplicate_id), &h.pool)
+        .await
+        .expect("query")
+        .expect("exists");
+    assert_eq!(duplicate.status, "archived");
+    assert_eq!(duplicate.duplicate_of_id, Some(PostId::from_uuid(original_id)));
+
+    // Re-ingesting the duplicate's content lands on the survivor
+    let (status, body) = h
+        .ingest(&token, Some(Uuid::now_v7()), &dup_env)
+        .await
+        .expect("re-ingest");
+    assert_eq!(status.as_u16(), 201, "body = {body}");
+    assert_eq!(body["content_hash_dedup_hit"], true);
+    assert_eq!(body["post_id"], json!(original_id));
+
+    let again = merge_into(duplicate_id, original_id, &[], member_id, &h.deps).await;
+    assert!(matches!(again, Err(PostMergeError::AlreadyMerged(id)) if id == duplicate_id));
+}
@@ -0,0 +1,151 @@
+//! Signal Inbox review flags against a real Postgres.
+//!
+//! Coverage:
+//!   * resolving one of two blocking flags leaves the post `in_review`;
+//!     resolving the last one activates it
+//!   * resolving a non-blocking flag never activates a post
+//!   * an editor save that redacts PII raises `pii_detected` and pulls an
+//!     active post back to `in_review`
+
+mod common;
+
+use common::TestHarness;
+use serde_json::json;
+use server_core::common::PostId;
+use server_core::domains::posts::activities::{admin_update_post, resolve_review_flag};
+use server_core::domains::posts::models::{CreatePost, NewReviewFlag, Post, PostReviewFlag};
+use uuid::Uuid;
+
+async fn fixture_post(h: &TestHarness, status: &str, flags: &[NewReviewFlag]) -> PostId {
+    let post = Post::create(
+        CreatePost::builder()
+            .title("Review-flag test post")
+            .body_raw("Short body for a fixture post used by the review flag test suite.")
+            .post_type("story".to_string())
+            .weight("medium".to_string())
+            .status(status.to_string())
+            .build(),
+        &h.pool,
+    )
+    .await
+    .expect("create post");
+    PostReviewFlag::raise_all(post.id, flags, &h.pool)
+        .await
+        .expect("raise flags");
+    post.id
+}
+
+async fn flag_id(h: &TestHarness, post_id: PostId, flag: &str) -> Uuid {
+    PostReviewFlag::find_for_post(post_id, &h.pool)
+        .await
+        .expect("flags")
+        .into_iter()
+        .find(|f| f.flag == flag)
+        .expect("flag raised")
+        .id
+        .into_uuid()
+}
+
+async fn status(h: &TestHarness, post_id: PostId) -> String {
+    Post::find_by_id(post_id, &h.pool)
+        .await
+        .expect("query")
+        .expect("exists")
+        .status
+}
+
+#[tokio::test]
+async fn resolving_the_last_blocking_flag_activates_the_post() {
+    let h = TestHarness::new().await.expect("harness");
+    let member_id = h.staff_member().await.expect("member");
+    let post_id = fixture_post(
+        &h,
+        "in_review",
+        &[
+            NewReviewFlag::blocking("low_confidence", json!({ "extraction_confidence": 40 })),
+            NewReviewFlag::blocking("pii_detected", json!({ "finding_count": 1 })),
+        ],
+    )
+    .await;
+
+    let first = flag_id(&h, post_id, "low_confidence").await;
+    let resolved = resolve_review_flag(post_id.into_uuid(), first, member_id, true, &[], &h.deps)
+        .await
+        .expect("resolve")
+        .expect("open flag");
+    assert!(resolved.resolved_at.is_some());
+    assert_eq!(status(&h, post_id).await, "in_review");
+
+    let second = flag_id(&h, post_id, "pii_detected").await;
+    resolve_review_flag(post_id.into_uuid(), second, member_id, true, &[], &h.deps)
+        .await
+        .expect("resolve")
+        .expect("open flag");
+    assert_eq!(status(&h, post_id).await, "active");
+
+    // Already resolved
+    let again = resolve_review_flag(post_id.into_uuid(), second, member_id, true, &[], &h.deps)
+        .await
+        .expect("resolve");
+    assert!(again.is_none());
+}
+
+#[tokio::test]
+async fn resolving_a_non_blocking_flag_leaves_the_post_in_review() {
+    let h = TestHarness::new().await.expect("harness");
+    let member_id = h.staff_member().await.expect("member");
+    let post_id = fixture_post(
+        &h,
+        "in_review",
+        &[NewReviewFlag {
+            flag: "unknown_topic",
+            details: json!({ "topics": ["a-brand-new-topic-slug"] }),
+            blocking: false,
+        }],
+    )
+    .await;
+
+    let flag = flag_id(&h, post_id, "unknown_topic").await;
+    resolve_review_flag(post_id.into_uuid(), flag, member_id, true, &[], &h.deps)
+        .await
+        .expect("resolve")
+        .expect("open flag");
+    assert_eq!(status(&h, post_id).await, "in_review");
+}
+
+#[tokio::test]
+async fn editor_save_that_redacts_a_phone_number_raises_pii_detected() {
+    let h = TestHarness::new().await.expect("harness");
+    let member_id = h.staff_member().await.expect("member");
+    let post_id = fixture_post(&h, "active", &[]).await;
+
+    let post = admin_update_post(
+        post_id.into_uuid(),
+        None,
+        Some("My cell is 612-555-0199 if anyone has a spare crib.".to_string()),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        member_id,
+        &h.deps,
+    )
+    .await
+    .expect("update");
+    assert!(!post.body_raw.contains("612-555-0199"));
+    assert_eq!(status(&h, post_id).await, "in_review");
+
+    let flags = PostReviewFlag::find_for_post(post_id, &h.pool)
+        .await
+        .expect("flags");
+    let pii = flags
+        .iter()
+        .find(|f| f.flag == "pii_detected")
+        .expect("pii_detected raised");
+    assert!(pii.blocking);
+    assert!(pii.resolved_at.is_none());
+}
@@ -3,7 +3,7 @@
 use std::collections::HashMap;
 
 pub mod models;
-use reqwest::{header, Client};
+use reqwest::{header, Client, StatusCode};
 
 use crate::models::{OTPResponse, OTPVerifyResponse};
 use serde_json::Value;
@@ -35,25 +35,38 @@ impl TwilioService {
         Self { options }
     }
 
+    /// Send an OTP by SMS to an E.164 phone number.
     pub async fn send_otp(
         self: &TwilioService,
+        phone_number: &str,
+    ) -> Result<OTPResponse, &'static str> {
+        if !is_phone_number(phone_number) {
+            return Err("Invalid phone number");
+        }
+        self.start_verification(phone_number, "sms").await
+    }
+
+    /// Send an OTP by email, through the Verify service's email channel.
+    /// The channel (and its SendGrid template) must be enabled on the service.
+    pub async fn send_email_otp(
+        self: &TwilioService,
+        email: &str,
+    ) -> Result<OTPResponse, &'static str> {
+        if !is_email(email) {
+            return Err("Invalid email address");
+        }
+        self.start_verification(email, "email").await
+    }
+
+    async fn start_verification(
+        &self,
         recipient: &str,
+        channel: &str,
     ) -> Result<OTPResponse, &'static str> {
         let account_sid = self.options.account_sid.clone();
         let auth_token = self.options.auth_token.clone();
         let service_id = self.options.service_id.clone();
 
-        // Validate recipient format
-        let channel = if is_email(recipient) {
-            "email"
-        } else if is_phone_number(recipient) {
-            "sms"
-        } else {
-            eprintln!("Invalid recipient format: {}", recipient);
-            eprintln!("Expected: email (user@example.com) or E.164 phone (+1234567890)");
-            return Err("Invalid recipient format");
-        };
-
         let url = format!(
             "https://verify.twilio.com/v2/Services/{serv_id}/Verifications",
             serv_id = service_id
@@ -85,29 +98,21 @@ impl TwilioService {
                 let status = response.status();
                 if !status.is_success() {
                     let error_body = response.text().await.unwrap_or_default();
-                    eprintln!("Twilio error ({}): {}", status, error_body);
 
                     // Parse error to provide more helpful messages
                     if let Ok(error_json) = serde_json::from_str::<serde_json::Value>(&error_body) {
                         if let Some(code) = error_json.get("code").and_then(|c| c.as_i64()) {
                             match code {
                                 60200 => {
-                                    // Invalid parameter - often means email channel not enabled
+                                    // Invalid parameter - often means the email channel
+                                    // isn't enabled on the Verify service
                                     if channel == "email" {
-                                        eprintln!("Email channel may not be enabled on your Twilio Verify Service.");
-                                        eprintln!("Enable it at: https://console.twilio.com/us1/develop/verify/services");
                                         return Err("Email verification not enabled");
                                     }
                                     return Err("Invalid parameter");
                                 }
-                                60203 => {
-                                    eprintln!("Maximum check attempts reached");
-                                    return Err("Too many verification attempts");
-                                }
-                                60202 => {
-                                    eprintln!("Maximum send attempts reached");
-                                    return Err("Too many send attempts");
-                                }
+                                60203 => return Err("Too many verification attempts"),
+                                60202 => return Err("Too many send attempts"),
                                 _ => return Err("Twilio returned an error"),
                             }
                         }
@@ -119,20 +124,19 @@ impl TwilioService {
                 let result = response.json::<OTPResponse>().await;
                 match result {
                     Ok(data) => Ok(data),
-                    Err(e) => {
-                        eprintln!("Failed to parse Twilio response: {}", e);
-                        Err("Error parsing OTP response")
-                    }
+                    Err(_) => Err("Error parsing OTP response"),
                 }
             }
-            Err(e) => {
-                eprintln!("Request to Twilio failed: {}", e);
-                Err("Error sending OTP")
-            }
+            Err(_) => Err("Error sending OTP"),
         }
     }
 
-    pub async fn verify_otp(&self, recipient: &str, code: &str) -> Result<(), &'static str> {
+    /// Check a code sent by either channel; `recipient` is the phone number
+    /// or email it was sent to.
+    ///
+    /// `Ok(false)` means Twilio definitely rejected the code (wrong, expired
+    /// or no pending verification). `Err` means no answer was obtained.
+    pub async fn verify_otp(&self, recipient: &str, code: &str) -> Result<bool, &'static str> {
         let account_sid = self.options.account_sid.clone();
         let auth_token = self.options.auth_token.clone();
         let service_id = self.options.service_id.clone();
@@ -165,16 +169,17 @@ impl TwilioService {
 
         match res {
             Ok(response) => {
-                let data = response.json::<OTPVerifyResponse>().await;
-                match data {
-                    Ok(result) => {
-                        if result.status == "approved" {
-                            Ok(())
-                        } else {
-                            Err("Error verifying OTP")
-                        }
-                    }
-                    Err(_) => Err("Error verifying OTP"),
+                // Twilio answers 404 once the verification has expired, been
+                // used, or run out of attempts: a rejection, not an outage.
+                if response.status() == StatusCode::NOT_FOUND {
+                    return Ok(false);
+                }
+                if !response.status().is_success() {
+                    return Err("Twilio returned an error");
+                }
+                match response.json::<OTPVerifyResponse>().await {
+                    Ok(result) => Ok(result.status == "approved"),
+                    Err(_) => Err("Error parsing OTP response"),
                 }
             }
             Err(_) => Err("Error verifying OTP"),